@@ -1,4 +1,6 @@
 use crate::RuleDiagnostic;
+use biome_console::MarkupBuf;
+use biome_diagnostics::Applicability;
 use biome_parser::AnyParse;
 use camino::Utf8PathBuf;
 use std::{fmt::Debug, sync::Arc};
@@ -11,9 +13,53 @@ pub type AnalyzerPluginVec = Vec<Arc<Box<dyn AnalyzerPlugin>>>;
 
 /// Definition of an analyzer plugin.
 pub trait AnalyzerPlugin: Debug + Send + Sync {
-    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic>;
+    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<PluginSignal>;
 
     fn supports_css(&self) -> bool;
 
+    fn supports_graphql(&self) -> bool;
+
+    fn supports_html(&self) -> bool;
+
     fn supports_js(&self) -> bool;
+
+    fn supports_json(&self) -> bool;
+}
+
+/// A diagnostic emitted by an [AnalyzerPlugin], with the code action that
+/// fixes it, if any.
+#[derive(Debug)]
+pub struct PluginSignal {
+    pub diagnostic: RuleDiagnostic,
+    pub action: Option<PluginAction>,
+}
+
+impl From<RuleDiagnostic> for PluginSignal {
+    fn from(diagnostic: RuleDiagnostic) -> Self {
+        Self {
+            diagnostic,
+            action: None,
+        }
+    }
+}
+
+impl PluginSignal {
+    pub fn with_action(mut self, action: PluginAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
+
+/// Code action emitted by an [AnalyzerPlugin].
+///
+/// The action replaces the root of the analyzed file with the root of the
+/// rewritten file, which should only differ from the analyzed file where the
+/// plugin matched the code the action is attached to.
+#[derive(Clone, Debug)]
+pub struct PluginAction {
+    pub applicability: Applicability,
+    pub message: MarkupBuf,
+    /// The rewritten file. It must be parsed with the same language as the
+    /// analyzed file, otherwise the action is discarded.
+    pub root: AnyParse,
 }
@@ -30,7 +30,9 @@ mod visitor;
 // Re-exported for use in the `declare_group` macro
 pub use biome_diagnostics::category_concat;
 
-pub use crate::analyzer_plugin::{AnalyzerPlugin, AnalyzerPluginSlice, AnalyzerPluginVec};
+pub use crate::analyzer_plugin::{
+    AnalyzerPlugin, AnalyzerPluginSlice, AnalyzerPluginVec, PluginAction, PluginSignal,
+};
 pub use crate::categories::{
     ActionCategory, OtherActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder,
     RuleCategory, SUPPRESSION_INLINE_ACTION_CATEGORY, SUPPRESSION_TOP_LEVEL_ACTION_CATEGORY,
@@ -201,8 +203,8 @@ where
 
         for plugin in plugins {
             let root: AnyParse = ctx.root.syntax().as_send().expect("not a root node").into();
-            let diagnostics = plugin.evaluate(root, ctx.options.file_path.clone());
-            for diagnostic in diagnostics {
+            let signals = plugin.evaluate(root, ctx.options.file_path.clone());
+            for PluginSignal { diagnostic, action } in signals {
                 let name = diagnostic
                     .subcategory
                     .clone()
@@ -261,7 +263,28 @@ where
                         suppression.did_suppress_signal = true;
                     }
                 } else {
-                    let signal = DiagnosticSignal::new(|| diagnostic.clone());
+                    let signal = DiagnosticSignal::new(|| diagnostic.clone()).with_action(|| {
+                        let action = action.as_ref()?;
+                        // The plugin may rewrite the file with another language,
+                        // in which case it can't replace the analyzed root
+                        let next_root = action.root.root().into_node::<L>()?;
+                        let root = ctx.root.syntax().clone();
+                        // A rewrite that doesn't change the code would be
+                        // applied over and over by `fix_all`
+                        if next_root.text_with_trivia() == root.text_with_trivia() {
+                            return None;
+                        }
+                        let mut mutation = BatchMutation::new(root.clone());
+                        mutation.replace_element_discard_trivia(root.into(), next_root.into());
+
+                        Some(AnalyzerAction {
+                            rule_name: None,
+                            category: ActionCategory::QuickFix(format!("plugin.{name}").into()),
+                            applicability: action.applicability,
+                            message: action.message.clone(),
+                            mutation,
+                        })
+                    });
                     if let ControlFlow::Break(br) = (emit_signal)(&signal) {
                         return Some(br);
                     }
@@ -443,12 +466,29 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(&token, true, index, comment.text(), piece.text_range())?;
+                self.handle_comment(
+                    token.text_range(),
+                    true,
+                    index,
+                    comment.text(),
+                    piece.text_range(),
+                )?;
             }
         }
 
         self.bump_line_index(token.text_trimmed(), token.text_trimmed_range());
 
+        if let Some(comment) = self.suppression_action.comment_ending_with(&token) {
+            let text = comment.text_trimmed().to_string();
+            self.handle_comment(
+                comment.text_range_with_trivia(),
+                false,
+                0,
+                &text,
+                comment.text_trimmed_range(),
+            )?;
+        }
+
         for (index, piece) in token.trailing_trivia().pieces().enumerate() {
             if matches!(
                 piece.kind(),
@@ -460,7 +500,13 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(&token, false, index, comment.text(), piece.text_range())?;
+                self.handle_comment(
+                    token.text_range(),
+                    false,
+                    index,
+                    comment.text(),
+                    piece.text_range(),
+                )?;
             }
         }
 
@@ -581,7 +627,7 @@ where
     /// comments, and create line suppression entries accordingly
     fn handle_comment(
         &mut self,
-        token: &SyntaxToken<L>,
+        token_range: TextRange,
         _is_leading: bool,
         _index: usize,
         text: &str,
@@ -629,7 +675,7 @@ where
 
             if let Err(diagnostic) =
                 self.suppressions
-                    .push_suppression(&suppression, range, token.text_range())
+                    .push_suppression(&suppression, range, token_range)
             {
                 let signal = DiagnosticSignal::new(|| diagnostic.clone());
                 (self.emit_signal)(&signal)?;
@@ -1,7 +1,7 @@
 use crate::SuppressionCommentEmitterPayload;
 use biome_rowan::{
-    BatchMutation, Language, SyntaxToken, TextLen, TextRange, TokenAtOffset, TriviaPiece,
-    TriviaPieceKind,
+    BatchMutation, Language, SyntaxNode, SyntaxToken, TextLen, TextRange, TokenAtOffset,
+    TriviaPiece, TriviaPieceKind,
 };
 
 pub trait SuppressionAction {
@@ -109,6 +109,19 @@ pub trait SuppressionAction {
 
     /// Returns the whole top level comment, based on the language
     fn suppression_top_level_comment(&self, _suppression_text: &str) -> String;
+
+    /// Returns the comment node that ends with `token`.
+    ///
+    /// Some languages, such as HTML, don't store comments in the trivia of the
+    /// tokens, but as nodes of the syntax tree. The analyzer calls this function
+    /// for every token, so the suppression comments of these languages can be
+    /// collected as well.
+    fn comment_ending_with(
+        &self,
+        _token: &SyntaxToken<Self::Language>,
+    ) -> Option<SyntaxNode<Self::Language>> {
+        None
+    }
 }
 
 /// Convenient type to store useful information
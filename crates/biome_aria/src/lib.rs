@@ -1,7 +1,9 @@
 #![deny(clippy::use_self)]
 
+mod options;
 pub mod roles;
 
+pub use options::ValidAriaRoleOptions;
 pub use roles::AriaRoles;
 
 pub trait Element {
@@ -0,0 +1,11 @@
+use biome_deserialize_macros::Deserializable;
+use serde::{Deserialize, Serialize};
+
+/// Options of the `useValidAriaRole` rule, shared by all the languages that implement it.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ValidAriaRoleOptions {
+    pub allow_invalid_roles: Box<[Box<str>]>,
+    pub ignore_non_dom: bool,
+}
@@ -1,28 +1,19 @@
 use crate::CliDiagnostic;
 use biome_configuration::Configuration;
 use biome_fs::FileSystem;
+use biome_rowan::{TextRange, TextSize};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::collections::BTreeMap;
 use std::ffi::OsString;
+use std::ops::Range;
+use std::sync::Arc;
 
 pub(crate) fn get_changed_files(
     fs: &dyn FileSystem,
     configuration: &Configuration,
     since: Option<&str>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
-    let default_branch = configuration
-        .vcs
-        .as_ref()
-        .and_then(|v| v.default_branch.as_ref());
-
-    let base = match (since, default_branch) {
-        (Some(since), Some(_)) => since,
-        (Some(since), None) => since,
-        (None, Some(branch)) => branch,
-        (None, None) => {
-            return Err(CliDiagnostic::incompatible_end_configuration(
-                "The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.",
-            ));
-        }
-    };
+    let base = get_base(configuration, since)?;
 
     let changed_files = fs.get_changed_files(base)?;
 
@@ -38,3 +29,242 @@ pub(crate) fn get_staged_files(fs: &dyn FileSystem) -> Result<Vec<OsString>, Cli
 
     Ok(filtered_staged_files)
 }
+
+/// Reads the lines that have been changed compared to the base branch, or
+/// the lines that have been staged when `staged` is `true`.
+pub(crate) fn get_changed_lines(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+    since: Option<&str>,
+    staged: bool,
+) -> Result<ChangedLines, CliDiagnostic> {
+    let diff = if staged {
+        fs.get_staged_diff()?
+    } else {
+        fs.get_changed_diff(get_base(configuration, since)?)?
+    };
+
+    Ok(ChangedLines::from_diff(
+        &diff,
+        fs.working_directory().as_deref(),
+    ))
+}
+
+/// Returns the reference to compare against when `--changed` is used.
+fn get_base<'a>(
+    configuration: &'a Configuration,
+    since: Option<&'a str>,
+) -> Result<&'a str, CliDiagnostic> {
+    let default_branch = configuration
+        .vcs
+        .as_ref()
+        .and_then(|v| v.default_branch.as_deref());
+
+    match (since, default_branch) {
+        (Some(since), Some(_)) => Ok(since),
+        (Some(since), None) => Ok(since),
+        (None, Some(branch)) => Ok(branch),
+        (None, None) => Err(CliDiagnostic::incompatible_end_configuration(
+            "The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.",
+        )),
+    }
+}
+
+/// The lines that have been changed in each file, read from a unified diff.
+///
+/// Lines are zero-based, and each range excludes its end.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChangedLines(Arc<BTreeMap<Utf8PathBuf, Vec<Range<usize>>>>);
+
+impl ChangedLines {
+    /// Parses a diff generated with `--unified=0`.
+    ///
+    /// The paths of the files are joined to `working_directory`, if any.
+    pub(crate) fn from_diff(diff: &str, working_directory: Option<&Utf8Path>) -> Self {
+        let mut files: BTreeMap<Utf8PathBuf, Vec<Range<usize>>> = BTreeMap::new();
+        let mut current_file: Option<&mut Vec<Range<usize>>> = None;
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                let path = path.trim_end().trim_matches('"');
+                current_file = path.strip_prefix("b/").map(|path| {
+                    let path = match working_directory {
+                        Some(working_directory) => working_directory.join(path),
+                        None => Utf8PathBuf::from(path),
+                    };
+                    files.entry(path).or_default()
+                });
+            } else if let Some(hunk) = line.strip_prefix("@@ ") {
+                let Some(ranges) = current_file.as_mut() else {
+                    continue;
+                };
+                // The header of a hunk looks like `@@ -start,count +start,count @@`,
+                // where the count is omitted when it's `1`.
+                let Some(added) = hunk
+                    .split_whitespace()
+                    .find_map(|part| part.strip_prefix('+'))
+                else {
+                    continue;
+                };
+                let (start, count) = match added.split_once(',') {
+                    Some((start, count)) => (start.parse::<usize>(), count.parse::<usize>()),
+                    None => (added.parse::<usize>(), Ok(1)),
+                };
+                if let (Ok(start), Ok(count)) = (start, count) {
+                    // Hunks that only remove lines don't add any line to check.
+                    if count > 0 {
+                        let start = start.saturating_sub(1);
+                        ranges.push(start..start + count);
+                    }
+                }
+            }
+        }
+
+        Self(Arc::new(files))
+    }
+
+    /// Returns the changed lines of the file at `path`, or an empty slice if
+    /// the file has no changed lines.
+    pub(crate) fn get(&self, path: &Utf8Path) -> &[Range<usize>] {
+        self.0.get(path).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns whether `range` of `content`, the content of the file at
+    /// `path`, overlaps with a changed line.
+    pub(crate) fn contains(&self, path: &Utf8Path, content: &str, range: TextRange) -> bool {
+        let start = line_of_offset(content, range.start());
+        // The end of a range is exclusive
+        let end = line_of_offset(
+            content,
+            range.end().max(range.start() + TextSize::from(1)) - TextSize::from(1),
+        );
+        self.get(path)
+            .iter()
+            .any(|lines| lines.start <= end && start < lines.end)
+    }
+
+    /// Returns the text ranges of the changed lines of `content`, the content
+    /// of the file at `path`.
+    ///
+    /// There's a range for every changed line that isn't blank, without its
+    /// leading and trailing whitespace. Formatting a range extends it to the
+    /// enclosing nodes, so a single range that spans multiple statements would
+    /// cause their whole parent to be formatted.
+    pub(crate) fn text_ranges(&self, path: &Utf8Path, content: &str) -> Vec<TextRange> {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        self.get(path)
+            .iter()
+            .flat_map(|lines| lines.clone())
+            .filter_map(|line| {
+                let start = *line_starts.get(line)?;
+                let end = line_starts
+                    .get(line + 1)
+                    .map_or(content.len(), |next_line_start| next_line_start - 1);
+                let text = &content[start..end];
+                let trimmed = text.trim_start();
+                let start = start + text.len() - trimmed.len();
+                let end = start + trimmed.trim_end().len();
+                if start == end {
+                    return None;
+                }
+
+                Some(TextRange::new(
+                    TextSize::try_from(start).ok()?,
+                    TextSize::try_from(end).ok()?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Returns the zero-based line of `offset` in `content`.
+fn line_of_offset(content: &str, offset: TextSize) -> usize {
+    let offset = usize::from(offset).min(content.len());
+    content[..offset].matches('\n').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = r#"diff --git a/src/a.js b/src/a.js
+index 0000000..1111111 100644
+--- a/src/a.js
++++ b/src/a.js
+@@ -1,0 +2,2 @@ const a = 1;
++const b = 2;
++const c = 3;
+@@ -10 +12 @@ function f() {
+-  return 1;
++  return 2;
+@@ -20,2 +21,0 @@
+-const d = 4;
+-const e = 5;
+diff --git a/src/new.css b/src/new.css
+new file mode 100644
+index 0000000..2222222
+--- /dev/null
++++ b/src/new.css
+@@ -0,0 +1,3 @@
++a {
++  color: red;
++}
+"#;
+
+    #[test]
+    fn parses_added_lines_of_hunks() {
+        let changed_lines = ChangedLines::from_diff(DIFF, None);
+
+        assert_eq!(
+            changed_lines.get(Utf8Path::new("src/a.js")),
+            &[1..3, 11..12]
+        );
+        assert_eq!(
+            changed_lines.get(Utf8Path::new("src/new.css")),
+            &[Range { start: 0, end: 3 }]
+        );
+        assert!(changed_lines.get(Utf8Path::new("src/b.js")).is_empty());
+    }
+
+    #[test]
+    fn joins_paths_to_the_working_directory() {
+        let changed_lines = ChangedLines::from_diff(DIFF, Some(Utf8Path::new("/project")));
+
+        assert_eq!(
+            changed_lines.get(Utf8Path::new("/project/src/new.css")),
+            &[Range { start: 0, end: 3 }]
+        );
+    }
+
+    #[test]
+    fn checks_whether_ranges_overlap_changed_lines() {
+        let changed_lines = ChangedLines::from_diff(DIFF, None);
+        let path = Utf8Path::new("src/a.js");
+        let content = "line0\nline1\nline2\nline3\n";
+
+        // `line0`
+        assert!(!changed_lines.contains(path, content, TextRange::new(0.into(), 5.into())));
+        // From `line0` to the start of `line1`
+        assert!(changed_lines.contains(path, content, TextRange::new(0.into(), 7.into())));
+        // `line2`
+        assert!(changed_lines.contains(path, content, TextRange::new(12.into(), 17.into())));
+        // `line3`
+        assert!(!changed_lines.contains(path, content, TextRange::new(18.into(), 23.into())));
+    }
+
+    #[test]
+    fn converts_changed_lines_to_text_ranges() {
+        let changed_lines = ChangedLines::from_diff(DIFF, None);
+        let content = "line0\nline1\n  line2 \nline3\n";
+
+        assert_eq!(
+            changed_lines.text_ranges(Utf8Path::new("src/a.js"), content),
+            vec![
+                TextRange::new(6.into(), 11.into()),
+                TextRange::new(14.into(), 19.into())
+            ]
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use super::{FixFileModeOptions, LoadEditorConfig, determine_fix_file_mode};
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
+use crate::commands::{
+    CommandRunner, get_changed_lines_with_cli_options, get_files_to_process_with_cli_options,
+};
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
@@ -26,6 +29,8 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) cache: bool,
 }
 
 impl LoadEditorConfig for CheckCommandPayload {
@@ -140,4 +145,33 @@ impl CommandRunner for CheckCommandPayload {
         })
         .set_report(cli_options))
     }
+
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.changed_lines,
+            self.since.as_deref(),
+            self.changed,
+            self.staged,
+            fs,
+            configuration,
+        )
+    }
+
+    fn should_use_cache(&self) -> bool {
+        self.cache
+    }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.changed_lines && self.should_write() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "changed-lines",
+                "write",
+            ));
+        }
+        Ok(())
+    }
 }
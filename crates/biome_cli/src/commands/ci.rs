@@ -1,6 +1,6 @@
-use crate::changed::get_changed_files;
+use crate::changed::{ChangedLines, get_changed_files};
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, LoadEditorConfig};
+use crate::commands::{CommandRunner, LoadEditorConfig, get_changed_lines_with_cli_options};
 use crate::{CliDiagnostic, Execution};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
@@ -22,6 +22,8 @@ pub(crate) struct CiCommandPayload {
     pub(crate) configuration: Option<Configuration>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) cache: bool,
 }
 
 impl LoadEditorConfig for CiCommandPayload {
@@ -136,4 +138,23 @@ impl CommandRunner for CiCommandPayload {
         }
         Ok(())
     }
+
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.changed_lines,
+            self.since.as_deref(),
+            self.changed,
+            false,
+            fs,
+            configuration,
+        )
+    }
+
+    fn should_use_cache(&self) -> bool {
+        self.cache
+    }
 }
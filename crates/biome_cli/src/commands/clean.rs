@@ -1,15 +1,31 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::execute::cache::CACHE_DIRECTORY;
 use crate::{CliDiagnostic, CliSession};
+use biome_configuration::ConfigurationPathHint;
 use biome_flags::biome_env;
+use biome_service::configuration::load_configuration;
 use camino::Utf8PathBuf;
 use std::fs::{create_dir, remove_dir_all};
 
 /// Runs the clean command
-pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
+pub fn clean(cli_session: CliSession) -> Result<(), CliDiagnostic> {
     let logs_path = biome_env()
         .biome_log_path
         .value()
         .map_or(default_biome_log_path(), Utf8PathBuf::from);
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
+
+    // The cache is stored next to the configuration file, or inside the
+    // working directory when there isn't one
+    let fs = cli_session.app.workspace.fs();
+    let workspace_path = load_configuration(fs, ConfigurationPathHint::default())?
+        .directory_path
+        .or_else(|| fs.working_directory());
+    if let Some(workspace_path) = workspace_path {
+        let cache_path = workspace_path.join(CACHE_DIRECTORY);
+        if cache_path.exists() {
+            remove_dir_all(cache_path)?;
+        }
+    }
     Ok(())
 }
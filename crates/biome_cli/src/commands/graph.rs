@@ -0,0 +1,77 @@
+use super::CommandRunner;
+use crate::CliDiagnostic;
+use crate::cli_options::CliOptions;
+use crate::execute::{Execution, GraphFormat, TraversalMode};
+use biome_configuration::Configuration;
+use biome_console::Console;
+use biome_fs::FileSystem;
+use biome_glob::Glob;
+use biome_service::configuration::LoadedConfiguration;
+use biome_service::{Workspace, WorkspaceError};
+use camino::Utf8PathBuf;
+use std::ffi::OsString;
+
+pub(crate) struct GraphCommandPayload {
+    pub(crate) format: GraphFormat,
+    pub(crate) include: Vec<Glob>,
+    pub(crate) dependencies_of: Vec<Utf8PathBuf>,
+    pub(crate) dependents_of: Vec<Utf8PathBuf>,
+    pub(crate) depth: Option<usize>,
+}
+
+impl CommandRunner for GraphCommandPayload {
+    const COMMAND_NAME: &'static str = "graph";
+
+    fn merge_configuration(
+        &mut self,
+        loaded_configuration: LoadedConfiguration,
+        _fs: &dyn FileSystem,
+        _console: &mut dyn Console,
+    ) -> Result<Configuration, WorkspaceError> {
+        Ok(loaded_configuration.configuration)
+    }
+
+    fn get_files_to_process(
+        &self,
+        _fs: &dyn FileSystem,
+        _configuration: &Configuration,
+    ) -> Result<Vec<OsString>, CliDiagnostic> {
+        Ok(vec![])
+    }
+
+    fn get_stdin_file_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn should_write(&self) -> bool {
+        false
+    }
+
+    fn get_execution(
+        &self,
+        cli_options: &CliOptions,
+        _console: &mut dyn Console,
+        _workspace: &dyn Workspace,
+    ) -> Result<Execution, CliDiagnostic> {
+        Ok(Execution::new(TraversalMode::Graph {
+            format: self.format,
+            include: self.include.clone(),
+            dependencies_of: self.dependencies_of.clone(),
+            dependents_of: self.dependents_of.clone(),
+            depth: self.depth,
+        })
+        .set_report(cli_options))
+    }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.depth.is_some() && self.dependencies_of.is_empty() && self.dependents_of.is_empty()
+        {
+            return Err(CliDiagnostic::missing_argument(
+                "--dependencies-of or --dependents-of",
+                Self::COMMAND_NAME,
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,14 @@
 use super::{FixFileModeOptions, determine_fix_file_mode};
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
+use crate::commands::{
+    CommandRunner, get_changed_lines_with_cli_options, get_files_to_process_with_cli_options,
+};
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::css::CssLinterConfiguration;
 use biome_configuration::graphql::GraphqlLinterConfiguration;
+use biome_configuration::html::HtmlLinterConfiguration;
 use biome_configuration::javascript::JsLinterConfiguration;
 use biome_configuration::json::JsonLinterConfiguration;
 use biome_configuration::vcs::VcsConfiguration;
@@ -32,10 +36,13 @@ pub(crate) struct LintCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) cache: bool,
     pub(crate) javascript_linter: Option<JsLinterConfiguration>,
     pub(crate) json_linter: Option<JsonLinterConfiguration>,
     pub(crate) css_linter: Option<CssLinterConfiguration>,
     pub(crate) graphql_linter: Option<GraphqlLinterConfiguration>,
+    pub(crate) html_linter: Option<HtmlLinterConfiguration>,
 }
 
 impl CommandRunner for LintCommandPayload {
@@ -82,6 +89,10 @@ impl CommandRunner for LintCommandPayload {
                 .get_or_insert_with(Default::default);
             graphql.linter.merge_with(self.graphql_linter.clone());
         }
+        if self.html_linter.is_some() {
+            let html = fs_configuration.html.get_or_insert_with(Default::default);
+            html.linter.merge_with(self.html_linter.clone());
+        }
         if self.javascript_linter.is_some() {
             let javascript = fs_configuration
                 .javascript
@@ -146,4 +157,33 @@ impl CommandRunner for LintCommandPayload {
         })
         .set_report(cli_options))
     }
+
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.changed_lines,
+            self.since.as_deref(),
+            self.changed,
+            self.staged,
+            fs,
+            configuration,
+        )
+    }
+
+    fn should_use_cache(&self) -> bool {
+        self.cache
+    }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.changed_lines && self.should_write() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "changed-lines",
+                "write",
+            ));
+        }
+        Ok(())
+    }
 }
@@ -1,7 +1,8 @@
-use crate::changed::{get_changed_files, get_staged_files};
+use crate::changed::{ChangedLines, get_changed_files, get_changed_lines, get_staged_files};
 use crate::cli_options::{CliOptions, CliReporter, ColorsArg, cli_options};
 use crate::commands::scan_kind::get_forced_scan_kind;
-use crate::execute::Stdin;
+use crate::execute::cache::AnalysisCache;
+use crate::execute::{GraphFormat, Stdin};
 use crate::logging::LoggingKind;
 use crate::{
     CliDiagnostic, CliSession, Execution, LoggingLevel, TraversalMode, VERSION, execute_mode,
@@ -12,10 +13,17 @@ use biome_configuration::analyzer::{LinterEnabled, RuleSelector};
 use biome_configuration::css::{CssFormatterConfiguration, CssLinterConfiguration};
 use biome_configuration::formatter::FormatterEnabled;
 use biome_configuration::graphql::{GraphqlFormatterConfiguration, GraphqlLinterConfiguration};
-use biome_configuration::html::{HtmlFormatterConfiguration, html_formatter_configuration};
+use biome_configuration::html::{
+    HtmlFormatterConfiguration, HtmlLinterConfiguration, html_formatter_configuration,
+    html_linter_configuration,
+};
 use biome_configuration::javascript::{JsFormatterConfiguration, JsLinterConfiguration};
 use biome_configuration::json::{JsonFormatterConfiguration, JsonLinterConfiguration};
+use biome_configuration::markdown::{
+    MarkdownFormatterConfiguration, markdown_formatter_configuration,
+};
 use biome_configuration::vcs::VcsConfiguration;
+use biome_configuration::yaml::{YamlFormatterConfiguration, yaml_formatter_configuration};
 use biome_configuration::{BiomeDiagnostic, Configuration};
 use biome_configuration::{
     FilesConfiguration, FormatterConfiguration, LinterConfiguration, configuration,
@@ -29,13 +37,15 @@ use biome_console::{Console, ConsoleExt, markup};
 use biome_deserialize::Merge;
 use biome_diagnostics::{Diagnostic, PrintDiagnostic, Severity};
 use biome_fs::{BiomePath, FileSystem};
+use biome_glob::Glob;
 use biome_grit_patterns::GritTargetLanguage;
 use biome_resolver::FsWithResolverProxy;
 use biome_service::configuration::{LoadedConfiguration, load_configuration, load_editorconfig};
 use biome_service::documentation::Doc;
 use biome_service::projects::ProjectKey;
 use biome_service::workspace::{
-    FixFileMode, OpenProjectParams, ScanKind, ScanProjectFolderParams, UpdateSettingsParams,
+    FixFileMode, GetModuleGraphParams, OpenProjectParams, ScanKind, ScanProjectFolderParams,
+    UpdateSettingsParams,
 };
 use biome_service::{Workspace, WorkspaceError};
 use bpaf::Bpaf;
@@ -50,6 +60,7 @@ pub(crate) mod clean;
 pub(crate) mod daemon;
 pub(crate) mod explain;
 pub(crate) mod format;
+pub(crate) mod graph;
 pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
@@ -174,6 +185,16 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the diagnostics of the lines that have been changed are reported,
+        /// including the formatter diagnostics. It must be used with `--changed` or `--staged`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Reuse the results of previous runs for the files that didn't change since then.
+        /// Results are stored inside `node_modules/.cache/biome`, and they can be removed with `biome clean`.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -222,6 +243,9 @@ pub enum BiomeCommand {
         #[bpaf(external(graphql_linter_configuration), optional, hide_usage, hide)]
         graphql_linter: Option<GraphqlLinterConfiguration>,
 
+        #[bpaf(external(html_linter_configuration), optional, hide_usage, hide)]
+        html_linter: Option<HtmlLinterConfiguration>,
+
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
@@ -262,6 +286,16 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+
+        /// When set to true, only the diagnostics of the lines that have been changed are reported.
+        /// It must be used with `--changed` or `--staged`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Reuse the results of previous runs for the files that didn't change since then.
+        /// Results are stored inside `node_modules/.cache/biome`, and they can be removed with `biome clean`.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -287,6 +321,12 @@ pub enum BiomeCommand {
         #[bpaf(external(html_formatter_configuration), optional, hide_usage, hide)]
         html_formatter: Option<HtmlFormatterConfiguration>,
 
+        #[bpaf(external(yaml_formatter_configuration), optional, hide_usage, hide)]
+        yaml_formatter: Option<YamlFormatterConfiguration>,
+
+        #[bpaf(external(markdown_formatter_configuration), optional, hide_usage, hide)]
+        markdown_formatter: Option<MarkdownFormatterConfiguration>,
+
         #[bpaf(external(vcs_configuration), optional, hide_usage)]
         vcs_configuration: Option<VcsConfiguration>,
 
@@ -329,6 +369,16 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the lines that have been changed are formatted, and only their
+        /// diagnostics are reported. It must be used with `--changed` or `--staged`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Reuse the results of previous runs for the files that didn't change since then.
+        /// Results are stored inside `node_modules/.cache/biome`, and they can be removed with `biome clean`.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -368,6 +418,16 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the diagnostics of the lines that have been changed are reported,
+        /// including the formatter diagnostics. It must be used with `--changed`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Reuse the results of previous runs for the files that didn't change since then.
+        /// Results are stored inside `node_modules/.cache/biome`, and they can be removed with `biome clean`.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
         /// The number of threads to use. This is useful when running the CLI in environments
         /// with limited resource, for example CI.
         #[bpaf(
@@ -446,6 +506,9 @@ pub enum BiomeCommand {
     /// ```shell
     /// biome search '`console.log($message)`' # find all `console.log` invocations
     /// ```
+    ///
+    /// Patterns that contain rewrites, such as `$old => $new`, print the
+    /// changes they would apply. Use `--write` to apply them.
     #[bpaf(command)]
     Search {
         #[bpaf(external, hide_usage)]
@@ -481,9 +544,11 @@ pub enum BiomeCommand {
         #[bpaf(long("language"), short('l'))]
         language: Option<GritTargetLanguage>,
 
+        /// Writes the rewrites of the pattern to the matched files.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command (currently) does not support rewrites.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -492,6 +557,57 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
 
+    /// Prints the import graph of the modules and stylesheets of the project.
+    ///
+    /// By default, all the modules of the project are printed with their
+    /// resolved imports. Use `--dependencies-of` and `--dependents-of` to
+    /// only print the modules that a given module transitively depends on, or
+    /// that transitively depend on it.
+    ///
+    /// ### Examples
+    ///
+    /// ```shell
+    /// biome graph --format=dot > graph.dot
+    /// ```
+    ///
+    /// ```shell
+    /// biome graph --dependents-of=src/utils.ts --depth=1
+    /// ```
+    #[bpaf(command)]
+    Graph {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The format in which the graph is printed: `json` or `dot`.
+        #[bpaf(
+            long("format"),
+            argument("json|dot"),
+            fallback(GraphFormat::Json),
+            display_fallback
+        )]
+        format: GraphFormat,
+
+        /// Only prints the modules whose path, relative to the working
+        /// directory, matches the given glob. Can be passed multiple times.
+        #[bpaf(long("include"), argument("GLOB"), many)]
+        include: Vec<Glob>,
+
+        /// Prints the modules that the given module depends on, directly or
+        /// transitively. Can be passed multiple times.
+        #[bpaf(long("dependencies-of"), argument("PATH"), many)]
+        dependencies_of: Vec<Utf8PathBuf>,
+
+        /// Prints the modules that depend on the given module, directly or
+        /// transitively. Can be passed multiple times.
+        #[bpaf(long("dependents-of"), argument("PATH"), many)]
+        dependents_of: Vec<Utf8PathBuf>,
+
+        /// The maximum number of imports to follow from the modules passed to
+        /// `--dependencies-of` and `--dependents-of`.
+        #[bpaf(long("depth"), argument("NUMBER"), optional)]
+        depth: Option<usize>,
+    },
+
     /// Shows documentation of various aspects of the CLI.
     ///
     /// ### Examples
@@ -511,7 +627,7 @@ pub enum BiomeCommand {
     },
 
     #[bpaf(command)]
-    /// Cleans the logs emitted by the daemon.
+    /// Cleans the logs emitted by the daemon, and the cache of the current project.
     Clean,
 
     #[bpaf(command("__run_server"), hide)]
@@ -563,6 +679,16 @@ pub enum MigrateSubCommand {
         #[bpaf(long("include-nursery"))]
         include_nursery: bool,
     },
+    /// It attempts to find the Stylelint configuration file in the working directory, and update the Biome's configuration file as a result.
+    #[bpaf(command)]
+    Stylelint {
+        /// Includes rules inspired from a Stylelint rule in the migration
+        #[bpaf(long("include-inspired"))]
+        include_inspired: bool,
+        /// Includes nursery rules in the migration
+        #[bpaf(long("include-nursery"))]
+        include_nursery: bool,
+    },
 }
 
 impl MigrateSubCommand {
@@ -581,7 +707,8 @@ impl BiomeCommand {
             | Self::Ci { cli_options, .. }
             | Self::Format { cli_options, .. }
             | Self::Migrate { cli_options, .. }
-            | Self::Search { cli_options, .. } => Some(cli_options),
+            | Self::Search { cli_options, .. }
+            | Self::Graph { cli_options, .. } => Some(cli_options),
             Self::LspProxy { .. }
             | Self::Start { .. }
             | Self::Stop
@@ -732,6 +859,26 @@ fn get_files_to_process_with_cli_options(
     }
 }
 
+fn get_changed_lines_with_cli_options(
+    changed_lines: bool,
+    since: Option<&str>,
+    changed: bool,
+    staged: bool,
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+) -> Result<Option<ChangedLines>, CliDiagnostic> {
+    if !changed_lines {
+        return Ok(None);
+    }
+    if !changed && !staged {
+        return Err(CliDiagnostic::incompatible_end_configuration(
+            "The `--changed-lines` flag was set, but Biome doesn't know which changes to read. Use it with the --changed or the --staged argument.",
+        ));
+    }
+
+    Ok(Some(get_changed_lines(fs, configuration, since, staged)?))
+}
+
 /// Holds the options to determine the fix file mode.
 pub(crate) struct FixFileModeOptions {
     write: bool,
@@ -830,6 +977,7 @@ pub(crate) trait CommandRunner: Sized {
             duration,
             configuration_files,
             project_key,
+            cache,
         } = self.configure_workspace(fs, console, workspace, cli_options)?;
         execute_mode(
             execution,
@@ -839,6 +987,7 @@ pub(crate) trait CommandRunner: Sized {
             duration,
             configuration_files,
             project_key,
+            cache,
         )
     }
 
@@ -872,13 +1021,18 @@ pub(crate) trait CommandRunner: Sized {
         );
         let configuration_dir_path = loaded_configuration.directory_path.clone();
         let configuration = self.merge_configuration(loaded_configuration, fs, console)?;
+        // The configuration is needed to compute the key of the cache once the project is scanned
+        let cached_configuration = self.should_use_cache().then(|| configuration.clone());
         let paths = self.get_files_to_process(fs, &configuration)?;
         let project_path = fs
             .working_directory()
             .map(BiomePath::from)
             .unwrap_or_default();
 
-        let execution = self.get_execution(cli_options, console, workspace)?;
+        let changed_lines = self.get_changed_lines(fs, &configuration)?;
+        let execution = self
+            .get_execution(cli_options, console, workspace)?
+            .set_changed_lines(changed_lines);
 
         let params = if let TraversalMode::Lint { only, skip, .. } = execution.traversal_mode() {
             OpenProjectParams {
@@ -913,15 +1067,16 @@ pub(crate) trait CommandRunner: Sized {
             }
         });
 
+        // When the user provides the path to the configuration, we can't use its directory because
+        // it might be outside the project, so we need to use the current project directory.
+        let workspace_directory = if is_configuration_from_user {
+            Some(project_path.clone())
+        } else {
+            configuration_dir_path.clone().map(BiomePath::from)
+        };
         let result = workspace.update_settings(UpdateSettingsParams {
             project_key: open_project_result.project_key,
-            // When the user provides the path to the configuration, we can't use its directory because
-            // it might be outside the project, so we need to use the current project directory.
-            workspace_directory: if is_configuration_from_user {
-                Some(project_path.clone())
-            } else {
-                configuration_dir_path.map(BiomePath::from)
-            },
+            workspace_directory: workspace_directory.clone(),
             configuration,
         })?;
         if self.should_validate_configuration_diagnostics() {
@@ -948,12 +1103,31 @@ pub(crate) trait CommandRunner: Sized {
             )?;
         }
 
+        let cache = cached_configuration.and_then(|configuration| {
+            let modules = workspace
+                .get_module_graph(GetModuleGraphParams {
+                    project_key: open_project_result.project_key,
+                })
+                .ok()?
+                .modules;
+            AnalysisCache::load(
+                fs,
+                workspace_directory.as_ref().unwrap_or(&project_path),
+                &execution,
+                &configuration,
+                configuration_dir_path.as_deref(),
+                &result.configuration_files,
+                &modules,
+            )
+        });
+
         Ok(ConfiguredWorkspace {
             execution,
             paths,
             duration: Some(result.duration),
             configuration_files: result.configuration_files,
             project_key: open_project_result.project_key,
+            cache,
         })
     }
 
@@ -1024,6 +1198,21 @@ pub(crate) trait CommandRunner: Sized {
     fn should_validate_configuration_diagnostics(&self) -> bool {
         true
     }
+
+    /// It returns the lines that have been changed, when the command only
+    /// reports the diagnostics of the changed lines.
+    fn get_changed_lines(
+        &self,
+        _fs: &dyn FileSystem,
+        _configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        Ok(None)
+    }
+
+    /// Whether the results of the previous runs should be reused for the files that didn't change.
+    fn should_use_cache(&self) -> bool {
+        false
+    }
 }
 
 pub(crate) struct ConfiguredWorkspace {
@@ -1037,6 +1226,8 @@ pub(crate) struct ConfiguredWorkspace {
     pub configuration_files: Vec<BiomePath>,
     /// The unique identifier of the project
     pub project_key: ProjectKey,
+    /// The cache of the results of previous runs, when enabled
+    pub cache: Option<AnalysisCache>,
 }
 
 pub trait LoadEditorConfig: CommandRunner {
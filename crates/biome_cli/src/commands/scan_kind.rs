@@ -23,6 +23,7 @@ pub(crate) fn get_forced_scan_kind(
     // the compiler will error, and we will need to handle the new variant
     match execution.traversal_mode() {
         TraversalMode::Migrate { .. } => Some(ScanKind::KnownFiles),
+        TraversalMode::Graph { .. } => Some(ScanKind::Project),
         TraversalMode::Format { .. } | TraversalMode::Search { .. } => {
             if configuration.is_root() {
                 Some(ScanKind::KnownFiles)
@@ -19,6 +19,7 @@ pub(crate) struct SearchCommandPayload {
     pub(crate) language: Option<GritTargetLanguage>,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
+    pub(crate) write: bool,
 }
 
 impl CommandRunner for SearchCommandPayload {
@@ -54,7 +55,7 @@ impl CommandRunner for SearchCommandPayload {
     }
 
     fn should_write(&self) -> bool {
-        false
+        self.write
     }
 
     fn get_execution(
@@ -72,6 +73,7 @@ impl CommandRunner for SearchCommandPayload {
         Ok(Execution::new(TraversalMode::Search {
             pattern,
             language: self.language.clone(),
+            write: self.write,
             stdin: self.get_stdin(console)?,
         })
         .set_report(cli_options))
@@ -60,6 +60,8 @@ pub enum CliDiagnostic {
     Report(ReportDiagnostic),
     /// Emitted when there's an error emitted when using stdin mode
     Stdin(StdinDiagnostic),
+    /// Returned by the `biome graph` command when a queried module isn't part of the module graph
+    ModuleNotFound(ModuleNotFound),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -228,6 +230,20 @@ pub struct IncompatibleEndConfiguration {
     reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "graph",
+    severity = Error,
+    message(
+        description = "The module {path} isn't part of the module graph.",
+        message("The module "<Emphasis>{self.path}</Emphasis>" isn't part of the module graph.")
+    ),
+    advice = "Check that the file exists and that it isn't ignored by the configuration.",
+)]
+pub struct ModuleNotFound {
+    path: String,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     severity = Error,
@@ -342,6 +358,11 @@ impl CliDiagnostic {
         })
     }
 
+    /// Returned when a module queried by `biome graph` isn't part of the module graph
+    pub fn module_not_found(path: impl Into<String>) -> Self {
+        Self::ModuleNotFound(ModuleNotFound { path: path.into() })
+    }
+
     /// When no files were processed while traversing the file system
     pub fn no_files_processed(category: &'static Category, paths: impl Into<Vec<String>>) -> Self {
         Self::NoFilesWereProcessed(NoFilesWereProcessed {
@@ -485,6 +506,7 @@ impl Termination for CliDiagnostic {
 pub enum StdinDiagnostic {
     NotFormatted,
     NoExtension,
+    InvalidRewrite,
 }
 
 impl StdinDiagnostic {
@@ -495,6 +517,10 @@ impl StdinDiagnostic {
     pub(crate) fn new_no_extension() -> Self {
         Self::NoExtension
     }
+
+    pub(crate) fn new_invalid_rewrite() -> Self {
+        Self::InvalidRewrite
+    }
 }
 
 impl Diagnostic for StdinDiagnostic {
@@ -516,6 +542,9 @@ impl Diagnostic for StdinDiagnostic {
                     "The file passed via "<Emphasis>"--stdin-file-path"</Emphasis>" doesn't have an extension. Biome needs a file extension to know how handle the file."
                 })
             }
+            Self::InvalidRewrite => fmt.write_str(
+                "The rewrite of the pattern produces code with syntax errors, so it wasn't applied.",
+            ),
         }
     }
 }
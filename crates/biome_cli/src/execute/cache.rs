@@ -0,0 +1,515 @@
+//! On-disk cache of the results of the processing of files.
+//!
+//! When the cache is enabled via `--cache`, the messages emitted while
+//! processing a file are recorded, and stored inside [CACHE_DIRECTORY] at the
+//! end of the traversal. The next runs replay those messages instead of
+//! processing the file again, as long as:
+//! - the version of Biome, the command and its options, the root configuration
+//!   and the plugins didn't change;
+//! - the content of the file didn't change;
+//! - the nested configuration files that apply to the file didn't change;
+//! - the content of the modules the file depends on, directly or transitively,
+//!   didn't change, because rules such as `noImportCycles` or
+//!   `noUnresolvedImports` query the module graph;
+//! - the imports of the modules that depend on the file, directly or
+//!   transitively, didn't change, because rules such as `noUnusedExports` or
+//!   `noUnusedFiles` depend on the modules that import the file, rather than
+//!   on the modules it imports.
+//!
+//! Changing any other file of the project doesn't invalidate the entry.
+//!
+//! Files that contain unresolved imports are never cached, because adding the
+//! missing module wouldn't invalidate their entry.
+//!
+//! The content of the files isn't stored: since an entry is only replayed
+//! when the file didn't change, the content is read again from disk.
+
+use crate::VERSION;
+use crate::execute::process_file::{DiffKind, FileResult, FileStatus, Message};
+use crate::execute::traverse::TraversalOptions;
+use crate::execute::{Execution, TraversalMode};
+use biome_configuration::Configuration;
+use biome_diagnostics::Error;
+use biome_diagnostics::serde::Diagnostic;
+use biome_fs::{BiomePath, FileSystem, OpenOptions};
+use biome_service::workspace::{GetModuleDependenciesParams, ModuleGraphNode};
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, RwLock};
+use tracing::warn;
+use xxhash_rust::xxh3::{Xxh3, xxh3_128};
+
+/// The directory, relative to the root of the workspace, where the cache is stored.
+pub(crate) const CACHE_DIRECTORY: &str = "node_modules/.cache/biome";
+
+pub struct AnalysisCache {
+    /// The file where the cache is persisted
+    path: Utf8PathBuf,
+    /// Hash of everything that affects the result of the processing of all
+    /// the files
+    key: String,
+    /// The nested configuration files of the project
+    configuration_files: Vec<BiomePath>,
+    /// The modules of the project, by path
+    modules: FxHashMap<Utf8PathBuf, ModuleGraphNode>,
+    /// The modules that import each module, by path
+    importers: FxHashMap<Utf8PathBuf, Vec<Utf8PathBuf>>,
+    /// Entries loaded from the previous run. Entries are removed once they
+    /// are looked up.
+    previous_entries: Mutex<FxHashMap<String, CacheEntry>>,
+    /// Entries that are valid for the current run
+    entries: Mutex<BTreeMap<String, CacheEntry>>,
+    /// Hashes of the files read during the current run
+    hashes: RwLock<FxHashMap<Utf8PathBuf, Option<String>>>,
+}
+
+impl AnalysisCache {
+    /// Loads the cache of the given [Execution] from the `workspace_path`.
+    ///
+    /// Returns [None] if the execution doesn't support caching.
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        workspace_path: &Utf8Path,
+        execution: &Execution,
+        configuration: &Configuration,
+        configuration_path: Option<&Utf8Path>,
+        configuration_files: &[BiomePath],
+        modules: &[ModuleGraphNode],
+    ) -> Option<Self> {
+        let traversal_mode = execution.traversal_mode();
+        match traversal_mode {
+            TraversalMode::Check { stdin: None, .. }
+            | TraversalMode::Lint { stdin: None, .. }
+            | TraversalMode::Format { stdin: None, .. }
+            | TraversalMode::CI { .. } => {}
+            _ => return None,
+        }
+
+        let mut hasher = Xxh3::new();
+        hasher.update(VERSION.as_bytes());
+        hasher.update(format!("{traversal_mode:?}").as_bytes());
+        hasher.update(
+            serde_json::to_string(configuration)
+                .unwrap_or_default()
+                .as_bytes(),
+        );
+
+        let base_path = configuration_path.unwrap_or(workspace_path);
+        for plugin in configuration
+            .plugins
+            .iter()
+            .flat_map(|plugins| plugins.iter())
+        {
+            if let Ok(content) = fs.read_file_from_path(&base_path.join(plugin.path())) {
+                hasher.update(content.as_bytes());
+            }
+        }
+
+        let mut configuration_files = configuration_files.to_vec();
+        configuration_files.sort();
+
+        let mut importers: FxHashMap<Utf8PathBuf, Vec<Utf8PathBuf>> = FxHashMap::default();
+        for module in modules {
+            for import in &module.imports {
+                if let Some(resolved_path) = &import.resolved_path {
+                    importers
+                        .entry(resolved_path.to_path_buf())
+                        .or_default()
+                        .push(module.path.to_path_buf());
+                }
+            }
+        }
+        let modules = modules
+            .iter()
+            .map(|module| (module.path.to_path_buf(), module.clone()))
+            .collect();
+
+        let key = format!("{:032x}", hasher.digest128());
+        let path = workspace_path
+            .join(CACHE_DIRECTORY)
+            .join(format!("{traversal_mode}.json"));
+
+        let previous_entries = fs
+            .read_file_from_path(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| cache_file.key == key)
+            .map(|cache_file| cache_file.entries.into_iter().collect())
+            .unwrap_or_default();
+
+        Some(Self {
+            path,
+            key,
+            configuration_files,
+            modules,
+            importers,
+            previous_entries: Mutex::new(previous_entries),
+            entries: Mutex::default(),
+            hashes: RwLock::default(),
+        })
+    }
+
+    /// Looks up the entry of the file at `path`.
+    ///
+    /// Returns [None] if the file can't be read.
+    pub(crate) fn lookup(&self, fs: &dyn FileSystem, path: &BiomePath) -> Option<CacheLookup> {
+        let content = fs.read_file_from_path(path).ok()?;
+        let hash = hash_content(&content);
+        self.hashes
+            .write()
+            .unwrap()
+            .insert(path.to_path_buf(), Some(hash.clone()));
+        let settings = self.hash_settings(fs, path);
+        let importers = self.hash_importers(path);
+        let entry = self
+            .previous_entries
+            .lock()
+            .unwrap()
+            .remove(path.as_str())
+            .filter(|entry| {
+                entry.hash == hash
+                    && entry.settings == settings
+                    && entry.importers == importers
+                    && entry.dependencies.iter().all(|dependency| {
+                        self.hash_file(fs, &dependency.path).as_ref() == Some(&dependency.hash)
+                    })
+            });
+
+        let Some(entry) = entry else {
+            return Some(CacheLookup {
+                hash,
+                settings,
+                importers,
+                cached: None,
+            });
+        };
+
+        let cached = (
+            entry.status.clone().into_file_status(&content),
+            entry
+                .messages
+                .iter()
+                .cloned()
+                .map(|message| message.into_message(&content))
+                .collect(),
+        );
+        self.entries.lock().unwrap().insert(path.to_string(), entry);
+
+        Some(CacheLookup {
+            hash,
+            settings,
+            importers,
+            cached: Some(cached),
+        })
+    }
+
+    /// Stores the result of the processing of the file at `path`, and the
+    /// messages that were emitted along the way.
+    ///
+    /// Results that modified the file, or that failed, are not stored.
+    pub(crate) fn store(
+        &self,
+        ctx: &TraversalOptions,
+        path: &BiomePath,
+        lookup: CacheLookup,
+        result: &FileResult,
+        messages: Vec<CachedMessage>,
+    ) {
+        let status = match result {
+            Ok(FileStatus::Unchanged) => CachedStatus::Unchanged,
+            Ok(FileStatus::Ignored) => CachedStatus::Ignored,
+            Ok(FileStatus::Message(message)) => CachedStatus::Message(message.into()),
+            _ => return,
+        };
+
+        let Ok(result) = ctx
+            .workspace
+            .get_module_dependencies(GetModuleDependenciesParams {
+                project_key: ctx.project_key,
+                path: path.clone(),
+            })
+        else {
+            return;
+        };
+        if result.has_unresolved_imports {
+            return;
+        }
+
+        let dependencies = result
+            .dependencies
+            .into_iter()
+            .map(|dependency| {
+                let hash = self.hash_file(ctx.fs, &dependency)?;
+                Some(CachedDependency {
+                    path: dependency.into(),
+                    hash,
+                })
+            })
+            .collect::<Option<Vec<_>>>();
+        let Some(dependencies) = dependencies else {
+            return;
+        };
+
+        self.entries.lock().unwrap().insert(
+            path.to_string(),
+            CacheEntry {
+                hash: lookup.hash,
+                settings: lookup.settings,
+                importers: lookup.importers,
+                dependencies,
+                status,
+                messages,
+            },
+        );
+    }
+
+    /// Writes the cache to disk.
+    ///
+    /// Entries of the previous run that weren't looked up are preserved, as
+    /// long as their file still exists.
+    pub(crate) fn persist(self, fs: &dyn FileSystem) {
+        let mut entries = self.entries.into_inner().unwrap();
+        for (path, entry) in self.previous_entries.into_inner().unwrap() {
+            if fs.path_exists(Utf8Path::new(&path)) {
+                entries.entry(path).or_insert(entry);
+            }
+        }
+
+        let cache_file = CacheFile {
+            key: self.key,
+            entries,
+        };
+        let result = serde_json::to_string(&cache_file)
+            .map_err(std::io::Error::from)
+            .and_then(|content| {
+                if let Some(directory) = self.path.parent() {
+                    fs.create_dir_all(directory)?;
+                }
+                let mut file = fs.open_with_options(
+                    &self.path,
+                    OpenOptions::default().write(true).create(true),
+                )?;
+                file.set_content(content.as_bytes())
+            });
+
+        if let Err(error) = result {
+            warn!("Failed to write the cache to {}: {error}", self.path);
+        }
+    }
+
+    /// Returns the hash of the nested configuration files that apply to the
+    /// file at `path`.
+    fn hash_settings(&self, fs: &dyn FileSystem, path: &Utf8Path) -> String {
+        let mut hasher = Xxh3::new();
+        for configuration_file in &self.configuration_files {
+            let applies = configuration_file
+                .parent()
+                .is_some_and(|directory| path.starts_with(directory));
+            if applies {
+                hasher.update(configuration_file.as_str().as_bytes());
+                if let Some(hash) = self.hash_file(fs, configuration_file) {
+                    hasher.update(hash.as_bytes());
+                }
+            }
+        }
+
+        format!("{:032x}", hasher.digest128())
+    }
+
+    /// Returns the hash of the imports of the modules that depend on the file
+    /// at `path`, either directly or transitively.
+    fn hash_importers(&self, path: &Utf8Path) -> String {
+        let mut visited = BTreeSet::new();
+        let mut queue = vec![path];
+        while let Some(path) = queue.pop() {
+            for importer in self.importers.get(path).into_iter().flatten() {
+                if visited.insert(importer.as_path()) {
+                    queue.push(importer);
+                }
+            }
+        }
+
+        let mut hasher = Xxh3::new();
+        for importer in visited {
+            if let Some(module) = self.modules.get(importer) {
+                hasher.update(serde_json::to_string(module).unwrap_or_default().as_bytes());
+            }
+        }
+
+        format!("{:032x}", hasher.digest128())
+    }
+
+    /// Returns the hash of the content of the file at `path`, reading it only
+    /// once per run.
+    fn hash_file(&self, fs: &dyn FileSystem, path: &Utf8Path) -> Option<String> {
+        if let Some(hash) = self.hashes.read().unwrap().get(path) {
+            return hash.clone();
+        }
+
+        let hash = fs
+            .read_file_from_path(path)
+            .ok()
+            .map(|content| hash_content(&content));
+        self.hashes
+            .write()
+            .unwrap()
+            .insert(path.to_path_buf(), hash.clone());
+        hash
+    }
+}
+
+fn hash_content(content: &str) -> String {
+    format!("{:032x}", xxh3_128(content.as_bytes()))
+}
+
+/// The result of [AnalysisCache::lookup].
+pub(crate) struct CacheLookup {
+    /// The hash of the content of the file
+    hash: String,
+    /// The hash of the nested configuration files that apply to the file
+    settings: String,
+    /// The hash of the imports of the modules that depend on the file
+    importers: String,
+    /// The status and the messages stored in the cache, if they're still valid
+    pub(crate) cached: Option<(FileStatus, Vec<Message>)>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CacheFile {
+    key: String,
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    /// The hash of the content of the file
+    hash: String,
+    /// The hash of the nested configuration files that apply to the file
+    settings: String,
+    /// The hash of the imports of the modules that depend on the file
+    importers: String,
+    /// The modules the file depends on, with the hash of their content
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    dependencies: Vec<CachedDependency>,
+    status: CachedStatus,
+    /// The messages emitted while processing the file
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    messages: Vec<CachedMessage>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CachedDependency {
+    path: Utf8PathBuf,
+    hash: String,
+}
+
+/// Serializable counterpart of [FileStatus]
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum CachedStatus {
+    Unchanged,
+    Ignored,
+    Message(CachedMessage),
+}
+
+impl CachedStatus {
+    /// Converts the status back to a [FileStatus], where `content` is the
+    /// content of the file the status belongs to.
+    fn into_file_status(self, content: &str) -> FileStatus {
+        match self {
+            Self::Unchanged => FileStatus::Unchanged,
+            Self::Ignored => FileStatus::Ignored,
+            Self::Message(message) => FileStatus::Message(message.into_message(content)),
+        }
+    }
+}
+
+/// Serializable counterpart of [Message]
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CachedMessage {
+    SkippedFixes {
+        skipped_suggested_fixes: u32,
+    },
+    Failure,
+    Error(Diagnostic),
+    Diagnostics {
+        file_path: String,
+        diagnostics: Vec<Diagnostic>,
+        skipped_diagnostics: u32,
+    },
+    Diff {
+        file_name: String,
+        new: String,
+    },
+}
+
+impl From<&Message> for CachedMessage {
+    fn from(message: &Message) -> Self {
+        match message {
+            Message::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Self::SkippedFixes {
+                skipped_suggested_fixes: *skipped_suggested_fixes,
+            },
+            Message::Failure => Self::Failure,
+            Message::Error(error) => Self::Error(Diagnostic::new(error)),
+            Message::Diagnostics {
+                file_path,
+                content: _,
+                diagnostics,
+                skipped_diagnostics,
+            } => Self::Diagnostics {
+                file_path: file_path.clone(),
+                diagnostics: diagnostics.iter().map(Diagnostic::new).collect(),
+                skipped_diagnostics: *skipped_diagnostics,
+            },
+            // Rewrite diffs are emitted by `biome search`, which doesn't use
+            // the cache
+            Message::Diff {
+                file_name,
+                old: _,
+                new,
+                diff_kind: DiffKind::Format | DiffKind::Rewrite,
+            } => Self::Diff {
+                file_name: file_name.clone(),
+                new: new.clone(),
+            },
+        }
+    }
+}
+
+impl CachedMessage {
+    /// Converts the message back to a [Message], where `content` is the
+    /// content of the file the message belongs to.
+    fn into_message(self, content: &str) -> Message {
+        match self {
+            Self::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Message::SkippedFixes {
+                skipped_suggested_fixes,
+            },
+            Self::Failure => Message::Failure,
+            Self::Error(diagnostic) => Message::Error(Error::from(diagnostic)),
+            Self::Diagnostics {
+                file_path,
+                diagnostics,
+                skipped_diagnostics,
+            } => Message::Diagnostics {
+                file_path,
+                content: content.to_string(),
+                diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                skipped_diagnostics,
+            },
+            Self::Diff { file_name, new } => Message::Diff {
+                file_name,
+                old: content.to_string(),
+                new,
+                diff_kind: DiffKind::Format,
+            },
+        }
+    }
+}
@@ -82,6 +82,25 @@ pub(crate) struct SkippedDiagnostic;
 #[diagnostic(category = "search", severity = Information)]
 pub(crate) struct SearchDiagnostic;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Error,
+    message = "The rewrite of the pattern produces code with syntax errors, so it wasn't applied."
+)]
+pub(crate) struct InvalidRewriteDiagnostic;
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern would rewrite the file with the following changes:"
+)]
+pub(crate) struct SearchDiffDiagnostic {
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 /// Extension trait for turning [Display]-able error types into [TraversalError]
 pub(crate) trait ResultExt {
     type Result;
@@ -0,0 +1,264 @@
+use crate::execute::GraphFormat;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{ConsoleExt, markup};
+use biome_fs::normalize_path;
+use biome_glob::{CandidatePath, Glob};
+use biome_service::projects::ProjectKey;
+use biome_service::workspace::{
+    GetModuleGraphParams, ModuleGraphImport, ModuleGraphImportKind, ModuleGraphNode,
+};
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+pub(crate) struct GraphPayload<'a> {
+    pub(crate) session: CliSession<'a>,
+    pub(crate) project_key: ProjectKey,
+    pub(crate) format: GraphFormat,
+    pub(crate) include: Vec<Glob>,
+    pub(crate) dependencies_of: Vec<Utf8PathBuf>,
+    pub(crate) dependents_of: Vec<Utf8PathBuf>,
+    pub(crate) depth: Option<usize>,
+}
+
+pub(crate) fn run(payload: GraphPayload) -> Result<(), CliDiagnostic> {
+    let GraphPayload {
+        session,
+        project_key,
+        format,
+        include,
+        dependencies_of,
+        dependents_of,
+        depth,
+    } = payload;
+    let workspace = &*session.app.workspace;
+    let console = session.app.console;
+    let working_directory = workspace.fs().working_directory().unwrap_or_default();
+
+    let modules = workspace
+        .get_module_graph(GetModuleGraphParams { project_key })?
+        .modules;
+    let graph = Graph::new(&modules);
+
+    let mut roots = FxHashSet::default();
+    let mut selected = FxHashSet::default();
+    for (paths, direction) in [
+        (&dependencies_of, Direction::Dependencies),
+        (&dependents_of, Direction::Dependents),
+    ] {
+        for path in paths {
+            let path = normalize_path(&working_directory.join(path));
+            let Some(&root) = graph.indices.get(path.as_path()) else {
+                return Err(CliDiagnostic::module_not_found(path.as_str()));
+            };
+            roots.insert(root);
+            selected.extend(graph.walk(root, direction, depth));
+        }
+    }
+
+    // Without queries, the whole graph is printed.
+    let is_query = !roots.is_empty();
+    let printed_modules: Vec<PrintedModule> = modules
+        .iter()
+        .enumerate()
+        .filter(|(index, module)| {
+            if roots.contains(index) {
+                return true;
+            }
+
+            let path = relative_path(&module.path, &working_directory);
+            (!is_query || selected.contains(index))
+                && (include.is_empty()
+                    || CandidatePath::new(path).matches_with_exceptions(&include))
+        })
+        .map(|(_, module)| PrintedModule {
+            path: relative_path(&module.path, &working_directory),
+            imports: &module.imports,
+        })
+        .collect();
+
+    let output = match format {
+        GraphFormat::Json => print_json(&printed_modules, &working_directory),
+        GraphFormat::Dot => print_dot(&printed_modules, &working_directory),
+    };
+    console.log(markup! {{output}});
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    /// Follows the imports of a module
+    Dependencies,
+    /// Follows the modules that import a module
+    Dependents,
+}
+
+/// Adjacency lists of the module graph, indexed in the same order as the
+/// modules returned by the workspace.
+struct Graph<'a> {
+    indices: FxHashMap<&'a Utf8Path, usize>,
+    dependencies: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+}
+
+impl<'a> Graph<'a> {
+    fn new(modules: &'a [ModuleGraphNode]) -> Self {
+        let indices: FxHashMap<&Utf8Path, usize> = modules
+            .iter()
+            .enumerate()
+            .map(|(index, module)| (module.path.as_path(), index))
+            .collect();
+
+        let mut dependencies = vec![Vec::new(); modules.len()];
+        let mut dependents = vec![Vec::new(); modules.len()];
+        for (index, module) in modules.iter().enumerate() {
+            for import in &module.imports {
+                let Some(&imported) = import
+                    .resolved_path
+                    .as_ref()
+                    .and_then(|path| indices.get(path.as_path()))
+                else {
+                    continue;
+                };
+                if !dependencies[index].contains(&imported) {
+                    dependencies[index].push(imported);
+                    dependents[imported].push(index);
+                }
+            }
+        }
+
+        Self {
+            indices,
+            dependencies,
+            dependents,
+        }
+    }
+
+    /// Returns the modules reachable from `root` in the given `direction`,
+    /// following at most `depth` edges.
+    fn walk(&self, root: usize, direction: Direction, depth: Option<usize>) -> FxHashSet<usize> {
+        let edges = match direction {
+            Direction::Dependencies => &self.dependencies,
+            Direction::Dependents => &self.dependents,
+        };
+
+        let mut visited = FxHashSet::default();
+        let mut queue = VecDeque::from([(root, 0)]);
+        while let Some((index, distance)) = queue.pop_front() {
+            if depth.is_some_and(|depth| distance >= depth) {
+                continue;
+            }
+
+            for &next in &edges[index] {
+                if next != root && visited.insert(next) {
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        visited
+    }
+}
+
+struct PrintedModule<'a> {
+    path: &'a Utf8Path,
+    imports: &'a [ModuleGraphImport],
+}
+
+fn relative_path<'a>(path: &'a Utf8Path, working_directory: &Utf8Path) -> &'a Utf8Path {
+    path.strip_prefix(working_directory).unwrap_or(path)
+}
+
+#[derive(Serialize)]
+struct JsonGraph<'a> {
+    modules: Vec<JsonModule<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonModule<'a> {
+    path: &'a Utf8Path,
+    imports: Vec<JsonImport<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonImport<'a> {
+    specifier: &'a str,
+    path: Option<&'a Utf8Path>,
+    kind: ModuleGraphImportKind,
+}
+
+fn print_json(modules: &[PrintedModule], working_directory: &Utf8Path) -> String {
+    let graph = JsonGraph {
+        modules: modules
+            .iter()
+            .map(|module| JsonModule {
+                path: module.path,
+                imports: module
+                    .imports
+                    .iter()
+                    .map(|import| JsonImport {
+                        specifier: &import.specifier,
+                        path: import
+                            .resolved_path
+                            .as_ref()
+                            .map(|path| relative_path(path, working_directory)),
+                        kind: import.kind,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&graph).expect("the module graph to be serializable")
+}
+
+/// Prints the graph in the DOT language. Only the imports between printed
+/// modules are rendered as edges.
+fn print_dot(modules: &[PrintedModule], working_directory: &Utf8Path) -> String {
+    let printed: FxHashSet<&Utf8Path> = modules.iter().map(|module| module.path).collect();
+
+    let mut output = String::from("digraph {\n");
+    for module in modules {
+        writeln!(output, "  {};", dot_id(module.path.as_str())).unwrap();
+    }
+
+    for module in modules {
+        let mut imported_paths = FxHashSet::default();
+        for import in module.imports {
+            let Some(path) = import
+                .resolved_path
+                .as_ref()
+                .map(|path| relative_path(path, working_directory))
+            else {
+                continue;
+            };
+            if !printed.contains(path) || !imported_paths.insert(path) {
+                continue;
+            }
+
+            let attributes = match import.kind {
+                ModuleGraphImportKind::Static => "",
+                ModuleGraphImportKind::Dynamic => " [style=dashed]",
+                ModuleGraphImportKind::Url => " [style=dotted]",
+            };
+            writeln!(
+                output,
+                "  {} -> {}{attributes};",
+                dot_id(module.path.as_str()),
+                dot_id(path.as_str())
+            )
+            .unwrap();
+        }
+    }
+    output.push('}');
+
+    output
+}
+
+/// Quotes `value` so it can be used as an identifier in the DOT language.
+fn dot_id(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,488 @@
+use crate::CliDiagnostic;
+use crate::diagnostics::MigrationDiagnostic;
+use biome_console::{Console, ConsoleExt, markup};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableTypes, DeserializableValue,
+    DeserializationContext, DeserializationVisitor, Merge, Text,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use camino::Utf8Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::eslint_eslint::ShorthandVec;
+use super::{node, yaml};
+
+/// This modules includes implementations for loading and deserializing a Stylelint configuration.
+///
+/// The defined types follow the Stylelint configuration schema described at
+/// <https://stylelint.io/user-guide/configure>.
+/// Only the severity of the rules is deserialized, because the options of the Stylelint rules
+/// don't have Biome equivalents.
+///
+/// See [super::stylelint_to_biome] for converting a Stylelint config to a Biome config.
+///
+/// Stylelint configuration filenames.
+///
+/// See https://stylelint.io/user-guide/configure
+///
+/// Order is important.
+/// It translates the priority of the files.
+const CONFIG_FILES: [&str; 10] = [
+    ".stylelintrc",
+    ".stylelintrc.json",
+    ".stylelintrc.yaml",
+    ".stylelintrc.yml",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./.stylelintrc.js",
+    "./.stylelintrc.cjs",
+    "./.stylelintrc.mjs",
+    "./stylelint.config.js",
+    "./stylelint.config.cjs",
+    "./stylelint.config.mjs",
+];
+
+/// A Stylelint config can be embedded in `package.json`
+const PACKAGE_JSON: &str = "package.json";
+
+/// Stylelint Ignore file. Use the same syntax as gitignore.
+pub(crate) const IGNORE_FILE: &str = ".stylelintignore";
+
+#[derive(Debug)]
+pub(crate) struct Config {
+    /// Path of the Stylelint config file
+    pub(crate) path: &'static str,
+    /// Resolved Stylelint config
+    pub(crate) data: StylelintConfiguration,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintPackageJson {
+    pub(crate) stylelint: Option<StylelintConfiguration>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintConfiguration {
+    pub(crate) extends: ShorthandVec<String>,
+    /// The severity of the rules that don't specify a severity.
+    pub(crate) default_severity: Option<SeverityOption>,
+    /// The glob patterns of the files to ignore.
+    pub(crate) ignore_files: ShorthandVec<Box<str>>,
+    pub(crate) rules: Rules,
+    pub(crate) overrides: Vec<OverrideConfiguration>,
+}
+impl Merge for StylelintConfiguration {
+    fn merge_with(&mut self, mut other: Self) {
+        self.extends.merge_with(other.extends);
+        if self.default_severity.is_none() {
+            self.default_severity = other.default_severity;
+        }
+        self.ignore_files.merge_with(other.ignore_files);
+        self.rules.merge_with(other.rules);
+        self.overrides.append(&mut other.overrides);
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct OverrideConfiguration {
+    /// The glob patterns for target files.
+    pub(crate) files: ShorthandVec<Box<str>>,
+    pub(crate) rules: Rules,
+}
+
+/// The severity of a rule once its configuration is resolved.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+impl From<Severity> for biome_configuration::RulePlainConfiguration {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Off => Self::Off,
+            Severity::Warning => Self::Warn,
+            Severity::Error => Self::Error,
+        }
+    }
+}
+
+/// The value of the `defaultSeverity` field and of the `severity` secondary option.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, PartialEq)]
+pub(crate) enum SeverityOption {
+    Warning,
+    #[default]
+    Error,
+}
+impl From<SeverityOption> for Severity {
+    fn from(value: SeverityOption) -> Self {
+        match value {
+            SeverityOption::Warning => Self::Warning,
+            SeverityOption::Error => Self::Error,
+        }
+    }
+}
+
+/// The rules indexed by name.
+///
+/// When two configurations are merged, the rules of the first configuration take precedence.
+#[derive(Debug, Default, Deserializable)]
+pub(crate) struct Rules(pub(crate) BTreeMap<Box<str>, RuleConf>);
+impl Merge for Rules {
+    fn merge_with(&mut self, other: Self) {
+        for (rule_name, conf) in other.0 {
+            self.0.entry(rule_name).or_insert(conf);
+        }
+    }
+}
+
+/// Model the possible shapes of a Stylelint's rule configuration
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum RuleConf {
+    // `{ rule: null }` and `{ rule: [null, <secondary options>] }`
+    Disabled,
+    // `{ rule: <primary option> }` and `{ rule: [<primary option>, <secondary options>] }`
+    Enabled(Option<SeverityOption>),
+}
+impl RuleConf {
+    pub(crate) fn severity(&self, default_severity: Option<SeverityOption>) -> Severity {
+        match self {
+            Self::Disabled => Severity::Off,
+            Self::Enabled(severity) => severity.or(default_severity).unwrap_or_default().into(),
+        }
+    }
+}
+impl Deserializable for RuleConf {
+    fn deserialize(
+        ctx: &mut impl DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor;
+        impl DeserializationVisitor for Visitor {
+            type Output = RuleConf;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::all();
+            fn visit_null(
+                self,
+                _ctx: &mut impl DeserializationContext,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                Some(RuleConf::Disabled)
+            }
+            fn visit_bool(
+                self,
+                _ctx: &mut impl DeserializationContext,
+                value: bool,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                Some(if value {
+                    RuleConf::Enabled(None)
+                } else {
+                    RuleConf::Disabled
+                })
+            }
+            fn visit_number(
+                self,
+                _ctx: &mut impl DeserializationContext,
+                _value: biome_deserialize::TextNumber,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                Some(RuleConf::Enabled(None))
+            }
+            fn visit_str(
+                self,
+                _ctx: &mut impl DeserializationContext,
+                _value: Text,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                Some(RuleConf::Enabled(None))
+            }
+            fn visit_array(
+                self,
+                ctx: &mut impl DeserializationContext,
+                values: impl Iterator<Item = Option<impl DeserializableValue>>,
+                _range: TextRange,
+                name: &str,
+            ) -> Option<Self::Output> {
+                let values: Vec<_> = values.flatten().collect();
+                match values.as_slice() {
+                    [primary, ..] if primary.visitable_type()? == DeserializableType::Null => {
+                        Some(RuleConf::Disabled)
+                    }
+                    // The secondary options are only accepted after a primary option.
+                    [_, secondary] if secondary.visitable_type()? == DeserializableType::Map => {
+                        let options = SecondaryOptions::deserialize(ctx, secondary, name)?;
+                        Some(RuleConf::Enabled(options.severity))
+                    }
+                    _ => Some(RuleConf::Enabled(None)),
+                }
+            }
+            fn visit_map(
+                self,
+                _ctx: &mut impl DeserializationContext,
+                _members: impl Iterator<
+                    Item = Option<(impl DeserializableValue, impl DeserializableValue)>,
+                >,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                Some(RuleConf::Enabled(None))
+            }
+        }
+        value.deserialize(ctx, Visitor, name)
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct SecondaryOptions {
+    severity: Option<SeverityOption>,
+}
+
+/// Returns the Stylelint configuration file in the working directory with the highest priority.
+///
+/// Unlike Stylelint, it doesn't look for a configuration file in parent directories
+/// when no configuration file is found in the working directory.
+///
+/// Deserialization errors are reported using `console`.
+/// Other errors (File Not found, unsupported config format, ...) are directly returned.
+///
+/// We extract the Stylelint configuration from a JavaScript file, by invoking `node`.
+///
+/// The `extends` field is recursively resolved.
+pub(crate) fn read_stylelint_config(
+    fs: &dyn FileSystem,
+    console: &mut dyn Console,
+) -> Result<Config, CliDiagnostic> {
+    // We don't report an error if Stylelint config is not embedded in `PACKAGE_JSON`.
+    if let Ok(data) = load_config(fs, Utf8Path::new(PACKAGE_JSON), console) {
+        return Ok(Config {
+            path: PACKAGE_JSON,
+            data,
+        });
+    }
+    for config_path_str in CONFIG_FILES {
+        let path = Utf8Path::new(config_path_str);
+        if fs.path_exists(path) {
+            return load_config(fs, path, console).map(|data| Config {
+                path: config_path_str,
+                data,
+            });
+        }
+    }
+    Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: "Biome couldn't find a Stylelint configuration file.".to_string(),
+    }))
+}
+
+fn load_config(
+    fs: &dyn FileSystem,
+    path: &Utf8Path,
+    console: &mut dyn Console,
+) -> Result<StylelintConfiguration, CliDiagnostic> {
+    let (deserialized, diagnostics) = match path.extension() {
+        None | Some("json" | "yaml" | "yml") => {
+            let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            if path.file_name().is_some_and(|name| name == PACKAGE_JSON) {
+                let (deserialized, diagnostics) =
+                    deserialize_from_json_str::<StylelintPackageJson>(
+                        &content,
+                        JsonParserOptions::default()
+                            .with_allow_trailing_commas()
+                            .with_allow_comments(),
+                        "",
+                    )
+                    .consume();
+                (
+                    deserialized.and_then(|packagejson| packagejson.stylelint),
+                    diagnostics,
+                )
+            } else {
+                // `.stylelintrc` is either a JSON or a YAML file.
+                let is_yaml = match path.extension() {
+                    Some(extension) => extension != "json",
+                    None => !content.trim_start().starts_with('{'),
+                };
+                if is_yaml {
+                    let Some(json) = yaml::yaml_to_json(&content) else {
+                        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                            reason: format!(
+                                "The Stylelint configuration file `{path}` isn't a valid YAML file."
+                            ),
+                        }));
+                    };
+                    content = json.to_string();
+                }
+                deserialize_from_json_str::<StylelintConfiguration>(
+                    &content,
+                    JsonParserOptions::default()
+                        .with_allow_trailing_commas()
+                        .with_allow_comments(),
+                    "",
+                )
+                .consume()
+            }
+        }
+        Some("js" | "cjs" | "mjs") => {
+            let node::Resolution { content, .. } = node::load_config(path.as_ref())?;
+            deserialize_from_json_str::<StylelintConfiguration>(
+                &content,
+                JsonParserOptions::default(),
+                "",
+            )
+            .consume()
+        }
+        Some(ext) => {
+            return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                reason: format!(
+                    "Stylelint configuration ending with the extension `{ext}` are not supported."
+                ),
+            }));
+        }
+    };
+    let path_str = path.to_string();
+    for diagnostic in diagnostics.into_iter().filter(|diag| {
+        matches!(
+            diag.severity(),
+            biome_diagnostics::Severity::Fatal
+                | biome_diagnostics::Severity::Error
+                | biome_diagnostics::Severity::Warning
+        )
+    }) {
+        let diagnostic = diagnostic.with_file_path(path_str.to_string());
+        console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+    }
+    if let Some(mut result) = deserialized {
+        // recursively resolve the `extends` field.
+        while !result.extends.is_empty() {
+            resolve_extends(&mut result, console);
+        }
+        Ok(result)
+    } else {
+        Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the Stylelint configuration file".to_string(),
+        }))
+    }
+}
+
+/// Returns the shared configuration `name` or an error if the resolution failed.
+///
+/// `name` is either a package such as `stylelint-config-standard`,
+/// or a path relative to the configuration file.
+fn load_stylelint_extends_config(name: &str) -> Result<StylelintConfiguration, CliDiagnostic> {
+    let node::Resolution {
+        content,
+        resolved_path,
+    } = node::load_config(name)?;
+    let Some(mut deserialized) = deserialize_from_json_str::<StylelintConfiguration>(
+        &content,
+        JsonParserOptions::default(),
+        "",
+    )
+    .into_deserialized() else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: format!(
+                "The Stylelint configuration of the module '{name}' cannot be extracted. This is likely an internal error."
+            ),
+        }));
+    };
+    // Resolve relative path in `extends`.
+    deserialized.extends.iter_mut().for_each(|extends_item| {
+        if extends_item.starts_with('.') {
+            let Some(resolved_path) = Path::new(&resolved_path).parent() else {
+                return;
+            };
+            let mut path = PathBuf::new();
+            path.push(resolved_path);
+            path.push(Path::new(&extends_item));
+            *extends_item = path.to_string_lossy().to_string();
+        }
+    });
+    Ok(deserialized)
+}
+
+/// Load and merge included configuration via `config.extends`.
+///
+/// A configuration takes precedence over the configurations it extends,
+/// and an extended configuration takes precedence over the configurations that precede it.
+/// `config.extends` is replaced by an empty array.
+fn resolve_extends(config: &mut StylelintConfiguration, console: &mut dyn Console) {
+    let extensions: Vec<_> = config
+        .extends
+        .iter()
+        .filter_map(|name| match load_stylelint_extends_config(name) {
+            Ok(config) => Some(config),
+            Err(diag) => {
+                console.error(markup! {{PrintDiagnostic::simple(&diag)}});
+                None
+            }
+        })
+        .collect();
+    config.extends.clear();
+    for ext in extensions.into_iter().rev() {
+        config.merge_with(ext);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deserialize(content: &str) -> StylelintConfiguration {
+        deserialize_from_json_str::<StylelintConfiguration>(
+            content,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap()
+    }
+
+    #[test]
+    fn deserialize_rule_conf() {
+        let config = deserialize(
+            r#"{
+                "defaultSeverity": "warning",
+                "rules": {
+                    "a": null,
+                    "b": true,
+                    "c": "always",
+                    "d": [null, { "severity": "error" }],
+                    "e": ["always", { "severity": "error" }],
+                    "f": ["px", "em"],
+                    "g": [["px"], { "message": "custom" }]
+                }
+            }"#,
+        );
+        let rules = &config.rules.0;
+        assert_eq!(rules["a"], RuleConf::Disabled);
+        assert_eq!(rules["b"], RuleConf::Enabled(None));
+        assert_eq!(rules["c"], RuleConf::Enabled(None));
+        assert_eq!(rules["d"], RuleConf::Disabled);
+        assert_eq!(rules["e"], RuleConf::Enabled(Some(SeverityOption::Error)));
+        assert_eq!(rules["f"], RuleConf::Enabled(None));
+        assert_eq!(rules["g"], RuleConf::Enabled(None));
+        assert_eq!(
+            rules["b"].severity(config.default_severity),
+            Severity::Warning
+        );
+        assert_eq!(
+            rules["e"].severity(config.default_severity),
+            Severity::Error
+        );
+    }
+}
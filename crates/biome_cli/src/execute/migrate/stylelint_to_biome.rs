@@ -0,0 +1,391 @@
+use std::collections::BTreeSet;
+
+use super::{stylelint, stylelint_any_rule_to_biome::migrate_stylelint_any_rule};
+use biome_configuration::{self as biome_config};
+use biome_console::markup;
+use biome_diagnostics::Location;
+
+/// This modules includes implementations for converting a Stylelint config to a Biome config.
+///
+/// The conversion relies on the generated
+/// [super::stylelint_any_rule_to_biome::migrate_stylelint_any_rule] module that relies on
+/// Biome's rule metadata to determine the equivalent Biome's rule of a Stylelint rule.
+///
+/// Unlike the ESLint migration, the recommended rules are kept enabled,
+/// because they include the rules of the other languages.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MigrationOptions {
+    /// Migrate inspired rules from Stylelint?
+    #[expect(
+        dead_code,
+        reason = "no CSS rule is inspired from a Stylelint rule yet"
+    )]
+    pub(crate) include_inspired: bool,
+    /// Migrate nursery rules from Stylelint?
+    pub(crate) include_nursery: bool,
+}
+
+/// Sorted Stylelint stylistic rules.
+/// They were deprecated in Stylelint v15 and removed in Stylelint v16.
+/// The array is sorted to allow binary search.
+const STYLELINT_STYLISTIC_RULES: &[&str] = &[
+    "at-rule-name-case",
+    "at-rule-name-newline-after",
+    "at-rule-name-space-after",
+    "at-rule-semicolon-newline-after",
+    "at-rule-semicolon-space-before",
+    "block-closing-brace-empty-line-before",
+    "block-closing-brace-newline-after",
+    "block-closing-brace-newline-before",
+    "block-closing-brace-space-after",
+    "block-closing-brace-space-before",
+    "block-opening-brace-newline-after",
+    "block-opening-brace-newline-before",
+    "block-opening-brace-space-after",
+    "block-opening-brace-space-before",
+    "color-hex-case",
+    "declaration-bang-space-after",
+    "declaration-bang-space-before",
+    "declaration-block-semicolon-newline-after",
+    "declaration-block-semicolon-newline-before",
+    "declaration-block-semicolon-space-after",
+    "declaration-block-semicolon-space-before",
+    "declaration-block-trailing-semicolon",
+    "declaration-colon-newline-after",
+    "declaration-colon-space-after",
+    "declaration-colon-space-before",
+    "function-comma-newline-after",
+    "function-comma-newline-before",
+    "function-comma-space-after",
+    "function-comma-space-before",
+    "function-max-empty-lines",
+    "function-parentheses-newline-inside",
+    "function-parentheses-space-inside",
+    "function-whitespace-after",
+    "indentation",
+    "linebreaks",
+    "max-empty-lines",
+    "max-line-length",
+    "media-feature-colon-space-after",
+    "media-feature-colon-space-before",
+    "media-feature-name-case",
+    "media-feature-parentheses-space-inside",
+    "media-feature-range-operator-space-after",
+    "media-feature-range-operator-space-before",
+    "media-query-list-comma-newline-after",
+    "media-query-list-comma-newline-before",
+    "media-query-list-comma-space-after",
+    "media-query-list-comma-space-before",
+    "no-empty-first-line",
+    "no-eol-whitespace",
+    "no-extra-semicolons",
+    "no-missing-end-of-source-newline",
+    "number-leading-zero",
+    "number-no-trailing-zeros",
+    "property-case",
+    "selector-attribute-brackets-space-inside",
+    "selector-attribute-operator-space-after",
+    "selector-attribute-operator-space-before",
+    "selector-combinator-space-after",
+    "selector-combinator-space-before",
+    "selector-descendant-combinator-no-non-space",
+    "selector-list-comma-newline-after",
+    "selector-list-comma-newline-before",
+    "selector-list-comma-space-after",
+    "selector-list-comma-space-before",
+    "selector-max-empty-lines",
+    "selector-pseudo-class-case",
+    "selector-pseudo-class-parentheses-space-inside",
+    "selector-pseudo-element-case",
+    "string-quotes",
+    "unicode-bom",
+    "unit-case",
+    "value-list-comma-newline-after",
+    "value-list-comma-newline-before",
+    "value-list-comma-space-after",
+    "value-list-comma-space-before",
+    "value-list-max-empty-lines",
+];
+
+#[derive(Debug, Default)]
+pub(crate) struct MigrationResults {
+    /// Path to the migrated Stylelint configuration
+    pub(crate) stylelint_path: Option<Box<str>>,
+    /// Is the Biome configuration updated?
+    pub(crate) write: bool,
+    // Contains inspired rules that were not migrated because `include_inspired` is disabled
+    pub(crate) inspired: BTreeSet<Box<str>>,
+    // Contains nursery rules that were not migrated because `include_nursery` is disabled
+    pub(crate) nursery: BTreeSet<Box<str>>,
+    pub(crate) migrated: BTreeSet<Box<str>>,
+    /// Stylistic rules that are not supported on purpose.
+    pub(crate) stylistic: BTreeSet<Box<str>>,
+    pub(crate) unsupported: BTreeSet<Box<str>>,
+}
+impl MigrationResults {
+    pub(crate) fn add(&mut self, rule_name: &str, status: RuleMigrationResult) {
+        let set = match status {
+            RuleMigrationResult::Migrated => &mut self.migrated,
+            RuleMigrationResult::Inspired => &mut self.inspired,
+            RuleMigrationResult::Nursery => &mut self.nursery,
+            RuleMigrationResult::Unsupported => {
+                if rule_name.starts_with("@stylistic/")
+                    || STYLELINT_STYLISTIC_RULES.binary_search(&rule_name).is_ok()
+                {
+                    &mut self.stylistic
+                } else {
+                    &mut self.unsupported
+                }
+            }
+        };
+        set.insert(rule_name.into());
+    }
+
+    pub(crate) fn rule_count(&self) -> usize {
+        self.migrated.len()
+            + self.inspired.len()
+            + self.nursery.len()
+            + self.stylistic.len()
+            + self.unsupported.len()
+    }
+}
+impl biome_diagnostics::Diagnostic for MigrationResults {
+    fn category(&self) -> Option<&'static biome_diagnostics::Category> {
+        Some(biome_diagnostics::category!("migrate"))
+    }
+
+    fn severity(&self) -> biome_diagnostics::Severity {
+        biome_diagnostics::Severity::Information
+    }
+
+    fn location(&self) -> Location<'_> {
+        let mut builder = Location::builder();
+        if let Some(path) = self.stylelint_path.as_ref() {
+            builder = builder.resource(path);
+        }
+        builder.build()
+    }
+
+    fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
+        let count = self.rule_count();
+        if count != 0 {
+            let migrated_count = self.migrated.len();
+            let migrated_percent = migrated_count * 100 / count;
+            let verb = if self.write { "have been" } else { "can be" };
+            fmt.write_markup(markup! { {migrated_percent}"% ("{migrated_count}"/"{count}") of the rules "{verb}" migrated." })
+        } else {
+            fmt.write_markup(markup! { "No rules to migrate." })
+        }
+    }
+
+    fn advices(&self, visitor: &mut dyn biome_diagnostics::Visit) -> std::io::Result<()> {
+        let record_rules = |visitor: &mut dyn biome_diagnostics::Visit,
+                            rules: &BTreeSet<Box<str>>,
+                            title: &dyn biome_console::fmt::Display| {
+            if rules.is_empty() {
+                return Ok(());
+            }
+            visitor.record_log(biome_diagnostics::LogCategory::Info, title)?;
+            let list: Vec<_> = rules
+                .iter()
+                .map(|item| item as &dyn biome_console::fmt::Display)
+                .collect();
+            visitor.record_list(list.as_slice())
+        };
+        if self.migrated.len() != self.rule_count() {
+            if self.write {
+                record_rules(visitor, &self.migrated, &markup! { "Migrated rules:" })?;
+            } else {
+                record_rules(
+                    visitor,
+                    &self.migrated,
+                    &markup! { "Rules that can be migrated:" },
+                )?;
+            }
+        }
+        record_rules(
+            visitor,
+            &self.inspired,
+            &markup! { "Rules that can be migrated to an inspired rule using "<Emphasis>"--include-inspired"</Emphasis>":" },
+        )?;
+        record_rules(
+            visitor,
+            &self.nursery,
+            &markup! { "Rules that can be migrated to a nursery rule using "<Emphasis>"--include-nursery"</Emphasis>":" },
+        )?;
+        record_rules(
+            visitor,
+            &self.stylistic,
+            &markup! { "Stylistic rules that the formatter may support (manual migration required):" },
+        )?;
+        record_rules(
+            visitor,
+            &self.unsupported,
+            &markup! { "Rules without equivalent:" },
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum RuleMigrationResult {
+    /// A rule that has been migrated.
+    Migrated,
+    /// A rule that could be migrated if `--include-inspired` was passed
+    #[expect(
+        dead_code,
+        reason = "no CSS rule is inspired from a Stylelint rule yet"
+    )]
+    Inspired,
+    /// A rule that could be migrated if `--include-nursery` was passed
+    Nursery,
+    /// An unsupported rule
+    Unsupported,
+}
+
+impl stylelint::StylelintConfiguration {
+    pub(crate) fn into_biome_config(
+        self,
+        options: &MigrationOptions,
+    ) -> (biome_config::Configuration, MigrationResults) {
+        let mut results = MigrationResults::default();
+        let mut biome_config = biome_config::Configuration::default();
+        let default_severity = self.default_severity;
+        let mut linter = biome_config::LinterConfiguration {
+            rules: Some(
+                self.rules
+                    .into_biome_rules(default_severity, options, &mut results),
+            ),
+            ..Default::default()
+        };
+        let includes = to_biome_includes(&[] as &[&str], &self.ignore_files);
+        linter.includes = (!includes.is_empty()).then_some(includes);
+        if !self.overrides.is_empty() {
+            let mut overrides = biome_config::Overrides::default();
+            for override_elt in self.overrides {
+                let mut override_pattern = biome_config::OverridePattern::default();
+                let includes = to_biome_includes(&override_elt.files, &[] as &[&str]);
+                override_pattern.includes = (!includes.is_empty())
+                    .then_some(biome_config::OverrideGlobs::Globs(includes.into()));
+                if !override_elt.rules.0.is_empty() {
+                    override_pattern.linter = Some(biome_config::OverrideLinterConfiguration {
+                        rules: Some(override_elt.rules.into_biome_rules(
+                            default_severity,
+                            options,
+                            &mut results,
+                        )),
+                        ..Default::default()
+                    });
+                }
+                overrides.0.push(override_pattern);
+            }
+            biome_config.overrides = Some(overrides);
+        }
+        biome_config.linter = Some(linter);
+        (biome_config, results)
+    }
+}
+
+impl stylelint::Rules {
+    pub(crate) fn into_biome_rules(
+        self,
+        default_severity: Option<stylelint::SeverityOption>,
+        options: &MigrationOptions,
+        results: &mut MigrationResults,
+    ) -> biome_config::Rules {
+        let mut rules = biome_config::Rules::default();
+        for (rule_name, conf) in self.0 {
+            migrate_stylelint_any_rule(
+                &mut rules,
+                &rule_name,
+                conf.severity(default_severity),
+                options,
+                results,
+            );
+        }
+        rules
+    }
+}
+
+/// Stylelint ignores the files that match `ignore_files`,
+/// unless the pattern is negated with `!`.
+fn to_biome_includes(
+    files: &[impl AsRef<str>],
+    ignore_files: &[impl AsRef<str>],
+) -> Vec<biome_glob::NormalizedGlob> {
+    let mut includes: Vec<biome_glob::NormalizedGlob> = files
+        .iter()
+        .filter_map(|glob| glob.as_ref().parse().ok())
+        .collect();
+    if !ignore_files.is_empty() {
+        if includes.is_empty() {
+            if let Ok(glob) = "**".parse() {
+                includes.push(glob);
+            }
+        }
+        includes.extend(ignore_files.iter().filter_map(|glob| {
+            if let Some(rest) = glob.as_ref().strip_prefix('!') {
+                rest.parse()
+            } else {
+                glob.as_ref()
+                    .parse()
+                    .map(|glob: biome_glob::NormalizedGlob| glob.negated())
+            }
+            .ok()
+        }));
+    }
+    includes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_deserialize::json::deserialize_from_json_str;
+    use biome_json_parser::JsonParserOptions;
+
+    #[test]
+    fn test_stylelint_stylistic_rules_order() {
+        assert!(STYLELINT_STYLISTIC_RULES.is_sorted());
+    }
+
+    #[test]
+    fn stylelint_config_to_biome_config() {
+        let stylelint_config = deserialize_from_json_str::<stylelint::StylelintConfiguration>(
+            r#"{
+                "ignoreFiles": ["dist/**"],
+                "rules": {
+                    "block-no-empty": [true, { "severity": "warning" }],
+                    "color-no-invalid-hex": null
+                },
+                "overrides": [{
+                    "files": "*.scss",
+                    "rules": { "block-no-empty": null }
+                }]
+            }"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        let (biome_config, results) =
+            stylelint_config.into_biome_config(&MigrationOptions::default());
+
+        let linter = biome_config.linter.unwrap();
+        assert_eq!(
+            linter.includes.unwrap(),
+            ["**".parse().unwrap(), "!dist/**".parse().unwrap()]
+        );
+        let rules = linter.rules.unwrap();
+        assert!(rules.recommended.is_none());
+        assert_eq!(
+            rules.suspicious.unwrap().unwrap_group().no_empty_block,
+            Some(biome_config::RuleConfiguration::Plain(
+                biome_config::RulePlainConfiguration::Warn
+            ))
+        );
+        let overrides = biome_config.overrides.unwrap();
+        assert_eq!(overrides.0.len(), 1);
+        assert_eq!(results.migrated.len(), 1);
+        assert_eq!(results.unsupported.len(), 1);
+    }
+}
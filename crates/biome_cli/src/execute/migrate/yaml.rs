@@ -0,0 +1,415 @@
+//! This modules converts a YAML configuration file into a JSON value,
+//! so that it can be deserialized like the JSON configuration files.
+//!
+//! Only the first document of the file is converted.
+//! Anchors and tags are ignored, and aliases are converted to `null`.
+
+use biome_rowan::{AstNodeList, AstSeparatedList, SyntaxResult};
+use biome_yaml_parser::parse_yaml;
+use biome_yaml_syntax::{
+    AnyYamlBlockCollectionContent, AnyYamlBlockInBlockNode, AnyYamlBlockIndented,
+    AnyYamlBlockMapEntry, AnyYamlBlockMapImplicitKey, AnyYamlBlockNode, AnyYamlBlockScalarContent,
+    AnyYamlBlockSequenceEntry, AnyYamlDocument, AnyYamlFlowMapEntry, AnyYamlFlowMapImplicitKey,
+    AnyYamlFlowNode, AnyYamlFlowSequenceEntry, AnyYamlJsonContent, YamlBlockMapEntryList,
+    YamlBlockSequenceEntryList, YamlFlowMapImplicitEntry, YamlFlowMapping, YamlFlowSequence,
+    YamlSyntaxToken,
+};
+use serde_json::{Map, Number, Value};
+
+/// Converts the YAML `source` into a JSON value.
+///
+/// Returns `None` if the YAML file has syntax errors.
+pub(crate) fn yaml_to_json(source: &str) -> Option<Value> {
+    let parse = parse_yaml(source);
+    if parse.has_errors() {
+        return None;
+    }
+    let document = parse.tree().documents().iter().next();
+    match document {
+        Some(AnyYamlDocument::YamlDocument(document)) => document
+            .node()
+            .map_or(Some(Value::Null), |node| block_node(&node).ok()),
+        Some(AnyYamlDocument::YamlBogus(_)) => None,
+        None => Some(Value::Null),
+    }
+}
+
+fn block_node(node: &AnyYamlBlockNode) -> SyntaxResult<Value> {
+    match node {
+        AnyYamlBlockNode::AnyYamlBlockInBlockNode(node) => match node {
+            AnyYamlBlockInBlockNode::YamlBlockCollection(collection) => {
+                match collection.content()? {
+                    AnyYamlBlockCollectionContent::YamlBlockMapping(mapping) => {
+                        block_mapping(&mapping.entries())
+                    }
+                    AnyYamlBlockCollectionContent::YamlBlockSequence(sequence) => {
+                        block_sequence(&sequence.entries())
+                    }
+                }
+            }
+            AnyYamlBlockInBlockNode::YamlBlockScalar(scalar) => {
+                Ok(Value::String(match scalar.content()? {
+                    AnyYamlBlockScalarContent::YamlLiteralScalar(scalar) => {
+                        block_scalar(scalar.value_token()?.text_trimmed(), false)
+                    }
+                    AnyYamlBlockScalarContent::YamlFoldedScalar(scalar) => {
+                        block_scalar(scalar.value_token()?.text_trimmed(), true)
+                    }
+                }))
+            }
+        },
+        AnyYamlBlockNode::YamlFlowInBlockNode(node) => flow_node(&node.flow()?),
+        AnyYamlBlockNode::YamlBogusBlockNode(_) => Ok(Value::Null),
+    }
+}
+
+fn block_indented(node: Option<AnyYamlBlockIndented>) -> SyntaxResult<Value> {
+    match node {
+        Some(AnyYamlBlockIndented::AnyYamlBlockNode(node)) => block_node(&node),
+        Some(AnyYamlBlockIndented::YamlCompactMapping(mapping)) => {
+            block_mapping(&mapping.entries())
+        }
+        Some(AnyYamlBlockIndented::YamlCompactSequence(sequence)) => {
+            block_sequence(&sequence.entries())
+        }
+        None => Ok(Value::Null),
+    }
+}
+
+fn block_mapping(entries: &YamlBlockMapEntryList) -> SyntaxResult<Value> {
+    let mut map = Map::new();
+    for entry in entries.iter() {
+        let (key, value) = match entry {
+            AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => {
+                let key = match entry.key() {
+                    Some(AnyYamlBlockMapImplicitKey::YamlFlowJsonNode(node)) => {
+                        flow_node(&AnyYamlFlowNode::YamlFlowJsonNode(node))?
+                    }
+                    Some(AnyYamlBlockMapImplicitKey::YamlFlowYamlNode(node)) => {
+                        flow_node(&AnyYamlFlowNode::YamlFlowYamlNode(node))?
+                    }
+                    Some(AnyYamlBlockMapImplicitKey::YamlAliasNode(_)) | None => Value::Null,
+                };
+                let value = match entry.value()?.value() {
+                    Some(value) => block_node(&value)?,
+                    None => Value::Null,
+                };
+                (key, value)
+            }
+            AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => {
+                let key = block_indented(entry.key()?.key())?;
+                let value = block_indented(entry.value().and_then(|value| value.value()))?;
+                (key, value)
+            }
+            AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => continue,
+        };
+        map.insert(key_to_string(key), value);
+    }
+    Ok(Value::Object(map))
+}
+
+fn block_sequence(entries: &YamlBlockSequenceEntryList) -> SyntaxResult<Value> {
+    let mut array = Vec::new();
+    for entry in entries.iter() {
+        if let AnyYamlBlockSequenceEntry::YamlBlockSequenceEntry(entry) = entry {
+            array.push(block_indented(entry.value())?);
+        }
+    }
+    Ok(Value::Array(array))
+}
+
+fn flow_node(node: &AnyYamlFlowNode) -> SyntaxResult<Value> {
+    match node {
+        AnyYamlFlowNode::YamlFlowYamlNode(node) => match node.content() {
+            Some(scalar) => Ok(plain_scalar(&scalar.value_token()?)),
+            None => Ok(Value::Null),
+        },
+        AnyYamlFlowNode::YamlFlowJsonNode(node) => match node.content() {
+            Some(AnyYamlJsonContent::YamlFlowMapping(mapping)) => flow_mapping(&mapping),
+            Some(AnyYamlJsonContent::YamlFlowSequence(sequence)) => flow_sequence(&sequence),
+            Some(AnyYamlJsonContent::YamlDoubleQuotedScalar(scalar)) => Ok(Value::String(
+                double_quoted_scalar(scalar.value_token()?.text_trimmed()),
+            )),
+            Some(AnyYamlJsonContent::YamlSingleQuotedScalar(scalar)) => Ok(Value::String(
+                single_quoted_scalar(scalar.value_token()?.text_trimmed()),
+            )),
+            None => Ok(Value::Null),
+        },
+        AnyYamlFlowNode::YamlAliasNode(_) | AnyYamlFlowNode::YamlBogusFlowNode(_) => {
+            Ok(Value::Null)
+        }
+    }
+}
+
+fn flow_mapping(mapping: &YamlFlowMapping) -> SyntaxResult<Value> {
+    let mut map = Map::new();
+    for entry in mapping.entries().iter() {
+        let entry = match entry? {
+            AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => entry,
+            AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => match entry.entry() {
+                Some(entry) => entry,
+                None => continue,
+            },
+        };
+        let (key, value) = flow_map_entry(&entry)?;
+        map.insert(key_to_string(key), value);
+    }
+    Ok(Value::Object(map))
+}
+
+fn flow_sequence(sequence: &YamlFlowSequence) -> SyntaxResult<Value> {
+    let mut array = Vec::new();
+    for entry in sequence.entries().iter() {
+        let value = match entry? {
+            AnyYamlFlowSequenceEntry::AnyYamlFlowNode(node) => flow_node(&node)?,
+            // `[a: b]` is a shorthand for `[{ a: b }]`
+            AnyYamlFlowSequenceEntry::AnyYamlFlowMapEntry(entry) => {
+                let entry = match entry {
+                    AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => entry,
+                    AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => match entry.entry() {
+                        Some(entry) => entry,
+                        None => continue,
+                    },
+                };
+                let (key, value) = flow_map_entry(&entry)?;
+                let mut map = Map::new();
+                map.insert(key_to_string(key), value);
+                Value::Object(map)
+            }
+        };
+        array.push(value);
+    }
+    Ok(Value::Array(array))
+}
+
+fn flow_map_entry(entry: &YamlFlowMapImplicitEntry) -> SyntaxResult<(Value, Value)> {
+    let key = match entry.key() {
+        Some(AnyYamlFlowMapImplicitKey::YamlFlowJsonNode(node)) => {
+            flow_node(&AnyYamlFlowNode::YamlFlowJsonNode(node))?
+        }
+        Some(AnyYamlFlowMapImplicitKey::YamlFlowYamlNode(node)) => {
+            flow_node(&AnyYamlFlowNode::YamlFlowYamlNode(node))?
+        }
+        Some(AnyYamlFlowMapImplicitKey::YamlAliasNode(_)) | None => Value::Null,
+    };
+    let value = match entry.value() {
+        Some(value) => flow_node(&value)?,
+        None => Value::Null,
+    };
+    Ok((key, value))
+}
+
+fn key_to_string(key: Value) -> String {
+    match key {
+        Value::String(key) => key,
+        Value::Null => String::new(),
+        key => key.to_string(),
+    }
+}
+
+/// Resolves a plain scalar using the YAML 1.2 core schema.
+fn plain_scalar(token: &YamlSyntaxToken) -> Value {
+    let text = fold_lines(token.text_trimmed());
+    match text.as_str() {
+        "" | "~" | "null" | "Null" | "NULL" => Value::Null,
+        "true" | "True" | "TRUE" => Value::Bool(true),
+        "false" | "False" | "FALSE" => Value::Bool(false),
+        _ => {
+            if let Ok(number) = text.parse::<i64>() {
+                Value::Number(number.into())
+            } else if let Some(number) = text
+                .parse::<f64>()
+                .ok()
+                .filter(|_| text.bytes().any(|byte| byte.is_ascii_digit()))
+                .and_then(Number::from_f64)
+            {
+                Value::Number(number)
+            } else {
+                Value::String(text)
+            }
+        }
+    }
+}
+
+fn single_quoted_scalar(text: &str) -> String {
+    let text = text
+        .strip_prefix('\'')
+        .and_then(|text| text.strip_suffix('\''))
+        .unwrap_or(text);
+    fold_lines(text).replace("''", "'")
+}
+
+fn double_quoted_scalar(text: &str) -> String {
+    let text = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .unwrap_or(text);
+    let text = fold_lines(text);
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('0') => result.push('\0'),
+            Some('a') => result.push('\u{07}'),
+            Some('b') => result.push('\u{08}'),
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some('v') => result.push('\u{0B}'),
+            Some('f') => result.push('\u{0C}'),
+            Some('r') => result.push('\r'),
+            Some('e') => result.push('\u{1B}'),
+            Some(c @ ('x' | 'u' | 'U')) => {
+                let len = match c {
+                    'x' => 2,
+                    'u' => 4,
+                    _ => 8,
+                };
+                let hex: String = chars.by_ref().take(len).collect();
+                if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    result.push(c);
+                }
+            }
+            Some(c) => result.push(c),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Folds the lines of a multi-line flow scalar:
+/// a line break is converted to a space, and an empty line to a line break.
+fn fold_lines(text: &str) -> String {
+    if !text.contains('\n') {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut empty_lines = 0;
+    for (index, line) in text.lines().enumerate() {
+        let line = if index == 0 {
+            line.trim_end()
+        } else {
+            line.trim()
+        };
+        if index > 0 && line.is_empty() {
+            empty_lines += 1;
+            continue;
+        }
+        if index > 0 {
+            if empty_lines == 0 {
+                result.push(' ');
+            } else {
+                result.extend(std::iter::repeat_n('\n', empty_lines));
+            }
+        }
+        empty_lines = 0;
+        result.push_str(line);
+    }
+    result
+}
+
+/// Returns the content of a literal (`|`) or a folded (`>`) block scalar.
+///
+/// The indentation is detected from the first non-empty line.
+fn block_scalar(text: &str, folded: bool) -> String {
+    let (header, content) = text.split_once('\n').unwrap_or((text, ""));
+    let lines: Vec<_> = content.lines().collect();
+    let indent = lines
+        .iter()
+        .find(|line| !line.trim().is_empty())
+        .map_or(0, |line| line.len() - line.trim_start().len());
+    let lines: Vec<_> = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default())
+        .collect();
+    let mut result = String::with_capacity(content.len());
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            let previous = lines[index - 1];
+            let is_folded = folded
+                && !line.is_empty()
+                && !previous.is_empty()
+                && !line.starts_with([' ', '\t'])
+                && !previous.starts_with([' ', '\t']);
+            result.push(if is_folded { ' ' } else { '\n' });
+        }
+        result.push_str(line);
+    }
+    if header.contains('-') {
+        result.truncate(result.trim_end_matches('\n').len());
+    } else if header.contains('+') {
+        result.push('\n');
+    } else {
+        result.truncate(result.trim_end_matches('\n').len());
+        if !result.is_empty() {
+            result.push('\n');
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn converts_block_collections() {
+        let source = r#"extends:
+  - stylelint-config-standard
+  - ./local.js
+rules:
+  block-no-empty: null
+  color-no-invalid-hex: true
+  unit-allowed-list: [px, em]
+  max-nesting-depth:
+    - 2
+    - severity: warning
+      ignore: ["blockless-at-rules"]
+ignoreFiles: "dist/**"
+"#;
+        assert_eq!(
+            yaml_to_json(source),
+            Some(json!({
+                "extends": ["stylelint-config-standard", "./local.js"],
+                "rules": {
+                    "block-no-empty": null,
+                    "color-no-invalid-hex": true,
+                    "unit-allowed-list": ["px", "em"],
+                    "max-nesting-depth": [2, { "severity": "warning", "ignore": ["blockless-at-rules"] }],
+                },
+                "ignoreFiles": "dist/**",
+            }))
+        );
+    }
+
+    #[test]
+    fn converts_scalars() {
+        let source = r#"plain: multiple
+  lines
+single: 'it''s'
+double: "tab\tand é"
+number: 1.5
+literal: |
+  line 1
+  line 2
+folded: >-
+  folded
+  text
+"#;
+        assert_eq!(
+            yaml_to_json(source),
+            Some(json!({
+                "plain": "multiple lines",
+                "single": "it's",
+                "double": "tab\tand é",
+                "number": 1.5,
+                "literal": "line 1\nline 2\n",
+                "folded": "folded text",
+            }))
+        );
+    }
+}
@@ -1,18 +1,24 @@
+pub(crate) mod cache;
 mod diagnostics;
+mod graph;
 mod migrate;
 mod process_file;
 mod std_in;
 pub(crate) mod traverse;
 
+use crate::changed::ChangedLines;
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
+use crate::execute::cache::AnalysisCache;
+use crate::execute::graph::GraphPayload;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{TraverseResult, traverse};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{
@@ -23,6 +29,7 @@ use biome_console::{ConsoleExt, markup};
 use biome_diagnostics::{Category, category};
 use biome_diagnostics::{Resource, SerdeJsonError};
 use biome_fs::BiomePath;
+use biome_glob::Glob;
 use biome_grit_patterns::GritTargetLanguage;
 use biome_service::projects::ProjectKey;
 use biome_service::workspace::{
@@ -33,6 +40,7 @@ use camino::{Utf8Path, Utf8PathBuf};
 use std::cmp::Ordering;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::time::Duration;
 use tracing::{info, instrument};
 
@@ -83,11 +91,18 @@ impl From<(Utf8PathBuf, String)> for Stdin {
 pub struct VcsTargeted {
     pub staged: bool,
     pub changed: bool,
+    /// The lines that have been changed, when only the diagnostics of the
+    /// changed lines should be reported
+    pub changed_lines: Option<ChangedLines>,
 }
 
 impl From<(bool, bool)> for VcsTargeted {
     fn from((staged, changed): (bool, bool)) -> Self {
-        Self { staged, changed }
+        Self {
+            staged,
+            changed,
+            changed_lines: None,
+        }
     }
 }
 
@@ -176,8 +191,6 @@ pub enum TraversalMode {
     /// This mode is enabled when running the command `biome search`
     Search {
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command does not support rewrites.
         pattern: PatternId,
 
         /// The language to query for.
@@ -189,11 +202,27 @@ pub enum TraversalMode {
         /// If none given, the default language is JavaScript.
         language: Option<GritTargetLanguage>,
 
+        /// Write the rewrites of the pattern to disk
+        write: bool,
+
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<Stdin>,
     },
+    /// This mode is enabled when running the command `biome graph`
+    Graph {
+        /// The format in which the module graph is printed
+        format: GraphFormat,
+        /// Only modules whose path matches one of these globs are printed
+        include: Vec<Glob>,
+        /// Print the modules that the given modules depend on
+        dependencies_of: Vec<Utf8PathBuf>,
+        /// Print the modules that depend on the given modules
+        dependents_of: Vec<Utf8PathBuf>,
+        /// The maximum number of import edges to follow from the given modules
+        depth: Option<usize>,
+    },
 }
 
 impl Display for TraversalMode {
@@ -205,6 +234,7 @@ impl Display for TraversalMode {
             Self::Migrate { .. } => write!(f, "migrate"),
             Self::Lint { .. } => write!(f, "lint"),
             Self::Search { .. } => write!(f, "search"),
+            Self::Graph { .. } => write!(f, "graph"),
         }
     }
 }
@@ -229,6 +259,40 @@ impl TraversalMode {
                 }
             }
             Self::Migrate { .. } => ScanKind::NoScanner,
+            Self::Graph { .. } => ScanKind::Project,
+        }
+    }
+}
+
+/// The format in which `biome graph` prints the module graph
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum GraphFormat {
+    /// A JSON document listing the modules and their imports
+    #[default]
+    Json,
+    /// A directed graph in the DOT language of Graphviz
+    Dot,
+}
+
+impl FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "dot" => Ok(Self::Dot),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --format argument, expected one of: json, dot"
+            )),
+        }
+    }
+}
+
+impl Display for GraphFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json => write!(f, "json"),
+            Self::Dot => write!(f, "dot"),
         }
     }
 }
@@ -247,6 +311,8 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in the [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
 }
 
 impl Default for ReportMode {
@@ -269,6 +335,7 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Sarif => Self::Sarif,
         }
     }
 }
@@ -314,6 +381,20 @@ impl Execution {
         self
     }
 
+    /// It sets the lines that have been changed, if the traversal mode supports VCS targeting
+    pub(crate) fn set_changed_lines(mut self, changed_lines: Option<ChangedLines>) -> Self {
+        match &mut self.traversal_mode {
+            TraversalMode::Check { vcs_targeted, .. }
+            | TraversalMode::Lint { vcs_targeted, .. }
+            | TraversalMode::Format { vcs_targeted, .. }
+            | TraversalMode::CI { vcs_targeted, .. } => vcs_targeted.changed_lines = changed_lines,
+            TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Graph { .. } => {}
+        }
+        self
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -330,7 +411,8 @@ impl Execution {
             TraversalMode::Format { .. }
             | TraversalMode::CI { .. }
             | TraversalMode::Migrate { .. }
-            | TraversalMode::Search { .. } => None,
+            | TraversalMode::Search { .. }
+            | TraversalMode::Graph { .. } => None,
         }
     }
 
@@ -342,6 +424,7 @@ impl Execution {
             TraversalMode::Format { .. } => category!("format"),
             TraversalMode::Migrate { .. } => category!("migrate"),
             TraversalMode::Search { .. } => category!("search"),
+            TraversalMode::Graph { .. } => category!("graph"),
         }
     }
 
@@ -369,6 +452,7 @@ impl Execution {
             TraversalMode::Format { stdin, .. } => stdin.is_some(),
             TraversalMode::Migrate { .. } => false,
             TraversalMode::Search { stdin, .. } => stdin.is_some(),
+            TraversalMode::Graph { .. } => false,
         }
     }
 
@@ -414,8 +498,10 @@ impl Execution {
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } | TraversalMode::Graph { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -425,7 +511,9 @@ impl Execution {
             | TraversalMode::Lint { stdin, .. }
             | TraversalMode::Check { stdin, .. }
             | TraversalMode::Search { stdin, .. } => stdin.as_ref(),
-            TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => None,
+            TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Graph { .. } => None,
         }
     }
 
@@ -435,7 +523,23 @@ impl Execution {
             | TraversalMode::Lint { vcs_targeted, .. }
             | TraversalMode::Format { vcs_targeted, .. }
             | TraversalMode::CI { vcs_targeted, .. } => vcs_targeted.staged || vcs_targeted.changed,
-            TraversalMode::Migrate { .. } | TraversalMode::Search { .. } => false,
+            TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Graph { .. } => false,
+        }
+    }
+
+    /// Returns the lines that have been changed, when only the diagnostics of
+    /// the changed lines should be reported
+    pub(crate) fn changed_lines(&self) -> Option<&ChangedLines> {
+        match &self.traversal_mode {
+            TraversalMode::Check { vcs_targeted, .. }
+            | TraversalMode::Lint { vcs_targeted, .. }
+            | TraversalMode::Format { vcs_targeted, .. }
+            | TraversalMode::CI { vcs_targeted, .. } => vcs_targeted.changed_lines.as_ref(),
+            TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Graph { .. } => None,
         }
     }
 
@@ -447,7 +551,8 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
+            TraversalMode::Graph { .. } => false,
         }
     }
 
@@ -476,7 +581,7 @@ impl Execution {
                 .with_linter()
                 .with_assist()
                 .build(),
-            TraversalMode::Migrate { .. } => FeatureName::empty(),
+            TraversalMode::Migrate { .. } | TraversalMode::Graph { .. } => FeatureName::empty(),
             TraversalMode::Search { .. } => FeaturesBuilder::new().with_search().build(),
         }
     }
@@ -484,7 +589,7 @@ impl Execution {
     #[instrument(level = "debug", skip(self), fields(result))]
     pub(crate) fn should_write(&self) -> bool {
         let result = match self.traversal_mode {
-            TraversalMode::Format { write, .. } => write,
+            TraversalMode::Format { write, .. } | TraversalMode::Search { write, .. } => write,
 
             _ => self.is_safe_fixes_enabled() || self.is_safe_and_unsafe_fixes_enabled(),
         };
@@ -526,6 +631,7 @@ impl Execution {
 
 /// Based on the [mode](TraversalMode), the function might launch a traversal of the file system
 /// or handles the stdin file.
+#[expect(clippy::too_many_arguments)]
 pub fn execute_mode(
     mut execution: Execution,
     mut session: CliSession,
@@ -534,6 +640,7 @@ pub fn execute_mode(
     scanner_duration: Option<Duration>,
     nested_configuration_files: Vec<BiomePath>,
     project_key: ProjectKey,
+    cache: Option<AnalysisCache>,
 ) -> Result<(), CliDiagnostic> {
     // If a custom reporter was provided, let's lift the limit so users can see all of them
     execution.max_diagnostics = if cli_options.reporter.is_default() {
@@ -564,6 +671,28 @@ pub fn execute_mode(
         return migrate::run(payload);
     }
 
+    // graph command doesn't do any traversal either, it reads the module graph
+    // that was built by the scanner.
+    if let TraversalMode::Graph {
+        format,
+        include,
+        dependencies_of,
+        dependents_of,
+        depth,
+    } = execution.traversal_mode
+    {
+        let payload = GraphPayload {
+            session,
+            project_key,
+            format,
+            include,
+            dependencies_of,
+            dependents_of,
+            depth,
+        };
+        return graph::run(payload);
+    }
+
     // don't do any traversal if there's some content coming from stdin
     if let Some(stdin) = execution.as_stdin_file() {
         let biome_path = BiomePath::new(stdin.as_path());
@@ -587,6 +716,7 @@ pub fn execute_mode(
         project_key,
         cli_options,
         paths.clone(),
+        cache,
     )?;
     diagnostics.sort_unstable_by(|a, b| match a.severity().cmp(&b.severity()) {
         Ordering::Equal => {
@@ -702,6 +832,17 @@ pub fn execute_mode(
                 session.app.workspace.fs().working_directory(),
             ))?;
         }
+        ReportMode::Sarif => {
+            let reporter = SarifReporter {
+                diagnostics: diagnostics_payload,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+            };
+            reporter.write(&mut SarifReporterVisitor::new(
+                console,
+                session.app.workspace.fs().working_directory(),
+            ))?;
+        }
         ReportMode::Junit => {
             let reporter = JunitReporter {
                 summary,
@@ -1,22 +1,24 @@
 mod check;
 mod format;
 mod lint_and_assist;
-mod search;
+pub(crate) mod search;
 pub(crate) mod workspace_file;
 
 use crate::execute::TraversalMode;
+use crate::execute::cache::{CacheLookup, CachedMessage};
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::traverse::TraversalOptions;
 use biome_analyze::RuleCategoriesBuilder;
-use biome_diagnostics::{DiagnosticExt, DiagnosticTags, Error, category};
+use biome_diagnostics::{Diagnostic, DiagnosticExt, DiagnosticTags, Error, category};
 use biome_fs::BiomePath;
 use biome_service::workspace::{
-    DocumentFileSource, FeatureKind, SupportKind, SupportsFeatureParams,
+    DocumentFileSource, FeatureKind, FileFeaturesResult, SupportKind, SupportsFeatureParams,
 };
 use check::check_file;
 use format::format;
 use lint_and_assist::lint_and_assist;
 use search::search;
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::ops::Deref;
 
@@ -74,6 +76,8 @@ impl Message {
 #[derive(Debug)]
 pub(crate) enum DiffKind {
     Format,
+    /// Changes applied by the rewrites of a search pattern
+    Rewrite,
 }
 
 impl<D> From<D> for Message
@@ -97,10 +101,33 @@ where
 ///   `skipped` counter
 pub(crate) type FileResult = Result<FileStatus, Message>;
 
+/// Returns whether `diagnostic`, emitted for the file at `path` with the
+/// given `content`, should be reported.
+///
+/// When `--changed-lines` is used, only the diagnostics that overlap a changed
+/// line are reported. Diagnostics without a range are always reported.
+pub(crate) fn is_in_changed_lines(
+    ctx: &SharedTraversalOptions,
+    path: &BiomePath,
+    content: &str,
+    diagnostic: &impl Diagnostic,
+) -> bool {
+    let Some(changed_lines) = ctx.execution.changed_lines() else {
+        return true;
+    };
+    diagnostic
+        .location()
+        .span
+        .is_none_or(|span| changed_lines.contains(path, content, span))
+}
+
 /// Data structure that allows to pass [TraversalOptions] to multiple consumers, bypassing the
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// Messages emitted while processing the file, recorded when the result
+    /// is going to be cached
+    recorded: Option<RefCell<Vec<CachedMessage>>>,
     _p: PhantomData<&'app ()>,
 }
 
@@ -109,7 +136,29 @@ impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
         Self {
             _p: PhantomData,
             inner: t,
+            recorded: None,
+        }
+    }
+
+    fn with_recording(mut self) -> Self {
+        self.recorded = Some(RefCell::default());
+        self
+    }
+
+    fn into_recorded(self) -> Vec<CachedMessage> {
+        self.recorded.map(RefCell::into_inner).unwrap_or_default()
+    }
+
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        let msg = msg.into();
+        if let Some(recorded) = &self.recorded {
+            recorded.borrow_mut().push(CachedMessage::from(&msg));
         }
+        self.inner.push_message(msg);
+    }
+
+    pub(crate) fn push_diagnostic(&self, error: Error) {
+        self.push_message(error);
     }
 }
 
@@ -185,7 +234,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             ),
         TraversalMode::Format { .. } => file_features.support_kind_for(&FeatureKind::Format),
         TraversalMode::Lint { .. } => file_features.support_kind_for(&FeatureKind::Lint),
-        TraversalMode::Migrate { .. } => None,
+        TraversalMode::Migrate { .. } | TraversalMode::Graph { .. } => None,
         TraversalMode::Search { .. } => file_features.support_kind_for(&FeatureKind::Search),
     };
 
@@ -206,9 +255,46 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
         };
     }
 
-    let shared_context = &SharedTraversalOptions::new(ctx);
+    let lookup = ctx.cache.and_then(|cache| cache.lookup(ctx.fs, biome_path));
+    let lookup = match lookup {
+        Some(CacheLookup {
+            cached: Some((status, messages)),
+            ..
+        }) => {
+            for message in messages {
+                ctx.push_message(message);
+            }
+            return Ok(status);
+        }
+        lookup => lookup,
+    };
+
+    let mut shared_context = SharedTraversalOptions::new(ctx);
+    if lookup.is_some() {
+        shared_context = shared_context.with_recording();
+    }
 
-    match ctx.execution.traversal_mode {
+    let result = process_file_with_mode(&shared_context, biome_path, &file_features);
+
+    if let (Some(cache), Some(lookup)) = (ctx.cache, lookup) {
+        cache.store(
+            ctx,
+            biome_path,
+            lookup,
+            &result,
+            shared_context.into_recorded(),
+        );
+    }
+
+    result
+}
+
+fn process_file_with_mode(
+    shared_context: &SharedTraversalOptions,
+    biome_path: &BiomePath,
+    file_features: &FileFeaturesResult,
+) -> FileResult {
+    match shared_context.execution.traversal_mode {
         TraversalMode::Lint {
             ref suppression_reason,
             suppress,
@@ -229,11 +315,14 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             format(shared_context, biome_path.clone())
         }
         TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
-            check_file(shared_context, biome_path.clone(), &file_features)
+            check_file(shared_context, biome_path.clone(), file_features)
         }
         TraversalMode::Migrate { .. } => {
             unreachable!("The migration should not be called for this file")
         }
+        TraversalMode::Graph { .. } => {
+            unreachable!("The module graph should not be printed for this file")
+        }
         TraversalMode::Search { ref pattern, .. } => {
             // the unsupported case should be handled already at this point
             search(shared_context, biome_path.clone(), pattern)
@@ -1,13 +1,15 @@
 use crate::execute::diagnostics::{ResultExt, SkippedDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
-    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions, is_in_changed_lines,
 };
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
+use biome_rowan::TextRange;
+use biome_service::WorkspaceError;
 use biome_service::diagnostics::FileTooLarge;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use tracing::{debug, instrument};
 
 #[instrument(name = "cli_format", level = "debug", skip(ctx, path))]
@@ -70,7 +72,9 @@ pub(crate) fn format_with_guard<'ctx>(
             // Formatting is usually blocked by errors, so we want to print only diagnostics that
             // Have error severity
             .filter_map(|diagnostic| {
-                if diagnostic.severity() >= Severity::Error {
+                if diagnostic.severity() >= Severity::Error
+                    && is_in_changed_lines(ctx, &workspace_file.path, &input, &diagnostic)
+                {
                     Some(Error::from(diagnostic))
                 } else {
                     None
@@ -80,35 +84,34 @@ pub(crate) fn format_with_guard<'ctx>(
         skipped_diagnostics: diagnostics_result.skipped_diagnostics as u32,
     });
 
-    let printed = workspace_file
-        .guard()
-        .format_file()
-        .with_file_path_and_code(workspace_file.path.to_string(), category!("format"))?;
+    let output = match format_changed_lines(ctx, workspace_file, &input)? {
+        Some(output) => output,
+        None => {
+            let printed = workspace_file
+                .guard()
+                .format_file()
+                .with_file_path_and_code(workspace_file.path.to_string(), category!("format"))?;
 
-    let mut output = printed.into_code();
+            let output = printed.into_code();
 
-    match workspace_file.as_extension() {
-        Some("astro") => {
-            if output.is_empty() {
-                return Ok(FileStatus::Unchanged);
-            }
-            output = AstroFileHandler::output(input.as_str(), output.as_str());
-        }
-        Some("vue") => {
-            if output.is_empty() {
-                return Ok(FileStatus::Unchanged);
-            }
-            output = VueFileHandler::output(input.as_str(), output.as_str());
-        }
+            match workspace_file.as_extension() {
+                Some("astro") => {
+                    if output.is_empty() {
+                        return Ok(FileStatus::Unchanged);
+                    }
+                    AstroFileHandler::output(input.as_str(), output.as_str())
+                }
 
-        Some("svelte") => {
-            if output.is_empty() {
-                return Ok(FileStatus::Unchanged);
+                Some("svelte") => {
+                    if output.is_empty() {
+                        return Ok(FileStatus::Unchanged);
+                    }
+                    SvelteFileHandler::output(input.as_str(), output.as_str())
+                }
+                _ => output,
             }
-            output = SvelteFileHandler::output(input.as_str(), output.as_str());
         }
-        _ => {}
-    }
+    };
 
     debug!("Format output is different from input: {}", output != input);
     if output != input {
@@ -127,3 +130,62 @@ pub(crate) fn format_with_guard<'ctx>(
         Ok(FileStatus::Unchanged)
     }
 }
+
+/// Formats only the changed lines of the file when `--changed-lines` is used.
+///
+/// Returns `None` when the whole file must be formatted instead, that is when
+/// `--changed-lines` isn't used or when the file doesn't support range
+/// formatting.
+fn format_changed_lines<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &WorkspaceFile,
+    input: &str,
+) -> Result<Option<String>, Error> {
+    let Some(changed_lines) = ctx.execution.changed_lines() else {
+        return Ok(None);
+    };
+    // The output of these files is built from the formatted code of their
+    // scripts, which requires formatting the whole file.
+    if matches!(
+        workspace_file.as_extension(),
+        Some("astro" | "svelte" | "vue")
+    ) {
+        return Ok(None);
+    }
+
+    // The formatted ranges, sorted and without overlaps
+    let mut replacements: Vec<(TextRange, String)> = Vec::new();
+    for range in changed_lines.text_ranges(&workspace_file.path, input) {
+        let mut range = range;
+        let printed = loop {
+            let printed = match workspace_file.guard().format_range(range) {
+                Ok(printed) => printed,
+                Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+                Err(error) => {
+                    return Err(error).with_file_path_and_code(
+                        workspace_file.path.to_string(),
+                        category!("format"),
+                    );
+                }
+            };
+            let printed_range = printed.range().unwrap_or(range);
+            // The formatter can extend the range to the enclosing nodes, so
+            // overlapping ranges are formatted again as a single range.
+            match replacements.last() {
+                Some((last_range, _)) if last_range.end() > printed_range.start() => {
+                    range = last_range.cover(printed_range);
+                    replacements.pop();
+                }
+                _ => break (printed_range, printed.into_code()),
+            }
+        };
+        replacements.push(printed);
+    }
+
+    let mut output = input.to_string();
+    for (range, code) in replacements.into_iter().rev() {
+        output.replace_range(std::ops::Range::<usize>::from(range), &code);
+    }
+
+    Ok(Some(output))
+}
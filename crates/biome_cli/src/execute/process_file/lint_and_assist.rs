@@ -1,13 +1,15 @@
 use crate::TraversalMode;
 use crate::execute::diagnostics::{ResultExt, SkippedDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    FileResult, FileStatus, Message, SharedTraversalOptions, is_in_changed_lines,
+};
 use biome_analyze::RuleCategories;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_rowan::TextSize;
 use biome_service::diagnostics::FileTooLarge;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use tracing::{info, instrument};
 
 /// Lints a single file and returns a [FileResult]
@@ -95,9 +97,6 @@ pub(crate) fn analyze_with_guard<'ctx>(
             Some("astro") => {
                 output = AstroFileHandler::output(input.as_str(), output.as_str());
             }
-            Some("vue") => {
-                output = VueFileHandler::output(input.as_str(), output.as_str());
-            }
             Some("svelte") => {
                 output = SvelteFileHandler::output(input.as_str(), output.as_str());
             }
@@ -131,25 +130,29 @@ pub(crate) fn analyze_with_guard<'ctx>(
 
     if !no_diagnostics {
         let offset = match workspace_file.as_extension() {
-            Some("vue") => VueFileHandler::start(input.as_str()),
             Some("astro") => AstroFileHandler::start(input.as_str()),
             Some("svelte") => SvelteFileHandler::start(input.as_str()),
             _ => None,
         };
 
+        let diagnostics: Vec<_> = pull_diagnostics_result
+            .diagnostics
+            .into_iter()
+            .map(|d| {
+                if let Some(offset) = offset {
+                    d.with_offset(TextSize::from(offset))
+                } else {
+                    d
+                }
+            })
+            .filter(|diagnostic| is_in_changed_lines(ctx, &workspace_file.path, &input, diagnostic))
+            .collect();
+
         ctx.push_message(Message::Diagnostics {
             file_path: workspace_file.path.to_string(),
             content: input,
-            diagnostics: pull_diagnostics_result
-                .diagnostics
+            diagnostics: diagnostics
                 .into_iter()
-                .map(|d| {
-                    if let Some(offset) = offset {
-                        d.with_offset(TextSize::from(offset))
-                    } else {
-                        d
-                    }
-                })
                 .map(|diagnostic| {
                     let category = diagnostic.category();
                     if let Some(category) = category {
@@ -1,13 +1,16 @@
 use crate::execute::TraversalMode;
-use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
+use crate::execute::diagnostics::{InvalidRewriteDiagnostic, ResultExt, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
 use biome_diagnostics::{DiagnosticExt, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_grit_patterns::{GritTargetLanguage, JsTargetLanguage};
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::DocumentFileSource;
 use biome_service::workspace::PatternId;
+use camino::Utf8Path;
 
 pub(crate) fn search<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
@@ -29,14 +32,14 @@ pub(crate) fn search<'ctx>(
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
 ) -> FileResult {
     let _ = tracing::info_span!("Search ", path =? workspace_file.path).entered();
 
     let file_source = DocumentFileSource::from_path(workspace_file.path.as_path());
-    let pattern_language = match &_ctx.execution.traversal_mode {
+    let pattern_language = match &ctx.execution.traversal_mode {
         TraversalMode::Search {
             language: Some(pattern_language),
             ..
@@ -62,18 +65,55 @@ pub(crate) fn search_with_guard<'ctx>(
     let file_name = workspace_file.path.to_string();
     let matches_len = result.matches.len();
 
+    // Don't let a rewrite break the code
+    let rewritten = result.rewritten.filter(|output| output != &input);
+    let is_invalid_rewrite = rewritten.as_ref().is_some_and(|output| {
+        has_syntax_errors(pattern_language, output, workspace_file.path.as_path())
+    });
+
     let search_results = Message::Diagnostics {
-        file_path: file_name,
-        content: input,
+        file_path: file_name.clone(),
+        content: input.clone(),
         diagnostics: result
             .matches
             .into_iter()
             .map(|mat| SearchDiagnostic.with_file_span(mat))
+            .chain(is_invalid_rewrite.then(|| InvalidRewriteDiagnostic.into()))
             .collect(),
         skipped_diagnostics: 0,
     };
 
-    Ok(FileStatus::SearchResult(matches_len, search_results))
+    match rewritten {
+        Some(output) if !is_invalid_rewrite => {
+            ctx.increment_matches(matches_len);
+            ctx.push_message(search_results);
+            if ctx.execution.should_write() {
+                workspace_file.update_file(output)?;
+                Ok(FileStatus::Changed)
+            } else {
+                Ok(FileStatus::Message(Message::Diff {
+                    file_name,
+                    old: input,
+                    new: output,
+                    diff_kind: DiffKind::Rewrite,
+                }))
+            }
+        }
+        _ => Ok(FileStatus::SearchResult(matches_len, search_results)),
+    }
+}
+
+/// Returns whether `content`, the content of the file at `path` after a
+/// rewrite, has syntax errors in the target language of the pattern.
+pub(crate) fn has_syntax_errors(
+    pattern_language: &GritTargetLanguage,
+    content: &str,
+    path: &Utf8Path,
+) -> bool {
+    pattern_language
+        .get_parser()
+        .parse_with_path(content, path)
+        .has_errors()
 }
 
 fn is_file_compatible_with_pattern(
@@ -85,5 +125,14 @@ fn is_file_compatible_with_pattern(
         GritTargetLanguage::CssTargetLanguage(_) => {
             matches!(file_source, DocumentFileSource::Css(_))
         }
+        GritTargetLanguage::GraphqlTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Graphql(_))
+        }
+        GritTargetLanguage::HtmlTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Html(_))
+        }
+        GritTargetLanguage::JsonTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Json(_))
+        }
     }
 }
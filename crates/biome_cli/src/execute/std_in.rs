@@ -2,18 +2,20 @@
 //!
 use crate::diagnostics::StdinDiagnostic;
 use crate::execute::Execution;
+use crate::execute::process_file::search::has_syntax_errors;
 use crate::{CliDiagnostic, CliSession, TraversalMode};
 use biome_analyze::RuleCategoriesBuilder;
 use biome_console::{ConsoleExt, markup};
 use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
 use biome_fs::BiomePath;
+use biome_grit_patterns::{GritTargetLanguage, JsTargetLanguage};
 use biome_service::WorkspaceError;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use biome_service::projects::ProjectKey;
 use biome_service::workspace::{
     ChangeFileParams, CloseFileParams, DropPatternParams, FeaturesBuilder, FileContent,
-    FixFileParams, FormatFileParams, OpenFileParams, SupportsFeatureParams,
+    FixFileParams, FormatFileParams, OpenFileParams, SearchPatternParams, SupportsFeatureParams,
 };
 use std::borrow::Cow;
 
@@ -67,7 +69,6 @@ pub(crate) fn run<'a>(
             let code = printed.into_code();
             let output = match biome_path.extension() {
                 Some("astro") => AstroFileHandler::output(content, code.as_str()),
-                Some("vue") => VueFileHandler::output(content, code.as_str()),
                 Some("svelte") => SvelteFileHandler::output(content, code.as_str()),
                 _ => code,
             };
@@ -154,7 +155,6 @@ pub(crate) fn run<'a>(
                 let code = fix_file_result.code;
                 let output = match biome_path.extension() {
                     Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                    Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
                     Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
                     _ => code,
                 };
@@ -179,7 +179,6 @@ pub(crate) fn run<'a>(
             let code = printed.into_code();
             let output = match biome_path.extension() {
                 Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
                 Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
                 _ => code,
             };
@@ -210,13 +209,56 @@ pub(crate) fn run<'a>(
             project_key,
             path: biome_path.clone(),
         })?;
-    } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+    } else if let TraversalMode::Search {
+        pattern,
+        language,
+        write,
+        ..
+    } = mode.traversal_mode()
+    {
+        let result = if *write {
+            workspace.open_file(OpenFileParams {
+                project_key,
+                path: biome_path.clone(),
+                content: FileContent::from_client(content),
+                document_file_source: None,
+                persist_node_cache: false,
+            })?;
+            let result = workspace.search_pattern(SearchPatternParams {
+                project_key,
+                path: biome_path.clone(),
+                pattern: pattern.clone(),
+            });
+            workspace.close_file(CloseFileParams {
+                project_key,
+                path: biome_path.clone(),
+            })?;
+            Some(result)
+        } else {
+            None
+        };
+
         // Make sure patterns are always cleaned up at the end of execution.
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
             pattern: pattern.clone(),
         });
 
-        console.append(markup! {{content}});
+        match result.transpose()?.and_then(|result| result.rewritten) {
+            Some(rewritten)
+                if has_syntax_errors(
+                    language
+                        .as_ref()
+                        .unwrap_or(&GritTargetLanguage::JsTargetLanguage(JsTargetLanguage)),
+                    &rewritten,
+                    biome_path.as_path(),
+                ) =>
+            {
+                console.append(markup! {{content}});
+                return Err(StdinDiagnostic::new_invalid_rewrite().into());
+            }
+            Some(rewritten) => console.append(markup! {{rewritten}}),
+            None => console.append(markup! {{content}}),
+        }
     } else {
         console.append(markup! {{content}});
     }
@@ -1,8 +1,10 @@
 use super::process_file::{DiffKind, FileStatus, Message, process_file};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
+use crate::execute::cache::AnalysisCache;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic, PanicDiagnostic,
+    SearchDiffDiagnostic,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
@@ -41,6 +43,7 @@ pub(crate) fn traverse(
     project_key: ProjectKey,
     cli_options: &CliOptions,
     mut inputs: Vec<OsString>,
+    cache: Option<AnalysisCache>,
 ) -> Result<TraverseResult, CliDiagnostic> {
     if inputs.is_empty() {
         match &execution.traversal_mode {
@@ -110,6 +113,7 @@ pub(crate) fn traverse(
                 skipped: &skipped,
                 messages: sender,
                 evaluated_paths: RwLock::default(),
+                cache: cache.as_ref(),
             },
         );
         // wait for the main thread to finish
@@ -118,6 +122,10 @@ pub(crate) fn traverse(
         (elapsed, evaluated_paths, diagnostics)
     });
 
+    if let Some(cache) = cache {
+        cache.persist(fs);
+    }
+
     // Make sure patterns are always cleaned up at the end of traversal.
     if let TraversalMode::Search { pattern, .. } = execution.traversal_mode() {
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
@@ -387,14 +395,18 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     diff_kind,
                 } => {
                     let file_path = self.to_relative_file_path(&file_name);
-                    // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    // A diff is an error in CI mode and in format check mode.
+                    // The rewrites of a search are only informative.
+                    let is_error = matches!(diff_kind, DiffKind::Format)
+                        && (self.execution.is_ci() || !self.execution.is_format_write());
                     if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if matches!(diff_kind, DiffKind::Rewrite) {
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -434,6 +446,18 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     diagnostics_to_print.push(diag);
                                 }
                             }
+                            DiffKind::Rewrite => {
+                                diagnostics_to_print.push(
+                                    SearchDiffDiagnostic {
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new,
+                                        },
+                                    }
+                                    .with_file_source_code(old)
+                                    .with_file_path(file_path.to_string()),
+                                );
+                            }
                         }
                     }
                 }
@@ -476,6 +500,8 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     pub(crate) messages: Sender<Message>,
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<BTreeSet<BiomePath>>,
+    /// The cache of the results of previous runs, when enabled
+    pub(crate) cache: Option<&'ctx AnalysisCache>,
 }
 
 impl TraversalOptions<'_, '_> {
@@ -622,6 +648,7 @@ impl TraversalContext for TraversalOptions<'_, '_> {
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
             TraversalMode::Search { .. } => file_features.supports_search(),
+            TraversalMode::Graph { .. } => false,
         }
     }
 
@@ -28,6 +28,7 @@ use crate::commands::CommandRunner;
 use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
+use crate::commands::graph::GraphCommandPayload;
 use crate::commands::lint::LintCommandPayload;
 use crate::commands::migrate::MigrateCommandPayload;
 pub use crate::commands::{BiomeCommand, biome_command};
@@ -91,6 +92,8 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                cache,
             } => run_command(
                 self,
                 &cli_options,
@@ -108,6 +111,8 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
                 },
             ),
             BiomeCommand::Lint {
@@ -127,10 +132,13 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                cache,
                 css_linter,
                 javascript_linter,
                 json_linter,
                 graphql_linter,
+                html_linter,
             } => run_command(
                 self,
                 &cli_options,
@@ -150,10 +158,13 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
                     css_linter,
                     javascript_linter,
                     json_linter,
                     graphql_linter,
+                    html_linter,
                 },
             ),
             BiomeCommand::Ci {
@@ -166,6 +177,8 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                changed_lines,
+                cache,
                 ..
             } => run_command(
                 self,
@@ -179,6 +192,8 @@ impl<'app> CliSession<'app> {
                     paths,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
                 },
             ),
             BiomeCommand::Format {
@@ -195,9 +210,13 @@ impl<'app> CliSession<'app> {
                 css_formatter,
                 graphql_formatter,
                 html_formatter,
+                yaml_formatter,
+                markdown_formatter,
                 staged,
                 changed,
                 since,
+                changed_lines,
+                cache,
             } => run_command(
                 self,
                 &cli_options,
@@ -214,9 +233,13 @@ impl<'app> CliSession<'app> {
                     css_formatter,
                     graphql_formatter,
                     html_formatter,
+                    yaml_formatter,
+                    markdown_formatter,
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
@@ -250,6 +273,7 @@ impl<'app> CliSession<'app> {
                 language,
                 stdin_file_path,
                 vcs_configuration,
+                write,
             } => run_command(
                 self,
                 &cli_options,
@@ -260,6 +284,25 @@ impl<'app> CliSession<'app> {
                     language,
                     stdin_file_path,
                     vcs_configuration,
+                    write,
+                },
+            ),
+            BiomeCommand::Graph {
+                cli_options,
+                format,
+                include,
+                dependencies_of,
+                dependents_of,
+                depth,
+            } => run_command(
+                self,
+                &cli_options,
+                GraphCommandPayload {
+                    format,
+                    include,
+                    dependencies_of,
+                    dependents_of,
+                    depth,
                 },
             ),
             BiomeCommand::RunServer {
@@ -0,0 +1,612 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata,
+    RuleSourceKind,
+};
+use biome_console::fmt::{Display, Formatter, Termcolor};
+use biome_console::{Console, ConsoleExt, markup};
+use biome_css_syntax::CssLanguage;
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::{
+    Error, LogCategory, PrintDescription, Resource, Severity, SourceCode, Visit,
+};
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
+use biome_js_syntax::JsLanguage;
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{Language, TextSize};
+use biome_text_edit::TextEdit;
+use camino::{Utf8Path, Utf8PathBuf};
+use path_absolutize::Absolutize;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub struct SarifReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics: DiagnosticsPayload,
+    pub(crate) verbose: bool,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> std::io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics, self.verbose)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    working_directory: Option<Utf8PathBuf>,
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    pub fn new(console: &'a mut dyn Console, working_directory: Option<Utf8PathBuf>) -> Self {
+        Self {
+            console,
+            working_directory,
+        }
+    }
+}
+
+impl ReporterVisitor for SarifReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _: &Execution,
+        _: TraversalSummary,
+        _verbose: bool,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+        verbose: bool,
+    ) -> std::io::Result<()> {
+        let diagnostics = SarifDiagnostics {
+            payload,
+            path: self.working_directory.as_deref(),
+            verbose,
+        };
+        self.console.log(markup!({ diagnostics }));
+        Ok(())
+    }
+}
+
+struct SarifDiagnostics<'a> {
+    payload: DiagnosticsPayload,
+    verbose: bool,
+    path: Option<&'a Utf8Path>,
+}
+
+impl SarifDiagnostics<'_> {
+    fn attempt_to_relativize(&self, subject: &str) -> Option<Utf8PathBuf> {
+        let Ok(resolved) = Path::new(subject).absolutize() else {
+            return None;
+        };
+
+        let Ok(relativized) = resolved.strip_prefix(self.path?) else {
+            return None;
+        };
+
+        Some(Utf8PathBuf::from_path_buf(relativized.to_path_buf()).expect("To be UTF-8 path"))
+    }
+
+    /// Returns the URI of the file the diagnostic belongs to, relative to the
+    /// working directory when possible.
+    fn artifact_uri(&self, diagnostic: &Error) -> Option<String> {
+        let Some(Resource::File(file)) = diagnostic.location().resource else {
+            return None;
+        };
+
+        let uri = match self.attempt_to_relativize(file) {
+            Some(relativized) => relativized.as_str().to_string(),
+            None => file.to_string(),
+        };
+        // SARIF expects URIs, which always use forward slashes
+        Some(uri.replace('\\', "/"))
+    }
+}
+
+impl Display for SarifDiagnostics<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        let Self {
+            payload, verbose, ..
+        } = self;
+
+        let diagnostics: Vec<_> = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| {
+                if diagnostic.tags().is_verbose() {
+                    *verbose
+                } else {
+                    true
+                }
+            })
+            .collect();
+
+        // Only the rules that emitted a diagnostic are listed in the report, sorted
+        // by name so that the `ruleIndex` of the results is stable
+        let categories: BTreeMap<&str, _> = diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.category())
+            .map(|category| (category.name(), category))
+            .collect();
+        let rule_ids: HashMap<&str, usize> = categories
+            .keys()
+            .enumerate()
+            .map(|(index, id)| (*id, index))
+            .collect();
+
+        let rules_metadata = RulesMetadata::new();
+        let rules = categories
+            .into_iter()
+            .map(|(id, category)| {
+                ReportingDescriptor::new(id, category.link(), rules_metadata.get(id))
+            })
+            .collect();
+
+        let results = diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                let rule_id = diagnostic.category().map(|category| category.name());
+                SarifResult {
+                    rule_id,
+                    rule_index: rule_id.and_then(|id| rule_ids.get(id).copied()),
+                    level: to_level(diagnostic.severity()),
+                    message: Message {
+                        text: PrintDescription(diagnostic).to_string(),
+                    },
+                    locations: self
+                        .artifact_uri(diagnostic)
+                        .map(|uri| {
+                            vec![SarifLocation {
+                                physical_location: PhysicalLocation {
+                                    region: diagnostic_region(diagnostic),
+                                    artifact_location: ArtifactLocation { uri },
+                                },
+                            }]
+                        })
+                        .unwrap_or_default(),
+                    fixes: self
+                        .artifact_uri(diagnostic)
+                        .map(|uri| collect_fixes(diagnostic, uri))
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results,
+            }],
+        };
+
+        let serialized = serde_json::to_string_pretty(&log)?;
+        fmt.write_str(serialized.as_str())?;
+        Ok(())
+    }
+}
+
+fn to_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Hint | Severity::Information => "note",
+        Severity::Warning => "warning",
+        Severity::Error | Severity::Fatal => "error",
+    }
+}
+
+/// Computes the region of the source code highlighted by the diagnostic.
+fn diagnostic_region(diagnostic: &Error) -> Option<Region> {
+    let location = diagnostic.location();
+    let span = location.span?;
+    let source_code = location.source_code?;
+    Region::from_offsets(source_code, span.start(), span.end())
+}
+
+/// Extracts the code suggestions attached to the diagnostic and converts them
+/// into SARIF fixes.
+fn collect_fixes(diagnostic: &Error, uri: String) -> Vec<Fix> {
+    let Some(source_code) = diagnostic.location().source_code else {
+        return Vec::new();
+    };
+
+    let mut visitor = FixVisitor {
+        source_code,
+        uri,
+        last_message: None,
+        fixes: Vec::new(),
+    };
+    // Failing to collect the fixes shouldn't prevent the diagnostic from being reported
+    let _ = diagnostic.advices(&mut visitor);
+    visitor.fixes
+}
+
+/// Visits the advices of a diagnostic, collecting every diff as a [Fix].
+///
+/// The message logged right before a diff, e.g. "Safe fix: Remove debugger
+/// statement", is used as the description of the fix.
+struct FixVisitor<'a> {
+    source_code: SourceCode<&'a str, &'a biome_diagnostics::LineIndex>,
+    uri: String,
+    last_message: Option<String>,
+    fixes: Vec<Fix>,
+}
+
+impl Visit for FixVisitor<'_> {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn Display) -> std::io::Result<()> {
+        let mut buffer = Vec::new();
+        let mut writer = Termcolor(NoColor::new(&mut buffer));
+        Formatter::new(&mut writer).write_markup(markup!({ text }))?;
+        self.last_message = Some(String::from_utf8_lossy(&buffer).into_owned());
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> std::io::Result<()> {
+        let description = self.last_message.take().map(|text| Message { text });
+
+        let old = self.source_code.text;
+        let new = diff.new_string(old);
+
+        // The diff spans the whole file, so we only keep the section that changed
+        let prefix = old
+            .char_indices()
+            .zip(new.chars())
+            .find(|((_, old_char), new_char)| old_char != new_char)
+            .map_or(old.len().min(new.len()), |((index, _), _)| index);
+        let suffix: usize = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(old_char, new_char)| old_char == new_char)
+            .map(|(old_char, _)| old_char.len_utf8())
+            .sum();
+
+        let start = TextSize::from(prefix as u32);
+        let end = TextSize::from((old.len() - suffix) as u32);
+        let Some(deleted_region) = Region::from_offsets(self.source_code, start, end) else {
+            return Ok(());
+        };
+
+        self.fixes.push(Fix {
+            description,
+            artifact_changes: vec![ArtifactChange {
+                artifact_location: ArtifactLocation {
+                    uri: self.uri.clone(),
+                },
+                replacements: vec![Replacement {
+                    deleted_region,
+                    inserted_content: ArtifactContent {
+                        text: new[prefix..new.len() - suffix].to_string(),
+                    },
+                }],
+            }],
+        });
+
+        Ok(())
+    }
+}
+
+/// Collects the metadata of the rules of all the analyzers, indexed by the
+/// category of the diagnostics they emit, e.g. `lint/suspicious/noDebugger`.
+struct RulesMetadata {
+    rules: HashMap<String, RuleMetadata>,
+}
+
+impl RulesMetadata {
+    fn new() -> Self {
+        let mut visitor = Self {
+            rules: HashMap::new(),
+        };
+
+        biome_graphql_analyze::visit_registry(&mut visitor);
+        biome_html_analyze::visit_registry(&mut visitor);
+        biome_css_analyze::visit_registry(&mut visitor);
+        biome_json_analyze::visit_registry(&mut visitor);
+        biome_js_analyze::visit_registry(&mut visitor);
+
+        visitor
+    }
+
+    fn get(&self, category: &str) -> Option<&RuleMetadata> {
+        self.rules.get(category)
+    }
+
+    fn store_rule<R, L>(&mut self)
+    where
+        L: Language,
+        R: Rule<Options: Default, Query: Queryable<Language = L, Output: Clone>> + 'static,
+    {
+        let category = <<R::Group as RuleGroup>::Category as GroupCategory>::CATEGORY;
+        if matches!(category, RuleCategory::Lint | RuleCategory::Action) {
+            let id = format!(
+                "{}/{}/{}",
+                category.as_suppression_category(),
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name
+            );
+            self.rules.insert(id, R::METADATA);
+        }
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for RulesMetadata {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
+    {
+        self.store_rule::<R, JsLanguage>();
+    }
+}
+
+impl RegistryVisitor<JsonLanguage> for RulesMetadata {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = JsonLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, JsonLanguage>();
+    }
+}
+
+impl RegistryVisitor<CssLanguage> for RulesMetadata {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = CssLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, CssLanguage>();
+    }
+}
+
+impl RegistryVisitor<GraphqlLanguage> for RulesMetadata {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = GraphqlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, GraphqlLanguage>();
+    }
+}
+
+impl RegistryVisitor<HtmlLanguage> for RulesMetadata {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, HtmlLanguage>();
+    }
+}
+
+/// The root of a SARIF report.
+/// See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    /// How the columns of the regions are counted. SARIF defaults to UTF-16
+    /// code units, while the columns of Biome count Unicode code points.
+    column_kind: &'static str,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<ReportingDescriptor<'a>>,
+}
+
+/// The description of a rule, or of any other check that emitted a diagnostic.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor<'a> {
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`.
+    id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_configuration: Option<ReportingConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<RuleProperties>,
+}
+
+impl<'a> ReportingDescriptor<'a> {
+    fn new(id: &'a str, help_uri: Option<&'static str>, metadata: Option<&RuleMetadata>) -> Self {
+        let Some(metadata) = metadata else {
+            return Self {
+                id,
+                name: None,
+                short_description: None,
+                help_uri,
+                default_configuration: None,
+                properties: None,
+            };
+        };
+
+        let short_description = metadata.docs.trim().lines().next().map(|line| Message {
+            text: line.to_string(),
+        });
+        let sources = metadata
+            .sources
+            .iter()
+            .map(|source| RuleSourceProperty {
+                name: source.source.to_namespaced_rule_name(),
+                url: source.source.to_rule_url(),
+                kind: match source.kind {
+                    RuleSourceKind::SameLogic => "sameLogic",
+                    RuleSourceKind::Inspired => "inspired",
+                },
+            })
+            .collect();
+
+        Self {
+            id,
+            name: Some(metadata.name),
+            short_description,
+            help_uri,
+            default_configuration: Some(ReportingConfiguration {
+                level: to_level(metadata.severity),
+            }),
+            properties: Some(RuleProperties {
+                recommended: metadata.recommended,
+                sources,
+            }),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ReportingConfiguration {
+    level: &'static str,
+}
+
+/// Biome specific information about a rule.
+#[derive(Serialize)]
+struct RuleProperties {
+    recommended: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<RuleSourceProperty>,
+}
+
+/// A rule, from another tool, that a Biome rule is based on.
+#[derive(Serialize)]
+struct RuleSourceProperty {
+    name: String,
+    url: String,
+    kind: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: Message,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+/// A region of a file. Lines and columns are 1-based.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl Region {
+    fn from_offsets(
+        source_code: SourceCode<&str, &biome_diagnostics::LineIndex>,
+        start: TextSize,
+        end: TextSize,
+    ) -> Option<Self> {
+        let source_file = SourceFile::new(source_code);
+        let start = source_file.location(start).ok()?;
+        let end = source_file.location(end).ok()?;
+
+        Some(Self {
+            start_line: start.line_number.get(),
+            start_column: start.column_number.get(),
+            end_line: end.line_number.get(),
+            end_column: end.column_number.get(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Message>,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
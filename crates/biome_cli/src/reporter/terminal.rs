@@ -123,7 +123,9 @@ impl ReporterVisitor for ConsoleReporterVisitor<'_> {
         verbose: bool,
     ) -> io::Result<()> {
         for diagnostic in &diagnostics_payload.diagnostics {
-            if execution.is_search() {
+            // Matches are printed as bare code frames. Diagnostics without a
+            // span, such as the changes of a rewrite, are printed normally.
+            if execution.is_search() && diagnostic.location().span.is_some() {
                 self.0.log(markup! {{PrintDiagnostic::search(diagnostic)}});
                 continue;
             }
@@ -161,8 +163,19 @@ struct SummaryDetail<'a>(pub(crate) &'a TraversalMode, usize);
 impl fmt::Display for SummaryDetail<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         let Self(mode, files) = self;
-        if let TraversalMode::Search { .. } = mode {
-            return Ok(());
+        if let TraversalMode::Search { write, .. } = mode {
+            if !write {
+                return Ok(());
+            }
+            return if *files > 0 {
+                fmt.write_markup(markup! {
+                    " Rewrote "{Files(*files)}"."
+                })
+            } else {
+                fmt.write_markup(markup! {
+                    " No rewrites applied."
+                })
+            };
         }
 
         if *files > 0 {
@@ -226,6 +239,10 @@ impl fmt::Display for SummaryTotal<'_> {
             TraversalMode::Search { .. } => fmt.write_markup(markup! {
                 "Searched "{files}" in "{duration}"."
             }),
+
+            TraversalMode::Graph { .. } => fmt.write_markup(markup! {
+                "Printed the module graph in "{duration}"."
+            }),
         }
     }
 }
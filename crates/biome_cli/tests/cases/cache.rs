@@ -0,0 +1,222 @@
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use crate::{run_cli, run_cli_with_server_workspace};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CACHE_FILE: &str = "node_modules/.cache/biome/lint.json";
+
+#[test]
+fn lint_replays_cached_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut BufferConsole::default(),
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let cache = fs
+        .read_file_from_path(Utf8Path::new(CACHE_FILE))
+        .expect("the cache to be persisted");
+    assert!(cache.contains("file.js"), "the file to be cached: {cache}");
+    assert!(
+        !cache.contains(r#""content":"debugger;"#),
+        "the content of the file shouldn't be cached: {cache}"
+    );
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_replays_cached_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_invalidates_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut BufferConsole::default(),
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), "let a = 1;\na == 1;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_invalidates_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_doesnt_persist_cache_by_default() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut BufferConsole::default(),
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert!(
+        fs.read_file_from_path(Utf8Path::new(CACHE_FILE)).is_err(),
+        "the cache shouldn't be persisted"
+    );
+}
+
+#[test]
+fn lint_invalidates_files_whose_importers_changed() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "nursery": { "noUnusedExports": "error" } } } }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("package.json").into(),
+        r#"{ "main": "./src/index.js" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/index.js").into(),
+        "import { used } from \"./b.js\";\nused;\n".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/b.js").into(),
+        "export const used = 1;\nexport const bUnused = 2;\n".as_bytes(),
+    );
+
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut BufferConsole::default(),
+        Args::from(["lint", "--cache", "src"].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(
+        Utf8Path::new("src/index.js").into(),
+        "import { used, bUnused } from \"./b.js\";\nused;\nbUnused;\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", "src"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_invalidates_files_whose_importers_changed",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_keeps_unrelated_files_cached() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "nursery": { "noImportCycles": "error" } } } }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/a.js").into(),
+        "import { b } from \"./b.js\";\ndebugger;\nb;\n".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/b.js").into(),
+        "export const b = 1;\ndebugger;\n".as_bytes(),
+    );
+    fs.insert(Utf8Path::new("src/c.js").into(), "debugger;\n".as_bytes());
+    fs.insert(
+        Utf8Path::new("src/d.js").into(),
+        "export const d = 1;\n".as_bytes(),
+    );
+
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut BufferConsole::default(),
+        Args::from(["lint", "--cache", "src"].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // Alter the cached diagnostics, so that the replayed ones can be told
+    // apart from the ones emitted by processing the file again.
+    let cache = fs
+        .read_file_from_path(Utf8Path::new(CACHE_FILE))
+        .expect("the cache to be persisted");
+    fs.insert(
+        Utf8Path::new(CACHE_FILE).into(),
+        cache
+            .replace("an unexpected use", "a cached use")
+            .as_bytes(),
+    );
+
+    // Changing the imports of a module doesn't affect the modules it isn't
+    // connected to.
+    fs.insert(
+        Utf8Path::new("src/c.js").into(),
+        "import { d } from \"./d.js\";\ndebugger;\nd;\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", "src"].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_keeps_unrelated_files_cached",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,5 +1,5 @@
-use crate::run_cli;
 use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use crate::{run_cli, run_cli_with_server_workspace};
 use biome_console::BufferConsole;
 use biome_formatter::LineWidth;
 use biome_fs::MemoryFileSystem;
@@ -550,3 +550,152 @@ fn extends_config_merge_overrides() {
         result,
     ));
 }
+
+#[test]
+fn extends_config_preset_from_npm_package_with_plugins() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = Utf8Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{ "extends": ["@acme/biome-config/react"] }"#,
+    );
+
+    fs.insert(
+        "node_modules/@acme/biome-config/package.json".into(),
+        r#"{
+    "name": "@acme/biome-config",
+    "exports": {
+        ".": "./biome.json",
+        "./react": "./react.json"
+    }
+}"#,
+    );
+    fs.insert(
+        "node_modules/@acme/biome-config/biome.json".into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+    );
+    fs.insert(
+        "node_modules/@acme/biome-config/react.json".into(),
+        r#"{
+    "extends": ["./biome.json"],
+    "plugins": ["./rules/useObjectSpread.grit"]
+}"#,
+    );
+    fs.insert(
+        "node_modules/@acme/biome-config/rules/useObjectSpread.grit".into(),
+        r#"`Object.assign({}, $args)` as $call where {
+    register_diagnostic(span = $call, message = "Prefer object spread instead of `Object.assign()`.")
+}"#,
+    );
+
+    let test_file = Utf8Path::new("test.js");
+    fs.insert(
+        test_file.into(),
+        "debugger;\nexport const a = Object.assign({}, b);\n",
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", test_file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_config_preset_from_npm_package_with_plugins",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_config_from_npm_package_that_extends_its_dependency() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = Utf8Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{ "extends": ["@acme/biome-config"] }"#,
+    );
+
+    fs.insert(
+        "node_modules/@acme/biome-config/package.json".into(),
+        r#"{
+    "name": "@acme/biome-config",
+    "exports": { ".": "./biome.json" }
+}"#,
+    );
+    fs.insert(
+        "node_modules/@acme/biome-config/biome.json".into(),
+        r#"{
+    "extends": ["@acme/base-config"],
+    "formatter": { "indentStyle": "space" }
+}"#,
+    );
+    fs.insert(
+        "node_modules/@acme/biome-config/node_modules/@acme/base-config/package.json".into(),
+        r#"{
+    "name": "@acme/base-config",
+    "exports": { ".": "./biome.json" }
+}"#,
+    );
+    fs.insert(
+        "node_modules/@acme/biome-config/node_modules/@acme/base-config/biome.json".into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+
+    let test_file = Utf8Path::new("test.js");
+    fs.insert(test_file.into(), "if (a) {\n\tb(\"c\");\n}\n");
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", test_file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_config_from_npm_package_that_extends_its_dependency",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_should_raise_an_error_for_circular_extends() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = Utf8Path::new("biome.json");
+    fs.insert(biome_json.into(), r#"{ "extends": ["./a.json"] }"#);
+    fs.insert("a.json".into(), r#"{ "extends": ["./b.json"] }"#);
+    fs.insert("b.json".into(), r#"{ "extends": ["./a.json"] }"#);
+
+    let test_file = Utf8Path::new("test.js");
+    fs.insert(test_file.into(), "debugger;\n");
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", test_file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_circular_extends",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,282 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"const Button = styled.button`
+color:red;
+    ${mixin}
+  &:hover { background : ${(props) => props.background} }
+  margin-${side}: 0
+`;
+
+const fadeIn = keyframes`from{opacity:0} to {opacity:1}`;
+
+const GlobalStyle = createGlobalStyle`body{margin:0}`;
+
+const query = gql`
+  query   User { user(id: 1) { ...UserFields } }
+  ${USER_FIELDS}
+`;
+
+const invalid = css`color: red; }{`;
+"#;
+
+const FORMATTED: &str = r#"const Button = styled.button`
+  color: red;
+  ${mixin}
+  &:hover {
+    background: ${(props) => props.background};
+  }
+  margin-${side}: 0;
+`;
+
+const fadeIn = keyframes`
+  from {
+    opacity: 0;
+  }
+  to {
+    opacity: 1;
+  }
+`;
+
+const GlobalStyle = createGlobalStyle`
+  body {
+    margin: 0;
+  }
+`;
+
+const query = gql`
+  query User {
+    user(id: 1) {
+      ...UserFields
+    }
+  }
+  ${USER_FIELDS}
+`;
+
+const invalid = css`color: red; }{`;
+"#;
+
+#[test]
+fn should_not_format_embedded_templates_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_not_format_embedded_templates_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_embedded_templates_when_enabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "formatter": { "indentStyle": "space" },
+    "javascript": { "embeddedTemplates": { "enabled": true } }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_embedded_templates_when_enabled",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_embedded_templates_with_configured_tags() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "formatter": { "indentStyle": "space" },
+    "javascript": {
+        "embeddedTemplates": {
+            "enabled": true,
+            "cssTags": ["tw"],
+            "graphqlTags": ["schema"]
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"const Card = tw.div`display:block`;
+const typeDefs = schema`type User{id:ID!}`;
+const unknown = html`<p>${text}</p>`;
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"const Card = tw.div`
+  display: block;
+`;
+const typeDefs = schema`
+  type User {
+    id: ID!
+  }
+`;
+const unknown = html`<p>${text}</p>`;
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_embedded_templates_with_configured_tags",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_embedded_templates() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "javascript": { "embeddedTemplates": { "enabled": true } },
+    "linter": { "rules": { "correctness": { "noUnusedVariables": "off" } } }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"const Button = styled.button`
+  colr: ${(props) => props.color};
+  ${mixin}
+  margin: 0;
+`;
+
+const Title = styled.h1`
+  font-size: ${size}px;
+  colr: red;
+`;
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_embedded_templates",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_embedded_templates_with_secondary_labels() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "javascript": { "embeddedTemplates": { "enabled": true } },
+    "linter": { "rules": { "correctness": { "noUnusedVariables": "off" } } }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"const x = "x";
+const Button = styled.button`
+  color: red;
+  margin: ${margin};
+  color: blue;
+`;
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_embedded_templates_with_secondary_labels",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,5 +1,5 @@
-use crate::run_cli;
 use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use crate::{run_cli, run_cli_with_server_workspace};
 use biome_console::BufferConsole;
 use biome_fs::MemoryFileSystem;
 use bpaf::Args;
@@ -103,3 +103,93 @@ fn lint_single_rule() {
         result,
     ));
 }
+
+#[test]
+fn lint_against_configured_schema() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "graphql": {
+        "schema": ["schema.graphql"]
+    },
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUnknownFields": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("schema.graphql").into(),
+        "type Query {\n  me: User\n}\n\ntype User {\n  id: ID\n  name: String\n}\n".as_bytes(),
+    );
+    let file_path = Utf8Path::new("query.graphql");
+    fs.insert(
+        file_path.into(),
+        "query Me {\n  me {\n    id\n    email\n  }\n}\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_against_configured_schema",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_introspection_and_sdl_schema() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "graphql": {
+        "schema": ["schema.graphql", "introspection.json"]
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("schema.graphql").into(),
+        "type Query {\n  me: String\n}\n".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("introspection.json").into(),
+        r#"{ "__schema": { "types": [] } }"#.as_bytes(),
+    );
+    let file_path = Utf8Path::new("query.graphql");
+    fs.insert(file_path.into(), "query Me {\n  me\n}\n".as_bytes());
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_introspection_and_sdl_schema",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -106,6 +106,72 @@ const slots = defineSlots<{
 </script>
 <template></template>"#;
 
+const VUE_MULTIPLE_SCRIPT_BLOCKS_UNFORMATTED: &str = r#"<script lang="ts">
+export default   {    inheritAttrs   :   false }
+</script>
+
+<script setup lang="ts">
+const   count   =   ref(0)
+</script>
+
+<template>
+  <button @click="count++">{{ count }}</button>
+</template>"#;
+
+const VUE_MULTIPLE_SCRIPT_BLOCKS_FORMATTED: &str = r#"<script lang="ts">
+export default { inheritAttrs: false };
+</script>
+
+<script setup lang="ts">
+const count = ref(0);
+</script>
+
+<template>
+  <button @click="count++">{{ count }}</button>
+</template>"#;
+
+const VUE_MULTIPLE_SCRIPT_BLOCKS_NOT_LINTED: &str = r#"<script lang="ts">
+import { sharedState } from "./state";
+
+export default {
+    inheritAttrs: false,
+};
+</script>
+
+<script setup lang="ts">
+if (sharedState == null) {
+    debugger;
+}
+</script>
+
+<template></template>"#;
+
+const VUE_TEMPLATE_REFERENCES: &str = r#"<script setup lang="ts">
+import { ref } from "vue";
+import MyButton from "./MyButton.vue";
+import TheFooter from "./TheFooter.vue";
+import { vFocus } from "./directives";
+import { formatDate } from "./utils";
+
+const count = ref(0);
+const items = ref([]);
+const visible = ref(true);
+const title = "Hello";
+function increment() {
+    count.value++;
+}
+const unused = 1;
+</script>
+
+<template>
+  <h1 :title="title">{{ formatDate(new Date()) }}</h1>
+  <MyButton v-if="visible" @click="increment" v-focus>{{ count }}</MyButton>
+  <ul>
+    <li v-for="item in items" :key="item">{{ item }}</li>
+  </ul>
+  <the-footer />
+</template>"#;
+
 #[test]
 fn format_vue_implicit_js_files() {
     let mut fs = MemoryFileSystem::default();
@@ -753,3 +819,123 @@ fn vue_compiler_macros_as_globals() {
         result,
     ));
 }
+
+#[test]
+fn format_vue_multiple_script_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_MULTIPLE_SCRIPT_BLOCKS_UNFORMATTED.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", vue_file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_MULTIPLE_SCRIPT_BLOCKS_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_multiple_script_blocks",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_multiple_script_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_MULTIPLE_SCRIPT_BLOCKS_NOT_LINTED.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", vue_file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_multiple_script_blocks",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_script_block_with_secondary_labels() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        r#"<template>
+  <div>{{ a }}</div>
+</template>
+
+<script setup>
+let a = 1;
+let a = 2;
+</script>
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", vue_file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_script_block_with_secondary_labels",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_template_references() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(vue_file_path.into(), VUE_TEMPLATE_REFERENCES.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", vue_file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_template_references",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,314 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const MISSING_ALT: &str = r#"<div>
+    <img src="image.png">
+</div>
+"#;
+
+const MISSING_ALT_SUPPRESSED: &str = r#"<div>
+    <!-- biome-ignore lint/a11y/useAltText: ignored using `--suppress` -->
+    <img src="image.png">
+</div>
+"#;
+
+const POSITIVE_TABINDEX: &str = r#"<span tabindex="5">foo</span>
+"#;
+
+const POSITIVE_TABINDEX_FIXED: &str = r#"<span tabindex="0">foo</span>
+"#;
+
+const LINTER_ENABLED: &str = r#"{
+    "html": {
+        "linter": {
+            "enabled": true
+        }
+    }
+}"#;
+
+#[test]
+fn does_not_lint_html_files_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), MISSING_ALT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_lint_html_files_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_html_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), MISSING_ALT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--html-linter-enabled=true", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_html_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_and_fix_html_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(config_path.into(), LINTER_ENABLED.as_bytes());
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), POSITIVE_TABINDEX.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write", "--unsafe", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, POSITIVE_TABINDEX_FIXED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_and_fix_html_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_html_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(config_path.into(), LINTER_ENABLED.as_bytes());
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), MISSING_ALT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--suppress", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, MISSING_ALT_SUPPRESSED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_html_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+const SCRIPT_AND_STYLE: &str = r#"<html>
+<head>
+<style>
+body{margin:0}
+</style>
+<script type="application/ld+json">
+{"@context":"https://schema.org","@type":"Person","name":"Jane"}
+</script>
+</head>
+<body>
+<script type="module">
+import {a} from "./a.js"
+const message = `line one
+line two`
+a( message )
+</script>
+<script>let invalid = ;</script>
+</body>
+</html>
+"#;
+
+#[test]
+fn format_script_and_style_elements() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "formatter": { "indentStyle": "space" },
+    "html": { "formatter": { "enabled": true } }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), SCRIPT_AND_STYLE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_script_and_style_elements",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_script_and_style_elements_with_indent() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "formatter": { "indentStyle": "space" },
+    "html": { "formatter": { "enabled": true, "indentScriptAndStyle": true } }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), SCRIPT_AND_STYLE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_script_and_style_elements_with_indent",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_script_and_style_elements() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(config_path.into(), LINTER_ENABLED.as_bytes());
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(
+        file_path.into(),
+        r#"<style>
+a { colr: red; }
+</style>
+<script>
+debugger;
+</script>
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_script_and_style_elements",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_script_and_style_elements_with_secondary_labels() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(config_path.into(), LINTER_ENABLED.as_bytes());
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(
+        file_path.into(),
+        r#"<!doctype html>
+<html>
+<head>
+<style>
+a { color: red; color: blue; }
+</style>
+<script>
+let a = 1;
+let a = 2;
+</script>
+</head>
+</html>
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_script_and_style_elements_with_secondary_labels",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,186 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"Biome
+=====
+
+*   Format
+*   Lint
+    +   JavaScript
+    +   CSS
+
+```js
+const answer   =  {value:42}
+```
+
+```json
+{"extends":["./base.json"]}
+```
+
+```rust
+fn main()   {}
+```
+"#;
+
+const FORMATTED: &str = r#"# Biome
+
+- Format
+- Lint
+  - JavaScript
+  - CSS
+
+```js
+const answer = { value: 42 };
+```
+
+```json
+{ "extends": ["./base.json"] }
+```
+
+```rust
+fn main()   {}
+```
+"#;
+
+#[test]
+fn should_not_format_markdown_files_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("README.md");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", file_path.as_str()].as_slice()),
+    );
+
+    // no files processed error
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_not_format_markdown_files_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_markdown_files_when_opt_in() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("README.md");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--markdown-formatter-enabled=true",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_markdown_files_when_opt_in",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_and_write_markdown_files_with_code_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{ "markdown": { "formatter": { "enabled": true } } }"#.as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("docs/guide.markdown");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_and_write_markdown_files_with_code_blocks",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_markdown_code_blocks_with_language_options() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "markdown": { "formatter": { "enabled": true } },
+    "javascript": { "formatter": { "quoteStyle": "single", "semicolons": "asNeeded" } }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("README.md");
+    fs.insert(
+        file_path.into(),
+        "```ts\nconst name: string = \"biome\";\n```\n\n```css\na{color:red}\n```\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "```ts\nconst name: string = 'biome'\n```\n\n```css\na {\n\tcolor: red;\n}\n```\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_markdown_code_blocks_with_language_options",
+        fs,
+        console,
+        result,
+    ));
+}
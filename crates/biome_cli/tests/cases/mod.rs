@@ -3,19 +3,23 @@
 
 mod assist;
 mod biome_json_support;
+mod cache;
 mod config_extends;
 mod config_path;
 mod cts_files;
 mod diagnostics;
 mod editorconfig;
+mod embedded_templates;
 mod graphql;
 mod handle_astro_files;
 mod handle_css_files;
 mod handle_svelte_files;
 mod handle_vue_files;
+mod html;
 mod included_files;
 mod linter_domains;
 mod linter_groups_plain;
+mod markdown;
 mod migrate_v2;
 mod monorepo;
 mod overrides_formatter;
@@ -26,9 +30,11 @@ mod protected_files;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
+mod reporter_sarif;
 mod reporter_summary;
 mod reporter_terminal;
 mod rules_via_dependencies;
 mod suppressions;
 mod unknown_files;
 mod vcs_ignored_files;
+mod yaml;
@@ -0,0 +1,123 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const UNFORMATTED: &str = r#"name:   CI
+on:
+    push:
+        branches: [main,   next]
+jobs:
+    test:
+        steps:
+        -   uses: actions/checkout@v4
+        -   run: cargo test # run the tests
+"#;
+
+const FORMATTED: &str = r#"name: CI
+on:
+  push:
+    branches: [main, next]
+jobs:
+  test:
+    steps:
+      - uses: actions/checkout@v4
+      - run: cargo test # run the tests
+"#;
+
+#[test]
+fn should_not_format_yaml_files_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("ci.yml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", file_path.as_str()].as_slice()),
+    );
+
+    // no files processed error
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_not_format_yaml_files_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_yaml_files_when_opt_in() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("ci.yml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--yaml-formatter-enabled=true",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_yaml_files_when_opt_in",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_and_write_yaml_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Utf8Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{ "yaml": { "formatter": { "enabled": true } } }"#.as_bytes(),
+    );
+
+    let file_path = Utf8Path::new("manifest.yaml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_and_write_yaml_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2954,6 +2954,86 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
     ));
 }
 
+#[test]
+fn should_only_check_changed_lines_when_changed_lines_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_staged_diff(Box::new(|| {
+        String::from(
+            r#"diff --git a/file.js b/file.js
+--- a/file.js
++++ b/file.js
+@@ -1,0 +2 @@ debugger;
++let a   =   1;
+"#,
+        )
+    }));
+
+    fs.insert(
+        Utf8Path::new("file.js").into(),
+        "debugger;\nlet a   =   1;\nlet b   =   2;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--staged", "--changed-lines"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_check_changed_lines_when_changed_lines_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_only_format_changed_lines_of_hunks_spanning_multiple_statements() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_staged_diff(Box::new(|| {
+        String::from(
+            r#"diff --git a/file.js b/file.js
+--- a/file.js
++++ b/file.js
+@@ -1,0 +2,2 @@ var   x=1
++let   a=2
++let   b=3
+"#,
+        )
+    }));
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "var   x=1\nlet   a=2\nlet   b=3\nvar   y=4\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--staged", "--changed-lines"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_format_changed_lines_of_hunks_spanning_multiple_statements",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn html_enabled_by_arg_check() {
     let mut fs = MemoryFileSystem::default();
@@ -3341,6 +3341,118 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
     ));
 }
 
+#[test]
+fn should_only_format_changed_lines_when_changed_lines_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_diff(Box::new(|| {
+        String::from(
+            r#"diff --git a/file.js b/file.js
+--- a/file.js
++++ b/file.js
+@@ -2,0 +3 @@ const a   =   1;
++const c   =   3;
+"#,
+        )
+    }));
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "const a   =   1;\nconst b   =   2;\nconst c   =   3;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--changed",
+                "--since=main",
+                "--changed-lines",
+                "--write",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "const a   =   1;\nconst b   =   2;\nconst c = 3;\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_format_changed_lines_when_changed_lines_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_only_format_changed_lines_of_hunks_spanning_multiple_statements() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_diff(Box::new(|| {
+        String::from(
+            r#"diff --git a/file.js b/file.js
+--- a/file.js
++++ b/file.js
+@@ -1,0 +2,3 @@ var   x=1
++let   a=2
++function f( b ){
++return b
+"#,
+        )
+    }));
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "var   x=1\nlet   a=2\nfunction f( b ){\nreturn b\n}\nvar   y=3\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--changed",
+                "--since=main",
+                "--changed-lines",
+                "--write",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "var   x=1\nlet a = 2;\nfunction f(b) {\n\treturn b;\n}\nvar   y=3\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_format_changed_lines_of_hunks_spanning_multiple_statements",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn applies_custom_bracket_spacing_for_graphql() {
     let mut fs = MemoryFileSystem::default();
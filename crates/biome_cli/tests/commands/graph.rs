@@ -0,0 +1,198 @@
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+use crate::run_cli_with_server_workspace;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+
+fn project_fs() -> MemoryFileSystem {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8Path::new("src/index.ts").into(),
+        r#"import { format } from "./utils/format";
+import "./styles.css";
+
+export const lazy = () => import("./lazy");
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/utils/format.ts").into(),
+        r#"import { pad } from "./pad";
+import { missing } from "./missing";
+
+export const format = (value: string) => pad(value);
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/utils/pad.ts").into(),
+        r#"export const pad = (value: string) => ` ${value} `;"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/lazy.ts").into(),
+        r#"import { pad } from "./utils/pad";"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/styles.css").into(),
+        r#"@import "./reset.css";"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("src/reset.css").into(),
+        r#"* { margin: 0; }"#.as_bytes(),
+    );
+    fs
+}
+
+#[test]
+fn graph_prints_json() {
+    let mut console = BufferConsole::default();
+
+    let (fs, result) =
+        run_cli_with_server_workspace(project_fs(), &mut console, Args::from(["graph"].as_slice()));
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "graph_prints_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn graph_prints_dot() {
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli_with_server_workspace(
+        project_fs(),
+        &mut console,
+        Args::from(["graph", "--format=dot"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "graph_prints_dot",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn graph_filters_by_include() {
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli_with_server_workspace(
+        project_fs(),
+        &mut console,
+        Args::from(["graph", "--format=dot", "--include=src/utils/**"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "graph_filters_by_include",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn graph_prints_dependencies_of() {
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli_with_server_workspace(
+        project_fs(),
+        &mut console,
+        Args::from(["graph", "--format=dot", "--dependencies-of=src/index.ts"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "graph_prints_dependencies_of",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn graph_prints_dependents_of_with_depth() {
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli_with_server_workspace(
+        project_fs(),
+        &mut console,
+        Args::from(
+            [
+                "graph",
+                "--format=dot",
+                "--dependents-of=src/utils/pad.ts",
+                "--depth=1",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "graph_prints_dependents_of_with_depth",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn graph_errors_on_unknown_module() {
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli_with_server_workspace(
+        project_fs(),
+        &mut console,
+        Args::from(["graph", "--dependents-of=src/unknown.ts"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "graph_errors_on_unknown_module",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn graph_errors_on_depth_without_query() {
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli_with_server_workspace(
+        project_fs(),
+        &mut console,
+        Args::from(["graph", "--depth=1"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "graph_errors_on_depth_without_query",
+        fs,
+        console,
+        result,
+    ));
+}
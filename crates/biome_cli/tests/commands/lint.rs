@@ -2705,6 +2705,101 @@ fn should_not_process_ignored_file_even_if_its_staged() {
     ));
 }
 
+#[test]
+fn should_only_report_diagnostics_of_changed_lines_when_changed_lines_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_diff(Box::new(|| {
+        String::from(
+            r#"diff --git a/file.js b/file.js
+--- a/file.js
++++ b/file.js
+@@ -3,0 +4 @@ function f() {
++    debugger;
+"#,
+        )
+    }));
+
+    fs.insert(
+        Utf8Path::new("file.js").into(),
+        "debugger;\n\nfunction f() {\n    debugger;\n}\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--changed", "--since=main", "--changed-lines"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_report_diagnostics_of_changed_lines_when_changed_lines_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_lines_flag_is_used_without_changed_or_staged() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Utf8Path::new("file.js").into(),
+        r#"console.log('file');"#.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--changed-lines", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_lines_flag_is_used_without_changed_or_staged",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_lines_flag_is_used_with_write() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("file.js")]));
+
+    fs.insert(
+        Utf8Path::new("file.js").into(),
+        r#"console.log('file');"#.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--staged", "--changed-lines", "--write"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_lines_flag_is_used_with_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn lint_syntax_rules() {
     let mut fs = MemoryFileSystem::default();
@@ -4078,3 +4173,252 @@ bar();"#
         result,
     ));
 }
+
+#[test]
+fn linter_resolves_imported_symbols_from_types_packages() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noFloatingPromises": "on"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    fs.insert(
+        Utf8Path::new("package.json").into(),
+        r#"{ "name": "app", "dependencies": { "legacy": "1.0.0" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("node_modules/legacy/package.json").into(),
+        r#"{ "name": "legacy", "version": "1.0.0", "main": "./index.js" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("node_modules/legacy/index.js").into(),
+        r#"exports.save = function save() { return Promise.resolve(); };"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("node_modules/@types/legacy/package.json").into(),
+        r#"{ "name": "@types/legacy", "version": "1.0.0", "types": "index.d.ts" }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("node_modules/@types/legacy/index.d.ts").into(),
+        r#"export declare function save(): Promise<void>;"#.as_bytes(),
+    );
+
+    let file = Utf8Path::new("src/index.ts");
+    fs.insert(
+        file.into(),
+        r#"import { save } from "legacy";
+
+save();"#
+            .as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file.as_str()].as_slice()),
+    );
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "linter_resolves_imported_symbols_from_types_packages",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_applies_safe_plugin_rewrites() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "plugins": [{ "path": "useObjectSpread.grit", "fix": "safe" }]
+}"#
+        .as_bytes(),
+    );
+
+    fs.insert(
+        Utf8Path::new("useObjectSpread.grit").into(),
+        r#"`Object.assign({}, $args)` as $call where {
+    register_diagnostic(span = $call, message = "Prefer object spread instead of `Object.assign()`."),
+    $call => `({ ...$args })`
+}"#
+        .as_bytes(),
+    );
+
+    let file = Utf8Path::new("file.js");
+    fs.insert(
+        file.into(),
+        r#"export const a = Object.assign({}, b);
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write", file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_applies_safe_plugin_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_applies_plugin_rewrites_without_diagnostics() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "plugins": [{ "path": "useObjectSpread.grit", "fix": "safe" }]
+}"#
+        .as_bytes(),
+    );
+
+    fs.insert(
+        Utf8Path::new("useObjectSpread.grit").into(),
+        r#"`Object.assign({}, $args)` => `({ ...$args })`"#.as_bytes(),
+    );
+
+    let file = Utf8Path::new("file.js");
+    fs.insert(
+        file.into(),
+        r#"export const a = Object.assign({}, b);
+export const c = Object.assign({}, d);
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write", file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_applies_plugin_rewrites_without_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_suggests_plugin_rewrites_of_each_match() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "plugins": ["useObjectSpread.grit"]
+}"#
+        .as_bytes(),
+    );
+
+    fs.insert(
+        Utf8Path::new("useObjectSpread.grit").into(),
+        r#"`Object.assign({}, $args)` as $call where {
+    register_diagnostic(span = $call, message = "Prefer object spread instead of `Object.assign()`."),
+    $call => `({ ...$args })`
+}"#
+        .as_bytes(),
+    );
+
+    let file = Utf8Path::new("file.js");
+    fs.insert(
+        file.into(),
+        r#"export const a = Object.assign({}, b);
+export const c = Object.assign({}, d);
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_suggests_plugin_rewrites_of_each_match",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_suggests_unsafe_plugin_rewrites() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "plugins": ["useObjectSpread.grit"]
+}"#
+        .as_bytes(),
+    );
+
+    fs.insert(
+        Utf8Path::new("useObjectSpread.grit").into(),
+        r#"`Object.assign({}, $args)` as $call where {
+    register_diagnostic(span = $call, message = "Prefer object spread instead of `Object.assign()`."),
+    $call => `({ ...$args })`
+}"#
+        .as_bytes(),
+    );
+
+    let file = Utf8Path::new("file.js");
+    fs.insert(
+        file.into(),
+        r#"export const a = Object.assign({}, b);
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write", file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_suggests_unsafe_plugin_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
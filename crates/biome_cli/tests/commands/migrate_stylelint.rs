@@ -0,0 +1,297 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+#[test]
+fn migrate_stylelintrcjson() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "defaultSeverity": "warning",
+        "ignoreFiles": ["dist/**", "!dist/keep.css"],
+        "rules": {
+            "block-no-empty": true,
+            "color-no-invalid-hex": [true, { "severity": "error" }],
+            "declaration-block-no-duplicate-properties": null,
+            "selector-max-id": 0,
+            "indentation": 2,
+            "@stylistic/string-quotes": "double"
+        },
+        "overrides": [{
+            "files": ["*.module.css"],
+            "rules": {
+                "block-no-empty": null
+            }
+        }],
+        "unknownField": "ignored"
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcjson_write() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "defaultSeverity": "warning",
+        "ignoreFiles": ["dist/**", "!dist/keep.css"],
+        "rules": {
+            "block-no-empty": true,
+            "color-no-invalid-hex": [true, { "severity": "error" }],
+            "declaration-block-no-duplicate-properties": null,
+            "selector-max-id": 0,
+            "indentation": 2,
+            "@stylistic/string-quotes": "double"
+        },
+        "overrides": [{
+            "files": ["*.module.css"],
+            "rules": {
+                "block-no-empty": null
+            }
+        }],
+        "unknownField": "ignored"
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcjson_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrcyaml() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"
+defaultSeverity: error
+ignoreFiles:
+  - "vendor/**"
+rules:
+  block-no-empty:
+    - true
+    - severity: warning
+  no-duplicate-selectors: true
+  font-family-no-duplicate-names: null
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.yml").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrcyaml",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintrc_without_extension_yaml() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"
+defaultSeverity: error
+ignoreFiles:
+  - "vendor/**"
+rules:
+  block-no-empty:
+    - true
+    - severity: warning
+  no-duplicate-selectors: true
+  font-family-no-duplicate-names: null
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Utf8Path::new(".stylelintrc").into(), stylelintrc.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintrc_without_extension_yaml",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelint_package_json() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let packagejson = r#"{
+        "name": "project",
+        "stylelint": {
+            "rules": { "block-no-empty": true }
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Utf8Path::new("package.json").into(), packagejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelint_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelintignore() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{ "rules": { "block-no-empty": true } }"#;
+    let stylelintignore = r#"
+# Comment
+/dist
+*.min.css
+"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new(".stylelintignore").into(),
+        stylelintignore.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelintignore",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_stylelint_include_nursery() {
+    let biomejson = r#"{}"#;
+    let stylelintrc = r#"{ "rules": { "at-rule-no-unknown": true } }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint", "--include-nursery", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_stylelint_include_nursery",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_no_stylelint_config() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_no_stylelint_config",
+        fs,
+        console,
+        result,
+    ));
+}
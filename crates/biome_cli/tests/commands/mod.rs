@@ -2,12 +2,14 @@ mod check;
 mod ci;
 mod explain;
 mod format;
+mod graph;
 mod init;
 mod lint;
 mod lsp_proxy;
 mod migrate;
 mod migrate_eslint;
 mod migrate_prettier;
+mod migrate_stylelint;
 mod rage;
 mod search;
 mod version;
@@ -1,11 +1,11 @@
-use biome_console::BufferConsole;
+use biome_console::{BufferConsole, markup};
 use biome_fs::MemoryFileSystem;
 use bpaf::Args;
 use camino::Utf8Path;
 
 use crate::{
     run_cli,
-    snap_test::{SnapshotPayload, assert_cli_snapshot},
+    snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents, markup_to_string},
 };
 
 // Feel free to add content at the end of this dummy file. It shouldn't affect
@@ -18,6 +18,27 @@ const CSS_FILE_CONTENT: &str = r#"div {
 // existing tests.
 const JS_FILE_CONTENT: &str = r#"const a = 'foo';"#;
 
+// Feel free to add content at the end of this dummy file. It shouldn't affect
+// existing tests.
+const JSON_FILE_CONTENT: &str = r#"{
+  "name": "my-package",
+  "dependencies": {
+    "lodash": "^4.17.21"
+  }
+}"#;
+
+// Feel free to add content at the end of this dummy file. It shouldn't affect
+// existing tests.
+const GRAPHQL_FILE_CONTENT: &str = r#"query {
+  user {
+    oldName
+  }
+}"#;
+
+// Feel free to add content at the end of this dummy file. It shouldn't affect
+// existing tests.
+const HTML_FILE_CONTENT: &str = r#"<button onclick="submit()">Submit</button>"#;
+
 #[test]
 fn search_css_pattern() {
     let mut fs = MemoryFileSystem::default();
@@ -76,6 +97,97 @@ fn search_css_pattern_shorthand() {
     ));
 }
 
+#[test]
+fn search_json_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.json");
+    fs.insert(file_path.into(), JSON_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--language=json",
+                "`\"lodash\": $version`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_json_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_graphql_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.graphql");
+    fs.insert(file_path.into(), GRAPHQL_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--language=graphql",
+                "`oldName`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_graphql_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_html_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), HTML_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["search", "--language=html", "`onclick`", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_html_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn search_js_pattern() {
     let mut fs = MemoryFileSystem::default();
@@ -169,3 +281,178 @@ fn search_css_pattern_skips_js_files() {
         result,
     ));
 }
+
+#[test]
+fn search_js_pattern_with_rewrite_prints_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["search", "`'foo'` => `'bar'`", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, JS_FILE_CONTENT);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_js_pattern_with_rewrite_prints_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_js_pattern_with_rewrite_writes_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "`'foo'` => `'bar'`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "const a = 'bar';");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_js_pattern_with_rewrite_writes_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_js_pattern_with_rewrite_from_stdin() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(JS_FILE_CONTENT.to_string());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "--stdin-file-path=file.js",
+                "`'foo'` => `'bar'`",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, "const a = 'bar';");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_js_pattern_with_rewrite_from_stdin",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_js_pattern_with_rewrite_does_not_write_invalid_code() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["search", "--write", "`'foo'` => `1 +`", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, JS_FILE_CONTENT);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_js_pattern_with_rewrite_does_not_write_invalid_code",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_js_pattern_with_rewrite_from_stdin_does_not_print_invalid_code() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(JS_FILE_CONTENT.to_string());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "--stdin-file-path=file.js",
+                "`'foo'` => `1 +`",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, JS_FILE_CONTENT);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_js_pattern_with_rewrite_from_stdin_does_not_print_invalid_code",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -114,6 +114,7 @@ pub enum RuleName {
     NoCommonJs,
     NoCompareNegZero,
     NoConfusingLabels,
+    NoConfusingVoidExpression,
     NoConfusingVoidType,
     NoConsole,
     NoConstAssign,
@@ -194,9 +195,11 @@ pub enum RuleName {
     NoInvalidBuiltinInstantiation,
     NoInvalidConstructorSuper,
     NoInvalidDirectionInLinearGradient,
+    NoInvalidFragmentTypes,
     NoInvalidGridAreas,
     NoInvalidPositionAtImportRule,
     NoInvalidUseBeforeDeclaration,
+    NoInvalidVariableTypes,
     NoIrregularWhitespace,
     NoLabelVar,
     NoLabelWithoutControl,
@@ -206,6 +209,7 @@ pub enum RuleName {
     NoMisplacedAssertion,
     NoMisrefactoredShorthandAssign,
     NoMissingVarFunction,
+    NoMisusedPromises,
     NoNamespace,
     NoNamespaceImport,
     NoNegationElse,
@@ -260,24 +264,33 @@ pub enum RuleName {
     NoThisInStatic,
     NoTsIgnore,
     NoUnassignedVariables,
+    NoUndeclaredCssModuleClasses,
     NoUndeclaredDependencies,
     NoUndeclaredVariables,
+    NoUnknownArguments,
     NoUnknownAtRule,
+    NoUnknownFields,
     NoUnknownFunction,
     NoUnknownMediaFeatureName,
     NoUnknownProperty,
     NoUnknownPseudoClass,
     NoUnknownPseudoElement,
     NoUnknownTypeSelector,
+    NoUnknownTypes,
     NoUnknownUnit,
     NoUnmatchableAnbSelector,
+    NoUnnecessaryCondition,
     NoUnreachable,
     NoUnreachableSuper,
+    NoUnresolvedCssImports,
     NoUnresolvedImports,
     NoUnsafeDeclarationMerging,
     NoUnsafeFinally,
     NoUnsafeNegation,
     NoUnsafeOptionalChaining,
+    NoUnusedCssModuleClasses,
+    NoUnusedExports,
+    NoUnusedFiles,
     NoUnusedFunctionParameters,
     NoUnusedImports,
     NoUnusedLabels,
@@ -324,6 +337,7 @@ pub enum RuleName {
     UseAsConstAssertion,
     UseAtIndex,
     UseAwait,
+    UseAwaitThenable,
     UseBlockStatements,
     UseButtonType,
     UseCollapsedElseIf,
@@ -392,6 +406,7 @@ pub enum RuleName {
     UseParseIntRadix,
     UseReadonlyClassProperties,
     UseRegexLiterals,
+    UseRequiredArguments,
     UseSelfClosingElements,
     UseSemanticElements,
     UseShorthandAssign,
@@ -449,6 +464,7 @@ impl RuleName {
             Self::NoCommonJs => "noCommonJs",
             Self::NoCompareNegZero => "noCompareNegZero",
             Self::NoConfusingLabels => "noConfusingLabels",
+            Self::NoConfusingVoidExpression => "noConfusingVoidExpression",
             Self::NoConfusingVoidType => "noConfusingVoidType",
             Self::NoConsole => "noConsole",
             Self::NoConstAssign => "noConstAssign",
@@ -531,9 +547,11 @@ impl RuleName {
             Self::NoInvalidBuiltinInstantiation => "noInvalidBuiltinInstantiation",
             Self::NoInvalidConstructorSuper => "noInvalidConstructorSuper",
             Self::NoInvalidDirectionInLinearGradient => "noInvalidDirectionInLinearGradient",
+            Self::NoInvalidFragmentTypes => "noInvalidFragmentTypes",
             Self::NoInvalidGridAreas => "noInvalidGridAreas",
             Self::NoInvalidPositionAtImportRule => "noInvalidPositionAtImportRule",
             Self::NoInvalidUseBeforeDeclaration => "noInvalidUseBeforeDeclaration",
+            Self::NoInvalidVariableTypes => "noInvalidVariableTypes",
             Self::NoIrregularWhitespace => "noIrregularWhitespace",
             Self::NoLabelVar => "noLabelVar",
             Self::NoLabelWithoutControl => "noLabelWithoutControl",
@@ -543,6 +561,7 @@ impl RuleName {
             Self::NoMisplacedAssertion => "noMisplacedAssertion",
             Self::NoMisrefactoredShorthandAssign => "noMisrefactoredShorthandAssign",
             Self::NoMissingVarFunction => "noMissingVarFunction",
+            Self::NoMisusedPromises => "noMisusedPromises",
             Self::NoNamespace => "noNamespace",
             Self::NoNamespaceImport => "noNamespaceImport",
             Self::NoNegationElse => "noNegationElse",
@@ -599,24 +618,33 @@ impl RuleName {
             Self::NoThisInStatic => "noThisInStatic",
             Self::NoTsIgnore => "noTsIgnore",
             Self::NoUnassignedVariables => "noUnassignedVariables",
+            Self::NoUndeclaredCssModuleClasses => "noUndeclaredCssModuleClasses",
             Self::NoUndeclaredDependencies => "noUndeclaredDependencies",
             Self::NoUndeclaredVariables => "noUndeclaredVariables",
+            Self::NoUnknownArguments => "noUnknownArguments",
             Self::NoUnknownAtRule => "noUnknownAtRule",
+            Self::NoUnknownFields => "noUnknownFields",
             Self::NoUnknownFunction => "noUnknownFunction",
             Self::NoUnknownMediaFeatureName => "noUnknownMediaFeatureName",
             Self::NoUnknownProperty => "noUnknownProperty",
             Self::NoUnknownPseudoClass => "noUnknownPseudoClass",
             Self::NoUnknownPseudoElement => "noUnknownPseudoElement",
             Self::NoUnknownTypeSelector => "noUnknownTypeSelector",
+            Self::NoUnknownTypes => "noUnknownTypes",
             Self::NoUnknownUnit => "noUnknownUnit",
             Self::NoUnmatchableAnbSelector => "noUnmatchableAnbSelector",
+            Self::NoUnnecessaryCondition => "noUnnecessaryCondition",
             Self::NoUnreachable => "noUnreachable",
             Self::NoUnreachableSuper => "noUnreachableSuper",
+            Self::NoUnresolvedCssImports => "noUnresolvedCssImports",
             Self::NoUnresolvedImports => "noUnresolvedImports",
             Self::NoUnsafeDeclarationMerging => "noUnsafeDeclarationMerging",
             Self::NoUnsafeFinally => "noUnsafeFinally",
             Self::NoUnsafeNegation => "noUnsafeNegation",
             Self::NoUnsafeOptionalChaining => "noUnsafeOptionalChaining",
+            Self::NoUnusedCssModuleClasses => "noUnusedCssModuleClasses",
+            Self::NoUnusedExports => "noUnusedExports",
+            Self::NoUnusedFiles => "noUnusedFiles",
             Self::NoUnusedFunctionParameters => "noUnusedFunctionParameters",
             Self::NoUnusedImports => "noUnusedImports",
             Self::NoUnusedLabels => "noUnusedLabels",
@@ -663,6 +691,7 @@ impl RuleName {
             Self::UseAsConstAssertion => "useAsConstAssertion",
             Self::UseAtIndex => "useAtIndex",
             Self::UseAwait => "useAwait",
+            Self::UseAwaitThenable => "useAwaitThenable",
             Self::UseBlockStatements => "useBlockStatements",
             Self::UseButtonType => "useButtonType",
             Self::UseCollapsedElseIf => "useCollapsedElseIf",
@@ -731,6 +760,7 @@ impl RuleName {
             Self::UseParseIntRadix => "useParseIntRadix",
             Self::UseReadonlyClassProperties => "useReadonlyClassProperties",
             Self::UseRegexLiterals => "useRegexLiterals",
+            Self::UseRequiredArguments => "useRequiredArguments",
             Self::UseSelfClosingElements => "useSelfClosingElements",
             Self::UseSemanticElements => "useSemanticElements",
             Self::UseShorthandAssign => "useShorthandAssign",
@@ -788,6 +818,7 @@ impl RuleName {
             Self::NoCommonJs => RuleGroup::Style,
             Self::NoCompareNegZero => RuleGroup::Suspicious,
             Self::NoConfusingLabels => RuleGroup::Suspicious,
+            Self::NoConfusingVoidExpression => RuleGroup::Nursery,
             Self::NoConfusingVoidType => RuleGroup::Suspicious,
             Self::NoConsole => RuleGroup::Suspicious,
             Self::NoConstAssign => RuleGroup::Correctness,
@@ -868,9 +899,11 @@ impl RuleName {
             Self::NoInvalidBuiltinInstantiation => RuleGroup::Correctness,
             Self::NoInvalidConstructorSuper => RuleGroup::Correctness,
             Self::NoInvalidDirectionInLinearGradient => RuleGroup::Correctness,
+            Self::NoInvalidFragmentTypes => RuleGroup::Nursery,
             Self::NoInvalidGridAreas => RuleGroup::Correctness,
             Self::NoInvalidPositionAtImportRule => RuleGroup::Correctness,
             Self::NoInvalidUseBeforeDeclaration => RuleGroup::Correctness,
+            Self::NoInvalidVariableTypes => RuleGroup::Nursery,
             Self::NoIrregularWhitespace => RuleGroup::Suspicious,
             Self::NoLabelVar => RuleGroup::Suspicious,
             Self::NoLabelWithoutControl => RuleGroup::A11y,
@@ -880,6 +913,7 @@ impl RuleName {
             Self::NoMisplacedAssertion => RuleGroup::Suspicious,
             Self::NoMisrefactoredShorthandAssign => RuleGroup::Suspicious,
             Self::NoMissingVarFunction => RuleGroup::Correctness,
+            Self::NoMisusedPromises => RuleGroup::Nursery,
             Self::NoNamespace => RuleGroup::Style,
             Self::NoNamespaceImport => RuleGroup::Performance,
             Self::NoNegationElse => RuleGroup::Style,
@@ -934,24 +968,33 @@ impl RuleName {
             Self::NoThisInStatic => RuleGroup::Complexity,
             Self::NoTsIgnore => RuleGroup::Nursery,
             Self::NoUnassignedVariables => RuleGroup::Nursery,
+            Self::NoUndeclaredCssModuleClasses => RuleGroup::Nursery,
             Self::NoUndeclaredDependencies => RuleGroup::Correctness,
             Self::NoUndeclaredVariables => RuleGroup::Correctness,
+            Self::NoUnknownArguments => RuleGroup::Nursery,
             Self::NoUnknownAtRule => RuleGroup::Nursery,
+            Self::NoUnknownFields => RuleGroup::Nursery,
             Self::NoUnknownFunction => RuleGroup::Correctness,
             Self::NoUnknownMediaFeatureName => RuleGroup::Correctness,
             Self::NoUnknownProperty => RuleGroup::Correctness,
             Self::NoUnknownPseudoClass => RuleGroup::Correctness,
             Self::NoUnknownPseudoElement => RuleGroup::Correctness,
             Self::NoUnknownTypeSelector => RuleGroup::Correctness,
+            Self::NoUnknownTypes => RuleGroup::Nursery,
             Self::NoUnknownUnit => RuleGroup::Correctness,
             Self::NoUnmatchableAnbSelector => RuleGroup::Correctness,
+            Self::NoUnnecessaryCondition => RuleGroup::Nursery,
             Self::NoUnreachable => RuleGroup::Correctness,
             Self::NoUnreachableSuper => RuleGroup::Correctness,
+            Self::NoUnresolvedCssImports => RuleGroup::Nursery,
             Self::NoUnresolvedImports => RuleGroup::Nursery,
             Self::NoUnsafeDeclarationMerging => RuleGroup::Suspicious,
             Self::NoUnsafeFinally => RuleGroup::Correctness,
             Self::NoUnsafeNegation => RuleGroup::Suspicious,
             Self::NoUnsafeOptionalChaining => RuleGroup::Correctness,
+            Self::NoUnusedCssModuleClasses => RuleGroup::Nursery,
+            Self::NoUnusedExports => RuleGroup::Nursery,
+            Self::NoUnusedFiles => RuleGroup::Nursery,
             Self::NoUnusedFunctionParameters => RuleGroup::Correctness,
             Self::NoUnusedImports => RuleGroup::Correctness,
             Self::NoUnusedLabels => RuleGroup::Correctness,
@@ -998,6 +1041,7 @@ impl RuleName {
             Self::UseAsConstAssertion => RuleGroup::Style,
             Self::UseAtIndex => RuleGroup::Style,
             Self::UseAwait => RuleGroup::Suspicious,
+            Self::UseAwaitThenable => RuleGroup::Nursery,
             Self::UseBlockStatements => RuleGroup::Style,
             Self::UseButtonType => RuleGroup::A11y,
             Self::UseCollapsedElseIf => RuleGroup::Style,
@@ -1066,6 +1110,7 @@ impl RuleName {
             Self::UseParseIntRadix => RuleGroup::Nursery,
             Self::UseReadonlyClassProperties => RuleGroup::Nursery,
             Self::UseRegexLiterals => RuleGroup::Complexity,
+            Self::UseRequiredArguments => RuleGroup::Nursery,
             Self::UseSelfClosingElements => RuleGroup::Style,
             Self::UseSemanticElements => RuleGroup::A11y,
             Self::UseShorthandAssign => RuleGroup::Style,
@@ -1126,6 +1171,7 @@ impl std::str::FromStr for RuleName {
             "noCommonJs" => Ok(Self::NoCommonJs),
             "noCompareNegZero" => Ok(Self::NoCompareNegZero),
             "noConfusingLabels" => Ok(Self::NoConfusingLabels),
+            "noConfusingVoidExpression" => Ok(Self::NoConfusingVoidExpression),
             "noConfusingVoidType" => Ok(Self::NoConfusingVoidType),
             "noConsole" => Ok(Self::NoConsole),
             "noConstAssign" => Ok(Self::NoConstAssign),
@@ -1210,9 +1256,11 @@ impl std::str::FromStr for RuleName {
             "noInvalidBuiltinInstantiation" => Ok(Self::NoInvalidBuiltinInstantiation),
             "noInvalidConstructorSuper" => Ok(Self::NoInvalidConstructorSuper),
             "noInvalidDirectionInLinearGradient" => Ok(Self::NoInvalidDirectionInLinearGradient),
+            "noInvalidFragmentTypes" => Ok(Self::NoInvalidFragmentTypes),
             "noInvalidGridAreas" => Ok(Self::NoInvalidGridAreas),
             "noInvalidPositionAtImportRule" => Ok(Self::NoInvalidPositionAtImportRule),
             "noInvalidUseBeforeDeclaration" => Ok(Self::NoInvalidUseBeforeDeclaration),
+            "noInvalidVariableTypes" => Ok(Self::NoInvalidVariableTypes),
             "noIrregularWhitespace" => Ok(Self::NoIrregularWhitespace),
             "noLabelVar" => Ok(Self::NoLabelVar),
             "noLabelWithoutControl" => Ok(Self::NoLabelWithoutControl),
@@ -1222,6 +1270,7 @@ impl std::str::FromStr for RuleName {
             "noMisplacedAssertion" => Ok(Self::NoMisplacedAssertion),
             "noMisrefactoredShorthandAssign" => Ok(Self::NoMisrefactoredShorthandAssign),
             "noMissingVarFunction" => Ok(Self::NoMissingVarFunction),
+            "noMisusedPromises" => Ok(Self::NoMisusedPromises),
             "noNamespace" => Ok(Self::NoNamespace),
             "noNamespaceImport" => Ok(Self::NoNamespaceImport),
             "noNegationElse" => Ok(Self::NoNegationElse),
@@ -1278,24 +1327,33 @@ impl std::str::FromStr for RuleName {
             "noThisInStatic" => Ok(Self::NoThisInStatic),
             "noTsIgnore" => Ok(Self::NoTsIgnore),
             "noUnassignedVariables" => Ok(Self::NoUnassignedVariables),
+            "noUndeclaredCssModuleClasses" => Ok(Self::NoUndeclaredCssModuleClasses),
             "noUndeclaredDependencies" => Ok(Self::NoUndeclaredDependencies),
             "noUndeclaredVariables" => Ok(Self::NoUndeclaredVariables),
+            "noUnknownArguments" => Ok(Self::NoUnknownArguments),
             "noUnknownAtRule" => Ok(Self::NoUnknownAtRule),
+            "noUnknownFields" => Ok(Self::NoUnknownFields),
             "noUnknownFunction" => Ok(Self::NoUnknownFunction),
             "noUnknownMediaFeatureName" => Ok(Self::NoUnknownMediaFeatureName),
             "noUnknownProperty" => Ok(Self::NoUnknownProperty),
             "noUnknownPseudoClass" => Ok(Self::NoUnknownPseudoClass),
             "noUnknownPseudoElement" => Ok(Self::NoUnknownPseudoElement),
             "noUnknownTypeSelector" => Ok(Self::NoUnknownTypeSelector),
+            "noUnknownTypes" => Ok(Self::NoUnknownTypes),
             "noUnknownUnit" => Ok(Self::NoUnknownUnit),
             "noUnmatchableAnbSelector" => Ok(Self::NoUnmatchableAnbSelector),
+            "noUnnecessaryCondition" => Ok(Self::NoUnnecessaryCondition),
             "noUnreachable" => Ok(Self::NoUnreachable),
             "noUnreachableSuper" => Ok(Self::NoUnreachableSuper),
+            "noUnresolvedCssImports" => Ok(Self::NoUnresolvedCssImports),
             "noUnresolvedImports" => Ok(Self::NoUnresolvedImports),
             "noUnsafeDeclarationMerging" => Ok(Self::NoUnsafeDeclarationMerging),
             "noUnsafeFinally" => Ok(Self::NoUnsafeFinally),
             "noUnsafeNegation" => Ok(Self::NoUnsafeNegation),
             "noUnsafeOptionalChaining" => Ok(Self::NoUnsafeOptionalChaining),
+            "noUnusedCssModuleClasses" => Ok(Self::NoUnusedCssModuleClasses),
+            "noUnusedExports" => Ok(Self::NoUnusedExports),
+            "noUnusedFiles" => Ok(Self::NoUnusedFiles),
             "noUnusedFunctionParameters" => Ok(Self::NoUnusedFunctionParameters),
             "noUnusedImports" => Ok(Self::NoUnusedImports),
             "noUnusedLabels" => Ok(Self::NoUnusedLabels),
@@ -1342,6 +1400,7 @@ impl std::str::FromStr for RuleName {
             "useAsConstAssertion" => Ok(Self::UseAsConstAssertion),
             "useAtIndex" => Ok(Self::UseAtIndex),
             "useAwait" => Ok(Self::UseAwait),
+            "useAwaitThenable" => Ok(Self::UseAwaitThenable),
             "useBlockStatements" => Ok(Self::UseBlockStatements),
             "useButtonType" => Ok(Self::UseButtonType),
             "useCollapsedElseIf" => Ok(Self::UseCollapsedElseIf),
@@ -1410,6 +1469,7 @@ impl std::str::FromStr for RuleName {
             "useParseIntRadix" => Ok(Self::UseParseIntRadix),
             "useReadonlyClassProperties" => Ok(Self::UseReadonlyClassProperties),
             "useRegexLiterals" => Ok(Self::UseRegexLiterals),
+            "useRequiredArguments" => Ok(Self::UseRequiredArguments),
             "useSelfClosingElements" => Ok(Self::UseSelfClosingElements),
             "useSemanticElements" => Ok(Self::UseSemanticElements),
             "useShorthandAssign" => Ok(Self::UseShorthandAssign),
@@ -4747,6 +4807,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_bitwise_operators:
         Option<RuleConfiguration<biome_js_analyze::options::NoBitwiseOperators>>,
+    #[doc = "Require expressions of type void to appear in statement position."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_confusing_void_expression:
+        Option<RuleConfiguration<biome_js_analyze::options::NoConfusingVoidExpression>>,
     #[doc = "Disallow expressions where the operation doesn't affect the value"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_constant_binary_expression:
@@ -4778,9 +4842,21 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_important_styles:
         Option<RuleFixConfiguration<biome_css_analyze::options::NoImportantStyles>>,
+    #[doc = "Disallow fragments whose type condition can never match."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_fragment_types:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoInvalidFragmentTypes>>,
+    #[doc = "Disallow variables whose type doesn't match where they are used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_variable_types:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoInvalidVariableTypes>>,
     #[doc = "Reports usage of \"magic numbers\" — numbers used directly instead of being assigned to named constants."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_magic_numbers: Option<RuleConfiguration<biome_js_analyze::options::NoMagicNumbers>>,
+    #[doc = "Disallow Promises to be used in places where they are almost certainly a mistake."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_misused_promises:
+        Option<RuleConfiguration<biome_js_analyze::options::NoMisusedPromises>>,
     #[doc = "Disallows defining React components inside other components."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_nested_component_definitions:
@@ -4813,13 +4889,46 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unassigned_variables:
         Option<RuleConfiguration<biome_js_analyze::options::NoUnassignedVariables>>,
+    #[doc = "Disallow references to classes that aren't declared by the imported CSS Module."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_undeclared_css_module_classes:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUndeclaredCssModuleClasses>>,
+    #[doc = "Disallow passing arguments that aren't defined by the field or directive."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_arguments:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoUnknownArguments>>,
     #[doc = "Disallow unknown at-rules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_at_rule: Option<RuleConfiguration<biome_css_analyze::options::NoUnknownAtRule>>,
+    #[doc = "Disallow selecting fields that don't exist on the type of their selection set."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_fields:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoUnknownFields>>,
+    #[doc = "Disallow references to types that don't exist in the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_types: Option<RuleConfiguration<biome_graphql_analyze::options::NoUnknownTypes>>,
+    #[doc = "Disallow conditionals that are always truthy or always falsy, based on their type."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unnecessary_condition:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUnnecessaryCondition>>,
+    #[doc = "Disallow references to stylesheets and resources that cannot be resolved."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unresolved_css_imports:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnresolvedCssImports>>,
     #[doc = "Warn when importing non-existing exports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unresolved_imports:
         Option<RuleConfiguration<biome_js_analyze::options::NoUnresolvedImports>>,
+    #[doc = "Disallow classes of CSS Modules that are never used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_css_module_classes:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnusedCssModuleClasses>>,
+    #[doc = "Disallow exports that are never imported by another module."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_exports: Option<RuleConfiguration<biome_js_analyze::options::NoUnusedExports>>,
+    #[doc = "Disallow files that are not reachable from any entry point of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_files: Option<RuleConfiguration<biome_js_analyze::options::NoUnusedFiles>>,
     #[doc = "Prevent duplicate polyfills from Polyfill.io."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unwanted_polyfillio:
@@ -4840,6 +4949,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_adjacent_getter_setter:
         Option<RuleConfiguration<biome_js_analyze::options::UseAdjacentGetterSetter>>,
+    #[doc = "Require await to only be used on Promise-like values."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_await_thenable:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseAwaitThenable>>,
     #[doc = "Require the consistent declaration of object literals. Defaults to explicit definitions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_object_definition:
@@ -4899,6 +5012,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_readonly_class_properties:
         Option<RuleFixConfiguration<biome_js_analyze::options::UseReadonlyClassProperties>>,
+    #[doc = "Require the arguments that are non-null and without a default value in the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_required_arguments:
+        Option<RuleConfiguration<biome_graphql_analyze::options::UseRequiredArguments>>,
     #[doc = "Enforce JSDoc comment lines to start with a single asterisk, except for the first one."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_single_js_doc_asterisk:
@@ -4925,6 +5042,7 @@ impl Nursery {
     pub(crate) const GROUP_RULES: &'static [&'static str] = &[
         "noAwaitInLoop",
         "noBitwiseOperators",
+        "noConfusingVoidExpression",
         "noConstantBinaryExpression",
         "noDestructuredProps",
         "noExcessiveLinesPerFunction",
@@ -4933,7 +5051,10 @@ impl Nursery {
         "noImplicitCoercion",
         "noImportCycles",
         "noImportantStyles",
+        "noInvalidFragmentTypes",
+        "noInvalidVariableTypes",
         "noMagicNumbers",
+        "noMisusedPromises",
         "noNestedComponentDefinitions",
         "noNoninteractiveElementInteractions",
         "noProcessGlobal",
@@ -4943,13 +5064,23 @@ impl Nursery {
         "noShadow",
         "noTsIgnore",
         "noUnassignedVariables",
+        "noUndeclaredCssModuleClasses",
+        "noUnknownArguments",
         "noUnknownAtRule",
+        "noUnknownFields",
+        "noUnknownTypes",
+        "noUnnecessaryCondition",
+        "noUnresolvedCssImports",
         "noUnresolvedImports",
+        "noUnusedCssModuleClasses",
+        "noUnusedExports",
+        "noUnusedFiles",
         "noUnwantedPolyfillio",
         "noUselessBackrefInRegex",
         "noUselessEscapeInString",
         "noUselessUndefined",
         "useAdjacentGetterSetter",
+        "useAwaitThenable",
         "useConsistentObjectDefinition",
         "useConsistentResponse",
         "useExhaustiveSwitchCases",
@@ -4966,6 +5097,7 @@ impl Nursery {
         "useObjectSpread",
         "useParseIntRadix",
         "useReadonlyClassProperties",
+        "useRequiredArguments",
         "useSingleJsDocAsterisk",
         "useSortedClasses",
         "useSymbolDescription",
@@ -4973,15 +5105,15 @@ impl Nursery {
         "useUniqueElementIds",
     ];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
@@ -5032,6 +5164,21 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]),
     ];
 }
 impl RuleGroupExt for Nursery {
@@ -5053,236 +5200,311 @@ impl RuleGroupExt for Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_constant_binary_expression.as_ref() {
+        if let Some(rule) = self.no_confusing_void_expression.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_destructured_props.as_ref() {
+        if let Some(rule) = self.no_constant_binary_expression.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_excessive_lines_per_function.as_ref() {
+        if let Some(rule) = self.no_destructured_props.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_floating_promises.as_ref() {
+        if let Some(rule) = self.no_excessive_lines_per_function.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
+        if let Some(rule) = self.no_floating_promises.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_implicit_coercion.as_ref() {
+        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_import_cycles.as_ref() {
+        if let Some(rule) = self.no_implicit_coercion.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_important_styles.as_ref() {
+        if let Some(rule) = self.no_import_cycles.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_magic_numbers.as_ref() {
+        if let Some(rule) = self.no_important_styles.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
+        if let Some(rule) = self.no_invalid_fragment_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
+        if let Some(rule) = self.no_invalid_variable_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_process_global.as_ref() {
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_react_prop_assign.as_ref() {
+        if let Some(rule) = self.no_misused_promises.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_restricted_elements.as_ref() {
+        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_shadow.as_ref() {
+        if let Some(rule) = self.no_process_global.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_ts_ignore.as_ref() {
+        if let Some(rule) = self.no_react_prop_assign.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_unassigned_variables.as_ref() {
+        if let Some(rule) = self.no_restricted_elements.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+        if let Some(rule) = self.no_secrets.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_unresolved_imports.as_ref() {
+        if let Some(rule) = self.no_shadow.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
+        if let Some(rule) = self.no_ts_ignore.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
+        if let Some(rule) = self.no_unassigned_variables.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
+        if let Some(rule) = self.no_undeclared_css_module_classes.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.no_unknown_arguments.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
+        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
+        if let Some(rule) = self.no_unknown_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.use_consistent_response.as_ref() {
+        if let Some(rule) = self.no_unknown_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
+        if let Some(rule) = self.no_unnecessary_condition.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.use_explicit_type.as_ref() {
+        if let Some(rule) = self.no_unresolved_css_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.use_exports_last.as_ref() {
+        if let Some(rule) = self.no_unresolved_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.use_for_component.as_ref() {
+        if let Some(rule) = self.no_unused_css_module_classes.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+        if let Some(rule) = self.no_unused_exports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.use_index_of.as_ref() {
+        if let Some(rule) = self.no_unused_files.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
+        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.use_json_import_attribute.as_ref() {
+        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_named_operation.as_ref() {
+        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_naming_convention.as_ref() {
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_numeric_separators.as_ref() {
+        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_object_spread.as_ref() {
+        if let Some(rule) = self.use_await_thenable.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+        if let Some(rule) = self.use_consistent_response.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.use_explicit_type.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_symbol_description.as_ref() {
+        if let Some(rule) = self.use_exports_last.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+        if let Some(rule) = self.use_for_component.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
+        if let Some(rule) = self.use_index_of.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.use_json_import_attribute.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.use_named_operation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.use_naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.use_object_spread.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
+        if let Some(rule) = self.use_required_arguments.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
+            }
+        }
+        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
+            }
+        }
+        if let Some(rule) = self.use_symbol_description.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
+            }
+        }
+        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
+            }
+        }
+        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
+            }
+        }
         index_set
     }
     fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
@@ -5297,236 +5519,311 @@ impl RuleGroupExt for Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_constant_binary_expression.as_ref() {
+        if let Some(rule) = self.no_confusing_void_expression.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_destructured_props.as_ref() {
+        if let Some(rule) = self.no_constant_binary_expression.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_excessive_lines_per_function.as_ref() {
+        if let Some(rule) = self.no_destructured_props.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_floating_promises.as_ref() {
+        if let Some(rule) = self.no_excessive_lines_per_function.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
+        if let Some(rule) = self.no_floating_promises.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_implicit_coercion.as_ref() {
+        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_import_cycles.as_ref() {
+        if let Some(rule) = self.no_implicit_coercion.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_important_styles.as_ref() {
+        if let Some(rule) = self.no_import_cycles.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_magic_numbers.as_ref() {
+        if let Some(rule) = self.no_important_styles.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
+        if let Some(rule) = self.no_invalid_fragment_types.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
+        if let Some(rule) = self.no_invalid_variable_types.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_process_global.as_ref() {
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_react_prop_assign.as_ref() {
+        if let Some(rule) = self.no_misused_promises.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_restricted_elements.as_ref() {
+        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_shadow.as_ref() {
+        if let Some(rule) = self.no_process_global.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_ts_ignore.as_ref() {
+        if let Some(rule) = self.no_react_prop_assign.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_unassigned_variables.as_ref() {
+        if let Some(rule) = self.no_restricted_elements.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+        if let Some(rule) = self.no_secrets.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_unresolved_imports.as_ref() {
+        if let Some(rule) = self.no_shadow.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
+        if let Some(rule) = self.no_ts_ignore.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
+        if let Some(rule) = self.no_unassigned_variables.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
+        if let Some(rule) = self.no_undeclared_css_module_classes.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.no_unknown_arguments.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
+        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
+        if let Some(rule) = self.no_unknown_fields.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.use_consistent_response.as_ref() {
+        if let Some(rule) = self.no_unknown_types.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
+        if let Some(rule) = self.no_unnecessary_condition.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.use_explicit_type.as_ref() {
+        if let Some(rule) = self.no_unresolved_css_imports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.use_exports_last.as_ref() {
+        if let Some(rule) = self.no_unresolved_imports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.use_for_component.as_ref() {
+        if let Some(rule) = self.no_unused_css_module_classes.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+        if let Some(rule) = self.no_unused_exports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.use_index_of.as_ref() {
+        if let Some(rule) = self.no_unused_files.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
+        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.use_json_import_attribute.as_ref() {
+        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_named_operation.as_ref() {
+        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_naming_convention.as_ref() {
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_numeric_separators.as_ref() {
+        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_object_spread.as_ref() {
+        if let Some(rule) = self.use_await_thenable.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+        if let Some(rule) = self.use_consistent_response.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.use_explicit_type.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_symbol_description.as_ref() {
+        if let Some(rule) = self.use_exports_last.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+        if let Some(rule) = self.use_for_component.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
+        if let Some(rule) = self.use_index_of.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.use_json_import_attribute.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.use_named_operation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.use_naming_convention.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.use_object_spread.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
+        if let Some(rule) = self.use_required_arguments.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
+            }
+        }
+        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
+            }
+        }
+        if let Some(rule) = self.use_symbol_description.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
+            }
+        }
+        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
+            }
+        }
+        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -5565,6 +5862,10 @@ impl RuleGroupExt for Nursery {
                 .no_bitwise_operators
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noConfusingVoidExpression" => self
+                .no_confusing_void_expression
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noConstantBinaryExpression" => self
                 .no_constant_binary_expression
                 .as_ref()
@@ -5597,10 +5898,22 @@ impl RuleGroupExt for Nursery {
                 .no_important_styles
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidFragmentTypes" => self
+                .no_invalid_fragment_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidVariableTypes" => self
+                .no_invalid_variable_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noMagicNumbers" => self
                 .no_magic_numbers
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noMisusedPromises" => self
+                .no_misused_promises
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noNestedComponentDefinitions" => self
                 .no_nested_component_definitions
                 .as_ref()
@@ -5637,14 +5950,50 @@ impl RuleGroupExt for Nursery {
                 .no_unassigned_variables
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUndeclaredCssModuleClasses" => self
+                .no_undeclared_css_module_classes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownArguments" => self
+                .no_unknown_arguments
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnknownAtRule" => self
                 .no_unknown_at_rule
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownFields" => self
+                .no_unknown_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownTypes" => self
+                .no_unknown_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnnecessaryCondition" => self
+                .no_unnecessary_condition
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnresolvedCssImports" => self
+                .no_unresolved_css_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnresolvedImports" => self
                 .no_unresolved_imports
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedCssModuleClasses" => self
+                .no_unused_css_module_classes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedExports" => self
+                .no_unused_exports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedFiles" => self
+                .no_unused_files
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnwantedPolyfillio" => self
                 .no_unwanted_polyfillio
                 .as_ref()
@@ -5665,6 +6014,10 @@ impl RuleGroupExt for Nursery {
                 .use_adjacent_getter_setter
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useAwaitThenable" => self
+                .use_await_thenable
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useConsistentObjectDefinition" => self
                 .use_consistent_object_definition
                 .as_ref()
@@ -5729,6 +6082,10 @@ impl RuleGroupExt for Nursery {
                 .use_readonly_class_properties
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useRequiredArguments" => self
+                .use_required_arguments
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useSingleJsDocAsterisk" => self
                 .use_single_js_doc_asterisk
                 .as_ref()
@@ -5759,6 +6116,7 @@ impl From<GroupPlainConfiguration> for Nursery {
             recommended: None,
             no_await_in_loop: Some(value.into()),
             no_bitwise_operators: Some(value.into()),
+            no_confusing_void_expression: Some(value.into()),
             no_constant_binary_expression: Some(value.into()),
             no_destructured_props: Some(value.into()),
             no_excessive_lines_per_function: Some(value.into()),
@@ -5767,7 +6125,10 @@ impl From<GroupPlainConfiguration> for Nursery {
             no_implicit_coercion: Some(value.into()),
             no_import_cycles: Some(value.into()),
             no_important_styles: Some(value.into()),
+            no_invalid_fragment_types: Some(value.into()),
+            no_invalid_variable_types: Some(value.into()),
             no_magic_numbers: Some(value.into()),
+            no_misused_promises: Some(value.into()),
             no_nested_component_definitions: Some(value.into()),
             no_noninteractive_element_interactions: Some(value.into()),
             no_process_global: Some(value.into()),
@@ -5777,13 +6138,23 @@ impl From<GroupPlainConfiguration> for Nursery {
             no_shadow: Some(value.into()),
             no_ts_ignore: Some(value.into()),
             no_unassigned_variables: Some(value.into()),
+            no_undeclared_css_module_classes: Some(value.into()),
+            no_unknown_arguments: Some(value.into()),
             no_unknown_at_rule: Some(value.into()),
+            no_unknown_fields: Some(value.into()),
+            no_unknown_types: Some(value.into()),
+            no_unnecessary_condition: Some(value.into()),
+            no_unresolved_css_imports: Some(value.into()),
             no_unresolved_imports: Some(value.into()),
+            no_unused_css_module_classes: Some(value.into()),
+            no_unused_exports: Some(value.into()),
+            no_unused_files: Some(value.into()),
             no_unwanted_polyfillio: Some(value.into()),
             no_useless_backref_in_regex: Some(value.into()),
             no_useless_escape_in_string: Some(value.into()),
             no_useless_undefined: Some(value.into()),
             use_adjacent_getter_setter: Some(value.into()),
+            use_await_thenable: Some(value.into()),
             use_consistent_object_definition: Some(value.into()),
             use_consistent_response: Some(value.into()),
             use_exhaustive_switch_cases: Some(value.into()),
@@ -5800,6 +6171,7 @@ impl From<GroupPlainConfiguration> for Nursery {
             use_object_spread: Some(value.into()),
             use_parse_int_radix: Some(value.into()),
             use_readonly_class_properties: Some(value.into()),
+            use_required_arguments: Some(value.into()),
             use_single_js_doc_asterisk: Some(value.into()),
             use_sorted_classes: Some(value.into()),
             use_symbol_description: Some(value.into()),
@@ -8,6 +8,9 @@ use biome_diagnostics::Severity;
 use serde::{Deserialize, Serialize};
 
 /// A list of paths to other JSON files, used to extends the current configuration.
+///
+/// Entries that don't start with `.` are resolved like Node.js modules, so
+/// configurations can be shared via npm packages, e.g. `@acme/biome-config/react`.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, Merge)]
 #[serde(deny_unknown_fields, rename_all = "camelCase", untagged)]
 pub enum Extends {
@@ -27,6 +27,16 @@ pub struct GraphqlConfiguration {
     #[bpaf(external(graphql_assist_configuration), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assist: Option<GraphqlAssistConfiguration>,
+
+    /// Paths to the files that describe the GraphQL schema of the project,
+    /// relative to the configuration file.
+    ///
+    /// `.json` files are read as the result of an introspection query, and
+    /// other files as SDL. Some lint rules use the schema to validate the
+    /// operations of the project.
+    #[bpaf(pure(Default::default()), hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Vec<String>>,
 }
 
 pub type GraphqlFormatterEnabled = Bool<true>;
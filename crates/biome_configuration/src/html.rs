@@ -25,10 +25,15 @@ pub struct HtmlConfiguration {
     #[bpaf(external(html_formatter_configuration), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub formatter: Option<HtmlFormatterConfiguration>,
+
+    /// HTML linter options
+    #[bpaf(external(html_linter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linter: Option<HtmlLinterConfiguration>,
 }
 
 pub type HtmlFormatterEnabled = Bool<false>; // Keep it disabled by default while experimental.
-pub type HtmlLinterEnabled = Bool<false>;
+pub type HtmlLinterEnabled = Bool<false>; // Keep it disabled by default while experimental.
 pub type HtmlAssistEnabled = Bool<false>;
 
 /// Options that changes how the HTML parser behaves
@@ -98,7 +103,7 @@ pub struct HtmlFormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub whitespace_sensitivity: Option<WhitespaceSensitivity>,
 
-    /// Whether to indent the `<script>` and `<style>` tags for HTML (and its super languages). Defaults to false.
+    /// Whether to indent the content of `<script>` and `<style>` tags in templating languages like Astro. Defaults to false. The content of these tags is always indented in HTML files.
     #[bpaf(
         long("html-formatter-indent-script-and-style"),
         argument("true|false"),
@@ -116,3 +121,16 @@ pub struct HtmlFormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub self_close_void_elements: Option<SelfCloseVoidElements>,
 }
+
+/// Options that changes how the HTML linter behaves
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Bpaf, Deserializable, Merge,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct HtmlLinterConfiguration {
+    /// Control the linter for HTML (and its super languages) files.
+    #[bpaf(long("html-linter-enabled"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<HtmlLinterEnabled>,
+}
@@ -44,6 +44,12 @@ pub struct JsConfiguration {
     #[bpaf(hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_runtime: Option<JsxRuntime>,
+
+    /// Options for the CSS and GraphQL code embedded in tagged template
+    /// literals, such as ``styled.div`...` `` or ``gql`...` ``.
+    #[bpaf(pure(Default::default()), hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedded_templates: Option<JsEmbeddedTemplatesConfiguration>,
 }
 
 pub type UnsafeParameterDecoratorsEnabled = Bool<false>;
@@ -113,6 +119,30 @@ impl FromStr for JsxRuntime {
     }
 }
 
+pub type JsEmbeddedTemplatesEnabled = Bool<false>;
+
+/// Options that control how the code embedded in tagged template literals is handled
+#[derive(Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsEmbeddedTemplatesConfiguration {
+    /// Whether the CSS and GraphQL code embedded in tagged template literals
+    /// is formatted and linted.
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<JsEmbeddedTemplatesEnabled>,
+
+    /// Tags of template literals that contain CSS, in addition to `css`,
+    /// `keyframes`, `createGlobalStyle`, `injectGlobal` and `styled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub css_tags: Option<Vec<String>>,
+
+    /// Tags of template literals that contain GraphQL, in addition to `gql`
+    /// and `graphql`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graphql_tags: Option<Vec<String>>,
+}
+
 pub type JsLinterEnabled = Bool<true>;
 
 /// Linter options specific to the JavaScript linter
@@ -18,10 +18,12 @@ pub mod grit;
 pub mod html;
 pub mod javascript;
 pub mod json;
+pub mod markdown;
 pub mod max_size;
 mod overrides;
 pub mod plugins;
 pub mod vcs;
+pub mod yaml;
 
 use crate::analyzer::assist::{Actions, AssistConfiguration, Source, assist_configuration};
 use crate::analyzer::{RuleAssistConfiguration, RuleDomains};
@@ -59,6 +61,7 @@ pub use graphql::{GraphqlConfiguration, graphql_configuration};
 pub use html::{HtmlConfiguration, html_configuration};
 pub use javascript::{JsConfiguration, js_configuration};
 pub use json::{JsonConfiguration, json_configuration};
+pub use markdown::{MarkdownConfiguration, markdown_configuration};
 pub use overrides::{
     OverrideAssistConfiguration, OverrideFilesConfiguration, OverrideFormatterConfiguration,
     OverrideGlobs, OverrideLinterConfiguration, OverridePattern, Overrides,
@@ -72,6 +75,7 @@ use std::num::NonZeroU64;
 use std::str::FromStr;
 use std::sync::LazyLock;
 use vcs::VcsClientKind;
+pub use yaml::{YamlConfiguration, yaml_configuration};
 
 pub const VERSION: &str = match option_env!("BIOME_VERSION") {
     Some(version) => version,
@@ -160,6 +164,16 @@ pub struct Configuration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html: Option<HtmlConfiguration>,
 
+    /// Specific configuration for the YAML language
+    #[bpaf(external(yaml_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yaml: Option<YamlConfiguration>,
+
+    /// Specific configuration for the Markdown language
+    #[bpaf(external(markdown_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<MarkdownConfiguration>,
+
     /// A list of granular patterns that should be applied only to a sub set of files
     #[bpaf(hide, pure(Default::default()))]
     #[serde(skip_serializing_if = "Option::is_none")]
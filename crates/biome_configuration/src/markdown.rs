@@ -0,0 +1,59 @@
+use crate::bool::Bool;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::LineEnding;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to Markdown files
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Bpaf, Deserializable, Merge,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MarkdownConfiguration {
+    /// Markdown formatter options
+    #[bpaf(external(markdown_formatter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<MarkdownFormatterConfiguration>,
+}
+
+pub type MarkdownFormatterEnabled = Bool<false>; // Keep it disabled by default while experimental.
+
+// The formatter doesn't wrap the paragraphs, and the indentation of the content of the list
+// items follows their markers, so there are no line width and indentation options.
+
+/// Options that changes how the Markdown formatter behaves
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Bpaf, Deserializable, Merge,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MarkdownFormatterConfiguration {
+    /// Control the formatter for Markdown files.
+    #[bpaf(long("markdown-formatter-enabled"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<MarkdownFormatterEnabled>,
+
+    /// The type of line ending applied to Markdown files.
+    #[bpaf(
+        long("markdown-formatter-line-ending"),
+        argument("lf|crlf|cr"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_ending: Option<LineEnding>,
+}
+
+impl MarkdownFormatterConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+}
+
+#[test]
+fn default_markdown_formatter() {
+    let markdown_configuration = MarkdownFormatterConfiguration::default();
+
+    assert!(!markdown_configuration.is_enabled());
+    assert_eq!(markdown_configuration.line_ending, None);
+}
@@ -1,7 +1,9 @@
+use biome_analyze::FixKind;
 use biome_deserialize::{
     Deserializable, DeserializableType, DeserializableValue, DeserializationContext,
 };
 use biome_deserialize_macros::{Deserializable, Merge};
+use camino::Utf8Path;
 use serde::{Deserialize, Serialize};
 use std::{
     ops::{Deref, DerefMut},
@@ -17,6 +19,22 @@ impl Plugins {
     pub fn iter(&self) -> impl Iterator<Item = &PluginConfiguration> {
         self.deref().iter()
     }
+
+    /// Joins the relative paths of the plugins to `base_path`.
+    ///
+    /// This is used for configurations loaded via `extends`, so that their
+    /// plugins are resolved relative to the file that declares them.
+    pub fn rebase(&mut self, base_path: &Utf8Path) {
+        for plugin in self.iter_mut() {
+            let path = match plugin {
+                PluginConfiguration::Path(path) => path,
+                PluginConfiguration::PathWithOptions(plugin) => &mut plugin.path,
+            };
+            if Utf8Path::new(path).is_relative() {
+                *path = base_path.join(&*path).to_string();
+            }
+        }
+    }
 }
 
 impl FromStr for Plugins {
@@ -46,7 +64,26 @@ impl DerefMut for Plugins {
 #[serde(rename_all = "camelCase", deny_unknown_fields, untagged)]
 pub enum PluginConfiguration {
     Path(String),
-    // TODO: PathWithOptions(PluginPathWithOptions),
+    PathWithOptions(PluginPathWithOptions),
+}
+
+impl PluginConfiguration {
+    /// Returns the path of the plugin, relative to the configuration file.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Path(path) => path,
+            Self::PathWithOptions(plugin) => &plugin.path,
+        }
+    }
+
+    /// Returns the kind of the code actions emitted for the rewrites of the
+    /// plugin. Rewrites are unsafe unless configured otherwise.
+    pub fn fix_kind(&self) -> FixKind {
+        match self {
+            Self::Path(_) => FixKind::Unsafe,
+            Self::PathWithOptions(plugin) => plugin.fix.unwrap_or(FixKind::Unsafe),
+        }
+    }
 }
 
 impl Deserializable for PluginConfiguration {
@@ -58,14 +95,25 @@ impl Deserializable for PluginConfiguration {
         if value.visitable_type()? == DeserializableType::Str {
             Deserializable::deserialize(ctx, value, rule_name).map(Self::Path)
         } else {
-            // TODO: Fix this to allow plugins to receive options.
+            // TODO: Allow plugins to receive their own options.
             //       We probably need to pass them as `AnyJsonValue` or
             //       `biome_json_value::JsonValue`, since plugin options are
             //       untyped.
-            //       Also, we don't have a way to configure Grit plugins yet.
-            /*Deserializable::deserialize(value, rule_name, diagnostics)
-            .map(|plugin| Self::PathWithOptions(plugin))*/
-            None
+            Deserializable::deserialize(ctx, value, rule_name).map(Self::PathWithOptions)
         }
     }
 }
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginPathWithOptions {
+    /// The path of the plugin, relative to the configuration file.
+    #[deserializable(required)]
+    pub path: String,
+
+    /// The kind of the code actions emitted for the rewrites of the plugin.
+    /// Defaults to `unsafe`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<FixKind>,
+}
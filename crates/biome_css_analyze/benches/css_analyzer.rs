@@ -4,6 +4,7 @@ use biome_analyze::{
     RuleCategoriesBuilder,
 };
 use biome_css_parser::CssParserOptions;
+use biome_css_syntax::CssFileSource;
 use biome_test_utils::BenchCase;
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
 use std::collections::HashMap;
@@ -61,6 +62,7 @@ fn bench_analyzer(criterion: &mut Criterion) {
                                 &parse.tree(),
                                 filter,
                                 &options,
+                                CssFileSource::css().into(),
                                 &[],
                                 |event| {
                                     black_box(event.diagnostic());
@@ -220,6 +220,8 @@ impl RecessOrderProperties {
 // The order of these enum members controls the sort order of the css nodes.
 #[derive(PartialEq, Eq, PartialOrd, Ord)]
 pub enum NodeKindOrder {
+    /// e.g. $primary-color: red;
+    ScssVariable,
     /// e.g. --custom-property: red;
     CustomProperty,
     /// e.g. composes: .rule;
@@ -242,11 +244,13 @@ impl RecessOrderMember {
             AnyCssDeclarationOrRule::CssEmptyDeclaration(_) => NodeKindOrder::UnknownKind,
             AnyCssDeclarationOrRule::CssBogus(_) => NodeKindOrder::UnknownKind,
             AnyCssDeclarationOrRule::CssMetavariable(_) => NodeKindOrder::UnknownKind,
+            AnyCssDeclarationOrRule::ScssNestedProperty(_) => NodeKindOrder::UnknownKind,
             AnyCssDeclarationOrRule::AnyCssRule(rule) => match rule {
                 AnyCssRule::CssAtRule(_) => NodeKindOrder::NestedRuleOrAtRule,
                 AnyCssRule::CssBogusRule(_) => NodeKindOrder::UnknownKind,
                 AnyCssRule::CssNestedQualifiedRule(_) => NodeKindOrder::NestedRuleOrAtRule,
                 AnyCssRule::CssQualifiedRule(_) => NodeKindOrder::UnknownKind,
+                AnyCssRule::ScssDeclaration(_) => NodeKindOrder::ScssVariable,
             },
             AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(decl_with_semicolon) => {
                 let Some(decl) = decl_with_semicolon.declaration().ok() else {
@@ -267,6 +271,9 @@ impl RecessOrderMember {
                                 NodeKindOrder::CustomProperty
                             }
                             AnyCssDeclarationName::CssIdentifier(_) => NodeKindOrder::Declaration,
+                            AnyCssDeclarationName::ScssInterpolatedIdentifier(_) => {
+                                NodeKindOrder::UnknownKind
+                            }
                         }
                     }
                 }
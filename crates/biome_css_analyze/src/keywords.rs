@@ -5743,6 +5743,15 @@ pub(crate) const MATH_ML_TAGS: [&str; 32] = [
     "semantics",
 ];
 
+// https://sass-lang.com/documentation/at-rules/
+pub const SCSS_AT_RULES: [&str; 12] = [
+    "at-root", "content", "debug", "each", "else", "error", "for", "function", "if", "return",
+    "warn", "while",
+];
+
+// https://sass-lang.com/documentation/at-rules/control/
+pub const SCSS_CONTROL_FLOW_AT_RULES: [&str; 5] = ["each", "else", "for", "if", "while"];
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
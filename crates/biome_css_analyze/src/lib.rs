@@ -17,11 +17,12 @@ use biome_analyze::{
     ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
     to_analyzer_suppressions,
 };
-use biome_css_syntax::{CssLanguage, TextRange};
+use biome_css_syntax::{CssFileSource, CssLanguage, TextRange};
 use biome_diagnostics::Error;
+use biome_module_graph::ModuleGraph;
 use biome_suppression::{SuppressionDiagnostic, parse_suppression_comment};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 pub(crate) type CssRuleAction = RuleAction<CssLanguage>;
 
@@ -31,6 +32,30 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     metadata
 });
 
+#[derive(Default)]
+pub struct CssAnalyzerServices {
+    module_graph: Arc<ModuleGraph>,
+    file_source: CssFileSource,
+}
+
+impl From<(Arc<ModuleGraph>, CssFileSource)> for CssAnalyzerServices {
+    fn from((module_graph, file_source): (Arc<ModuleGraph>, CssFileSource)) -> Self {
+        Self {
+            module_graph,
+            file_source,
+        }
+    }
+}
+
+impl From<CssFileSource> for CssAnalyzerServices {
+    fn from(file_source: CssFileSource) -> Self {
+        Self {
+            module_graph: Default::default(),
+            file_source,
+        }
+    }
+}
+
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action
@@ -38,6 +63,7 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    services: CssAnalyzerServices,
     plugins: AnalyzerPluginSlice<'a>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
@@ -45,7 +71,15 @@ where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, plugins, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        services,
+        plugins,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -59,6 +93,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    css_services: CssAnalyzerServices,
     plugins: AnalyzerPluginSlice<'a>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
@@ -96,13 +131,20 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors, categories) = registry.build();
+    let (registry, mut services, diagnostics, visitors, categories) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
         return (None, diagnostics);
     }
 
+    let CssAnalyzerServices {
+        module_graph,
+        file_source,
+    } = css_services;
+    services.insert_service(file_source);
+    services.insert_service(module_graph);
+
     let mut analyzer = biome_analyze::Analyzer::new(
         METADATA.deref(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
@@ -139,7 +181,7 @@ mod tests {
     use biome_console::fmt::{Formatter, Termcolor};
     use biome_console::{Markup, markup};
     use biome_css_parser::{CssParserOptions, parse_css};
-    use biome_css_syntax::TextRange;
+    use biome_css_syntax::{CssFileSource, TextRange};
     use biome_diagnostics::termcolor::NoColor;
     use biome_diagnostics::{
         Diagnostic, DiagnosticExt, PrintDiagnostic, Severity, category, print_diagnostic_to_string,
@@ -192,6 +234,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            CssFileSource::css().into(),
             &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
@@ -237,18 +280,25 @@ mod tests {
         };
 
         let options = AnalyzerOptions::default();
-        analyze(&parsed.tree(), filter, &options, &[], |signal| {
-            if let Some(diag) = signal.diagnostic() {
-                let error = diag
-                    .with_file_path("dummyFile")
-                    .with_file_source_code(SOURCE);
-                let text = print_diagnostic_to_string(&error);
-                eprintln!("{text}");
-                panic!("Unexpected diagnostic");
-            }
-
-            ControlFlow::<Never>::Continue(())
-        });
+        analyze(
+            &parsed.tree(),
+            filter,
+            &options,
+            CssFileSource::css().into(),
+            &[],
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let error = diag
+                        .with_file_path("dummyFile")
+                        .with_file_source_code(SOURCE);
+                    let text = print_diagnostic_to_string(&error);
+                    eprintln!("{text}");
+                    panic!("Unexpected diagnostic");
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
     }
 
     #[test]
@@ -277,18 +327,25 @@ a {
         };
 
         let options = AnalyzerOptions::default();
-        analyze(&parsed.tree(), filter, &options, &[], |signal| {
-            if let Some(diag) = signal.diagnostic() {
-                let error = diag
-                    .with_file_path("dummyFile")
-                    .with_file_source_code(SOURCE);
-                let text = print_diagnostic_to_string(&error);
-                eprintln!("{text}");
-                panic!("Unexpected diagnostic");
-            }
-
-            ControlFlow::<Never>::Continue(())
-        });
+        analyze(
+            &parsed.tree(),
+            filter,
+            &options,
+            CssFileSource::css().into(),
+            &[],
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let error = diag
+                        .with_file_path("dummyFile")
+                        .with_file_source_code(SOURCE);
+                    let text = print_diagnostic_to_string(&error);
+                    eprintln!("{text}");
+                    panic!("Unexpected diagnostic");
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
     }
 
     #[test]
@@ -313,18 +370,25 @@ a {
         };
 
         let options = AnalyzerOptions::default();
-        analyze(&parsed.tree(), filter, &options, &[], |signal| {
-            if let Some(diag) = signal.diagnostic() {
-                let error = diag
-                    .with_file_path("dummyFile")
-                    .with_file_source_code(SOURCE);
-                let text = print_diagnostic_to_string(&error);
-                eprintln!("{text}");
-                panic!("Unexpected diagnostic");
-            }
-
-            ControlFlow::<Never>::Continue(())
-        });
+        analyze(
+            &parsed.tree(),
+            filter,
+            &options,
+            CssFileSource::css().into(),
+            &[],
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let error = diag
+                        .with_file_path("dummyFile")
+                        .with_file_source_code(SOURCE);
+                    let text = print_diagnostic_to_string(&error);
+                    eprintln!("{text}");
+                    panic!("Unexpected diagnostic");
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
     }
 
     #[test]
@@ -346,15 +410,22 @@ a {
         };
 
         let options = AnalyzerOptions::default();
-        analyze(&parsed.tree(), filter, &options, &[], |signal| {
-            if let Some(diag) = signal.diagnostic() {
-                let code = diag.category().unwrap();
-                if code != category!("suppressions/unused") {
-                    panic!("unexpected diagnostic {code:?}");
+        analyze(
+            &parsed.tree(),
+            filter,
+            &options,
+            CssFileSource::css().into(),
+            &[],
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let code = diag.category().unwrap();
+                    if code != category!("suppressions/unused") {
+                        panic!("unexpected diagnostic {code:?}");
+                    }
                 }
-            }
 
-            ControlFlow::<Never>::Continue(())
-        });
+                ControlFlow::<Never>::Continue(())
+            },
+        );
     }
 }
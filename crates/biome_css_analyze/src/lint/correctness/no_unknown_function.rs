@@ -2,7 +2,7 @@ use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::CssFunction;
+use biome_css_syntax::{CssFileSource, CssFunction};
 use biome_diagnostics::Severity;
 use biome_rowan::{AstNode, TextRange};
 
@@ -13,6 +13,9 @@ declare_lint_rule! {
     ///
     /// This rule ignores double-dashed custom functions, e.g. `--custom-function()`.
     ///
+    /// In SCSS files, functions can be defined with `@function` or come from Sass modules,
+    /// so this rule doesn't report any function there.
+    ///
     /// Data sources of known CSS value functions are:
     /// - MDN reference on [CSS value functions](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Functions)
     /// - MDN reference on [CSS reference](https://developer.mozilla.org/en-US/docs/Web/CSS/Reference)
@@ -54,6 +57,11 @@ impl Rule for NoUnknownFunction {
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        // Sass functions can't be resolved without a semantic model either.
+        if ctx.source_type::<CssFileSource>().is_scss() {
+            return None;
+        }
+
         let node = ctx.query();
         let binding = node.name().ok()?.value_token().ok()?;
         let function_name = binding.text_trimmed();
@@ -2,7 +2,9 @@ use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::CssGenericProperty;
+use biome_css_syntax::{
+    AnyCssDeclarationName, CssDeclarationOrRuleBlock, CssGenericProperty, ScssNestedProperty,
+};
 use biome_diagnostics::Severity;
 use biome_rowan::{AstNode, TextRange};
 use biome_string_case::StrLikeExtension;
@@ -20,6 +22,10 @@ declare_lint_rule! {
     ///
     /// - custom variables e.g. `--custom-property`
     /// - vendor-prefixed properties (e.g., `-moz-align-self,` `-webkit-align-self`)
+    /// - SCSS properties whose name contains interpolations (e.g., `#{$side}-margin`)
+    ///
+    /// The properties declared in a SCSS nested property are prefixed with its name,
+    /// so `family` is checked as `font-family` in `font: { family: serif; }`.
     ///
     /// ## Examples
     ///
@@ -75,7 +81,7 @@ impl Rule for NoUnknownProperty {
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
-        let property_name = node.name().ok()?.to_trimmed_text();
+        let property_name = full_property_name(node)?;
         let property_name_lower = property_name.to_ascii_lowercase_cow();
         if !property_name_lower.starts_with("--")
             // Ignore `composes` property.
@@ -107,3 +113,36 @@ impl Rule for NoUnknownProperty {
         )
     }
 }
+
+/// Returns the name of the property, prefixed with the names of the SCSS nested
+/// properties that contain it.
+///
+/// Returns `None` when a name contains interpolations, because it's only known
+/// once Sass evaluates them.
+fn full_property_name(node: &CssGenericProperty) -> Option<String> {
+    let mut name = declaration_name(&node.name().ok()?)?;
+    let mut block = node
+        .syntax()
+        .ancestors()
+        .find_map(CssDeclarationOrRuleBlock::cast);
+
+    while let Some(nested_property) = block.and_then(|block| block.parent::<ScssNestedProperty>()) {
+        let prefix = declaration_name(&nested_property.name().ok()?)?;
+        name = format!("{prefix}-{name}");
+        block = nested_property
+            .syntax()
+            .ancestors()
+            .find_map(CssDeclarationOrRuleBlock::cast);
+    }
+
+    Some(name)
+}
+
+fn declaration_name(name: &AnyCssDeclarationName) -> Option<String> {
+    match name {
+        AnyCssDeclarationName::CssDashedIdentifier(_) | AnyCssDeclarationName::CssIdentifier(_) => {
+            Some(name.to_trimmed_text().to_string())
+        }
+        AnyCssDeclarationName::ScssInterpolatedIdentifier(_) => None,
+    }
+}
@@ -2,9 +2,9 @@ use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::CssTypeSelector;
+use biome_css_syntax::{CssCompoundSelector, CssFileSource, CssTypeSelector};
 use biome_diagnostics::Severity;
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, AstNodeList};
 
 use crate::utils::is_known_type_selector;
 
@@ -20,6 +20,9 @@ declare_lint_rule! {
     ///
     /// This rule allows custom elements.
     ///
+    /// In SCSS files, a name that follows the parent selector, like `&__element`, is a suffix
+    /// of the parent selector and isn't reported.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -68,6 +71,15 @@ impl Rule for NoUnknownTypeSelector {
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let css_type_selector = ctx.query();
+
+        if ctx.source_type::<CssFileSource>().is_scss()
+            && css_type_selector
+                .parent::<CssCompoundSelector>()
+                .is_some_and(|selector| !selector.nesting_selectors().is_empty())
+        {
+            return None;
+        }
+
         let type_selector = css_type_selector
             .ident()
             .ok()?
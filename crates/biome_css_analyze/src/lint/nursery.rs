@@ -5,5 +5,7 @@
 use biome_analyze::declare_lint_group;
 pub mod no_important_styles;
 pub mod no_unknown_at_rule;
+pub mod no_unresolved_css_imports;
+pub mod no_unused_css_module_classes;
 pub mod no_useless_escape_in_string;
-declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: no_important_styles :: NoImportantStyles , self :: no_unknown_at_rule :: NoUnknownAtRule , self :: no_useless_escape_in_string :: NoUselessEscapeInString ,] } }
+declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: no_important_styles :: NoImportantStyles , self :: no_unknown_at_rule :: NoUnknownAtRule , self :: no_unresolved_css_imports :: NoUnresolvedCssImports , self :: no_unused_css_module_classes :: NoUnusedCssModuleClasses , self :: no_useless_escape_in_string :: NoUselessEscapeInString ,] } }
@@ -2,15 +2,19 @@ use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::{CssUnknownBlockAtRule, CssUnknownValueAtRule};
+use biome_css_syntax::{CssFileSource, CssUnknownBlockAtRule, CssUnknownValueAtRule};
 use biome_diagnostics::Severity;
 use biome_rowan::{AstNode, TextRange, declare_node_union};
 
+use crate::keywords::SCSS_AT_RULES;
+
 declare_lint_rule! {
     /// Disallow unknown at-rules.
     ///
     /// For details on known at-rules, see the [MDN web docs](https://developer.mozilla.org/en-US/docs/Web/CSS/At-rule).
     ///
+    /// In SCSS files, the [Sass at-rules](https://sass-lang.com/documentation/at-rules/) are known as well.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -69,9 +73,15 @@ impl Rule for NoUnknownAtRule {
             AnyUnknownAtRule::CssUnknownBlockAtRule(rule) => rule.name().ok()?,
             AnyUnknownAtRule::CssUnknownValueAtRule(rule) => rule.name().ok()?,
         };
+        let name = rule.value_token().ok()?;
+        let name = name.text_trimmed();
+        if ctx.source_type::<CssFileSource>().is_scss() && SCSS_AT_RULES.contains(&name) {
+            return None;
+        }
+
         Some(NoUnknownAtRuleState {
             range: rule.range(),
-            name: rule.value_token().ok()?.text_trimmed().to_string(),
+            name: name.to_string(),
         })
     }
 
@@ -0,0 +1,95 @@
+use biome_analyze::{Rule, RuleDiagnostic, RuleDomain, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_css_syntax::CssRoot;
+use biome_module_graph::{CssReference, CssReferenceKind};
+
+use crate::services::module_graph::ResolvedImports;
+
+declare_lint_rule! {
+    /// Disallow references to stylesheets and resources that cannot be resolved.
+    ///
+    /// Stylesheets refer to other files through `@import` rules, `url()`
+    /// functions, and, in CSS Modules, through the `composes` property. In
+    /// SCSS files, the `@use` and `@forward` rules are checked as well. A
+    /// reference to a file that doesn't exist is an error at build time, or
+    /// results in missing styles or resources at runtime.
+    ///
+    /// Specifiers are resolved relative to the stylesheet. Packages are
+    /// resolved from `node_modules` as a fallback, and the `~` prefix that is
+    /// used by webpack to refer to packages is supported too.
+    ///
+    /// URLs with a scheme, such as `https:` or `data:`, root-relative URLs,
+    /// and specifiers containing SCSS interpolation or variables are not
+    /// checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css
+    /// @import "./missing.css";
+    /// ```
+    ///
+    /// ```css
+    /// .logo {
+    ///   background-image: url("./missing.png");
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @import "https://example.com/style.css";
+    /// ```
+    ///
+    pub NoUnresolvedCssImports {
+        version: "next",
+        name: "noUnresolvedCssImports",
+        language: "css",
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+impl Rule for NoUnresolvedCssImports {
+    type Query = ResolvedImports<CssRoot>;
+    type State = CssReference;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(module_info) = ctx.css_module_info_for_path(ctx.file_path()) else {
+            return Box::default();
+        };
+
+        module_info
+            .references
+            .iter()
+            .filter(|reference| reference.resolved_path.error().is_some())
+            .cloned()
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, reference: &Self::State) -> Option<RuleDiagnostic> {
+        let resolve_error = reference.resolved_path.error()?;
+        let specifier = reference.specifier.text();
+        let specifier_kind = match &reference.kind {
+            CssReferenceKind::Import => "stylesheet",
+            CssReferenceKind::Url => "URL",
+            CssReferenceKind::Composes { .. } => "CSS Module",
+        };
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                reference.range,
+                markup! {
+                    "The "{specifier_kind}" "<Emphasis>{specifier}</Emphasis>" cannot be resolved: "<Emphasis>{resolve_error.to_string()}</Emphasis>
+                },
+            )
+            .note(markup! {
+                "Make sure that the path exists and is readable."
+            }),
+        )
+    }
+}
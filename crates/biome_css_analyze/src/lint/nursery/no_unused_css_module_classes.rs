@@ -0,0 +1,112 @@
+use biome_analyze::{Rule, RuleDiagnostic, RuleDomain, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_css_syntax::CssRoot;
+use biome_module_graph::{dashes_to_camel_case, is_css_module_path};
+use biome_rowan::{Text, TextRange};
+
+use crate::services::module_graph::ResolvedImports;
+
+declare_lint_rule! {
+    /// Disallow classes of CSS Modules that are never used.
+    ///
+    /// The classes of a CSS Module are scoped to the modules that import it,
+    /// so a class that none of them references is dead code.
+    ///
+    /// A class is used if a JavaScript module accesses it on the default or
+    /// namespace import of the CSS Module, such as `styles.button` or
+    /// `styles["button"]`, or if it's composed by another class through
+    /// `composes`. Classes with dashes in their name are also used when they
+    /// are accessed in camel case, such as `styles.primaryButton` for
+    /// `.primary-button`.
+    ///
+    /// This rule only checks files ending with `.module.css` or
+    /// `.module.scss`. It doesn't report anything when the CSS Module isn't
+    /// imported by any JavaScript module of the project, or when the classes
+    /// that are used cannot be determined statically, for instance because
+    /// the CSS Module is imported dynamically or because the import is passed
+    /// to a function.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// **`button.module.css`**
+    /// ```css
+    /// .button {
+    ///   color: blue;
+    /// }
+    ///
+    /// .unused {
+    ///   color: red;
+    /// }
+    /// ```
+    ///
+    /// **`button.jsx`**
+    /// ```jsx
+    /// import styles from "./button.module.css";
+    ///
+    /// export const Button = () => <button className={styles.button} />;
+    /// ```
+    ///
+    pub NoUnusedCssModuleClasses {
+        version: "next",
+        name: "noUnusedCssModuleClasses",
+        language: "css",
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+impl Rule for NoUnusedCssModuleClasses {
+    type Query = ResolvedImports<CssRoot>;
+    type State = (Text, TextRange);
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let path = ctx.file_path();
+        if !is_css_module_path(path) {
+            return Box::default();
+        }
+
+        let Some(module_info) = ctx.css_module_info_for_path(path) else {
+            return Box::default();
+        };
+
+        let Some(referenced_classes) = ctx.module_graph().referenced_css_module_classes(path)
+        else {
+            return Box::default();
+        };
+
+        module_info
+            .classes
+            .iter()
+            .filter(|(class, _)| {
+                let is_used = referenced_classes.contains(*class)
+                    || module_info.locally_composed_classes.contains(*class)
+                    || class.contains('-')
+                        && referenced_classes.contains(dashes_to_camel_case(class).as_str());
+                !is_used
+            })
+            .map(|(class, range)| (class.clone(), *range))
+            .collect()
+    }
+
+    fn diagnostic(
+        _ctx: &RuleContext<Self>,
+        (class, range): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The class "<Emphasis>{class.text()}</Emphasis>" is never used by the modules that import this CSS Module."
+                },
+            )
+            .note(markup! {
+                "Classes of CSS Modules are scoped to the modules that import them. Remove the class if it's no longer needed."
+            }),
+        )
+    }
+}
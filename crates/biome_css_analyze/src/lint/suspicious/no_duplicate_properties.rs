@@ -1,11 +1,15 @@
 use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
 use biome_console::markup;
-use biome_css_syntax::{CssDeclarationOrRuleList, CssKeyframesAtRule};
+use biome_css_syntax::{
+    CssDeclarationOrRuleList, CssFileSource, CssKeyframesAtRule, CssSyntaxNode,
+    CssUnknownBlockAtRule,
+};
 use biome_diagnostics::Severity;
 use biome_rowan::{AstNode, TextRange};
 use rustc_hash::FxHashMap;
 use std::collections::hash_map::Entry;
 
+use crate::keywords::SCSS_CONTROL_FLOW_AT_RULES;
 use crate::services::semantic::Semantic;
 
 declare_lint_rule! {
@@ -13,6 +17,9 @@ declare_lint_rule! {
     ///
     /// This rule checks the declaration blocks for duplicate properties. It ignores custom properties.
     ///
+    /// In SCSS files, the properties declared in the branches of control-flow at-rules, like
+    /// `@if` and `@else`, are only compared with the properties of the same branch.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -52,10 +59,23 @@ impl Rule for NoDuplicateProperties {
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
         let model = ctx.model();
+        let is_scss = ctx.source_type::<CssFileSource>().is_scss();
+
+        // The declarations of a control-flow at-rule belong to the enclosing
+        // rule, so they're checked along with the declarations of the rule.
+        if is_scss
+            && node
+                .syntax()
+                .grand_parent()
+                .and_then(CssUnknownBlockAtRule::cast)
+                .is_some_and(|at_rule| is_scss_control_flow_at_rule(&at_rule))
+        {
+            return None;
+        }
 
         let rule = model.get_rule_by_range(node.range())?;
 
-        let mut seen: FxHashMap<Box<str>, TextRange> = FxHashMap::default();
+        let mut seen: FxHashMap<(Option<TextRange>, Box<str>), TextRange> = FxHashMap::default();
 
         for declaration in rule.declarations() {
             let prop = declaration.property();
@@ -77,7 +97,15 @@ impl Rule for NoDuplicateProperties {
                 continue;
             }
 
-            match seen.entry(prop_name.clone().into()) {
+            // The branches of `@if` and `@else` are exclusive, so a property
+            // can be declared once per branch.
+            let branch = if is_scss {
+                scss_control_flow_branch(prop.syntax())
+            } else {
+                None
+            };
+
+            match seen.entry((branch, prop_name.clone().into())) {
                 Entry::Occupied(entry) => {
                     return Some((*entry.get(), (prop_range, prop_name.into())));
                 }
@@ -109,3 +137,18 @@ impl Rule for NoDuplicateProperties {
         )
     }
 }
+
+/// Returns the range of the innermost SCSS control-flow at-rule that contains the node.
+fn scss_control_flow_branch(node: &CssSyntaxNode) -> Option<TextRange> {
+    node.ancestors()
+        .filter_map(CssUnknownBlockAtRule::cast)
+        .find(is_scss_control_flow_at_rule)
+        .map(|at_rule| at_rule.range())
+}
+
+fn is_scss_control_flow_at_rule(at_rule: &CssUnknownBlockAtRule) -> bool {
+    at_rule
+        .name()
+        .and_then(|name| name.value_token())
+        .is_ok_and(|token| SCSS_CONTROL_FLOW_AT_RULES.contains(&token.text_trimmed()))
+}
@@ -35,6 +35,8 @@ pub type NoUnknownTypeSelector = < lint :: correctness :: no_unknown_type_select
 pub type NoUnknownUnit =
     <lint::correctness::no_unknown_unit::NoUnknownUnit as biome_analyze::Rule>::Options;
 pub type NoUnmatchableAnbSelector = < lint :: correctness :: no_unmatchable_anb_selector :: NoUnmatchableAnbSelector as biome_analyze :: Rule > :: Options ;
+pub type NoUnresolvedCssImports = < lint :: nursery :: no_unresolved_css_imports :: NoUnresolvedCssImports as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedCssModuleClasses = < lint :: nursery :: no_unused_css_module_classes :: NoUnusedCssModuleClasses as biome_analyze :: Rule > :: Options ;
 pub type NoUselessEscapeInString = < lint :: nursery :: no_useless_escape_in_string :: NoUselessEscapeInString as biome_analyze :: Rule > :: Options ;
 pub type NoValueAtRule =
     <lint::style::no_value_at_rule::NoValueAtRule as biome_analyze::Rule>::Options;
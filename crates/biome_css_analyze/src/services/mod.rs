@@ -1 +1,2 @@
+pub mod module_graph;
 pub mod semantic;
@@ -1,6 +1,6 @@
 use biome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleFilter};
 use biome_css_parser::{CssParserOptions, parse_css};
-use biome_css_syntax::TextRange;
+use biome_css_syntax::{CssFileSource, TextRange};
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Severity, print_diagnostic_to_string};
 use std::slice;
 
@@ -48,6 +48,7 @@ fn quick_test() {
             ..AnalysisFilter::default()
         },
         &options,
+        CssFileSource::css().into(),
         &[],
         |signal| {
             if let Some(diag) = signal.diagnostic() {
@@ -0,0 +1,7 @@
+import styles from "./button.module.css";
+
+export const Button = () => (
+  <button className={`${styles.button} ${styles.primaryButton}`}>
+    <span className={styles["icon"]} />
+  </button>
+);
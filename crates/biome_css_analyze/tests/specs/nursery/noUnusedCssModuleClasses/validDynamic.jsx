@@ -0,0 +1,3 @@
+import styles from "./validDynamic.module.css";
+
+export const Text = ({ size }) => <span className={styles[size]} />;
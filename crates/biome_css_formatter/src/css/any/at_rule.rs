@@ -32,6 +32,11 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssUnknownValueAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssValueAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssViewTransitionAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssExtendAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssForwardAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIncludeAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssMixinAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssUseAtRule(node) => node.format().fmt(f),
         }
     }
 }
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyCssSelectorName;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssSelectorName;
+impl FormatRule<AnyCssSelectorName> for FormatAnyCssSelectorName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssSelectorName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssSelectorName::CssCustomIdentifier(node) => node.format().fmt(f),
+            AnyCssSelectorName::ScssInterpolation(node) => node.format().fmt(f),
+        }
+    }
+}
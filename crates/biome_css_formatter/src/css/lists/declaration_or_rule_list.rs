@@ -1,5 +1,7 @@
+use crate::css::lists::rule_list::is_scss_else_clause;
 use crate::prelude::*;
 use biome_css_syntax::CssDeclarationOrRuleList;
+use biome_formatter::format_args;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatCssDeclarationOrRuleList;
 impl FormatRule<CssDeclarationOrRuleList> for FormatCssDeclarationOrRuleList {
@@ -9,11 +11,25 @@ impl FormatRule<CssDeclarationOrRuleList> for FormatCssDeclarationOrRuleList {
         // lines from the input, so we can use `join_nodes_with_hardline`.
         let mut join = f.join_nodes_with_hardline();
 
-        for declaration_or_rule in node {
-            join.entry(
-                declaration_or_rule.syntax(),
-                &format_or_verbatim(declaration_or_rule.format()),
-            );
+        for (index, declaration_or_rule) in node.iter().enumerate() {
+            // The `@else` clause of a SCSS `@if` at-rule stays on the line of
+            // the closing curly brace of the previous clause.
+            let is_else_clause = declaration_or_rule
+                .as_any_css_rule()
+                .and_then(|rule| rule.as_css_at_rule())
+                .is_some_and(is_scss_else_clause);
+
+            if index > 0 && is_else_clause {
+                join.entry_no_separator(&format_args![
+                    space(),
+                    format_or_verbatim(declaration_or_rule.format())
+                ]);
+            } else {
+                join.entry(
+                    declaration_or_rule.syntax(),
+                    &format_or_verbatim(declaration_or_rule.format()),
+                );
+            }
         }
 
         join.finish()
@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use biome_css_syntax::CssRuleList;
+use biome_css_syntax::{CssAtRule, CssRuleList};
+use biome_formatter::format_args;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatCssRuleList;
 impl FormatRule<CssRuleList> for FormatCssRuleList {
@@ -9,10 +10,22 @@ impl FormatRule<CssRuleList> for FormatCssRuleList {
         // lines from the input, so we can use `join_nodes_with_hardline`.
         let mut join = f.join_nodes_with_hardline();
 
-        for rule in node {
-            join.entry(rule.syntax(), &format_or_verbatim(rule.format()));
+        for (index, rule) in node.iter().enumerate() {
+            // The `@else` clause of a SCSS `@if` at-rule stays on the line of
+            // the closing curly brace of the previous clause.
+            if index > 0 && rule.as_css_at_rule().is_some_and(is_scss_else_clause) {
+                join.entry_no_separator(&format_args![space(), format_or_verbatim(rule.format())]);
+            } else {
+                join.entry(rule.syntax(), &format_or_verbatim(rule.format()));
+            }
         }
 
         join.finish()
     }
 }
+
+/// Checks if the at-rule is an `@else` clause that can be written on the line
+/// of the previous clause, because it isn't preceded by a comment.
+pub(crate) fn is_scss_else_clause(at_rule: &CssAtRule) -> bool {
+    at_rule.is_scss_else_clause() && !at_rule.syntax().has_leading_comments()
+}
@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyScssArgument;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyScssArgument;
+impl FormatRule<AnyScssArgument> for FormatAnyScssArgument {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyScssArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyScssArgument::CssListOfComponentValuesExpression(node) => node.format().fmt(f),
+            AnyScssArgument::ScssKeywordArgument(node) => node.format().fmt(f),
+            AnyScssArgument::ScssRestArgument(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod argument;
+pub(crate) mod forward_member;
+pub(crate) mod include_name;
+pub(crate) mod interpolated_identifier_part;
+pub(crate) mod module_member;
+pub(crate) mod use_namespace_name;
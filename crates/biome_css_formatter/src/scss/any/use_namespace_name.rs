@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyScssUseNamespaceName;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyScssUseNamespaceName;
+impl FormatRule<AnyScssUseNamespaceName> for FormatAnyScssUseNamespaceName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyScssUseNamespaceName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyScssUseNamespaceName::CssIdentifier(node) => node.format().fmt(f),
+            AnyScssUseNamespaceName::ScssUseAllNamespace(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssForwardPrefix, ScssForwardPrefixFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForwardPrefix;
+impl FormatNodeRule<ScssForwardPrefix> for FormatScssForwardPrefix {
+    fn fmt_fields(&self, node: &ScssForwardPrefix, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssForwardPrefixFields {
+            as_token,
+            prefix,
+            star_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                as_token.format(),
+                space(),
+                prefix.format(),
+                star_token.format()
+            ]
+        )
+    }
+}
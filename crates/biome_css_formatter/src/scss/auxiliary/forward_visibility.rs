@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssForwardVisibility, ScssForwardVisibilityFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForwardVisibility;
+impl FormatNodeRule<ScssForwardVisibility> for FormatScssForwardVisibility {
+    fn fmt_fields(&self, node: &ScssForwardVisibility, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssForwardVisibilityFields { modifier, members } = node.as_fields();
+
+        write!(
+            f,
+            [
+                modifier.format(),
+                space(),
+                group(&indent(&members.format()))
+            ]
+        )
+    }
+}
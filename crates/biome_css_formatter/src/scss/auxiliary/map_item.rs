@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssMapItem, ScssMapItemFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssMapItem;
+impl FormatNodeRule<ScssMapItem> for FormatScssMapItem {
+    fn fmt_fields(&self, node: &ScssMapItem, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssMapItemFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [key.format(), colon_token.format(), space(), value.format()]
+        )
+    }
+}
@@ -0,0 +1,19 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod arguments;
+pub(crate) mod binary_expression;
+pub(crate) mod declaration;
+pub(crate) mod extend_optional_modifier;
+pub(crate) mod forward_prefix;
+pub(crate) mod forward_visibility;
+pub(crate) mod keyword_argument;
+pub(crate) mod map_item;
+pub(crate) mod module_configuration;
+pub(crate) mod parameter;
+pub(crate) mod parameter_default_value;
+pub(crate) mod parameters;
+pub(crate) mod rest_argument;
+pub(crate) mod unary_expression;
+pub(crate) mod use_all_namespace;
+pub(crate) mod use_namespace;
+pub(crate) mod variable_modifier;
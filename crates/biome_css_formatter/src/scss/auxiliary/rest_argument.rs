@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssRestArgument, ScssRestArgumentFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssRestArgument;
+impl FormatNodeRule<ScssRestArgument> for FormatScssRestArgument {
+    fn fmt_fields(&self, node: &ScssRestArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssRestArgumentFields {
+            value,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        write!(f, [value.format(), dotdotdot_token.format()])
+    }
+}
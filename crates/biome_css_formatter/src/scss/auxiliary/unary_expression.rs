@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssUnaryExpression, ScssUnaryExpressionFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssUnaryExpression;
+impl FormatNodeRule<ScssUnaryExpression> for FormatScssUnaryExpression {
+    fn fmt_fields(&self, node: &ScssUnaryExpression, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssUnaryExpressionFields {
+            operator,
+            expression,
+        } = node.as_fields();
+
+        write!(f, [operator.format(), expression.format()])
+    }
+}
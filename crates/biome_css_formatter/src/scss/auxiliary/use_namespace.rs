@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssUseNamespace, ScssUseNamespaceFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssUseNamespace;
+impl FormatNodeRule<ScssUseNamespace> for FormatScssUseNamespace {
+    fn fmt_fields(&self, node: &ScssUseNamespace, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssUseNamespaceFields { as_token, name } = node.as_fields();
+
+        write!(f, [as_token.format(), space(), name.format()])
+    }
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssParameterList;
+use biome_formatter::separated::TrailingSeparator;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssParameterList;
+impl FormatRule<ScssParameterList> for FormatScssParameterList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &ScssParameterList, f: &mut CssFormatter) -> FormatResult<()> {
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node
+            .format_separated(",")
+            .with_trailing_separator(TrailingSeparator::Allowed)
+        {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssNestedProperty, ScssNestedPropertyFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssNestedProperty;
+impl FormatNodeRule<ScssNestedProperty> for FormatScssNestedProperty {
+    fn fmt_fields(&self, node: &ScssNestedProperty, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssNestedPropertyFields {
+            name,
+            colon_token,
+            value,
+            block,
+        } = node.as_fields();
+
+        write!(f, [name.format(), colon_token.format(), space()])?;
+
+        // `font: {` has no value, while `font: bold {` has one.
+        if !value.is_empty() {
+            write!(f, [value.format(), space()])?;
+        }
+
+        write!(f, [block.format()])
+    }
+}
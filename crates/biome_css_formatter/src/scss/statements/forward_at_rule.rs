@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssForwardAtRule, ScssForwardAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForwardAtRule;
+impl FormatNodeRule<ScssForwardAtRule> for FormatScssForwardAtRule {
+    fn fmt_fields(&self, node: &ScssForwardAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssForwardAtRuleFields {
+            forward_token,
+            url,
+            prefix,
+            visibility,
+            configuration,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(f, [forward_token.format(), space(), url.format()])?;
+
+        if let Some(prefix) = prefix {
+            write!(f, [space(), prefix.format()])?;
+        }
+
+        if let Some(visibility) = visibility {
+            write!(f, [space(), visibility.format()])?;
+        }
+
+        if let Some(configuration) = configuration {
+            write!(f, [space(), configuration.format()])?;
+        }
+
+        write!(f, [semicolon_token.format()])
+    }
+}
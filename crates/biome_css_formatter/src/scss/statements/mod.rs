@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod extend_at_rule;
+pub(crate) mod forward_at_rule;
+pub(crate) mod include_at_rule;
+pub(crate) mod mixin_at_rule;
+pub(crate) mod use_at_rule;
@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod identifier;
+pub(crate) mod interpolated_identifier;
+pub(crate) mod interpolation;
+pub(crate) mod map_expression;
+pub(crate) mod qualified_name;
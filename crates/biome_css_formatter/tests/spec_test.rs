@@ -1,6 +1,7 @@
 use biome_configuration::css::CssFormatterConfiguration;
 use biome_configuration::{Configuration, CssConfiguration};
 use biome_css_formatter::{CssFormatLanguage, context::CssFormatOptions};
+use biome_css_syntax::CssFileSource;
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_service::workspace::UpdateSettingsParams;
 use camino::Utf8Path;
@@ -50,7 +51,8 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
     };
 
     let options = CssFormatOptions::default();
-    let language = language::CssTestFormatLanguage::default();
+    let source_type = CssFileSource::try_from(Utf8Path::new(spec_input_file)).unwrap_or_default();
+    let language = language::CssTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(
         test_file,
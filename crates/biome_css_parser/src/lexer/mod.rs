@@ -311,6 +311,12 @@ impl<'src> CssLexer<'src> {
             PRD => {
                 if self.is_number_start() {
                     self.consume_number(current)
+                } else if self.options.is_scss_enabled()
+                    && self.peek_byte() == Some(b'.')
+                    && self.byte_at(2) == Some(b'.')
+                {
+                    self.advance(2);
+                    self.consume_byte(T![...])
                 } else {
                     self.consume_byte(T![.])
                 }
@@ -322,6 +328,9 @@ impl<'src> CssLexer<'src> {
                 self.advance(1);
                 self.consume_byte(T!["$="])
             }
+            DOL if self.options.is_scss_enabled() && self.is_scss_variable_start() => {
+                self.consume_byte(T![$])
+            }
             UNI if self.options.is_metavariable_enabled() && self.is_metavariable_start() => {
                 self.consume_metavariable(GRIT_METAVARIABLE)
             }
@@ -922,6 +931,16 @@ impl<'src> CssLexer<'src> {
             b"composes" => COMPOSES_KW,
             b"position-try" => POSITION_TRY_KW,
             b"view-transition" => VIEW_TRANSITION_KW,
+            // SCSS
+            b"use" => USE_KW,
+            b"forward" => FORWARD_KW,
+            b"mixin" => MIXIN_KW,
+            b"include" => INCLUDE_KW,
+            b"extend" => EXTEND_KW,
+            b"with" => WITH_KW,
+            b"show" => SHOW_KW,
+            b"hide" => HIDE_KW,
+            b"optional" => OPTIONAL_KW,
             _ => IDENT,
         }
     }
@@ -1093,7 +1112,9 @@ impl<'src> CssLexer<'src> {
                     COMMENT
                 }
             }
-            Some(b'/') if self.options.allow_wrong_line_comments => {
+            Some(b'/')
+                if self.options.allow_wrong_line_comments || self.options.is_scss_enabled() =>
+            {
                 self.advance(2);
 
                 while let Some(chr) = self.current_byte() {
@@ -1255,6 +1276,14 @@ impl<'src> CssLexer<'src> {
         }
     }
 
+    /// Check if the lexer starts a SCSS variable, a `$` immediately followed by an identifier.
+    fn is_scss_variable_start(&mut self) -> bool {
+        self.advance(1);
+        let is_ident_start = self.is_ident_start();
+        self.position -= 1;
+        is_ident_start
+    }
+
     /// Check if the lexer starts an identifier.
     fn is_ident_start(&self) -> bool {
         // See https://drafts.csswg.org/css-syntax-3/#typedef-ident-token
@@ -33,6 +33,11 @@ pub struct CssParserOptions {
     /// Enables parsing of Grit metavariables.
     /// Defaults to `false`.
     pub grit_metavariables: bool,
+
+    /// Enables parsing of SCSS features, such as variables, mixins and interpolation.
+    /// Comments starting with `//` are also allowed.
+    /// Defaults to `false`.
+    pub scss: bool,
 }
 
 impl CssParserOptions {
@@ -54,6 +59,12 @@ impl CssParserOptions {
         self
     }
 
+    /// Enables parsing of SCSS features.
+    pub fn allow_scss(mut self) -> Self {
+        self.scss = true;
+        self
+    }
+
     /// Checks if parsing of CSS Modules features is disabled.
     pub fn is_css_modules_disabled(&self) -> bool {
         !self.css_modules
@@ -63,6 +74,11 @@ impl CssParserOptions {
     pub fn is_metavariable_enabled(&self) -> bool {
         self.grit_metavariables
     }
+
+    /// Checks if parsing of SCSS features is enabled.
+    pub fn is_scss_enabled(&self) -> bool {
+        self.scss
+    }
 }
 
 impl<'source> CssParser<'source> {
@@ -2,6 +2,7 @@ use super::parse_error::expected_media_query;
 use crate::parser::CssParser;
 use crate::syntax::at_rule::feature::parse_any_query_feature;
 use crate::syntax::block::parse_conditional_block;
+use crate::syntax::scss::{is_at_scss_interpolation, parse_scss_interpolation};
 use crate::syntax::{
     is_at_identifier, is_at_metavariable, is_nth_at_identifier, parse_metavariable,
     parse_regular_identifier,
@@ -82,6 +83,8 @@ fn parse_any_media_query(p: &mut CssParser) -> ParsedSyntax {
         parse_any_media_type_query(p)
     } else if is_at_metavariable(p) {
         parse_metavariable(p)
+    } else if is_at_scss_interpolation(p) {
+        parse_scss_interpolation(p)
     } else if is_at_any_media_condition(p) {
         let m = p.start();
         parse_any_media_condition(p).ok(); // TODO handle error
@@ -2,6 +2,9 @@ use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::ParseBlockBody;
 use crate::syntax::parse_error::expected_any_declaration_or_at_rule;
+use crate::syntax::scss::{
+    is_at_scss_declaration, parse_scss_declaration, parse_scss_nested_property,
+};
 use crate::syntax::{
     is_at_declaration, is_at_declaration_semicolon, is_at_metavariable,
     is_at_nested_qualified_rule, parse_declaration_with_semicolon, parse_empty_declaration,
@@ -37,6 +40,7 @@ impl ParseBlockBody for DeclarationOrRuleListBlock {
 #[inline]
 fn is_at_declaration_or_rule_item(p: &mut CssParser) -> bool {
     is_at_at_rule(p)
+        || is_at_scss_declaration(p)
         || is_at_nested_qualified_rule(p)
         || is_at_declaration(p)
         || is_at_metavariable(p)
@@ -62,7 +66,21 @@ impl ParseNodeList for DeclarationOrRuleList {
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
         if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
         } else if is_at_declaration(p) {
+            // In SCSS, a declaration followed by a block is a nested property.
+            // E.g.
+            // main {
+            //     font: bold {  <---
+            //         family: $font-stack;
+            //     }
+            // }
+            let property = parse_scss_nested_property(p);
+            if property.is_present() {
+                return property;
+            }
+
             // if we are at a declaration,
             // we still can have a nested qualified rule or a declaration
             // E.g.
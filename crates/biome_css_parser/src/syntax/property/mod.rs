@@ -7,6 +7,10 @@ use crate::syntax::css_modules::{
     composes_not_allowed, expected_classes_list, expected_composes_import_source,
 };
 use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::scss::{
+    is_at_scss_interpolated_identifier, is_at_scss_interpolated_property, parse_scss_expression,
+    parse_scss_interpolated_identifier,
+};
 use crate::syntax::{
     is_at_any_value, is_at_dashed_identifier, is_at_identifier, is_at_string, parse_any_value,
     parse_custom_identifier_with_keywords, parse_dashed_identifier, parse_regular_identifier,
@@ -159,7 +163,7 @@ const END_OF_COMPOSES_CLASS_TOKEN_SET: TokenSet<CssSyntaxKind> =
 
 #[inline]
 fn is_at_generic_property(p: &mut CssParser) -> bool {
-    is_at_identifier(p) && p.nth_at(1, T![:])
+    (is_at_identifier(p) && p.nth_at(1, T![:])) || is_at_scss_interpolated_property(p)
 }
 
 #[inline]
@@ -170,21 +174,28 @@ fn parse_generic_property(p: &mut CssParser) -> ParsedSyntax {
 
     let m = p.start();
 
-    if is_at_dashed_identifier(p) {
-        parse_dashed_identifier(p).ok();
-    } else {
-        parse_regular_identifier(p).ok();
-    }
-
+    parse_declaration_name(p).ok();
     p.expect(T![:]);
 
     GenericComponentValueList.parse_list(p);
 
     Present(m.complete(p, CSS_GENERIC_PROPERTY))
 }
+/// Parses the name of a declaration, which can contain interpolations in SCSS.
+#[inline]
+pub(crate) fn parse_declaration_name(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_interpolated_identifier(p) {
+        parse_scss_interpolated_identifier(p)
+    } else if is_at_dashed_identifier(p) {
+        parse_dashed_identifier(p)
+    } else {
+        parse_regular_identifier(p)
+    }
+}
+
 const END_OF_PROPERTY_VALUE_TOKEN_SET: TokenSet<CssSyntaxKind> = token_set!(T!['}'], T![;]);
 
-struct GenericComponentValueList;
+pub(crate) struct GenericComponentValueList;
 
 impl ParseNodeList for GenericComponentValueList {
     type Kind = CssSyntaxKind;
@@ -226,6 +237,8 @@ fn parse_generic_component_value(p: &mut CssParser) -> ParsedSyntax {
 
     if is_at_generic_delimiter(p) {
         parse_generic_delimiter(p)
+    } else if p.options().is_scss_enabled() {
+        parse_scss_expression(p)
     } else {
         parse_any_value(p)
     }
@@ -0,0 +1,53 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::expected_selector;
+use crate::syntax::selector::SelectorList;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, token_set};
+
+#[inline]
+fn is_at_scss_extend_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![extend])
+}
+
+/// Parses the `@extend` at-rule, that makes a selector inherit the styles of another one.
+///
+/// ```scss
+/// .error--serious {
+///     @extend .error;
+///     @extend %message-shared !optional;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_extend_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_extend_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![extend]);
+
+    let selectors = SelectorList::default()
+        .with_end_kind_ts(token_set![T![;], T![!], T!['}']])
+        .with_recovery_ts(token_set![T![;], T![!], T!['}']])
+        .parse_list(p);
+
+    if selectors.range(p).is_empty() {
+        p.error(expected_selector(p, p.cur_range()));
+    }
+
+    if p.at(T![!]) && p.nth_at(1, T![optional]) {
+        let optional = p.start();
+        p.bump(T![!]);
+        p.bump(T![optional]);
+        optional.complete(p, SCSS_EXTEND_OPTIONAL_MODIFIER);
+    }
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_EXTEND_AT_RULE))
+}
@@ -0,0 +1,109 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_identifier, expected_string};
+use crate::syntax::scss::at_rule::module::parse_scss_module_configuration;
+use crate::syntax::scss::parse_error::expected_scss_forward_member;
+use crate::syntax::scss::value::{is_at_scss_identifier, parse_scss_variable_or_identifier};
+use crate::syntax::{is_at_identifier, parse_regular_identifier, parse_string};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, token_set};
+
+#[inline]
+fn is_at_scss_forward_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![forward])
+}
+
+/// Parses the `@forward` at-rule, that makes the members of a module available
+/// to the stylesheets that load the current one.
+///
+/// ```scss
+/// @forward "src/list" hide list-reset, $horizontal-list-gap;
+/// @forward "library" as btn-* show btn-primary;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_forward_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_forward_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![forward]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+    parse_scss_forward_prefix(p).ok();
+    parse_scss_forward_visibility(p).ok();
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_FORWARD_AT_RULE))
+}
+
+/// Parses the prefix added to the forwarded members, like `as btn-*`.
+#[inline]
+fn parse_scss_forward_prefix(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![as]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![as]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    p.expect(T![*]);
+
+    Present(m.complete(p, SCSS_FORWARD_PREFIX))
+}
+
+#[inline]
+fn parse_scss_forward_visibility(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![show]) && !p.at(T![hide]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump_any();
+    ScssForwardMemberList.parse_list(p);
+
+    Present(m.complete(p, SCSS_FORWARD_VISIBILITY))
+}
+
+struct ScssForwardMemberList;
+
+impl ParseSeparatedList for ScssForwardMemberList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_FORWARD_MEMBER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if is_at_scss_identifier(p) || is_at_identifier(p) {
+            parse_scss_variable_or_identifier(p)
+        } else {
+            Absent
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![;]) || p.at(T![with])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![;]]),
+            expected_scss_forward_member,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
@@ -0,0 +1,135 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::is_at_any_value;
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::scss::parse_error::expected_scss_argument;
+use crate::syntax::scss::value::{
+    ScssComponentValueList, is_at_scss_identifier, parse_scss_identifier, parse_scss_mixin_name,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, TokenSet, token_set};
+
+#[inline]
+fn is_at_scss_include_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![include])
+}
+
+/// Parses the `@include` at-rule, that includes the styles of a mixin.
+///
+/// ```scss
+/// .info {
+///     @include theme;
+///     @include theme($theme: DarkRed);
+///     @include shadows($shadows...);
+///     @include breakpoints.up(md) {
+///         width: 50%;
+///     }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_include_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_include_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![include]);
+    parse_scss_mixin_name(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_arguments(p).ok();
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+    } else if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_INCLUDE_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssArgumentList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_ARGUMENTS))
+}
+
+const SCSS_ARGUMENT_END_TOKEN_SET: TokenSet<CssSyntaxKind> = token_set![T![,], T![')']];
+
+struct ScssArgumentList;
+
+impl ParseSeparatedList for ScssArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_any_scss_argument(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS,
+                SCSS_ARGUMENT_END_TOKEN_SET.union(token_set![T![;], T!['{']]),
+            ),
+            expected_scss_argument,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_any_scss_argument(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_identifier(p) && p.nth_at(2, T![:]) {
+        let m = p.start();
+        parse_scss_identifier(p).ok();
+        p.bump(T![:]);
+        ScssComponentValueList::new(SCSS_ARGUMENT_END_TOKEN_SET).parse_list(p);
+        Present(m.complete(p, SCSS_KEYWORD_ARGUMENT))
+    } else if is_at_any_value(p) {
+        let m = p.start();
+        ScssComponentValueList::new(SCSS_ARGUMENT_END_TOKEN_SET.union(token_set![T![...]]))
+            .parse_list(p);
+
+        // A list or a map that is passed as the rest of the arguments, like `$args...`.
+        let kind = if p.eat(T![...]) {
+            SCSS_REST_ARGUMENT
+        } else {
+            CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION
+        };
+
+        Present(m.complete(p, kind))
+    } else {
+        Absent
+    }
+}
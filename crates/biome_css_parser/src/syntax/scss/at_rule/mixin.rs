@@ -0,0 +1,121 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::parse_regular_identifier;
+use crate::syntax::scss::parse_error::{expected_scss_parameter, expected_scss_variable};
+use crate::syntax::scss::value::{
+    ScssComponentValueList, is_at_scss_identifier, parse_scss_identifier,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, token_set};
+
+#[inline]
+fn is_at_scss_mixin_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![mixin])
+}
+
+/// Parses the `@mixin` at-rule, that defines styles that can be reused with `@include`.
+///
+/// ```scss
+/// @mixin theme($theme: DarkGray) {
+///     background: $theme;
+/// }
+///
+/// @mixin shadows($shadows...) {
+///     box-shadow: $shadows;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_mixin_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_mixin_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![mixin]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_parameters(p).ok();
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_MIXIN_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_PARAMETERS))
+}
+
+struct ScssParameterList;
+
+impl ParseSeparatedList for ScssParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_parameter(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![')'], T!['{']]),
+            expected_scss_parameter,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_parameter(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).or_add_diagnostic(p, expected_scss_variable);
+
+    if p.at(T![:]) {
+        let default_value = p.start();
+        p.bump(T![:]);
+        ScssComponentValueList::new(token_set![T![,], T![')']]).parse_list(p);
+        default_value.complete(p, SCSS_PARAMETER_DEFAULT_VALUE);
+    }
+
+    // The rest parameter of the mixin, like `$args...`.
+    p.eat(T![...]);
+
+    Present(m.complete(p, SCSS_PARAMETER))
+}
@@ -0,0 +1,11 @@
+mod extend;
+mod forward;
+mod include;
+mod mixin;
+mod module;
+
+pub(crate) use extend::parse_scss_extend_at_rule;
+pub(crate) use forward::parse_scss_forward_at_rule;
+pub(crate) use include::parse_scss_include_at_rule;
+pub(crate) use mixin::parse_scss_mixin_at_rule;
+pub(crate) use module::parse_scss_use_at_rule;
@@ -0,0 +1,81 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_identifier, expected_string};
+use crate::syntax::scss::parse_error::expected_scss_map;
+use crate::syntax::scss::value::parse_scss_map_expression;
+use crate::syntax::{is_at_identifier, parse_regular_identifier, parse_string};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+
+#[inline]
+fn is_at_scss_use_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![use])
+}
+
+/// Parses the `@use` at-rule, that loads the members of another stylesheet as a module.
+///
+/// ```scss
+/// @use "sass:math";
+/// @use "src/corners" as c;
+/// @use "library" with ($black: #222, $border-radius: 0.1rem);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_use_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_use_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![use]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+    parse_scss_use_namespace(p).ok();
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_USE_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_use_namespace(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![as]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![as]);
+
+    if p.at(T![*]) {
+        let all = p.start();
+        p.bump(T![*]);
+        all.complete(p, SCSS_USE_ALL_NAMESPACE);
+    } else if is_at_identifier(p) {
+        parse_regular_identifier(p).ok();
+    } else {
+        p.error(expected_identifier(p, p.cur_range()));
+    }
+
+    Present(m.complete(p, SCSS_USE_NAMESPACE))
+}
+
+/// Parses the configuration of a module loaded with `@use` or `@forward`.
+///
+/// ```scss
+/// @use "library" with ($black: #222);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_module_configuration(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![with]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![with]);
+    parse_scss_map_expression(p).or_add_diagnostic(p, expected_scss_map);
+
+    Present(m.complete(p, SCSS_MODULE_CONFIGURATION))
+}
@@ -0,0 +1,165 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::property::{GenericComponentValueList, parse_declaration_name};
+use crate::syntax::scss::parse_error::expected_scss_variable_modifier;
+use crate::syntax::scss::value::{
+    ScssComponentValueList, has_preceding_whitespace, is_at_scss_identifier,
+    is_at_scss_interpolated_identifier, parse_scss_identifier, parse_scss_interpolated_identifier,
+};
+use crate::syntax::{is_at_declaration, try_parse};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, token_set};
+
+/// Checks if the parser is at a SCSS variable declaration, like `$primary: #333;`.
+#[inline]
+pub(crate) fn is_at_scss_declaration(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses a SCSS variable declaration.
+///
+/// ```scss
+/// $primary-color: #333;
+/// $font-stack: Helvetica, sans-serif !default;
+/// ```
+///
+/// As for regular declarations, the semicolon is optional for the last
+/// declaration of a block, and of the stylesheet.
+#[inline]
+pub(crate) fn parse_scss_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+    ScssVariableModifierList.parse_list(p);
+
+    if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_DECLARATION))
+}
+
+struct ScssVariableModifierList;
+
+impl ParseNodeList for ScssVariableModifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_VARIABLE_MODIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_variable_modifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(T![!])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![;], T!['}']]),
+            expected_scss_variable_modifier,
+        )
+    }
+}
+
+#[inline]
+fn is_at_scss_variable_modifier(p: &mut CssParser) -> bool {
+    p.at(T![!]) && (p.nth_at(1, T![default]) || p.nth_at(1, T![global]))
+}
+
+/// Parses the `!default` and `!global` flags of a SCSS variable declaration.
+#[inline]
+fn parse_scss_variable_modifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_variable_modifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![!]);
+    p.bump_any();
+    Present(m.complete(p, SCSS_VARIABLE_MODIFIER))
+}
+
+/// Checks if the parser is at a declaration whose name contains interpolations,
+/// like `#{$property}-top: 1px;`.
+#[inline]
+pub(crate) fn is_at_scss_interpolated_property(p: &mut CssParser) -> bool {
+    if !is_at_scss_interpolated_identifier(p) {
+        return false;
+    }
+
+    let checkpoint = p.checkpoint();
+    parse_scss_interpolated_identifier(p).ok();
+    let is_property = p.at(T![:]);
+    p.rewind(checkpoint);
+
+    is_property
+}
+
+#[inline]
+fn is_at_scss_nested_property(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && is_at_declaration(p)
+}
+
+/// Parses a SCSS nested property, that declares the properties sharing the
+/// same prefix in a block.
+///
+/// ```scss
+/// .button {
+///     font: {
+///         family: $font-stack;
+///         size: 16px;
+///     }
+///     margin: auto {
+///         bottom: 10px;
+///     }
+/// }
+/// ```
+///
+/// As in Sass, the value of a nested property must be preceded by a whitespace,
+/// so that `a:hover {}` remains a nested rule.
+#[inline]
+pub(crate) fn parse_scss_nested_property(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_nested_property(p) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+
+        parse_declaration_name(p).ok();
+
+        if !p.eat(T![:]) || (!p.at(T!['{']) && !has_preceding_whitespace(p)) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        ScssComponentValueList::new(token_set![T!['{']]).parse_list(p);
+
+        if !p.at(T!['{']) {
+            m.abandon(p);
+            return Err(());
+        }
+
+        parse_declaration_or_rule_list_block(p);
+
+        Ok(Present(m.complete(p, SCSS_NESTED_PROPERTY)))
+    })
+    .unwrap_or(Absent)
+}
@@ -0,0 +1,29 @@
+//! Parsing of the SCSS extensions to the CSS syntax.
+//!
+//! SCSS features are only recognized when [crate::CssParserOptions::is_scss_enabled]
+//! returns `true`, which is the case for `.scss` files. Plain CSS is never affected
+//! by the functions of this module.
+
+mod at_rule;
+mod declaration;
+mod parse_error;
+mod selector;
+mod value;
+
+pub(crate) use at_rule::{
+    parse_scss_extend_at_rule, parse_scss_forward_at_rule, parse_scss_include_at_rule,
+    parse_scss_mixin_at_rule, parse_scss_use_at_rule,
+};
+pub(crate) use declaration::{
+    is_at_scss_declaration, is_at_scss_interpolated_property, parse_scss_declaration,
+    parse_scss_nested_property,
+};
+pub(crate) use selector::{
+    is_nth_at_scss_placeholder_selector, parse_scss_placeholder_selector,
+    parse_scss_selector_interpolation,
+};
+pub(crate) use value::{
+    is_at_any_scss_value, is_at_scss_interpolated_identifier, is_at_scss_interpolation,
+    parse_any_scss_value, parse_scss_expression, parse_scss_interpolated_identifier,
+    parse_scss_interpolation,
+};
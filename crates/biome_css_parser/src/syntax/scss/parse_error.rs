@@ -0,0 +1,32 @@
+use crate::parser::CssParser;
+use biome_css_syntax::TextRange;
+use biome_parser::diagnostic::{ParseDiagnostic, expect_one_of, expected_any, expected_node};
+use biome_parser::prelude::ToDiagnostic;
+
+pub(crate) fn expected_scss_variable(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS variable", range, p)
+}
+
+pub(crate) fn expected_scss_variable_modifier(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["!default", "!global"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_scss_map_item(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("map item", range, p)
+}
+
+pub(crate) fn expected_scss_parameter(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("mixin parameter", range, p)
+}
+
+pub(crate) fn expected_scss_argument(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("mixin argument", range, p)
+}
+
+pub(crate) fn expected_scss_forward_member(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["identifier", "SCSS variable"], range, p)
+}
+
+pub(crate) fn expected_scss_map(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("map", range, p)
+}
@@ -0,0 +1,45 @@
+use crate::parser::CssParser;
+use crate::syntax::is_nth_at_identifier;
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::scss::value::parse_scss_interpolation_with_context;
+use crate::syntax::selector::{parse_selector_custom_identifier, selector_lex_context};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+
+#[inline]
+pub(crate) fn is_nth_at_scss_placeholder_selector(p: &mut CssParser, n: usize) -> bool {
+    p.options().is_scss_enabled() && p.nth_at(n, T![%]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses a placeholder selector, that is only meant to be used with `@extend`.
+///
+/// ```scss
+/// %message-shared {
+///     border: 1px solid #ccc;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_placeholder_selector(p: &mut CssParser) -> ParsedSyntax {
+    if !is_nth_at_scss_placeholder_selector(p, 0) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![%]);
+    parse_selector_custom_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, SCSS_PLACEHOLDER_SELECTOR))
+}
+
+/// Parses an interpolation that is part of a selector, like `.icon-#{$name}`.
+///
+/// The token that follows the interpolation is lexed in the selector context,
+/// so that a following whitespace is treated as a descendant combinator.
+#[inline]
+pub(crate) fn parse_scss_selector_interpolation(p: &mut CssParser) -> ParsedSyntax {
+    parse_scss_interpolation_with_context(p, selector_lex_context)
+}
@@ -0,0 +1,460 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::scss::parse_error::expected_scss_map_item;
+use crate::syntax::value::function::{is_at_function, parse_function};
+use crate::syntax::{
+    is_at_identifier, is_nth_at_identifier, parse_any_value, parse_regular_identifier,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{CompletedMarker, Parser, TokenSet, token_set};
+
+/// Checks if the parser is at any value that only exists in SCSS.
+#[inline]
+pub(crate) fn is_at_any_scss_value(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p)
+        || is_at_scss_qualified_name(p)
+        || is_at_scss_map_expression(p)
+        || is_at_scss_interpolation(p)
+        || is_at_scss_unary_expression(p)
+}
+
+/// Parses any value that only exists in SCSS: variables, module members,
+/// maps, interpolations and unary expressions.
+#[inline]
+pub(crate) fn parse_any_scss_value(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_at_scss_qualified_name(p) {
+        parse_scss_qualified_name(p)
+    } else if is_at_scss_map_expression(p) {
+        parse_scss_map_expression(p)
+    } else if is_at_scss_interpolation(p) {
+        parse_scss_interpolation(p)
+    } else if is_at_scss_unary_expression(p) {
+        parse_scss_unary_expression(p)
+    } else {
+        Absent
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_scss_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_scss_identifier(p, 0)
+}
+
+/// The lexer only emits a `$` token in SCSS files, when it's immediately
+/// followed by an identifier.
+#[inline]
+pub(crate) fn is_nth_at_scss_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at(n, T![$]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses a SCSS variable, like `$primary-color`.
+#[inline]
+pub(crate) fn parse_scss_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![$]);
+    parse_regular_identifier(p).ok();
+    Present(m.complete(p, SCSS_IDENTIFIER))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_qualified_name(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled()
+        && is_at_identifier(p)
+        && p.nth_at(1, T![.])
+        && (is_nth_at_scss_identifier(p, 2) || is_nth_at_identifier(p, 2))
+}
+
+/// Parses a member of a module loaded with `@use`.
+///
+/// ```scss
+/// .a {
+///     width: math.div(100%, 3);
+///     height: math.$pi * 1px;
+/// }
+/// ```
+#[inline]
+fn parse_scss_qualified_name(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_qualified_name(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_regular_identifier(p).ok();
+    p.bump(T![.]);
+
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p).ok();
+    } else if is_at_function(p) {
+        parse_function(p).ok();
+    } else {
+        parse_regular_identifier(p).ok();
+    }
+
+    Present(m.complete(p, SCSS_QUALIFIED_NAME))
+}
+
+/// Parses the name of a mixin, that can be a member of a module loaded with `@use`.
+///
+/// ```scss
+/// @include theme;
+/// @include mixins.theme;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_mixin_name(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_identifier(p) {
+        return Absent;
+    }
+
+    if !is_at_scss_qualified_name(p) || is_nth_at_scss_identifier(p, 2) {
+        return parse_regular_identifier(p);
+    }
+
+    let m = p.start();
+    parse_regular_identifier(p).ok();
+    p.bump(T![.]);
+    parse_regular_identifier(p).ok();
+    Present(m.complete(p, SCSS_QUALIFIED_NAME))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_map_expression(p: &mut CssParser) -> bool {
+    if !p.options().is_scss_enabled() || !p.at(T!['(']) {
+        return false;
+    }
+
+    if is_nth_at_scss_identifier(p, 1) {
+        p.nth_at(3, T![:])
+    } else {
+        (is_nth_at_identifier(p, 1)
+            || p.nth_at(1, CSS_STRING_LITERAL)
+            || p.nth_at(1, CSS_NUMBER_LITERAL))
+            && p.nth_at(2, T![:])
+    }
+}
+
+/// Parses a SCSS map.
+///
+/// ```scss
+/// $breakpoints: (small: 576px, medium: 768px, large: 992px);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_map_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_map_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    ScssMapItemList.parse_list(p);
+    p.expect(T![')']);
+    Present(m.complete(p, SCSS_MAP_EXPRESSION))
+}
+
+struct ScssMapItemList;
+
+impl ParseSeparatedList for ScssMapItemList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_MAP_ITEM_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_map_item(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![')'], T![;]]),
+            expected_scss_map_item,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_map_item(p: &mut CssParser) -> ParsedSyntax {
+    let key = parse_any_value(p);
+
+    if key.is_absent() {
+        return Absent;
+    }
+
+    let m = key.precede(p);
+    p.expect(T![:]);
+    ScssComponentValueList::new(token_set![T![,], T![')']]).parse_list(p);
+    Present(m.complete(p, SCSS_MAP_ITEM))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_interpolation(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && p.at(T![#]) && p.nth_at(1, T!['{'])
+}
+
+/// Parses a SCSS interpolation.
+///
+/// ```scss
+/// .icon-#{$name} {
+///     width: calc(100% - #{$gutter});
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_interpolation(p: &mut CssParser) -> ParsedSyntax {
+    parse_scss_interpolation_with_context(p, |_| CssLexContext::Regular)
+}
+
+/// Parses a SCSS interpolation, lexing the token that follows the closing
+/// curly brace in the context returned by `context`.
+#[inline]
+pub(crate) fn parse_scss_interpolation_with_context(
+    p: &mut CssParser,
+    context: impl FnOnce(&mut CssParser) -> CssLexContext,
+) -> ParsedSyntax {
+    if !is_at_scss_interpolation(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![#]);
+    p.bump(T!['{']);
+    ScssComponentValueList::new(token_set![T!['}']]).parse_list(p);
+
+    let context = context(p);
+    p.expect_with_context(T!['}'], context);
+
+    Present(m.complete(p, SCSS_INTERPOLATION))
+}
+
+/// Checks if the parser is at an identifier that contains interpolations, like
+/// `#{$property}-top` or `margin-#{$side}`.
+#[inline]
+pub(crate) fn is_at_scss_interpolated_identifier(p: &mut CssParser) -> bool {
+    is_at_scss_interpolation(p)
+        || (p.options().is_scss_enabled()
+            && is_at_identifier(p)
+            && p.nth_at(1, T![#])
+            && p.nth_at(2, T!['{']))
+}
+
+/// Parses an identifier that contains interpolations, which is used as the
+/// name of a declaration.
+///
+/// ```scss
+/// .box {
+///     #{$property}-top: 1px;
+///     margin-#{$side}: 0;
+/// }
+/// ```
+///
+/// The parts of the identifier can't be separated by whitespaces.
+#[inline]
+pub(crate) fn parse_scss_interpolated_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_interpolated_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    ScssInterpolatedIdentifierPartList::default().parse_list(p);
+    Present(m.complete(p, SCSS_INTERPOLATED_IDENTIFIER))
+}
+
+#[derive(Default)]
+struct ScssInterpolatedIdentifierPartList {
+    has_parts: bool,
+}
+
+impl ParseNodeList for ScssInterpolatedIdentifierPartList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_INTERPOLATED_IDENTIFIER_PART_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        self.has_parts = true;
+
+        if is_at_scss_interpolation(p) {
+            parse_scss_interpolation(p)
+        } else {
+            parse_regular_identifier(p)
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        (self.has_parts && has_preceding_whitespace(p))
+            || !(is_at_identifier(p) || is_at_scss_interpolation(p))
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![:], T![;], T!['}']]),
+            expected_identifier,
+        )
+    }
+}
+
+#[inline]
+fn is_at_scss_unary_expression(p: &mut CssParser) -> bool {
+    p.at_ts(token_set![T![+], T![-]]) && is_nth_at_scss_identifier(p, 1)
+}
+
+/// Parses a unary expression, like `-$gutter`.
+#[inline]
+fn parse_scss_unary_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_unary_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_any();
+    parse_scss_identifier(p).ok();
+    Present(m.complete(p, SCSS_UNARY_EXPRESSION))
+}
+
+/// Parses a value that can be part of a SCSS arithmetic expression, like
+/// `$gutter * 2` or `$width - 10px`.
+///
+/// Multiplicative operators bind tighter than additive operators.
+#[inline]
+pub(crate) fn parse_scss_expression(p: &mut CssParser) -> ParsedSyntax {
+    match parse_any_value(p) {
+        Present(left) => Present(parse_scss_binary_expression(p, left, 0)),
+        Absent => Absent,
+    }
+}
+
+fn parse_scss_binary_expression(
+    p: &mut CssParser,
+    mut left: CompletedMarker,
+    min_precedence: u8,
+) -> CompletedMarker {
+    while let Some(precedence) = scss_binary_operator_precedence(p) {
+        if precedence <= min_precedence {
+            break;
+        }
+
+        let m = left.precede(p);
+        p.bump_any();
+
+        if let Present(right) = parse_any_value(p) {
+            parse_scss_binary_expression(p, right, precedence);
+        } else {
+            p.error(expected_component_value(p, p.cur_range()));
+        }
+
+        left = m.complete(p, SCSS_BINARY_EXPRESSION);
+    }
+
+    left
+}
+
+/// Returns the precedence of the binary operator the parser is at, if any.
+///
+/// A `-` or `+` preceded by whitespace but immediately followed by the
+/// operand, like in `0 -$gutter`, is a unary operator of a list item, and
+/// not a binary operator.
+fn scss_binary_operator_precedence(p: &mut CssParser) -> Option<u8> {
+    let precedence = match p.cur() {
+        T![*] | T![%] => 2,
+        T![+] | T![-] => 1,
+        _ => return None,
+    };
+
+    if precedence == 1 && has_preceding_whitespace(p) {
+        let checkpoint = p.checkpoint();
+        p.bump_any();
+        let is_unary = !has_preceding_whitespace(p);
+        p.rewind(checkpoint);
+
+        if is_unary {
+            return None;
+        }
+    }
+
+    Some(precedence)
+}
+
+#[inline]
+pub(crate) fn has_preceding_whitespace(p: &CssParser) -> bool {
+    p.last_end()
+        .is_some_and(|last_end| p.cur_range().start() > last_end)
+}
+
+/// A list of component values that can include SCSS arithmetic expressions.
+pub(crate) struct ScssComponentValueList {
+    end_kind_ts: TokenSet<CssSyntaxKind>,
+}
+
+impl ScssComponentValueList {
+    pub(crate) fn new(end_kind_ts: TokenSet<CssSyntaxKind>) -> Self {
+        Self { end_kind_ts }
+    }
+}
+
+impl ParseNodeList for ScssComponentValueList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_expression(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(self.end_kind_ts)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS,
+                self.end_kind_ts.union(token_set![T![;], T!['}']]),
+            ),
+            expected_component_value,
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn parse_scss_variable_or_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else {
+        parse_regular_identifier(p)
+    }
+}
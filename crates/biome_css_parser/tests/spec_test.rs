@@ -46,6 +46,14 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
         // TODO: find a way to make it configurable
         .allow_metavariables();
 
+    let is_scss = test_case_path
+        .extension()
+        .is_some_and(|extension| extension == "scss");
+
+    if is_scss {
+        options = options.allow_scss();
+    }
+
     let options_path = Utf8Path::new(test_directory).join("options.json");
 
     if options_path.exists() {
@@ -86,7 +94,8 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let formatted_ast = format!("{:#?}", parsed.tree());
 
     let mut snapshot = String::new();
-    writeln!(snapshot, "\n## Input\n\n```css\n{content}\n```\n\n").unwrap();
+    let language = if is_scss { "scss" } else { "css" };
+    writeln!(snapshot, "\n## Input\n\n```{language}\n{content}\n```\n\n").unwrap();
 
     writeln!(
         snapshot,
@@ -125,6 +125,8 @@ impl SemanticEventExtractor {
                                 AnyCssDeclarationName::CssIdentifier(name) => {
                                     CssProperty::from(name)
                                 }
+                                // The name is only known once Sass evaluates the interpolations.
+                                AnyCssDeclarationName::ScssInterpolatedIdentifier(_) => return,
                             };
 
                             self.stash.push_back(SemanticEvent::PropertyDeclaration {
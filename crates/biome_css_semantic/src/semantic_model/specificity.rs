@@ -124,6 +124,11 @@ fn evaluate_any_subselector(selector: &AnyCssSubSelector) -> Specificity {
         AnyCssSubSelector::CssPseudoClassSelector(s) => evaluate_pseudo_selector(s),
         AnyCssSubSelector::CssPseudoElementSelector(_) => TYPE_SPECIFICITY,
         AnyCssSubSelector::CssBogusSubSelector(_) => ZERO_SPECIFICITY,
+        // Placeholders are replaced by the selectors that extend them, but
+        // they're written like classes, so they're counted like classes.
+        AnyCssSubSelector::ScssPlaceholderSelector(_) => CLASS_SPECIFICITY,
+        // The value of an interpolation is only known at compile time.
+        AnyCssSubSelector::ScssInterpolation(_) => ZERO_SPECIFICITY,
     }
 }
 
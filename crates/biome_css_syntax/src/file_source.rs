@@ -13,16 +13,18 @@ pub struct CssFileSource {
 
 /// The style of CSS contained in the file.
 ///
-/// Currently, Biome only supports plain CSS, and aims to be compatible with
-/// the latest Recommendation level standards.
+/// Biome supports plain CSS, and aims to be compatible with the latest
+/// Recommendation level standards. It also supports the SCSS syntax of Sass.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
 #[serde(rename_all = "camelCase")]
-enum CssVariant {
+pub enum CssVariant {
     #[default]
     Standard,
+    /// The SCSS syntax of [Sass](https://sass-lang.com/documentation/syntax/#scss).
+    Scss,
 }
 
 impl CssFileSource {
@@ -32,6 +34,20 @@ impl CssFileSource {
         }
     }
 
+    pub fn scss() -> Self {
+        Self {
+            variant: CssVariant::Scss,
+        }
+    }
+
+    pub const fn variant(&self) -> CssVariant {
+        self.variant
+    }
+
+    pub const fn is_scss(&self) -> bool {
+        matches!(self.variant, CssVariant::Scss)
+    }
+
     /// Try to return the CSS file source corresponding to this file name from well-known files
     pub fn try_from_well_known(_: &Utf8Path) -> Result<Self, FileSourceError> {
         // TODO: to be implemented
@@ -43,6 +59,7 @@ impl CssFileSource {
         // We assume the file extension is normalized to lowercase
         match extension {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -56,6 +73,7 @@ impl CssFileSource {
     pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
         match language_id {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
@@ -1,6 +1,6 @@
 use crate::CssSyntaxToken;
 use crate::generated::{
-    CssDeclarationBlock, CssDeclarationOrAtRuleBlock, CssDeclarationOrRuleBlock,
+    CssAtRule, CssDeclarationBlock, CssDeclarationOrAtRuleBlock, CssDeclarationOrRuleBlock,
     CssFontFeatureValuesBlock, CssKeyframesBlock, CssPageAtRuleBlock, CssRuleBlock,
 };
 use biome_rowan::{AstNodeList, SyntaxResult, declare_node_union};
@@ -60,3 +60,22 @@ impl CssBlockLike {
                 .is_ok_and(|token| token.has_leading_comments())
     }
 }
+
+impl CssAtRule {
+    /// Checks if the at-rule is the `@else` clause of a SCSS `@if` at-rule.
+    ///
+    /// ```scss
+    /// @if $condition {
+    ///     color: red;
+    /// } @else {
+    ///     color: blue;
+    /// }
+    /// ```
+    pub fn is_scss_else_clause(&self) -> bool {
+        self.rule()
+            .ok()
+            .and_then(|rule| rule.as_css_unknown_block_at_rule()?.name().ok())
+            .and_then(|name| name.value_token().ok())
+            .is_some_and(|token| token.text_trimmed() == "else")
+    }
+}
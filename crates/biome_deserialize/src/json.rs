@@ -101,6 +101,8 @@ impl DeserializableValue for AnyJsonValue {
                 // No need to emit another diagnostic.
                 None
             }
+            // Metavariables only appear in Grit snippets.
+            Self::JsonMetavariable(_) => None,
             Self::JsonBooleanValue(value) => {
                 let value = value.value_token().ok()?;
                 visitor.visit_bool(ctx, value.kind() == T![true], range, name)
@@ -128,7 +130,7 @@ impl DeserializableValue for AnyJsonValue {
     fn visitable_type(&self) -> Option<DeserializableType> {
         match self {
             Self::JsonArrayValue(_) => Some(DeserializableType::Array),
-            Self::JsonBogusValue(_) => None,
+            Self::JsonBogusValue(_) | Self::JsonMetavariable(_) => None,
             Self::JsonBooleanValue(_) => Some(DeserializableType::Bool),
             Self::JsonNullValue(_) => Some(DeserializableType::Null),
             Self::JsonNumberValue(_) => Some(DeserializableType::Number),
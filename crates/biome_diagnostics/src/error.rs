@@ -100,6 +100,18 @@ impl AsDiagnostic for Error {
     }
 }
 
+impl AsDiagnostic for &Error {
+    type Diagnostic = dyn Diagnostic;
+
+    fn as_diagnostic(&self) -> &Self::Diagnostic {
+        (*self).as_diagnostic()
+    }
+
+    fn as_dyn(&self) -> &dyn Diagnostic {
+        self.as_diagnostic()
+    }
+}
+
 impl AsRef<dyn Diagnostic + 'static> for Error {
     fn as_ref(&self) -> &(dyn Diagnostic + 'static) {
         self.as_diagnostic()
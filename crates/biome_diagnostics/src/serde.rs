@@ -75,11 +75,25 @@ impl Diagnostic {
         }
     }
 
-    pub fn with_offset(mut self, offset: TextSize) -> Self {
-        self.location.span = self
-            .location
-            .span
-            .map(|span| TextRange::new(span.start() + offset, span.end() + offset));
+    /// Shifts the span of the diagnostic, and the spans of the code frames of
+    /// its advices, by `offset`.
+    pub fn with_offset(self, offset: TextSize) -> Self {
+        self.with_mapped_spans(|span| {
+            Some(TextRange::new(span.start() + offset, span.end() + offset))
+        })
+    }
+
+    /// Maps the span of the diagnostic, and the spans of the code frames of
+    /// its advices, with `map`.
+    ///
+    /// This is used for diagnostics emitted for code embedded in another file,
+    /// so they point to the host file. The code frames that `map` can't map
+    /// are removed, and the span of the diagnostic is removed if it can't be
+    /// mapped. The code frames that carry their own source code aren't changed.
+    pub fn with_mapped_spans(mut self, map: impl Fn(TextRange) -> Option<TextRange>) -> Self {
+        self.location.span = self.location.span.and_then(&map);
+        self.advices.map_spans(&map);
+        self.verbose_advices.map_spans(&map);
         self
     }
 }
@@ -175,6 +189,28 @@ impl Advices {
             advices: Vec::new(),
         }
     }
+
+    /// Maps the spans of the code frames that point to the source code of the
+    /// diagnostic, and removes the frames that can't be mapped.
+    fn map_spans(&mut self, map: &impl Fn(TextRange) -> Option<TextRange>) {
+        self.advices.retain_mut(|advice| match advice {
+            Advice::Frame(location) if location.source_code.is_none() => match location.span {
+                Some(span) => match map(span) {
+                    Some(span) => {
+                        location.span = Some(span);
+                        true
+                    }
+                    None => false,
+                },
+                None => true,
+            },
+            Advice::Group(_, advices) => {
+                advices.map_spans(map);
+                true
+            }
+            _ => true,
+        });
+    }
 }
 
 impl Visit for Advices {
@@ -223,9 +223,19 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Returns the resolution of a symbolic link.
     fn read_link(&self, path: &Utf8Path) -> io::Result<Utf8PathBuf>;
 
+    /// Recursively creates a directory and all of its missing parents.
+    fn create_dir_all(&self, path: &Utf8Path) -> io::Result<()>;
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
 
     fn get_staged_files(&self) -> io::Result<Vec<String>>;
+
+    /// Returns the diff, without context lines, of the files that have been
+    /// changed compared to `base`.
+    fn get_changed_diff(&self, base: &str) -> io::Result<String>;
+
+    /// Returns the diff, without context lines, of the files that have been staged.
+    fn get_staged_diff(&self) -> io::Result<String>;
 }
 
 /// Result of the auto search
@@ -432,9 +442,21 @@ where
         T::get_staged_files(self)
     }
 
+    fn get_changed_diff(&self, base: &str) -> io::Result<String> {
+        T::get_changed_diff(self, base)
+    }
+
+    fn get_staged_diff(&self) -> io::Result<String> {
+        T::get_staged_diff(self)
+    }
+
     fn read_link(&self, path: &Utf8Path) -> io::Result<Utf8PathBuf> {
         T::read_link(self, path)
     }
+
+    fn create_dir_all(&self, path: &Utf8Path) -> io::Result<()> {
+        T::create_dir_all(self, path)
+    }
 }
 
 #[derive(Debug, Diagnostic, Deserialize, Serialize)]
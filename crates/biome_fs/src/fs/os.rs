@@ -97,6 +97,10 @@ impl FileSystem for OsFileSystem {
         path.read_link_utf8()
     }
 
+    fn create_dir_all(&self, path: &Utf8Path) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         let output = Command::new("git")
             .arg("diff")
@@ -136,6 +140,45 @@ impl FileSystem for OsFileSystem {
             .map(|l| l.to_string())
             .collect())
     }
+
+    fn get_changed_diff(&self, base: &str) -> io::Result<String> {
+        git_diff(&[&format!("{base}...HEAD")])
+    }
+
+    fn get_staged_diff(&self) -> io::Result<String> {
+        git_diff(&["--staged"])
+    }
+}
+
+/// Runs `git diff` with the given arguments and returns a diff without
+/// context lines.
+///
+/// The prefixes and the quoting of the paths are set explicitly, so the
+/// output doesn't depend on the Git configuration of the user, for example
+/// `diff.noprefix`.
+fn git_diff(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("git")
+        .arg("-c")
+        .arg("core.quotePath=false")
+        .arg("diff")
+        .arg("--unified=0")
+        .arg("--relative")
+        .arg("--no-color")
+        .arg("--no-ext-diff")
+        .arg("--src-prefix=a/")
+        .arg("--dst-prefix=b/")
+        .arg("--diff-filter=ACMR")
+        .args(args)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "`git diff` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
 }
 
 #[derive(Debug)]
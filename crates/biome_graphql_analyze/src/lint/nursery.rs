@@ -3,6 +3,12 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use biome_analyze::declare_lint_group;
+pub mod no_invalid_fragment_types;
+pub mod no_invalid_variable_types;
+pub mod no_unknown_arguments;
+pub mod no_unknown_fields;
+pub mod no_unknown_types;
 pub mod use_named_operation;
 pub mod use_naming_convention;
-declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: use_named_operation :: UseNamedOperation , self :: use_naming_convention :: UseNamingConvention ,] } }
+pub mod use_required_arguments;
+declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: no_invalid_fragment_types :: NoInvalidFragmentTypes , self :: no_invalid_variable_types :: NoInvalidVariableTypes , self :: no_unknown_arguments :: NoUnknownArguments , self :: no_unknown_fields :: NoUnknownFields , self :: no_unknown_types :: NoUnknownTypes , self :: use_named_operation :: UseNamedOperation , self :: use_naming_convention :: UseNamingConvention , self :: use_required_arguments :: UseRequiredArguments ,] } }
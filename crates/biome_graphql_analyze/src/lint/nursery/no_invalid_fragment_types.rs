@@ -0,0 +1,210 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlSelection, GraphqlFragmentDefinition, GraphqlFragmentSpread,
+    GraphqlInlineFragment, GraphqlRoot, GraphqlTypeCondition,
+};
+use biome_rowan::{AstNode, AstNodeList, TextRange, declare_node_union};
+
+declare_lint_rule! {
+    /// Disallow fragments whose type condition can never match.
+    ///
+    /// The type condition of a fragment must be an object, an interface or a
+    /// union. When a fragment is spread, its type must also overlap with the
+    /// type of the selection set it's spread into, otherwise it would never
+    /// select anything.
+    ///
+    /// This rule requires a schema, configured with the `graphql.schema` option.
+    /// Without a schema, the rule doesn't report anything.
+    ///
+    /// ## Examples
+    ///
+    /// ```graphql,schema
+    /// type Query {
+    ///   hero: Character
+    /// }
+    ///
+    /// interface Character {
+    ///   name: String
+    /// }
+    ///
+    /// type Human implements Character {
+    ///   name: String
+    /// }
+    ///
+    /// type Starship {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero {
+    ///     ... on Starship {
+    ///       name
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// fragment Name on String {
+    ///   length
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   hero {
+    ///     ... on Human {
+    ///       name
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoInvalidFragmentTypes {
+        version: "next",
+        name: "noInvalidFragmentTypes",
+        language: "graphql",
+        sources: &[
+            RuleSource::EslintGraphql("fragments-on-composite-type").same(),
+            RuleSource::EslintGraphql("possible-fragment-spread").same(),
+        ],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlFragment = GraphqlFragmentDefinition | GraphqlFragmentSpread | GraphqlInlineFragment
+}
+
+pub enum InvalidFragmentType {
+    /// The type condition isn't an object, an interface or a union.
+    NotComposite { range: TextRange, ty: Box<str> },
+    /// The type of the fragment never overlaps with the type it's spread into.
+    Impossible {
+        range: TextRange,
+        ty: Box<str>,
+        parent_type: Box<str>,
+    },
+}
+
+impl Rule for NoInvalidFragmentTypes {
+    type Query = Schema<AnyGraphqlFragment>;
+    type State = InvalidFragmentType;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let schema = ctx.schema()?;
+        let (range, type_condition, selection) = match ctx.query() {
+            AnyGraphqlFragment::GraphqlFragmentDefinition(fragment) => {
+                let type_condition = fragment.type_condition().ok()?;
+                let ty = schema.type_condition_type(&type_condition)?;
+                if ty.is_composite() {
+                    return None;
+                }
+                return Some(InvalidFragmentType::NotComposite {
+                    range: type_condition.range(),
+                    ty: ty.name.clone(),
+                });
+            }
+            AnyGraphqlFragment::GraphqlInlineFragment(fragment) => {
+                let type_condition = fragment.type_condition()?;
+                let ty = schema.type_condition_type(&type_condition)?;
+                if !ty.is_composite() {
+                    return Some(InvalidFragmentType::NotComposite {
+                        range: type_condition.range(),
+                        ty: ty.name.clone(),
+                    });
+                }
+                (
+                    type_condition.range(),
+                    type_condition,
+                    AnyGraphqlSelection::from(fragment.clone()),
+                )
+            }
+            AnyGraphqlFragment::GraphqlFragmentSpread(spread) => {
+                let name = spread.name().ok()?.value_token().ok()?;
+                let root = spread
+                    .syntax()
+                    .ancestors()
+                    .last()
+                    .and_then(GraphqlRoot::cast)?;
+                // Fragments defined in other files can't be resolved
+                let type_condition = find_fragment_type_condition(&root, name.text_trimmed())?;
+                (
+                    spread.range(),
+                    type_condition,
+                    AnyGraphqlSelection::from(spread.clone()),
+                )
+            }
+        };
+
+        let ty = schema.type_condition_type(&type_condition)?;
+        // Fragments on non-composite types are reported on their definition
+        if !ty.is_composite() {
+            return None;
+        }
+        let parent_type = schema.parent_type(&selection)?;
+        if schema.types_overlap(&ty.name, &parent_type.name) {
+            return None;
+        }
+
+        Some(InvalidFragmentType::Impossible {
+            range,
+            ty: ty.name.clone(),
+            parent_type: parent_type.name.clone(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidFragmentType::NotComposite { range, ty } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Fragments can't be defined on the type "<Emphasis>{ty}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Only objects, interfaces and unions can have a selection set."
+            }),
+            InvalidFragmentType::Impossible {
+                range,
+                ty,
+                parent_type,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This fragment on "<Emphasis>{ty}</Emphasis>" can never match a value of the type "<Emphasis>{parent_type}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "No object type is both a "<Emphasis>{ty}</Emphasis>" and a "<Emphasis>{parent_type}</Emphasis>", so the fragment would never select anything."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+fn find_fragment_type_condition(root: &GraphqlRoot, name: &str) -> Option<GraphqlTypeCondition> {
+    root.definitions().iter().find_map(|definition| {
+        let AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) = definition else {
+            return None;
+        };
+        let fragment_name = fragment.name().ok()?.value_token().ok()?;
+        if fragment_name.text_trimmed() == name {
+            fragment.type_condition().ok()
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,250 @@
+use crate::services::schema::Schema;
+use crate::utils::AnyGraphqlArgumentsOwner;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_semantic::{GraphqlSchema, SchemaTypeRef};
+use biome_graphql_syntax::{
+    AnyGraphqlValue, GraphqlArgument, GraphqlArguments, GraphqlListValue, GraphqlObjectField,
+    GraphqlObjectValue, GraphqlOperationDefinition, GraphqlSyntaxNode, GraphqlVariableDefinition,
+    GraphqlVariableReference,
+};
+use biome_rowan::{AstNode, AstNodeList, TextRange, declare_node_union};
+
+declare_lint_rule! {
+    /// Disallow variables whose type doesn't match where they are used.
+    ///
+    /// Variables must be of an input type: a scalar, an enum or an input object.
+    /// A variable can only be passed where a value of its type is expected. A
+    /// nullable variable can't be passed to a non-null argument, unless the
+    /// variable or the argument has a default value.
+    ///
+    /// Variables used in fragments aren't checked, because their type depends
+    /// on the operations that use the fragment.
+    ///
+    /// This rule requires a schema, configured with the `graphql.schema` option.
+    /// Without a schema, the rule doesn't report anything.
+    ///
+    /// ## Examples
+    ///
+    /// ```graphql,schema
+    /// type Query {
+    ///   hero(episode: Episode!): Character
+    /// }
+    ///
+    /// enum Episode {
+    ///   NEWHOPE
+    ///   EMPIRE
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query ($episode: String!) {
+    ///   hero(episode: $episode) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query ($episode: Episode) {
+    ///   hero(episode: $episode) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query ($hero: Character) {
+    ///   hero(episode: EMPIRE) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query ($episode: Episode = EMPIRE) {
+    ///   hero(episode: $episode) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoInvalidVariableTypes {
+        version: "next",
+        name: "noInvalidVariableTypes",
+        language: "graphql",
+        sources: &[
+            RuleSource::EslintGraphql("variables-are-input-types").same(),
+            RuleSource::EslintGraphql("variables-in-allowed-position").same(),
+        ],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlVariable = GraphqlVariableDefinition | GraphqlVariableReference
+}
+
+pub enum InvalidVariableType {
+    /// The variable is defined with an output type.
+    NotInputType { range: TextRange, ty: String },
+    /// The variable is used where a value of another type is expected.
+    NotAllowed {
+        range: TextRange,
+        name: Box<str>,
+        variable_type: String,
+        expected_type: String,
+    },
+}
+
+impl Rule for NoInvalidVariableTypes {
+    type Query = Schema<AnyGraphqlVariable>;
+    type State = InvalidVariableType;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let schema = ctx.schema()?;
+        match ctx.query() {
+            AnyGraphqlVariable::GraphqlVariableDefinition(definition) => {
+                let ty = definition.ty().ok()?;
+                let variable_type = SchemaTypeRef::from_type(&ty)?;
+                // Unknown types are reported by `noUnknownTypes`
+                let named_type = schema.get_type(variable_type.named_type())?;
+                if named_type.is_input() {
+                    return None;
+                }
+                Some(InvalidVariableType::NotInputType {
+                    range: ty.range(),
+                    ty: variable_type.to_string(),
+                })
+            }
+            AnyGraphqlVariable::GraphqlVariableReference(reference) => {
+                let name = reference.name().ok()?.value_token().ok()?;
+                let name = name.text_trimmed();
+                let operation = reference
+                    .syntax()
+                    .ancestors()
+                    .find_map(GraphqlOperationDefinition::cast)?;
+                let definition = operation
+                    .variables()?
+                    .elements()
+                    .iter()
+                    .find(|definition| {
+                        definition
+                            .variable()
+                            .and_then(|variable| variable.name())
+                            .and_then(|name| name.value_token())
+                            .is_ok_and(|token| token.text_trimmed() == name)
+                    })?;
+                let variable_type = SchemaTypeRef::from_type(&definition.ty().ok()?)?;
+                let (expected_type, has_default_value) = expected_type(schema, reference.syntax())?;
+
+                let is_allowed = if expected_type.is_non_null() && !variable_type.is_non_null() {
+                    let has_non_null_default_value = definition
+                        .default()
+                        .and_then(|default| default.value().ok())
+                        .is_some_and(|value| {
+                            !matches!(value, AnyGraphqlValue::GraphqlNullValue(_))
+                        });
+                    (has_non_null_default_value || has_default_value)
+                        && variable_type.is_subtype_of(expected_type.nullable())
+                } else {
+                    variable_type.is_subtype_of(&expected_type)
+                };
+                if is_allowed {
+                    return None;
+                }
+
+                Some(InvalidVariableType::NotAllowed {
+                    range: reference.range(),
+                    name: name.into(),
+                    variable_type: variable_type.to_string(),
+                    expected_type: expected_type.to_string(),
+                })
+            }
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidVariableType::NotInputType { range, ty } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Variables can't be of the type "<Emphasis>{ty}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Only scalars, enums and input objects can be used as the type of a variable."
+            }),
+            InvalidVariableType::NotAllowed {
+                range,
+                name,
+                variable_type,
+                expected_type,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The variable "<Emphasis>"$"{name}</Emphasis>" of type "<Emphasis>{variable_type}</Emphasis>" is used where "<Emphasis>{expected_type}</Emphasis>" is expected."
+                },
+            )
+            .note(markup! {
+                "Change the type of the variable, or give it a default value if the expected type is non-null."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the type expected at the position of `value`, and whether this
+/// position has a default value.
+fn expected_type(
+    schema: &GraphqlSchema,
+    value: &GraphqlSyntaxNode,
+) -> Option<(SchemaTypeRef, bool)> {
+    let parent = value.parent()?;
+    if let Some(argument) = GraphqlArgument::cast_ref(&parent) {
+        let name = argument.name().ok()?.value_token().ok()?;
+        let owner = argument
+            .syntax()
+            .ancestors()
+            .find_map(GraphqlArguments::cast)?
+            .syntax()
+            .parent()
+            .and_then(AnyGraphqlArgumentsOwner::cast)?;
+        let definition = owner
+            .argument_definitions(schema)?
+            .iter()
+            .find(|definition| definition.name.as_ref() == name.text_trimmed())?;
+        Some((definition.ty.clone(), definition.has_default_value))
+    } else if let Some(field) = GraphqlObjectField::cast_ref(&parent) {
+        let name = field.name().ok()?.value_token().ok()?;
+        let object = field
+            .syntax()
+            .ancestors()
+            .find_map(GraphqlObjectValue::cast)?;
+        let (object_type, _) = expected_type(schema, object.syntax())?;
+        let definition = schema
+            .get_type(object_type.named_type())?
+            .input_fields
+            .get(name.text_trimmed())?;
+        Some((definition.ty.clone(), definition.has_default_value))
+    } else {
+        let list = parent.parent().and_then(GraphqlListValue::cast)?;
+        let (list_type, _) = expected_type(schema, list.syntax())?;
+        match list_type.nullable() {
+            SchemaTypeRef::List(item_type) => Some((item_type.as_ref().clone(), false)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::services::schema::Schema;
+use crate::utils::AnyGraphqlArgumentsOwner;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlArgument, GraphqlArguments};
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Disallow passing arguments that aren't defined by the field or directive.
+    ///
+    /// This rule requires a schema, configured with the `graphql.schema` option.
+    /// Without a schema, the rule doesn't report anything.
+    ///
+    /// ## Examples
+    ///
+    /// ```graphql,schema
+    /// type Query {
+    ///   hero(episode: String): Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero(film: "EMPIRE") {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero {
+    ///     name @include(unless: false)
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   hero(episode: "EMPIRE") {
+    ///     name @include(if: true)
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownArguments {
+        version: "next",
+        name: "noUnknownArguments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("known-argument-names").same()],
+        recommended: false,
+    }
+}
+
+pub struct UnknownArgument {
+    range: TextRange,
+    name: Box<str>,
+    owner: String,
+}
+
+impl Rule for NoUnknownArguments {
+    type Query = Schema<GraphqlArgument>;
+    type State = UnknownArgument;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let schema = ctx.schema()?;
+        let argument = ctx.query();
+        let name = argument.name().ok()?;
+        let name_token = name.value_token().ok()?;
+
+        let owner = argument
+            .syntax()
+            .ancestors()
+            .find_map(GraphqlArguments::cast)?
+            .syntax()
+            .parent()
+            .and_then(AnyGraphqlArgumentsOwner::cast)?;
+        // The arguments of unknown fields and directives can't be checked
+        let definitions = owner.argument_definitions(schema)?;
+        if definitions
+            .iter()
+            .any(|definition| definition.name.as_ref() == name_token.text_trimmed())
+        {
+            return None;
+        }
+
+        Some(UnknownArgument {
+            range: name.range(),
+            name: name_token.text_trimmed().into(),
+            owner: owner.name()?,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnknownArgument { range, name, owner } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The argument "<Emphasis>{name}</Emphasis>" isn't defined by "<Emphasis>{owner}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Check the spelling of the argument, or remove it."
+            }),
+        )
+    }
+}
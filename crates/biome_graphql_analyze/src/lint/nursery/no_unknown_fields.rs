@@ -0,0 +1,115 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_semantic::OperationKind;
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Disallow selecting fields that don't exist on the type of their selection set.
+    ///
+    /// This rule requires a schema, configured with the `graphql.schema` option.
+    /// Without a schema, the rule doesn't report anything.
+    ///
+    /// ## Examples
+    ///
+    /// ```graphql,schema
+    /// type Query {
+    ///   hero: Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero {
+    ///     age
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   hero {
+    ///     __typename
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownFields {
+        version: "next",
+        name: "noUnknownFields",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("fields-on-correct-type").same()],
+        recommended: false,
+    }
+}
+
+pub struct UnknownField {
+    range: TextRange,
+    name: Box<str>,
+    parent_type: Box<str>,
+}
+
+impl Rule for NoUnknownFields {
+    type Query = Schema<GraphqlField>;
+    type State = UnknownField;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let schema = ctx.schema()?;
+        let field = ctx.query();
+        let name = field.name().ok()?;
+        let name_token = name.value_token().ok()?;
+        let name_text = name_token.text_trimmed();
+
+        let parent_type = schema.parent_type(&field.clone().into())?;
+        if parent_type.field(name_text).is_some() || name_text == "__typename" {
+            return None;
+        }
+
+        // The introspection fields are available on the root type of queries.
+        if matches!(name_text, "__schema" | "__type")
+            && schema
+                .root_type(OperationKind::Query)
+                .is_some_and(|root| root.name == parent_type.name)
+        {
+            return None;
+        }
+
+        Some(UnknownField {
+            range: name.range(),
+            name: name_text.into(),
+            parent_type: parent_type.name.clone(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnknownField {
+            range,
+            name,
+            parent_type,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The field "<Emphasis>{name}</Emphasis>" doesn't exist on the type "<Emphasis>{parent_type}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Check the spelling of the field, or select it in a fragment on a type that defines it."
+            }),
+        )
+    }
+}
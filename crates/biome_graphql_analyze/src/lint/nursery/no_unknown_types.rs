@@ -0,0 +1,133 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlNameReference, GraphqlTypeCondition, GraphqlVariableDefinition};
+use biome_rowan::{AstNode, TextRange, declare_node_union};
+
+declare_lint_rule! {
+    /// Disallow references to types that don't exist in the schema.
+    ///
+    /// Types are referenced by the type conditions of fragments, and by the
+    /// definitions of variables.
+    ///
+    /// This rule requires a schema, configured with the `graphql.schema` option.
+    /// Without a schema, the rule doesn't report anything.
+    ///
+    /// ## Examples
+    ///
+    /// ```graphql,schema
+    /// type Query {
+    ///   hero(episode: Episode): Character
+    /// }
+    ///
+    /// enum Episode {
+    ///   NEWHOPE
+    ///   EMPIRE
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// fragment HeroName on Hero {
+    ///   name
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query ($episode: Film) {
+    ///   hero(episode: $episode) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query ($episode: Episode) {
+    ///   hero(episode: $episode) {
+    ///     ...HeroName
+    ///   }
+    /// }
+    ///
+    /// fragment HeroName on Character {
+    ///   name
+    /// }
+    /// ```
+    ///
+    pub NoUnknownTypes {
+        version: "next",
+        name: "noUnknownTypes",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("known-type-names").same()],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlTypeReference = GraphqlTypeCondition | GraphqlVariableDefinition
+}
+
+impl AnyGraphqlTypeReference {
+    /// Returns the reference to the named type, without its list and non-null wrappers.
+    fn named_type(&self) -> Option<GraphqlNameReference> {
+        match self {
+            Self::GraphqlTypeCondition(type_condition) => type_condition.ty().ok(),
+            Self::GraphqlVariableDefinition(definition) => definition
+                .ty()
+                .ok()?
+                .syntax()
+                .descendants()
+                .find_map(GraphqlNameReference::cast),
+        }
+    }
+}
+
+pub struct UnknownType {
+    range: TextRange,
+    name: Box<str>,
+}
+
+impl Rule for NoUnknownTypes {
+    type Query = Schema<AnyGraphqlTypeReference>;
+    type State = UnknownType;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let schema = ctx.schema()?;
+        let named_type = ctx.query().named_type()?;
+        let name = named_type.value_token().ok()?;
+        let name = name.text_trimmed();
+
+        if schema.get_type(name).is_some() {
+            return None;
+        }
+
+        Some(UnknownType {
+            range: named_type.range(),
+            name: name.into(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnknownType { range, name } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The type "<Emphasis>{name}</Emphasis>" doesn't exist in the schema."
+                },
+            )
+            .note(markup! {
+                "Check the spelling of the type, or update the schema if the type was added recently."
+            }),
+        )
+    }
+}
@@ -0,0 +1,139 @@
+use crate::services::schema::Schema;
+use crate::utils::AnyGraphqlArgumentsOwner;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Require the arguments that are non-null and without a default value in the schema.
+    ///
+    /// This rule requires a schema, configured with the `graphql.schema` option.
+    /// Without a schema, the rule doesn't report anything.
+    ///
+    /// ## Examples
+    ///
+    /// ```graphql,schema
+    /// type Query {
+    ///   hero(episode: String!, first: Int! = 10): Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero(episode: "EMPIRE") {
+    ///     name @skip
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   hero(episode: "EMPIRE") {
+    ///     name @skip(if: false)
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseRequiredArguments {
+        version: "next",
+        name: "useRequiredArguments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("provided-required-arguments").same()],
+        recommended: false,
+    }
+}
+
+pub struct MissingArgument {
+    range: TextRange,
+    name: Box<str>,
+    ty: String,
+    owner: String,
+}
+
+impl Rule for UseRequiredArguments {
+    type Query = Schema<AnyGraphqlArgumentsOwner>;
+    type State = MissingArgument;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(schema) = ctx.schema() else {
+            return Box::default();
+        };
+        let owner = ctx.query();
+        let Some(definitions) = owner.argument_definitions(schema) else {
+            return Box::default();
+        };
+        let (name, arguments) = match owner {
+            AnyGraphqlArgumentsOwner::GraphqlField(field) => {
+                (field.name().map(|name| name.range()), field.arguments())
+            }
+            AnyGraphqlArgumentsOwner::GraphqlDirective(directive) => (
+                directive.name().map(|_| directive.range()),
+                directive.arguments(),
+            ),
+        };
+        let (Ok(range), Some(owner_name)) = (name, owner.name()) else {
+            return Box::default();
+        };
+
+        let provided: Vec<_> = arguments
+            .into_iter()
+            .flat_map(|arguments| arguments.arguments())
+            .filter_map(|argument| argument.name().ok()?.value_token().ok())
+            .collect();
+
+        definitions
+            .iter()
+            .filter(|definition| {
+                definition.is_required()
+                    && !provided
+                        .iter()
+                        .any(|name| name.text_trimmed() == definition.name.as_ref())
+            })
+            .map(|definition| MissingArgument {
+                range,
+                name: definition.name.clone(),
+                ty: definition.ty.to_string(),
+                owner: owner_name.clone(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let MissingArgument {
+            range,
+            name,
+            ty,
+            owner,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The argument "<Emphasis>{name}</Emphasis>" of "<Emphasis>{owner}</Emphasis>" is required, but it's missing."
+                },
+            )
+            .note(markup! {
+                "The argument has the type "<Emphasis>{ty}</Emphasis>", which is non-null, and has no default value."
+            }),
+        )
+    }
+}
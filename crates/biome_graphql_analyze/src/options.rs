@@ -5,9 +5,19 @@
 use crate::lint;
 pub type NoDuplicateFields =
     <lint::suspicious::no_duplicate_fields::NoDuplicateFields as biome_analyze::Rule>::Options;
+pub type NoInvalidFragmentTypes = < lint :: nursery :: no_invalid_fragment_types :: NoInvalidFragmentTypes as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidVariableTypes = < lint :: nursery :: no_invalid_variable_types :: NoInvalidVariableTypes as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownArguments =
+    <lint::nursery::no_unknown_arguments::NoUnknownArguments as biome_analyze::Rule>::Options;
+pub type NoUnknownFields =
+    <lint::nursery::no_unknown_fields::NoUnknownFields as biome_analyze::Rule>::Options;
+pub type NoUnknownTypes =
+    <lint::nursery::no_unknown_types::NoUnknownTypes as biome_analyze::Rule>::Options;
 pub type UseDeprecatedReason =
     <lint::style::use_deprecated_reason::UseDeprecatedReason as biome_analyze::Rule>::Options;
 pub type UseNamedOperation =
     <lint::nursery::use_named_operation::UseNamedOperation as biome_analyze::Rule>::Options;
 pub type UseNamingConvention =
     <lint::nursery::use_naming_convention::UseNamingConvention as biome_analyze::Rule>::Options;
+pub type UseRequiredArguments =
+    <lint::nursery::use_required_arguments::UseRequiredArguments as biome_analyze::Rule>::Options;
@@ -0,0 +1,76 @@
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, QueryMatch, Queryable, RuleKey,
+    RuleMetadata, ServiceBag, ServicesDiagnostic, SyntaxVisitor,
+};
+use biome_graphql_semantic::GraphqlSchema;
+use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode};
+use biome_rowan::{AstNode, TextRange};
+use std::sync::Arc;
+
+/// Gives access to the schema configured with `graphql.schema`, if any.
+#[derive(Debug, Clone)]
+pub struct SchemaService(Option<Arc<GraphqlSchema>>);
+
+impl SchemaService {
+    /// Returns the schema of the project, or [None] when no schema is configured.
+    pub fn schema(&self) -> Option<&GraphqlSchema> {
+        self.0.as_deref()
+    }
+}
+
+impl FromServices for SchemaService {
+    fn from_services(
+        rule_key: &RuleKey,
+        _rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> Result<Self, ServicesDiagnostic> {
+        let schema: &Option<Arc<GraphqlSchema>> = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["GraphqlSchema"]))?;
+
+        Ok(Self(schema.clone()))
+    }
+}
+
+impl Phase for SchemaService {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules that validate operations against the
+/// schema of the project.
+#[derive(Clone)]
+pub struct Schema<N>(N);
+
+impl<N> QueryMatch for Schema<N>
+where
+    N: AstNode<Language = GraphqlLanguage> + 'static,
+{
+    fn text_range(&self) -> TextRange {
+        self.0.range()
+    }
+}
+
+impl<N> Queryable for Schema<N>
+where
+    N: AstNode<Language = GraphqlLanguage> + 'static,
+{
+    type Input = GraphqlSyntaxNode;
+    type Output = N;
+
+    type Language = GraphqlLanguage;
+    type Services = SchemaService;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<GraphqlLanguage>, _: &GraphqlRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
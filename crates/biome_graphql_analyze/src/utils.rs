@@ -0,0 +1,47 @@
+use biome_graphql_semantic::{GraphqlSchema, SchemaInputValue};
+use biome_graphql_syntax::{GraphqlDirective, GraphqlField};
+use biome_rowan::declare_node_union;
+
+declare_node_union! {
+    /// A node that can receive arguments.
+    pub AnyGraphqlArgumentsOwner = GraphqlField | GraphqlDirective
+}
+
+impl AnyGraphqlArgumentsOwner {
+    /// Returns the arguments that the schema defines for this field or directive.
+    pub fn argument_definitions<'a>(
+        &self,
+        schema: &'a GraphqlSchema,
+    ) -> Option<&'a [SchemaInputValue]> {
+        match self {
+            Self::GraphqlField(field) => schema
+                .field_definition(field)
+                .map(|definition| definition.arguments.as_slice()),
+            Self::GraphqlDirective(directive) => {
+                let name = directive.name().ok()?.value_token().ok()?;
+                schema
+                    .get_directive(name.text_trimmed())
+                    .map(|definition| definition.arguments.as_slice())
+            }
+        }
+    }
+
+    /// Returns the name of the field or directive, as written in the operation.
+    pub fn name(&self) -> Option<String> {
+        match self {
+            Self::GraphqlField(field) => Some(
+                field
+                    .name()
+                    .ok()?
+                    .value_token()
+                    .ok()?
+                    .text_trimmed()
+                    .to_string(),
+            ),
+            Self::GraphqlDirective(directive) => Some(format!(
+                "@{}",
+                directive.name().ok()?.value_token().ok()?.text_trimmed()
+            )),
+        }
+    }
+}
@@ -12,6 +12,7 @@ pub(crate) mod implements_interfaces;
 pub(crate) mod inline_fragment;
 pub(crate) mod list_type;
 pub(crate) mod literal_name;
+pub(crate) mod metavariable;
 pub(crate) mod name_binding;
 pub(crate) mod name_reference;
 pub(crate) mod non_null_type;
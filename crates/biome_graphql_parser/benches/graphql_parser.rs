@@ -1,5 +1,5 @@
 use biome_diagnostics::{DiagnosticExt, print_diagnostic_to_string};
-use biome_graphql_parser::{parse_graphql, parse_graphql_with_cache};
+use biome_graphql_parser::{GraphqlParserOptions, parse_graphql, parse_graphql_with_cache};
 use biome_rowan::NodeCache;
 use biome_test_utils::BenchCase;
 use criterion::{
@@ -59,11 +59,19 @@ fn bench_parser(criterion: &mut Criterion) {
                         b.iter_batched(
                             || {
                                 let mut cache = NodeCache::default();
-                                parse_graphql_with_cache(code, &mut cache);
+                                parse_graphql_with_cache(
+                                    code,
+                                    &mut cache,
+                                    GraphqlParserOptions::default(),
+                                );
                                 cache
                             },
                             |mut cache| {
-                                black_box(parse_graphql_with_cache(code, &mut cache));
+                                black_box(parse_graphql_with_cache(
+                                    code,
+                                    &mut cache,
+                                    GraphqlParserOptions::default(),
+                                ));
                             },
                             BatchSize::SmallInput,
                         )
@@ -2,6 +2,7 @@
 #[rustfmt::skip]
 mod tests;
 
+use crate::parser::GraphqlParserOptions;
 use biome_graphql_syntax::{GraphqlSyntaxKind, GraphqlSyntaxKind::*, T, TextLen, TextSize};
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
@@ -31,6 +32,8 @@ pub struct GraphqlLexer<'src> {
     current_flags: TokenFlags,
 
     diagnostics: Vec<ParseDiagnostic>,
+
+    options: GraphqlParserOptions,
 }
 
 impl<'src> Lexer<'src> for GraphqlLexer<'src> {
@@ -145,9 +148,14 @@ impl<'src> GraphqlLexer<'src> {
             position: 0,
             diagnostics: vec![],
             unicode_bom_length: 0,
+            options: GraphqlParserOptions::default(),
         }
     }
 
+    pub(crate) fn with_options(self, options: GraphqlParserOptions) -> Self {
+        Self { options, ..self }
+    }
+
     /// Bumps the current byte and creates a lexed token of the passed in kind
     fn consume_byte(&mut self, tok: GraphqlSyntaxKind) -> GraphqlSyntaxKind {
         self.advance(1);
@@ -180,6 +188,9 @@ impl<'src> GraphqlLexer<'src> {
             b'#' => self.consume_comment(),
             _ if is_name_start(current) => self.consume_name(current),
             _ if is_number_start(current) => self.consume_number(current),
+            _ if self.options.is_metavariable_enabled() && self.is_metavariable_start() => {
+                self.consume_metavariable(GRIT_METAVARIABLE)
+            }
             _ if self.position == 0 => {
                 if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
                     self.unicode_bom_length = bom_size;
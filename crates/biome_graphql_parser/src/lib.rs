@@ -9,6 +9,8 @@ use biome_parser::{AnyParse, tree_sink::LosslessTreeSink};
 use biome_rowan::{AstNode, NodeCache};
 use parser::{GraphqlParser, parse_root};
 
+pub use parser::GraphqlParserOptions;
+
 mod lexer;
 mod parser;
 mod token_source;
@@ -17,13 +19,22 @@ pub(crate) type GraphqlLosslessTreeSink<'source> =
     LosslessTreeSink<'source, GraphqlLanguage, GraphqlSyntaxFactory>;
 
 pub fn parse_graphql(source: &str) -> GraphqlParse {
+    parse_graphql_with_options(source, GraphqlParserOptions::default())
+}
+
+/// Parses the provided string as Graphql program using the provided options.
+pub fn parse_graphql_with_options(source: &str, options: GraphqlParserOptions) -> GraphqlParse {
     let mut cache = NodeCache::default();
-    parse_graphql_with_cache(source, &mut cache)
+    parse_graphql_with_cache(source, &mut cache, options)
 }
 
 /// Parses the provided string as Graphql program using the provided node cache.
-pub fn parse_graphql_with_cache(source: &str, cache: &mut NodeCache) -> GraphqlParse {
-    let mut parser = GraphqlParser::new(source);
+pub fn parse_graphql_with_cache(
+    source: &str,
+    cache: &mut NodeCache,
+    options: GraphqlParserOptions,
+) -> GraphqlParse {
+    let mut parser = GraphqlParser::new(source, options);
 
     parse_root(&mut parser);
 
@@ -66,11 +66,31 @@ pub(crate) struct GraphqlParser<'source> {
     source: GraphqlTokenSource<'source>,
 }
 
+#[derive(Default, Debug, Clone, Copy)]
+pub struct GraphqlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    /// Defaults to `false`.
+    pub grit_metavariables: bool,
+}
+
+impl GraphqlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    pub fn allow_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
+
+    /// Checks if parsing of Grit metavariables is enabled.
+    pub fn is_metavariable_enabled(&self) -> bool {
+        self.grit_metavariables
+    }
+}
+
 impl<'source> GraphqlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: GraphqlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: GraphqlTokenSource::from_str(source),
+            source: GraphqlTokenSource::from_str(source, options),
         }
     }
 
@@ -123,6 +143,21 @@ pub(crate) fn parse_root(p: &mut GraphqlParser) -> CompletedMarker {
     m.complete(p, GRAPHQL_ROOT)
 }
 
+#[inline]
+fn is_at_metavariable(p: &mut GraphqlParser) -> bool {
+    p.at(GRIT_METAVARIABLE)
+}
+
+#[inline]
+fn parse_metavariable(p: &mut GraphqlParser) -> ParsedSyntax {
+    if !is_at_metavariable(p) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump(GRIT_METAVARIABLE);
+    Present(m.complete(p, GRAPHQL_METAVARIABLE))
+}
+
 #[inline]
 fn parse_literal_name(p: &mut GraphqlParser) -> ParsedSyntax {
     if !is_nth_at_name(p, 0) {
@@ -12,9 +12,9 @@ use biome_parser::{
 
 use super::{
     argument::is_at_argument_list_end,
-    is_nth_at_name,
+    is_at_metavariable, is_nth_at_name,
     parse_error::{expected_object_field, expected_value},
-    parse_literal_name,
+    parse_literal_name, parse_metavariable,
     variable::{is_at_variable, parse_variable_reference},
 };
 
@@ -114,7 +114,9 @@ pub(crate) fn parse_default_value(p: &mut GraphqlParser) -> ParsedSyntax {
 
 #[inline]
 pub(crate) fn parse_value(p: &mut GraphqlParser) -> ParsedSyntax {
-    if is_at_variable(p) {
+    if is_at_metavariable(p) {
+        parse_metavariable(p)
+    } else if is_at_variable(p) {
         parse_variable_reference(p)
     } else if is_at_int(p) {
         parse_int(p)
@@ -235,7 +237,8 @@ fn parse_object_field(p: &mut GraphqlParser) -> ParsedSyntax {
 
 #[inline]
 fn is_at_value(p: &mut GraphqlParser) -> bool {
-    is_at_variable(p)
+    is_at_metavariable(p)
+        || is_at_variable(p)
         || is_at_int(p)
         || is_at_float(p)
         || is_at_string(p)
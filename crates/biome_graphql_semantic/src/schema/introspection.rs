@@ -0,0 +1,216 @@
+use super::{
+    GraphqlSchema, SchemaDirective, SchemaField, SchemaInputValue, SchemaType, SchemaTypeKind,
+    SchemaTypeRef,
+};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Error;
+use biome_json_parser::JsonParserOptions;
+
+impl GraphqlSchema {
+    /// Builds a schema from the JSON result of an introspection query.
+    ///
+    /// Both the full response, `{ "data": { "__schema": ... } }`, and its
+    /// `data` object are accepted.
+    ///
+    /// ```rust
+    /// use biome_graphql_semantic::GraphqlSchema;
+    ///
+    /// let (schema, diagnostics) = GraphqlSchema::from_introspection(r#"{
+    ///     "__schema": {
+    ///         "queryType": { "name": "Query" },
+    ///         "types": [{
+    ///             "kind": "OBJECT",
+    ///             "name": "Query",
+    ///             "fields": [{
+    ///                 "name": "hero",
+    ///                 "args": [],
+    ///                 "type": { "kind": "SCALAR", "name": "String", "ofType": null }
+    ///             }]
+    ///         }]
+    ///     }
+    /// }"#);
+    /// assert!(diagnostics.is_empty());
+    ///
+    /// let schema = schema.unwrap();
+    /// assert!(schema.get_type("Query").unwrap().field("hero").is_some());
+    /// ```
+    pub fn from_introspection(source: &str) -> (Option<Self>, Vec<Error>) {
+        let (result, diagnostics) = deserialize_from_json_str::<IntrospectionResult>(
+            source,
+            JsonParserOptions::default(),
+            "",
+        )
+        .consume();
+        let schema = result
+            .and_then(|result| result.data.map(|data| data.schema).or(result.schema))
+            .map(Self::from);
+        (schema, diagnostics)
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionResult {
+    data: Option<IntrospectionData>,
+    #[deserializable(rename = "__schema")]
+    schema: Option<IntrospectionSchema>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionData {
+    #[deserializable(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionSchema {
+    query_type: Option<IntrospectionNamedType>,
+    mutation_type: Option<IntrospectionNamedType>,
+    subscription_type: Option<IntrospectionNamedType>,
+    types: Vec<IntrospectionType>,
+    directives: Vec<IntrospectionDirective>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionNamedType {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionType {
+    kind: String,
+    name: String,
+    fields: Option<Vec<IntrospectionField>>,
+    input_fields: Option<Vec<IntrospectionInputValue>>,
+    interfaces: Option<Vec<IntrospectionNamedType>>,
+    possible_types: Option<Vec<IntrospectionNamedType>>,
+    enum_values: Option<Vec<IntrospectionNamedType>>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionField {
+    name: String,
+    args: Vec<IntrospectionInputValue>,
+    #[deserializable(rename = "type")]
+    ty: IntrospectionTypeRef,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionInputValue {
+    name: String,
+    #[deserializable(rename = "type")]
+    ty: IntrospectionTypeRef,
+    default_value: Option<String>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionTypeRef {
+    kind: String,
+    name: Option<String>,
+    of_type: Option<Box<IntrospectionTypeRef>>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct IntrospectionDirective {
+    name: String,
+    args: Vec<IntrospectionInputValue>,
+}
+
+impl From<IntrospectionSchema> for GraphqlSchema {
+    fn from(introspection: IntrospectionSchema) -> Self {
+        let mut schema = Self {
+            query_type: introspection.query_type.map(|ty| ty.name.into()),
+            mutation_type: introspection.mutation_type.map(|ty| ty.name.into()),
+            subscription_type: introspection.subscription_type.map(|ty| ty.name.into()),
+            ..Default::default()
+        };
+
+        for introspection_type in introspection.types {
+            let Some(kind) = type_kind(&introspection_type.kind) else {
+                continue;
+            };
+            let mut ty = SchemaType::new(introspection_type.name, kind);
+            for field in introspection_type.fields.unwrap_or_default() {
+                let Some(field_type) = type_ref(field.ty) else {
+                    continue;
+                };
+                let field = SchemaField {
+                    name: field.name.into(),
+                    arguments: input_values(field.args),
+                    ty: field_type,
+                };
+                ty.fields.insert(field.name.clone(), field);
+            }
+            for field in input_values(introspection_type.input_fields.unwrap_or_default()) {
+                ty.input_fields.insert(field.name.clone(), field);
+            }
+            ty.interfaces = names(introspection_type.interfaces);
+            if kind == SchemaTypeKind::Union {
+                ty.members = names(introspection_type.possible_types);
+            }
+            ty.enum_values = names(introspection_type.enum_values);
+            schema.types.insert(ty.name.clone(), ty);
+        }
+
+        for directive in introspection.directives {
+            let directive = SchemaDirective {
+                name: directive.name.into(),
+                arguments: input_values(directive.args),
+            };
+            schema.directives.insert(directive.name.clone(), directive);
+        }
+
+        schema.insert_builtins();
+        schema
+    }
+}
+
+fn type_kind(kind: &str) -> Option<SchemaTypeKind> {
+    match kind {
+        "SCALAR" => Some(SchemaTypeKind::Scalar),
+        "OBJECT" => Some(SchemaTypeKind::Object),
+        "INTERFACE" => Some(SchemaTypeKind::Interface),
+        "UNION" => Some(SchemaTypeKind::Union),
+        "ENUM" => Some(SchemaTypeKind::Enum),
+        "INPUT_OBJECT" => Some(SchemaTypeKind::InputObject),
+        _ => None,
+    }
+}
+
+fn type_ref(ty: IntrospectionTypeRef) -> Option<SchemaTypeRef> {
+    match ty.kind.as_str() {
+        "NON_NULL" => Some(SchemaTypeRef::NonNull(Box::new(type_ref(*ty.of_type?)?))),
+        "LIST" => Some(SchemaTypeRef::List(Box::new(type_ref(*ty.of_type?)?))),
+        _ => Some(SchemaTypeRef::Named(ty.name?.into())),
+    }
+}
+
+fn input_values(values: Vec<IntrospectionInputValue>) -> Vec<SchemaInputValue> {
+    values
+        .into_iter()
+        .filter_map(|value| {
+            Some(SchemaInputValue {
+                name: value.name.into(),
+                ty: type_ref(value.ty)?,
+                has_default_value: value.default_value.is_some(),
+            })
+        })
+        .collect()
+}
+
+fn names(types: Option<Vec<IntrospectionNamedType>>) -> Vec<Box<str>> {
+    types
+        .unwrap_or_default()
+        .into_iter()
+        .map(|ty| ty.name.into())
+        .collect()
+}
@@ -0,0 +1,454 @@
+//! A model of a GraphQL schema, used to validate operations against the types
+//! they query.
+//!
+//! A [GraphqlSchema] can be built from SDL documents with [GraphqlSchemaBuilder],
+//! or from the result of an introspection query with [GraphqlSchema::from_introspection].
+
+mod introspection;
+mod sdl;
+
+use biome_graphql_syntax::{
+    AnyGraphqlPrimitiveType, AnyGraphqlSelection, AnyGraphqlType, GraphqlDefinitionList,
+    GraphqlField, GraphqlFragmentDefinition, GraphqlInlineFragment, GraphqlOperationDefinition,
+    GraphqlSelectionSet, GraphqlSyntaxKind, GraphqlTypeCondition,
+};
+use biome_rowan::AstNode;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::fmt::{Display, Formatter};
+
+pub use sdl::*;
+
+/// The scalars that are part of every schema.
+const BUILTIN_SCALARS: [&str; 5] = ["Boolean", "Float", "ID", "Int", "String"];
+
+/// The kind of operation, which determines the root type of its selection set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+impl OperationKind {
+    pub fn from_keyword(kind: GraphqlSyntaxKind) -> Option<Self> {
+        match kind {
+            GraphqlSyntaxKind::QUERY_KW => Some(Self::Query),
+            GraphqlSyntaxKind::MUTATION_KW => Some(Self::Mutation),
+            GraphqlSyntaxKind::SUBSCRIPTION_KW => Some(Self::Subscription),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SchemaTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+/// A named type of the schema.
+#[derive(Clone, Debug)]
+pub struct SchemaType {
+    pub name: Box<str>,
+    pub kind: SchemaTypeKind,
+    /// The fields of an object or an interface.
+    pub fields: FxHashMap<Box<str>, SchemaField>,
+    /// The fields of an input object.
+    pub input_fields: FxHashMap<Box<str>, SchemaInputValue>,
+    /// The interfaces implemented by an object or an interface.
+    pub interfaces: Vec<Box<str>>,
+    /// The members of a union.
+    pub members: Vec<Box<str>>,
+    /// The values of an enum.
+    pub enum_values: Vec<Box<str>>,
+}
+
+impl SchemaType {
+    pub fn new(name: impl Into<Box<str>>, kind: SchemaTypeKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            fields: FxHashMap::default(),
+            input_fields: FxHashMap::default(),
+            interfaces: Vec::new(),
+            members: Vec::new(),
+            enum_values: Vec::new(),
+        }
+    }
+
+    /// Returns the field called `name`, if the type is an object or an interface.
+    pub fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.get(name)
+    }
+
+    /// Whether the type can have a selection set.
+    pub const fn is_composite(&self) -> bool {
+        matches!(
+            self.kind,
+            SchemaTypeKind::Object | SchemaTypeKind::Interface | SchemaTypeKind::Union
+        )
+    }
+
+    /// Whether the type can be used for variables and arguments.
+    pub const fn is_input(&self) -> bool {
+        matches!(
+            self.kind,
+            SchemaTypeKind::Scalar | SchemaTypeKind::Enum | SchemaTypeKind::InputObject
+        )
+    }
+
+    /// Whether the type is a scalar or an enum, which can't have a selection set.
+    pub const fn is_leaf(&self) -> bool {
+        matches!(self.kind, SchemaTypeKind::Scalar | SchemaTypeKind::Enum)
+    }
+
+    pub const fn is_abstract(&self) -> bool {
+        matches!(self.kind, SchemaTypeKind::Interface | SchemaTypeKind::Union)
+    }
+}
+
+/// A field of an object or an interface.
+#[derive(Clone, Debug)]
+pub struct SchemaField {
+    pub name: Box<str>,
+    pub arguments: Vec<SchemaInputValue>,
+    pub ty: SchemaTypeRef,
+}
+
+impl SchemaField {
+    pub fn argument(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.arguments
+            .iter()
+            .find(|argument| argument.name.as_ref() == name)
+    }
+}
+
+/// An argument of a field or a directive, or a field of an input object.
+#[derive(Clone, Debug)]
+pub struct SchemaInputValue {
+    pub name: Box<str>,
+    pub ty: SchemaTypeRef,
+    pub has_default_value: bool,
+}
+
+impl SchemaInputValue {
+    /// Whether a value must be provided for this argument.
+    pub fn is_required(&self) -> bool {
+        self.ty.is_non_null() && !self.has_default_value
+    }
+}
+
+/// A directive that can be used in operations.
+#[derive(Clone, Debug)]
+pub struct SchemaDirective {
+    pub name: Box<str>,
+    pub arguments: Vec<SchemaInputValue>,
+}
+
+impl SchemaDirective {
+    pub fn argument(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.arguments
+            .iter()
+            .find(|argument| argument.name.as_ref() == name)
+    }
+}
+
+/// A reference to a type, with its list and non-null wrappers, e.g. `[String!]!`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaTypeRef {
+    Named(Box<str>),
+    List(Box<Self>),
+    NonNull(Box<Self>),
+}
+
+impl SchemaTypeRef {
+    /// Converts a type of the syntax tree, returning [None] if it contains bogus nodes.
+    pub fn from_type(ty: &AnyGraphqlType) -> Option<Self> {
+        match ty {
+            AnyGraphqlType::AnyGraphqlPrimitiveType(ty) => Self::from_primitive_type(ty),
+            AnyGraphqlType::GraphqlNonNullType(ty) => Some(Self::NonNull(Box::new(
+                Self::from_primitive_type(&ty.base().ok()?)?,
+            ))),
+            AnyGraphqlType::GraphqlBogusType(_) => None,
+        }
+    }
+
+    fn from_primitive_type(ty: &AnyGraphqlPrimitiveType) -> Option<Self> {
+        match ty {
+            AnyGraphqlPrimitiveType::GraphqlNameReference(name) => {
+                Some(Self::Named(name.value_token().ok()?.text_trimmed().into()))
+            }
+            AnyGraphqlPrimitiveType::GraphqlListType(list) => Some(Self::List(Box::new(
+                Self::from_type(&list.element().ok()?)?,
+            ))),
+        }
+    }
+
+    /// Returns the name of the type, without its list and non-null wrappers.
+    pub fn named_type(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) | Self::NonNull(ty) => ty.named_type(),
+        }
+    }
+
+    pub const fn is_non_null(&self) -> bool {
+        matches!(self, Self::NonNull(_))
+    }
+
+    /// Returns the type without its non-null wrapper.
+    pub fn nullable(&self) -> &Self {
+        match self {
+            Self::NonNull(ty) => ty,
+            ty => ty,
+        }
+    }
+
+    /// Whether a value of this type can be used where a value of `other` is
+    /// expected.
+    ///
+    /// A non-null type can be used where its nullable type is expected, but
+    /// not the other way around.
+    pub fn is_subtype_of(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::NonNull(ty), Self::NonNull(other)) => ty.is_subtype_of(other),
+            (_, Self::NonNull(_)) => false,
+            (Self::NonNull(ty), other) => ty.is_subtype_of(other),
+            (Self::List(ty), Self::List(other)) => ty.is_subtype_of(other),
+            (Self::Named(name), Self::Named(other)) => name == other,
+            _ => false,
+        }
+    }
+}
+
+impl Display for SchemaTypeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Named(name) => write!(f, "{name}"),
+            Self::List(ty) => write!(f, "[{ty}]"),
+            Self::NonNull(ty) => write!(f, "{ty}!"),
+        }
+    }
+}
+
+/// The types and directives of a GraphQL schema.
+#[derive(Clone, Debug, Default)]
+pub struct GraphqlSchema {
+    types: FxHashMap<Box<str>, SchemaType>,
+    directives: FxHashMap<Box<str>, SchemaDirective>,
+    query_type: Option<Box<str>>,
+    mutation_type: Option<Box<str>>,
+    subscription_type: Option<Box<str>>,
+}
+
+impl GraphqlSchema {
+    /// Returns the type called `name`.
+    pub fn get_type(&self, name: &str) -> Option<&SchemaType> {
+        self.types.get(name)
+    }
+
+    /// Returns the directive called `name`, without its `@`.
+    pub fn get_directive(&self, name: &str) -> Option<&SchemaDirective> {
+        self.directives.get(name)
+    }
+
+    /// Returns the root type of the operations of the given kind.
+    pub fn root_type(&self, kind: OperationKind) -> Option<&SchemaType> {
+        let name = match kind {
+            OperationKind::Query => self.query_type.as_deref(),
+            OperationKind::Mutation => self.mutation_type.as_deref(),
+            OperationKind::Subscription => self.subscription_type.as_deref(),
+        }?;
+        self.get_type(name)
+    }
+
+    /// Returns the names of the object types that a value of the type `name`
+    /// can have at runtime.
+    pub fn possible_types(&self, name: &str) -> FxHashSet<&str> {
+        let Some(ty) = self.get_type(name) else {
+            return FxHashSet::default();
+        };
+        match ty.kind {
+            SchemaTypeKind::Object => FxHashSet::from_iter([ty.name.as_ref()]),
+            SchemaTypeKind::Union => ty.members.iter().map(AsRef::as_ref).collect(),
+            SchemaTypeKind::Interface => self
+                .types
+                .values()
+                .filter(|candidate| {
+                    candidate.kind == SchemaTypeKind::Object && self.implements(candidate, &ty.name)
+                })
+                .map(|candidate| candidate.name.as_ref())
+                .collect(),
+            _ => FxHashSet::default(),
+        }
+    }
+
+    /// Whether the type `ty` implements the interface `interface`, directly or
+    /// through another interface.
+    fn implements(&self, ty: &SchemaType, interface: &str) -> bool {
+        let mut visited = FxHashSet::default();
+        let mut queue: Vec<&str> = ty.interfaces.iter().map(AsRef::as_ref).collect();
+        while let Some(name) = queue.pop() {
+            if name == interface {
+                return true;
+            }
+            if visited.insert(name) {
+                if let Some(ty) = self.get_type(name) {
+                    queue.extend(ty.interfaces.iter().map(AsRef::as_ref));
+                }
+            }
+        }
+        false
+    }
+
+    /// Whether a fragment on the type `fragment_type` can ever match inside a
+    /// selection set of the type `parent_type`.
+    pub fn types_overlap(&self, fragment_type: &str, parent_type: &str) -> bool {
+        if fragment_type == parent_type {
+            return true;
+        }
+        let parent_possible_types = self.possible_types(parent_type);
+        self.possible_types(fragment_type)
+            .iter()
+            .any(|ty| parent_possible_types.contains(ty))
+    }
+
+    /// Returns the type of the values selected by `selection_set`, which is
+    /// the type of its field, of its fragment, or the root type of its operation.
+    ///
+    /// ```rust
+    /// use biome_graphql_parser::parse_graphql;
+    /// use biome_graphql_semantic::GraphqlSchemaBuilder;
+    /// use biome_graphql_syntax::GraphqlSelectionSet;
+    /// use biome_rowan::AstNode;
+    ///
+    /// let schema = parse_graphql("type Query { hero: Character } type Character { name: String }");
+    /// let mut builder = GraphqlSchemaBuilder::default();
+    /// builder.add_document(&schema.tree());
+    /// let schema = builder.build();
+    ///
+    /// let operation = parse_graphql("query { hero { name } }");
+    /// let selection_set = operation
+    ///     .syntax()
+    ///     .descendants()
+    ///     .filter_map(GraphqlSelectionSet::cast)
+    ///     .last()
+    ///     .unwrap();
+    ///
+    /// let ty = schema.selection_set_type(&selection_set).unwrap();
+    /// assert_eq!(ty.name.as_ref(), "Character");
+    /// ```
+    pub fn selection_set_type(&self, selection_set: &GraphqlSelectionSet) -> Option<&SchemaType> {
+        let parent = selection_set.syntax().parent()?;
+        if let Some(operation) = GraphqlOperationDefinition::cast_ref(&parent) {
+            let keyword = operation.ty().ok()?.value_token().ok()?;
+            self.root_type(OperationKind::from_keyword(keyword.kind())?)
+        } else if GraphqlDefinitionList::can_cast(parent.kind()) {
+            // A selection set used as a definition is a shorthand for a query
+            self.root_type(OperationKind::Query)
+        } else if let Some(field) = GraphqlField::cast_ref(&parent) {
+            let definition = self.field_definition(&field)?;
+            self.get_type(definition.ty.named_type())
+        } else if let Some(fragment) = GraphqlInlineFragment::cast_ref(&parent) {
+            match fragment.type_condition() {
+                Some(type_condition) => self.type_condition_type(&type_condition),
+                None => self.parent_type(&fragment.into()),
+            }
+        } else if let Some(fragment) = GraphqlFragmentDefinition::cast_ref(&parent) {
+            self.type_condition_type(&fragment.type_condition().ok()?)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type of the selection set that contains `selection`.
+    pub fn parent_type(&self, selection: &AnyGraphqlSelection) -> Option<&SchemaType> {
+        let selection_set = selection
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(GraphqlSelectionSet::cast)?;
+        self.selection_set_type(&selection_set)
+    }
+
+    /// Returns the definition of the field selected by `field`.
+    pub fn field_definition(&self, field: &GraphqlField) -> Option<&SchemaField> {
+        let name = field.name().ok()?.value_token().ok()?;
+        self.parent_type(&field.clone().into())?
+            .field(name.text_trimmed())
+    }
+
+    /// Returns the type named by a type condition, e.g. `on Droid`.
+    pub fn type_condition_type(
+        &self,
+        type_condition: &GraphqlTypeCondition,
+    ) -> Option<&SchemaType> {
+        let name = type_condition.ty().ok()?.value_token().ok()?;
+        self.get_type(name.text_trimmed())
+    }
+
+    fn insert_builtins(&mut self) {
+        for scalar in BUILTIN_SCALARS {
+            self.types
+                .entry(scalar.into())
+                .or_insert_with(|| SchemaType::new(scalar, SchemaTypeKind::Scalar));
+        }
+
+        let argument = |name: &str, ty: &str, non_null: bool| {
+            let ty = SchemaTypeRef::Named(ty.into());
+            SchemaInputValue {
+                name: name.into(),
+                ty: if non_null {
+                    SchemaTypeRef::NonNull(Box::new(ty))
+                } else {
+                    ty
+                },
+                has_default_value: false,
+            }
+        };
+        for directive in [
+            SchemaDirective {
+                name: "skip".into(),
+                arguments: vec![argument("if", "Boolean", true)],
+            },
+            SchemaDirective {
+                name: "include".into(),
+                arguments: vec![argument("if", "Boolean", true)],
+            },
+            SchemaDirective {
+                name: "deprecated".into(),
+                arguments: vec![SchemaInputValue {
+                    has_default_value: true,
+                    ..argument("reason", "String", false)
+                }],
+            },
+            SchemaDirective {
+                name: "specifiedBy".into(),
+                arguments: vec![argument("url", "String", true)],
+            },
+            SchemaDirective {
+                name: "oneOf".into(),
+                arguments: Vec::new(),
+            },
+        ] {
+            self.directives
+                .entry(directive.name.clone())
+                .or_insert(directive);
+        }
+
+        // Without a schema definition, the root types are found by their conventional names.
+        for (root_type, name) in [
+            (&mut self.query_type, "Query"),
+            (&mut self.mutation_type, "Mutation"),
+            (&mut self.subscription_type, "Subscription"),
+        ] {
+            if root_type.is_none() && self.types.contains_key(name) {
+                *root_type = Some(name.into());
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use super::{
+    GraphqlSchema, OperationKind, SchemaDirective, SchemaField, SchemaInputValue, SchemaType,
+    SchemaTypeKind, SchemaTypeRef,
+};
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlTypeDefinition, AnyGraphqlTypeExtension,
+    GraphqlArgumentsDefinition, GraphqlEnumValuesDefinition, GraphqlFieldsDefinition,
+    GraphqlImplementsInterfaces, GraphqlInputFieldsDefinition, GraphqlInputValueDefinition,
+    GraphqlRoot, GraphqlRootOperationTypes, GraphqlSyntaxToken, GraphqlUnionMemberTypes,
+};
+use biome_rowan::{AstNodeList, AstSeparatedList};
+
+/// Builds a [GraphqlSchema] from one or more SDL documents.
+///
+/// Types can be split across documents, and extended with `extend type` in any
+/// document.
+///
+/// ```rust
+/// use biome_graphql_parser::parse_graphql;
+/// use biome_graphql_semantic::GraphqlSchemaBuilder;
+///
+/// let parse = parse_graphql("type Query { hero: Character } type Character { name: String }");
+/// let mut builder = GraphqlSchemaBuilder::default();
+/// builder.add_document(&parse.tree());
+/// let schema = builder.build();
+///
+/// let hero = schema.get_type("Query").unwrap().field("hero").unwrap();
+/// assert_eq!(hero.ty.named_type(), "Character");
+/// ```
+#[derive(Debug, Default)]
+pub struct GraphqlSchemaBuilder {
+    schema: GraphqlSchema,
+}
+
+impl GraphqlSchemaBuilder {
+    /// Adds the type system definitions of `root` to the schema.
+    ///
+    /// Operations and fragments are ignored.
+    pub fn add_document(&mut self, root: &GraphqlRoot) {
+        for definition in root.definitions() {
+            match definition {
+                AnyGraphqlDefinition::GraphqlSchemaDefinition(definition) => {
+                    if let Ok(types) = definition.root_operation_types() {
+                        self.add_root_operation_types(&types);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlSchemaExtension(extension) => {
+                    if let Some(types) = extension.root_operation_types() {
+                        self.add_root_operation_types(&types);
+                    }
+                }
+                AnyGraphqlDefinition::AnyGraphqlTypeDefinition(definition) => {
+                    self.add_type_definition(&definition);
+                }
+                AnyGraphqlDefinition::AnyGraphqlTypeExtension(extension) => {
+                    self.add_type_extension(&extension);
+                }
+                AnyGraphqlDefinition::GraphqlDirectiveDefinition(definition) => {
+                    let Ok(name) = definition.name().and_then(|name| name.value_token()) else {
+                        continue;
+                    };
+                    let directive = SchemaDirective {
+                        name: name.text_trimmed().into(),
+                        arguments: definition
+                            .arguments()
+                            .map(|arguments| input_values_of_arguments(&arguments))
+                            .unwrap_or_default(),
+                    };
+                    self.schema
+                        .directives
+                        .insert(directive.name.clone(), directive);
+                }
+                AnyGraphqlDefinition::GraphqlOperationDefinition(_)
+                | AnyGraphqlDefinition::GraphqlSelectionSet(_)
+                | AnyGraphqlDefinition::GraphqlFragmentDefinition(_)
+                | AnyGraphqlDefinition::GraphqlBogusDefinition(_) => {}
+            }
+        }
+    }
+
+    /// Adds the built-in scalars and directives, and returns the schema.
+    pub fn build(mut self) -> GraphqlSchema {
+        self.schema.insert_builtins();
+        self.schema
+    }
+
+    fn add_root_operation_types(&mut self, types: &GraphqlRootOperationTypes) {
+        for definition in types.root_operation_type() {
+            let Ok(operation_type) = definition.operation_type() else {
+                continue;
+            };
+            let Ok(name) = definition.named_type().and_then(|name| name.value_token()) else {
+                continue;
+            };
+            let Ok(keyword) = operation_type.value_token() else {
+                continue;
+            };
+            let name = Some(name.text_trimmed().into());
+            match OperationKind::from_keyword(keyword.kind()) {
+                Some(OperationKind::Query) => self.schema.query_type = name,
+                Some(OperationKind::Mutation) => self.schema.mutation_type = name,
+                Some(OperationKind::Subscription) => self.schema.subscription_type = name,
+                None => {}
+            }
+        }
+    }
+
+    fn add_type_definition(&mut self, definition: &AnyGraphqlTypeDefinition) {
+        match definition {
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(definition) => {
+                if let Ok(name) = definition.name().and_then(|name| name.value_token()) {
+                    self.type_entry(&name, SchemaTypeKind::Scalar);
+                }
+            }
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(definition) => {
+                if let Ok(name) = definition.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Object);
+                    add_interfaces(ty, definition.implements());
+                    add_fields(ty, definition.fields());
+                }
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(definition) => {
+                if let Ok(name) = definition.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Interface);
+                    add_interfaces(ty, definition.implements());
+                    add_fields(ty, definition.fields());
+                }
+            }
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(definition) => {
+                if let Ok(name) = definition.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Union);
+                    add_members(ty, definition.union_members());
+                }
+            }
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(definition) => {
+                if let Ok(name) = definition.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Enum);
+                    add_enum_values(ty, definition.enum_values());
+                }
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(definition) => {
+                if let Ok(name) = definition.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::InputObject);
+                    add_input_fields(ty, definition.input_fields());
+                }
+            }
+        }
+    }
+
+    fn add_type_extension(&mut self, extension: &AnyGraphqlTypeExtension) {
+        match extension {
+            AnyGraphqlTypeExtension::GraphqlScalarTypeExtension(extension) => {
+                if let Ok(name) = extension.name().and_then(|name| name.value_token()) {
+                    self.type_entry(&name, SchemaTypeKind::Scalar);
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(extension) => {
+                if let Ok(name) = extension.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Object);
+                    add_interfaces(ty, extension.implements());
+                    add_fields(ty, extension.fields());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(extension) => {
+                if let Ok(name) = extension.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Interface);
+                    add_interfaces(ty, extension.implements());
+                    add_fields(ty, extension.fields());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlUnionTypeExtension(extension) => {
+                if let Ok(name) = extension.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Union);
+                    add_members(ty, extension.union_members());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlEnumTypeExtension(extension) => {
+                if let Ok(name) = extension.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::Enum);
+                    add_enum_values(ty, extension.enum_values());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlInputObjectTypeExtension(extension) => {
+                if let Ok(name) = extension.name().and_then(|name| name.value_token()) {
+                    let ty = self.type_entry(&name, SchemaTypeKind::InputObject);
+                    add_input_fields(ty, extension.input_fields());
+                }
+            }
+        }
+    }
+
+    /// Returns the type called `name`, creating it if it doesn't exist yet.
+    ///
+    /// Extensions can come before the definition they extend, so the kind of
+    /// the type is always taken from the last definition.
+    fn type_entry(&mut self, name: &GraphqlSyntaxToken, kind: SchemaTypeKind) -> &mut SchemaType {
+        let name = name.text_trimmed();
+        self.schema
+            .types
+            .entry(name.into())
+            .and_modify(|ty| ty.kind = kind)
+            .or_insert_with(|| SchemaType::new(name, kind))
+    }
+}
+
+fn add_interfaces(ty: &mut SchemaType, implements: Option<GraphqlImplementsInterfaces>) {
+    let Some(implements) = implements else {
+        return;
+    };
+    ty.interfaces.extend(
+        implements
+            .interfaces()
+            .iter()
+            .filter_map(|interface| interface.ok()?.value_token().ok())
+            .map(|interface| interface.text_trimmed().into()),
+    );
+}
+
+fn add_members(ty: &mut SchemaType, members: Option<GraphqlUnionMemberTypes>) {
+    let Some(members) = members else {
+        return;
+    };
+    ty.members.extend(
+        members
+            .members()
+            .iter()
+            .filter_map(|member| member.ok()?.value_token().ok())
+            .map(|member| member.text_trimmed().into()),
+    );
+}
+
+fn add_enum_values(ty: &mut SchemaType, values: Option<GraphqlEnumValuesDefinition>) {
+    let Some(values) = values else {
+        return;
+    };
+    ty.enum_values.extend(
+        values
+            .values()
+            .iter()
+            .filter_map(|value| value.value().ok()?.value_token().ok())
+            .map(|value| value.text_trimmed().into()),
+    );
+}
+
+fn add_fields(ty: &mut SchemaType, fields: Option<GraphqlFieldsDefinition>) {
+    let Some(fields) = fields else {
+        return;
+    };
+    for field in fields.fields() {
+        let Ok(name) = field.name().and_then(|name| name.value_token()) else {
+            continue;
+        };
+        let Some(field_type) = field.ty().ok().and_then(|ty| SchemaTypeRef::from_type(&ty)) else {
+            continue;
+        };
+        let field = SchemaField {
+            name: name.text_trimmed().into(),
+            arguments: field
+                .arguments()
+                .map(|arguments| input_values_of_arguments(&arguments))
+                .unwrap_or_default(),
+            ty: field_type,
+        };
+        ty.fields.insert(field.name.clone(), field);
+    }
+}
+
+fn add_input_fields(ty: &mut SchemaType, fields: Option<GraphqlInputFieldsDefinition>) {
+    let Some(fields) = fields else {
+        return;
+    };
+    for field in fields.fields() {
+        if let Some(field) = input_value(&field) {
+            ty.input_fields.insert(field.name.clone(), field);
+        }
+    }
+}
+
+fn input_values_of_arguments(arguments: &GraphqlArgumentsDefinition) -> Vec<SchemaInputValue> {
+    arguments
+        .arguments()
+        .iter()
+        .filter_map(|argument| input_value(&argument))
+        .collect()
+}
+
+fn input_value(definition: &GraphqlInputValueDefinition) -> Option<SchemaInputValue> {
+    Some(SchemaInputValue {
+        name: definition
+            .name()
+            .ok()?
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            .into(),
+        ty: SchemaTypeRef::from_type(&definition.ty().ok()?)?,
+        has_default_value: definition.default().is_some(),
+    })
+}
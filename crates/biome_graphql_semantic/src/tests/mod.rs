@@ -5,6 +5,7 @@ mod interface;
 mod object;
 mod operation;
 mod scalar;
+mod schema;
 mod union;
 
 use biome_graphql_parser::GraphqlParse;
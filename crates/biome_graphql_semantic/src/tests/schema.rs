@@ -0,0 +1,199 @@
+use biome_graphql_parser::parse_graphql;
+
+use crate::{GraphqlSchema, GraphqlSchemaBuilder, OperationKind, SchemaTypeKind, SchemaTypeRef};
+
+fn build_schema(sources: &[&str]) -> GraphqlSchema {
+    let mut builder = GraphqlSchemaBuilder::default();
+    for source in sources {
+        builder.add_document(&parse_graphql(source).tree());
+    }
+    builder.build()
+}
+
+#[test]
+fn ok_schema_from_sdl_documents() {
+    let schema = build_schema(&[
+        r#"
+schema {
+  query: RootQuery
+}
+
+type RootQuery {
+  hero: Character
+}
+
+interface Character {
+  name: String!
+}
+"#,
+        r#"
+type Droid implements Character {
+  name: String!
+}
+
+extend type Droid {
+  primaryFunction(language: String = "en"): String
+}
+
+extend type RootQuery {
+  droids(ids: [ID!]!): [Droid]
+}
+"#,
+    ]);
+
+    let query = schema.root_type(OperationKind::Query).unwrap();
+    assert_eq!(query.name.as_ref(), "RootQuery");
+    assert!(schema.root_type(OperationKind::Mutation).is_none());
+
+    let droids = query.field("droids").unwrap();
+    assert_eq!(droids.ty.to_string(), "[Droid]");
+    let ids = droids.argument("ids").unwrap();
+    assert_eq!(ids.ty.to_string(), "[ID!]!");
+    assert!(ids.is_required());
+
+    let droid = schema.get_type("Droid").unwrap();
+    assert_eq!(droid.kind, SchemaTypeKind::Object);
+    assert!(droid.field("name").is_some());
+    let language = droid
+        .field("primaryFunction")
+        .unwrap()
+        .argument("language")
+        .unwrap();
+    assert!(!language.is_required());
+
+    // Built-in scalars and directives are always defined
+    assert!(schema.get_type("Boolean").unwrap().is_leaf());
+    assert!(
+        schema
+            .get_directive("include")
+            .unwrap()
+            .argument("if")
+            .is_some()
+    );
+}
+
+#[test]
+fn ok_schema_from_introspection() {
+    let (schema, diagnostics) = GraphqlSchema::from_introspection(
+        r#"{
+    "data": {
+        "__schema": {
+            "queryType": { "name": "Query" },
+            "mutationType": null,
+            "subscriptionType": null,
+            "types": [
+                {
+                    "kind": "OBJECT",
+                    "name": "Query",
+                    "fields": [
+                        {
+                            "name": "droids",
+                            "args": [
+                                {
+                                    "name": "ids",
+                                    "type": {
+                                        "kind": "NON_NULL",
+                                        "name": null,
+                                        "ofType": {
+                                            "kind": "LIST",
+                                            "name": null,
+                                            "ofType": { "kind": "SCALAR", "name": "ID", "ofType": null }
+                                        }
+                                    },
+                                    "defaultValue": null
+                                }
+                            ],
+                            "type": { "kind": "LIST", "name": null, "ofType": { "kind": "UNION", "name": "SearchResult", "ofType": null } }
+                        }
+                    ],
+                    "interfaces": []
+                },
+                {
+                    "kind": "UNION",
+                    "name": "SearchResult",
+                    "possibleTypes": [{ "name": "Query" }]
+                },
+                {
+                    "kind": "INPUT_OBJECT",
+                    "name": "Filter",
+                    "inputFields": [
+                        {
+                            "name": "limit",
+                            "type": { "kind": "SCALAR", "name": "Int", "ofType": null },
+                            "defaultValue": "10"
+                        }
+                    ]
+                }
+            ],
+            "directives": []
+        }
+    }
+}"#,
+    );
+    assert!(diagnostics.is_empty());
+
+    let schema = schema.unwrap();
+    let droids = schema
+        .root_type(OperationKind::Query)
+        .unwrap()
+        .field("droids")
+        .unwrap();
+    assert_eq!(droids.argument("ids").unwrap().ty.to_string(), "[ID]!");
+    assert_eq!(droids.ty.named_type(), "SearchResult");
+
+    assert!(schema.get_type("SearchResult").unwrap().is_abstract());
+    let filter = schema.get_type("Filter").unwrap();
+    assert!(filter.is_input());
+    assert!(filter.input_fields["limit"].has_default_value);
+
+    // Built-in directives are added even when the introspection omits them
+    assert!(schema.get_directive("skip").is_some());
+}
+
+#[test]
+fn ok_types_overlap() {
+    let schema = build_schema(&[r#"
+type Query {
+  hero: Character
+}
+
+interface Character {
+  name: String
+}
+
+type Human implements Character {
+  name: String
+}
+
+type Droid implements Character {
+  name: String
+}
+
+type Starship {
+  name: String
+}
+
+union SearchResult = Human | Starship
+"#]);
+
+    assert!(schema.types_overlap("Human", "Character"));
+    assert!(schema.types_overlap("Character", "Human"));
+    assert!(schema.types_overlap("Character", "SearchResult"));
+    assert!(schema.types_overlap("Starship", "SearchResult"));
+    assert!(!schema.types_overlap("Droid", "SearchResult"));
+    assert!(!schema.types_overlap("Starship", "Character"));
+    assert!(!schema.types_overlap("Human", "Droid"));
+}
+
+#[test]
+fn ok_type_ref_subtypes() {
+    let id = SchemaTypeRef::Named("ID".into());
+    let non_null_id = SchemaTypeRef::NonNull(Box::new(id.clone()));
+    let list = SchemaTypeRef::List(Box::new(non_null_id.clone()));
+
+    assert!(non_null_id.is_subtype_of(&id));
+    assert!(!id.is_subtype_of(&non_null_id));
+    assert!(list.is_subtype_of(&SchemaTypeRef::List(Box::new(id.clone()))));
+    assert!(!list.is_subtype_of(&id));
+    assert_eq!(SchemaTypeRef::NonNull(Box::new(list)).to_string(), "[ID!]!");
+}
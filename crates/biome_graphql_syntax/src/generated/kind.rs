@@ -73,6 +73,7 @@ pub enum GraphqlSyntaxKind {
     IDENT,
     COMMENT,
     COMMA,
+    GRIT_METAVARIABLE,
     GRAPHQL_ROOT,
     GRAPHQL_LITERAL_NAME,
     GRAPHQL_NAME_BINDING,
@@ -148,6 +149,7 @@ pub enum GraphqlSyntaxKind {
     GRAPHQL_INT_VALUE,
     GRAPHQL_BOOLEAN_VALUE,
     GRAPHQL_NULL_VALUE,
+    GRAPHQL_METAVARIABLE,
     GRAPHQL_BOGUS,
     GRAPHQL_BOGUS_DEFINITION,
     GRAPHQL_BOGUS_SELECTION,
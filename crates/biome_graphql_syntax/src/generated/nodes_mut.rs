@@ -753,6 +753,14 @@ impl GraphqlLiteralName {
         )
     }
 }
+impl GraphqlMetavariable {
+    pub fn with_value_token(self, element: SyntaxToken) -> Self {
+        Self::unwrap_cast(
+            self.syntax
+                .splice_slots(0usize..=0usize, once(Some(element.into()))),
+        )
+    }
+}
 impl GraphqlNameBinding {
     pub fn with_value_token(self, element: SyntaxToken) -> Self {
         Self::unwrap_cast(
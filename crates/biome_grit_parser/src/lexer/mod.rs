@@ -792,6 +792,7 @@ impl<'src> GritLexer<'src> {
                     b"r" => self.consume_regex(),
                     b"js" => T![js],
                     b"json" => T![json],
+                    b"graphql" => T![graphql],
                     b"css" => T![css],
                     b"grit" => T![grit],
                     b"html" => T![html],
@@ -845,6 +846,7 @@ impl<'src> GritLexer<'src> {
             b"js" => JS_KW,
             b"css" => CSS_KW,
             b"json" => JSON_KW,
+            b"graphql" => GRAPHQL_KW,
             b"grit" => GRIT_KW,
             b"html" => HTML_KW,
             b"typescript" => TYPESCRIPT_KW,
@@ -5,10 +5,13 @@ use crate::{
 use biome_diagnostics::{SourceCode, display::SourceFile};
 use biome_rowan::TextRange;
 use grit_pattern_matcher::{
-    binding::Binding, constant::Constant, effects::Effect, pattern::FileRegistry,
+    binding::Binding,
+    constant::Constant,
+    effects::Effect,
+    pattern::{FileRegistry, ResolvedPattern, get_top_level_effects},
 };
 use grit_util::{
-    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, Range,
+    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, EffectKind, Range,
     error::{GritPatternError, GritResult},
 };
 use std::{borrow::Cow, collections::HashMap, path::Path};
@@ -157,14 +160,42 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
         _distributed_indent: Option<usize>,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Node(node) => {
+                let (text, _) = linearize_binding(
+                    language,
+                    effects,
+                    files,
+                    memo,
+                    node.source(),
+                    node.code_range(),
+                    logs,
+                )?;
+                Ok(text.into())
+            }
+            Self::Range(range, source) => {
+                let (text, _) = linearize_binding(
+                    language,
+                    effects,
+                    files,
+                    memo,
+                    source,
+                    range.to_code_range(source),
+                    logs,
+                )?;
+                Ok(text.into())
+            }
+            Self::File(..) | Self::Empty(..) | Self::Constant(..) => {
+                Ok(self.text(language)?.into_owned().into())
+            }
+        }
     }
 
     fn text(&self, _language: &GritTargetLanguage) -> GritResult<Cow<'a, str>> {
@@ -272,6 +303,78 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
     }
 }
 
+/// Returns the text of the given `range` of `source` after applying the
+/// effects that target it.
+///
+/// Also returns the ranges of the replaced fragments in the returned text.
+pub(crate) fn linearize_binding<'a>(
+    language: &GritTargetLanguage,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    memo: &mut HashMap<CodeRange, Option<String>>,
+    source: &'a str,
+    range: CodeRange,
+    logs: &mut AnalysisLogs,
+) -> GritResult<(String, Vec<ByteRange>)> {
+    let top_level_effects = get_top_level_effects(effects, memo, &range, language, logs)?;
+
+    let mut replacements = Vec::with_capacity(top_level_effects.len());
+    for effect in top_level_effects {
+        let (Some(byte_range), Some(binding_range)) = (
+            effect.binding.range(language),
+            effect.binding.code_range(language),
+        ) else {
+            continue;
+        };
+
+        if let (EffectKind::Rewrite, Some(Some(text))) = (&effect.kind, memo.get(&binding_range)) {
+            replacements.push((byte_range, text.clone()));
+            continue;
+        }
+
+        // Marks the binding as being linearized, so that references to the
+        // binding from its own replacement resolve to its original text.
+        let previous = memo.insert(binding_range.clone(), None);
+        let text = effect
+            .pattern
+            .linearized_text(language, effects, files, memo, false, logs)?
+            .into_owned();
+        match effect.kind {
+            EffectKind::Rewrite => {
+                memo.insert(binding_range, Some(text.clone()));
+                replacements.push((byte_range, text));
+            }
+            EffectKind::Insert => {
+                match previous {
+                    Some(previous) => memo.insert(binding_range, previous),
+                    None => memo.remove(&binding_range),
+                };
+                replacements.push((ByteRange::new(byte_range.end, byte_range.end), text));
+            }
+        }
+    }
+
+    replacements.sort_by_key(|(byte_range, _)| (byte_range.start, byte_range.end));
+
+    let mut text = String::new();
+    let mut ranges = Vec::with_capacity(replacements.len());
+    let mut cursor = range.start as usize;
+    for (byte_range, replacement) in replacements {
+        if byte_range.start < cursor {
+            continue;
+        }
+
+        text.push_str(&source[cursor..byte_range.start]);
+        let start = text.len();
+        text.push_str(&replacement);
+        ranges.push(ByteRange::new(start, text.len()));
+        cursor = byte_range.end;
+    }
+    text.push_str(&source[cursor..range.end as usize]);
+
+    Ok((text, ranges))
+}
+
 /// Checks whether two nodes are equivalent.
 ///
 /// We define two nodes to be equivalent if they have the same sort (kind) and
@@ -1,4 +1,4 @@
-use crate::grit_binding::GritBinding;
+use crate::grit_binding::{GritBinding, linearize_binding};
 use crate::grit_built_in_functions::BuiltIns;
 use crate::grit_code_snippet::GritCodeSnippet;
 use crate::grit_file::GritFile;
@@ -18,9 +18,11 @@ use grit_pattern_matcher::pattern::{
     PredicateDefinition, ResolvedPattern, State,
 };
 use grit_util::error::GritPatternError;
-use grit_util::{AnalysisLogs, FileOrigin, InputRanges, MatchRanges, error::GritResult};
+use grit_util::{
+    AnalysisLogs, ByteRange, CodeRange, FileOrigin, InputRanges, MatchRanges, error::GritResult,
+};
 use path_absolutize::Absolutize;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
@@ -178,13 +180,49 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
         };
         for file_ptr in files {
             let file = state.files.get_file_owner(file_ptr);
-            let mut match_log = file.matches.borrow_mut();
+            {
+                let mut match_log = file.matches.borrow_mut();
+                if match_log.input_matches.is_none() {
+                    match_log.input_matches = Some(input_ranges.clone());
+                }
+            }
 
-            if match_log.input_matches.is_none() {
-                match_log.input_matches = Some(input_ranges.clone());
+            if state.effects.is_empty() {
+                continue;
             }
 
-            // TODO: Implement effect application
+            let source = file.tree.text();
+            let (new_source, byte_ranges) = linearize_binding(
+                &self.lang,
+                &state.effects,
+                &state.files,
+                &mut HashMap::new(),
+                source,
+                CodeRange::new(0, source.len() as u32, source),
+                logs,
+            )?;
+            if new_source == source {
+                continue;
+            }
+
+            let owned_file = mutated_file_owner(
+                file.name.clone(),
+                &new_source,
+                byte_ranges,
+                &self.lang,
+                logs,
+            )?
+            .ok_or_else(|| {
+                GritPatternError::Builder(format!(
+                    "failed to parse rewritten file {}",
+                    file.name.to_string_lossy()
+                ))
+            })?;
+            self.files().push(owned_file);
+            // SAFETY: We just pushed to the list of files, so there must be one.
+            state
+                .files
+                .push_revision(&file_ptr, self.files().last().unwrap());
         }
 
         let new_files_binding = &mut state.bindings[GLOBAL_VARS_SCOPE_INDEX as usize]
@@ -325,6 +363,31 @@ fn new_file_owner(
     }))
 }
 
+fn mutated_file_owner(
+    name: PathBuf,
+    source: &str,
+    byte_ranges: Vec<ByteRange>,
+    language: &GritTargetLanguage,
+    logs: &mut AnalysisLogs,
+) -> GritResult<Option<FileOwner<GritTargetTree>>> {
+    let Some(tree) =
+        language
+            .get_parser()
+            .parse_file(source, Some(&name), logs, FileOrigin::Mutated)
+    else {
+        return Ok(None);
+    };
+
+    let absolute_path = name.absolutize()?.to_path_buf();
+    Ok(Some(FileOwner {
+        name,
+        absolute_path,
+        tree,
+        matches: MatchRanges::new(byte_ranges).into(),
+        new: false,
+    }))
+}
+
 /// Simple wrapper for target files so that we can avoid doing file I/O inside
 /// the Grit engine.
 ///
@@ -15,6 +15,7 @@ use crate::variables::{VarRegistry, VariableLocations};
 use crate::{BuiltInFunction, CompileError};
 use biome_analyze::RuleDiagnostic;
 use biome_grit_syntax::{GritRoot, GritRootExt};
+use biome_parser::AnyParse;
 use camino::Utf8Path;
 use grit_pattern_matcher::constants::{
     ABSOLUTE_PATH_INDEX, FILENAME_INDEX, NEW_FILES_INDEX, PROGRAM_INDEX,
@@ -197,13 +198,32 @@ impl GritQuery {
         })
     }
 
+    /// Parses the given `source` with the target language of the query.
+    ///
+    /// This is used to turn the output of a [Rewrite] back into a syntax tree.
+    pub fn parse(&self, source: &str, path: &Utf8Path) -> AnyParse {
+        self.language.get_parser().parse_with_path(source, path)
+    }
+
     pub fn supports_css(&self) -> bool {
         matches!(self.language, GritTargetLanguage::CssTargetLanguage(_))
     }
 
+    pub fn supports_graphql(&self) -> bool {
+        matches!(self.language, GritTargetLanguage::GraphqlTargetLanguage(_))
+    }
+
+    pub fn supports_html(&self) -> bool {
+        matches!(self.language, GritTargetLanguage::HtmlTargetLanguage(_))
+    }
+
     pub fn supports_js(&self) -> bool {
         matches!(self.language, GritTargetLanguage::JsTargetLanguage(_))
     }
+
+    pub fn supports_json(&self) -> bool {
+        matches!(self.language, GritTargetLanguage::JsonTargetLanguage(_))
+    }
 }
 
 #[derive(Debug)]
@@ -302,6 +322,8 @@ impl Match {
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Rewrite {
     pub original: Match,
+    /// The content of the file before it was rewritten
+    pub original_content: String,
     pub rewritten: OutputFile,
 }
 
@@ -312,9 +334,10 @@ impl From<Rewrite> for GritQueryEffect {
 }
 
 impl Rewrite {
-    fn new(original: Match, rewritten: OutputFile) -> Self {
+    fn new(original: Match, original_content: String, rewritten: OutputFile) -> Self {
         Self {
             original,
+            original_content,
             rewritten,
         }
     }
@@ -329,7 +352,11 @@ impl Rewrite {
             return Err(GritPatternError::new("cannot have rewrite without matches"));
         };
         let rewritten = OutputFile::from_file(rewritten_file);
-        Ok(Self::new(original, rewritten))
+        Ok(Self::new(
+            original,
+            initial.tree.source().into_owned(),
+            rewritten,
+        ))
     }
 }
 
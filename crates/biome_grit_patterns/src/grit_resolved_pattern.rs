@@ -527,14 +527,41 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<CodeRange, Option<String>>,
         _should_pad_snippet: bool,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Binding(bindings) => Ok(bindings
+                .last()
+                .ok_or_else(|| {
+                    GritPatternError::new("cannot grab text of resolved_pattern with no binding")
+                })?
+                .linearized_text(language, effects, files, memo, None, logs)?
+                .into_owned()
+                .into()),
+            Self::Snippets(snippets) => Ok(snippets
+                .iter()
+                .try_fold(String::new(), |mut text, snippet| {
+                    text.push_str(
+                        &snippet.linearized_text(language, effects, files, memo, None, logs)?,
+                    );
+                    Ok::<String, GritPatternError>(text)
+                })?
+                .into()),
+            Self::List(list) => Ok(list
+                .iter()
+                .map(|pattern| pattern.linearized_text(language, effects, files, memo, false, logs))
+                .collect::<GritResult<Vec<_>>>()?
+                .join(",")
+                .into()),
+            Self::Map(_) | Self::File(_) | Self::Files(_) | Self::Constant(_) => {
+                self.text(files, language)
+            }
+        }
     }
 
     fn matches_undefined(&self) -> bool {
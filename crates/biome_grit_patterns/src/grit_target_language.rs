@@ -1,8 +1,14 @@
 mod css_target_language;
+mod graphql_target_language;
+mod html_target_language;
 mod js_target_language;
+mod json_target_language;
 
 pub use css_target_language::CssTargetLanguage;
+pub use graphql_target_language::GraphqlTargetLanguage;
+pub use html_target_language::HtmlTargetLanguage;
 pub use js_target_language::JsTargetLanguage;
+pub use json_target_language::JsonTargetLanguage;
 
 use camino::Utf8Path;
 use grit_util::{AnalysisLogs, Ast, CodeRange, EffectRange, Language, Parser, SnippetTree};
@@ -17,7 +23,10 @@ use biome_string_case::StrOnlyExtension;
 
 use crate::CompileError;
 use crate::grit_css_parser::GritCssParser;
+use crate::grit_graphql_parser::GritGraphqlParser;
+use crate::grit_html_parser::GritHtmlParser;
 use crate::grit_js_parser::GritJsParser;
+use crate::grit_json_parser::GritJsonParser;
 use crate::grit_target_node::{GritTargetNode, GritTargetSyntaxKind};
 use crate::grit_tree::GritTargetTree;
 
@@ -207,7 +216,10 @@ macro_rules! generate_target_language {
 
 generate_target_language! {
     [CssTargetLanguage, GritCssParser, "CSS"],
-    [JsTargetLanguage, GritJsParser, "JavaScript"]
+    [GraphqlTargetLanguage, GritGraphqlParser, "GraphQL"],
+    [HtmlTargetLanguage, GritHtmlParser, "HTML"],
+    [JsTargetLanguage, GritJsParser, "JavaScript"],
+    [JsonTargetLanguage, GritJsonParser, "JSON"]
 }
 
 impl Default for GritTargetLanguage {
@@ -229,7 +241,10 @@ impl GritTargetLanguage {
             .kind()
         {
             GritSyntaxKind::CSS_KW => Some(Self::CssTargetLanguage(CssTargetLanguage)),
+            GritSyntaxKind::GRAPHQL_KW => Some(Self::GraphqlTargetLanguage(GraphqlTargetLanguage)),
+            GritSyntaxKind::HTML_KW => Some(Self::HtmlTargetLanguage(HtmlTargetLanguage)),
             GritSyntaxKind::JS_KW => Some(Self::JsTargetLanguage(JsTargetLanguage)),
+            GritSyntaxKind::JSON_KW => Some(Self::JsonTargetLanguage(JsonTargetLanguage)),
             _ => None,
         }
     }
@@ -238,9 +253,12 @@ impl GritTargetLanguage {
     pub fn from_extension(extension: &str) -> Option<Self> {
         match extension {
             "css" => Some(Self::CssTargetLanguage(CssTargetLanguage)),
+            "graphql" | "gql" => Some(Self::GraphqlTargetLanguage(GraphqlTargetLanguage)),
+            "html" | "htm" => Some(Self::HtmlTargetLanguage(HtmlTargetLanguage)),
             "cjs" | "js" | "jsx" | "mjs" | "ts" | "tsx" => {
                 Some(Self::JsTargetLanguage(JsTargetLanguage))
             }
+            "json" | "jsonc" => Some(Self::JsonTargetLanguage(JsonTargetLanguage)),
             _ => None,
         }
     }
@@ -3,5 +3,8 @@
 use crate::grit_target_language::DisregardedSlotCondition::{self, *};
 use biome_css_syntax::CssSyntaxKind::{self, *};
 
-pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(CssSyntaxKind, u32, DisregardedSlotCondition)] =
-    &[(CSS_DECLARATION_WITH_SEMICOLON, 1, Always)];
+pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(CssSyntaxKind, u32, DisregardedSlotCondition)] = &[
+    (CSS_DECLARATION_WITH_SEMICOLON, 1, Always),
+    (SCSS_DECLARATION, 4, Always),
+    (SCSS_INCLUDE_AT_RULE, 4, Always),
+];
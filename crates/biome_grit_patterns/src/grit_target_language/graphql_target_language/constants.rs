@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::grit_target_language::DisregardedSlotCondition::{self, *};
+use biome_graphql_syntax::GraphqlSyntaxKind::{self, *};
+
+pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(
+    GraphqlSyntaxKind,
+    u32,
+    DisregardedSlotCondition,
+)] = &[
+    (GRAPHQL_FIELD, 0, OnlyIf(&[""])),
+    (GRAPHQL_FIELD, 2, OnlyIf(&[""])),
+    (GRAPHQL_FIELD, 3, OnlyIf(&[""])),
+    (GRAPHQL_FIELD, 4, OnlyIf(&[""])),
+];
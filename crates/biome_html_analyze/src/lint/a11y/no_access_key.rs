@@ -0,0 +1,96 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::HtmlAttribute;
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Enforce that the `accesskey` attribute is not used on any HTML element.
+    ///
+    /// The `accesskey` assigns a keyboard shortcut to the current element. However, the `accesskey` value
+    /// can conflict with keyboard commands used by screen readers and keyboard-only users, which leads to
+    /// inconsistent keyboard actions across applications. To avoid accessibility complications,
+    /// this rule suggests users remove the `accesskey` attribute on elements.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <input type="submit" accesskey="s" value="Submit">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <a href="https://webaim.org/" accesskey="w">WebAIM.org</a>
+    /// ```
+    ///
+    /// ## Resources
+    ///
+    /// - [WebAIM: Keyboard Accessibility - Accesskey](https://webaim.org/techniques/keyboard/accesskey#spec)
+    /// - [MDN `accesskey` documentation](https://developer.mozilla.org/docs/Web/HTML/Global_attributes/accesskey)
+    ///
+    pub NoAccessKey {
+        version: "next",
+        name: "noAccessKey",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("no-access-key").same()],
+        recommended: true,
+        severity: Severity::Error,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoAccessKey {
+    type Query = Ast<HtmlAttribute>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !node.has_name("accesskey") {
+            return None;
+        }
+
+        // An empty value doesn't assign any keyboard shortcut
+        if node.value().is_none_or(|value| value.trim().is_empty()) {
+            return None;
+        }
+
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Avoid the "<Emphasis>"accesskey"</Emphasis>" attribute to reduce inconsistencies between \
+                    keyboard shortcuts and screen reader keyboard comments."
+                },
+            ).note(
+                markup! {
+                    "Assigning keyboard shortcuts using the "<Emphasis>"accesskey"</Emphasis>" attribute leads to \
+                    inconsistent keyboard actions across applications."
+                },
+            )
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<HtmlRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        mutation.remove_node(node.clone());
+        Some(HtmlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the "<Emphasis>"accesskey"</Emphasis>" attribute." }.to_owned(),
+            mutation,
+        ))
+    }
+}
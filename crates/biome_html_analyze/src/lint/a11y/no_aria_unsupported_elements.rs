@@ -0,0 +1,145 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_aria_metadata::AriaAttribute;
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::HtmlAttribute;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_rowan::{AstNode, BatchMutationExt};
+use biome_string_case::StrLikeExtension;
+use std::str::FromStr;
+
+declare_lint_rule! {
+    /// Enforce that elements that do not support ARIA roles, states, and properties do not have those attributes.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <meta charset="UTF-8" role="meta">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <html aria-required="true"></html>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <meta charset="UTF-8">
+    /// ```
+    ///
+    /// ```html
+    /// <html></html>
+    /// ```
+    ///
+    pub NoAriaUnsupportedElements {
+        version: "next",
+        name: "noAriaUnsupportedElements",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("aria-unsupported-elements").same()],
+        recommended: true,
+        severity: Severity::Error,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+const ARIA_UNSUPPORTED_ELEMENTS: [&str; 4] = ["meta", "html", "script", "style"];
+
+#[derive(Debug)]
+enum AttributeKind {
+    Role,
+    Aria,
+}
+
+impl AttributeKind {
+    /// Converts an [AttributeKind] to a string.
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Role => "role",
+            Self::Aria => "aria-*",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RuleState {
+    attribute: HtmlAttribute,
+    attribute_kind: AttributeKind,
+}
+
+impl Rule for NoAriaUnsupportedElements {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        if !ARIA_UNSUPPORTED_ELEMENTS
+            .iter()
+            .any(|element_name| node.has_name(element_name))
+        {
+            return None;
+        }
+
+        // Check if the unsupported element has `role` or `aria-*` attribute
+        node.attributes().into_iter().find_map(|attribute| {
+            let attribute = attribute.as_html_attribute()?;
+            let attribute_name = attribute.name().ok()?.value_token().ok()?;
+            let attribute_name = attribute_name.text_trimmed().to_ascii_lowercase_cow();
+
+            let attribute_kind = if attribute_name == "role" {
+                AttributeKind::Role
+            } else if attribute_name.starts_with("aria-")
+                && AriaAttribute::from_str(&attribute_name).is_ok()
+            {
+                AttributeKind::Aria
+            } else {
+                return None;
+            };
+
+            Some(RuleState {
+                attribute: attribute.clone(),
+                attribute_kind,
+            })
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let attribute_kind = state.attribute_kind.as_str();
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Avoid the "<Emphasis>"role"</Emphasis>" attribute and "<Emphasis>"aria-*"</Emphasis>" attributes when using "<Emphasis>"meta"</Emphasis>", "<Emphasis>"html"</Emphasis>", "<Emphasis>"script"</Emphasis>", and "<Emphasis>"style"</Emphasis>" elements."
+                },
+            )
+            .note(markup! {
+                "Using "{attribute_kind}" on elements that do not support them can cause issues with screen readers."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<HtmlRuleAction> {
+        let mut mutation = ctx.root().begin();
+
+        let removed_attribute = state.attribute.syntax().text_trimmed().to_string();
+        mutation.remove_node(state.attribute.clone());
+
+        Some(HtmlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the "<Emphasis>""{removed_attribute}""</Emphasis>" attribute." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
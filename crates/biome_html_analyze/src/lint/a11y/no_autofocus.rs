@@ -0,0 +1,120 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_html_syntax::{HtmlAttribute, HtmlElement};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Enforce that the `autofocus` attribute is not used on elements.
+    ///
+    /// Autofocusing elements can cause usability issues for sighted and non-sighted users, alike.
+    /// But the autofocus attribute should be added to the element the user is expected to
+    /// interact with immediately upon opening a modal dialog or popover.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <input autofocus>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input autofocus="false">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <input>
+    /// ```
+    ///
+    /// ```html
+    /// <!-- `autofocus` in an element that has the `popover` attribute is valid -->
+    /// <div popover><input autofocus></div>
+    /// ```
+    ///
+    /// ```html
+    /// <!-- `autofocus` in `dialog` is valid -->
+    /// <dialog><input autofocus></dialog>
+    /// ```
+    ///
+    /// ## Resources
+    ///
+    /// - [WHATWG HTML Standard, The autofocus attribute](https://html.spec.whatwg.org/multipage/interaction.html#attr-fe-autofocus)
+    /// - [The accessibility of HTML 5 autofocus](https://brucelawson.co.uk/2009/the-accessibility-of-html-5-autofocus/)
+    /// - [MDN Web Docs, HTMLElement: autofocus property](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/autofocus)
+    ///
+    pub NoAutofocus {
+        version: "next",
+        name: "noAutofocus",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("no-autofocus").same()],
+        recommended: true,
+        severity: Severity::Error,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoAutofocus {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = HtmlAttribute;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let autofocus_attribute = node.find_attribute_by_name("autofocus")?;
+        if is_inside_dialog_or_popover(node) {
+            return None;
+        }
+        Some(autofocus_attribute)
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, attr: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            attr.range(),
+            markup! {
+                "Avoid the "<Emphasis>"autofocus"</Emphasis>" attribute."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, attr: &Self::State) -> Option<HtmlRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.remove_node(attr.clone());
+        Some(HtmlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the "<Emphasis>"autofocus"</Emphasis>" attribute." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Whether `element` is a descendant of a `dialog` element, or of an element
+/// with the `popover` attribute.
+fn is_inside_dialog_or_popover(element: &AnyHtmlTagElement) -> bool {
+    // The opening element belongs to its own `HtmlElement`, which must be skipped
+    let parent = match element {
+        AnyHtmlTagElement::HtmlOpeningElement(element) => element
+            .parent::<HtmlElement>()
+            .and_then(|element| element.syntax().parent()),
+        AnyHtmlTagElement::HtmlSelfClosingElement(element) => element.syntax().parent(),
+    };
+    let Some(parent) = parent else {
+        return false;
+    };
+
+    parent
+        .ancestors()
+        .filter_map(HtmlElement::cast)
+        .filter_map(|element| element.opening_element().ok())
+        .map(AnyHtmlTagElement::from)
+        .any(|element| element.has_name("dialog") || element.has_attribute("popover"))
+}
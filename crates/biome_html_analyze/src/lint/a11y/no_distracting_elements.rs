@@ -0,0 +1,99 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_html_syntax::{HtmlElement, HtmlSyntaxToken};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Enforces that no distracting elements are used.
+    ///
+    /// Elements that can be visually distracting can cause accessibility issues with visually impaired users.
+    /// Such elements are most likely deprecated, and should be avoided.
+    /// By default, the following elements are visually distracting: `<marquee>` and `<blink>`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <marquee>Breaking news</marquee>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <blink>Sale</blink>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <div>Sale</div>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 2.2.2](https://www.w3.org/WAI/WCAG21/Understanding/pause-stop-hide)
+    ///
+    pub NoDistractingElements {
+        version: "next",
+        name: "noDistractingElements",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("no-distracting-elements").same()],
+        recommended: true,
+        severity: Severity::Error,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoDistractingElements {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = HtmlSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        if element.has_name("marquee") || element.has_name("blink") {
+            return element.name_value_token().ok();
+        }
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let element = ctx.query();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            element.range(),
+            markup! {"Don't use the '"{name.text_trimmed()}"' element."}.to_owned(),
+        )
+        .note(markup! {
+            "Visually distracting elements can cause accessibility issues and should be avoided."
+        });
+
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, name: &Self::State) -> Option<HtmlRuleAction> {
+        let element = ctx.query();
+        let mut mutation = ctx.root().begin();
+        match element {
+            // Remove the whole element, including its children and its closing tag
+            AnyHtmlTagElement::HtmlOpeningElement(element) => {
+                mutation.remove_node(element.parent::<HtmlElement>()?);
+            }
+            AnyHtmlTagElement::HtmlSelfClosingElement(element) => {
+                mutation.remove_node(element.clone());
+            }
+        }
+
+        Some(HtmlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the '"{name.text_trimmed()}"' element." }.to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,138 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_aria::ValidAriaRoleOptions;
+use biome_aria_metadata::AriaRole;
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Elements with ARIA roles must use a valid, non-abstract ARIA role.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <div role="datepicker"></div>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div role="range"></div>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div role=""></div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <div role="button"></div>
+    /// ```
+    ///
+    /// ```html
+    /// <div role="navigation presentation"></div>
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Custom elements, e.g. `<my-element>`, are ignored when `ignoreNonDom` is enabled.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "allowInvalidRoles": ["invalid-role", "text"],
+    ///         "ignoreNonDom": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    /// ## Resources
+    ///
+    /// - [DPUB-ARIA roles](https://www.w3.org/TR/dpub-aria-1.0/)
+    /// - [MDN: Using ARIA: Roles, states, and properties](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques)
+    ///
+    pub UseValidAriaRole {
+        version: "next",
+        name: "useValidAriaRole",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("aria-role").same()],
+        recommended: true,
+        severity: Severity::Error,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseValidAriaRole {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = Box<ValidAriaRoleOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+
+        if options.ignore_non_dom && node.is_custom_element() {
+            return None;
+        }
+
+        let role_attribute_value = node.find_attribute_by_name("role")?.value()?;
+        let role_attribute_value = role_attribute_value.trim();
+        if role_attribute_value.is_empty() {
+            return Some(());
+        }
+
+        let is_valid = role_attribute_value.split_ascii_whitespace().all(|val| {
+            AriaRole::from_roles(val).is_some()
+                || options
+                    .allow_invalid_roles
+                    .iter()
+                    .any(|role| role.as_ref() == val)
+        });
+
+        if is_valid {
+            return None;
+        }
+
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Enforce that elements with ARIA roles must use a valid, non-abstract ARIA role."
+                },
+            )
+            .note(markup! {
+                "Check "<Hyperlink href="https://www.w3.org/TR/wai-aria/#namefromauthor">"WAI-ARIA"</Hyperlink>" for valid roles or provide options accordingly."
+            })
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<HtmlRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let role_attribute = node.find_attribute_by_name("role")?;
+        mutation.remove_node(role_attribute);
+        Some(HtmlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the invalid "<Emphasis>"role"</Emphasis>" attribute.\n Check the list of all "<Hyperlink href="https://www.w3.org/TR/wai-aria/#role_definitions">"valid"</Hyperlink>" role attributes." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
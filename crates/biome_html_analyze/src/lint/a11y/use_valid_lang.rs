@@ -0,0 +1,120 @@
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_aria_metadata::{is_valid_country, is_valid_language};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Ensure that the attribute passed to the `lang` attribute is a correct ISO language and/or country.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <html lang="lorem"></html>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <html lang="en-babab"></html>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <html lang="en-GB-typo"></html>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <html lang="en-GB"></html>
+    /// ```
+    pub UseValidLang {
+        version: "next",
+        name: "useValidLang",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("lang").same()],
+        recommended: true,
+        severity: Severity::Error,
+    }
+}
+
+enum InvalidKind {
+    Language,
+    Country,
+    Value,
+}
+
+pub struct UseValidLangState {
+    invalid_kind: InvalidKind,
+    attribute_range: TextRange,
+}
+
+impl Rule for UseValidLang {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = UseValidLangState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !node.has_name("html") {
+            return None;
+        }
+
+        let attribute_value = node
+            .find_attribute_by_name("lang")?
+            .initializer()?
+            .value()
+            .ok()?;
+        let attribute_text = attribute_value.as_html_string()?.inner_string_text().ok()?;
+        let mut split_value = attribute_text.split('-');
+        let invalid_kind = match (split_value.next(), split_value.next()) {
+            (Some(language), _) if !is_valid_language(language) => InvalidKind::Language,
+            (Some(_), Some(country)) if !is_valid_country(country) => InvalidKind::Country,
+            (Some(_), Some(_)) if split_value.next().is_some() => InvalidKind::Value,
+            _ => return None,
+        };
+
+        Some(UseValidLangState {
+            invalid_kind,
+            attribute_range: attribute_value.range(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.attribute_range,
+            markup! {
+                "Provide a valid value for the "<Emphasis>"lang"</Emphasis>" attribute."
+            },
+        );
+        diagnostic = match state.invalid_kind {
+            InvalidKind::Language => {
+                let languages = biome_aria_metadata::languages();
+                let languages = if languages.len() > 15 {
+                    &languages[..15]
+                } else {
+                    languages
+                };
+
+                diagnostic.footer_list("Some of valid languages:", languages)
+            }
+            InvalidKind::Country => {
+                let countries = biome_aria_metadata::countries();
+                let countries = if countries.len() > 15 {
+                    &countries[..15]
+                } else {
+                    countries
+                };
+
+                diagnostic.footer_list("Some of valid countries:", countries)
+            }
+            InvalidKind::Value => diagnostic,
+        };
+        Some(diagnostic)
+    }
+}
@@ -7,6 +7,7 @@ use biome_formatter::{
 };
 use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 
+use crate::EmbeddedLanguageFormatter;
 use crate::comments::{FormatHtmlComment, HtmlCommentStyle, HtmlComments};
 
 #[derive(Debug, Clone, Default)]
@@ -39,16 +40,21 @@ pub struct HtmlFormatOptions {
     /// - <https://prettier.io/blog/2018/11/07/1.15.0#whitespace-sensitive-formatting>
     whitespace_sensitivity: WhitespaceSensitivity,
 
-    /// Whether to indent the content of `<script>` and `<style>` tags. Default is `false`.
+    /// Whether to indent the content of `<script>` and `<style>` tags in templating languages.
+    /// The content is always indented in HTML files. Default is `false`.
     indent_script_and_style: IndentScriptAndStyle,
 
     /// Controls whether void elements should be self-closed.
     self_close_void_elements: SelfCloseVoidElements,
+
+    /// The kind of file being formatted.
+    file_source: HtmlFileSource,
 }
 
 impl HtmlFormatOptions {
-    pub fn new(_file_source: HtmlFileSource) -> Self {
+    pub fn new(file_source: HtmlFileSource) -> Self {
         Self {
+            file_source,
             ..Default::default()
         }
     }
@@ -143,6 +149,10 @@ impl HtmlFormatOptions {
         self.self_close_void_elements
     }
 
+    pub fn file_source(&self) -> HtmlFileSource {
+        self.file_source
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -363,6 +373,9 @@ pub struct HtmlFormatContext {
     comments: Rc<HtmlComments>,
 
     source_map: Option<TransformSourceMap>,
+
+    /// Formats the content of the `<script>` and `<style>` elements, if any.
+    embedded_language_formatter: Option<Rc<dyn EmbeddedLanguageFormatter>>,
 }
 
 impl HtmlFormatContext {
@@ -371,6 +384,7 @@ impl HtmlFormatContext {
             options,
             comments: Rc::new(comments),
             source_map: None,
+            embedded_language_formatter: None,
         }
     }
 
@@ -378,6 +392,18 @@ impl HtmlFormatContext {
         self.source_map = source_map;
         self
     }
+
+    pub fn with_embedded_language_formatter(
+        mut self,
+        embedded_language_formatter: Option<Rc<dyn EmbeddedLanguageFormatter>>,
+    ) -> Self {
+        self.embedded_language_formatter = embedded_language_formatter;
+        self
+    }
+
+    pub(crate) fn embedded_language_formatter(&self) -> Option<&dyn EmbeddedLanguageFormatter> {
+        self.embedded_language_formatter.as_deref()
+    }
 }
 
 impl FormatContext for HtmlFormatContext {
@@ -342,6 +342,44 @@ impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlElement {
         )
     }
 }
+impl FormatRule<biome_html_syntax::HtmlMetavariable>
+    for crate::html::auxiliary::metavariable::FormatHtmlMetavariable
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlMetavariable,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlMetavariable>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlMetavariable {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlMetavariable,
+        crate::html::auxiliary::metavariable::FormatHtmlMetavariable,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::metavariable::FormatHtmlMetavariable::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlMetavariable {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlMetavariable,
+        crate::html::auxiliary::metavariable::FormatHtmlMetavariable,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::metavariable::FormatHtmlMetavariable::default(),
+        )
+    }
+}
 impl FormatRule<biome_html_syntax::HtmlOpeningElement>
     for crate::html::auxiliary::opening_element::FormatHtmlOpeningElement
 {
@@ -699,6 +737,31 @@ impl IntoFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlAttribute {
         )
     }
 }
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlAttributeInitializer {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::AnyHtmlAttributeInitializer,
+        crate::html::any::attribute_initializer::FormatAnyHtmlAttributeInitializer,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::html::any::attribute_initializer::FormatAnyHtmlAttributeInitializer::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlAttributeInitializer {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::AnyHtmlAttributeInitializer,
+        crate::html::any::attribute_initializer::FormatAnyHtmlAttributeInitializer,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::any::attribute_initializer::FormatAnyHtmlAttributeInitializer::default(),
+        )
+    }
+}
 impl AsFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlElement {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_html_syntax::AnyHtmlAttributeInitializer;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyHtmlAttributeInitializer;
+impl FormatRule<AnyHtmlAttributeInitializer> for FormatAnyHtmlAttributeInitializer {
+    type Context = HtmlFormatContext;
+    fn fmt(&self, node: &AnyHtmlAttributeInitializer, f: &mut HtmlFormatter) -> FormatResult<()> {
+        match node {
+            AnyHtmlAttributeInitializer::HtmlMetavariable(node) => node.format().fmt(f),
+            AnyHtmlAttributeInitializer::HtmlString(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
 pub(crate) mod attribute;
+pub(crate) mod attribute_initializer;
 pub(crate) mod element;
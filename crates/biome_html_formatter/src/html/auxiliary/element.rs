@@ -1,11 +1,13 @@
 use crate::html::lists::element_list::{FormatHtmlElementListOptions, HtmlChildListLayout};
 use crate::utils::metadata::is_element_whitespace_sensitive;
+use crate::{EmbeddedLanguage, FormattedEmbeddedContent};
 use crate::{
     html::lists::element_list::{FormatChildrenResult, FormatHtmlElementList},
     prelude::*,
 };
 use biome_formatter::{FormatRuleWithOptions, format_args, write};
-use biome_html_syntax::{HtmlElement, HtmlElementFields};
+use biome_html_syntax::{HtmlElement, HtmlElementFields, HtmlElementList};
+use biome_rowan::{Direction, TextSize};
 
 use super::{
     closing_element::{FormatHtmlClosingElement, FormatHtmlClosingElementOptions},
@@ -13,7 +15,8 @@ use super::{
 };
 
 /// `pre` tags are "preformatted", so we should not format the content inside them. <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/pre>
-/// We ignore the `script` and `style` tags as well, since embedded language parsing/formatting is not yet implemented.
+/// The content of the `script` and `style` tags is printed as it is, unless it's formatted by the
+/// [EmbeddedLanguageFormatter](crate::EmbeddedLanguageFormatter) of the context.
 const HTML_VERBATIM_TAGS: &[&str] = &["script", "style", "pre"];
 
 #[derive(Debug, Clone, Default)]
@@ -36,11 +39,19 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
             .map(|t| t.value_token())
             .transpose()?;
 
-        let should_be_verbatim = HTML_VERBATIM_TAGS.iter().any(|tag| {
-            tag_name
-                .as_ref()
-                .is_some_and(|tag_name| tag_name.text().eq_ignore_ascii_case(tag))
-        });
+        let embedded_content = f
+            .context()
+            .embedded_language_formatter()
+            .zip(EmbeddedLanguage::from_opening_element(&opening_element))
+            .and_then(|(formatter, language)| {
+                formatter.format(language, &children.syntax().to_string())
+            });
+        let should_be_verbatim = embedded_content.is_none()
+            && HTML_VERBATIM_TAGS.iter().any(|tag| {
+                tag_name
+                    .as_ref()
+                    .is_some_and(|tag_name| tag_name.text().eq_ignore_ascii_case(tag))
+            });
 
         let content_has_leading_whitespace = children
             .syntax()
@@ -80,10 +91,14 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
         // to borrow, while the child formatters are responsible for actually printing
         // the tokens. `HtmlElementList` prints them if they are borrowed, otherwise
         // they are printed by their original formatter.
-        let should_borrow_opening_r_angle =
-            is_whitespace_sensitive && !children.is_empty() && !content_has_leading_whitespace;
-        let should_borrow_closing_tag =
-            is_whitespace_sensitive && !children.is_empty() && !content_has_trailing_whitespace;
+        let should_borrow_opening_r_angle = is_whitespace_sensitive
+            && embedded_content.is_none()
+            && !children.is_empty()
+            && !content_has_leading_whitespace;
+        let should_borrow_closing_tag = is_whitespace_sensitive
+            && embedded_content.is_none()
+            && !children.is_empty()
+            && !content_has_trailing_whitespace;
 
         let borrowed_r_angle = if should_borrow_opening_r_angle {
             opening_element.r_angle_token().ok()
@@ -105,7 +120,15 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
             &opening_element,
             f,
         )?;
-        if should_be_verbatim {
+        if let Some(content) = embedded_content {
+            write!(
+                f,
+                [FormatEmbeddedContent {
+                    children: &children,
+                    content
+                }]
+            )?;
+        } else if should_be_verbatim {
             write!(f, [&format_verbatim_skipped(children.syntax())])?;
         } else {
             let format_children = FormatHtmlElementList::default()
@@ -152,3 +175,91 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
         Ok(())
     }
 }
+
+/// Prints the formatted content of a `<script>` or `<style>` element on its own lines, indented
+/// in HTML files, and in templating languages when `indentScriptAndStyle` is enabled:
+///
+/// ```html
+/// <script>
+///   console.log("Hello, world!");
+/// </script>
+/// ```
+struct FormatEmbeddedContent<'a> {
+    children: &'a HtmlElementList,
+    content: FormattedEmbeddedContent,
+}
+
+impl FormatEmbeddedContent<'_> {
+    /// Splits the code in the lines that can be indented. A line that starts within a verbatim
+    /// range is kept in the same text as the previous line, so that its indentation is printed
+    /// as it is. The flag is set for the lines that follow an empty line.
+    fn lines(&self) -> Vec<(bool, String)> {
+        let mut lines: Vec<(bool, String)> = Vec::new();
+        let mut has_empty_line = false;
+        let mut start = TextSize::from(0);
+        for line in self.content.code.split('\n') {
+            let is_verbatim = self
+                .content
+                .verbatim_ranges
+                .iter()
+                .any(|range| range.start() < start && start < range.end());
+            start += TextSize::of(line) + TextSize::from(1);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            match lines.last_mut() {
+                Some((_, text)) if is_verbatim => {
+                    text.push('\n');
+                    text.push_str(line);
+                }
+                _ if line.trim().is_empty() => has_empty_line = !lines.is_empty(),
+                _ => {
+                    lines.push((has_empty_line, line.to_string()));
+                    has_empty_line = false;
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+impl Format<HtmlFormatContext> for FormatEmbeddedContent<'_> {
+    fn fmt(&self, f: &mut Formatter<HtmlFormatContext>) -> FormatResult<()> {
+        let children = self.children.syntax();
+        for node in children.descendants() {
+            f.comments().mark_suppression_checked(&node);
+        }
+        for token in children.descendants_tokens(Direction::Next) {
+            write!(f, [format_removed(&token)])?;
+        }
+
+        let lines = self.lines();
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        let position = children.text_trimmed_range().start();
+        let format_lines = format_with(|f| {
+            for (index, (has_empty_line, line)) in lines.iter().enumerate() {
+                if index > 0 {
+                    if *has_empty_line {
+                        write!(f, [empty_line()])?;
+                    } else {
+                        write!(f, [hard_line_break()])?;
+                    }
+                }
+                write!(f, [dynamic_text(line, position)])?;
+            }
+            Ok(())
+        });
+
+        // The content is always indented in HTML files, while templating
+        // languages leave it to the `indentScriptAndStyle` option.
+        let options = f.options();
+        if options.file_source().is_html() || options.indent_script_and_style().value() {
+            write!(f, [block_indent(&format_lines)])
+        } else {
+            write!(f, [hard_line_break(), format_lines, hard_line_break()])
+        }
+    }
+}
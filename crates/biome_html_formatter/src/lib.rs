@@ -3,12 +3,16 @@
 use biome_formatter::comments::Comments;
 use biome_formatter::{CstFormatContext, FormatOwnedWithRule, FormatRefWithRule, prelude::*};
 use biome_formatter::{FormatLanguage, FormatResult, FormatToken, Formatted, write};
-use biome_html_syntax::{HtmlLanguage, HtmlSyntaxNode, HtmlSyntaxToken};
-use biome_rowan::AstNode;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_html_syntax::{HtmlLanguage, HtmlOpeningElement, HtmlSyntaxNode, HtmlSyntaxToken};
+use biome_rowan::{AstNode, TextRange};
+use biome_string_case::StrLikeExtension;
 use comments::HtmlCommentStyle;
 use context::HtmlFormatContext;
 pub use context::HtmlFormatOptions;
 use cst::FormatHtmlSyntaxNode;
+use std::fmt;
+use std::rc::Rc;
 
 mod comments;
 pub mod context;
@@ -28,6 +32,20 @@ pub fn format_node(
     biome_formatter::format_node(root, HtmlFormatLanguage::new(options))
 }
 
+/// Formats a Html file, using `embedded_language_formatter` to format the content of its
+/// `<script>` and `<style>` elements.
+pub fn format_node_with_embedded_languages(
+    options: HtmlFormatOptions,
+    root: &HtmlSyntaxNode,
+    embedded_language_formatter: Rc<dyn EmbeddedLanguageFormatter>,
+) -> FormatResult<Formatted<HtmlFormatContext>> {
+    biome_formatter::format_node(
+        root,
+        HtmlFormatLanguage::new(options)
+            .with_embedded_language_formatter(embedded_language_formatter),
+    )
+}
+
 /// Used to get an object that knows how to format this object.
 pub(crate) trait AsFormat<Context> {
     type Format<'a>: biome_formatter::Format<Context>
@@ -124,14 +142,99 @@ where
     }
 }
 
+/// The language of the content of a `<script>` or `<style>` element
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EmbeddedLanguage {
+    /// A classic script
+    Script,
+    /// A script with `type="module"`
+    Module,
+    /// A data block with a JSON type, e.g. `type="application/ld+json"`
+    Json,
+    /// A stylesheet
+    Style,
+}
+
+impl EmbeddedLanguage {
+    /// Returns the language of the content of the element opened by `element`, based on its
+    /// `type` attribute.
+    ///
+    /// Returns `None` if the element isn't a `<script>` or a `<style>` element, or if the
+    /// language of its `type` isn't supported, e.g. `<script type="text/template">`.
+    pub fn from_opening_element(element: &HtmlOpeningElement) -> Option<Self> {
+        let element = AnyHtmlTagElement::from(element.clone());
+        let type_attribute = element
+            .find_attribute_by_name("type")
+            .and_then(|attribute| attribute.value());
+        let content_type = type_attribute
+            .as_ref()
+            .map_or("", |value| value.text().trim());
+
+        if element.has_name("script") {
+            // https://html.spec.whatwg.org/multipage/scripting.html#attr-script-type
+            match content_type.to_ascii_lowercase_cow().as_ref() {
+                ""
+                | "text/javascript"
+                | "application/javascript"
+                | "text/ecmascript"
+                | "application/ecmascript" => Some(Self::Script),
+                "module" => Some(Self::Module),
+                "importmap" | "speculationrules" | "application/json" | "application/ld+json" => {
+                    Some(Self::Json)
+                }
+                _ => None,
+            }
+        } else if element.has_name("style") {
+            (content_type.is_empty() || content_type.eq_ignore_ascii_case("text/css"))
+                .then_some(Self::Style)
+        } else {
+            None
+        }
+    }
+}
+
+/// Formats the content of the `<script>` and `<style>` elements.
+///
+/// The Html formatter doesn't know how to format other languages. The content of these
+/// elements is printed as it is unless a formatter is provided with
+/// [HtmlFormatLanguage::with_embedded_language_formatter].
+pub trait EmbeddedLanguageFormatter: fmt::Debug {
+    /// Returns the formatted `code` written in `language`, or `None` if the code can't be
+    /// formatted.
+    fn format(&self, language: EmbeddedLanguage, code: &str) -> Option<FormattedEmbeddedContent>;
+}
+
+/// The content of a `<script>` or `<style>` element, formatted by an
+/// [EmbeddedLanguageFormatter].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FormattedEmbeddedContent {
+    pub code: String,
+
+    /// The ranges of `code` whose text must be printed as it is, such as multi-line template
+    /// literals or comments. The lines that start within these ranges aren't indented.
+    pub verbatim_ranges: Vec<TextRange>,
+}
+
 #[derive(Debug, Clone)]
 pub struct HtmlFormatLanguage {
     options: HtmlFormatOptions,
+    embedded_language_formatter: Option<Rc<dyn EmbeddedLanguageFormatter>>,
 }
 
 impl HtmlFormatLanguage {
     pub fn new(options: HtmlFormatOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            embedded_language_formatter: None,
+        }
+    }
+
+    pub fn with_embedded_language_formatter(
+        mut self,
+        embedded_language_formatter: Rc<dyn EmbeddedLanguageFormatter>,
+    ) -> Self {
+        self.embedded_language_formatter = Some(embedded_language_formatter);
+        self
     }
 }
 
@@ -150,7 +253,9 @@ impl FormatLanguage for HtmlFormatLanguage {
         source_map: Option<biome_formatter::TransformSourceMap>,
     ) -> Self::Context {
         let comments = Comments::from_node(root, &HtmlCommentStyle, source_map.as_ref());
-        HtmlFormatContext::new(self.options, comments).with_source_map(source_map)
+        HtmlFormatContext::new(self.options, comments)
+            .with_source_map(source_map)
+            .with_embedded_language_formatter(self.embedded_language_formatter)
     }
 }
 
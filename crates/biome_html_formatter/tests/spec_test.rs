@@ -1,15 +1,73 @@
 use biome_configuration::{Configuration, HtmlConfiguration, html::HtmlFormatterConfiguration};
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_parser::{CssParserOptions, parse_css};
+use biome_css_syntax::CssFileSource;
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_fs::BiomePath;
-use biome_html_formatter::{HtmlFormatLanguage, context::HtmlFormatOptions};
+use biome_html_formatter::{
+    EmbeddedLanguage, EmbeddedLanguageFormatter, FormattedEmbeddedContent, HtmlFormatLanguage,
+    context::HtmlFormatOptions,
+};
 use biome_html_syntax::HtmlFileSource;
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::JsFileSource;
 use biome_service::workspace::UpdateSettingsParams;
 use camino::Utf8Path;
+use std::rc::Rc;
 
 mod language {
     include!("language.rs");
 }
 
+/// Formats the content of the `<script>` and `<style>` elements with the default options of
+/// the JavaScript and CSS formatters.
+#[derive(Debug)]
+struct HtmlTestEmbeddedLanguageFormatter;
+
+impl EmbeddedLanguageFormatter for HtmlTestEmbeddedLanguageFormatter {
+    fn format(&self, language: EmbeddedLanguage, code: &str) -> Option<FormattedEmbeddedContent> {
+        let code = match language {
+            EmbeddedLanguage::Script | EmbeddedLanguage::Module => {
+                let file_source = if language == EmbeddedLanguage::Module {
+                    JsFileSource::js_module()
+                } else {
+                    JsFileSource::js_script()
+                };
+                let parse = biome_js_parser::parse(code, file_source, JsParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                biome_js_formatter::format_node(JsFormatOptions::new(file_source), &parse.syntax())
+                    .ok()?
+                    .print()
+                    .ok()?
+                    .into_code()
+            }
+            EmbeddedLanguage::Json => return None,
+            EmbeddedLanguage::Style => {
+                let parse = parse_css(code, CssParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                biome_css_formatter::format_node(
+                    CssFormatOptions::new(CssFileSource::css()),
+                    &parse.syntax(),
+                )
+                .ok()?
+                .print()
+                .ok()?
+                .into_code()
+            }
+        };
+
+        Some(FormattedEmbeddedContent {
+            code,
+            verbatim_ranges: Vec::new(),
+        })
+    }
+}
+
 /// [insta.rs](https://insta.rs/docs) snapshot testing
 ///
 /// For better development workflow, run
@@ -58,7 +116,8 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         test_file,
         test_directory,
         language,
-        HtmlFormatLanguage::new(options),
+        HtmlFormatLanguage::new(options)
+            .with_embedded_language_formatter(Rc::new(HtmlTestEmbeddedLanguageFormatter)),
     );
 
     snapshot.test()
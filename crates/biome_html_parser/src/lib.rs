@@ -5,6 +5,7 @@ mod parser;
 mod syntax;
 mod token_source;
 
+pub use crate::parser::HtmlParserOptions;
 use crate::parser::{HtmlLosslessTreeSink, HtmlParser};
 use crate::syntax::parse_root;
 use biome_html_syntax::{HtmlRoot, HtmlSyntaxNode};
@@ -14,7 +15,21 @@ use biome_rowan::{AstNode, NodeCache};
 
 /// Parses the provided string as HTML program using the provided node cache.
 pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
-    let mut parser = HtmlParser::new(source);
+    parse_html_with_cache_and_options(source, cache, HtmlParserOptions::default())
+}
+
+/// Parses the provided string as HTML program using the provided options.
+pub fn parse_html_with_options(source: &str, options: HtmlParserOptions) -> HtmlParse {
+    let mut cache = NodeCache::default();
+    parse_html_with_cache_and_options(source, &mut cache, options)
+}
+
+fn parse_html_with_cache_and_options(
+    source: &str,
+    cache: &mut NodeCache,
+    options: HtmlParserOptions,
+) -> HtmlParse {
+    let mut parser = HtmlParser::new(source, options);
 
     parse_root(&mut parser);
 
@@ -15,11 +15,31 @@ pub(crate) struct HtmlParser<'source> {
     source: HtmlTokenSource<'source>,
 }
 
+#[derive(Default, Debug, Clone, Copy)]
+pub struct HtmlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    /// Defaults to `false`.
+    pub grit_metavariables: bool,
+}
+
+impl HtmlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    pub fn allow_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
+
+    /// Checks if parsing of Grit metavariables is enabled.
+    pub fn is_metavariable_enabled(&self) -> bool {
+        self.grit_metavariables
+    }
+}
+
 impl<'source> HtmlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: HtmlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: HtmlTokenSource::from_str(source),
+            source: HtmlTokenSource::from_str(source, options),
         }
     }
 
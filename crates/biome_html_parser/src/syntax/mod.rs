@@ -168,6 +168,11 @@ impl ParseNodeList for ElementList {
             T![<!--] => parse_comment(p),
             T!["<![CDATA["] => parse_cdata_section(p),
             T![<] => parse_element(p),
+            GRIT_METAVARIABLE => {
+                let m = p.start();
+                p.bump_with_context(GRIT_METAVARIABLE, HtmlLexContext::OutsideTag);
+                Present(m.complete(p, HTML_METAVARIABLE))
+            }
             HTML_LITERAL => {
                 let m = p.start();
                 p.bump_with_context(HTML_LITERAL, HtmlLexContext::OutsideTag);
@@ -228,6 +233,12 @@ impl ParseNodeList for AttributeList {
 }
 
 fn parse_attribute(p: &mut HtmlParser) -> ParsedSyntax {
+    if p.at(GRIT_METAVARIABLE) {
+        let m = p.start();
+        p.bump(GRIT_METAVARIABLE);
+        return Present(m.complete(p, HTML_METAVARIABLE));
+    }
+
     if !p.at(HTML_LITERAL) {
         return Absent;
     }
@@ -263,13 +274,23 @@ fn parse_attribute_string_literal(p: &mut HtmlParser) -> ParsedSyntax {
     Present(m.complete(p, HTML_STRING))
 }
 
+fn parse_attribute_value(p: &mut HtmlParser) -> ParsedSyntax {
+    if p.at(GRIT_METAVARIABLE) {
+        let m = p.start();
+        p.bump(GRIT_METAVARIABLE);
+        return Present(m.complete(p, HTML_METAVARIABLE));
+    }
+
+    parse_attribute_string_literal(p)
+}
+
 fn parse_attribute_initializer(p: &mut HtmlParser) -> ParsedSyntax {
     if !p.at(T![=]) {
         return Absent;
     }
     let m = p.start();
     p.bump_with_context(T![=], HtmlLexContext::AttributeValue);
-    parse_attribute_string_literal(p).or_add_diagnostic(p, expected_initializer);
+    parse_attribute_value(p).or_add_diagnostic(p, expected_initializer);
     Present(m.complete(p, HTML_ATTRIBUTE_INITIALIZER_CLAUSE))
 }
 
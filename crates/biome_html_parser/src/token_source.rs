@@ -1,4 +1,5 @@
 use crate::lexer::HtmlLexer;
+use crate::parser::HtmlParserOptions;
 use biome_html_syntax::HtmlSyntaxKind::EOF;
 use biome_html_syntax::{HtmlSyntaxKind, TextRange};
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -64,8 +65,8 @@ impl LexContext for HtmlLexContext {
 
 impl<'source> HtmlTokenSource<'source> {
     /// Creates a new token source for the given string
-    pub fn from_str(source: &'source str) -> Self {
-        let lexer = HtmlLexer::from_str(source);
+    pub fn from_str(source: &'source str, options: HtmlParserOptions) -> Self {
+        let lexer = HtmlLexer::from_str(source).with_options(options);
 
         let buffered = BufferedLexer::new(lexer);
         let mut source = Self::new(buffered);
@@ -1,5 +1,9 @@
-use crate::HtmlSelfClosingElement;
-use biome_rowan::SyntaxResult;
+use crate::{
+    AnyHtmlAttribute, HtmlAttribute, HtmlAttributeList, HtmlOpeningElement, HtmlSelfClosingElement,
+    HtmlString, HtmlSyntaxToken, inner_string_text,
+};
+use biome_rowan::{AstNodeList, SyntaxResult, TokenText, declare_node_union};
+use biome_string_case::StrLikeExtension;
 
 /// https://html.spec.whatwg.org/#void-elements
 const VOID_ELEMENTS: &[&str] = &[
@@ -18,3 +22,114 @@ impl HtmlSelfClosingElement {
             .is_ok())
     }
 }
+
+declare_node_union! {
+    /// An element that can carry attributes: `<div>` or `<img />`
+    pub AnyHtmlTagElement = HtmlOpeningElement | HtmlSelfClosingElement
+}
+
+impl AnyHtmlTagElement {
+    pub fn name_value_token(&self) -> SyntaxResult<HtmlSyntaxToken> {
+        match self {
+            Self::HtmlOpeningElement(element) => element.name()?.value_token(),
+            Self::HtmlSelfClosingElement(element) => element.name()?.value_token(),
+        }
+    }
+
+    pub fn attributes(&self) -> HtmlAttributeList {
+        match self {
+            Self::HtmlOpeningElement(element) => element.attributes(),
+            Self::HtmlSelfClosingElement(element) => element.attributes(),
+        }
+    }
+
+    /// Whether the name of the element is `name`.
+    ///
+    /// HTML element names are ASCII case-insensitive.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name_value_token()
+            .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case(name))
+    }
+
+    /// Whether the element is a [custom element](https://html.spec.whatwg.org/#valid-custom-element-name).
+    pub fn is_custom_element(&self) -> bool {
+        self.name_value_token()
+            .is_ok_and(|token| token.text_trimmed().contains('-'))
+    }
+
+    /// Returns the first attribute named `name_to_lookup`.
+    ///
+    /// HTML attribute names are ASCII case-insensitive.
+    pub fn find_attribute_by_name(&self, name_to_lookup: &str) -> Option<HtmlAttribute> {
+        self.attributes().find_by_name(name_to_lookup)
+    }
+
+    pub fn has_attribute(&self, name_to_lookup: &str) -> bool {
+        self.find_attribute_by_name(name_to_lookup).is_some()
+    }
+}
+
+impl HtmlAttributeList {
+    /// Returns the first attribute named `name_to_lookup`.
+    ///
+    /// HTML attribute names are ASCII case-insensitive.
+    pub fn find_by_name(&self, name_to_lookup: &str) -> Option<HtmlAttribute> {
+        self.iter().find_map(|attribute| match attribute {
+            AnyHtmlAttribute::HtmlAttribute(attribute) if attribute.has_name(name_to_lookup) => {
+                Some(attribute)
+            }
+            _ => None,
+        })
+    }
+}
+
+impl HtmlAttribute {
+    /// Whether the name of the attribute is `name`, ignoring the ASCII case.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the unquoted value of the attribute, or `None` if the attribute
+    /// doesn't have any value, e.g. `<input disabled>`.
+    pub fn value(&self) -> Option<TokenText> {
+        self.initializer()?
+            .value()
+            .ok()?
+            .as_html_string()?
+            .inner_string_text()
+            .ok()
+    }
+}
+
+impl HtmlString {
+    /// Returns the text of the string, without its delimiting quotes if any.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        Ok(inner_string_text(&self.value_token()?))
+    }
+}
+
+impl biome_aria::Element for AnyHtmlTagElement {
+    fn name(&self) -> Option<impl AsRef<str>> {
+        let token = self.name_value_token().ok()?;
+        Some(token.text_trimmed().to_ascii_lowercase_cow().into_owned())
+    }
+
+    fn attributes(&self) -> impl Iterator<Item = impl biome_aria::Attribute> {
+        self.attributes()
+            .into_iter()
+            .filter_map(|attribute| attribute.as_html_attribute().cloned())
+    }
+}
+
+impl biome_aria::Attribute for HtmlAttribute {
+    fn name(&self) -> Option<impl AsRef<str>> {
+        let token = self.name().ok()?.value_token().ok()?;
+        Some(token.text_trimmed().to_ascii_lowercase_cow().into_owned())
+    }
+
+    fn value(&self) -> Option<impl AsRef<str>> {
+        self.value()
+    }
+}
@@ -32,6 +32,12 @@ impl HtmlFileSource {
         }
     }
 
+    /// Returns whether this is a plain HTML file, as opposed to a templating
+    /// language like Astro.
+    pub const fn is_html(&self) -> bool {
+        matches!(self.variant, HtmlVariant::Standard)
+    }
+
     /// Try to return the HTML file source corresponding to this file name from well-known files
     pub fn try_from_well_known(_: &Utf8Path) -> Result<Self, FileSourceError> {
         // TODO: to be implemented
@@ -82,7 +82,7 @@ impl HtmlAttributeInitializerClause {
     pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<HtmlString> {
+    pub fn value(&self) -> SyntaxResult<AnyHtmlAttributeInitializer> {
         support::required_node(&self.syntax, 1usize)
     }
 }
@@ -97,7 +97,7 @@ impl Serialize for HtmlAttributeInitializerClause {
 #[derive(Serialize)]
 pub struct HtmlAttributeInitializerClauseFields {
     pub eq_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<HtmlString>,
+    pub value: SyntaxResult<AnyHtmlAttributeInitializer>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlAttributeName {
@@ -425,6 +425,41 @@ pub struct HtmlElementFields {
     pub closing_element: SyntaxResult<HtmlClosingElement>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HtmlMetavariable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl HtmlMetavariable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> HtmlMetavariableFields {
+        HtmlMetavariableFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for HtmlMetavariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct HtmlMetavariableFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlOpeningElement {
     pub(crate) syntax: SyntaxNode,
 }
@@ -653,6 +688,7 @@ pub struct HtmlTagNameFields {
 pub enum AnyHtmlAttribute {
     HtmlAttribute(HtmlAttribute),
     HtmlBogusAttribute(HtmlBogusAttribute),
+    HtmlMetavariable(HtmlMetavariable),
 }
 impl AnyHtmlAttribute {
     pub fn as_html_attribute(&self) -> Option<&HtmlAttribute> {
@@ -667,6 +703,31 @@ impl AnyHtmlAttribute {
             _ => None,
         }
     }
+    pub fn as_html_metavariable(&self) -> Option<&HtmlMetavariable> {
+        match &self {
+            Self::HtmlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyHtmlAttributeInitializer {
+    HtmlMetavariable(HtmlMetavariable),
+    HtmlString(HtmlString),
+}
+impl AnyHtmlAttributeInitializer {
+    pub fn as_html_metavariable(&self) -> Option<&HtmlMetavariable> {
+        match &self {
+            Self::HtmlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_string(&self) -> Option<&HtmlString> {
+        match &self {
+            Self::HtmlString(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyHtmlElement {
@@ -675,6 +736,7 @@ pub enum AnyHtmlElement {
     HtmlComment(HtmlComment),
     HtmlContent(HtmlContent),
     HtmlElement(HtmlElement),
+    HtmlMetavariable(HtmlMetavariable),
     HtmlSelfClosingElement(HtmlSelfClosingElement),
 }
 impl AnyHtmlElement {
@@ -708,6 +770,12 @@ impl AnyHtmlElement {
             _ => None,
         }
     }
+    pub fn as_html_metavariable(&self) -> Option<&HtmlMetavariable> {
+        match &self {
+            Self::HtmlMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_html_self_closing_element(&self) -> Option<&HtmlSelfClosingElement> {
         match &self {
             Self::HtmlSelfClosingElement(item) => Some(item),
@@ -1219,6 +1287,56 @@ impl From<HtmlElement> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for HtmlMetavariable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_METAVARIABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_METAVARIABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("HtmlMetavariable")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("HtmlMetavariable").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<HtmlMetavariable> for SyntaxNode {
+    fn from(n: HtmlMetavariable) -> Self {
+        n.syntax
+    }
+}
+impl From<HtmlMetavariable> for SyntaxElement {
+    fn from(n: HtmlMetavariable) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for HtmlOpeningElement {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1501,17 +1619,27 @@ impl From<HtmlBogusAttribute> for AnyHtmlAttribute {
         Self::HtmlBogusAttribute(node)
     }
 }
+impl From<HtmlMetavariable> for AnyHtmlAttribute {
+    fn from(node: HtmlMetavariable) -> Self {
+        Self::HtmlMetavariable(node)
+    }
+}
 impl AstNode for AnyHtmlAttribute {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        HtmlAttribute::KIND_SET.union(HtmlBogusAttribute::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = HtmlAttribute::KIND_SET
+        .union(HtmlBogusAttribute::KIND_SET)
+        .union(HtmlMetavariable::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, HTML_ATTRIBUTE | HTML_BOGUS_ATTRIBUTE)
+        matches!(
+            kind,
+            HTML_ATTRIBUTE | HTML_BOGUS_ATTRIBUTE | HTML_METAVARIABLE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             HTML_ATTRIBUTE => Self::HtmlAttribute(HtmlAttribute { syntax }),
             HTML_BOGUS_ATTRIBUTE => Self::HtmlBogusAttribute(HtmlBogusAttribute { syntax }),
+            HTML_METAVARIABLE => Self::HtmlMetavariable(HtmlMetavariable { syntax }),
             _ => return None,
         };
         Some(res)
@@ -1520,12 +1648,14 @@ impl AstNode for AnyHtmlAttribute {
         match self {
             Self::HtmlAttribute(it) => &it.syntax,
             Self::HtmlBogusAttribute(it) => &it.syntax,
+            Self::HtmlMetavariable(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             Self::HtmlAttribute(it) => it.syntax,
             Self::HtmlBogusAttribute(it) => it.syntax,
+            Self::HtmlMetavariable(it) => it.syntax,
         }
     }
 }
@@ -1534,6 +1664,7 @@ impl std::fmt::Debug for AnyHtmlAttribute {
         match self {
             Self::HtmlAttribute(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlBogusAttribute(it) => std::fmt::Debug::fmt(it, f),
+            Self::HtmlMetavariable(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -1542,6 +1673,7 @@ impl From<AnyHtmlAttribute> for SyntaxNode {
         match n {
             AnyHtmlAttribute::HtmlAttribute(it) => it.into(),
             AnyHtmlAttribute::HtmlBogusAttribute(it) => it.into(),
+            AnyHtmlAttribute::HtmlMetavariable(it) => it.into(),
         }
     }
 }
@@ -1551,6 +1683,66 @@ impl From<AnyHtmlAttribute> for SyntaxElement {
         node.into()
     }
 }
+impl From<HtmlMetavariable> for AnyHtmlAttributeInitializer {
+    fn from(node: HtmlMetavariable) -> Self {
+        Self::HtmlMetavariable(node)
+    }
+}
+impl From<HtmlString> for AnyHtmlAttributeInitializer {
+    fn from(node: HtmlString) -> Self {
+        Self::HtmlString(node)
+    }
+}
+impl AstNode for AnyHtmlAttributeInitializer {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        HtmlMetavariable::KIND_SET.union(HtmlString::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, HTML_METAVARIABLE | HTML_STRING)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            HTML_METAVARIABLE => Self::HtmlMetavariable(HtmlMetavariable { syntax }),
+            HTML_STRING => Self::HtmlString(HtmlString { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::HtmlMetavariable(it) => &it.syntax,
+            Self::HtmlString(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            Self::HtmlMetavariable(it) => it.syntax,
+            Self::HtmlString(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyHtmlAttributeInitializer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HtmlMetavariable(it) => std::fmt::Debug::fmt(it, f),
+            Self::HtmlString(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyHtmlAttributeInitializer> for SyntaxNode {
+    fn from(n: AnyHtmlAttributeInitializer) -> Self {
+        match n {
+            AnyHtmlAttributeInitializer::HtmlMetavariable(it) => it.into(),
+            AnyHtmlAttributeInitializer::HtmlString(it) => it.into(),
+        }
+    }
+}
+impl From<AnyHtmlAttributeInitializer> for SyntaxElement {
+    fn from(n: AnyHtmlAttributeInitializer) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
 impl From<HtmlBogusElement> for AnyHtmlElement {
     fn from(node: HtmlBogusElement) -> Self {
         Self::HtmlBogusElement(node)
@@ -1576,6 +1768,11 @@ impl From<HtmlElement> for AnyHtmlElement {
         Self::HtmlElement(node)
     }
 }
+impl From<HtmlMetavariable> for AnyHtmlElement {
+    fn from(node: HtmlMetavariable) -> Self {
+        Self::HtmlMetavariable(node)
+    }
+}
 impl From<HtmlSelfClosingElement> for AnyHtmlElement {
     fn from(node: HtmlSelfClosingElement) -> Self {
         Self::HtmlSelfClosingElement(node)
@@ -1588,6 +1785,7 @@ impl AstNode for AnyHtmlElement {
         .union(HtmlComment::KIND_SET)
         .union(HtmlContent::KIND_SET)
         .union(HtmlElement::KIND_SET)
+        .union(HtmlMetavariable::KIND_SET)
         .union(HtmlSelfClosingElement::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -1597,6 +1795,7 @@ impl AstNode for AnyHtmlElement {
                 | HTML_COMMENT
                 | HTML_CONTENT
                 | HTML_ELEMENT
+                | HTML_METAVARIABLE
                 | HTML_SELF_CLOSING_ELEMENT
         )
     }
@@ -1607,6 +1806,7 @@ impl AstNode for AnyHtmlElement {
             HTML_COMMENT => Self::HtmlComment(HtmlComment { syntax }),
             HTML_CONTENT => Self::HtmlContent(HtmlContent { syntax }),
             HTML_ELEMENT => Self::HtmlElement(HtmlElement { syntax }),
+            HTML_METAVARIABLE => Self::HtmlMetavariable(HtmlMetavariable { syntax }),
             HTML_SELF_CLOSING_ELEMENT => {
                 Self::HtmlSelfClosingElement(HtmlSelfClosingElement { syntax })
             }
@@ -1621,6 +1821,7 @@ impl AstNode for AnyHtmlElement {
             Self::HtmlComment(it) => &it.syntax,
             Self::HtmlContent(it) => &it.syntax,
             Self::HtmlElement(it) => &it.syntax,
+            Self::HtmlMetavariable(it) => &it.syntax,
             Self::HtmlSelfClosingElement(it) => &it.syntax,
         }
     }
@@ -1631,6 +1832,7 @@ impl AstNode for AnyHtmlElement {
             Self::HtmlComment(it) => it.syntax,
             Self::HtmlContent(it) => it.syntax,
             Self::HtmlElement(it) => it.syntax,
+            Self::HtmlMetavariable(it) => it.syntax,
             Self::HtmlSelfClosingElement(it) => it.syntax,
         }
     }
@@ -1643,6 +1845,7 @@ impl std::fmt::Debug for AnyHtmlElement {
             Self::HtmlComment(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlContent(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlElement(it) => std::fmt::Debug::fmt(it, f),
+            Self::HtmlMetavariable(it) => std::fmt::Debug::fmt(it, f),
             Self::HtmlSelfClosingElement(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -1655,6 +1858,7 @@ impl From<AnyHtmlElement> for SyntaxNode {
             AnyHtmlElement::HtmlComment(it) => it.into(),
             AnyHtmlElement::HtmlContent(it) => it.into(),
             AnyHtmlElement::HtmlElement(it) => it.into(),
+            AnyHtmlElement::HtmlMetavariable(it) => it.into(),
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.into(),
         }
     }
@@ -1670,6 +1874,11 @@ impl std::fmt::Display for AnyHtmlAttribute {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyHtmlAttributeInitializer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyHtmlElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1720,6 +1929,11 @@ impl std::fmt::Display for HtmlElement {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for HtmlMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for HtmlOpeningElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
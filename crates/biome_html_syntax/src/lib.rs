@@ -113,10 +113,17 @@ impl TryFrom<HtmlSyntaxKind> for TriviaPieceKind {
 pub fn inner_string_text(token: &HtmlSyntaxToken) -> TokenText {
     let mut text = token.token_text_trimmed();
     if token.kind() == HtmlSyntaxKind::HTML_STRING_LITERAL {
-        // remove string delimiters
-        // SAFETY: string literal token have a delimiters at the start and the end of the string
-        let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
-        text = text.slice(range);
+        // Attribute values can be unquoted, e.g. `<div class=foo>`,
+        // so only remove the delimiters when they are present
+        let is_quoted = text.len() >= TextSize::from(2)
+            && matches!(
+                (text.as_bytes().first(), text.as_bytes().last()),
+                (Some(b'"'), Some(b'"')) | (Some(b'\''), Some(b'\''))
+            );
+        if is_quoted {
+            let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
+            text = text.slice(range);
+        }
     }
     text
 }
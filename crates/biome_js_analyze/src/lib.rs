@@ -33,6 +33,7 @@ pub mod utils;
 
 pub use crate::registry::visit_registry;
 pub use crate::services::control_flow::ControlFlowGraph;
+pub use crate::services::embedded_bindings::EmbeddedBindings;
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -47,6 +48,16 @@ pub struct JsAnalyzerServices {
     module_graph: Arc<ModuleGraph>,
     project_layout: Arc<ProjectLayout>,
     source_type: JsFileSource,
+    embedded_bindings: EmbeddedBindings,
+}
+
+impl JsAnalyzerServices {
+    /// Sets the bindings declared and referenced by the document that embeds
+    /// the analyzed script, e.g. a Vue single-file component.
+    pub fn with_embedded_bindings(mut self, embedded_bindings: EmbeddedBindings) -> Self {
+        self.embedded_bindings = embedded_bindings;
+        self
+    }
 }
 
 impl From<(Arc<ModuleGraph>, Arc<ProjectLayout>, JsFileSource)> for JsAnalyzerServices {
@@ -61,6 +72,7 @@ impl From<(Arc<ModuleGraph>, Arc<ProjectLayout>, JsFileSource)> for JsAnalyzerSe
             module_graph,
             project_layout,
             source_type,
+            embedded_bindings: EmbeddedBindings::default(),
         }
     }
 }
@@ -118,6 +130,7 @@ where
         module_graph,
         project_layout,
         source_type,
+        embedded_bindings,
     } = services;
 
     let (registry, mut services, diagnostics, visitors, categories) = registry.build();
@@ -168,6 +181,7 @@ where
     services.insert_service(file_path);
     services.insert_service(type_resolver);
     services.insert_service(project_layout);
+    services.insert_service(embedded_bindings);
 
     (
         analyzer.run(AnalyzerContext {
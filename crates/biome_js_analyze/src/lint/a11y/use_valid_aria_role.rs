@@ -2,13 +2,12 @@ use crate::JsRuleAction;
 use biome_analyze::{
     Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
+pub use biome_aria::ValidAriaRoleOptions;
 use biome_aria_metadata::AriaRole;
 use biome_console::markup;
-use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::Severity;
 use biome_js_syntax::jsx_ext::AnyJsxElement;
 use biome_rowan::{AstNode, BatchMutationExt};
-use serde::{Deserialize, Serialize};
 
 declare_lint_rule! {
     /// Elements with ARIA roles must use a valid, non-abstract ARIA role.
@@ -75,14 +74,6 @@ declare_lint_rule! {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
-#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
-pub struct ValidAriaRoleOptions {
-    pub allow_invalid_roles: Box<[Box<str>]>,
-    pub ignore_non_dom: bool,
-}
-
 impl Rule for UseValidAriaRole {
     type Query = Ast<AnyJsxElement>;
     type State = ();
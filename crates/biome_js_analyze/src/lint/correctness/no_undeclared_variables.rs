@@ -1,4 +1,5 @@
 use crate::globals::{is_js_global, is_ts_global};
+use crate::services::embedded_bindings::EmbeddedBindings;
 use crate::services::semantic::SemanticServices;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{Rule, RuleDiagnostic, RuleSource, declare_lint_rule};
@@ -80,6 +81,15 @@ impl Rule for NoUndeclaredVariables {
                     return None;
                 }
 
+                // Bindings declared by the document that embeds the script,
+                // e.g. another `<script>` block of a Vue component
+                if ctx
+                    .get_service::<EmbeddedBindings>()
+                    .is_some_and(|bindings| bindings.is_declared(text))
+                {
+                    return None;
+                }
+
                 // Typescript Const Assertion
                 if text == "const" && under_as_expression {
                     return None;
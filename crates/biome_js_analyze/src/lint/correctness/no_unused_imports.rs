@@ -1,3 +1,4 @@
+use crate::services::embedded_bindings::EmbeddedBindings;
 use crate::services::semantic::{SemanticModelBuilderVisitor, SemanticServices};
 use crate::{
     JsRuleAction,
@@ -620,6 +621,15 @@ fn is_unused(ctx: &RuleContext<NoUnusedImports>, local_name: &AnyJsBinding) -> b
         return false;
     }
 
+    // Imports referenced by the document that embeds the script, e.g. the
+    // template of a Vue component, are used
+    let embedded_bindings = ctx.get_service::<EmbeddedBindings>();
+    if binding.name_token().is_ok_and(|name| {
+        embedded_bindings.is_some_and(|bindings| bindings.is_referenced(name.text_trimmed()))
+    }) {
+        return false;
+    }
+
     let model = ctx.semantic_model();
     binding.all_references(model).next().is_none()
 }
@@ -1,4 +1,5 @@
 use crate::JsRuleAction;
+use crate::services::embedded_bindings::EmbeddedBindings;
 use crate::{services::semantic::Semantic, utils::rename::RenameSymbolExtensions};
 use biome_analyze::RuleSource;
 use biome_analyze::{FixKind, Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
@@ -317,9 +318,24 @@ impl Rule for NoUnusedVariables {
             }
         }
 
+        let name_token = binding.name_token().ok()?;
+        let name = name_token.text_trimmed();
+
         // Ignore name prefixed with `_`
-        let is_underscore_prefixed = binding.name_token().ok()?.text_trimmed().starts_with('_');
-        if !is_underscore_prefixed && is_unused(model, binding) {
+        if name.starts_with('_') {
+            return None;
+        }
+
+        // Ignore top-level bindings referenced by the document that embeds
+        // the script, e.g. the template of a Vue component
+        let is_referenced_by_document = ctx
+            .get_service::<EmbeddedBindings>()
+            .is_some_and(|bindings| bindings.is_referenced(name))
+            && model
+                .global_scope()
+                .get_binding(name)
+                .is_some_and(|global| global.syntax() == binding.syntax());
+        if !is_referenced_by_document && is_unused(model, binding) {
             suggested_fix_if_unused(binding, ctx.options())
         } else {
             None
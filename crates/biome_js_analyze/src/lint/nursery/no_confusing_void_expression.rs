@@ -0,0 +1,157 @@
+use std::ops::Deref;
+
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsExpression, JsCallExpression, JsConditionalExpression, JsForStatement,
+    JsLogicalExpression, JsSequenceExpression, JsSyntaxKind, JsUnaryExpression, JsUnaryOperator,
+};
+use biome_js_type_info::TypeData;
+use biome_rowan::AstNode;
+
+use crate::services::typed::Typed;
+
+declare_lint_rule! {
+    /// Require expressions of type `void` to appear in statement position.
+    ///
+    /// Returning the result of a function that returns `void`, or assigning it
+    /// to a variable, is confusing: `void` means the function doesn't return
+    /// anything useful, yet its result is treated as a value. This is often a
+    /// sign that the wrong function was called, or that the programmer assumed
+    /// the function returns something it doesn't.
+    ///
+    /// Calls that return `void` are allowed as expression statements, as the
+    /// operand of the `void` operator, and as the body of an arrow function
+    /// shorthand.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts
+    /// const response = console.log('hello');
+    /// ```
+    ///
+    /// ```ts
+    /// function log(message: string): void {
+    ///   console.log(message);
+    /// }
+    ///
+    /// function handle(message: string) {
+    ///   return log(message);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// console.log('hello');
+    /// ```
+    ///
+    /// ```ts
+    /// function log(message: string): void {
+    ///   console.log(message);
+    /// }
+    ///
+    /// const handle = (message: string) => log(message);
+    /// ```
+    ///
+    pub NoConfusingVoidExpression {
+        version: "next",
+        name: "noConfusingVoidExpression",
+        language: "ts",
+        recommended: false,
+        sources: &[RuleSource::EslintTypeScript("no-confusing-void-expression").inspired()],
+        domains: &[RuleDomain::Project],
+    }
+}
+
+impl Rule for NoConfusingVoidExpression {
+    type Query = Typed<JsCallExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if is_in_statement_position(node) {
+            return None;
+        }
+
+        let ty = ctx.type_for_expression(&AnyJsExpression::JsCallExpression(node.clone()));
+        matches!(ty.deref(), TypeData::VoidKeyword).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The result of an expression of type "<Emphasis>"void"</Emphasis>" is used as a value."
+                },
+            )
+            .note(markup! {
+                "Expressions of type "<Emphasis>"void"</Emphasis>" don't produce a useful value. Move the expression to a statement of its own."
+            }),
+        )
+    }
+}
+
+/// Returns whether the result of the given `call` is discarded, because it
+/// is effectively in statement position.
+fn is_in_statement_position(call: &JsCallExpression) -> bool {
+    let mut node = call.syntax().clone();
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION => {}
+            JsSyntaxKind::JS_CONDITIONAL_EXPRESSION => {
+                // Both branches of a conditional inherit its position, but
+                // the test is used as a value.
+                let is_test = JsConditionalExpression::cast_ref(&parent)
+                    .and_then(|expr| expr.test().ok())
+                    .is_some_and(|test| test.syntax() == &node);
+                if is_test {
+                    return false;
+                }
+            }
+            JsSyntaxKind::JS_LOGICAL_EXPRESSION => {
+                let is_left = JsLogicalExpression::cast_ref(&parent)
+                    .and_then(|expr| expr.left().ok())
+                    .is_some_and(|left| left.syntax() == &node);
+                if is_left {
+                    return false;
+                }
+            }
+            JsSyntaxKind::JS_SEQUENCE_EXPRESSION => {
+                let is_left = JsSequenceExpression::cast_ref(&parent)
+                    .and_then(|expr| expr.left().ok())
+                    .is_some_and(|left| left.syntax() == &node);
+                if is_left {
+                    return true;
+                }
+            }
+            JsSyntaxKind::JS_UNARY_EXPRESSION => {
+                return JsUnaryExpression::cast_ref(&parent)
+                    .and_then(|expr| expr.operator().ok())
+                    .is_some_and(|operator| operator == JsUnaryOperator::Void);
+            }
+            JsSyntaxKind::JS_FOR_STATEMENT => {
+                return JsForStatement::cast_ref(&parent)
+                    .and_then(|stmt| stmt.test())
+                    .is_none_or(|test| test.syntax() != &node);
+            }
+            JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION | JsSyntaxKind::JS_EXPRESSION_STATEMENT => {
+                return true;
+            }
+            _ => return false,
+        }
+
+        node = parent;
+    }
+
+    false
+}
@@ -0,0 +1,253 @@
+use std::ops::Deref;
+
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsArrayElement, AnyJsCallArgument, AnyJsExpression, JsCallExpression,
+    JsConditionalExpression, JsDoWhileStatement, JsForStatement, JsIfStatement,
+    JsLogicalExpression, JsLogicalOperator, JsUnaryExpression, JsUnaryOperator, JsWhileStatement,
+};
+use biome_js_type_info::{Type, TypeData};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange, declare_node_union};
+
+use crate::services::typed::Typed;
+
+declare_lint_rule! {
+    /// Disallow Promises to be used in places where they are almost certainly a mistake.
+    ///
+    /// TypeScript allows Promises in a few places where they are not
+    /// necessarily a mistake, even though they almost always are:
+    ///
+    /// - A Promise used in a boolean position, such as the condition of an
+    ///   `if` statement, is always truthy. Most likely, an `await` is missing.
+    /// - A function returning a Promise that is passed as an argument where a
+    ///   function returning `void` is expected. The caller won't wait for the
+    ///   Promise, and rejections of the Promise will go unhandled.
+    ///
+    /// The elements of an array literal that is spread into the arguments of
+    /// a call, as in `f(...[callback])`, are checked against the parameters
+    /// they're passed to. Arguments from any other spread argument onwards
+    /// aren't checked, because the parameters they're passed to are unknown.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts
+    /// const promise = Promise.resolve('value');
+    /// if (promise) {
+    ///   // This branch is always executed.
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// const promise = Promise.resolve('value');
+    /// const value = promise ? 123 : 456;
+    /// ```
+    ///
+    /// ```ts
+    /// declare const urls: Array<string>;
+    /// urls.forEach(async (url) => {
+    ///   await fetch(url);
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// const promise = Promise.resolve('value');
+    /// if (await promise) {
+    ///   // Only executed if the value is truthy.
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// declare const urls: Array<string>;
+    /// await Promise.all(urls.map(async (url) => {
+    ///   await fetch(url);
+    /// }));
+    /// ```
+    ///
+    pub NoMisusedPromises {
+        version: "next",
+        name: "noMisusedPromises",
+        language: "ts",
+        recommended: false,
+        sources: &[RuleSource::EslintTypeScript("no-misused-promises").inspired()],
+        domains: &[RuleDomain::Project],
+    }
+}
+
+declare_node_union! {
+    pub AnyPromiseUsage =
+        JsCallExpression
+        | JsConditionalExpression
+        | JsDoWhileStatement
+        | JsForStatement
+        | JsIfStatement
+        | JsLogicalExpression
+        | JsUnaryExpression
+        | JsWhileStatement
+}
+
+pub enum MisusedPromise {
+    /// A Promise is used in a boolean position.
+    Conditional(TextRange),
+
+    /// A function returning a Promise is passed where a function returning
+    /// `void` is expected.
+    VoidReturn(TextRange),
+}
+
+impl Rule for NoMisusedPromises {
+    type Query = Typed<AnyPromiseUsage>;
+    type State = MisusedPromise;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if let AnyPromiseUsage::JsCallExpression(call) = node {
+            return misused_promise_arguments(ctx, call).unwrap_or_default();
+        }
+
+        let Some(test) = boolean_test(node) else {
+            return Box::default();
+        };
+
+        if ctx.type_for_expression(&test).is_promise_instance() {
+            Box::new([MisusedPromise::Conditional(test.range())])
+        } else {
+            Box::default()
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            MisusedPromise::Conditional(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "A Promise is used in a boolean position, where it is always truthy."
+                },
+            )
+            .note(markup! {
+                "You may have forgotten to "<Emphasis>"await"</Emphasis>" the Promise."
+            }),
+            MisusedPromise::VoidReturn(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "A function returning a Promise is passed where a function returning "<Emphasis>"void"</Emphasis>" is expected."
+                },
+            )
+            .note(markup! {
+                "The Promise won't be awaited by the caller, and any errors it throws will be left unhandled."
+            }),
+        };
+
+        Some(diagnostic)
+    }
+}
+
+/// Returns the expression that is used in a boolean position by `node`, if
+/// any.
+fn boolean_test(node: &AnyPromiseUsage) -> Option<AnyJsExpression> {
+    match node {
+        AnyPromiseUsage::JsCallExpression(_) => None,
+        AnyPromiseUsage::JsConditionalExpression(expr) => expr.test().ok(),
+        AnyPromiseUsage::JsDoWhileStatement(stmt) => stmt.test().ok(),
+        AnyPromiseUsage::JsForStatement(stmt) => stmt.test(),
+        AnyPromiseUsage::JsIfStatement(stmt) => stmt.test().ok(),
+        AnyPromiseUsage::JsLogicalExpression(expr) => match expr.operator().ok()? {
+            JsLogicalOperator::LogicalAnd | JsLogicalOperator::LogicalOr => expr.left().ok(),
+            JsLogicalOperator::NullishCoalescing => None,
+        },
+        AnyPromiseUsage::JsUnaryExpression(expr) => match expr.operator().ok()? {
+            JsUnaryOperator::LogicalNot => expr.argument().ok(),
+            _ => None,
+        },
+        AnyPromiseUsage::JsWhileStatement(stmt) => stmt.test().ok(),
+    }
+}
+
+/// Returns the arguments of a `call` that are functions returning a Promise,
+/// while the callee expects a function that returns `void`.
+fn misused_promise_arguments(
+    ctx: &RuleContext<NoMisusedPromises>,
+    call: &JsCallExpression,
+) -> Option<Box<[MisusedPromise]>> {
+    let callee = ctx.type_for_expression(&call.callee().ok()?);
+    let TypeData::Function(function) = callee.deref() else {
+        return None;
+    };
+
+    let misused = positional_arguments(call)
+        .into_iter()
+        .zip(function.parameters.iter())
+        .filter_map(|(arg, param)| {
+            if param.is_rest || !returns_void(&callee.resolve(&param.ty)?) {
+                return None;
+            }
+
+            ctx.type_for_expression(&arg)
+                .is_function_that_returns_promise()
+                .then(|| MisusedPromise::VoidReturn(arg.range()))
+        })
+        .collect();
+
+    Some(misused)
+}
+
+/// Returns the arguments of `call`, in the order of the parameters they're
+/// passed to.
+///
+/// The elements of an array literal that is spread into the arguments are
+/// passed to parameters of their own, as in `f(...[a, b])`. The number of
+/// elements of any other spread argument is unknown, so the arguments from
+/// that point on are left out.
+fn positional_arguments(call: &JsCallExpression) -> Vec<AnyJsExpression> {
+    let mut arguments = Vec::new();
+    let Ok(args) = call.arguments() else {
+        return arguments;
+    };
+
+    for arg in args.args().iter() {
+        match arg {
+            Ok(AnyJsCallArgument::AnyJsExpression(arg)) => arguments.push(arg),
+            Ok(AnyJsCallArgument::JsSpread(spread)) => {
+                let Some(AnyJsExpression::JsArrayExpression(array)) =
+                    spread.argument().ok().map(|arg| arg.omit_parentheses())
+                else {
+                    break;
+                };
+
+                for element in array.elements().iter() {
+                    match element {
+                        Ok(AnyJsArrayElement::AnyJsExpression(element)) => {
+                            arguments.push(element);
+                        }
+                        _ => return arguments,
+                    }
+                }
+            }
+            Err(_) => break,
+        }
+    }
+
+    arguments
+}
+
+/// Returns whether `ty` is a function that returns `void`.
+fn returns_void(ty: &Type) -> bool {
+    match ty.deref() {
+        TypeData::Function(function) => function
+            .return_type
+            .as_type()
+            .and_then(|return_ty| ty.resolve(return_ty))
+            .is_some_and(|return_ty| matches!(return_ty.deref(), TypeData::VoidKeyword)),
+        _ => false,
+    }
+}
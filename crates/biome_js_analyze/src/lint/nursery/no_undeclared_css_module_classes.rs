@@ -0,0 +1,116 @@
+use biome_analyze::{Rule, RuleDiagnostic, RuleDomain, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::{Text, TextRange};
+use camino::Utf8PathBuf;
+
+use crate::services::module_graph::ResolvedImports;
+
+declare_lint_rule! {
+    /// Disallow references to classes that aren't declared by the imported CSS Module.
+    ///
+    /// The default or namespace import of a CSS Module is an object whose
+    /// properties are the classes declared by the stylesheet. Accessing a
+    /// class that the stylesheet doesn't declare results in `undefined`, so
+    /// the element silently ends up without styles.
+    ///
+    /// This rule checks the member accesses on the imports of files ending
+    /// with `.module.css` or `.module.scss`, such as `styles.button` or
+    /// `styles["button"]`. Classes with dashes in their name may be accessed
+    /// in camel case, such as `styles.primaryButton` for `.primary-button`.
+    /// The ids and keyframes declared by the stylesheet are exported too.
+    ///
+    /// Stylesheets that may declare classes that cannot be determined
+    /// statically, such as SCSS files with selectors like `&-primary`, are
+    /// not checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// **`button.module.css`**
+    /// ```css
+    /// .button {
+    ///   color: blue;
+    /// }
+    /// ```
+    ///
+    /// **`button.jsx`**
+    /// ```jsx
+    /// import styles from "./button.module.css";
+    ///
+    /// // Attempt to use a class with a typo:
+    /// export const Button = () => <button className={styles.buton} />;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// **`button.jsx`**
+    /// ```jsx
+    /// import styles from "./button.module.css";
+    ///
+    /// export const Button = () => <button className={styles.button} />;
+    /// ```
+    ///
+    pub NoUndeclaredCssModuleClasses {
+        version: "next",
+        name: "noUndeclaredCssModuleClasses",
+        language: "js",
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+pub struct NoUndeclaredCssModuleClassesState {
+    range: TextRange,
+    class_name: Text,
+    path: Utf8PathBuf,
+}
+
+impl Rule for NoUndeclaredCssModuleClasses {
+    type Query = ResolvedImports<AnyJsRoot>;
+    type State = NoUndeclaredCssModuleClassesState;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(module_info) = ctx.module_info_for_path(ctx.file_path()) else {
+            return Box::default();
+        };
+
+        module_info
+            .css_module_class_references
+            .iter()
+            .filter_map(|reference| {
+                let class_name = reference.class_name.as_ref()?;
+                let path = reference.resolved_path.as_path()?;
+                let css_module_info = ctx.module_graph().css_module_info_for_path(path)?;
+                if css_module_info.has_dynamic_classes || css_module_info.exports_name(class_name) {
+                    return None;
+                }
+
+                Some(NoUndeclaredCssModuleClassesState {
+                    range: reference.range,
+                    class_name: class_name.clone(),
+                    path: path.to_path_buf(),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let file_name = state.path.file_name().unwrap_or(state.path.as_str());
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The class "<Emphasis>{state.class_name.text()}</Emphasis>" is not declared by the CSS Module "<Emphasis>{file_name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Accessing a class that isn't declared results in "<Emphasis>"undefined"</Emphasis>". Make sure that the class name is correct."
+            }),
+        )
+    }
+}
@@ -0,0 +1,258 @@
+use std::ops::Deref;
+
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsExpression, JsConditionalExpression, JsDoWhileStatement, JsForStatement, JsIfStatement,
+    JsLogicalExpression, JsLogicalOperator, JsSyntaxKind, JsWhileStatement, T,
+};
+use biome_js_type_info::{Literal, Type, TypeData};
+use biome_rowan::{AstNode, Direction, TextRange, declare_node_union};
+
+use crate::services::typed::Typed;
+
+declare_lint_rule! {
+    /// Disallow conditionals that are always truthy or always falsy, based on their type.
+    ///
+    /// Any expression used as a condition must be able to evaluate as truthy
+    /// and falsy in order to make sense. If the inferred type of a condition
+    /// shows that it can only ever be one of the two, the condition is
+    /// unnecessary. Similarly, the left operand of the `??` operator must be
+    /// able to be both nullish and non-nullish.
+    ///
+    /// Unnecessary conditions are often a sign of a mistake, such as checking
+    /// the wrong variable, or forgetting to call a function.
+    ///
+    /// This rule only reports conditions when the inferred type is definitely
+    /// known. Primitive literal types are not reported, since those are already covered
+    /// by [noConstantCondition](https://biomejs.dev/linter/rules/no-constant-condition).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts
+    /// function head(items: Array<string>) {
+    ///   // Arrays are always truthy, even when they are empty.
+    ///   if (items) {
+    ///     return items[0];
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function handle(callback: () => void) {
+    ///   // Functions are always truthy.
+    ///   return callback && callback();
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function greet(user: { name: string }) {
+    ///   return user ?? { name: "anonymous" };
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function head(items: Array<string>) {
+    ///   if (items.length > 0) {
+    ///     return items[0];
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function handle(callback?: () => void) {
+    ///   return callback && callback();
+    /// }
+    /// ```
+    ///
+    pub NoUnnecessaryCondition {
+        version: "next",
+        name: "noUnnecessaryCondition",
+        language: "ts",
+        recommended: false,
+        sources: &[RuleSource::EslintTypeScript("no-unnecessary-condition").inspired()],
+        domains: &[RuleDomain::Project],
+    }
+}
+
+declare_node_union! {
+    pub AnyConditionalNode =
+        JsConditionalExpression
+        | JsDoWhileStatement
+        | JsForStatement
+        | JsIfStatement
+        | JsLogicalExpression
+        | JsWhileStatement
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum UnnecessaryCondition {
+    AlwaysTruthy,
+    AlwaysFalsy,
+    AlwaysNullish,
+    NeverNullish,
+}
+
+impl Rule for NoUnnecessaryCondition {
+    type Query = Typed<AnyConditionalNode>;
+    type State = (TextRange, UnnecessaryCondition);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let (test, is_nullish_check) = match node {
+            AnyConditionalNode::JsConditionalExpression(expr) => (expr.test().ok()?, false),
+            AnyConditionalNode::JsDoWhileStatement(stmt) => (stmt.test().ok()?, false),
+            AnyConditionalNode::JsForStatement(stmt) => (stmt.test()?, false),
+            AnyConditionalNode::JsIfStatement(stmt) => (stmt.test().ok()?, false),
+            AnyConditionalNode::JsLogicalExpression(expr) => (
+                expr.left().ok()?,
+                expr.operator().ok()? == JsLogicalOperator::NullishCoalescing,
+            ),
+            AnyConditionalNode::JsWhileStatement(stmt) => (stmt.test().ok()?, false),
+        };
+
+        let test = test.omit_parentheses();
+        if has_unreliable_type(&test) {
+            return None;
+        }
+
+        let ty = ctx.type_for_expression(&test);
+        let condition = if is_nullish_check {
+            match truthiness(&ty)? {
+                Truthiness::Falsy => UnnecessaryCondition::AlwaysNullish,
+                Truthiness::Truthy => UnnecessaryCondition::NeverNullish,
+            }
+        } else {
+            match truthiness(&ty)? {
+                Truthiness::Falsy => UnnecessaryCondition::AlwaysFalsy,
+                Truthiness::Truthy => UnnecessaryCondition::AlwaysTruthy,
+            }
+        };
+
+        // Variables that are declared without an initial value, or that are
+        // initialised with `null`, are often assigned a value later.
+        if matches!(
+            condition,
+            UnnecessaryCondition::AlwaysFalsy | UnnecessaryCondition::AlwaysNullish
+        ) && matches!(test, AnyJsExpression::JsIdentifierExpression(_))
+        {
+            return None;
+        }
+
+        Some((test.range(), condition))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (range, condition) = state;
+        let diagnostic = match condition {
+            UnnecessaryCondition::AlwaysTruthy => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! { "This condition is always truthy." },
+            )
+            .note(markup! {
+                "Based on its type, this expression can never be falsy, which makes the condition unnecessary."
+            }),
+            UnnecessaryCondition::AlwaysFalsy => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! { "This condition is always falsy." },
+            )
+            .note(markup! {
+                "Based on its type, this expression can never be truthy, which makes the condition unnecessary."
+            }),
+            UnnecessaryCondition::AlwaysNullish => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! { "The left operand of "<Emphasis>"??"</Emphasis>" is always nullish." },
+            )
+            .note(markup! {
+                "Based on its type, this expression is always "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>", so the right operand is always used."
+            }),
+            UnnecessaryCondition::NeverNullish => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! { "The left operand of "<Emphasis>"??"</Emphasis>" is never nullish." },
+            )
+            .note(markup! {
+                "Based on its type, this expression can never be "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>", so the right operand is never used."
+            }),
+        };
+
+        Some(diagnostic)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Truthiness {
+    Truthy,
+    Falsy,
+}
+
+/// Returns whether `ty` is always truthy or always falsy, if known.
+///
+/// Types that are always truthy are also never nullish, while the types that
+/// are always falsy are also always nullish.
+fn truthiness(ty: &Type) -> Option<Truthiness> {
+    match ty.deref() {
+        TypeData::Null | TypeData::Undefined | TypeData::VoidKeyword => Some(Truthiness::Falsy),
+        TypeData::Class(_) | TypeData::Function(_) | TypeData::Tuple(_) => Some(Truthiness::Truthy),
+        // The empty object type `{}` also accepts primitives.
+        TypeData::Object(object) if !object.members.is_empty() => Some(Truthiness::Truthy),
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::Object(_) | Literal::RegExp(_) => Some(Truthiness::Truthy),
+            _ => None,
+        },
+        TypeData::InstanceOf(instance) => {
+            let instance_of = ty.resolve(&instance.ty)?;
+            let name = match instance_of.deref() {
+                TypeData::Class(class) => class.name.as_ref(),
+                TypeData::Interface(interface) => Some(&interface.name),
+                _ => return None,
+            };
+
+            // Instances of the wrappers of primitive types may be assigned
+            // primitive values.
+            let is_primitive_wrapper = name.is_some_and(|name| {
+                matches!(
+                    name.text(),
+                    "BigInt" | "Boolean" | "Number" | "Object" | "String" | "Symbol"
+                )
+            });
+            (!is_primitive_wrapper).then_some(Truthiness::Truthy)
+        }
+        TypeData::Union(union) => {
+            let mut variants = union
+                .types()
+                .iter()
+                .map(|variant| ty.resolve(variant).and_then(|variant| truthiness(&variant)));
+            let first = variants.next()??;
+            variants
+                .all(|variant| variant == Some(first))
+                .then_some(first)
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether the inferred type of `expr` may be unreliable.
+///
+/// We don't infer `undefined` for optional chains or for accessing elements by
+/// index, so we skip expressions that contain these.
+fn has_unreliable_type(expr: &AnyJsExpression) -> bool {
+    expr.syntax()
+        .descendants_tokens(Direction::Next)
+        .any(|token| token.kind() == T![?.])
+        || expr
+            .syntax()
+            .descendants()
+            .any(|node| node.kind() == JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION)
+}
@@ -0,0 +1,317 @@
+use std::sync::Arc;
+
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_fs::BiomePath;
+use biome_js_syntax::{
+    AnyJsDeclarationClause, AnyJsExportClause, AnyTsModuleName, JsExport, JsIdentifierBinding,
+    JsInitializerClause, JsModule, JsSyntaxToken, inner_string_text,
+};
+use biome_package::PackageJson;
+use biome_rowan::{AstNode, SyntaxResult, Text, TextRange};
+use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+use crate::{services::module_graph::ResolvedImports, utils::entry_points::EntryPoints};
+
+declare_lint_rule! {
+    /// Disallow exports that are never imported by another module.
+    ///
+    /// Exports that are not imported anywhere in the project are dead code.
+    /// They make a module's interface larger than it needs to be, and keep
+    /// code alive that could otherwise be removed.
+    ///
+    /// This rule uses the module graph of the project to find the exports that
+    /// are never imported, either directly or through a re-export. Exports of
+    /// the project's entry points are never reported, since they may be
+    /// imported from outside the project. Entry points are the files declared
+    /// in the `main`, `module`, `types`, `typings`, `bin`, and `exports`
+    /// fields of the nearest `package.json`, as well as the files matching the
+    /// [`entryPoints`](#entrypoints) option.
+    ///
+    /// If no entry points are declared or configured, this rule doesn't report
+    /// anything.
+    ///
+    /// Modules that are imported through a namespace import, such as
+    /// `import * as utils from "./utils.js"`, a dynamic `import()` or a
+    /// `require()` call are considered to use all of their exports.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// **`utils.js`**
+    /// ```js
+    /// export function used() {}
+    ///
+    /// // Not imported by any other module:
+    /// export function unused() {}
+    /// ```
+    ///
+    /// **`index.js`**, which is the `main` entry point of the package:
+    /// ```js
+    /// import { used } from "./utils.js";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `entryPoints`
+    ///
+    /// A list of globs matching the files that should be treated as entry
+    /// points, in addition to those declared in the `package.json`. The globs
+    /// are matched against paths relative to the directory of the nearest
+    /// `package.json`.
+    ///
+    /// This is useful for applications that don't declare their entry points,
+    /// or for files that are used by tools, such as tests and configuration
+    /// files.
+    ///
+    /// ```json,options
+    /// {
+    ///   "options": {
+    ///     "entryPoints": ["src/main.ts", "**/*.test.ts", "*.config.ts"]
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnusedExports {
+        version: "next",
+        name: "noUnusedExports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-unused-modules").inspired()],
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+/// Options for the rule `noUnusedExports`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnusedExportsOptions {
+    /// Globs matching the files that are entry points of the project, in
+    /// addition to those declared in the `package.json`.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub entry_points: Box<[biome_glob::Glob]>,
+}
+
+pub struct UnusedExport {
+    name: Text,
+    range: TextRange,
+}
+
+impl Rule for NoUnusedExports {
+    type Query = ResolvedImports<JsExport>;
+    type State = UnusedExport;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUnusedExportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let path = ctx.file_path();
+
+        // Exports inside namespaces and ambient modules are not exports of
+        // the module itself. Declaration files are used without being
+        // imported.
+        let is_module_export = node
+            .syntax()
+            .grand_parent()
+            .is_some_and(|grand_parent| JsModule::can_cast(grand_parent.kind()));
+        if !is_module_export || BiomePath::new(path).is_type_declaration() {
+            return Box::default();
+        }
+
+        let manifest = ctx.get_service::<Option<(Utf8PathBuf, Arc<PackageJson>)>>();
+        let entry_points = EntryPoints::new(
+            manifest.and_then(Option::as_ref),
+            &ctx.options().entry_points,
+        );
+        if entry_points.is_empty() || entry_points.contains(path) {
+            return Box::default();
+        }
+
+        let module_graph = ctx.module_graph();
+        exported_names(node)
+            .into_iter()
+            .filter(|export| {
+                !module_graph
+                    .is_export_used(path, export.name.text(), |path| entry_points.contains(path))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnusedExport { name, range } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The export "<Emphasis>{name.text()}</Emphasis>" is never imported by another module."
+                },
+            )
+            .note(markup! {
+                "Unused exports are dead code. Remove the export, or the code that is exported if it's unused within the module too."
+            }),
+        )
+    }
+}
+
+/// Returns the names under which the given `export` exports symbols, together
+/// with the range to report for each of them.
+///
+/// Blanket re-exports, such as `export * from "./module.js"`, and export
+/// assignments are not included.
+fn exported_names(export: &JsExport) -> Vec<UnusedExport> {
+    let Ok(clause) = export.export_clause() else {
+        return Vec::new();
+    };
+
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+            declaration_exported_names(&declaration)
+        }
+        AnyJsExportClause::TsExportDeclareClause(clause) => clause
+            .declaration()
+            .map(|declaration| declaration_exported_names(&declaration))
+            .unwrap_or_default(),
+        AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+            default_export(clause.default_token()).into_iter().collect()
+        }
+        AnyJsExportClause::JsExportDefaultExpressionClause(clause) => {
+            default_export(clause.default_token()).into_iter().collect()
+        }
+        AnyJsExportClause::JsExportNamedClause(clause) => clause
+            .specifiers()
+            .into_iter()
+            .flatten()
+            .filter_map(|specifier| {
+                Some(UnusedExport {
+                    name: specifier.exported_name().ok()?,
+                    range: specifier.range(),
+                })
+            })
+            .collect(),
+        AnyJsExportClause::JsExportNamedFromClause(clause) => clause
+            .specifiers()
+            .into_iter()
+            .flatten()
+            .filter_map(|specifier| {
+                let name = match specifier.export_as() {
+                    Some(export_as) => export_as.exported_name().ok()?.inner_string_text().ok()?,
+                    None => specifier.source_name().ok()?.inner_string_text().ok()?,
+                };
+                Some(UnusedExport {
+                    name: name.into(),
+                    range: specifier.range(),
+                })
+            })
+            .collect(),
+        AnyJsExportClause::JsExportFromClause(clause) => clause
+            .export_as()
+            .and_then(|export_as| {
+                let name = export_as.exported_name().ok()?;
+                Some(UnusedExport {
+                    name: name.inner_string_text().ok()?.into(),
+                    range: name.range(),
+                })
+            })
+            .into_iter()
+            .collect(),
+        AnyJsExportClause::TsExportAsNamespaceClause(_)
+        | AnyJsExportClause::TsExportAssignmentClause(_) => Vec::new(),
+    }
+}
+
+fn declaration_exported_names(declaration: &AnyJsDeclarationClause) -> Vec<UnusedExport> {
+    let identifier = match declaration {
+        AnyJsDeclarationClause::JsClassDeclaration(node) => node
+            .id()
+            .ok()
+            .and_then(|id| id.as_js_identifier_binding()?.name_token().ok()),
+        AnyJsDeclarationClause::JsFunctionDeclaration(node) => node
+            .id()
+            .ok()
+            .and_then(|id| id.as_js_identifier_binding()?.name_token().ok()),
+        AnyJsDeclarationClause::TsDeclareFunctionDeclaration(node) => node
+            .id()
+            .ok()
+            .and_then(|id| id.as_js_identifier_binding()?.name_token().ok()),
+        AnyJsDeclarationClause::TsEnumDeclaration(node) => node
+            .id()
+            .ok()
+            .and_then(|id| id.as_js_identifier_binding()?.name_token().ok()),
+        AnyJsDeclarationClause::TsInterfaceDeclaration(node) => node
+            .id()
+            .ok()
+            .and_then(|id| id.as_ts_identifier_binding()?.name_token().ok()),
+        AnyJsDeclarationClause::TsModuleDeclaration(node) => match node.name().ok() {
+            Some(AnyTsModuleName::AnyTsIdentifierBinding(id)) => id
+                .as_ts_identifier_binding()
+                .and_then(|id| id.name_token().ok()),
+            _ => None,
+        },
+        AnyJsDeclarationClause::TsTypeAliasDeclaration(node) => node
+            .binding_identifier()
+            .ok()
+            .and_then(|id| id.as_ts_identifier_binding()?.name_token().ok()),
+        AnyJsDeclarationClause::JsVariableDeclarationClause(node) => {
+            let Ok(declaration) = node.declaration() else {
+                return Vec::new();
+            };
+
+            return declaration
+                .declarators()
+                .into_iter()
+                .flatten()
+                .filter_map(|declarator| declarator.id().ok())
+                .flat_map(|id| {
+                    // Bindings inside default values, such as the parameters
+                    // of a function, are not exported.
+                    id.syntax()
+                        .descendants()
+                        .filter_map(JsIdentifierBinding::cast)
+                        .filter(|binding| {
+                            binding
+                                .syntax()
+                                .ancestors()
+                                .all(|ancestor| !JsInitializerClause::can_cast(ancestor.kind()))
+                        })
+                        .filter_map(|binding| binding.name_token().ok())
+                        .map(|token| exported_token(&token))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+        }
+        AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+        | AnyJsDeclarationClause::TsGlobalDeclaration(_)
+        | AnyJsDeclarationClause::TsImportEqualsDeclaration(_) => None,
+    };
+
+    identifier
+        .as_ref()
+        .map(exported_token)
+        .into_iter()
+        .collect()
+}
+
+fn default_export(default_token: SyntaxResult<JsSyntaxToken>) -> Option<UnusedExport> {
+    let default_token = default_token.ok()?;
+    Some(UnusedExport {
+        name: Text::Static("default"),
+        range: default_token.text_trimmed_range(),
+    })
+}
+
+fn exported_token(token: &JsSyntaxToken) -> UnusedExport {
+    UnusedExport {
+        name: inner_string_text(token).into(),
+        range: token.text_trimmed_range(),
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_fs::BiomePath;
+use biome_js_syntax::AnyJsRoot;
+use biome_package::PackageJson;
+use biome_rowan::TextRange;
+use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+use crate::{services::module_graph::ResolvedImports, utils::entry_points::EntryPoints};
+
+declare_lint_rule! {
+    /// Disallow files that are not reachable from any entry point of the project.
+    ///
+    /// Files that are never imported, neither directly nor indirectly, by an
+    /// entry point of the project are dead code. They can be removed without
+    /// affecting the project.
+    ///
+    /// This rule uses the module graph of the project to find the files that
+    /// are reachable from the entry points, following static imports,
+    /// re-exports, dynamic `import()` calls, and `require()` calls. Entry
+    /// points are the files declared in the `main`, `module`, `types`,
+    /// `typings`, `bin`, and `exports` fields of the nearest `package.json`,
+    /// as well as the files matching the [`entryPoints`](#entrypoints) option.
+    ///
+    /// If none of the entry points are part of the project, for example
+    /// because the `package.json` only refers to build output that is ignored
+    /// by Biome, this rule doesn't report anything. Type declaration files are
+    /// never reported, since they are used without being imported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// **`package.json`**
+    /// ```json
+    /// { "main": "src/index.js" }
+    /// ```
+    ///
+    /// **`src/index.js`**
+    /// ```js
+    /// import { used } from "./used.js";
+    /// ```
+    ///
+    /// **`src/unused.js`**, which is not imported by `src/index.js` nor by any of its dependencies:
+    /// ```js
+    /// export function unused() {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `entryPoints`
+    ///
+    /// A list of globs matching the files that should be treated as entry
+    /// points, in addition to those declared in the `package.json`. The globs
+    /// are matched against paths relative to the directory of the nearest
+    /// `package.json`.
+    ///
+    /// This is useful for applications that don't declare their entry points,
+    /// or for files that are used by tools, such as tests and configuration
+    /// files.
+    ///
+    /// ```json,options
+    /// {
+    ///   "options": {
+    ///     "entryPoints": ["src/main.ts", "**/*.test.ts", "*.config.ts"]
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnusedFiles {
+        version: "next",
+        name: "noUnusedFiles",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-unused-modules").inspired()],
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+/// Options for the rule `noUnusedFiles`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnusedFilesOptions {
+    /// Globs matching the files that are entry points of the project, in
+    /// addition to those declared in the `package.json`.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub entry_points: Box<[biome_glob::Glob]>,
+}
+
+impl Rule for NoUnusedFiles {
+    type Query = ResolvedImports<AnyJsRoot>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = NoUnusedFilesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let path = ctx.file_path();
+        if BiomePath::new(path).is_type_declaration() {
+            return None;
+        }
+
+        let manifest = ctx.get_service::<Option<(Utf8PathBuf, Arc<PackageJson>)>>();
+        let entry_points = EntryPoints::new(
+            manifest.and_then(Option::as_ref),
+            &ctx.options().entry_points,
+        );
+        if entry_points.is_empty() || entry_points.contains(path) {
+            return None;
+        }
+
+        let reachable_paths = entry_points.reachable_paths(ctx.module_graph());
+        (!reachable_paths.is_empty() && !reachable_paths.contains(path)).then_some(())
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                None as Option<TextRange>,
+                markup! {
+                    "This file is not reachable from any entry point of the project."
+                },
+            )
+            .note(markup! {
+                "Files that are never imported are dead code. Remove the file, or add it to the "<Emphasis>"entryPoints"</Emphasis>" option if it's used by a tool."
+            }),
+        )
+    }
+}
@@ -0,0 +1,149 @@
+use std::ops::Deref;
+
+use biome_analyze::{
+    FixKind, Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsExpression, JsAwaitExpression};
+use biome_js_type_info::{Literal, Type, TypeData};
+use biome_rowan::{AstNode, BatchMutationExt, chain_trivia_pieces};
+
+use crate::{JsRuleAction, services::typed::Typed};
+
+declare_lint_rule! {
+    /// Require `await` to only be used on Promise-like values.
+    ///
+    /// While it's valid JavaScript to `await` a value that is not a Promise,
+    /// doing so has no effect other than deferring the rest of the function
+    /// to a later tick. This is usually a sign that the `await` is
+    /// unnecessary, or that the value is not what the programmer expected.
+    ///
+    /// This rule reports `await` expressions of which the argument is known to
+    /// never be Promise-like, based on its inferred type.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts
+    /// await 'value';
+    /// ```
+    ///
+    /// ```ts
+    /// function createValue(): string {
+    ///   return 'value';
+    /// }
+    /// await createValue();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// await Promise.resolve('value');
+    /// ```
+    ///
+    /// ```ts
+    /// async function createValue(): Promise<string> {
+    ///   return 'value';
+    /// }
+    /// await createValue();
+    /// ```
+    ///
+    pub UseAwaitThenable {
+        version: "next",
+        name: "useAwaitThenable",
+        language: "ts",
+        recommended: false,
+        sources: &[RuleSource::EslintTypeScript("await-thenable").same()],
+        fix_kind: FixKind::Unsafe,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+impl Rule for UseAwaitThenable {
+    type Query = Typed<JsAwaitExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let argument = node.argument().ok()?;
+        let ty = ctx.type_for_expression(&argument);
+
+        is_never_thenable(&ty).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Unexpected "<Emphasis>"await"</Emphasis>" of a value that is not a Promise."
+                },
+            )
+            .note(markup! {
+                "Awaiting a value that is not Promise-like has no effect other than deferring the code that follows."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let await_token = node.await_token().ok()?;
+        let mut argument = node.argument().ok()?;
+        if await_token.has_trailing_comments() {
+            argument = argument.prepend_trivia_pieces(chain_trivia_pieces(
+                await_token.leading_trivia().pieces(),
+                await_token.trailing_trivia().pieces(),
+            ))?;
+        } else {
+            argument = argument.prepend_trivia_pieces(await_token.leading_trivia().pieces())?;
+        }
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(
+            AnyJsExpression::JsAwaitExpression(node.clone()),
+            argument,
+        );
+
+        Some(JsRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the "<Emphasis>"await"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns whether `ty` is known to never be Promise-like.
+///
+/// `null` and `undefined` are not reported, because variables initialised
+/// with them are often assigned a Promise later.
+fn is_never_thenable(ty: &Type) -> bool {
+    match ty.deref() {
+        TypeData::BigInt
+        | TypeData::Boolean
+        | TypeData::Number
+        | TypeData::String
+        | TypeData::Symbol
+        | TypeData::VoidKeyword
+        | TypeData::Function(_) => true,
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::Object(object) => !object
+                .members()
+                .iter()
+                .any(|member| member.has_name("then")),
+            Literal::Null => false,
+            _ => true,
+        },
+        TypeData::Object(object) => !object.members.iter().any(|member| member.has_name("then")),
+        TypeData::Union(union) => union
+            .types()
+            .iter()
+            .all(|variant| ty.resolve(variant).is_some_and(|ty| is_never_thenable(&ty))),
+        _ => false,
+    }
+}
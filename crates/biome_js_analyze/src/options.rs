@@ -43,6 +43,7 @@ pub type NoCompareNegZero =
     <lint::suspicious::no_compare_neg_zero::NoCompareNegZero as biome_analyze::Rule>::Options;
 pub type NoConfusingLabels =
     <lint::suspicious::no_confusing_labels::NoConfusingLabels as biome_analyze::Rule>::Options;
+pub type NoConfusingVoidExpression = < lint :: nursery :: no_confusing_void_expression :: NoConfusingVoidExpression as biome_analyze :: Rule > :: Options ;
 pub type NoConfusingVoidType =
     <lint::suspicious::no_confusing_void_type::NoConfusingVoidType as biome_analyze::Rule>::Options;
 pub type NoConsole = <lint::suspicious::no_console::NoConsole as biome_analyze::Rule>::Options;
@@ -162,6 +163,8 @@ pub type NoMisleadingCharacterClass = < lint :: suspicious :: no_misleading_char
 pub type NoMisleadingInstantiator = < lint :: suspicious :: no_misleading_instantiator :: NoMisleadingInstantiator as biome_analyze :: Rule > :: Options ;
 pub type NoMisplacedAssertion = < lint :: suspicious :: no_misplaced_assertion :: NoMisplacedAssertion as biome_analyze :: Rule > :: Options ;
 pub type NoMisrefactoredShorthandAssign = < lint :: suspicious :: no_misrefactored_shorthand_assign :: NoMisrefactoredShorthandAssign as biome_analyze :: Rule > :: Options ;
+pub type NoMisusedPromises =
+    <lint::nursery::no_misused_promises::NoMisusedPromises as biome_analyze::Rule>::Options;
 pub type NoNamespace = <lint::style::no_namespace::NoNamespace as biome_analyze::Rule>::Options;
 pub type NoNamespaceImport =
     <lint::performance::no_namespace_import::NoNamespaceImport as biome_analyze::Rule>::Options;
@@ -248,8 +251,10 @@ pub type NoThisInStatic =
 pub type NoTsIgnore = <lint::nursery::no_ts_ignore::NoTsIgnore as biome_analyze::Rule>::Options;
 pub type NoUnassignedVariables =
     <lint::nursery::no_unassigned_variables::NoUnassignedVariables as biome_analyze::Rule>::Options;
+pub type NoUndeclaredCssModuleClasses = < lint :: nursery :: no_undeclared_css_module_classes :: NoUndeclaredCssModuleClasses as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredDependencies = < lint :: correctness :: no_undeclared_dependencies :: NoUndeclaredDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredVariables = < lint :: correctness :: no_undeclared_variables :: NoUndeclaredVariables as biome_analyze :: Rule > :: Options ;
+pub type NoUnnecessaryCondition = < lint :: nursery :: no_unnecessary_condition :: NoUnnecessaryCondition as biome_analyze :: Rule > :: Options ;
 pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
 pub type NoUnreachableSuper =
@@ -262,6 +267,10 @@ pub type NoUnsafeFinally =
 pub type NoUnsafeNegation =
     <lint::suspicious::no_unsafe_negation::NoUnsafeNegation as biome_analyze::Rule>::Options;
 pub type NoUnsafeOptionalChaining = < lint :: correctness :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedExports =
+    <lint::nursery::no_unused_exports::NoUnusedExports as biome_analyze::Rule>::Options;
+pub type NoUnusedFiles =
+    <lint::nursery::no_unused_files::NoUnusedFiles as biome_analyze::Rule>::Options;
 pub type NoUnusedFunctionParameters = < lint :: correctness :: no_unused_function_parameters :: NoUnusedFunctionParameters as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedImports =
     <lint::correctness::no_unused_imports::NoUnusedImports as biome_analyze::Rule>::Options;
@@ -331,6 +340,8 @@ pub type UseAsConstAssertion =
     <lint::style::use_as_const_assertion::UseAsConstAssertion as biome_analyze::Rule>::Options;
 pub type UseAtIndex = <lint::style::use_at_index::UseAtIndex as biome_analyze::Rule>::Options;
 pub type UseAwait = <lint::suspicious::use_await::UseAwait as biome_analyze::Rule>::Options;
+pub type UseAwaitThenable =
+    <lint::nursery::use_await_thenable::UseAwaitThenable as biome_analyze::Rule>::Options;
 pub type UseBlockStatements =
     <lint::style::use_block_statements::UseBlockStatements as biome_analyze::Rule>::Options;
 pub type UseButtonType =
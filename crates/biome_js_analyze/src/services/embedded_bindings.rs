@@ -0,0 +1,38 @@
+use rustc_hash::FxHashSet;
+
+/// Bindings shared between a script and the document that embeds it.
+///
+/// For example, a Vue single-file component can have both a `<script>` and a
+/// `<script setup>` block: the top-level bindings of one block are visible
+/// from the other one, and from the template of the component. Each block is
+/// analyzed on its own, so the analyzer needs to be told about the names that
+/// the rest of the document declares and references.
+#[derive(Clone, Debug, Default)]
+pub struct EmbeddedBindings {
+    /// Names declared by the rest of the document.
+    declarations: FxHashSet<Box<str>>,
+    /// Names referenced by the rest of the document.
+    references: FxHashSet<Box<str>>,
+}
+
+impl EmbeddedBindings {
+    /// Records a name declared by the rest of the document.
+    pub fn insert_declaration(&mut self, name: impl Into<Box<str>>) {
+        self.declarations.insert(name.into());
+    }
+
+    /// Records a name referenced by the rest of the document.
+    pub fn insert_reference(&mut self, name: impl Into<Box<str>>) {
+        self.references.insert(name.into());
+    }
+
+    /// Returns `true` if `name` is declared by the rest of the document.
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.declarations.contains(name)
+    }
+
+    /// Returns `true` if `name` is referenced by the rest of the document.
+    pub fn is_referenced(&self, name: &str) -> bool {
+        self.references.contains(name)
+    }
+}
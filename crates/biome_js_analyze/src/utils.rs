@@ -3,6 +3,7 @@ use biome_rowan::{AstNode, Direction, WalkEvent};
 use std::iter;
 
 pub mod batch;
+pub mod entry_points;
 pub mod rename;
 pub mod restricted_regex;
 #[cfg(test)]
@@ -0,0 +1,86 @@
+//! Entry points of a project, as needed by rules that detect unused code
+//! across modules.
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use biome_fs::normalize_path;
+use biome_glob::{CandidatePath, Glob};
+use biome_module_graph::ModuleGraph;
+use biome_package::PackageJson;
+use camino::{Utf8Path, Utf8PathBuf};
+
+/// The files through which a project is entered.
+///
+/// Entry points are the files declared in the `main`, `module`, `types`,
+/// `typings`, `bin`, and `exports` fields of the nearest `package.json`,
+/// together with the files matching the globs configured by the user.
+pub(crate) struct EntryPoints<'a> {
+    /// Path to the directory of the package, if a `package.json` was found.
+    package_path: Option<&'a Utf8Path>,
+
+    /// Absolute paths of the entry points declared in the `package.json`.
+    manifest_entry_points: Vec<Utf8PathBuf>,
+
+    /// Globs matching additional entry points, relative to the package
+    /// directory.
+    globs: &'a [Glob],
+}
+
+impl<'a> EntryPoints<'a> {
+    pub fn new(manifest: Option<&'a (Utf8PathBuf, Arc<PackageJson>)>, globs: &'a [Glob]) -> Self {
+        let package_path = manifest.map(|(package_path, _)| package_path.as_path());
+        let manifest_entry_points = manifest
+            .map(|(package_path, manifest)| {
+                manifest
+                    .entry_points()
+                    .into_iter()
+                    .map(|entry_point| normalize_path(&package_path.join(entry_point)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            package_path,
+            manifest_entry_points,
+            globs,
+        }
+    }
+
+    /// Returns whether no entry points are declared or configured.
+    pub fn is_empty(&self) -> bool {
+        self.manifest_entry_points.is_empty() && self.globs.is_empty()
+    }
+
+    /// Returns whether the file at `path` is an entry point.
+    pub fn contains(&self, path: &Utf8Path) -> bool {
+        if self
+            .manifest_entry_points
+            .iter()
+            .any(|entry_point| entry_point == path)
+        {
+            return true;
+        }
+
+        if self.globs.is_empty() {
+            return false;
+        }
+
+        let relative_path = self
+            .package_path
+            .and_then(|package_path| path.strip_prefix(package_path).ok())
+            .unwrap_or(path);
+        CandidatePath::new(relative_path).matches_with_exceptions(self.globs)
+    }
+
+    /// Returns the paths of all the modules in the `module_graph` that are
+    /// reachable from the entry points.
+    ///
+    /// The module graph caches the result until it's updated, so that it's
+    /// computed once for all the files that share the same entry points.
+    pub fn reachable_paths(&self, module_graph: &ModuleGraph) -> Arc<BTreeSet<Utf8PathBuf>> {
+        module_graph.reachable_paths(
+            (self.package_path, &self.manifest_entry_points, self.globs),
+            |path| self.contains(path),
+        )
+    }
+}
@@ -0,0 +1,17 @@
+function log(message: string): void {
+	console.log(message);
+}
+
+const response = log("hello");
+
+function handle(message: string) {
+	return log(message);
+}
+
+const value = log("a") || "fallback";
+
+const conditional = log("a") ? 1 : 2;
+
+takesValue(log("a"));
+
+function takesValue(value: unknown) {}
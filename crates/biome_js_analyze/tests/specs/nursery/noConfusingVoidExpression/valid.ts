@@ -0,0 +1,20 @@
+/* should not generate diagnostics */
+function log(message: string): void {
+	console.log(message);
+}
+
+log("hello");
+
+const handle = (message: string) => log(message);
+
+void log("hello");
+
+(log("a"), log("b"));
+
+condition ? log("a") : log("b");
+
+condition && log("a");
+
+for (log("a"); condition; log("b")) {}
+
+declare const condition: boolean;
@@ -345,3 +345,41 @@ function returnMaybePromise(): Promise<void> | undefined {
 }
 
 returnMaybePromise();
+
+fetch("/api/users");
+
+navigator.clipboard.writeText("text");
+
+function withRetry<T>(fn: () => Promise<T>): Promise<T> {
+	return fn();
+}
+
+withRetry(() => fetch("/api/users"));
+
+class Deferred<T> {
+	value: T;
+}
+
+declare const deferred: Deferred<() => Promise<void>>;
+deferred.value();
+
+type Wrapper<T> = { value: T };
+
+declare const wrapper: Wrapper<Promise<number>>;
+wrapper.value;
+
+function unwrap<T>(wrapper: Wrapper<T>): T {
+	return wrapper.value;
+}
+
+unwrap({ value: fetch("/api/users") });
+
+class Holder<T> {
+	constructor(private value: T) {}
+
+	get(): T {
+		return this.value;
+	}
+}
+
+new Holder<Promise<number>>(Promise.resolve(1)).get();
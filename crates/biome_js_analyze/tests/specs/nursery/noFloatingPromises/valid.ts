@@ -248,3 +248,9 @@ async function testDestructuringAndCallingReturnsPromiseFromRest({
 }: Props) {
   rest.returnsPromise().catch(() => {}).finally(() => {});
 }
+
+async function testAwaitingLibFunctions() {
+	await fetch("/api/users");
+	await navigator.clipboard.writeText("text".toUpperCase());
+	new Map().set("key", "value");
+}
@@ -0,0 +1,39 @@
+async function fetchValue(): Promise<string> {
+	return "value";
+}
+
+const promise = fetchValue();
+
+if (promise) {
+}
+
+while (promise) {
+}
+
+do {
+} while (promise);
+
+for (; promise; ) {
+}
+
+const ternary = promise ? 1 : 2;
+
+const and = promise && 1;
+
+const not = !promise;
+
+[1, 2].forEach(async (n) => {
+	await fetchValue();
+});
+
+const values: string[] = [];
+
+values.forEach(fetchValue);
+
+function forEachValue(first: string, callback: (value: string) => void): void {
+	callback(first);
+}
+
+forEachValue(...["value", fetchValue]);
+
+forEachValue("value", ...[fetchValue]);
@@ -0,0 +1,32 @@
+/* should not generate diagnostics */
+async function fetchValue(): Promise<string> {
+	return "value";
+}
+
+const promise = fetchValue();
+
+if (await promise) {
+}
+
+const nullish = promise ?? 1;
+
+function map(callback: (value: string) => unknown): void {
+	callback("value");
+}
+
+map(async (value) => {
+	await fetchValue();
+});
+
+[1, 2].forEach((n) => {
+	console.log(n);
+});
+
+
+function forEachValue(first: string, callback: (value: string) => void): void {
+	callback(first);
+}
+
+const args: string[] = [];
+
+forEachValue(...args, fetchValue);
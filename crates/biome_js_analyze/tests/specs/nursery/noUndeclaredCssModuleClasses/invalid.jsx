@@ -0,0 +1,6 @@
+import styles from "./styles.module.css";
+import * as namespace from "./styles.module.css";
+
+export const Button = () => (
+  <button className={`${styles.buton} ${styles["missing"]} ${namespace.legacy}`} />
+);
@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+import styles from "./styles.module.css";
+import dynamicStyles from "./dynamic.module.scss";
+
+export const Button = ({ variant }) => (
+  <main className={styles.main}>
+    <button
+      className={`${styles.button} ${styles.primaryButton} ${styles["primary-button"]} ${styles.fadeIn}`}
+    />
+    <button className={`${dynamicStyles.buttonPrimary} ${styles[variant]}`} />
+  </main>
+);
@@ -0,0 +1,27 @@
+function head(items: Array<string>) {
+	if (items) {
+		return items[0];
+	}
+}
+
+function handle(callback: () => void) {
+	return callback && callback();
+}
+
+function greet(user: { name: string }) {
+	return user ?? { name: "anonymous" };
+}
+
+function loop(pattern: RegExp) {
+	while (pattern) {
+		break;
+	}
+}
+
+function ternary(value: { name: string }) {
+	return value ? 1 : 2;
+}
+
+function nothing(): void {}
+
+const fallback = nothing() ?? "fallback";
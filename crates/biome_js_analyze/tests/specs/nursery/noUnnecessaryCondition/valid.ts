@@ -0,0 +1,35 @@
+/* should not generate diagnostics */
+function head(items: Array<string>) {
+	if (items.length > 0) {
+		return items[0];
+	}
+}
+
+function handle(callback?: () => void) {
+	return callback && callback();
+}
+
+function greet(user: { name: string } | undefined) {
+	return user ?? { name: "anonymous" };
+}
+
+function optional(user: { profile?: { name: string } }) {
+	if (user.profile?.name) {
+		return user.profile.name;
+	}
+}
+
+function indexed(items: Array<{ name: string }>) {
+	if (items[0]) {
+		return items[0].name;
+	}
+}
+
+let later = null;
+if (later) {
+}
+
+function wrapper(value: String) {
+	if (value) {
+	}
+}
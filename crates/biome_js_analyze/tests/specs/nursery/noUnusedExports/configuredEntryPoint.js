@@ -0,0 +1,3 @@
+import { used } from "./utils.js";
+
+export const unusedInConfiguredProject = 1;
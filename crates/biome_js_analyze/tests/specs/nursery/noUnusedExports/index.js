@@ -0,0 +1,7 @@
+/* should not generate diagnostics */
+import { used, usedByDefault } from "./utils.js";
+import * as namespace from "./namespace.js";
+
+export { reexported } from "./reexported.js";
+export * from "./blanket.js";
+export const entryPointExport = 1;
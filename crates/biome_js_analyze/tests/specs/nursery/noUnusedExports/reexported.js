@@ -0,0 +1,3 @@
+export const reexported = 1;
+
+export const notReexported = 2;
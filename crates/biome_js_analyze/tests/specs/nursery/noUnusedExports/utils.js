@@ -0,0 +1,12 @@
+export function used() {}
+
+export function unused() {}
+
+export const { destructured, withDefault = (parameter) => parameter } = {};
+
+export class UnusedClass {}
+
+const local = 1;
+export { local as renamed, local as usedByDefault };
+
+export default function () {}
@@ -0,0 +1,2 @@
+/* should not generate diagnostics */
+import { used } from "./used.js";
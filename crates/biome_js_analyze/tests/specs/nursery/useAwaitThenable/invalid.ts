@@ -0,0 +1,15 @@
+async function test() {
+	await "value";
+
+	await 123;
+
+	function createValue(): string {
+		return "value";
+	}
+	await createValue();
+
+	await { value: 1 };
+
+	// leading comment
+	await /* inner comment */ createValue();
+}
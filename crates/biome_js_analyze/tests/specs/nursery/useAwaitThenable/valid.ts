@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+async function test() {
+	await Promise.resolve("value");
+
+	const createValue = async () => "value";
+	await createValue();
+
+	await { then() {} };
+
+	let pending = null;
+	await pending;
+}
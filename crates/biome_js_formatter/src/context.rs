@@ -1,5 +1,6 @@
 pub mod trailing_commas;
 
+use crate::EmbeddedTemplateFormatter;
 use crate::comments::{FormatJsLeadingComment, JsCommentStyle, JsComments};
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::printer::PrinterOptions;
@@ -45,6 +46,8 @@ pub struct JsFormatContext {
     cached_function_body: Option<(AnyJsFunctionBody, FormatElement)>,
 
     source_map: Option<TransformSourceMap>,
+
+    embedded_template_formatter: Option<Rc<dyn EmbeddedTemplateFormatter>>,
 }
 
 impl JsFormatContext {
@@ -54,6 +57,7 @@ impl JsFormatContext {
             comments: Rc::new(comments),
             cached_function_body: None,
             source_map: None,
+            embedded_template_formatter: None,
         }
     }
 
@@ -91,6 +95,18 @@ impl JsFormatContext {
         self.source_map = source_map;
         self
     }
+
+    pub fn with_embedded_template_formatter(
+        mut self,
+        embedded_template_formatter: Option<Rc<dyn EmbeddedTemplateFormatter>>,
+    ) -> Self {
+        self.embedded_template_formatter = embedded_template_formatter;
+        self
+    }
+
+    pub(crate) fn embedded_template_formatter(&self) -> Option<&dyn EmbeddedTemplateFormatter> {
+        self.embedded_template_formatter.as_deref()
+    }
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
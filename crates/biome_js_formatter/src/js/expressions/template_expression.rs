@@ -1,9 +1,12 @@
 use crate::js::lists::template_element_list::FormatJsTemplateElementListOptions;
 use crate::prelude::*;
 
+use crate::FormattedEmbeddedTemplate;
 use biome_formatter::write;
 use biome_js_syntax::parentheses::NeedsParentheses;
-use biome_js_syntax::{AnyJsExpression, JsTemplateExpression, TsTemplateLiteralType};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsTemplateElement, JsTemplateExpression, TsTemplateLiteralType,
+};
 use biome_js_syntax::{JsSyntaxToken, TsTypeArguments};
 use biome_rowan::{SyntaxResult, declare_node_union};
 
@@ -26,6 +29,27 @@ declare_node_union! {
 
 impl Format<JsFormatContext> for AnyJsTemplate {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        if let Self::JsTemplateExpression(template) = self {
+            let embedded = f
+                .context()
+                .embedded_template_formatter()
+                .and_then(|formatter| formatter.format(template))
+                .filter(|formatted| {
+                    let expressions = template
+                        .elements()
+                        .iter()
+                        .filter(|element| {
+                            matches!(element, AnyJsTemplateElement::JsTemplateElement(_))
+                        })
+                        .count();
+                    formatted.placeholders.len() == expressions
+                })
+                .and_then(|formatted| EmbeddedTemplateLines::new(&formatted));
+            if let Some(lines) = embedded {
+                return FormatEmbeddedTemplate { template, lines }.fmt(f);
+            }
+        }
+
         write!(
             f,
             [
@@ -87,3 +111,153 @@ impl AnyJsTemplate {
         }
     }
 }
+
+/// A segment of a line of a template literal formatted by an
+/// [EmbeddedTemplateFormatter](crate::EmbeddedTemplateFormatter).
+#[derive(Debug)]
+enum EmbeddedSegment {
+    Text(String),
+    /// The expression of the template at the given index
+    Expression(usize),
+}
+
+/// The lines of a formatted template literal, in which the placeholders have been located.
+#[derive(Debug)]
+struct EmbeddedTemplateLines(Vec<Vec<EmbeddedSegment>>);
+
+impl EmbeddedTemplateLines {
+    /// Splits the formatted `code` in lines of text and expressions.
+    ///
+    /// Returns `None` if the placeholders can't be found in the same order as the expressions,
+    /// in which case the template is printed as it is.
+    fn new(formatted: &FormattedEmbeddedTemplate) -> Option<Self> {
+        let code = normalize_newlines(&formatted.code, ['\r']);
+        let code = code.trim_matches('\n');
+        let mut lines = vec![Vec::new()];
+        let push_text = |lines: &mut Vec<Vec<EmbeddedSegment>>, text: &str| {
+            for (index, line) in text.split('\n').enumerate() {
+                if index > 0 {
+                    lines.push(Vec::new());
+                }
+                if !line.is_empty() {
+                    let current = lines.last_mut().expect("lines to not be empty");
+                    current.push(EmbeddedSegment::Text(line.to_string()));
+                }
+            }
+        };
+
+        let mut rest = code;
+        for (index, placeholder) in formatted.placeholders.iter().enumerate() {
+            let start = rest.find(placeholder.as_str())?;
+            push_text(&mut lines, &rest[..start]);
+            lines
+                .last_mut()
+                .expect("lines to not be empty")
+                .push(EmbeddedSegment::Expression(index));
+            rest = &rest[start + placeholder.len()..];
+        }
+        push_text(&mut lines, rest);
+
+        // Trailing whitespace is removed, unless it precedes an expression
+        for line in &mut lines {
+            if let Some(EmbeddedSegment::Text(text)) = line.last_mut() {
+                text.truncate(text.trim_end().len());
+                if text.is_empty() {
+                    line.pop();
+                }
+            }
+        }
+
+        Some(Self(lines))
+    }
+}
+
+/// Prints a template literal with its formatted content, indented on its own lines:
+///
+/// ```js
+/// const Button = styled.button`
+///   color: ${color};
+/// `;
+/// ```
+struct FormatEmbeddedTemplate<'a> {
+    template: &'a JsTemplateExpression,
+    lines: EmbeddedTemplateLines,
+}
+
+impl Format<JsFormatContext> for FormatEmbeddedTemplate<'_> {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        let template = self.template;
+        let l_tick_token = template.l_tick_token()?;
+        write!(
+            f,
+            [
+                template.tag().format(),
+                template.type_arguments().format(),
+                line_suffix_boundary(),
+                l_tick_token.format(),
+            ]
+        )?;
+
+        let mut expressions = Vec::new();
+        for element in template.elements() {
+            // The elements are printed as part of the formatted content
+            f.comments().mark_suppression_checked(element.syntax());
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    write!(f, [format_removed(&chunk.template_chunk_token()?)])?;
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => expressions.push(element),
+            }
+        }
+
+        let position = l_tick_token.text_trimmed_range().end();
+        let format_lines = format_with(|f| {
+            let mut has_blank_line = false;
+            let mut is_first = true;
+            for line in &self.lines.0 {
+                if line.is_empty() {
+                    has_blank_line = true;
+                    continue;
+                }
+
+                if !is_first {
+                    if has_blank_line {
+                        write!(f, [empty_line()])?;
+                    } else {
+                        write!(f, [hard_line_break()])?;
+                    }
+                }
+                is_first = false;
+                has_blank_line = false;
+
+                for segment in line {
+                    match segment {
+                        EmbeddedSegment::Text(text) => {
+                            write!(f, [dynamic_text(text, position)])?;
+                        }
+                        EmbeddedSegment::Expression(index) => {
+                            let element = &expressions[*index];
+                            write!(
+                                f,
+                                [
+                                    element.dollar_curly_token().format(),
+                                    element.expression().format(),
+                                    line_suffix_boundary(),
+                                    element.r_curly_token().format()
+                                ]
+                            )?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        if self.lines.0.iter().any(|line| !line.is_empty()) {
+            write!(f, [block_indent(&format_lines)])?;
+        }
+
+        write!(f, [template.r_tick_token().format()])
+    }
+}
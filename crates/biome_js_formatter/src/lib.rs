@@ -189,9 +189,12 @@ use biome_formatter::{
 };
 use biome_js_syntax::{
     AnyJsDeclaration, AnyJsStatement, JsLanguage, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsTemplateExpression,
 };
 use biome_rowan::TextRange;
 use biome_rowan::{AstNode, SyntaxNode};
+use std::fmt;
+use std::rc::Rc;
 
 use crate::comments::JsCommentStyle;
 use crate::context::{JsFormatContext, JsFormatOptions};
@@ -453,13 +456,48 @@ impl IntoFormat<JsFormatContext> for JsSyntaxToken {
     }
 }
 
+/// Formats the content of the template literals that embed code written in another language,
+/// such as the CSS of ``styled.div`...` `` or the GraphQL query of ``gql`...` ``.
+///
+/// The JavaScript formatter doesn't know how to format other languages. Template literals are
+/// printed as they are unless a formatter is provided with
+/// [JsFormatLanguage::with_embedded_template_formatter].
+pub trait EmbeddedTemplateFormatter: fmt::Debug {
+    /// Returns the formatted content of `template`, or `None` if the template doesn't embed a
+    /// supported language or if its content can't be formatted.
+    fn format(&self, template: &JsTemplateExpression) -> Option<FormattedEmbeddedTemplate>;
+}
+
+/// The content of a template literal, formatted by an [EmbeddedTemplateFormatter].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FormattedEmbeddedTemplate {
+    /// The formatted content, without the backticks, in which every expression of the template
+    /// is replaced by its placeholder.
+    pub code: String,
+
+    /// The placeholders of the expressions of the template, in the order of the expressions.
+    pub placeholders: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct JsFormatLanguage {
     options: JsFormatOptions,
+    embedded_template_formatter: Option<Rc<dyn EmbeddedTemplateFormatter>>,
 }
 impl JsFormatLanguage {
     pub fn new(options: JsFormatOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            embedded_template_formatter: None,
+        }
+    }
+
+    pub fn with_embedded_template_formatter(
+        mut self,
+        embedded_template_formatter: Rc<dyn EmbeddedTemplateFormatter>,
+    ) -> Self {
+        self.embedded_template_formatter = Some(embedded_template_formatter);
+        self
     }
 }
 
@@ -501,7 +539,9 @@ impl FormatLanguage for JsFormatLanguage {
         source_map: Option<TransformSourceMap>,
     ) -> Self::Context {
         let comments = Comments::from_node(root, &JsCommentStyle, source_map.as_ref());
-        JsFormatContext::new(self.options, comments).with_source_map(source_map)
+        JsFormatContext::new(self.options, comments)
+            .with_source_map(source_map)
+            .with_embedded_template_formatter(self.embedded_template_formatter)
     }
 }
 
@@ -534,6 +574,22 @@ pub fn format_node(
     biome_formatter::format_node(root, JsFormatLanguage::new(options))
 }
 
+/// Formats a JavaScript (and its super languages) file, using `embedded_template_formatter` to
+/// format the content of the template literals that embed code written in another language.
+///
+/// It returns a [Formatted] result, which the user can use to override a file.
+pub fn format_node_with_embedded_templates(
+    options: JsFormatOptions,
+    root: &JsSyntaxNode,
+    embedded_template_formatter: Rc<dyn EmbeddedTemplateFormatter>,
+) -> FormatResult<Formatted<JsFormatContext>> {
+    biome_formatter::format_node(
+        root,
+        JsFormatLanguage::new(options)
+            .with_embedded_template_formatter(embedded_template_formatter),
+    )
+}
+
 /// Formats a single node within a file, supported by Biome.
 ///
 /// This runs a simple heuristic to determine the initial indentation
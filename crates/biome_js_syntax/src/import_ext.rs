@@ -199,6 +199,33 @@ impl AnyJsNamedImportSpecifier {
         }
     }
 
+    /// Name of the symbol imported by this specifier, as it's exported by the
+    /// imported module.
+    ///
+    /// Unlike [Self::imported_name], this returns `a` for `import { a as b }`:
+    ///
+    /// ```
+    /// use biome_js_factory::make;
+    /// use biome_js_syntax::{AnyJsNamedImportSpecifier, T};
+    ///
+    /// let name = make::js_literal_export_name(make::ident("a"));
+    /// let binding = make::js_identifier_binding(make::ident("b"));
+    /// let specifier =
+    ///     make::js_named_import_specifier(name, make::token(T![as]), binding.into()).build();
+    /// let specifier = AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier);
+    ///
+    /// assert_eq!(specifier.imported_name().unwrap().text_trimmed(), "b");
+    /// assert_eq!(specifier.imported_symbol_name().unwrap().text_trimmed(), "a");
+    /// ```
+    pub fn imported_symbol_name(&self) -> Option<JsSyntaxToken> {
+        match self {
+            Self::JsNamedImportSpecifier(specifier) => specifier.name().ok()?.value().ok(),
+            Self::JsShorthandNamedImportSpecifier(_) | Self::JsBogusNamedImportSpecifier(_) => {
+                self.imported_name()
+            }
+        }
+    }
+
     /// Local name of this import specifier
     ///
     /// ```
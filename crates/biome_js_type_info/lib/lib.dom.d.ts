@@ -0,0 +1,388 @@
+// Subset of TypeScript's `lib.dom.d.ts`.
+
+interface Console {
+    assert(condition?: boolean, ...data: any[]): void;
+    clear(): void;
+    count(label?: string): void;
+    debug(...data: any[]): void;
+    dir(item?: any, options?: any): void;
+    error(...data: any[]): void;
+    group(...data: any[]): void;
+    groupEnd(): void;
+    info(...data: any[]): void;
+    log(...data: any[]): void;
+    table(tabularData?: any, properties?: string[]): void;
+    time(label?: string): void;
+    timeEnd(label?: string): void;
+    trace(...data: any[]): void;
+    warn(...data: any[]): void;
+}
+
+declare var console: Console;
+
+interface Event {
+    readonly bubbles: boolean;
+    readonly cancelable: boolean;
+    readonly defaultPrevented: boolean;
+    readonly target: EventTarget | null;
+    readonly currentTarget: EventTarget | null;
+    readonly timeStamp: number;
+    readonly type: string;
+    preventDefault(): void;
+    stopImmediatePropagation(): void;
+    stopPropagation(): void;
+}
+
+interface EventConstructor {
+    new (type: string, eventInitDict?: any): Event;
+    readonly prototype: Event;
+}
+
+declare var Event: EventConstructor;
+
+interface EventTarget {
+    addEventListener(type: string, callback: ((event: Event) => void) | null, options?: any): void;
+    dispatchEvent(event: Event): boolean;
+    removeEventListener(type: string, callback: ((event: Event) => void) | null, options?: any): void;
+}
+
+interface EventTargetConstructor {
+    new (): EventTarget;
+    readonly prototype: EventTarget;
+}
+
+declare var EventTarget: EventTargetConstructor;
+
+interface AbortSignal extends EventTarget {
+    readonly aborted: boolean;
+    readonly reason: any;
+    throwIfAborted(): void;
+}
+
+interface AbortSignalConstructor {
+    readonly prototype: AbortSignal;
+    abort(reason?: any): AbortSignal;
+    timeout(milliseconds: number): AbortSignal;
+}
+
+declare var AbortSignal: AbortSignalConstructor;
+
+interface AbortController {
+    readonly signal: AbortSignal;
+    abort(reason?: any): void;
+}
+
+interface AbortControllerConstructor {
+    new (): AbortController;
+    readonly prototype: AbortController;
+}
+
+declare var AbortController: AbortControllerConstructor;
+
+interface Blob {
+    readonly size: number;
+    readonly type: string;
+    arrayBuffer(): Promise<ArrayBuffer>;
+    slice(start?: number, end?: number, contentType?: string): Blob;
+    text(): Promise<string>;
+}
+
+interface BlobConstructor {
+    new (blobParts?: any[], options?: any): Blob;
+    readonly prototype: Blob;
+}
+
+declare var Blob: BlobConstructor;
+
+interface Headers {
+    append(name: string, value: string): void;
+    delete(name: string): void;
+    get(name: string): string | null;
+    has(name: string): boolean;
+    set(name: string, value: string): void;
+    forEach(callbackfn: (value: string, key: string, parent: Headers) => void, thisArg?: any): void;
+}
+
+interface HeadersConstructor {
+    new (init?: any): Headers;
+    readonly prototype: Headers;
+}
+
+declare var Headers: HeadersConstructor;
+
+interface RequestInit {
+    body?: any;
+    cache?: string;
+    credentials?: string;
+    headers?: any;
+    method?: string;
+    mode?: string;
+    redirect?: string;
+    signal?: AbortSignal | null;
+}
+
+interface Request {
+    readonly headers: Headers;
+    readonly method: string;
+    readonly url: string;
+    arrayBuffer(): Promise<ArrayBuffer>;
+    blob(): Promise<Blob>;
+    clone(): Request;
+    formData(): Promise<FormData>;
+    json(): Promise<any>;
+    text(): Promise<string>;
+}
+
+interface RequestConstructor {
+    new (input: Request | string | URL, init?: RequestInit): Request;
+    readonly prototype: Request;
+}
+
+declare var Request: RequestConstructor;
+
+interface Response {
+    readonly headers: Headers;
+    readonly ok: boolean;
+    readonly redirected: boolean;
+    readonly status: number;
+    readonly statusText: string;
+    readonly url: string;
+    arrayBuffer(): Promise<ArrayBuffer>;
+    blob(): Promise<Blob>;
+    clone(): Response;
+    formData(): Promise<FormData>;
+    json(): Promise<any>;
+    text(): Promise<string>;
+}
+
+interface ResponseConstructor {
+    new (body?: any, init?: any): Response;
+    readonly prototype: Response;
+    error(): Response;
+    json(data: any, init?: any): Response;
+    redirect(url: string | URL, status?: number): Response;
+}
+
+declare var Response: ResponseConstructor;
+
+interface FormData {
+    append(name: string, value: string | Blob): void;
+    delete(name: string): void;
+    get(name: string): any;
+    getAll(name: string): any[];
+    has(name: string): boolean;
+    set(name: string, value: string | Blob): void;
+}
+
+interface FormDataConstructor {
+    new (form?: HTMLFormElement): FormData;
+    readonly prototype: FormData;
+}
+
+declare var FormData: FormDataConstructor;
+
+interface URL {
+    hash: string;
+    host: string;
+    hostname: string;
+    href: string;
+    readonly origin: string;
+    password: string;
+    pathname: string;
+    port: string;
+    protocol: string;
+    search: string;
+    readonly searchParams: URLSearchParams;
+    username: string;
+    toJSON(): string;
+    toString(): string;
+}
+
+interface URLConstructor {
+    new (url: string | URL, base?: string | URL): URL;
+    readonly prototype: URL;
+    canParse(url: string | URL, base?: string): boolean;
+}
+
+declare var URL: URLConstructor;
+
+interface URLSearchParams {
+    readonly size: number;
+    append(name: string, value: string): void;
+    delete(name: string, value?: string): void;
+    get(name: string): string | null;
+    getAll(name: string): string[];
+    has(name: string, value?: string): boolean;
+    set(name: string, value: string): void;
+    sort(): void;
+    toString(): string;
+    forEach(callbackfn: (value: string, key: string, parent: URLSearchParams) => void, thisArg?: any): void;
+}
+
+interface URLSearchParamsConstructor {
+    new (init?: string[][] | string | URLSearchParams): URLSearchParams;
+    readonly prototype: URLSearchParams;
+}
+
+declare var URLSearchParams: URLSearchParamsConstructor;
+
+interface Storage {
+    readonly length: number;
+    clear(): void;
+    getItem(key: string): string | null;
+    key(index: number): string | null;
+    removeItem(key: string): void;
+    setItem(key: string, value: string): void;
+}
+
+declare var localStorage: Storage;
+declare var sessionStorage: Storage;
+
+interface Node extends EventTarget {
+    readonly childNodes: NodeList;
+    readonly firstChild: Node | null;
+    readonly lastChild: Node | null;
+    readonly nextSibling: Node | null;
+    readonly nodeName: string;
+    readonly nodeType: number;
+    readonly parentElement: HTMLElement | null;
+    readonly parentNode: Node | null;
+    readonly previousSibling: Node | null;
+    textContent: string | null;
+    appendChild<T extends Node>(node: T): T;
+    cloneNode(deep?: boolean): Node;
+    contains(other: Node | null): boolean;
+    hasChildNodes(): boolean;
+    insertBefore<T extends Node>(node: T, child: Node | null): T;
+    removeChild<T extends Node>(child: T): T;
+    replaceChild<T extends Node>(node: Node, child: T): T;
+}
+
+interface NodeList {
+    readonly length: number;
+    item(index: number): Node | null;
+    forEach(callbackfn: (value: Node, key: number, parent: NodeList) => void, thisArg?: any): void;
+}
+
+interface DOMTokenList {
+    readonly length: number;
+    value: string;
+    add(...tokens: string[]): void;
+    contains(token: string): boolean;
+    remove(...tokens: string[]): void;
+    replace(token: string, newToken: string): boolean;
+    toggle(token: string, force?: boolean): boolean;
+}
+
+interface Element extends Node {
+    readonly classList: DOMTokenList;
+    className: string;
+    id: string;
+    innerHTML: string;
+    outerHTML: string;
+    readonly tagName: string;
+    closest(selectors: string): Element | null;
+    getAttribute(qualifiedName: string): string | null;
+    getBoundingClientRect(): any;
+    hasAttribute(qualifiedName: string): boolean;
+    matches(selectors: string): boolean;
+    querySelector(selectors: string): Element | null;
+    querySelectorAll(selectors: string): NodeList;
+    remove(): void;
+    removeAttribute(qualifiedName: string): void;
+    requestFullscreen(options?: any): Promise<void>;
+    setAttribute(qualifiedName: string, value: string): void;
+    toggleAttribute(qualifiedName: string, force?: boolean): boolean;
+}
+
+interface HTMLElement extends Element {
+    hidden: boolean;
+    innerText: string;
+    readonly offsetHeight: number;
+    readonly offsetWidth: number;
+    title: string;
+    blur(): void;
+    click(): void;
+    focus(options?: any): void;
+}
+
+interface HTMLFormElement extends HTMLElement {
+    action: string;
+    method: string;
+    reset(): void;
+    submit(): void;
+}
+
+interface HTMLMediaElement extends HTMLElement {
+    currentTime: number;
+    readonly duration: number;
+    readonly paused: boolean;
+    src: string;
+    volume: number;
+    load(): void;
+    pause(): void;
+    play(): Promise<void>;
+}
+
+interface Document extends Node {
+    readonly body: HTMLElement;
+    readonly documentElement: HTMLElement;
+    readonly head: HTMLElement;
+    title: string;
+    createElement(tagName: string, options?: any): HTMLElement;
+    createTextNode(data: string): Node;
+    getElementById(elementId: string): HTMLElement | null;
+    getElementsByClassName(classNames: string): any;
+    getElementsByTagName(qualifiedName: string): any;
+    querySelector(selectors: string): Element | null;
+    querySelectorAll(selectors: string): NodeList;
+    exitFullscreen(): Promise<void>;
+}
+
+declare var document: Document;
+
+interface Location {
+    hash: string;
+    host: string;
+    hostname: string;
+    href: string;
+    readonly origin: string;
+    pathname: string;
+    port: string;
+    protocol: string;
+    search: string;
+    assign(url: string | URL): void;
+    reload(): void;
+    replace(url: string | URL): void;
+}
+
+declare var location: Location;
+
+interface Clipboard extends EventTarget {
+    readText(): Promise<string>;
+    writeText(data: string): Promise<void>;
+}
+
+interface Navigator {
+    readonly clipboard: Clipboard;
+    readonly language: string;
+    readonly onLine: boolean;
+    readonly userAgent: string;
+}
+
+declare var navigator: Navigator;
+
+declare function alert(message?: any): void;
+declare function confirm(message?: string): boolean;
+declare function prompt(message?: string, _default?: string): string | null;
+declare function fetch(input: Request | string | URL, init?: RequestInit): Promise<Response>;
+declare function setTimeout(handler: (...args: any[]) => void, timeout?: number, ...arguments: any[]): number;
+declare function clearTimeout(id: number | undefined): void;
+declare function setInterval(handler: (...args: any[]) => void, timeout?: number, ...arguments: any[]): number;
+declare function clearInterval(id: number | undefined): void;
+declare function queueMicrotask(callback: () => void): void;
+declare function requestAnimationFrame(callback: (time: number) => void): number;
+declare function cancelAnimationFrame(handle: number): void;
+declare function structuredClone<T = any>(value: T, options?: any): T;
+declare function atob(data: string): string;
+declare function btoa(data: string): string;
@@ -0,0 +1,19 @@
+// Subset of TypeScript's `lib.dom.iterable.d.ts`.
+
+interface Headers {
+    entries(): IterableIterator<[string, string]>;
+    keys(): IterableIterator<string>;
+    values(): IterableIterator<string>;
+}
+
+interface NodeList {
+    entries(): IterableIterator<[number, Node]>;
+    keys(): IterableIterator<number>;
+    values(): IterableIterator<Node>;
+}
+
+interface URLSearchParams {
+    entries(): IterableIterator<[string, string]>;
+    keys(): IterableIterator<string>;
+    values(): IterableIterator<string>;
+}
@@ -0,0 +1,70 @@
+// Subset of TypeScript's `lib.es2015.core.d.ts`.
+
+interface Array<T> {
+    find(predicate: (value: T, index: number, obj: T[]) => unknown, thisArg?: any): T | undefined;
+    findIndex(predicate: (value: T, index: number, obj: T[]) => unknown, thisArg?: any): number;
+    fill(value: T, start?: number, end?: number): this;
+    copyWithin(target: number, start: number, end?: number): this;
+}
+
+interface ArrayConstructor {
+    from<T>(arrayLike: ArrayLike<T>): T[];
+    of<T>(...items: T[]): T[];
+}
+
+interface Function {
+    readonly name: string;
+}
+
+interface Math {
+    clz32(x: number): number;
+    imul(x: number, y: number): number;
+    sign(x: number): number;
+    log10(x: number): number;
+    log2(x: number): number;
+    log1p(x: number): number;
+    expm1(x: number): number;
+    cosh(x: number): number;
+    sinh(x: number): number;
+    tanh(x: number): number;
+    acosh(x: number): number;
+    asinh(x: number): number;
+    atanh(x: number): number;
+    hypot(...values: number[]): number;
+    trunc(x: number): number;
+    fround(x: number): number;
+    cbrt(x: number): number;
+}
+
+interface NumberConstructor {
+    readonly EPSILON: number;
+    isFinite(number: unknown): boolean;
+    isInteger(number: unknown): boolean;
+    isNaN(number: unknown): boolean;
+    isSafeInteger(number: unknown): boolean;
+    readonly MAX_SAFE_INTEGER: number;
+    readonly MIN_SAFE_INTEGER: number;
+    parseFloat(string: string): number;
+    parseInt(string: string, radix?: number): number;
+}
+
+interface ObjectConstructor {
+    assign(target: object, ...sources: any[]): any;
+    getOwnPropertySymbols(o: any): symbol[];
+    is(value1: any, value2: any): boolean;
+    setPrototypeOf(o: any, proto: object | null): any;
+}
+
+interface String {
+    codePointAt(pos: number): number | undefined;
+    includes(searchString: string, position?: number): boolean;
+    endsWith(searchString: string, endPosition?: number): boolean;
+    normalize(form?: string): string;
+    repeat(count: number): string;
+    startsWith(searchString: string, position?: number): boolean;
+}
+
+interface StringConstructor {
+    fromCodePoint(...codePoints: number[]): string;
+    raw(template: { raw: readonly string[] }, ...substitutions: any[]): string;
+}
@@ -0,0 +1,12 @@
+// Subset of TypeScript's `lib.es2015.promise.d.ts`.
+
+interface PromiseConstructor {
+    readonly prototype: Promise<any>;
+    new <T>(executor: (resolve: (value: T | PromiseLike<T>) => void, reject: (reason?: any) => void) => void): Promise<T>;
+    all<T>(values: Iterable<T | PromiseLike<T>>): Promise<Awaited<T>[]>;
+    race<T>(values: Iterable<T | PromiseLike<T>>): Promise<Awaited<T>>;
+    reject<T = never>(reason?: any): Promise<T>;
+    resolve<T>(value: T | PromiseLike<T>): Promise<Awaited<T>>;
+}
+
+declare var Promise: PromiseConstructor;
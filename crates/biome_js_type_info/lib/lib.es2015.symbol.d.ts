@@ -0,0 +1,18 @@
+// Subset of TypeScript's `lib.es2015.symbol.d.ts`.
+
+interface Symbol {
+    toString(): string;
+    valueOf(): symbol;
+    readonly description: string | undefined;
+}
+
+interface SymbolConstructor {
+    readonly prototype: Symbol;
+    (description?: string | number): symbol;
+    for(key: string): symbol;
+    keyFor(sym: symbol): string | undefined;
+    readonly asyncIterator: unique symbol;
+    readonly iterator: unique symbol;
+}
+
+declare var Symbol: SymbolConstructor;
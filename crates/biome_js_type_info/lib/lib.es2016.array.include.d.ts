@@ -0,0 +1,9 @@
+// Subset of TypeScript's `lib.es2016.array.include.d.ts`.
+
+interface Array<T> {
+    includes(searchElement: T, fromIndex?: number): boolean;
+}
+
+interface ReadonlyArray<T> {
+    includes(searchElement: T, fromIndex?: number): boolean;
+}
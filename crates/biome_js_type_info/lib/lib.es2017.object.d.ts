@@ -0,0 +1,7 @@
+// Subset of TypeScript's `lib.es2017.object.d.ts`.
+
+interface ObjectConstructor {
+    values(o: {}): any[];
+    entries(o: {}): [string, any][];
+    getOwnPropertyDescriptors<T>(o: T): any;
+}
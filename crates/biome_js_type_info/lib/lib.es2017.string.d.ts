@@ -0,0 +1,6 @@
+// Subset of TypeScript's `lib.es2017.string.d.ts`.
+
+interface String {
+    padStart(maxLength: number, fillString?: string): string;
+    padEnd(maxLength: number, fillString?: string): string;
+}
@@ -0,0 +1,5 @@
+// Subset of TypeScript's `lib.es2018.promise.d.ts`.
+
+interface Promise<T> {
+    finally(onfinally?: (() => void) | undefined | null): Promise<T>;
+}
@@ -0,0 +1,11 @@
+// Subset of TypeScript's `lib.es2019.array.d.ts`.
+
+interface Array<T> {
+    flatMap<U>(callback: (value: T, index: number, array: T[]) => U | ReadonlyArray<U>, thisArg?: any): U[];
+    flat(depth?: number): any[];
+}
+
+interface ReadonlyArray<T> {
+    flatMap<U>(callback: (value: T, index: number, array: T[]) => U | ReadonlyArray<U>, thisArg?: any): U[];
+    flat(depth?: number): any[];
+}
@@ -0,0 +1,5 @@
+// Subset of TypeScript's `lib.es2019.object.d.ts`.
+
+interface ObjectConstructor {
+    fromEntries(entries: Iterable<readonly any[]>): any;
+}
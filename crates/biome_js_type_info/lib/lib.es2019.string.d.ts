@@ -0,0 +1,6 @@
+// Subset of TypeScript's `lib.es2019.string.d.ts`.
+
+interface String {
+    trimEnd(): string;
+    trimStart(): string;
+}
@@ -0,0 +1,5 @@
+// Subset of TypeScript's `lib.es2021.string.d.ts`.
+
+interface String {
+    replaceAll(searchValue: string | RegExp, replaceValue: string): string;
+}
@@ -0,0 +1,9 @@
+// Subset of TypeScript's `lib.es2022.array.d.ts`.
+
+interface Array<T> {
+    at(index: number): T | undefined;
+}
+
+interface ReadonlyArray<T> {
+    at(index: number): T | undefined;
+}
@@ -0,0 +1,5 @@
+// Subset of TypeScript's `lib.es2022.object.d.ts`.
+
+interface ObjectConstructor {
+    hasOwn(o: object, v: PropertyKey): boolean;
+}
@@ -0,0 +1,5 @@
+// Subset of TypeScript's `lib.es2022.string.d.ts`.
+
+interface String {
+    at(index: number): string | undefined;
+}
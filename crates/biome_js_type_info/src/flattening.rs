@@ -6,16 +6,17 @@ use std::{
 use biome_rowan::Text;
 
 use crate::{
-    CallArgumentType, DestructureField, GLOBAL_UNKNOWN_ID, Literal, ResolvedTypeData,
-    ResolvedTypeMember, ResolverId, TypeData, TypeInstance, TypeMemberKind, TypeReference,
-    TypeResolver, TypeofCallExpression, TypeofExpression, TypeofStaticMemberExpression,
+    DestructureField, Function, GLOBAL_UNKNOWN_ID, Literal, ResolvedTypeData, ResolvedTypeMember,
+    ResolverId, TypeData, TypeInstance, TypeMemberKind, TypeReference, TypeResolver,
+    TypeofCallExpression, TypeofExpression, TypeofStaticMemberExpression,
     globals::{
-        GLOBAL_BIGINT_STRING_LITERAL_ID, GLOBAL_BOOLEAN_STRING_LITERAL_ID,
+        GLOBAL_ARRAY_ID, GLOBAL_BIGINT_STRING_LITERAL_ID, GLOBAL_BOOLEAN_STRING_LITERAL_ID,
         GLOBAL_FUNCTION_STRING_LITERAL_ID, GLOBAL_NUMBER_STRING_LITERAL_ID,
         GLOBAL_OBJECT_STRING_LITERAL_ID, GLOBAL_STRING_STRING_LITERAL_ID,
         GLOBAL_SYMBOL_STRING_LITERAL_ID, GLOBAL_TYPEOF_OPERATOR_RETURN_UNION_ID,
         GLOBAL_UNDEFINED_STRING_LITERAL_ID,
     },
+    instantiation::{GenericArguments, generic_instance, instantiate},
 };
 
 impl TypeData {
@@ -188,13 +189,21 @@ fn flattened_expression(
                     callee = ResolvedTypeData::from((resolver_id, data));
                 }
 
-                flattened_function_call(expr, callee, resolver).map(|(is_instance, mut ty)| {
-                    if is_instance {
-                        ty = ty.into_instance(resolver);
+                let ty = match callee.as_raw_data() {
+                    TypeData::Function(function) => {
+                        let function = function.clone();
+                        flattened_function_call(expr, callee.resolver_id(), &function, resolver)
                     }
+                    TypeData::Object(_) => callee
+                        .all_members(resolver)
+                        .find(|member| member.has_name("constructor"))
+                        .map(ResolvedTypeMember::to_member)
+                        .and_then(|member| resolver.resolve_and_get(&member.ty))
+                        .map(ResolvedTypeData::to_data),
+                    _ => None,
+                };
 
-                    flattened(ty, resolver, depth)
-                })
+                ty.map(|ty| flattened(ty, resolver, depth))
             }
             None => None,
         },
@@ -356,6 +365,18 @@ fn flattened_expression(
                         .unwrap_or_else(|| expr.callee.clone());
                     TypeData::instance_of(constructed_ty)
                 }),
+                // Callees with type arguments, such as in `new Foo<Bar>()`,
+                // resolve to an instance of the class with those arguments.
+                Some(TypeData::InstanceOf(instance))
+                    if !instance.type_parameters.is_empty()
+                        && resolver
+                            .resolve_and_get(&instance.ty)
+                            .is_some_and(|resolved| {
+                                matches!(resolved.as_raw_data(), TypeData::Class(_))
+                            }) =>
+                {
+                    Some(TypeData::InstanceOf(instance))
+                }
                 // TODO: Handle objects with call signatures.
                 _ => None,
             }
@@ -446,21 +467,74 @@ fn flattened_expression(
                         return Some(TypeData::union_of(types));
                     }
 
+                    // Tuples share their members with arrays of the union of
+                    // their element types.
+                    TypeData::Tuple(tuple) => {
+                        let elements = tuple
+                            .elements()
+                            .iter()
+                            .filter_map(|element| {
+                                let ty = object.apply_module_id_to_reference(&element.ty);
+                                if !element.is_rest {
+                                    return Some(ty.into_owned());
+                                }
+
+                                resolver
+                                    .resolve_and_get(&ty)
+                                    .filter(|spread| {
+                                        spread.is_instance_of(resolver, GLOBAL_ARRAY_ID)
+                                    })
+                                    .and_then(|spread| spread.get_type_parameter(0))
+                                    .map(Cow::into_owned)
+                            })
+                            .collect();
+                        let elements = TypeData::union_of(elements);
+                        let array = TypeData::instance_of(TypeInstance {
+                            ty: GLOBAL_ARRAY_ID.into(),
+                            type_parameters: Box::new([resolver.reference_to_owned_data(elements)]),
+                        });
+
+                        return Some(TypeData::TypeofExpression(Box::new(
+                            TypeofExpression::StaticMember(TypeofStaticMemberExpression {
+                                object: resolver.reference_to_owned_data(array),
+                                member: expr.member.clone(),
+                            }),
+                        )));
+                    }
+
                     TypeData::Reference(_) => return None,
 
                     _ => {}
                 }
 
                 let is_class = matches!(object.as_raw_data(), TypeData::Class(_));
-                let member = object.all_members(resolver).find(|member| {
-                    member.has_name(&expr.member)
-                        && if is_class {
-                            member.is_static()
-                        } else {
-                            !member.is_static()
-                        }
-                });
-                member.map(|member| TypeData::reference(member.ty().into_owned()))
+                let instance = generic_instance(object, resolver);
+                let member_ty = object
+                    .all_members(resolver)
+                    .find(|member| {
+                        member.has_name(&expr.member)
+                            && if is_class {
+                                member.is_static()
+                            } else {
+                                !member.is_static()
+                            }
+                    })
+                    .map(|member| member.ty().into_owned())?;
+
+                // Members of instances of generic types are instantiated with
+                // the type arguments of the instance.
+                let member_ty = match instance {
+                    Some(instance) => {
+                        let args = GenericArguments::from_type_arguments(
+                            resolver,
+                            &instance.ty,
+                            &instance.type_parameters,
+                        );
+                        instantiate(resolver, &member_ty, &args)
+                    }
+                    None => member_ty,
+                };
+                Some(TypeData::reference(member_ty))
             } else {
                 None
             }
@@ -499,52 +573,20 @@ fn flattened_expression(
 
 fn flattened_function_call(
     expr: &TypeofCallExpression,
-    callee: ResolvedTypeData,
-    resolver: &dyn TypeResolver,
-) -> Option<(bool, TypeData)> {
-    match callee.as_raw_data() {
-        TypeData::Function(function) => function.return_type.as_type().and_then(|return_ty| {
-            let resolved_return_ty =
-                resolver.resolve_and_get(&callee.apply_module_id_to_reference(return_ty))?;
-
-            let (is_generic_instance, mut resolved_return_ty) = match resolved_return_ty
-                .as_raw_data()
-            {
-                TypeData::InstanceOf(instance) if instance.type_parameters.is_empty() => resolver
-                    .resolve_and_get(&resolved_return_ty.apply_module_id_to_reference(&instance.ty))
-                    .filter(|resolved| resolved.is_generic())
-                    .map_or((false, resolved_return_ty), |resolved| (true, resolved)),
-                _ => (false, resolved_return_ty),
-            };
+    resolver_id: ResolverId,
+    function: &Function,
+    resolver: &mut dyn TypeResolver,
+) -> Option<TypeData> {
+    let return_ty = resolver_id.apply_module_id_to_reference(function.return_type.as_type()?);
 
-            if is_generic_instance {
-                // See if we can infer the return type by looking for the
-                // generic in the input arguments.
-                let arg_index = function
-                    .parameters
-                    .iter()
-                    .position(|param| (param.ty == *return_ty))?;
-                let arg = expr.arguments.get(arg_index)?;
-                let reference = match arg {
-                    CallArgumentType::Argument(reference) => reference,
-                    CallArgumentType::Spread(_) => {
-                        return None; // TODO: Handle spread arguments
-                    }
-                };
-                resolved_return_ty = resolver.resolve_and_get(reference)?;
-            }
+    // Substitute the type parameters of generic functions with the type
+    // arguments inferred from the call.
+    let args = GenericArguments::infer_from_call(resolver, resolver_id, function, &expr.arguments);
+    let return_ty = instantiate(resolver, &return_ty, &args);
 
-            Some((is_generic_instance, resolved_return_ty.to_data()))
-        }),
-        TypeData::Object(_) => callee
-            .all_members(resolver)
-            .find(|member| member.has_name("constructor"))
-            .map(ResolvedTypeMember::to_member)
-            .and_then(|member| resolver.resolve_and_get(&member.ty))
-            .map(ResolvedTypeData::to_data)
-            .map(|data| (false, data)),
-        _ => None,
-    }
+    resolver
+        .resolve_and_get(&return_ty)
+        .map(ResolvedTypeData::to_data)
 }
 
 #[inline]
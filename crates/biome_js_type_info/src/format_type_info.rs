@@ -1,9 +1,9 @@
-use crate::globals::global_type_name;
+use crate::globals::{global_type_name, num_builtin_types};
 use crate::{
     CallArgumentType, Class, DestructureField, Function, FunctionParameter,
     FunctionParameterBinding, GenericTypeParameter, ImportSymbol, Interface, Literal,
-    MergedReference, NUM_PREDEFINED_TYPES, Object, ObjectLiteral, ReturnType, Type, TypeData,
-    TypeId, TypeImportQualifier, TypeInstance, TypeMember, TypeMemberKind, TypeReference,
+    MergedReference, Object, ObjectLiteral, ReturnType, Type, TypeData, TypeId,
+    TypeImportQualifier, TypeInstance, TypeMember, TypeMemberKind, TypeReference,
     TypeReferenceQualifier, TypeResolverLevel, TypeofAwaitExpression, TypeofExpression, Union,
 };
 use biome_formatter::prelude::*;
@@ -472,13 +472,12 @@ impl Format<FormatTypeContext> for TypeReference {
                 let level = resolved.level();
                 let id = resolved.id();
                 if level == TypeResolverLevel::Global {
-                    if resolved.index() < NUM_PREDEFINED_TYPES {
+                    if resolved.index() < num_builtin_types() {
                         write!(f, [text(global_type_name(id))])
                     } else {
-                        // Start counting from `NUM_PREDEFINED_TYPES` so
-                        // snapshots remain stable even if we add new predefined
-                        // types.
-                        let id = TypeId::new(id.index() - NUM_PREDEFINED_TYPES);
+                        // Start counting after the builtin types so snapshots
+                        // remain stable even if we add new builtin types.
+                        let id = TypeId::new(id.index() - num_builtin_types());
                         write!(
                             f,
                             [&format_args![
@@ -1,6 +1,11 @@
-//! Hardcoded global definitions.
+//! Global definitions.
+//!
+//! A small number of types are predefined, because we refer to them by ID.
+//! All other global types are inferred from the TypeScript lib declarations
+//! that are bundled with Biome.
 
-// FIXME: Implement inference from type definitions.
+mod builtins;
+mod ts_lib;
 
 use std::{borrow::Cow, sync::LazyLock};
 
@@ -13,15 +18,24 @@ use crate::{
     TypeReferenceQualifier, TypeResolver, TypeResolverLevel,
 };
 
+use builtins::BUILTINS;
+
+pub use ts_lib::{TsLib, TsLibSet, TsLibTypeDeclaration};
+
 const GLOBAL_LEVEL: TypeResolverLevel = TypeResolverLevel::Global;
 
 pub static GLOBAL_RESOLVER: LazyLock<GlobalsResolver> = LazyLock::new(GlobalsResolver::default);
 
 pub static GLOBAL_TYPE_MEMBERS: LazyLock<Vec<TypeMember>> = LazyLock::new(|| {
+    let mut values: Vec<_> = BUILTINS.values().collect();
+    values.sort_unstable_by(|(a, _), (b, _)| a.text().cmp(b.text()));
+
     (0..NUM_PREDEFINED_TYPES)
         .map(TypeId::new)
-        .map(|id| TypeMember {
-            kind: TypeMemberKind::Named(Text::Static(global_type_name(id))),
+        .map(|id| (Text::Static(global_type_name(id)), id))
+        .chain(values.into_iter().map(|(name, id)| (name.clone(), id)))
+        .map(|(name, id)| TypeMember {
+            kind: TypeMemberKind::Named(name),
             is_static: false,
             ty: ResolvedTypeId::new(GLOBAL_LEVEL, id).into(),
         })
@@ -121,131 +135,184 @@ pub fn global_type_name(id: TypeId) -> &'static str {
         }
         26 => "string",
         27 => "T",
-        _ => "inferred type",
+        _ => {
+            let builtins: &'static builtins::Builtins = &BUILTINS;
+            builtins.name_of(id).map_or("inferred type", Text::text)
+        }
     }
 }
 
+/// Returns a reference to the interface that declares the members of values of
+/// the given primitive type, such as `String` for strings.
+pub(crate) fn primitive_prototype(data: &TypeData) -> Option<&'static TypeReference> {
+    static PROTOTYPES: LazyLock<[TypeReference; 3]> = LazyLock::new(|| {
+        ["Boolean", "Number", "String"].map(|name| {
+            BUILTINS
+                .find_type(name, TsLib::Es5.into())
+                .map_or(TypeReference::Unknown, Into::into)
+        })
+    });
+
+    let index = match data {
+        TypeData::Boolean => 0,
+        TypeData::Number => 1,
+        TypeData::String => 2,
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::Boolean(_) => 0,
+            Literal::Number(_) => 1,
+            Literal::String(_) | Literal::Template(_) => 2,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(&PROTOTYPES[index])
+}
+
+/// Returns the number of types that are shared by all [`GlobalsResolver`]s.
+///
+/// These are the predefined types, followed by the types inferred from the
+/// bundled lib declarations. Types registered with a resolver come after.
+pub fn num_builtin_types() -> usize {
+    BUILTINS.types.len()
+}
+
 /// Resolver that is limited to resolving symbols in the global scope.
 ///
 /// This resolver does not check whether qualifiers that are being resolved have
 /// been shadowed by local declarations, so it should generally only be used
 /// after all other resolvers have failed.
-#[derive(Clone)]
+///
+/// Only the declarations from the libs the resolver was created with can be
+/// resolved by name, but all builtin types can be looked up by ID.
+#[derive(Clone, Default)]
 pub struct GlobalsResolver {
+    libs: TsLibSet,
+
+    /// Types registered with this resolver, in addition to the builtin ones.
     types: Vec<TypeData>,
 }
 
-impl Default for GlobalsResolver {
-    fn default() -> Self {
-        let promise_method = |name: &'static str, id: TypeId| TypeMember {
-            kind: TypeMemberKind::Named(Text::Static(name)),
-            is_static: false,
-            ty: ResolvedTypeId::new(TypeResolverLevel::Global, id).into(),
-        };
-
-        let static_promise_method = |name: &'static str, id: TypeId| TypeMember {
-            kind: TypeMemberKind::Named(Text::Static(name)),
-            is_static: true,
-            ty: ResolvedTypeId::new(TypeResolverLevel::Global, id).into(),
-        };
-
-        let promise_method_definition = |id: TypeId| {
-            TypeData::from(Function {
-                is_async: false,
-                type_parameters: Default::default(),
-                name: Some(Text::Static(global_type_name(id))),
-                parameters: Default::default(),
-                return_type: ReturnType::Type(GLOBAL_INSTANCEOF_PROMISE_ID.into()),
-            })
-        };
-
-        let string_literal = |value: &'static str| -> TypeData {
-            TypeData::from(Literal::String(Text::Static(value).into()))
-        };
-
-        let types = vec![
-            TypeData::Unknown,
-            TypeData::Undefined,
-            TypeData::Class(Box::new(Class {
-                name: Some(Text::Static("Array")),
-                type_parameters: Box::new([TypeReference::from(GLOBAL_T_ID)]),
-                extends: None,
-                implements: [].into(),
-                members: Box::new([TypeMember {
-                    kind: TypeMemberKind::Named(Text::Static("length")),
-                    is_static: false,
-                    ty: GLOBAL_NUMBER_ID.into(),
-                }]),
-            })),
-            TypeData::Global,
-            TypeData::instance_of(TypeReference::from(GLOBAL_PROMISE_ID)),
-            TypeData::Number,
-            TypeData::Class(Box::new(Class {
-                name: Some(Text::Static("Promise")),
-                type_parameters: Box::new([TypeReference::from(GLOBAL_T_ID)]),
-                extends: None,
-                implements: [].into(),
-                members: Box::new([
-                    promise_method("catch", PROMISE_CATCH_ID),
-                    promise_method("finally", PROMISE_FINALLY_ID),
-                    promise_method("then", PROMISE_THEN_ID),
-                    static_promise_method("all", PROMISE_ALL_ID),
-                    static_promise_method("allSettled", PROMISE_ALL_SETTLED_ID),
-                    static_promise_method("any", PROMISE_ANY_ID),
-                    static_promise_method("race", PROMISE_RACE_ID),
-                    static_promise_method("reject", PROMISE_REJECT_ID),
-                    static_promise_method("resolve", PROMISE_RESOLVE_ID),
-                    static_promise_method("try", PROMISE_TRY_ID),
-                ]),
-            })),
-            promise_method_definition(PROMISE_CATCH_ID),
-            promise_method_definition(PROMISE_FINALLY_ID),
-            promise_method_definition(PROMISE_THEN_ID),
-            promise_method_definition(PROMISE_ALL_ID),
-            promise_method_definition(PROMISE_ALL_SETTLED_ID),
-            promise_method_definition(PROMISE_ANY_ID),
-            promise_method_definition(PROMISE_RACE_ID),
-            promise_method_definition(PROMISE_REJECT_ID),
-            promise_method_definition(PROMISE_RESOLVE_ID),
-            promise_method_definition(PROMISE_TRY_ID),
-            string_literal("bigint"),
-            string_literal("boolean"),
-            string_literal("function"),
-            string_literal("number"),
-            string_literal("object"),
-            string_literal("string"),
-            string_literal("symbol"),
-            string_literal("undefined"),
-            TypeData::union_of(vec![
-                GLOBAL_BIGINT_STRING_LITERAL_ID.into(),
-                GLOBAL_BOOLEAN_STRING_LITERAL_ID.into(),
-                GLOBAL_FUNCTION_STRING_LITERAL_ID.into(),
-                GLOBAL_NUMBER_STRING_LITERAL_ID.into(),
-                GLOBAL_OBJECT_STRING_LITERAL_ID.into(),
-                GLOBAL_STRING_STRING_LITERAL_ID.into(),
-                GLOBAL_SYMBOL_STRING_LITERAL_ID.into(),
-                GLOBAL_UNDEFINED_STRING_LITERAL_ID.into(),
+/// Returns the predefined types, which are referenced by the IDs above.
+fn predefined_types() -> Vec<TypeData> {
+    let promise_method = |name: &'static str, id: TypeId| TypeMember {
+        kind: TypeMemberKind::Named(Text::Static(name)),
+        is_static: false,
+        ty: ResolvedTypeId::new(TypeResolverLevel::Global, id).into(),
+    };
+
+    let static_promise_method = |name: &'static str, id: TypeId| TypeMember {
+        kind: TypeMemberKind::Named(Text::Static(name)),
+        is_static: true,
+        ty: ResolvedTypeId::new(TypeResolverLevel::Global, id).into(),
+    };
+
+    let promise_method_definition = |id: TypeId| {
+        TypeData::from(Function {
+            is_async: false,
+            type_parameters: Default::default(),
+            name: Some(Text::Static(global_type_name(id))),
+            parameters: Default::default(),
+            return_type: ReturnType::Type(GLOBAL_INSTANCEOF_PROMISE_ID.into()),
+        })
+    };
+
+    let string_literal = |value: &'static str| -> TypeData {
+        TypeData::from(Literal::String(Text::Static(value).into()))
+    };
+
+    vec![
+        TypeData::Unknown,
+        TypeData::Undefined,
+        TypeData::Class(Box::new(Class {
+            name: Some(Text::Static("Array")),
+            type_parameters: Box::new([TypeReference::from(GLOBAL_T_ID)]),
+            extends: None,
+            implements: [].into(),
+            members: Box::new([TypeMember {
+                kind: TypeMemberKind::Named(Text::Static("length")),
+                is_static: false,
+                ty: GLOBAL_NUMBER_ID.into(),
+            }]),
+        })),
+        TypeData::Global,
+        TypeData::instance_of(TypeReference::from(GLOBAL_PROMISE_ID)),
+        TypeData::Number,
+        TypeData::Class(Box::new(Class {
+            name: Some(Text::Static("Promise")),
+            type_parameters: Box::new([TypeReference::from(GLOBAL_T_ID)]),
+            extends: None,
+            implements: [].into(),
+            members: Box::new([
+                promise_method("catch", PROMISE_CATCH_ID),
+                promise_method("finally", PROMISE_FINALLY_ID),
+                promise_method("then", PROMISE_THEN_ID),
+                static_promise_method("all", PROMISE_ALL_ID),
+                static_promise_method("allSettled", PROMISE_ALL_SETTLED_ID),
+                static_promise_method("any", PROMISE_ANY_ID),
+                static_promise_method("race", PROMISE_RACE_ID),
+                static_promise_method("reject", PROMISE_REJECT_ID),
+                static_promise_method("resolve", PROMISE_RESOLVE_ID),
+                static_promise_method("try", PROMISE_TRY_ID),
             ]),
-            TypeData::String,
-            TypeData::from(GenericTypeParameter {
-                name: Text::Static("T"),
-                constraint: TypeReference::Unknown,
-                default: TypeReference::Unknown,
-            }),
-        ];
-
-        Self { types }
-    }
+        })),
+        promise_method_definition(PROMISE_CATCH_ID),
+        promise_method_definition(PROMISE_FINALLY_ID),
+        promise_method_definition(PROMISE_THEN_ID),
+        promise_method_definition(PROMISE_ALL_ID),
+        promise_method_definition(PROMISE_ALL_SETTLED_ID),
+        promise_method_definition(PROMISE_ANY_ID),
+        promise_method_definition(PROMISE_RACE_ID),
+        promise_method_definition(PROMISE_REJECT_ID),
+        promise_method_definition(PROMISE_RESOLVE_ID),
+        promise_method_definition(PROMISE_TRY_ID),
+        string_literal("bigint"),
+        string_literal("boolean"),
+        string_literal("function"),
+        string_literal("number"),
+        string_literal("object"),
+        string_literal("string"),
+        string_literal("symbol"),
+        string_literal("undefined"),
+        TypeData::union_of(vec![
+            GLOBAL_BIGINT_STRING_LITERAL_ID.into(),
+            GLOBAL_BOOLEAN_STRING_LITERAL_ID.into(),
+            GLOBAL_FUNCTION_STRING_LITERAL_ID.into(),
+            GLOBAL_NUMBER_STRING_LITERAL_ID.into(),
+            GLOBAL_OBJECT_STRING_LITERAL_ID.into(),
+            GLOBAL_STRING_STRING_LITERAL_ID.into(),
+            GLOBAL_SYMBOL_STRING_LITERAL_ID.into(),
+            GLOBAL_UNDEFINED_STRING_LITERAL_ID.into(),
+        ]),
+        TypeData::String,
+        TypeData::from(GenericTypeParameter {
+            name: Text::Static("T"),
+            constraint: TypeReference::Unknown,
+            default: TypeReference::Unknown,
+        }),
+    ]
 }
 
 impl GlobalsResolver {
+    /// Creates a resolver for which only the declarations from the given
+    /// `libs` are visible.
+    pub fn with_libs(libs: TsLibSet) -> Self {
+        Self {
+            libs,
+            types: Vec::new(),
+        }
+    }
+
+    pub fn libs(&self) -> TsLibSet {
+        self.libs
+    }
+
     pub fn run_inference(&mut self) {
         self.resolve_all();
         self.flatten_all();
     }
 
     pub fn resolve_all(&mut self) {
-        let mut i = NUM_PREDEFINED_TYPES;
+        let mut i = 0;
         while i < self.types.len() {
             // First take the type to satisfy the borrow checker:
             let ty = std::mem::take(&mut self.types[i]);
@@ -255,7 +322,7 @@ impl GlobalsResolver {
     }
 
     fn flatten_all(&mut self) {
-        let mut i = NUM_PREDEFINED_TYPES;
+        let mut i = 0;
         while i < self.types.len() {
             // First take the type to satisfy the borrow checker:
             let ty = std::mem::take(&mut self.types[i]);
@@ -263,6 +330,24 @@ impl GlobalsResolver {
             i += 1;
         }
     }
+
+    /// Returns the ID of the given type data if it's either a predefined type
+    /// or a type registered with this resolver.
+    ///
+    /// Types from the lib declarations are not considered, so that types
+    /// registered with this resolver remain distinguishable from them.
+    fn position(&self, type_data: &TypeData) -> Option<TypeId> {
+        BUILTINS.types[..NUM_PREDEFINED_TYPES]
+            .iter()
+            .position(|data| data == type_data)
+            .or_else(|| {
+                self.types
+                    .iter()
+                    .position(|data| data == type_data)
+                    .map(|index| num_builtin_types() + index)
+            })
+            .map(TypeId::new)
+    }
 }
 
 impl TypeResolver for GlobalsResolver {
@@ -271,14 +356,14 @@ impl TypeResolver for GlobalsResolver {
     }
 
     fn find_type(&self, type_data: &TypeData) -> Option<TypeId> {
-        self.types
-            .iter()
-            .position(|data| data == type_data)
-            .map(TypeId::new)
+        self.position(type_data)
     }
 
     fn get_by_id(&self, id: TypeId) -> &TypeData {
-        &self.types[id.index()]
+        match BUILTINS.types.get(id.index()) {
+            Some(data) => data,
+            None => &self.types[id.index() - num_builtin_types()],
+        }
     }
 
     fn get_by_resolved_id(&self, id: ResolvedTypeId) -> Option<ResolvedTypeData> {
@@ -288,14 +373,10 @@ impl TypeResolver for GlobalsResolver {
     fn register_type(&mut self, type_data: Cow<TypeData>) -> TypeId {
         // Searching linearly may potentially become quite expensive, but it
         // should be outweighed by index lookups quite heavily.
-        match self
-            .types
-            .iter()
-            .position(|data| data == type_data.as_ref())
-        {
-            Some(index) => TypeId::new(index),
+        match self.position(type_data.as_ref()) {
+            Some(id) => id,
             None => {
-                let id = TypeId::new(self.types.len());
+                let id = TypeId::new(num_builtin_types() + self.types.len());
                 self.types.push(type_data.into_owned());
                 id
             }
@@ -318,17 +399,21 @@ impl TypeResolver for GlobalsResolver {
             Some(GLOBAL_ARRAY_ID)
         } else if qualifier.is_promise() && !qualifier.has_known_type_parameters() {
             Some(GLOBAL_PROMISE_ID)
-        } else if !qualifier.type_only && qualifier.path.len() == 1 {
-            self.resolve_type_of(&qualifier.path[0], qualifier.scope_id)
-        } else {
+        } else if qualifier.path.len() != 1 || qualifier.has_known_type_parameters() {
+            // Qualifiers with type parameters are resolved without them
+            // first, so that an instantiation can be created.
             None
+        } else if qualifier.type_only {
+            BUILTINS.find_type(&qualifier.path[0], self.libs)
+        } else {
+            self.resolve_type_of(&qualifier.path[0], qualifier.scope_id)
         }
     }
 
     fn resolve_type_of(&self, identifier: &Text, _scope_id: ScopeId) -> Option<ResolvedTypeId> {
         match identifier.text() {
             "globalThis" | "window" => Some(GLOBAL_GLOBAL_ID),
-            _ => None,
+            name => BUILTINS.find_value(name, self.libs),
         }
     }
 
@@ -337,6 +422,6 @@ impl TypeResolver for GlobalsResolver {
     }
 
     fn registered_types(&self) -> &[TypeData] {
-        &self.types[NUM_PREDEFINED_TYPES..]
+        &self.types
     }
 }
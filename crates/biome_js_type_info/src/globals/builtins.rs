@@ -0,0 +1,601 @@
+//! Global types inferred from the bundled TypeScript lib declarations.
+
+use std::{borrow::Cow, sync::LazyLock};
+
+use biome_js_parser::{JsParserOptions, parse};
+use biome_js_syntax::{
+    AnyJsDeclaration, AnyJsDeclarationClause, AnyJsExpression, AnyJsRoot, JsFileSource,
+    TsDeclareStatement,
+};
+use biome_rowan::{AstNode, Text};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    Class, Resolvable, ResolvedTypeData, ResolvedTypeId, ScopeId, TypeData, TypeId, TypeMember,
+    TypeMemberKind, TypeReference, TypeReferenceQualifier, TypeResolver, TypeResolverLevel,
+    TypeStore, owned_text,
+};
+
+use super::{
+    ARRAY_ID, GLOBAL_ARRAY_ID, GLOBAL_LEVEL, GLOBAL_PROMISE_ID, GLOBAL_UNKNOWN_ID,
+    NUM_PREDEFINED_TYPES, PROMISE_ID, TsLib, TsLibSet, TsLibTypeDeclaration, global_type_name,
+    predefined_types,
+};
+
+/// Types that are available to every [`GlobalsResolver`](super::GlobalsResolver).
+///
+/// These are the predefined types, followed by the types inferred from the
+/// bundled lib declarations. They're inferred only once, after which they're
+/// shared by all resolvers.
+pub(super) static BUILTINS: LazyLock<Builtins> = LazyLock::new(Builtins::from_bundled_libs);
+
+pub(super) struct Builtins {
+    /// Predefined types, followed by the types from the lib declarations.
+    pub types: Box<[TypeData]>,
+
+    /// Names of the types declared by interfaces and type aliases.
+    types_by_name: FxHashMap<Text, (TsLib, TypeId)>,
+
+    /// Names of the values declared using `declare var` and
+    /// `declare function`.
+    values_by_name: FxHashMap<Text, (TsLib, TypeId)>,
+
+    /// Interfaces and type aliases in the order they're declared, together
+    /// with the lib that declares them and the names of their members.
+    type_declarations: Box<[TsLibTypeDeclaration]>,
+
+    /// Declared names by type ID, used for formatting.
+    names_by_id: FxHashMap<TypeId, Text>,
+}
+
+impl Builtins {
+    fn from_bundled_libs() -> Self {
+        let mut collector = BuiltinsCollector::default();
+        for lib in TsLibSet::default().iter() {
+            collector.collect_lib(lib);
+        }
+
+        collector.merge_constructors();
+        collector.run_inference();
+        collector.into()
+    }
+
+    /// Returns the name under which the type with the given `id` was declared,
+    /// if any.
+    pub fn name_of(&self, id: TypeId) -> Option<&Text> {
+        self.names_by_id.get(&id)
+    }
+
+    /// Looks up a type declared by an interface or type alias in one of the
+    /// given `libs`.
+    pub fn find_type(&self, name: &str, libs: TsLibSet) -> Option<ResolvedTypeId> {
+        self.types_by_name
+            .get(name)
+            .filter(|(lib, _)| libs.contains(*lib))
+            .map(|(_, id)| ResolvedTypeId::new(GLOBAL_LEVEL, *id))
+    }
+
+    /// Looks up the type of a value declared in one of the given `libs`.
+    pub fn find_value(&self, name: &str, libs: TsLibSet) -> Option<ResolvedTypeId> {
+        self.values_by_name
+            .get(name)
+            .filter(|(lib, _)| libs.contains(*lib))
+            .map(|(_, id)| ResolvedTypeId::new(GLOBAL_LEVEL, *id))
+    }
+
+    /// Iterates over the names of all declared values and their types.
+    pub fn values(&self) -> impl Iterator<Item = (&Text, TypeId)> {
+        self.values_by_name
+            .iter()
+            .map(|(name, (_, id))| (name, *id))
+    }
+
+    /// Returns the interfaces and type aliases declared in the given `lib`,
+    /// sorted by name.
+    pub fn types_in_lib(&self, lib: TsLib) -> Vec<&TsLibTypeDeclaration> {
+        let mut declarations: Vec<_> = self
+            .type_declarations
+            .iter()
+            .filter(|declaration| declaration.lib == lib)
+            .collect();
+        declarations.sort_by(|a, b| a.name.text().cmp(b.name.text()));
+        declarations
+    }
+
+    /// Returns the names of the values declared in the given `lib`, sorted by
+    /// name.
+    pub fn values_in_lib(&self, lib: TsLib) -> Vec<&Text> {
+        let mut names: Vec<_> = self
+            .values_by_name
+            .iter()
+            .filter(|(_, (declaring_lib, _))| *declaring_lib == lib)
+            .map(|(name, _)| name)
+            .collect();
+        names.sort_unstable_by(|a, b| a.text().cmp(b.text()));
+        names
+    }
+}
+
+/// Resolver used for inferring the types of the bundled lib declarations.
+///
+/// Declarations from all libs are collected together, after which interfaces
+/// are merged with the `declare var` statements that provide their
+/// constructors. Which of the declarations are visible is only decided when
+/// resolving names, based on the libs that are enabled for a module.
+struct BuiltinsCollector {
+    types: TypeStore,
+    types_by_name: FxHashMap<Text, (TsLib, TypeId)>,
+    values_by_name: FxHashMap<Text, (TsLib, TypeId)>,
+    type_declarations: Vec<TsLibTypeDeclaration>,
+
+    /// Maps from the names of values to the names of the interfaces that
+    /// describe them, such as from `Map` to `MapConstructor`.
+    constructors: Vec<(Text, Text)>,
+
+    /// Generic type parameters by name.
+    ///
+    /// Lib declarations never use the names of their type parameters for
+    /// anything else, so we can resolve them without tracking scopes.
+    generics: FxHashMap<Text, TypeId>,
+}
+
+impl Default for BuiltinsCollector {
+    fn default() -> Self {
+        let mut types = TypeStore::default();
+        for data in predefined_types() {
+            types.register_type(Cow::Owned(data));
+        }
+        debug_assert_eq!(types.len(), NUM_PREDEFINED_TYPES);
+
+        // Declarations for `Array` and `Promise` are merged into their
+        // predefined classes, so that the IDs used by our helpers keep working.
+        let predefined = [
+            (Text::Static("Array"), (TsLib::Es5, ARRAY_ID)),
+            (Text::Static("Promise"), (TsLib::Es5, PROMISE_ID)),
+        ];
+
+        Self {
+            types,
+            types_by_name: predefined.clone().into_iter().collect(),
+            values_by_name: predefined.into_iter().collect(),
+            type_declarations: Vec::new(),
+            constructors: Vec::new(),
+            generics: Default::default(),
+        }
+    }
+}
+
+impl BuiltinsCollector {
+    fn collect_lib(&mut self, lib: TsLib) {
+        let parsed = parse(
+            lib.source(),
+            JsFileSource::d_ts(),
+            JsParserOptions::default(),
+        );
+        debug_assert!(
+            !parsed.has_errors(),
+            "bundled lib {} should parse without errors",
+            lib.name()
+        );
+
+        let AnyJsRoot::TsDeclarationModule(module) = parsed.tree() else {
+            return;
+        };
+
+        for item in module.items() {
+            let decl = match TsDeclareStatement::cast_ref(item.syntax()) {
+                Some(statement) => statement
+                    .declaration()
+                    .ok()
+                    .and_then(AnyJsDeclarationClause::into_declaration),
+                None => AnyJsDeclaration::cast_ref(item.syntax()),
+            };
+            if let Some(decl) = decl {
+                self.collect_declaration(lib, &decl);
+            }
+        }
+    }
+
+    fn collect_declaration(&mut self, lib: TsLib, decl: &AnyJsDeclaration) {
+        match decl {
+            AnyJsDeclaration::JsVariableDeclaration(decl) => {
+                for (name, ty) in TypeData::typed_bindings_from_js_variable_declaration(
+                    self,
+                    ScopeId::GLOBAL,
+                    decl,
+                ) {
+                    let Some(id) = self.id_for_reference(&ty) else {
+                        continue;
+                    };
+
+                    if let TypeData::InstanceOf(instance) = self.types.get_by_id(id) {
+                        if let TypeReference::Qualifier(qualifier) = &instance.ty {
+                            if qualifier.path.len() == 1 && qualifier.path[0] != name {
+                                self.constructors
+                                    .push((name.clone(), qualifier.path[0].clone()));
+                            }
+                        }
+                    }
+
+                    self.values_by_name.entry(name).or_insert((lib, id));
+                }
+            }
+            AnyJsDeclaration::TsDeclareFunctionDeclaration(decl) => {
+                let data =
+                    TypeData::from_ts_declare_function_declaration(self, ScopeId::GLOBAL, decl);
+                let name = match &data {
+                    TypeData::Function(function) => function.name.clone(),
+                    _ => None,
+                };
+                if let Some(name) = name {
+                    let id = self.register_type(Cow::Owned(data));
+                    // Only the first overload is used.
+                    self.values_by_name.entry(name).or_insert((lib, id));
+                }
+            }
+            AnyJsDeclaration::TsInterfaceDeclaration(decl) => {
+                let Some(TypeData::Interface(interface)) =
+                    TypeData::from_ts_interface_declaration(self, ScopeId::GLOBAL, decl)
+                else {
+                    return;
+                };
+
+                self.type_declarations.push(TsLibTypeDeclaration {
+                    lib,
+                    name: interface.name.clone(),
+                    members: interface
+                        .members
+                        .iter()
+                        .filter_map(TypeMember::name)
+                        .collect(),
+                });
+
+                match self.types_by_name.get(&interface.name) {
+                    Some((_, id)) => {
+                        // Declarations of the same interface are merged.
+                        let id = *id;
+                        self.update_type(id, |data| match data {
+                            TypeData::Class(mut class) => {
+                                class.members = merge_members(&class.members, &interface.members);
+                                TypeData::Class(class)
+                            }
+                            TypeData::Interface(mut existing) => {
+                                existing.members =
+                                    merge_members(&existing.members, &interface.members);
+                                existing.extends = existing
+                                    .extends
+                                    .iter()
+                                    .chain(interface.extends.iter())
+                                    .cloned()
+                                    .collect();
+                                TypeData::Interface(existing)
+                            }
+                            other => other,
+                        });
+                    }
+                    None => {
+                        let name = interface.name.clone();
+                        let id = self.register_type(Cow::Owned(TypeData::Interface(interface)));
+                        self.types_by_name.insert(name, (lib, id));
+                    }
+                }
+            }
+            AnyJsDeclaration::TsTypeAliasDeclaration(decl) => {
+                let Some(name) = decl
+                    .binding_identifier()
+                    .ok()
+                    .and_then(|binding| binding.as_ts_identifier_binding()?.name_token().ok())
+                    .map(|token| Text::from(token.token_text_trimmed()))
+                else {
+                    return;
+                };
+
+                if let Some(data) =
+                    TypeData::from_ts_type_alias_declaration(self, ScopeId::GLOBAL, decl)
+                {
+                    self.type_declarations.push(TsLibTypeDeclaration {
+                        lib,
+                        name: name.clone(),
+                        members: Box::default(),
+                    });
+
+                    let id = self.register_type(Cow::Owned(data));
+                    self.types_by_name.entry(name).or_insert((lib, id));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Merges interfaces such as `Map` with the interfaces that describe their
+    /// constructors, such as `MapConstructor`, to create classes.
+    ///
+    /// This way, the same type can be used for `new Map()` as for the type
+    /// annotation `Map<K, V>`.
+    fn merge_constructors(&mut self) {
+        for (name, constructor_name) in std::mem::take(&mut self.constructors) {
+            let (Some((_, id)), Some((_, constructor_id))) = (
+                self.types_by_name.get(&name).copied(),
+                self.types_by_name.get(&constructor_name).copied(),
+            ) else {
+                continue;
+            };
+
+            let TypeData::Interface(constructor) = self.types.get_by_id(constructor_id) else {
+                continue;
+            };
+
+            let static_members: Box<[TypeMember]> = constructor
+                .members
+                .iter()
+                .filter(|member| !matches!(member.kind, TypeMemberKind::CallSignature))
+                .map(|member| TypeMember {
+                    kind: member.kind.clone(),
+                    is_static: !matches!(member.kind, TypeMemberKind::Constructor),
+                    ty: member.ty.clone(),
+                })
+                .collect();
+
+            self.update_type(id, |data| match data {
+                TypeData::Class(mut class) => {
+                    class.members = merge_members(&class.members, &static_members);
+                    TypeData::Class(class)
+                }
+                TypeData::Interface(interface) => TypeData::Class(Box::new(Class {
+                    name: Some(interface.name),
+                    type_parameters: interface.type_parameters,
+                    extends: interface.extends.first().cloned(),
+                    implements: interface.extends.iter().skip(1).cloned().collect(),
+                    members: merge_members(&interface.members, &static_members),
+                })),
+                other => other,
+            });
+
+            if let Some((_, value_id)) = self.values_by_name.get_mut(&name) {
+                *value_id = id;
+            }
+        }
+    }
+
+    fn run_inference(&mut self) {
+        self.resolve_all();
+        self.flatten_all();
+        self.clear_unresolved_references();
+    }
+
+    fn resolve_all(&mut self) {
+        let mut i = 0;
+        while i < self.types.len() {
+            // SAFETY: We reinsert before anyone got a chance to do lookups.
+            unsafe {
+                let ty = self.types.take_from_index_temporarily(i);
+                let ty = ty.resolved(self);
+                self.types.reinsert_temporarily_taken_data(i, ty);
+            }
+            i += 1;
+        }
+    }
+
+    fn flatten_all(&mut self) {
+        let mut i = NUM_PREDEFINED_TYPES;
+        while i < self.types.len() {
+            // SAFETY: We reinsert before anyone got a chance to do lookups.
+            unsafe {
+                let ty = self.types.take_from_index_temporarily(i);
+                let ty = ty.flattened(self);
+                self.types.reinsert_temporarily_taken_data(i, ty);
+            }
+            i += 1;
+        }
+    }
+
+    /// Replaces references that couldn't be resolved with unknown references.
+    ///
+    /// Otherwise, the resolvers of the modules using these types would attempt
+    /// to resolve them from scopes they don't belong to.
+    fn clear_unresolved_references(&mut self) {
+        let mut i = 0;
+        while i < self.types.len() {
+            // SAFETY: We reinsert before anyone got a chance to do lookups.
+            unsafe {
+                let ty = self.types.take_from_index_temporarily(i);
+                let ty = ty.resolved_with_mapped_references(
+                    |reference, _| match reference {
+                        TypeReference::Qualifier(_) => TypeReference::Unknown,
+                        other => other,
+                    },
+                    self,
+                );
+                self.types.reinsert_temporarily_taken_data(i, ty);
+            }
+            i += 1;
+        }
+    }
+
+    fn id_for_reference(&self, reference: &TypeReference) -> Option<TypeId> {
+        match reference {
+            TypeReference::Resolved(resolved_id) => Some(resolved_id.id()),
+            _ => None,
+        }
+    }
+
+    fn update_type(&mut self, id: TypeId, update: impl FnOnce(TypeData) -> TypeData) {
+        // SAFETY: We reinsert before anyone got a chance to do lookups.
+        unsafe {
+            let ty = self.types.take_from_index_temporarily(id.index());
+            self.types
+                .reinsert_temporarily_taken_data(id.index(), update(ty));
+        }
+    }
+}
+
+impl From<BuiltinsCollector> for Builtins {
+    fn from(collector: BuiltinsCollector) -> Self {
+        let mut names_by_id: FxHashMap<TypeId, Text> = collector
+            .types_by_name
+            .iter()
+            .chain(collector.values_by_name.iter())
+            .filter(|(_, (_, id))| id.index() >= NUM_PREDEFINED_TYPES)
+            .map(|(name, (_, id))| (*id, name.clone()))
+            .collect();
+
+        // Give names to the generics and the instances of named types too, so
+        // they remain recognisable when formatted.
+        let anonymous_names: Vec<_> = collector
+            .types
+            .as_slice()
+            .iter()
+            .enumerate()
+            .skip(NUM_PREDEFINED_TYPES)
+            .filter(|(index, _)| !names_by_id.contains_key(&TypeId::new(*index)))
+            .filter_map(|(index, data)| {
+                let name = match data {
+                    TypeData::Generic(generic) => generic.name.clone(),
+                    TypeData::InstanceOf(instance) if instance.type_parameters.is_empty() => {
+                        let TypeReference::Resolved(resolved) = &instance.ty else {
+                            return None;
+                        };
+                        if let TypeData::Generic(generic) = collector.types.get_by_id(resolved.id())
+                        {
+                            return Some((TypeId::new(index), generic.name.clone()));
+                        }
+
+                        let name = match names_by_id.get(&resolved.id()) {
+                            Some(name) => name.text(),
+                            None if resolved.index() < NUM_PREDEFINED_TYPES => {
+                                global_type_name(resolved.id())
+                            }
+                            None => return None,
+                        };
+                        Text::Owned(format!("instanceof {name}"))
+                    }
+                    _ => return None,
+                };
+                Some((TypeId::new(index), name))
+            })
+            .collect();
+        names_by_id.extend(anonymous_names);
+
+        // The builtins live for the remainder of the process, so they shouldn't
+        // keep the syntax trees of the lib declarations alive.
+        let types: Box<[TypeData]> = collector.types.into();
+        let owned_names = |names: FxHashMap<Text, (TsLib, TypeId)>| {
+            names
+                .into_iter()
+                .map(|(name, value)| (owned_text(name), value))
+                .collect()
+        };
+
+        Self {
+            types: types.into_iter().map(TypeData::with_owned_texts).collect(),
+            types_by_name: owned_names(collector.types_by_name),
+            values_by_name: owned_names(collector.values_by_name),
+            type_declarations: collector
+                .type_declarations
+                .into_iter()
+                .map(|declaration| TsLibTypeDeclaration {
+                    lib: declaration.lib,
+                    name: owned_text(declaration.name),
+                    members: declaration.members.into_iter().map(owned_text).collect(),
+                })
+                .collect(),
+            names_by_id: names_by_id
+                .into_iter()
+                .map(|(id, name)| (id, owned_text(name)))
+                .collect(),
+        }
+    }
+}
+
+impl TypeResolver for BuiltinsCollector {
+    fn level(&self) -> TypeResolverLevel {
+        GLOBAL_LEVEL
+    }
+
+    fn find_type(&self, type_data: &TypeData) -> Option<TypeId> {
+        self.types.find_type(type_data)
+    }
+
+    fn get_by_id(&self, id: TypeId) -> &TypeData {
+        self.types.get_by_id(id)
+    }
+
+    fn get_by_resolved_id(&self, id: ResolvedTypeId) -> Option<ResolvedTypeData> {
+        (id.level() == GLOBAL_LEVEL).then(|| (id, self.get_by_id(id.id())).into())
+    }
+
+    fn register_type(&mut self, type_data: Cow<TypeData>) -> TypeId {
+        let generic_name = match type_data.as_ref() {
+            TypeData::Generic(generic) => Some(generic.name.clone()),
+            _ => None,
+        };
+
+        let id = self.types.register_type(type_data);
+        if let Some(name) = generic_name {
+            self.generics.entry(name).or_insert(id);
+        }
+
+        id
+    }
+
+    fn resolve_reference(&self, ty: &TypeReference) -> Option<ResolvedTypeId> {
+        match ty {
+            TypeReference::Qualifier(qualifier) => self.resolve_qualifier(qualifier),
+            TypeReference::Resolved(resolved_id) => {
+                (resolved_id.level() == GLOBAL_LEVEL).then_some(*resolved_id)
+            }
+            TypeReference::Import(_) => None,
+            TypeReference::Unknown => Some(GLOBAL_UNKNOWN_ID),
+        }
+    }
+
+    fn resolve_qualifier(&self, qualifier: &TypeReferenceQualifier) -> Option<ResolvedTypeId> {
+        if qualifier.is_array() && !qualifier.has_known_type_parameters() {
+            return Some(GLOBAL_ARRAY_ID);
+        } else if qualifier.is_promise() && !qualifier.has_known_type_parameters() {
+            return Some(GLOBAL_PROMISE_ID);
+        } else if qualifier.path.len() != 1 || qualifier.has_known_type_parameters() {
+            return None;
+        }
+
+        let name = &qualifier.path[0];
+        let value = || {
+            (!qualifier.type_only)
+                .then(|| self.values_by_name.get(name))
+                .flatten()
+        };
+        let id = match self.types_by_name.get(name).or_else(value) {
+            Some((_, id)) => *id,
+            None => *self.generics.get(name)?,
+        };
+        Some(ResolvedTypeId::new(GLOBAL_LEVEL, id))
+    }
+
+    fn resolve_type_of(&self, identifier: &Text, _scope_id: ScopeId) -> Option<ResolvedTypeId> {
+        self.values_by_name
+            .get(identifier)
+            .map(|(_, id)| ResolvedTypeId::new(GLOBAL_LEVEL, *id))
+    }
+
+    fn resolve_expression(&mut self, scope_id: ScopeId, expr: &AnyJsExpression) -> Cow<TypeData> {
+        Cow::Owned(TypeData::from_any_js_expression(self, scope_id, expr))
+    }
+
+    fn registered_types(&self) -> &[TypeData] {
+        &self.types.as_slice()[NUM_PREDEFINED_TYPES..]
+    }
+}
+
+/// Appends the `additional` members to `members`, skipping members with names
+/// that are already present.
+fn merge_members(members: &[TypeMember], additional: &[TypeMember]) -> Box<[TypeMember]> {
+    members
+        .iter()
+        .cloned()
+        .chain(additional.iter().filter(|member| {
+            !matches!(&member.kind, TypeMemberKind::Named(name) if members
+                .iter()
+                .any(|existing| existing.kind.has_name(name) && existing.is_static == member.is_static))
+        }).cloned())
+        .collect()
+}
@@ -0,0 +1,276 @@
+//! Selection of the TypeScript lib declarations that are bundled with Biome.
+
+use biome_rowan::Text;
+use biome_string_case::StrLikeExtension;
+use enumflags2::{BitFlags, bitflags};
+
+use super::builtins::{BUILTINS, Builtins};
+
+/// TypeScript lib declaration files that are bundled with Biome.
+///
+/// The bundled files are subsets of the ones shipped with TypeScript, limited
+/// to the declarations our inference is able to make use of. Their names
+/// follow those of the granular libs that can be specified through
+/// `compilerOptions.lib` in a `tsconfig.json`.
+///
+/// Globals and members that are missing from the bundled files resolve to
+/// `unknown`. The ones that are covered can be listed using
+/// [`Self::declared_types()`] and [`Self::declared_values()`], and are kept in
+/// the `bundled_lib_globals` snapshot of this crate's tests.
+#[bitflags]
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TsLib {
+    Es5 = 1 << 0,
+    Es2015Core = 1 << 1,
+    Es2015Collection = 1 << 2,
+    Es2015Iterable = 1 << 3,
+    Es2015Promise = 1 << 4,
+    Es2015Symbol = 1 << 5,
+    Es2016ArrayInclude = 1 << 6,
+    Es2017Object = 1 << 7,
+    Es2017String = 1 << 8,
+    Es2018Promise = 1 << 9,
+    Es2019Array = 1 << 10,
+    Es2019Object = 1 << 11,
+    Es2019String = 1 << 12,
+    Es2020Promise = 1 << 13,
+    Es2021Promise = 1 << 14,
+    Es2021String = 1 << 15,
+    Es2022Array = 1 << 16,
+    Es2022Object = 1 << 17,
+    Es2022String = 1 << 18,
+    Es2023Array = 1 << 19,
+    Dom = 1 << 20,
+    DomIterable = 1 << 21,
+}
+
+impl TsLib {
+    /// Returns the name by which the lib can be referenced from
+    /// `compilerOptions.lib`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Es5 => "es5",
+            Self::Es2015Core => "es2015.core",
+            Self::Es2015Collection => "es2015.collection",
+            Self::Es2015Iterable => "es2015.iterable",
+            Self::Es2015Promise => "es2015.promise",
+            Self::Es2015Symbol => "es2015.symbol",
+            Self::Es2016ArrayInclude => "es2016.array.include",
+            Self::Es2017Object => "es2017.object",
+            Self::Es2017String => "es2017.string",
+            Self::Es2018Promise => "es2018.promise",
+            Self::Es2019Array => "es2019.array",
+            Self::Es2019Object => "es2019.object",
+            Self::Es2019String => "es2019.string",
+            Self::Es2020Promise => "es2020.promise",
+            Self::Es2021Promise => "es2021.promise",
+            Self::Es2021String => "es2021.string",
+            Self::Es2022Array => "es2022.array",
+            Self::Es2022Object => "es2022.object",
+            Self::Es2022String => "es2022.string",
+            Self::Es2023Array => "es2023.array",
+            Self::Dom => "dom",
+            Self::DomIterable => "dom.iterable",
+        }
+    }
+
+    /// Returns the source of the bundled declaration file.
+    pub(crate) const fn source(self) -> &'static str {
+        match self {
+            Self::Es5 => include_str!("../../lib/lib.es5.d.ts"),
+            Self::Es2015Core => include_str!("../../lib/lib.es2015.core.d.ts"),
+            Self::Es2015Collection => include_str!("../../lib/lib.es2015.collection.d.ts"),
+            Self::Es2015Iterable => include_str!("../../lib/lib.es2015.iterable.d.ts"),
+            Self::Es2015Promise => include_str!("../../lib/lib.es2015.promise.d.ts"),
+            Self::Es2015Symbol => include_str!("../../lib/lib.es2015.symbol.d.ts"),
+            Self::Es2016ArrayInclude => include_str!("../../lib/lib.es2016.array.include.d.ts"),
+            Self::Es2017Object => include_str!("../../lib/lib.es2017.object.d.ts"),
+            Self::Es2017String => include_str!("../../lib/lib.es2017.string.d.ts"),
+            Self::Es2018Promise => include_str!("../../lib/lib.es2018.promise.d.ts"),
+            Self::Es2019Array => include_str!("../../lib/lib.es2019.array.d.ts"),
+            Self::Es2019Object => include_str!("../../lib/lib.es2019.object.d.ts"),
+            Self::Es2019String => include_str!("../../lib/lib.es2019.string.d.ts"),
+            Self::Es2020Promise => include_str!("../../lib/lib.es2020.promise.d.ts"),
+            Self::Es2021Promise => include_str!("../../lib/lib.es2021.promise.d.ts"),
+            Self::Es2021String => include_str!("../../lib/lib.es2021.string.d.ts"),
+            Self::Es2022Array => include_str!("../../lib/lib.es2022.array.d.ts"),
+            Self::Es2022Object => include_str!("../../lib/lib.es2022.object.d.ts"),
+            Self::Es2022String => include_str!("../../lib/lib.es2022.string.d.ts"),
+            Self::Es2023Array => include_str!("../../lib/lib.es2023.array.d.ts"),
+            Self::Dom => include_str!("../../lib/lib.dom.d.ts"),
+            Self::DomIterable => include_str!("../../lib/lib.dom.iterable.d.ts"),
+        }
+    }
+
+    /// Returns the interfaces and type aliases declared by the lib, sorted by
+    /// name.
+    pub fn declared_types(self) -> Vec<&'static TsLibTypeDeclaration> {
+        let builtins: &'static Builtins = &BUILTINS;
+        builtins.types_in_lib(self)
+    }
+
+    /// Returns the names of the values declared by the lib's `declare var` and
+    /// `declare function` statements, sorted by name.
+    pub fn declared_values(self) -> Vec<&'static Text> {
+        let builtins: &'static Builtins = &BUILTINS;
+        builtins.values_in_lib(self)
+    }
+
+    /// Returns the ECMAScript edition the lib belongs to, or `None` for libs
+    /// that are not part of ECMAScript.
+    const fn edition(self) -> Option<u16> {
+        match self {
+            Self::Es5 => Some(5),
+            Self::Es2015Core
+            | Self::Es2015Collection
+            | Self::Es2015Iterable
+            | Self::Es2015Promise
+            | Self::Es2015Symbol => Some(2015),
+            Self::Es2016ArrayInclude => Some(2016),
+            Self::Es2017Object | Self::Es2017String => Some(2017),
+            Self::Es2018Promise => Some(2018),
+            Self::Es2019Array | Self::Es2019Object | Self::Es2019String => Some(2019),
+            Self::Es2020Promise => Some(2020),
+            Self::Es2021Promise | Self::Es2021String => Some(2021),
+            Self::Es2022Array | Self::Es2022Object | Self::Es2022String => Some(2022),
+            Self::Es2023Array => Some(2023),
+            Self::Dom | Self::DomIterable => None,
+        }
+    }
+}
+
+/// Interface or type alias declared in one of the bundled libs.
+///
+/// Interfaces that are declared in multiple libs have a declaration for each
+/// of them, which are merged into a single type.
+#[derive(Debug)]
+pub struct TsLibTypeDeclaration {
+    pub lib: TsLib,
+    pub name: Text,
+
+    /// Names of the members declared by this declaration, excluding those that
+    /// are declared by other declarations of the same interface.
+    pub members: Box<[Text]>,
+}
+
+/// Set of TypeScript libs whose global declarations are visible to a module.
+///
+/// The default set includes all the bundled libs, which is what we use for
+/// modules that are not covered by a `tsconfig.json`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TsLibSet(BitFlags<TsLib>);
+
+impl Default for TsLibSet {
+    fn default() -> Self {
+        Self(BitFlags::all())
+    }
+}
+
+impl TsLibSet {
+    /// Determines the libs to use based on the `lib` and `target` compiler
+    /// options from a `tsconfig.json`.
+    ///
+    /// This follows TypeScript's semantics: If `lib` is specified, it
+    /// determines the libs completely. Otherwise, the libs are derived from
+    /// the `target`, which defaults to `ES5`. Names of libs that aren't
+    /// bundled with Biome are ignored.
+    pub fn from_compiler_options(lib: Option<&[String]>, target: Option<&str>) -> Self {
+        match lib {
+            Some(lib) => Self(
+                lib.iter()
+                    .filter_map(|name| libs_for_name(&name.to_ascii_lowercase_cow()))
+                    .collect(),
+            ),
+            None => {
+                let edition = target
+                    .and_then(|target| edition_for_name(&target.to_ascii_lowercase_cow()))
+                    .unwrap_or(5);
+                let libs = es_libs_up_to(edition) | TsLib::Dom;
+                Self(if edition >= 2015 {
+                    libs | TsLib::DomIterable
+                } else {
+                    libs
+                })
+            }
+        }
+    }
+
+    pub fn contains(self, lib: TsLib) -> bool {
+        self.0.contains(lib)
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = TsLib> {
+        self.0.iter()
+    }
+}
+
+impl From<TsLib> for TsLibSet {
+    fn from(lib: TsLib) -> Self {
+        Self(lib.into())
+    }
+}
+
+fn es_libs_up_to(edition: u16) -> BitFlags<TsLib> {
+    BitFlags::<TsLib>::all()
+        .iter()
+        .filter(|lib| {
+            lib.edition()
+                .is_some_and(|lib_edition| lib_edition <= edition)
+        })
+        .collect()
+}
+
+/// Returns the ECMAScript edition for names such as `es5`, `es6`, `es2020`,
+/// or `esnext`.
+fn edition_for_name(name: &str) -> Option<u16> {
+    match name {
+        "es3" | "es5" => Some(5),
+        "es6" => Some(2015),
+        "es7" => Some(2016),
+        "esnext" => Some(u16::MAX),
+        _ => name
+            .strip_prefix("es")
+            .and_then(|year| year.parse().ok())
+            .filter(|year| *year >= 2015),
+    }
+}
+
+fn libs_for_name(name: &str) -> Option<BitFlags<TsLib>> {
+    if let Some(lib) = BitFlags::<TsLib>::all()
+        .iter()
+        .find(|lib| lib.name() == name)
+    {
+        return Some(lib.into());
+    }
+
+    edition_for_name(name).map(es_libs_up_to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn libs_are_derived_from_target() {
+        let libs = TsLibSet::from_compiler_options(None, Some("ES2015"));
+        assert!(libs.contains(TsLib::Es2015Collection));
+        assert!(libs.contains(TsLib::DomIterable));
+        assert!(!libs.contains(TsLib::Es2016ArrayInclude));
+
+        let libs = TsLibSet::from_compiler_options(None, None);
+        assert!(libs.contains(TsLib::Es5));
+        assert!(libs.contains(TsLib::Dom));
+        assert!(!libs.contains(TsLib::Es2015Collection));
+    }
+
+    #[test]
+    fn lib_option_overrides_target() {
+        let lib = ["ES2019".to_string(), "es2022.array".to_string()];
+        let libs = TsLibSet::from_compiler_options(Some(&lib), Some("esnext"));
+        assert!(libs.contains(TsLib::Es2019Array));
+        assert!(libs.contains(TsLib::Es2022Array));
+        assert!(!libs.contains(TsLib::Es2022String));
+        assert!(!libs.contains(TsLib::Dom));
+    }
+}
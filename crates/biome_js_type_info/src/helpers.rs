@@ -4,7 +4,7 @@ use crate::{
     BindingId, Class, Interface, Module, Namespace, Object, ResolvedTypeData, ResolvedTypeId,
     ResolvedTypeMember, ResolverId, TypeData, TypeInstance, TypeMember, TypeReference,
     TypeResolver,
-    globals::{GLOBAL_ARRAY_ID, GLOBAL_PROMISE_ID, GLOBAL_TYPE_MEMBERS},
+    globals::{GLOBAL_ARRAY_ID, GLOBAL_PROMISE_ID, GLOBAL_TYPE_MEMBERS, primitive_prototype},
 };
 
 impl<'a> ResolvedTypeData<'a> {
@@ -14,11 +14,16 @@ impl<'a> ResolvedTypeData<'a> {
     /// Note that members which are inherited and overridden may appear multiple
     /// times, but the member that is closest to the current type is guaranteed
     /// to come first.
+    ///
+    /// Members of primitive values are those of their global wrapper
+    /// interfaces, such as `String`.
     pub fn all_members(self, resolver: &'a dyn TypeResolver) -> AllTypeMemberIterator<'a> {
+        let data = self.as_raw_data();
         AllTypeMemberIterator {
             resolver,
             resolver_id: self.resolver_id(),
-            owner: TypeMemberOwner::from_type_data(self.as_raw_data()),
+            owner: TypeMemberOwner::from_type_data(data)
+                .or_else(|| primitive_prototype(data).map(TypeMemberOwner::Primitive)),
             seen_types: Vec::new(),
             index: 0,
             excluded_binding_id: None,
@@ -243,12 +248,13 @@ impl<'a> Iterator for AllTypeMemberIterator<'a> {
             }
             Some(TypeMemberOwner::InstanceOf(instance_of)) => &instance_of.ty,
             Some(TypeMemberOwner::Interface(interface)) => {
-                match interface.members.get(self.index) {
-                    Some(member) => {
+                match (interface.members.get(self.index), interface.extends.first()) {
+                    (Some(member), _) => {
                         self.index += 1;
                         return Some((self.resolver_id, member).into());
                     }
-                    None => {
+                    (None, Some(extends)) => extends,
+                    (None, None) => {
                         self.owner = None;
                         return None;
                     }
@@ -276,6 +282,7 @@ impl<'a> Iterator for AllTypeMemberIterator<'a> {
                     }
                 }
             }
+            Some(TypeMemberOwner::Primitive(prototype)) => prototype,
             Some(TypeMemberOwner::Object(object)) => {
                 match (object.members.get(self.index), object.prototype.as_ref()) {
                     (Some(member), _) => {
@@ -344,7 +351,7 @@ impl<'a> Iterator for OwnTypeMemberIterator<'a> {
             Some(TypeMemberOwner::Module(module)) => module.members.get(self.index),
             Some(TypeMemberOwner::Namespace(namespace)) => namespace.members.get(self.index),
             Some(TypeMemberOwner::Object(object)) => object.members.get(self.index),
-            None | Some(TypeMemberOwner::InstanceOf(_)) => None,
+            None | Some(TypeMemberOwner::InstanceOf(_) | TypeMemberOwner::Primitive(_)) => None,
         };
 
         if next.is_some() {
@@ -366,6 +373,7 @@ enum TypeMemberOwner<'a> {
     Module(&'a Module),
     Namespace(&'a Namespace),
     Object(&'a Object),
+    Primitive(&'static TypeReference),
 }
 
 impl<'a> TypeMemberOwner<'a> {
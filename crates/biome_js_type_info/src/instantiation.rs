@@ -0,0 +1,455 @@
+//! Instantiation of generic types.
+//!
+//! Functions, classes, interfaces and type aliases may declare generic type
+//! parameters. Wherever such a type is used, its type parameters may be
+//! instantiated with concrete types: either because type arguments are given
+//! explicitly, such as in `Array<string>`, or because they can be inferred
+//! from the arguments of a function call.
+//!
+//! Instantiation substitutes the type parameters inside a type with the types
+//! they are instantiated with. Types that change as a result are registered as
+//! new types with the resolver, so the generic definitions themselves remain
+//! untouched.
+
+use crate::{
+    CallArgumentType, Function, Resolvable, ResolvedTypeData, ResolvedTypeId, ResolverId, TypeData,
+    TypeInstance, TypeReference, TypeResolver,
+};
+
+/// Arbitrary depth, may require tweaking.
+const MAX_INSTANTIATION_DEPTH: usize = 5;
+
+/// Mapping from generic type parameters to the types they are instantiated
+/// with.
+///
+/// Type parameters are identified by the resolved ID of their
+/// [`TypeData::Generic`] declaration.
+#[derive(Debug, Default)]
+pub(crate) struct GenericArguments {
+    arguments: Vec<(ResolvedTypeId, Option<TypeReference>)>,
+}
+
+impl GenericArguments {
+    /// Creates the mapping for an instance of the generic type referenced by
+    /// `ty`, such as a class or interface, with the given `type_arguments`.
+    ///
+    /// Both `ty` and `type_arguments` are expected to have their module IDs
+    /// applied already. Type parameters for which no type argument is given
+    /// fall back to their default.
+    pub fn from_type_arguments(
+        resolver: &mut dyn TypeResolver,
+        ty: &TypeReference,
+        type_arguments: &[TypeReference],
+    ) -> Self {
+        let type_parameters: Vec<_> = resolver
+            .resolve_and_get(ty)
+            .and_then(|resolved| {
+                let type_parameters = resolved.as_raw_data().type_parameters()?;
+                Some(
+                    type_parameters
+                        .iter()
+                        .map(|param| resolved.apply_module_id_to_reference(param).into_owned())
+                        .collect(),
+                )
+            })
+            .unwrap_or_default();
+
+        let mut args = Self::declared(resolver, &type_parameters);
+        for ((_, bound), ty) in args.arguments.iter_mut().zip(type_arguments) {
+            if ty.is_known() {
+                *bound = Some(ty.clone());
+            }
+        }
+
+        args.with_fallbacks(resolver, false)
+    }
+
+    /// Infers the type arguments of a call to the given generic `function`
+    /// from the types of the call `arguments`.
+    ///
+    /// The `resolver_id` is the ID of the resolver the `function` was
+    /// retrieved from. Type parameters that cannot be inferred fall back to
+    /// their default, or to their constraint if they have no default.
+    pub fn infer_from_call(
+        resolver: &mut dyn TypeResolver,
+        resolver_id: ResolverId,
+        function: &Function,
+        arguments: &[CallArgumentType],
+    ) -> Self {
+        let type_parameters: Vec<_> = function
+            .type_parameters
+            .iter()
+            .map(|param| resolver_id.apply_module_id_to_reference(param).into_owned())
+            .collect();
+        let mut args = Self::declared(resolver, &type_parameters);
+        if args.arguments.is_empty() {
+            return args;
+        }
+
+        for (param, arg) in function.parameters.iter().zip(arguments) {
+            let CallArgumentType::Argument(arg) = arg else {
+                break; // TODO: Handle spread arguments
+            };
+
+            let param = resolver_id.apply_module_id_to_reference(&param.ty);
+            args.infer(resolver, &param, arg, 0);
+        }
+
+        args.with_fallbacks(resolver, true)
+    }
+
+    /// Returns whether there are no type parameters bound to a type.
+    pub fn is_empty(&self) -> bool {
+        self.arguments.iter().all(|(_, bound)| bound.is_none())
+    }
+
+    fn declared(resolver: &dyn TypeResolver, type_parameters: &[TypeReference]) -> Self {
+        let arguments = type_parameters
+            .iter()
+            .filter_map(|param| generic_id(resolver, param))
+            .map(|id| (id, None))
+            .collect();
+        Self { arguments }
+    }
+
+    fn get(&self, id: ResolvedTypeId) -> Option<&TypeReference> {
+        self.arguments
+            .iter()
+            .find(|(param_id, _)| *param_id == id)
+            .and_then(|(_, bound)| bound.as_ref())
+    }
+
+    /// Binds the type parameter with the given `id` to `ty`, unless it was
+    /// bound already or isn't one of the declared parameters.
+    fn bind(&mut self, id: ResolvedTypeId, ty: &TypeReference) {
+        if let Some((_, bound @ None)) = self
+            .arguments
+            .iter_mut()
+            .find(|(param_id, _)| *param_id == id)
+        {
+            *bound = Some(ty.clone());
+        }
+    }
+
+    /// Infers type arguments by matching the parameter type `param` against
+    /// the type of the argument `arg`.
+    fn infer(
+        &mut self,
+        resolver: &dyn TypeResolver,
+        param: &TypeReference,
+        arg: &TypeReference,
+        depth: usize,
+    ) {
+        if depth > MAX_INSTANTIATION_DEPTH || !arg.is_known() {
+            return;
+        }
+
+        if let Some(id) = generic_id(resolver, param) {
+            self.bind(id, arg);
+            return;
+        }
+
+        let (Some(resolved_param), Some(resolved_arg)) = (
+            resolver.resolve_and_get(param),
+            resolver.resolve_and_get(arg),
+        ) else {
+            return;
+        };
+
+        let param_instance = generic_instance(resolved_param, resolver);
+        if let (Some(param_instance), Some(arg_instance)) =
+            (&param_instance, generic_instance(resolved_arg, resolver))
+        {
+            if resolver.resolve_reference(&param_instance.ty)
+                == resolver.resolve_reference(&arg_instance.ty)
+            {
+                for (param_ty, arg_ty) in param_instance
+                    .type_parameters
+                    .iter()
+                    .zip(arg_instance.type_parameters.iter())
+                {
+                    self.infer(resolver, param_ty, arg_ty, depth + 1);
+                }
+            }
+
+            return;
+        }
+
+        if let Some(param_instance) = param_instance {
+            self.infer_from_type_alias(resolver, &param_instance, arg, depth);
+            return;
+        }
+
+        match (resolved_param.as_raw_data(), resolved_arg.as_raw_data()) {
+            (TypeData::Function(param_function), TypeData::Function(arg_function)) => {
+                for (param_param, arg_param) in param_function
+                    .parameters
+                    .iter()
+                    .zip(arg_function.parameters.iter())
+                {
+                    self.infer(
+                        resolver,
+                        &resolved_param.apply_module_id_to_reference(&param_param.ty),
+                        &resolved_arg.apply_module_id_to_reference(&arg_param.ty),
+                        depth + 1,
+                    );
+                }
+
+                if let (Some(param_return_ty), Some(arg_return_ty)) = (
+                    param_function.return_type.as_type(),
+                    arg_function.return_type.as_type(),
+                ) {
+                    self.infer(
+                        resolver,
+                        &resolved_param.apply_module_id_to_reference(param_return_ty),
+                        &resolved_arg.apply_module_id_to_reference(arg_return_ty),
+                        depth + 1,
+                    );
+                }
+            }
+            (TypeData::Object(param_object), TypeData::Object(arg_object)) => {
+                for param_member in &param_object.members {
+                    let Some(name) = param_member.name() else {
+                        continue;
+                    };
+                    let Some(arg_member) = arg_object
+                        .members
+                        .iter()
+                        .find(|member| member.has_name(&name) && !member.is_static())
+                    else {
+                        continue;
+                    };
+
+                    self.infer(
+                        resolver,
+                        &resolved_param.apply_module_id_to_reference(&param_member.ty),
+                        &resolved_arg.apply_module_id_to_reference(&arg_member.ty),
+                        depth + 1,
+                    );
+                }
+            }
+            (TypeData::Union(union), _) => {
+                // For parameters such as `T | undefined`, we infer from the
+                // only variant that refers to a type parameter.
+                let mut generic_variants = union
+                    .types()
+                    .iter()
+                    .map(|variant| resolved_param.apply_module_id_to_reference(variant))
+                    .filter(|variant| generic_id(resolver, variant).is_some());
+                if let (Some(variant), None) = (generic_variants.next(), generic_variants.next()) {
+                    self.infer(resolver, &variant, arg, depth + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Infers type arguments by matching an instance of a generic type alias,
+    /// such as `Box<T>` for `type Box<T> = { value: T }`, against the type of
+    /// the argument `arg`.
+    ///
+    /// The type parameters of the alias are inferred from the aliased type
+    /// first, after which the type arguments of the instance are inferred from
+    /// those.
+    fn infer_from_type_alias(
+        &mut self,
+        resolver: &dyn TypeResolver,
+        instance: &TypeInstance,
+        arg: &TypeReference,
+        depth: usize,
+    ) {
+        let Some(alias) = resolver.resolve_and_get(&instance.ty) else {
+            return;
+        };
+        if !is_generic_type_alias(alias, resolver) {
+            return;
+        }
+        let TypeData::InstanceOf(alias_instance) = alias.as_raw_data() else {
+            return;
+        };
+
+        let alias_parameters: Vec<_> = alias_instance
+            .type_parameters
+            .iter()
+            .map(|param| alias.apply_module_id_to_reference(param).into_owned())
+            .collect();
+        let mut alias_args = Self::declared(resolver, &alias_parameters);
+        alias_args.infer(
+            resolver,
+            &alias.apply_module_id_to_reference(&alias_instance.ty),
+            arg,
+            depth + 1,
+        );
+
+        for ((_, bound), param_ty) in alias_args
+            .arguments
+            .iter()
+            .zip(instance.type_parameters.iter())
+        {
+            if let Some(bound) = bound {
+                self.infer(resolver, param_ty, bound, depth + 1);
+            }
+        }
+    }
+
+    /// Binds the type parameters that couldn't be inferred to their default,
+    /// and optionally to their constraint if there is no default.
+    fn with_fallbacks(mut self, resolver: &mut dyn TypeResolver, use_constraint: bool) -> Self {
+        for index in 0..self.arguments.len() {
+            let (id, None) = self.arguments[index] else {
+                continue;
+            };
+
+            let fallback = resolver.get_by_resolved_id(id).and_then(|resolved| {
+                let TypeData::Generic(generic) = resolved.as_raw_data() else {
+                    return None;
+                };
+
+                if generic.default.is_known() {
+                    Some(resolved.apply_module_id_to_reference(&generic.default))
+                } else if use_constraint && generic.constraint.is_known() {
+                    Some(resolved.apply_module_id_to_reference(&generic.constraint))
+                } else {
+                    None
+                }
+                .map(|fallback| fallback.into_owned())
+            });
+
+            // Defaults may refer to preceding type parameters.
+            let fallback = fallback.map(|fallback| instantiate(resolver, &fallback, &self));
+            self.arguments[index].1 = fallback;
+        }
+
+        self
+    }
+}
+
+/// Substitutes the type parameters in the type referenced by `reference` with
+/// the types they're bound to in `args`.
+///
+/// Returns a reference to the instantiated type, which is the given
+/// `reference` itself if nothing needed to be substituted.
+pub(crate) fn instantiate(
+    resolver: &mut dyn TypeResolver,
+    reference: &TypeReference,
+    args: &GenericArguments,
+) -> TypeReference {
+    if args.is_empty() {
+        return reference.clone();
+    }
+
+    instantiate_with_depth(resolver, reference, args, 0)
+}
+
+fn instantiate_with_depth(
+    resolver: &mut dyn TypeResolver,
+    reference: &TypeReference,
+    args: &GenericArguments,
+    depth: usize,
+) -> TypeReference {
+    if depth > MAX_INSTANTIATION_DEPTH {
+        return reference.clone();
+    }
+
+    if let Some(ty) = generic_id(resolver, reference).and_then(|id| args.get(id)) {
+        return ty.clone();
+    }
+
+    let data = match resolver.resolve_and_get(reference) {
+        Some(resolved) => match resolved.as_raw_data() {
+            // We don't descend into declarations, since the type parameters
+            // inside them are their own.
+            TypeData::Function(_)
+            | TypeData::InstanceOf(_)
+            | TypeData::Intersection(_)
+            | TypeData::Object(_)
+            | TypeData::Tuple(_)
+            | TypeData::Union(_) => resolved.to_data(),
+            _ => return reference.clone(),
+        },
+        None => return reference.clone(),
+    };
+
+    let instantiated = data.resolved_with_mapped_references(
+        |reference, resolver| instantiate_with_depth(resolver, &reference, args, depth + 1),
+        resolver,
+    );
+    if instantiated == data {
+        reference.clone()
+    } else {
+        resolver.reference_to_owned_data(instantiated)
+    }
+}
+
+/// Returns the instance with type arguments that `resolved` is an instance
+/// of, if any.
+///
+/// The returned instance has its module IDs applied.
+pub(crate) fn generic_instance<'a>(
+    mut resolved: ResolvedTypeData<'a>,
+    resolver: &'a dyn TypeResolver,
+) -> Option<TypeInstance> {
+    const MAX_INSTANCE_DEPTH: usize = 5; // Arbitrary depth, may require tweaking.
+
+    for _ in 0..MAX_INSTANCE_DEPTH {
+        let TypeData::InstanceOf(instance) = resolved.as_raw_data() else {
+            return None;
+        };
+
+        if !instance.type_parameters.is_empty() {
+            return match resolved.apply_module_id_to_data(TypeData::InstanceOf(instance.clone())) {
+                TypeData::InstanceOf(instance) => Some(*instance),
+                _ => None,
+            };
+        }
+
+        resolved =
+            resolver.resolve_and_get(&resolved.apply_module_id_to_reference(&instance.ty))?;
+    }
+
+    None
+}
+
+/// Returns whether `resolved` is a generic type alias, such as
+/// `type Box<T> = { value: T }`.
+///
+/// Generic type aliases are represented as an instance of the aliased type,
+/// with the declarations of their type parameters as type arguments. This sets
+/// them apart from uses of generic types, whose type arguments are instances.
+pub(crate) fn is_generic_type_alias(
+    resolved: ResolvedTypeData,
+    resolver: &dyn TypeResolver,
+) -> bool {
+    let TypeData::InstanceOf(instance) = resolved.as_raw_data() else {
+        return false;
+    };
+
+    !instance.type_parameters.is_empty()
+        && instance.type_parameters.iter().all(|param| {
+            resolver
+                .resolve_and_get(&resolved.apply_module_id_to_reference(param))
+                .is_some_and(ResolvedTypeData::is_generic)
+        })
+}
+
+/// Returns the ID of the generic type parameter referenced by `reference`, if
+/// it refers to one.
+///
+/// Uses of type parameters are represented as instances of the parameter, so
+/// both the [`TypeData::Generic`] itself and an instance of it are recognised.
+fn generic_id(resolver: &dyn TypeResolver, reference: &TypeReference) -> Option<ResolvedTypeId> {
+    let id = resolver.resolve_reference(reference)?;
+    let resolved = resolver.get_by_resolved_id(id)?;
+    match resolved.as_raw_data() {
+        TypeData::Generic(_) => Some(id),
+        TypeData::InstanceOf(instance) if instance.type_parameters.is_empty() => {
+            let id =
+                resolver.resolve_reference(&resolved.apply_module_id_to_reference(&instance.ty))?;
+            resolver
+                .get_by_resolved_id(id)
+                .is_some_and(ResolvedTypeData::is_generic)
+                .then_some(id)
+        }
+        _ => None,
+    }
+}
@@ -20,7 +20,9 @@ use biome_js_syntax::{
 };
 use biome_rowan::{AstNode, SyntaxResult, Text, TokenText};
 
-use crate::globals::{GLOBAL_INSTANCEOF_PROMISE_ID, GLOBAL_NUMBER_ID, GLOBAL_STRING_ID};
+use crate::globals::{
+    GLOBAL_INSTANCEOF_PROMISE_ID, GLOBAL_NUMBER_ID, GLOBAL_STRING_ID, GLOBAL_UNDEFINED_ID,
+};
 use crate::literal::{BooleanLiteral, NumberLiteral, StringLiteral};
 use crate::{
     AssertsReturnType, CallArgumentType, Class, Constructor, DestructureField, Function,
@@ -969,8 +971,24 @@ impl TypeData {
         scope_id: ScopeId,
         expr: &JsNewExpression,
     ) -> Option<Self> {
+        // Type arguments are applied to the callee, the same way as for
+        // instantiation expressions such as `typeof Foo<Bar>`.
+        let callee = match (
+            TypeReference::from_any_js_expression(resolver, scope_id, &expr.callee().ok()?),
+            expr.type_arguments(),
+        ) {
+            (TypeReference::Qualifier(qualifier), Some(type_arguments)) => TypeReference::from(
+                qualifier.with_type_parameters(TypeReference::types_from_ts_type_arguments(
+                    resolver,
+                    scope_id,
+                    Some(type_arguments),
+                )),
+            ),
+            (callee, _) => callee,
+        };
+
         Some(Self::from(TypeofExpression::New(TypeofNewExpression {
-            callee: TypeReference::from_any_js_expression(resolver, scope_id, &expr.callee().ok()?),
+            callee,
             arguments: CallArgumentType::types_from_js_call_arguments(
                 resolver,
                 scope_id,
@@ -1390,12 +1408,26 @@ impl FunctionParameter {
             .and_then(|annotation| annotation.ty().ok())
             .map(|ty| TypeData::from_any_ts_type(resolver, scope_id, &ty))
             .unwrap_or_default();
+        let is_optional = param.question_mark_token().is_some();
+
+        // Inside the function, optional parameters may also be `undefined`.
+        let binding_ty = if is_optional && ty != TypeData::Unknown {
+            TypeData::union_of(vec![
+                resolver.reference_to_owned_data(ty.clone()),
+                GLOBAL_UNDEFINED_ID.into(),
+            ])
+        } else {
+            ty.clone()
+        };
         let bindings = param
             .binding()
             .ok()
             .and_then(|binding| {
                 FunctionParameterBinding::bindings_from_any_js_binding_pattern_of_type(
-                    resolver, scope_id, &binding, &ty,
+                    resolver,
+                    scope_id,
+                    &binding,
+                    &binding_ty,
                 )
             })
             .unwrap_or_default();
@@ -1403,7 +1435,7 @@ impl FunctionParameter {
             name,
             ty: resolver.reference_to_owned_data(ty),
             bindings,
-            is_optional: param.question_mark_token().is_some(),
+            is_optional,
             is_rest: false,
         }
     }
@@ -1640,6 +1672,39 @@ impl TypeMember {
                     Self::from_class_member_info(resolver, name, ty.into(), is_static, false)
                 })
             }
+            AnyJsClassMember::TsMethodSignatureClassMember(member) => {
+                member.name().ok().and_then(|name| name.name()).map(|name| {
+                    let function = Function {
+                        is_async: member.async_token().is_some(),
+                        type_parameters: generic_params_from_ts_type_params(
+                            resolver,
+                            scope_id,
+                            member.type_parameters(),
+                        ),
+                        name: Some(text_from_class_member_name(name.clone())),
+                        parameters: function_params_from_js_params(
+                            resolver,
+                            scope_id,
+                            member.parameters(),
+                        ),
+                        return_type: return_type_from_annotation(
+                            resolver,
+                            scope_id,
+                            member.return_type_annotation(),
+                        )
+                        .unwrap_or_else(|| {
+                            return_type_from_async_token(resolver, scope_id, member.async_token())
+                        }),
+                    };
+                    let ty = resolver.register_and_resolve(function.into());
+                    let is_static = member
+                        .modifiers()
+                        .into_iter()
+                        .any(|modifier| modifier.as_js_static_modifier().is_some());
+                    let is_optional = member.question_mark_token().is_some();
+                    Self::from_class_member_info(resolver, name, ty.into(), is_static, is_optional)
+                })
+            }
             AnyJsClassMember::JsPropertyClassMember(member) => {
                 member.name().ok().and_then(|name| name.name()).map(|name| {
                     let ty = match member
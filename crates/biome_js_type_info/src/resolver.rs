@@ -4,9 +4,10 @@ use biome_js_syntax::AnyJsExpression;
 use biome_rowan::Text;
 
 use crate::{
-    NUM_PREDEFINED_TYPES, ScopeId, TypeData, TypeId, TypeImportQualifier, TypeInstance, TypeMember,
+    ImportSymbol, ScopeId, TypeData, TypeId, TypeImportQualifier, TypeInstance, TypeMember,
     TypeReference, TypeReferenceQualifier, TypeofValue, Union,
-    globals::{GLOBAL_UNDEFINED_ID, global_type_name},
+    globals::{GLOBAL_UNDEFINED_ID, global_type_name, num_builtin_types},
+    instantiation::is_generic_type_alias,
 };
 
 const NUM_MODULE_ID_BITS: i32 = 30;
@@ -24,10 +25,10 @@ pub struct ResolvedTypeId(ResolverId, TypeId);
 impl Debug for ResolvedTypeId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.0.level() == TypeResolverLevel::Global {
-            if self.1.index() < NUM_PREDEFINED_TYPES {
+            if self.1.index() < num_builtin_types() {
                 f.write_str(global_type_name(self.1))
             } else {
-                let id = self.1.index() - NUM_PREDEFINED_TYPES;
+                let id = self.1.index() - num_builtin_types();
                 f.write_fmt(format_args!("Global TypeId({id})"))
             }
         } else {
@@ -624,6 +625,22 @@ pub trait Resolvable: Sized {
     ///
     /// Does not perform any resolving in the process.
     fn with_module_id(self, module_id: ModuleId) -> Self;
+
+    /// Returns the instance with all texts that borrow from syntax tokens
+    /// replaced by owned copies.
+    ///
+    /// This allows types to outlive the syntax trees they were inferred from,
+    /// without keeping those trees alive.
+    fn with_owned_texts(self) -> Self;
+}
+
+/// Returns the given `text`, converted into an owned one if it borrows from a
+/// syntax token.
+pub(crate) fn owned_text(text: Text) -> Text {
+    match text {
+        Text::Borrowed(token_text) => Text::Owned(token_text.to_string()),
+        other => other,
+    }
 }
 
 impl Resolvable for TypeReference {
@@ -632,6 +649,39 @@ impl Resolvable for TypeReference {
             Self::Qualifier(qualifier) => {
                 let resolved_id = resolver.resolve_qualifier(qualifier);
                 match resolved_id {
+                    Some(resolved_id) if qualifier.has_known_type_parameters() => {
+                        // If the qualifier resolves to a generic class,
+                        // interface or type alias, we create an instantiation
+                        // for it with the given type parameters and resolve to
+                        // there.
+                        let parameters = resolver
+                            .get_by_resolved_id(resolved_id)
+                            .and_then(|resolved| match resolved.as_raw_data() {
+                                TypeData::Class(_) | TypeData::Interface(_) => {
+                                    Some(resolved.to_data())
+                                }
+                                TypeData::InstanceOf(_)
+                                    if is_generic_type_alias(resolved, resolver) =>
+                                {
+                                    Some(resolved.to_data())
+                                }
+                                _ => None,
+                            })
+                            .and_then(|data| data.type_parameters().map(<[_]>::to_vec))
+                            .filter(|parameters| !parameters.is_empty());
+                        match parameters {
+                            Some(parameters) => Self::Resolved(resolver.register_and_resolve(
+                                TypeData::instance_of(TypeInstance {
+                                    ty: resolved_id.into(),
+                                    type_parameters: Self::merge_parameters(
+                                        &parameters,
+                                        &qualifier.type_parameters,
+                                    ),
+                                }),
+                            )),
+                            None => Self::Resolved(resolved_id),
+                        }
+                    }
                     Some(resolved_id) => Self::Resolved(resolved_id),
                     None => {
                         // If we can't resolve the qualifier as is, attempt to
@@ -706,6 +756,28 @@ impl Resolvable for TypeReference {
             other => other,
         }
     }
+
+    fn with_owned_texts(self) -> Self {
+        match self {
+            Self::Qualifier(qualifier) => Self::Qualifier(Box::new(TypeReferenceQualifier {
+                path: qualifier.path.into_iter().map(owned_text).collect(),
+                type_parameters: qualifier
+                    .type_parameters
+                    .into_iter()
+                    .map(Self::with_owned_texts)
+                    .collect(),
+                ..*qualifier
+            })),
+            Self::Import(import) => Self::Import(Box::new(TypeImportQualifier {
+                symbol: match import.symbol {
+                    ImportSymbol::Named(name) => ImportSymbol::Named(owned_text(name)),
+                    other => other,
+                },
+                ..*import
+            })),
+            other => other,
+        }
+    }
 }
 
 impl Resolvable for TypeofValue {
@@ -755,6 +827,19 @@ impl Resolvable for TypeofValue {
             scope_id,
         }
     }
+
+    fn with_owned_texts(self) -> Self {
+        let Self {
+            identifier,
+            ty,
+            scope_id,
+        } = self;
+        Self {
+            identifier: owned_text(identifier),
+            ty: ty.with_owned_texts(),
+            scope_id,
+        }
+    }
 }
 
 macro_rules! derive_primitive_resolved {
@@ -775,6 +860,10 @@ macro_rules! derive_primitive_resolved {
             fn with_module_id(self, _module_id: ModuleId) -> Self {
                 self
             }
+
+            fn with_owned_texts(self) -> Self {
+                self
+            }
         })+
     };
 }
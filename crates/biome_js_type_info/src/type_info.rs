@@ -122,7 +122,7 @@ impl Type {
                 .return_type
                 .as_type()
                 .and_then(|ty| self.resolve(ty))
-                .is_some_and(|ty| ty.is_promise()),
+                .is_some_and(|ty| ty.is_promise_instance()),
             _ => false,
         }
     }
@@ -208,6 +208,26 @@ fn infer_flattened_type_from_static_promise_function() {
     )
 }
 
+#[test]
+fn infer_flattened_type_from_lib_function() {
+    const CODE: &str = r#"fetch("/api")"#;
+
+    let root = parse_ts(CODE);
+    let expr = get_expression(&root);
+    let mut resolver = GlobalsResolver::default();
+    let expr_ty = TypeData::from_any_js_expression(&mut resolver, ScopeId::GLOBAL, &expr);
+    resolver.run_inference();
+
+    let expr_ty = expr_ty.inferred(&mut resolver);
+
+    assert_type_data_snapshot(
+        CODE,
+        expr_ty,
+        &resolver,
+        "infer_flattened_type_from_lib_function",
+    )
+}
+
 #[test]
 fn infer_flattened_type_of_destructured_array_element() {
     const CODE: &str = r#"const [a]: Array<string> = [];"#;
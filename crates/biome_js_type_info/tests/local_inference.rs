@@ -3,8 +3,8 @@ mod utils;
 use biome_js_type_info::{GlobalsResolver, ScopeId, TypeData};
 
 use utils::{
-    assert_type_data_snapshot, assert_typed_bindings_snapshot, get_expression,
-    get_function_declaration, get_variable_declaration, parse_ts,
+    assert_type_data_snapshot, assert_typed_bindings_snapshot, get_class_declaration,
+    get_expression, get_function_declaration, get_variable_declaration, parse_ts,
 };
 
 #[test]
@@ -127,6 +127,22 @@ fn infer_type_of_function_with_destructured_arguments() {
     );
 }
 
+#[test]
+fn infer_type_of_function_with_optional_parameter() {
+    const CODE: &str = r#"function optional(value?: string) {}"#;
+
+    let root = parse_ts(CODE);
+    let decl = get_function_declaration(&root);
+    let mut resolver = GlobalsResolver::default();
+    let ty = TypeData::from_js_function_declaration(&mut resolver, ScopeId::GLOBAL, &decl);
+    assert_type_data_snapshot(
+        CODE,
+        ty,
+        &resolver,
+        "infer_type_of_function_with_optional_parameter",
+    );
+}
+
 #[test]
 fn infer_type_of_literal() {
     const CODE: &str = r#"const a = 123.45;"#;
@@ -196,3 +212,23 @@ fn infer_type_of_dynamic_import() {
     );
     assert_typed_bindings_snapshot(CODE, &bindings, &resolver, "infer_type_of_dynamic_import");
 }
+
+#[test]
+fn infer_type_of_class_with_method_signatures() {
+    const CODE: &str = r#"class Holder<T> {
+    get(): T;
+    find?(key: string): T;
+    static create<T>(value: T): Holder<T>;
+}"#;
+
+    let root = parse_ts(CODE);
+    let decl = get_class_declaration(&root);
+    let mut resolver = GlobalsResolver::default();
+    let ty = TypeData::from_js_class_declaration(&mut resolver, ScopeId::GLOBAL, &decl);
+    assert_type_data_snapshot(
+        CODE,
+        ty,
+        &resolver,
+        "infer_type_of_class_with_method_signatures",
+    );
+}
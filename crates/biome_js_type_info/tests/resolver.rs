@@ -1,7 +1,13 @@
 mod utils;
 
+use std::fmt::Write;
+
 use biome_js_syntax::{AnyJsModuleItem, AnyJsRoot, AnyJsStatement, JsExpressionStatement};
-use biome_js_type_info::{GlobalsResolver, Resolvable, ScopeId, TypeData};
+use biome_js_type_info::{
+    GLOBAL_RESOLVER, GlobalsResolver, Resolvable, ScopeId, TsLib, TsLibSet, TypeData,
+    TypeReferenceQualifier, TypeResolver,
+};
+use biome_rowan::Text;
 
 use utils::{
     HardcodedSymbolResolver, assert_type_data_snapshot, assert_typed_bindings_snapshot,
@@ -221,6 +227,92 @@ fn infer_resolved_type_of_destructured_array_element() {
     );
 }
 
+#[test]
+fn infer_resolved_type_from_lib_constructor() {
+    const CODE: &str = r#"new Map()"#;
+
+    let root = parse_ts(CODE);
+    let expr = get_expression_statement(&root);
+    let mut resolver = GlobalsResolver::default();
+    let expr_ty = TypeData::from_any_js_expression(
+        &mut resolver,
+        ScopeId::GLOBAL,
+        &expr.expression().unwrap(),
+    );
+    resolver.resolve_all();
+
+    let expr_ty = expr_ty.resolved(&mut resolver);
+
+    assert_type_data_snapshot(
+        CODE,
+        expr_ty,
+        &resolver,
+        "infer_resolved_type_from_lib_constructor",
+    )
+}
+
+#[test]
+fn infer_unknown_type_from_disabled_lib() {
+    const CODE: &str = r#"new Map()"#;
+
+    let root = parse_ts(CODE);
+    let expr = get_expression_statement(&root);
+    let mut resolver = GlobalsResolver::with_libs(TsLib::Es5.into());
+    let expr_ty = TypeData::from_any_js_expression(
+        &mut resolver,
+        ScopeId::GLOBAL,
+        &expr.expression().unwrap(),
+    );
+    resolver.resolve_all();
+
+    let expr_ty = expr_ty.resolved(&mut resolver);
+
+    assert_type_data_snapshot(
+        CODE,
+        expr_ty,
+        &resolver,
+        "infer_unknown_type_from_disabled_lib",
+    )
+}
+
+#[test]
+fn bundled_lib_globals() {
+    let mut content = String::new();
+    for lib in TsLibSet::default().iter() {
+        let resolver = GlobalsResolver::with_libs(lib.into());
+        writeln!(content, "# {}\n", lib.name()).unwrap();
+
+        writeln!(content, "## Types\n").unwrap();
+        for declaration in lib.declared_types() {
+            let qualifier =
+                TypeReferenceQualifier::from_name(ScopeId::GLOBAL, declaration.name.clone())
+                    .with_type_only();
+            assert!(GLOBAL_RESOLVER.resolve_qualifier(&qualifier).is_some());
+
+            let members: Vec<_> = declaration.members.iter().map(Text::text).collect();
+            if members.is_empty() {
+                writeln!(content, "{}", declaration.name).unwrap();
+            } else {
+                writeln!(content, "{}: {}", declaration.name, members.join(", ")).unwrap();
+            }
+        }
+
+        writeln!(content, "\n## Values\n").unwrap();
+        for name in lib.declared_values() {
+            assert!(resolver.resolve_type_of(name, ScopeId::GLOBAL).is_some());
+            writeln!(content, "{name}").unwrap();
+        }
+        writeln!(content).unwrap();
+    }
+
+    insta::with_settings!({
+        snapshot_path => "snapshots",
+        prepend_module_to_snapshot => false,
+    }, {
+        insta::assert_snapshot!("bundled_lib_globals", content);
+    });
+}
+
 pub fn get_expression_statement(root: &AnyJsRoot) -> JsExpressionStatement {
     let module = root.as_js_module().unwrap();
     module
@@ -7,7 +7,8 @@ use biome_js_formatter::format_node;
 use biome_js_parser::{JsParserOptions, parse};
 use biome_js_syntax::{AnyJsExpression, JsVariableDeclaration, TsInterfaceDeclaration};
 use biome_js_syntax::{
-    AnyJsModuleItem, AnyJsRoot, AnyJsStatement, JsFileSource, JsFunctionDeclaration,
+    AnyJsModuleItem, AnyJsRoot, AnyJsStatement, JsClassDeclaration, JsFileSource,
+    JsFunctionDeclaration,
 };
 use biome_js_type_info::{
     GlobalsResolver, NUM_PREDEFINED_TYPES, Resolvable, ResolvedTypeData, ResolvedTypeId, ScopeId,
@@ -231,6 +232,22 @@ pub fn get_expression(root: &AnyJsRoot) -> AnyJsExpression {
         .expect("cannot find expression")
 }
 
+pub fn get_class_declaration(root: &AnyJsRoot) -> JsClassDeclaration {
+    let module = root.as_js_module().unwrap();
+    module
+        .items()
+        .into_iter()
+        .filter_map(|item| match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => Some(statement),
+            _ => None,
+        })
+        .find_map(|statement| match statement {
+            AnyJsStatement::JsClassDeclaration(decl) => Some(decl),
+            _ => None,
+        })
+        .expect("cannot find class declaration")
+}
+
 pub fn get_function_declaration(root: &AnyJsRoot) -> JsFunctionDeclaration {
     let module = root.as_js_module().unwrap();
     module
@@ -131,6 +131,14 @@ pub(crate) fn generate_resolvable_enum(ident: Ident, variants: Vec<VariantData>)
         None => quote! { Self::#ident => Self::#ident },
     });
 
+    let variants_with_owned_texts = variants.iter().map(|VariantData { ident, ty }| match ty {
+        Some(ty) => {
+            let ty_with_owned_texts = unit_type_with_owned_texts(ty);
+            quote! { Self::#ident(ty) => Self::#ident(#ty_with_owned_texts) }
+        }
+        None => quote! { Self::#ident => Self::#ident },
+    });
+
     quote! {
         impl crate::Resolvable for #ident {
             fn resolved(&self, resolver: &mut dyn crate::TypeResolver) -> Self {
@@ -154,6 +162,12 @@ pub(crate) fn generate_resolvable_enum(ident: Ident, variants: Vec<VariantData>)
                     #( #variants_with_module_id ),*
                 }
             }
+
+            fn with_owned_texts(self) -> Self {
+                match self {
+                    #( #variants_with_owned_texts ),*
+                }
+            }
         }
     }
 }
@@ -174,6 +188,11 @@ pub(crate) fn generate_resolvable_struct(ident: Ident, fields: Vec<FieldData>) -
         quote! { #ident: #ty_with_module_id }
     });
 
+    let fields_with_owned_texts = fields.iter().map(|FieldData { ident, ty }| {
+        let ty_with_owned_texts = type_with_owned_texts(IdentOrZero::Ident(ident), ty);
+        quote! { #ident: #ty_with_owned_texts }
+    });
+
     quote! {
         impl crate::Resolvable for #ident {
             fn resolved(&self, resolver: &mut dyn crate::TypeResolver) -> Self {
@@ -197,6 +216,12 @@ pub(crate) fn generate_resolvable_struct(ident: Ident, fields: Vec<FieldData>) -
                     #( #fields_with_module_id ),*
                 }
             }
+
+            fn with_owned_texts(self) -> Self {
+                Self {
+                    #( #fields_with_owned_texts ),*
+                }
+            }
         }
     }
 }
@@ -209,6 +234,8 @@ fn generate_resolvable_unit_type(ident: Ident, ty: Type) -> TokenStream {
 
     let field_with_module_id = type_with_module_id(IdentOrZero::Zero, &ty);
 
+    let field_with_owned_texts = type_with_owned_texts(IdentOrZero::Zero, &ty);
+
     quote! {
         impl crate::Resolvable for #ident {
             fn resolved(&self, resolver: &mut dyn crate::TypeResolver) -> Self {
@@ -226,6 +253,10 @@ fn generate_resolvable_unit_type(ident: Ident, ty: Type) -> TokenStream {
             fn with_module_id(self, module_id: crate::ModuleId) -> Self {
                 Self(#field_with_module_id)
             }
+
+            fn with_owned_texts(self) -> Self {
+                Self(#field_with_owned_texts)
+            }
         }
     }
 }
@@ -612,3 +643,120 @@ fn unit_type_with_module_id(ty: &Type) -> TokenStream {
         }
     }
 }
+
+fn type_with_owned_texts(ident: IdentOrZero, ty: &Type) -> TokenStream {
+    let Type::Path(path) = ty else {
+        abort!(ty, "Resolvable derive requires plain path types");
+    };
+
+    match path.path.segments.last() {
+        Some(segment) if segment.ident == "Text" => {
+            quote! { crate::owned_text(self.#ident) }
+        }
+        Some(segment) if segment.ident == "Box" => match &segment.arguments {
+            PathArguments::None => abort!(segment, "Box is missing argument"),
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.iter().next().unwrap() {
+                    GenericArgument::Type(Type::Slice(slice)) => match slice.elem.as_ref() {
+                        Type::Path(ty) if ty.path.is_ident("Text") => quote! {
+                            self.#ident.into_iter().map(crate::owned_text).collect()
+                        },
+                        Type::Path(_) => quote! {
+                            self.#ident.into_iter().map(|elem| elem.with_owned_texts()).collect()
+                        },
+                        _ => abort!(slice, "Unsupported arguments"),
+                    },
+                    GenericArgument::Type(Type::Path(ty)) => {
+                        if ty.path.is_ident("Text") {
+                            quote! { Box::new(crate::owned_text(*self.#ident)) }
+                        } else {
+                            quote! { Box::new(self.#ident.with_owned_texts()) }
+                        }
+                    }
+                    _ => abort!(args, "Unsupported arguments"),
+                }
+            }
+            PathArguments::AngleBracketed(_) | PathArguments::Parenthesized(_) => {
+                abort!(path, "Unsupported type arguments in path")
+            }
+        },
+        Some(segment) if segment.ident == "Option" => match &segment.arguments {
+            PathArguments::None => abort!(segment, "Option is missing argument"),
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.iter().next().unwrap() {
+                    GenericArgument::Type(Type::Path(ty)) => {
+                        if ty.path.is_ident("Text") {
+                            quote! { self.#ident.map(crate::owned_text) }
+                        } else {
+                            quote! { self.#ident.map(|f| f.with_owned_texts()) }
+                        }
+                    }
+                    _ => abort!(args, "Unsupported arguments"),
+                }
+            }
+            PathArguments::AngleBracketed(_) | PathArguments::Parenthesized(_) => {
+                abort!(path, "Unsupported type arguments in path")
+            }
+        },
+        _ => {
+            quote! { self.#ident.with_owned_texts() }
+        }
+    }
+}
+
+fn unit_type_with_owned_texts(ty: &Type) -> TokenStream {
+    let Type::Path(path) = ty else {
+        abort!(ty, "Resolvable derive requires plain path types");
+    };
+
+    match path.path.segments.last() {
+        Some(segment) if segment.ident == "Text" => {
+            quote! { crate::owned_text(ty) }
+        }
+        Some(segment) if segment.ident == "Box" => match &segment.arguments {
+            PathArguments::None => abort!(segment, "Box is missing argument"),
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.iter().next().unwrap() {
+                    GenericArgument::Type(Type::Slice(slice)) => match slice.elem.as_ref() {
+                        Type::Path(ty) if ty.path.is_ident("Text") => {
+                            quote! { ty.into_iter().map(crate::owned_text).collect() }
+                        }
+                        _ => abort!(args, "Unsupported arguments"),
+                    },
+                    GenericArgument::Type(Type::Path(ty)) => {
+                        if ty.path.is_ident("Text") {
+                            quote! { Box::new(crate::owned_text(*ty)) }
+                        } else {
+                            quote! { Box::new(ty.with_owned_texts()) }
+                        }
+                    }
+                    _ => abort!(args, "Unsupported arguments"),
+                }
+            }
+            PathArguments::AngleBracketed(_) | PathArguments::Parenthesized(_) => {
+                abort!(path, "Unsupported type arguments in path")
+            }
+        },
+        Some(segment) if segment.ident == "Option" => match &segment.arguments {
+            PathArguments::None => abort!(segment, "Option is missing argument"),
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.iter().next().unwrap() {
+                    GenericArgument::Type(Type::Path(ty)) => {
+                        if ty.path.is_ident("Text") {
+                            quote! { ty.map(crate::owned_text) }
+                        } else {
+                            quote! { ty.map(|f| f.with_owned_texts()) }
+                        }
+                    }
+                    _ => abort!(args, "Unsupported arguments"),
+                }
+            }
+            PathArguments::AngleBracketed(_) | PathArguments::Parenthesized(_) => {
+                abort!(path, "Unsupported type arguments in path")
+            }
+        },
+        _ => {
+            quote! { ty.with_owned_texts() }
+        }
+    }
+}
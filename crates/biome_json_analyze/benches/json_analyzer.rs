@@ -64,6 +64,7 @@ fn bench_analyzer(criterion: &mut Criterion) {
                                 filter,
                                 &options,
                                 file_source,
+                                &[],
                                 |event| {
                                     black_box(event.diagnostic());
                                     black_box(event.actions());
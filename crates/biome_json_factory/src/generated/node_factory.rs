@@ -46,6 +46,12 @@ pub fn json_member_name(value_token: SyntaxToken) -> JsonMemberName {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn json_metavariable(value_token: SyntaxToken) -> JsonMetavariable {
+    JsonMetavariable::unwrap_cast(SyntaxNode::new_detached(
+        JsonSyntaxKind::JSON_METAVARIABLE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn json_null_value(value_token: SyntaxToken) -> JsonNullValue {
     JsonNullValue::unwrap_cast(SyntaxNode::new_detached(
         JsonSyntaxKind::JSON_NULL_VALUE,
@@ -319,7 +319,9 @@ impl<'src> Lexer<'src> {
             UNI => {
                 let chr = self.current_char_unchecked();
 
-                if is_js_id_start(chr) {
+                if self.options.grit_metavariables && self.is_metavariable_start() {
+                    self.consume_metavariable()
+                } else if is_js_id_start(chr) {
                     self.lex_identifier(current)
                 } else if self.position == 0 && self.consume_potential_bom().is_some() {
                     // A BOM can only appear at the start of a file, so if we haven't advanced at all yet,
@@ -788,6 +790,42 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Check if the lexer starts a grit metavariable
+    fn is_metavariable_start(&self) -> bool {
+        if self.current_char_unchecked() != 'µ' {
+            return false;
+        }
+
+        let length = 'µ'.len_utf8();
+        match self.byte_at(length) {
+            // µ[a-zA-Z_][a-zA-Z0-9_]*
+            Some(b'a'..=b'z' | b'A'..=b'Z' | b'_') => true,
+            // µ...
+            Some(b'.') => {
+                self.byte_at(length + 1) == Some(b'.') && self.byte_at(length + 2) == Some(b'.')
+            }
+            _ => false,
+        }
+    }
+
+    /// Consume a grit metavariable(µ[a-zA-Z_][a-zA-Z0-9_]*|µ...)
+    fn consume_metavariable(&mut self) -> JsonSyntaxKind {
+        debug_assert!(self.is_metavariable_start());
+
+        self.advance('µ'.len_utf8());
+
+        if self.current_byte() == Some(b'.') {
+            self.advance(3);
+        } else {
+            self.advance(1);
+            while let Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_') = self.current_byte() {
+                self.advance(1);
+            }
+        }
+
+        GRIT_METAVARIABLE
+    }
+
     pub(crate) fn with_options(mut self, options: JsonParserOptions) -> Self {
         self.options = options;
         self
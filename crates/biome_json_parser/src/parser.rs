@@ -16,6 +16,8 @@ pub(crate) struct JsonParser<'source> {
 pub struct JsonParserOptions {
     pub allow_comments: bool,
     pub allow_trailing_commas: bool,
+    /// Enables parsing of Grit metavariables.
+    pub grit_metavariables: bool,
 }
 
 impl JsonParserOptions {
@@ -30,6 +32,13 @@ impl JsonParserOptions {
         self.allow_trailing_commas = true;
         self
     }
+
+    /// Enables parsing of Grit metavariables.
+    #[must_use]
+    pub fn with_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
 }
 
 impl From<&JsonFileSource> for JsonParserOptions {
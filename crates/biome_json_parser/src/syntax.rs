@@ -16,6 +16,7 @@ const VALUE_START: TokenSet<JsonSyntaxKind> = token_set![
     JSON_NUMBER_LITERAL,
     T!['['],
     T!['{'],
+    GRIT_METAVARIABLE,
 ];
 
 const VALUE_RECOVERY_SET: TokenSet<JsonSyntaxKind> =
@@ -87,6 +88,12 @@ fn parse_value(p: &mut JsonParser) -> ParsedSyntax {
             Present(m.complete(p, JSON_BOGUS_VALUE))
         }
 
+        GRIT_METAVARIABLE => {
+            let m = p.start();
+            p.bump(GRIT_METAVARIABLE);
+            Present(m.complete(p, JSON_METAVARIABLE))
+        }
+
         _ => Absent,
     }
 }
@@ -295,6 +302,13 @@ fn parse_member_name(p: &mut JsonParser) -> ParsedSyntax {
             p.bump_remap(IDENT);
             Present(m.complete(p, JSON_MEMBER_NAME))
         }
+        GRIT_METAVARIABLE => {
+            // Keys are always string literals, so a metavariable key is
+            // remapped to one and matched by its text.
+            let m = p.start();
+            p.bump_remap(JSON_STRING_LITERAL);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
         _ => Absent,
     }
 }
@@ -39,6 +39,7 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let parse_config = JsonParserOptions {
         allow_comments: test_directory.contains("allow_comments"),
         allow_trailing_commas: test_directory.contains("allow_trailing_commas"),
+        grit_metavariables: test_directory.contains("grit_metavariable"),
     };
 
     let parsed = parse_json(&content, parse_config);
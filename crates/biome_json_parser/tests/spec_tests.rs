@@ -25,3 +25,8 @@ mod allow_trainling_commas {
     tests_macros::gen_tests! {"tests/json_test_suite/allow_trailing_commas/ok/*.json", crate::spec_test::run, "ok"}
     tests_macros::gen_tests! {"tests/json_test_suite/allow_trailing_commas/err/*.json", crate::spec_test::run, "error"}
 }
+
+mod grit_metavariable {
+    //! Tests with Grit metavariables, which are only parsed in Grit snippets
+    tests_macros::gen_tests! {"tests/json_test_suite/grit_metavariable/ok/*.json", crate::spec_test::run, "ok"}
+}
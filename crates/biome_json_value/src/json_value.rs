@@ -108,7 +108,7 @@ impl From<AnyJsonValue> for JsonValue {
     fn from(value: AnyJsonValue) -> Self {
         match value {
             AnyJsonValue::JsonArrayValue(array_value) => Self::Array(array_value.into()),
-            AnyJsonValue::JsonBogusValue(_) => Self::Bogus,
+            AnyJsonValue::JsonBogusValue(_) | AnyJsonValue::JsonMetavariable(_) => Self::Bogus,
             AnyJsonValue::JsonBooleanValue(boolean_value) => match boolean_value.value_token() {
                 Ok(value) => Self::Bool(value.text_trimmed() == "true"),
                 Err(_) => Self::Bogus,
@@ -1,3 +1,5 @@
 pub(crate) mod analysis;
 pub(crate) mod formatting;
+pub(crate) mod navigation;
+pub(crate) mod rename;
 pub(crate) mod text_document;
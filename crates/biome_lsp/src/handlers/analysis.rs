@@ -14,7 +14,7 @@ use biome_line_index::LineIndex;
 use biome_lsp_converters::from_proto;
 use biome_rowan::{TextRange, TextSize};
 use biome_service::WorkspaceError;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use biome_service::workspace::{
     CheckFileSizeParams, FeaturesBuilder, FixFileMode, FixFileParams, GetFileContentParams,
     IsPathIgnoredParams, PullActionsParams, SupportsFeatureParams,
@@ -115,7 +115,6 @@ pub(crate) fn code_actions(
         path: path.clone(),
     })?;
     let offset = match path.extension() {
-        Some("vue") => VueFileHandler::start(content.as_str()),
         Some("astro") => AstroFileHandler::start(content.as_str()),
         Some("svelte") => SvelteFileHandler::start(content.as_str()),
         _ => None,
@@ -350,7 +349,6 @@ fn fix_all(
             })?;
             match extension {
                 "astro" => AstroFileHandler::output(input.as_str(), fixed.code.as_str()),
-                "vue" => VueFileHandler::output(input.as_str(), fixed.code.as_str()),
                 "svelte" => SvelteFileHandler::output(input.as_str(), fixed.code.as_str()),
                 _ => fixed.code,
             }
@@ -5,7 +5,7 @@ use anyhow::Context;
 use biome_fs::BiomePath;
 use biome_lsp_converters::from_proto;
 use biome_rowan::{TextLen, TextRange, TextSize};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use biome_service::workspace::{
     CheckFileSizeParams, FeaturesBuilder, FileFeaturesResult, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetFileContentParams, IsPathIgnoredParams, SupportsFeatureParams,
@@ -75,9 +75,6 @@ pub(crate) fn format(
             Some("astro") => {
                 output = AstroFileHandler::output(input.as_str(), output.as_str());
             }
-            Some("vue") => {
-                output = VueFileHandler::output(input.as_str(), output.as_str());
-            }
             Some("svelte") => {
                 output = SvelteFileHandler::output(input.as_str(), output.as_str());
             }
@@ -153,7 +150,6 @@ pub(crate) fn format_range(
             path: path.clone(),
         })?;
         let offset = match path.extension() {
-            Some("vue") => VueFileHandler::start(content.as_str()),
             Some("astro") => AstroFileHandler::start(content.as_str()),
             Some("svelte") => SvelteFileHandler::start(content.as_str()),
             _ => None,
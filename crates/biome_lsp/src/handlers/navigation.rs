@@ -0,0 +1,129 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_fs::BiomePath;
+use biome_line_index::LineIndex;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::projects::ProjectKey;
+use biome_service::workspace::{
+    FindReferencesParams, GetFileContentParams, GotoDefinitionParams, SymbolLocation,
+};
+use tower_lsp_server::UriExt;
+use tower_lsp_server::lsp_types::{self as lsp, GotoDefinitionResponse, Uri};
+
+/// Handler for the `textDocument/definition` LSP request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: lsp::GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(&doc.line_index, position, position_encoding)?;
+
+    let result = session.workspace.goto_definition(GotoDefinitionParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at,
+    });
+    let definition = match result {
+        Ok(result) => result.definition,
+        // Navigation is only supported for some languages
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let Some(definition) = definition else {
+        return Ok(None);
+    };
+
+    let location = to_lsp_location(session, doc.project_key, &url, definition)?;
+    Ok(Some(GotoDefinitionResponse::Scalar(location)))
+}
+
+/// Handler for the `textDocument/references` LSP request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: lsp::ReferenceParams,
+) -> Result<Option<Vec<lsp::Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(&doc.line_index, position, position_encoding)?;
+
+    let result = session.workspace.find_references(FindReferencesParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at,
+        include_declaration: params.context.include_declaration,
+    });
+    let references = match result {
+        Ok(result) => result.references,
+        // Navigation is only supported for some languages
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let locations = references
+        .into_iter()
+        .map(|reference| to_lsp_location(session, doc.project_key, &url, reference))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(locations))
+}
+
+/// Converts a [SymbolLocation] returned by the workspace into an LSP
+/// [lsp::Location].
+fn to_lsp_location(
+    session: &Session,
+    project_key: ProjectKey,
+    current_url: &Uri,
+    location: SymbolLocation,
+) -> Result<lsp::Location, LspError> {
+    let (url, line_index) = url_and_line_index(session, project_key, current_url, location.path)?;
+    let range = to_proto::range(&line_index, location.range, session.position_encoding())?;
+    Ok(lsp::Location::new(url, range))
+}
+
+/// Returns the URI and the line index of the file at `path`.
+///
+/// The file may not be opened by the client, in which case its line index is
+/// computed from the content known by the workspace.
+pub(crate) fn url_and_line_index(
+    session: &Session,
+    project_key: ProjectKey,
+    current_url: &Uri,
+    path: BiomePath,
+) -> Result<(Uri, LineIndex), LspError> {
+    let url = if session.file_path(current_url)?.as_path() == path.as_path() {
+        current_url.clone()
+    } else {
+        Uri::from_file_path(path.as_path())
+            .with_context(|| format!("failed to convert path {path} to a URI"))?
+    };
+
+    let line_index = match session.document(&url) {
+        Some(doc) => doc.line_index,
+        None => {
+            let content = session
+                .workspace
+                .get_file_content(GetFileContentParams { project_key, path })?;
+            LineIndex::new(&content)
+        }
+    };
+
+    Ok((url, line_index))
+}
@@ -0,0 +1,105 @@
+#![expect(clippy::mutable_key_type)]
+
+use crate::diagnostics::LspError;
+use crate::handlers::navigation::url_and_line_index;
+use crate::session::Session;
+use crate::utils::text_edit;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::workspace::{FindReferencesParams, RenameParams};
+use std::collections::HashMap;
+use tower_lsp_server::lsp_types::{self as lsp, PrepareRenameResponse};
+
+/// Handler for the `textDocument/prepareRename` LSP request
+///
+/// Returns the range of the symbol to rename, or `None` if there is no symbol
+/// that can be renamed at the given position.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: lsp::TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>, LspError> {
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(&doc.line_index, params.position, position_encoding)?;
+
+    let result = session.workspace.find_references(FindReferencesParams {
+        project_key: doc.project_key,
+        path: path.clone(),
+        symbol_at,
+        include_declaration: true,
+    });
+    let references = match result {
+        Ok(result) => result.references,
+        // Renaming is only supported for some languages
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let Some(reference) = references
+        .into_iter()
+        .find(|reference| reference.path == path && reference.range.contains_inclusive(symbol_at))
+    else {
+        return Ok(None);
+    };
+
+    let range = to_proto::range(&doc.line_index, reference.range, position_encoding)?;
+    Ok(Some(PrepareRenameResponse::Range(range)))
+}
+
+/// Handler for the `textDocument/rename` LSP request
+///
+/// The returned edit may span multiple files when the renamed symbol is
+/// imported by other modules.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn rename(
+    session: &Session,
+    params: lsp::RenameParams,
+) -> Result<Option<lsp::WorkspaceEdit>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(&doc.line_index, position, position_encoding)?;
+
+    let result = session.workspace.rename(RenameParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at,
+        new_name: params.new_name,
+    });
+    let result = match result {
+        Ok(result) => result,
+        // Renaming is only supported for some languages
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut changes = HashMap::new();
+    changes.insert(
+        url.clone(),
+        text_edit(&doc.line_index, result.indels, position_encoding, None)?,
+    );
+
+    for file_edit in result.other_files {
+        let (file_url, line_index) =
+            url_and_line_index(session, doc.project_key, &url, file_edit.path)?;
+        let edits = text_edit(&line_index, file_edit.indels, position_encoding, None)?;
+        changes.insert(file_url, edits);
+    }
+
+    Ok(Some(lsp::WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    }))
+}
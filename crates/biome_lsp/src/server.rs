@@ -490,6 +490,44 @@ impl LanguageServer for LSPServer {
 
         self.map_op_error(result).await
     }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::goto_definition(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::references(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::rename::prepare_rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::rename::rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
 }
 
 impl Drop for LSPServer {
@@ -678,6 +716,10 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, goto_definition);
+        workspace_method!(builder, find_references);
+        workspace_method!(builder, get_module_dependencies);
+        workspace_method!(builder, get_module_graph);
         workspace_method!(builder, parse_pattern);
         workspace_method!(builder, search_pattern);
         workspace_method!(builder, drop_pattern);
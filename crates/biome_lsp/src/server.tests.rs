@@ -311,6 +311,54 @@ impl Server {
     }
 
     /// When calling this function, remember to insert the file inside the memory file system
+    async fn goto_definition(
+        &mut self,
+        uri: Uri,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<lsp::GotoDefinitionResponse>> {
+        self.request(
+            "textDocument/definition",
+            "goto_definition",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri },
+                    position: Position::new(line, character),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("goto_definition returned None")
+    }
+
+    async fn references(
+        &mut self,
+        uri: Uri,
+        line: u32,
+        character: u32,
+        include_declaration: bool,
+    ) -> Result<Option<Vec<lsp::Location>>> {
+        self.request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri },
+                    position: Position::new(line, character),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+                context: lsp::ReferenceContext {
+                    include_declaration,
+                },
+            },
+        )
+        .await?
+        .context("references returned None")
+    }
+
     async fn load_configuration(&mut self) -> Result<()> {
         self.notify(
             "workspace/didChangeConfiguration",
@@ -865,6 +913,287 @@ async fn document_range_formatting() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn rename_symbol() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .notify(
+            "textDocument/didOpen",
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri!("document.js"),
+                    language_id: String::from("javascript"),
+                    version: 0,
+                    text: String::from("let foo = 1;\nfoo + 1;\n"),
+                },
+            },
+        )
+        .await?;
+
+    let res: Option<PrepareRenameResponse> = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                position: Position::new(1, 1),
+            },
+        )
+        .await?
+        .context("prepare_rename returned None")?;
+
+    assert_eq!(
+        res,
+        Some(PrepareRenameResponse::Range(Range::new(
+            Position::new(1, 0),
+            Position::new(1, 3)
+        )))
+    );
+
+    let res: Option<WorkspaceEdit> = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            RenameParams {
+                text_document_position: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri!("document.js"),
+                    },
+                    position: Position::new(1, 1),
+                },
+                new_name: String::from("bar"),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+
+    let changes = res
+        .context("rename did not return an edit")?
+        .changes
+        .context("rename did not return any changes")?;
+    assert_eq!(
+        changes.get(&uri!("document.js")),
+        Some(&vec![
+            TextEdit::new(
+                Range::new(Position::new(0, 4), Position::new(0, 7)),
+                "bar".to_string()
+            ),
+            TextEdit::new(
+                Range::new(Position::new(1, 0), Position::new(1, 3)),
+                "bar".to_string()
+            ),
+        ])
+    );
+    assert_eq!(changes.len(), 1);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn goto_definition_and_references_of_local_binding() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(
+            "let foo = 1;\nfoo + 1;\n",
+            uri!("document.js"),
+            "javascript",
+        )
+        .await?;
+
+    let definition = server.goto_definition(uri!("document.js"), 1, 1).await?;
+    assert_eq!(
+        definition,
+        Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(
+            uri!("document.js"),
+            Range::new(Position::new(0, 4), Position::new(0, 7))
+        )))
+    );
+
+    let references = server.references(uri!("document.js"), 1, 1, true).await?;
+    assert_eq!(
+        references,
+        Some(vec![
+            lsp::Location::new(
+                uri!("document.js"),
+                Range::new(Position::new(0, 4), Position::new(0, 7))
+            ),
+            lsp::Location::new(
+                uri!("document.js"),
+                Range::new(Position::new(1, 0), Position::new(1, 3))
+            ),
+        ])
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn goto_definition_and_references_across_files() -> Result<()> {
+    const FILE_A_CONTENT: &str = "export function foo() {}\n";
+    const FILE_B_CONTENT: &str = "import { foo } from './a.ts';\nfoo();\n";
+
+    let mut fs = MemoryFileSystem::default();
+    // Navigating across files relies on the module graph, which is populated
+    // when a rule of the project domain is enabled.
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{ "linter": { "rules": { "nursery": { "noImportCycles": "error" } } } }"#,
+    );
+    fs.insert(to_utf8_file_path_buf(uri!("a.ts")), FILE_A_CONTENT);
+    fs.insert(to_utf8_file_path_buf(uri!("b.ts")), FILE_B_CONTENT);
+
+    let factory = ServerFactory::new_with_fs(Box::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(FILE_B_CONTENT, uri!("b.ts"), "typescript")
+        .await?;
+
+    let definition = server.goto_definition(uri!("b.ts"), 1, 1).await?;
+    assert_eq!(
+        definition,
+        Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(
+            uri!("a.ts"),
+            Range::new(Position::new(0, 16), Position::new(0, 19))
+        )))
+    );
+
+    let mut references = server
+        .references(uri!("b.ts"), 1, 1, true)
+        .await?
+        .context("references returned None")?;
+    references.sort_by_key(|location| (location.uri.to_string(), location.range.start));
+    assert_eq!(
+        references,
+        vec![
+            lsp::Location::new(
+                uri!("a.ts"),
+                Range::new(Position::new(0, 16), Position::new(0, 19))
+            ),
+            lsp::Location::new(
+                uri!("b.ts"),
+                Range::new(Position::new(0, 9), Position::new(0, 12))
+            ),
+            lsp::Location::new(
+                uri!("b.ts"),
+                Range::new(Position::new(1, 0), Position::new(1, 3))
+            ),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn goto_definition_and_references_through_renamed_import() -> Result<()> {
+    const FILE_A_CONTENT: &str = "export function foo() {}\n";
+    const FILE_B_CONTENT: &str = "import { foo as bar } from './a.ts';\nbar();\n";
+
+    let mut fs = MemoryFileSystem::default();
+    // Navigating across files relies on the module graph, which is populated
+    // when a rule of the project domain is enabled.
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{ "linter": { "rules": { "nursery": { "noImportCycles": "error" } } } }"#,
+    );
+    fs.insert(to_utf8_file_path_buf(uri!("a.ts")), FILE_A_CONTENT);
+    fs.insert(to_utf8_file_path_buf(uri!("b.ts")), FILE_B_CONTENT);
+
+    let factory = ServerFactory::new_with_fs(Box::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(FILE_B_CONTENT, uri!("b.ts"), "typescript")
+        .await?;
+
+    let definition = server.goto_definition(uri!("b.ts"), 1, 1).await?;
+    assert_eq!(
+        definition,
+        Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(
+            uri!("a.ts"),
+            Range::new(Position::new(0, 16), Position::new(0, 19))
+        )))
+    );
+
+    let mut references = server
+        .references(uri!("b.ts"), 1, 1, true)
+        .await?
+        .context("references returned None")?;
+    references.sort_by_key(|location| (location.uri.to_string(), location.range.start));
+    assert_eq!(
+        references,
+        vec![
+            lsp::Location::new(
+                uri!("a.ts"),
+                Range::new(Position::new(0, 16), Position::new(0, 19))
+            ),
+            lsp::Location::new(
+                uri!("b.ts"),
+                Range::new(Position::new(0, 16), Position::new(0, 19))
+            ),
+            lsp::Location::new(
+                uri!("b.ts"),
+                Range::new(Position::new(1, 0), Position::new(1, 3))
+            ),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_diagnostics() -> Result<()> {
     let factory = ServerFactory::default();
@@ -14,7 +14,7 @@ use biome_lsp_converters::{PositionEncoding, negotiated_encoding};
 use biome_service::Workspace;
 use biome_service::WorkspaceError;
 use biome_service::configuration::{LoadedConfiguration, load_configuration, load_editorconfig};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use biome_service::projects::ProjectKey;
 use biome_service::workspace::ServiceDataNotification;
 use biome_service::workspace::{
@@ -439,7 +439,6 @@ impl Session {
                 path: biome_path.clone(),
             })?;
             let offset = match biome_path.extension() {
-                Some("vue") => VueFileHandler::start(content.as_str()),
                 Some("astro") => AstroFileHandler::start(content.as_str()),
                 Some("svelte") => SvelteFileHandler::start(content.as_str()),
                 _ => None,
@@ -103,33 +103,45 @@ pub(crate) fn code_fix_to_lsp(
     offset: Option<u32>,
 ) -> Result<lsp::CodeAction> {
     // Mark diagnostics emitted by the same rule as resolved by this action
-    let diagnostics: Vec<_> = action
-        .rule_name
-        .as_ref()
-        .filter(|_| action.category.matches("quickfix"))
-        .map(|(group_name, rule_name)| {
-            diagnostics
-                .iter()
-                .filter_map(|d| {
-                    let code = d.code.as_ref()?;
-                    let code = match code {
-                        lsp::NumberOrString::String(code) => code.as_str(),
-                        lsp::NumberOrString::Number(_) => return None,
-                    };
-
-                    let code = code.strip_prefix("lint/")?;
-                    let code = code.strip_prefix(group_name.as_ref())?;
-                    let code = code.strip_prefix('/')?;
-
-                    if code == rule_name {
-                        Some(d.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        })
-        .unwrap_or_default();
+    let diagnostics: Vec<_> = if action.category.matches("quickfix.biome.plugin.") {
+        // Plugin rewrites apply to the whole document, so they resolve all the
+        // diagnostics emitted by plugins
+        diagnostics
+            .iter()
+            .filter(
+                |d| matches!(&d.code, Some(lsp::NumberOrString::String(code)) if code == "plugin"),
+            )
+            .cloned()
+            .collect()
+    } else {
+        action
+            .rule_name
+            .as_ref()
+            .filter(|_| action.category.matches("quickfix"))
+            .map(|(group_name, rule_name)| {
+                diagnostics
+                    .iter()
+                    .filter_map(|d| {
+                        let code = d.code.as_ref()?;
+                        let code = match code {
+                            lsp::NumberOrString::String(code) => code.as_str(),
+                            lsp::NumberOrString::Number(_) => return None,
+                        };
+
+                        let code = code.strip_prefix("lint/")?;
+                        let code = code.strip_prefix(group_name.as_ref())?;
+                        let code = code.strip_prefix('/')?;
+
+                        if code == rule_name {
+                            Some(d.clone())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
 
     let kind = action.category.to_str().into_owned();
     let suggestion = action.suggestion;
@@ -6,24 +6,33 @@ use biome_markdown_syntax::{
     MarkdownSyntaxToken as SyntaxToken, *,
 };
 use biome_rowan::AstNode;
-pub fn md_bullet(
-    bullet_token: SyntaxToken,
-    space_token: SyntaxToken,
-    content: MdInlineItemList,
-) -> MdBullet {
-    MdBullet::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_BULLET,
+pub fn md_autolink(
+    l_angle_token: SyntaxToken,
+    value_token: SyntaxToken,
+    r_angle_token: SyntaxToken,
+) -> MdAutolink {
+    MdAutolink::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_AUTOLINK,
         [
-            Some(SyntaxElement::Token(bullet_token)),
-            Some(SyntaxElement::Token(space_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(l_angle_token)),
+            Some(SyntaxElement::Token(value_token)),
+            Some(SyntaxElement::Token(r_angle_token)),
         ],
     ))
 }
-pub fn md_bullet_list_item(md_bullet_list: MdBulletList) -> MdBulletListItem {
+pub fn md_bullet_list(items: MdBulletListItemList) -> MdBulletList {
+    MdBulletList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_BULLET_LIST,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn md_bullet_list_item(marker_token: SyntaxToken, content: MdBlockList) -> MdBulletListItem {
     MdBulletListItem::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_BULLET_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
+        [
+            Some(SyntaxElement::Token(marker_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
     ))
 }
 pub fn md_document(value: MdBlockList, eof_token: SyntaxToken) -> MdDocumentBuilder {
@@ -31,23 +40,31 @@ pub fn md_document(value: MdBlockList, eof_token: SyntaxToken) -> MdDocumentBuil
         value,
         eof_token,
         bom_token: None,
+        front_matter: None,
     }
 }
 pub struct MdDocumentBuilder {
     value: MdBlockList,
     eof_token: SyntaxToken,
     bom_token: Option<SyntaxToken>,
+    front_matter: Option<MdFrontMatter>,
 }
 impl MdDocumentBuilder {
     pub fn with_bom_token(mut self, bom_token: SyntaxToken) -> Self {
         self.bom_token = Some(bom_token);
         self
     }
+    pub fn with_front_matter(mut self, front_matter: MdFrontMatter) -> Self {
+        self.front_matter = Some(front_matter);
+        self
+    }
     pub fn build(self) -> MdDocument {
         MdDocument::unwrap_cast(SyntaxNode::new_detached(
             MarkdownSyntaxKind::MD_DOCUMENT,
             [
                 self.bom_token.map(|token| SyntaxElement::Token(token)),
+                self.front_matter
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
                 Some(SyntaxElement::Node(self.value.into_syntax())),
                 Some(SyntaxElement::Token(self.eof_token)),
             ],
@@ -56,92 +73,82 @@ impl MdDocumentBuilder {
 }
 pub fn md_fenced_code_block(
     l_fence_token: SyntaxToken,
-    code_list: MdCodeNameList,
-    l_hard_line: MdHardLine,
-    content: MdTextual,
-    r_hard_line: MdHardLine,
-    r_fence_token: SyntaxToken,
-) -> MdFencedCodeBlock {
-    MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
-        [
-            Some(SyntaxElement::Token(l_fence_token)),
-            Some(SyntaxElement::Node(code_list.into_syntax())),
-            Some(SyntaxElement::Node(l_hard_line.into_syntax())),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Node(r_hard_line.into_syntax())),
-            Some(SyntaxElement::Token(r_fence_token)),
-        ],
-    ))
-}
-pub fn md_hard_line(value_token: SyntaxToken) -> MdHardLine {
-    MdHardLine::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_HARD_LINE,
-        [Some(SyntaxElement::Token(value_token))],
-    ))
-}
-pub fn md_hash(hash_token: SyntaxToken) -> MdHash {
-    MdHash::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_HASH,
-        [Some(SyntaxElement::Token(hash_token))],
-    ))
-}
-pub fn md_header(before: MdHashList, after: MdHashList) -> MdHeaderBuilder {
-    MdHeaderBuilder {
-        before,
-        after,
-        content: None,
+    content: MdVerbatimLineList,
+) -> MdFencedCodeBlockBuilder {
+    MdFencedCodeBlockBuilder {
+        l_fence_token,
+        content,
+        info_token: None,
+        r_fence_token: None,
     }
 }
-pub struct MdHeaderBuilder {
-    before: MdHashList,
-    after: MdHashList,
-    content: Option<MdParagraph>,
+pub struct MdFencedCodeBlockBuilder {
+    l_fence_token: SyntaxToken,
+    content: MdVerbatimLineList,
+    info_token: Option<SyntaxToken>,
+    r_fence_token: Option<SyntaxToken>,
 }
-impl MdHeaderBuilder {
-    pub fn with_content(mut self, content: MdParagraph) -> Self {
-        self.content = Some(content);
+impl MdFencedCodeBlockBuilder {
+    pub fn with_info_token(mut self, info_token: SyntaxToken) -> Self {
+        self.info_token = Some(info_token);
+        self
+    }
+    pub fn with_r_fence_token(mut self, r_fence_token: SyntaxToken) -> Self {
+        self.r_fence_token = Some(r_fence_token);
         self
     }
-    pub fn build(self) -> MdHeader {
-        MdHeader::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_HEADER,
+    pub fn build(self) -> MdFencedCodeBlock {
+        MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
             [
-                Some(SyntaxElement::Node(self.before.into_syntax())),
-                self.content
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-                Some(SyntaxElement::Node(self.after.into_syntax())),
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.info_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+                self.r_fence_token.map(|token| SyntaxElement::Token(token)),
             ],
         ))
     }
 }
-pub fn md_html_block(md_textual: MdTextual) -> MdHtmlBlock {
-    MdHtmlBlock::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_HTML_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+pub fn md_front_matter(value_token: SyntaxToken) -> MdFrontMatter {
+    MdFrontMatter::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_FRONT_MATTER,
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn md_indent(value_token: SyntaxToken) -> MdIndent {
-    MdIndent::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENT,
+pub fn md_hard_line(value_token: SyntaxToken) -> MdHardLine {
+    MdHardLine::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_HARD_LINE,
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn md_indent_code_block(lines: MdIndentedCodeLineList) -> MdIndentCodeBlock {
-    MdIndentCodeBlock::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK,
-        [Some(SyntaxElement::Node(lines.into_syntax()))],
+pub fn md_hash(value_token: SyntaxToken) -> MdHash {
+    MdHash::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_HASH,
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn md_indented_code_line(indentation: MdIndent, content: MdTextual) -> MdIndentedCodeLine {
-    MdIndentedCodeLine::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENTED_CODE_LINE,
+pub fn md_header(before: MdHashList, content: MdInlineItemList, after: MdHashList) -> MdHeader {
+    MdHeader::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_HEADER,
         [
-            Some(SyntaxElement::Node(indentation.into_syntax())),
+            Some(SyntaxElement::Node(before.into_syntax())),
             Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Node(after.into_syntax())),
         ],
     ))
 }
+pub fn md_html_block(content: MdVerbatimLineList) -> MdHtmlBlock {
+    MdHtmlBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_HTML_BLOCK,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
+}
+pub fn md_indent_code_block(content: MdVerbatimLineList) -> MdIndentCodeBlock {
+    MdIndentCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
+}
 pub fn md_inline_code(
     l_tick_token: SyntaxToken,
     content: MdInlineItemList,
@@ -170,88 +177,30 @@ pub fn md_inline_emphasis(
         ],
     ))
 }
-pub fn md_inline_image(
-    l_brack_token: SyntaxToken,
-    excl_token: SyntaxToken,
-    alt: MdInlineImageAlt,
-    source: MdInlineImageSource,
-    r_brack_token: SyntaxToken,
-) -> MdInlineImageBuilder {
-    MdInlineImageBuilder {
-        l_brack_token,
-        excl_token,
-        alt,
-        source,
-        r_brack_token,
-        link: None,
-    }
+pub fn md_inline_html(value: MdInlineItemList) -> MdInlineHtml {
+    MdInlineHtml::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_HTML,
+        [Some(SyntaxElement::Node(value.into_syntax()))],
+    ))
 }
-pub struct MdInlineImageBuilder {
-    l_brack_token: SyntaxToken,
+pub fn md_inline_image(
     excl_token: SyntaxToken,
-    alt: MdInlineImageAlt,
-    source: MdInlineImageSource,
-    r_brack_token: SyntaxToken,
-    link: Option<MdInlineImageLink>,
-}
-impl MdInlineImageBuilder {
-    pub fn with_link(mut self, link: MdInlineImageLink) -> Self {
-        self.link = Some(link);
-        self
-    }
-    pub fn build(self) -> MdInlineImage {
-        MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_INLINE_IMAGE,
-            [
-                Some(SyntaxElement::Token(self.l_brack_token)),
-                Some(SyntaxElement::Token(self.excl_token)),
-                Some(SyntaxElement::Node(self.alt.into_syntax())),
-                Some(SyntaxElement::Node(self.source.into_syntax())),
-                Some(SyntaxElement::Token(self.r_brack_token)),
-                self.link
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-            ],
-        ))
-    }
-}
-pub fn md_inline_image_alt(
     l_brack_token: SyntaxToken,
-    content: MdInlineItemList,
+    alt: MdInlineItemList,
     r_brack_token: SyntaxToken,
-) -> MdInlineImageAlt {
-    MdInlineImageAlt::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_ALT,
-        [
-            Some(SyntaxElement::Token(l_brack_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Token(r_brack_token)),
-        ],
-    ))
-}
-pub fn md_inline_image_link(
     l_paren_token: SyntaxToken,
-    content: MdInlineItemList,
-    r_paren_token: SyntaxToken,
-) -> MdInlineImageLink {
-    MdInlineImageLink::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_LINK,
-        [
-            Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Token(r_paren_token)),
-        ],
-    ))
-}
-pub fn md_inline_image_source(
-    l_paren_token: SyntaxToken,
-    content: MdInlineItemList,
+    source: MdInlineItemList,
     r_paren_token: SyntaxToken,
-) -> MdInlineImageSource {
-    MdInlineImageSource::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_SOURCE,
+) -> MdInlineImage {
+    MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_IMAGE,
         [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(alt.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
             Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Node(source.into_syntax())),
             Some(SyntaxElement::Token(r_paren_token)),
         ],
     ))
@@ -290,60 +239,86 @@ pub fn md_inline_link(
         ],
     ))
 }
-pub fn md_link_block(label: MdTextual, url: MdTextual) -> MdLinkBlockBuilder {
-    MdLinkBlockBuilder {
-        label,
-        url,
-        title: None,
-    }
+pub fn md_inline_strikethrough(
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    r_fence_token: SyntaxToken,
+) -> MdInlineStrikethrough {
+    MdInlineStrikethrough::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_STRIKETHROUGH,
+        [
+            Some(SyntaxElement::Token(l_fence_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(r_fence_token)),
+        ],
+    ))
 }
-pub struct MdLinkBlockBuilder {
-    label: MdTextual,
-    url: MdTextual,
-    title: Option<MdTextual>,
+pub fn md_link_reference_definition(
+    l_brack_token: SyntaxToken,
+    label: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+    colon_token: SyntaxToken,
+    destination: MdInlineItemList,
+) -> MdLinkReferenceDefinition {
+    MdLinkReferenceDefinition::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LINK_REFERENCE_DEFINITION,
+        [
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(label.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(destination.into_syntax())),
+        ],
+    ))
 }
-impl MdLinkBlockBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
-        self.title = Some(title);
-        self
-    }
-    pub fn build(self) -> MdLinkBlock {
-        MdLinkBlock::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_LINK_BLOCK,
-            [
-                Some(SyntaxElement::Node(self.label.into_syntax())),
-                Some(SyntaxElement::Node(self.url.into_syntax())),
-                self.title
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-            ],
-        ))
-    }
+pub fn md_newline(value_token: SyntaxToken) -> MdNewline {
+    MdNewline::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_NEWLINE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
 }
-pub fn md_order_list_item(md_bullet_list: MdBulletList) -> MdOrderListItem {
-    MdOrderListItem::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_ORDER_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
+pub fn md_ordered_list(items: MdOrderedListItemList) -> MdOrderedList {
+    MdOrderedList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_ORDERED_LIST,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
     ))
 }
-pub fn md_paragraph(list: MdInlineItemList, hard_line: MdHardLine) -> MdParagraph {
-    MdParagraph::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_PARAGRAPH,
+pub fn md_ordered_list_item(marker_token: SyntaxToken, content: MdBlockList) -> MdOrderedListItem {
+    MdOrderedListItem::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM,
         [
-            Some(SyntaxElement::Node(list.into_syntax())),
-            Some(SyntaxElement::Node(hard_line.into_syntax())),
+            Some(SyntaxElement::Token(marker_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
         ],
     ))
 }
-pub fn md_quote(any_md_block: AnyMdBlock) -> MdQuote {
+pub fn md_paragraph(content: MdInlineItemList) -> MdParagraph {
+    MdParagraph::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_PARAGRAPH,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
+}
+pub fn md_quote(marker_token: SyntaxToken, content: MdBlockList) -> MdQuote {
     MdQuote::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_QUOTE,
-        [Some(SyntaxElement::Node(any_md_block.into_syntax()))],
+        [
+            Some(SyntaxElement::Token(marker_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
     ))
 }
-pub fn md_setext_header(md_paragraph: MdParagraph) -> MdSetextHeader {
+pub fn md_setext_header(
+    content: MdInlineItemList,
+    line_break_token: SyntaxToken,
+    underline_token: SyntaxToken,
+) -> MdSetextHeader {
     MdSetextHeader::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_SETEXT_HEADER,
-        [Some(SyntaxElement::Node(md_paragraph.into_syntax()))],
+        [
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(line_break_token)),
+            Some(SyntaxElement::Token(underline_token)),
+        ],
     ))
 }
 pub fn md_soft_break(value_token: SyntaxToken) -> MdSoftBreak {
@@ -352,6 +327,62 @@ pub fn md_soft_break(value_token: SyntaxToken) -> MdSoftBreak {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn md_table(header: MdTableRow, delimiter: MdTableRow, rows: MdTableRowList) -> MdTable {
+    MdTable::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE,
+        [
+            Some(SyntaxElement::Node(header.into_syntax())),
+            Some(SyntaxElement::Node(delimiter.into_syntax())),
+            Some(SyntaxElement::Node(rows.into_syntax())),
+        ],
+    ))
+}
+pub fn md_table_cell(content: MdInlineItemList) -> MdTableCell {
+    MdTableCell::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_CELL,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
+}
+pub fn md_table_row(cells: MdTableCellList) -> MdTableRowBuilder {
+    MdTableRowBuilder {
+        cells,
+        line_break_token: None,
+        l_pipe_token: None,
+        r_pipe_token: None,
+    }
+}
+pub struct MdTableRowBuilder {
+    cells: MdTableCellList,
+    line_break_token: Option<SyntaxToken>,
+    l_pipe_token: Option<SyntaxToken>,
+    r_pipe_token: Option<SyntaxToken>,
+}
+impl MdTableRowBuilder {
+    pub fn with_line_break_token(mut self, line_break_token: SyntaxToken) -> Self {
+        self.line_break_token = Some(line_break_token);
+        self
+    }
+    pub fn with_l_pipe_token(mut self, l_pipe_token: SyntaxToken) -> Self {
+        self.l_pipe_token = Some(l_pipe_token);
+        self
+    }
+    pub fn with_r_pipe_token(mut self, r_pipe_token: SyntaxToken) -> Self {
+        self.r_pipe_token = Some(r_pipe_token);
+        self
+    }
+    pub fn build(self) -> MdTableRow {
+        MdTableRow::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_ROW,
+            [
+                self.line_break_token
+                    .map(|token| SyntaxElement::Token(token)),
+                self.l_pipe_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.cells.into_syntax())),
+                self.r_pipe_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
 pub fn md_textual(value_token: SyntaxToken) -> MdTextual {
     MdTextual::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_TEXTUAL,
@@ -376,82 +407,94 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_bullet_list<I>(items: I) -> MdBulletList
+pub fn md_bullet_list_item_list<I>(items: I) -> MdBulletListItemList
 where
-    I: IntoIterator<Item = MdBullet>,
+    I: IntoIterator<Item = MdBulletListItem>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdBulletList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_BULLET_LIST,
+    MdBulletListItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_BULLET_LIST_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_code_name_list<I, S>(items: I, separators: S) -> MdCodeNameList
+pub fn md_hash_list<I>(items: I) -> MdHashList
 where
-    I: IntoIterator<Item = MdTextual>,
+    I: IntoIterator<Item = MdHash>,
     I::IntoIter: ExactSizeIterator,
-    S: IntoIterator<Item = MarkdownSyntaxToken>,
-    S::IntoIter: ExactSizeIterator,
 {
-    let mut items = items.into_iter();
-    let mut separators = separators.into_iter();
-    let length = items.len() + separators.len();
-    MdCodeNameList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_CODE_NAME_LIST,
-        (0..length).map(|index| {
-            if index % 2 == 0 {
-                Some(items.next()?.into_syntax().into())
-            } else {
-                Some(separators.next()?.into())
-            }
-        }),
+    MdHashList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_HASH_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_hash_list<I>(items: I) -> MdHashList
+pub fn md_inline_item_list<I>(items: I) -> MdInlineItemList
 where
-    I: IntoIterator<Item = MdHash>,
+    I: IntoIterator<Item = AnyMdInline>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdHashList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_HASH_LIST,
+    MdInlineItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_indented_code_line_list<I>(items: I) -> MdIndentedCodeLineList
+pub fn md_ordered_list_item_list<I>(items: I) -> MdOrderedListItemList
 where
-    I: IntoIterator<Item = MdIndentedCodeLine>,
+    I: IntoIterator<Item = MdOrderedListItem>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdIndentedCodeLineList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENTED_CODE_LINE_LIST,
+    MdOrderedListItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_inline_item_list<I>(items: I) -> MdInlineItemList
+pub fn md_table_cell_list<I, S>(items: I, separators: S) -> MdTableCellList
 where
-    I: IntoIterator<Item = AnyMdInline>,
+    I: IntoIterator<Item = MdTableCell>,
     I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = MarkdownSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
 {
-    MdInlineItemList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_ITEM_LIST,
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    MdTableCellList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_CELL_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn md_table_row_list<I>(items: I) -> MdTableRowList
+where
+    I: IntoIterator<Item = MdTableRow>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MdTableRowList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_ROW_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_order_list<I>(items: I) -> MdOrderList
+pub fn md_verbatim_line_list<I>(items: I) -> MdVerbatimLineList
 where
-    I: IntoIterator<Item = AnyCodeBlock>,
+    I: IntoIterator<Item = AnyMdVerbatimLine>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdOrderList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_ORDER_LIST,
+    MdVerbatimLineList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_VERBATIM_LINE_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
@@ -15,12 +15,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
     ) -> RawSyntaxNode<Self::Kind> {
         match kind {
             MD_BOGUS => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
-            MD_BULLET => {
+            MD_AUTOLINK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [-] | T ! [*]) {
+                    if element.kind() == T ! [<] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -34,7 +34,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if element.kind() == T ! [>] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -42,18 +42,44 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_BULLET.to_bogus(),
+                        MD_AUTOLINK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_BULLET, children)
+                slots.into_node(MD_AUTOLINK, children)
             }
-            MD_BULLET_LIST_ITEM => {
+            MD_BULLET_LIST => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if MdBulletListItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_BULLET_LIST.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_BULLET_LIST, children)
+            }
+            MD_BULLET_LIST_ITEM => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [-] | T ! [*] | T ! [+]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -69,7 +95,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_DOCUMENT => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T![UNICODE_BOM] {
@@ -78,6 +104,13 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdFrontMatter::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element {
                     if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
@@ -102,45 +135,50 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_FENCED_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!["```"] {
+                    if element.kind() == MD_CODE_FENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdCodeNameList::can_cast(element.kind()) {
+                    if element.kind() == MD_TEXTUAL_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if MdVerbatimLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_CODE_FENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_FENCED_CODE_BLOCK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(MD_FENCED_CODE_BLOCK, children)
+            }
+            MD_FRONT_MATTER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!["```"] {
+                    if element.kind() == MD_FRONT_MATTER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -148,11 +186,11 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_FENCED_CODE_BLOCK.to_bogus(),
+                        MD_FRONT_MATTER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_FENCED_CODE_BLOCK, children)
+                slots.into_node(MD_FRONT_MATTER, children)
             }
             MD_HARD_LINE => {
                 let mut elements = (&children).into_iter();
@@ -201,7 +239,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdParagraph::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -227,7 +265,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdVerbatimLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -241,12 +279,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_HTML_BLOCK, children)
             }
-            MD_INDENT => {
+            MD_INDENT_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_INDENT_CHUNK_LITERAL {
+                    if MdVerbatimLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -254,44 +292,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENT.to_bogus(),
+                        MD_INDENT_CODE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENT, children)
+                slots.into_node(MD_INDENT_CODE_BLOCK, children)
             }
-            MD_INDENT_CODE_BLOCK => {
+            MD_INLINE_CODE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdIndentedCodeLineList::can_cast(element.kind()) {
+                    if element.kind() == MD_BACKTICKS_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_INDENT_CODE_BLOCK.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(MD_INDENT_CODE_BLOCK, children)
-            }
-            MD_INDENTED_CODE_LINE => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdIndent::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_BACKTICKS_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -299,18 +325,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENTED_CODE_LINE.to_bogus(),
+                        MD_INLINE_CODE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENTED_CODE_LINE, children)
+                slots.into_node(MD_INLINE_CODE, children)
             }
-            MD_INLINE_CODE => {
+            MD_INLINE_EMPHASIS => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['`'] {
+                    if matches!(element.kind(), T ! [**] | T!["__"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -324,7 +350,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['`'] {
+                    if matches!(element.kind(), T ! [**] | T!["__"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -332,23 +358,16 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_CODE.to_bogus(),
+                        MD_INLINE_EMPHASIS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_CODE, children)
+                slots.into_node(MD_INLINE_EMPHASIS, children)
             }
-            MD_INLINE_EMPHASIS => {
+            MD_INLINE_HTML => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [**] | T!["__"]) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if let Some(element) = &current_element {
                     if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
@@ -356,25 +375,25 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [**] | T!["__"]) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_EMPHASIS.to_bogus(),
+                        MD_INLINE_HTML.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_EMPHASIS, children)
+                slots.into_node(MD_INLINE_HTML, children)
             }
             MD_INLINE_IMAGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<7usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![!] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['['] {
                         slots.mark_present();
@@ -383,35 +402,35 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![!] {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageAlt::can_cast(element.kind()) {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageSource::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageLink::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -425,12 +444,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_INLINE_IMAGE, children)
             }
-            MD_INLINE_IMAGE_ALT => {
+            MD_INLINE_ITALIC => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['['] {
+                    if matches!(element.kind(), T ! [*] | T!["_"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -444,7 +463,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if matches!(element.kind(), T ! [*] | T!["_"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -452,18 +471,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_ALT.to_bogus(),
+                        MD_INLINE_ITALIC.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_ALT, children)
+                slots.into_node(MD_INLINE_ITALIC, children)
             }
-            MD_INLINE_IMAGE_LINK => {
+            MD_INLINE_LINK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -477,24 +496,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_LINK.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(MD_INLINE_IMAGE_LINK, children)
-            }
-            MD_INLINE_IMAGE_SOURCE => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['('] {
                         slots.mark_present();
@@ -518,18 +525,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_SOURCE.to_bogus(),
+                        MD_INLINE_LINK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_SOURCE, children)
+                slots.into_node(MD_INLINE_LINK, children)
             }
-            MD_INLINE_ITALIC => {
+            MD_INLINE_STRIKETHROUGH => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [*] | T!["_"]) {
+                    if matches!(element.kind(), T ! [~~] | T ! [~]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -543,7 +550,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [*] | T!["_"]) {
+                    if matches!(element.kind(), T ! [~~] | T ! [~]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -551,15 +558,15 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_ITALIC.to_bogus(),
+                        MD_INLINE_STRIKETHROUGH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_ITALIC, children)
+                slots.into_node(MD_INLINE_STRIKETHROUGH, children)
             }
-            MD_INLINE_LINK => {
+            MD_LINK_REFERENCE_DEFINITION => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['['] {
@@ -583,7 +590,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -596,8 +603,20 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_LINK_REFERENCE_DEFINITION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_LINK_REFERENCE_DEFINITION, children)
+            }
+            MD_NEWLINE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == NEWLINE {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -605,32 +624,44 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_LINK.to_bogus(),
+                        MD_NEWLINE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_LINK, children)
+                slots.into_node(MD_NEWLINE, children)
             }
-            MD_LINK_BLOCK => {
+            MD_ORDERED_LIST => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdOrderedListItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_ORDERED_LIST.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_ORDERED_LIST, children)
+            }
+            MD_ORDERED_LIST_ITEM => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_ORDERED_LIST_MARKER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -638,18 +669,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_LINK_BLOCK.to_bogus(),
+                        MD_ORDERED_LIST_ITEM.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_LINK_BLOCK, children)
+                slots.into_node(MD_ORDERED_LIST_ITEM, children)
             }
-            MD_ORDER_LIST_ITEM => {
+            MD_PARAGRAPH => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -657,16 +688,39 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_ORDER_LIST_ITEM.to_bogus(),
+                        MD_PARAGRAPH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_ORDER_LIST_ITEM, children)
+                slots.into_node(MD_PARAGRAPH, children)
             }
-            MD_PARAGRAPH => {
+            MD_QUOTE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [>] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdBlockList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(MD_QUOTE, children)
+            }
+            MD_SETEXT_HEADER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
@@ -675,7 +729,14 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if element.kind() == NEWLINE {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_SETEXT_UNDERLINE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -683,34 +744,67 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_PARAGRAPH.to_bogus(),
+                        MD_SETEXT_HEADER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_PARAGRAPH, children)
+                slots.into_node(MD_SETEXT_HEADER, children)
             }
-            MD_QUOTE => {
+            MD_SOFT_BREAK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyMdBlock::can_cast(element.kind()) {
+                    if element.kind() == MD_SOFT_BREAK_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
+                    return RawSyntaxNode::new(
+                        MD_SOFT_BREAK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.into_node(MD_QUOTE, children)
+                slots.into_node(MD_SOFT_BREAK, children)
             }
-            MD_SETEXT_HEADER => {
+            MD_TABLE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if MdTableRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableRowList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(MD_TABLE.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(MD_TABLE, children)
+            }
+            MD_TABLE_CELL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdParagraph::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -718,18 +812,39 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SETEXT_HEADER.to_bogus(),
+                        MD_TABLE_CELL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SETEXT_HEADER, children)
+                slots.into_node(MD_TABLE_CELL, children)
             }
-            MD_SOFT_BREAK => {
+            MD_TABLE_ROW => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_SOFT_BREAK_LITERAL {
+                    if element.kind() == NEWLINE {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableCellList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -737,11 +852,11 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SOFT_BREAK.to_bogus(),
+                        MD_TABLE_ROW.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SOFT_BREAK, children)
+                slots.into_node(MD_TABLE_ROW, children)
             }
             MD_TEXTUAL => {
                 let mut elements = (&children).into_iter();
@@ -782,22 +897,27 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.into_node(MD_THEMATIC_BREAK_BLOCK, children)
             }
             MD_BLOCK_LIST => Self::make_node_list_syntax(kind, children, AnyMdBlock::can_cast),
-            MD_BULLET_LIST => Self::make_node_list_syntax(kind, children, MdBullet::can_cast),
-            MD_CODE_NAME_LIST => Self::make_separated_list_syntax(
+            MD_BULLET_LIST_ITEM_LIST => {
+                Self::make_node_list_syntax(kind, children, MdBulletListItem::can_cast)
+            }
+            MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
+            MD_INLINE_ITEM_LIST => {
+                Self::make_node_list_syntax(kind, children, AnyMdInline::can_cast)
+            }
+            MD_ORDERED_LIST_ITEM_LIST => {
+                Self::make_node_list_syntax(kind, children, MdOrderedListItem::can_cast)
+            }
+            MD_TABLE_CELL_LIST => Self::make_separated_list_syntax(
                 kind,
                 children,
-                MdTextual::can_cast,
-                T ! [,],
+                MdTableCell::can_cast,
+                T ! [|],
                 false,
             ),
-            MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
-            MD_INDENTED_CODE_LINE_LIST => {
-                Self::make_node_list_syntax(kind, children, MdIndentedCodeLine::can_cast)
-            }
-            MD_INLINE_ITEM_LIST => {
-                Self::make_node_list_syntax(kind, children, AnyMdInline::can_cast)
+            MD_TABLE_ROW_LIST => Self::make_node_list_syntax(kind, children, MdTableRow::can_cast),
+            MD_VERBATIM_LINE_LIST => {
+                Self::make_node_list_syntax(kind, children, AnyMdVerbatimLine::can_cast)
             }
-            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
             _ => unreachable!("Is {:?} a token?", kind),
         }
     }
@@ -0,0 +1,176 @@
+use crate::CodeBlockFormatter;
+use crate::comments::{FormatMarkdownLeadingComment, MarkdownCommentStyle, MarkdownComments};
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
+    TransformSourceMap,
+};
+use biome_formatter::{IndentWidth, prelude::*};
+use biome_markdown_syntax::MarkdownLanguage;
+use std::fmt;
+use std::rc::Rc;
+
+/// The marker of a container block, printed at the start of every line of its content.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum LinePrefix {
+    /// The `>` of a block quote
+    Quote,
+    /// The indentation of the content of a list item, in columns
+    Indent(usize),
+}
+
+#[derive(Debug)]
+pub struct MarkdownFormatContext {
+    options: MarkdownFormatOptions,
+    /// The comments of the nodes and tokens in the program.
+    comments: Rc<MarkdownComments>,
+    source_map: Option<TransformSourceMap>,
+    code_block_formatter: Option<Rc<dyn CodeBlockFormatter>>,
+    /// The markers of the containers of the block that is being formatted, from the outermost
+    line_prefix: Vec<LinePrefix>,
+    /// Whether the blocks of the list item that is being formatted are separated by a single
+    /// line break, because the list is tight.
+    is_in_tight_list_item: bool,
+}
+
+impl MarkdownFormatContext {
+    pub fn new(options: MarkdownFormatOptions, comments: MarkdownComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+            code_block_formatter: None,
+            line_prefix: Vec::new(),
+            is_in_tight_list_item: false,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    pub fn with_code_block_formatter(
+        mut self,
+        code_block_formatter: Option<Rc<dyn CodeBlockFormatter>>,
+    ) -> Self {
+        self.code_block_formatter = code_block_formatter;
+        self
+    }
+
+    pub(crate) fn code_block_formatter(&self) -> Option<&dyn CodeBlockFormatter> {
+        self.code_block_formatter.as_deref()
+    }
+
+    pub(crate) fn line_prefix(&self) -> &[LinePrefix] {
+        &self.line_prefix
+    }
+
+    pub(crate) fn push_line_prefix(&mut self, prefix: LinePrefix) {
+        self.line_prefix.push(prefix);
+    }
+
+    pub(crate) fn pop_line_prefix(&mut self) {
+        self.line_prefix.pop();
+    }
+
+    pub(crate) fn is_in_tight_list_item(&self) -> bool {
+        self.is_in_tight_list_item
+    }
+
+    /// Sets whether the list item that is being formatted is tight, and returns the previous value
+    pub(crate) fn set_in_tight_list_item(&mut self, is_tight: bool) -> bool {
+        std::mem::replace(&mut self.is_in_tight_list_item, is_tight)
+    }
+}
+
+impl FormatContext for MarkdownFormatContext {
+    type Options = MarkdownFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for MarkdownFormatContext {
+    type Language = MarkdownLanguage;
+    type Style = MarkdownCommentStyle;
+    type CommentRule = FormatMarkdownLeadingComment;
+
+    fn comments(&self) -> &MarkdownComments {
+        &self.comments
+    }
+}
+
+/// The options of the Markdown formatter.
+///
+/// There's no indent style option: the content of the list items must be indented with spaces
+/// to line up with the first character after the list marker.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MarkdownFormatOptions {
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+}
+
+impl MarkdownFormatOptions {
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+}
+
+impl FormatOptions for MarkdownFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        IndentStyle::Space
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for MarkdownFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())
+    }
+}
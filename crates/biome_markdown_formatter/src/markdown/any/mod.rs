@@ -0,0 +1,8 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block;
+pub(crate) mod code_block;
+pub(crate) mod container_block;
+pub(crate) mod inline;
+pub(crate) mod leaf_block;
+pub(crate) mod verbatim_line;
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_markdown_syntax::AnyMdVerbatimLine;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyMdVerbatimLine;
+impl FormatRule<AnyMdVerbatimLine> for FormatAnyMdVerbatimLine {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &AnyMdVerbatimLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        match node {
+            AnyMdVerbatimLine::MdNewline(node) => node.format().fmt(f),
+            AnyMdVerbatimLine::MdTextual(node) => node.format().fmt(f),
+        }
+    }
+}
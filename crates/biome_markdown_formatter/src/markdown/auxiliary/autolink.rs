@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdAutolink, MdAutolinkFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdAutolink;
+impl FormatNodeRule<MdAutolink> for FormatMdAutolink {
+    fn fmt_fields(&self, node: &MdAutolink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdAutolinkFields {
+            l_angle_token,
+            value_token,
+            r_angle_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_angle_token.format(),
+                value_token.format(),
+                r_angle_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use crate::context::LinePrefix;
+use crate::prelude::*;
+use crate::utils::block::list_sibling_index;
+use crate::utils::format_container_content;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdBulletListItem, MdBulletListItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBulletListItem;
+impl FormatNodeRule<MdBulletListItem> for FormatMdBulletListItem {
+    fn fmt_fields(&self, node: &MdBulletListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdBulletListItemFields { marker, content } = node.as_fields();
+
+        // Two lists that follow each other must use different markers, otherwise they would
+        // be merged in a single list
+        let is_alternate_list = node
+            .syntax()
+            .grand_parent()
+            .is_some_and(|list| list_sibling_index(&list) % 2 == 1);
+        let marker_text = if is_alternate_list { "*" } else { "-" };
+
+        write!(f, [format_replaced(&marker?, &text(marker_text))])?;
+        format_container_content(&content, LinePrefix::Indent(marker_text.len() + 1), f)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use crate::utils::block::is_newline;
+use crate::utils::{blank_line, line_break};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdDocument, MdDocumentFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdDocument;
+impl FormatNodeRule<MdDocument> for FormatMdDocument {
+    fn fmt_fields(&self, node: &MdDocument, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdDocumentFields {
+            bom_token,
+            front_matter,
+            value,
+            eof_token,
+        } = node.as_fields();
+
+        let has_blocks = value.iter().any(|block| !is_newline(&block));
+
+        write!(f, [bom_token.format(), front_matter.format()])?;
+
+        if front_matter.is_some() && has_blocks {
+            write!(f, [blank_line(), line_break()])?;
+        }
+
+        write!(f, [value.format()])?;
+
+        if front_matter.is_some() || has_blocks {
+            write!(f, [hard_line_break()])?;
+        }
+
+        write!(f, [format_removed(&eof_token?)])
+    }
+}
@@ -0,0 +1,97 @@
+use crate::prelude::*;
+use crate::utils::verbatim::{code_fence, indentation_before, verbatim_lines};
+use crate::utils::{blank_line, format_removed_node, line_break};
+use biome_formatter::{normalize_newlines, write};
+use biome_markdown_syntax::{MdFencedCodeBlock, MdFencedCodeBlockFields};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdFencedCodeBlock;
+impl FormatNodeRule<MdFencedCodeBlock> for FormatMdFencedCodeBlock {
+    fn fmt_fields(&self, node: &MdFencedCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdFencedCodeBlockFields {
+            l_fence_token,
+            info_token,
+            content,
+            r_fence_token,
+        } = node.as_fields();
+        let l_fence_token = l_fence_token?;
+
+        // The content lines lose as much indentation as the opening fence has
+        // https://spec.commonmark.org/0.31.2/#fenced-code-blocks
+        let mut fence_start = indentation_before(&l_fence_token);
+        fence_start
+            .text
+            .push_str(l_fence_token.leading_trivia().text());
+        let fence_indentation = fence_start.indentation();
+
+        let mut lines = verbatim_lines(&content, None)?;
+        if r_fence_token.is_some() {
+            // The indentation of the closing fence
+            lines.pop();
+        }
+        let lines: Vec<_> = lines
+            .iter()
+            .map(|line| line.strip_indentation(fence_indentation))
+            .collect();
+
+        let language = info_token
+            .as_ref()
+            .and_then(|info| info.text_trimmed().split_whitespace().next());
+        let formatted = match (language, f.context().code_block_formatter()) {
+            (Some(language), Some(formatter)) => {
+                let mut code = lines.join("\n");
+                code.push('\n');
+                formatter.format(language, &code)
+            }
+            _ => None,
+        };
+        let formatted = formatted.map(|code| normalize_newlines(&code, ['\r']).into_owned());
+        let lines: Vec<&str> = match &formatted {
+            Some(code) => code.trim_end_matches('\n').lines().collect(),
+            None => lines.iter().map(String::as_str).collect(),
+        };
+
+        // The closing fence must be longer than any sequence of backticks in the code,
+        // and an info string can't contain a backtick when the fence is made of backticks
+        let fence_char = if info_token
+            .as_ref()
+            .is_some_and(|info| info.text_trimmed().contains('`'))
+        {
+            '~'
+        } else {
+            '`'
+        };
+        let fence = code_fence(&lines, fence_char);
+
+        let position = l_fence_token.text_trimmed_range().start();
+        write!(
+            f,
+            [
+                format_replaced(&l_fence_token, &dynamic_text(&fence, position)),
+                info_token.format()
+            ]
+        )?;
+
+        format_removed_node(content.syntax(), f)?;
+        for line in lines {
+            if line.is_empty() {
+                write!(f, [blank_line()])?;
+            } else {
+                write!(f, [line_break(), dynamic_text(line, position)])?;
+            }
+        }
+
+        write!(f, [line_break()])?;
+        match r_fence_token {
+            Some(r_fence_token) => write!(
+                f,
+                [format_replaced(
+                    &r_fence_token,
+                    &dynamic_text(&fence, r_fence_token.text_trimmed_range().start())
+                )]
+            ),
+            None => write!(f, [dynamic_text(&fence, position)]),
+        }
+    }
+}
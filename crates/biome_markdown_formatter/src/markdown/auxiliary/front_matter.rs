@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use biome_formatter::{normalize_newlines, write};
+use biome_markdown_syntax::{MdFrontMatter, MdFrontMatterFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdFrontMatter;
+impl FormatNodeRule<MdFrontMatter> for FormatMdFrontMatter {
+    fn fmt_fields(&self, node: &MdFrontMatter, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdFrontMatterFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        // The front matter is printed as it is, it's written in another language
+        let text = normalize_newlines(value_token.text_trimmed(), ['\r']);
+        write!(
+            f,
+            [format_replaced(
+                &value_token,
+                &dynamic_text(&text, value_token.text_trimmed_range().start())
+            )]
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::{format_continuation_line, line_break};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHardLine, MdHardLineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHardLine;
+impl FormatNodeRule<MdHardLine> for FormatMdHardLine {
+    fn fmt_fields(&self, node: &MdHardLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHardLineFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        // The spaces before a hard line break are part of the trailing trivia of the
+        // previous token
+        let marker = if value_token.text_trimmed().starts_with('\\') {
+            "\\"
+        } else {
+            "  "
+        };
+
+        write!(
+            f,
+            [text(marker), format_replaced(&value_token, &line_break())]
+        )?;
+        format_continuation_line(&value_token, f)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use crate::utils::format_removed_node;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHeader, MdHeaderFields};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHeader;
+impl FormatNodeRule<MdHeader> for FormatMdHeader {
+    fn fmt_fields(&self, node: &MdHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHeaderFields {
+            before,
+            content,
+            after,
+        } = node.as_fields();
+
+        write!(f, [before.format()])?;
+
+        if !content.is_empty() {
+            write!(f, [text(" "), content.format()])?;
+        }
+
+        // The closing sequence is optional
+        for hash in after {
+            format_removed_node(hash.syntax(), f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use crate::utils::verbatim::{indentation_before, verbatim_lines};
+use crate::utils::{blank_line, format_removed_node, line_break};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHtmlBlock, MdHtmlBlockFields};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHtmlBlock;
+impl FormatNodeRule<MdHtmlBlock> for FormatMdHtmlBlock {
+    fn fmt_fields(&self, node: &MdHtmlBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHtmlBlockFields { content } = node.as_fields();
+
+        let first_line = content
+            .syntax()
+            .first_token()
+            .map(|token| indentation_before(&token));
+        let lines = verbatim_lines(&content, first_line)?;
+
+        format_removed_node(content.syntax(), f)?;
+        for (index, line) in lines.iter().enumerate() {
+            let text = line.strip_indentation(0);
+            if index > 0 {
+                if text.trim().is_empty() {
+                    write!(f, [blank_line()])?;
+                    continue;
+                }
+                write!(f, [line_break()])?;
+            }
+            write!(f, [dynamic_text(&text, line.position)])?;
+        }
+
+        Ok(())
+    }
+}
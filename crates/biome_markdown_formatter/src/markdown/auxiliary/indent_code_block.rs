@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use crate::utils::verbatim::{code_fence, indentation_before, verbatim_lines};
+use crate::utils::{blank_line, format_removed_node, line_break, spaces};
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdIndentCodeBlock, MdIndentCodeBlockFields};
+use biome_rowan::AstNode;
+
+/// The indentation of the lines of an indented code block
+/// https://spec.commonmark.org/0.31.2/#indented-code-blocks
+const CODE_INDENTATION: usize = 4;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdIndentCodeBlock;
+impl FormatNodeRule<MdIndentCodeBlock> for FormatMdIndentCodeBlock {
+    fn fmt_fields(&self, node: &MdIndentCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdIndentCodeBlockFields { content } = node.as_fields();
+
+        let first_line = content
+            .syntax()
+            .first_token()
+            .map(|token| indentation_before(&token));
+        let lines = verbatim_lines(&content, first_line)?;
+
+        format_removed_node(content.syntax(), f)?;
+        let lines: Vec<_> = lines
+            .iter()
+            .map(|line| (line.strip_indentation(CODE_INDENTATION), line.position))
+            .collect();
+
+        // An indented code block that follows a list would be part of its last item once the
+        // list is formatted
+        if follows_list(node) {
+            let code: Vec<_> = lines.iter().map(|(text, _)| text.as_str()).collect();
+            let fence = code_fence(&code, '`');
+            let position = node.syntax().text_trimmed_range().start();
+
+            write!(f, [dynamic_text(&fence, position)])?;
+            for (text, position) in &lines {
+                if text.is_empty() {
+                    write!(f, [blank_line()])?;
+                } else {
+                    write!(f, [line_break(), dynamic_text(text, *position)])?;
+                }
+            }
+            return write!(f, [line_break(), dynamic_text(&fence, position)]);
+        }
+
+        for (index, (text, position)) in lines.iter().enumerate() {
+            if index > 0 {
+                if text.is_empty() {
+                    write!(f, [blank_line()])?;
+                    continue;
+                }
+                write!(f, [line_break()])?;
+            }
+            write!(f, [spaces(CODE_INDENTATION), dynamic_text(text, *position)])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if the block that precedes `node` is a list
+fn follows_list(node: &MdIndentCodeBlock) -> bool {
+    let mut previous = node.syntax().prev_sibling();
+    while let Some(sibling) = previous {
+        match sibling.kind() {
+            MarkdownSyntaxKind::MD_NEWLINE => previous = sibling.prev_sibling(),
+            kind => {
+                return matches!(
+                    kind,
+                    MarkdownSyntaxKind::MD_BULLET_LIST | MarkdownSyntaxKind::MD_ORDERED_LIST
+                );
+            }
+        }
+    }
+    false
+}
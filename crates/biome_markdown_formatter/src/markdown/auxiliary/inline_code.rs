@@ -0,0 +1,49 @@
+use crate::prelude::*;
+use crate::utils::line_break;
+use biome_formatter::write;
+use biome_markdown_syntax::{AnyMdInline, MdInlineCode, MdInlineCodeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineCode;
+impl FormatNodeRule<MdInlineCode> for FormatMdInlineCode {
+    fn fmt_fields(&self, node: &MdInlineCode, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineCodeFields {
+            l_tick_token,
+            content,
+            r_tick_token,
+        } = node.as_fields();
+
+        write!(f, [l_tick_token.format()])?;
+
+        // The spaces at the end of a line are part of the code, including the spaces of a hard
+        // line break
+        for item in &content {
+            match item {
+                AnyMdInline::MdTextual(textual) => {
+                    f.comments().mark_suppression_checked(textual.syntax());
+                    let token = textual.value_token()?;
+                    let text =
+                        std::format!("{}{}", token.text_trimmed(), token.trailing_trivia().text());
+                    write!(
+                        f,
+                        [format_replaced(
+                            &token,
+                            &dynamic_text(&text, token.text_trimmed_range().start())
+                        )]
+                    )?;
+                }
+                AnyMdInline::MdHardLine(hard_line) => {
+                    f.comments().mark_suppression_checked(hard_line.syntax());
+                    let token = hard_line.value_token()?;
+                    if token.text_trimmed().starts_with('\\') {
+                        write!(f, [text("\\")])?;
+                    }
+                    write!(f, [format_replaced(&token, &line_break())])?;
+                }
+                item => write!(f, [item.format()])?,
+            }
+        }
+
+        write!(f, [r_tick_token.format()])
+    }
+}
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MarkdownSyntaxToken, MdInlineEmphasis, MdInlineEmphasisFields, T,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineEmphasis;
+impl FormatNodeRule<MdInlineEmphasis> for FormatMdInlineEmphasis {
+    fn fmt_fields(&self, node: &MdInlineEmphasis, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineEmphasisFields {
+            l_fence,
+            content,
+            r_fence,
+        } = node.as_fields();
+
+        // `**` could pair with another `*` of the block instead
+        let has_star = node
+            .syntax()
+            .ancestors()
+            .filter(|ancestor| ancestor.kind() == MarkdownSyntaxKind::MD_INLINE_ITEM_LIST)
+            .last()
+            .is_some_and(|content| content.text_trimmed().contains_char('*'));
+
+        let l_fence = l_fence?;
+        let r_fence = r_fence?;
+        if has_star {
+            write!(f, [l_fence.format(), content.format(), r_fence.format()])
+        } else {
+            write!(
+                f,
+                [
+                    FormatStrongFence(&l_fence),
+                    content.format(),
+                    FormatStrongFence(&r_fence)
+                ]
+            )
+        }
+    }
+}
+
+/// Strong emphasis uses `**`, because `__` can't be used inside a word
+struct FormatStrongFence<'a>(&'a MarkdownSyntaxToken);
+
+impl Format<MarkdownFormatContext> for FormatStrongFence<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        if self.0.kind() == T!["__"] {
+            write!(f, [format_replaced(self.0, &text("**"))])
+        } else {
+            write!(f, [self.0.format()])
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineHtml, MdInlineHtmlFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineHtml;
+impl FormatNodeRule<MdInlineHtml> for FormatMdInlineHtml {
+    fn fmt_fields(&self, node: &MdInlineHtml, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineHtmlFields { value } = node.as_fields();
+
+        write!(f, [value.format()])
+    }
+}
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineImage, MdInlineImageFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineImage;
+impl FormatNodeRule<MdInlineImage> for FormatMdInlineImage {
+    fn fmt_fields(&self, node: &MdInlineImage, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineImageFields {
+            excl_token,
+            l_brack_token,
+            alt,
+            r_brack_token,
+            l_paren_token,
+            source,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                excl_token.format(),
+                l_brack_token.format(),
+                alt.format(),
+                r_brack_token.format(),
+                l_paren_token.format(),
+                source.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineStrikethrough, MdInlineStrikethroughFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineStrikethrough;
+impl FormatNodeRule<MdInlineStrikethrough> for FormatMdInlineStrikethrough {
+    fn fmt_fields(
+        &self,
+        node: &MdInlineStrikethrough,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdInlineStrikethroughFields {
+            l_fence,
+            content,
+            r_fence,
+        } = node.as_fields();
+
+        write!(f, [l_fence.format(), content.format(), r_fence.format()])
+    }
+}
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use crate::utils::format_removed_node;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    AnyMdInline, MdLinkReferenceDefinition, MdLinkReferenceDefinitionFields,
+};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLinkReferenceDefinition;
+impl FormatNodeRule<MdLinkReferenceDefinition> for FormatMdLinkReferenceDefinition {
+    fn fmt_fields(
+        &self,
+        node: &MdLinkReferenceDefinition,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdLinkReferenceDefinitionFields {
+            l_brack_token,
+            label,
+            r_brack_token,
+            colon_token,
+            destination,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                label.format(),
+                r_brack_token.format(),
+                colon_token.format(),
+                text(" ")
+            ]
+        )?;
+
+        // The destination can start on the line after the colon: it's moved after the colon
+        let mut items = destination.iter().peekable();
+        if let Some(AnyMdInline::MdSoftBreak(line_break)) = items.peek() {
+            format_removed_node(line_break.syntax(), f)?;
+            items.next();
+        }
+
+        f.join().entries(items.formatted()).finish()
+    }
+}
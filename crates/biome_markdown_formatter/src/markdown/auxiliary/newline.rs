@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdNewline, MdNewlineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdNewline;
+impl FormatNodeRule<MdNewline> for FormatMdNewline {
+    fn fmt_fields(&self, node: &MdNewline, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdNewlineFields { value_token } = node.as_fields();
+
+        // The line breaks and the empty lines between the blocks are printed by the list
+        // that contains them
+        write!(f, [format_removed(&value_token?)])
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::block::is_loose_list;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderedList, MdOrderedListFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderedList;
+impl FormatNodeRule<MdOrderedList> for FormatMdOrderedList {
+    fn fmt_fields(&self, node: &MdOrderedList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderedListFields { items } = node.as_fields();
+
+        let contents: Vec<_> = items.iter().map(|item| item.content()).collect();
+        let is_tight = !is_loose_list(&contents);
+
+        let was_tight = f.context_mut().set_in_tight_list_item(is_tight);
+        let result = write!(f, [items.format()]);
+        f.context_mut().set_in_tight_list_item(was_tight);
+
+        result
+    }
+}
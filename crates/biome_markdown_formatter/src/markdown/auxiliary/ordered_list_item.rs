@@ -0,0 +1,54 @@
+use crate::context::LinePrefix;
+use crate::prelude::*;
+use crate::utils::format_container_content;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderedListItem, MdOrderedListItemFields, MdOrderedListItemList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderedListItem;
+impl FormatNodeRule<MdOrderedListItem> for FormatMdOrderedListItem {
+    fn fmt_fields(&self, node: &MdOrderedListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderedListItemFields {
+            marker_token,
+            content,
+        } = node.as_fields();
+        let marker_token = marker_token?;
+
+        let marker_text = marker_token.text_trimmed();
+        let delimiter = &marker_text[marker_text.len() - 1..];
+        let number = list_item_number(node).unwrap_or(1);
+        let marker = std::format!("{number}{delimiter}");
+
+        write!(
+            f,
+            [format_replaced(
+                &marker_token,
+                &dynamic_text(&marker, marker_token.text_trimmed_range().start())
+            )]
+        )?;
+        format_container_content(&content, LinePrefix::Indent(marker.len() + 1), f)
+    }
+}
+
+/// Returns the number of `item` in its list.
+///
+/// The items are numbered from the number of the first item, unless the first two items have
+/// the same number. In that case, all the items keep it.
+fn list_item_number(item: &MdOrderedListItem) -> Option<u64> {
+    let items = item.parent::<MdOrderedListItemList>()?;
+    let mut numbers = items.iter().map(|item| marker_number(&item));
+
+    let start = numbers.next()??;
+    let increment = match numbers.next() {
+        Some(Some(second)) if second == start => 0,
+        _ => 1,
+    };
+
+    Some(start + increment * item.syntax().index() as u64)
+}
+
+fn marker_number(item: &MdOrderedListItem) -> Option<u64> {
+    let marker = item.marker_token().ok()?;
+    let text = marker.text_trimmed();
+    text[..text.len() - 1].parse().ok()
+}
@@ -0,0 +1,19 @@
+use crate::context::LinePrefix;
+use crate::prelude::*;
+use crate::utils::format_container_content;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdQuote, MdQuoteFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuote;
+impl FormatNodeRule<MdQuote> for FormatMdQuote {
+    fn fmt_fields(&self, node: &MdQuote, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdQuoteFields {
+            marker_token,
+            content,
+        } = node.as_fields();
+
+        write!(f, [marker_token.format()])?;
+        format_container_content(&content, LinePrefix::Quote, f)
+    }
+}
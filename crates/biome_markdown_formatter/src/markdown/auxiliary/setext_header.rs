@@ -0,0 +1,64 @@
+use crate::prelude::*;
+use crate::utils::line_break;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdSetextHeader, MdSetextHeaderFields};
+use biome_rowan::Direction;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSetextHeader;
+impl FormatNodeRule<MdSetextHeader> for FormatMdSetextHeader {
+    fn fmt_fields(&self, node: &MdSetextHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSetextHeaderFields {
+            content,
+            line_break_token,
+            underline_token,
+        } = node.as_fields();
+        let line_break_token = line_break_token?;
+        let underline_token = underline_token?;
+
+        // An ATX heading can't span multiple lines, and a `#` at its end would be
+        // part of the closing sequence
+        let is_multiline = content
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .any(|token| {
+                matches!(
+                    token.kind(),
+                    MarkdownSyntaxKind::MD_SOFT_BREAK_LITERAL
+                        | MarkdownSyntaxKind::MD_HARD_LINE_LITERAL
+                )
+            });
+        let ends_with_hash = content
+            .syntax()
+            .last_token()
+            .is_some_and(|token| token.text_trimmed().ends_with('#'));
+
+        if is_multiline || ends_with_hash {
+            return write!(
+                f,
+                [
+                    content.format(),
+                    format_replaced(&line_break_token, &line_break()),
+                    underline_token.format()
+                ]
+            );
+        }
+
+        let hashes = if underline_token.text_trimmed().starts_with('=') {
+            "#"
+        } else {
+            "##"
+        };
+
+        write!(
+            f,
+            [
+                text(hashes),
+                text(" "),
+                content.format(),
+                format_removed(&line_break_token),
+                format_removed(&underline_token)
+            ]
+        )
+    }
+}
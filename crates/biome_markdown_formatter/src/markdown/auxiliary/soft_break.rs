@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use crate::utils::{format_continuation_line, line_break};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdSoftBreak, MdSoftBreakFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSoftBreak;
+impl FormatNodeRule<MdSoftBreak> for FormatMdSoftBreak {
+    fn fmt_fields(&self, node: &MdSoftBreak, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSoftBreakFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        write!(f, [format_replaced(&value_token, &line_break())])?;
+        format_continuation_line(&value_token, f)
+    }
+}
@@ -0,0 +1,188 @@
+use crate::prelude::*;
+use crate::utils::{format_removed_node, line_break};
+use biome_formatter::format_element::FormatElement;
+use biome_formatter::{Buffer, CstFormatContext, write};
+use biome_markdown_syntax::{MdTable, MdTableFields, MdTableRow};
+use biome_rowan::{AstNode, AstSeparatedList, Direction, TextSize};
+use unicode_width::UnicodeWidthStr;
+
+/// The minimum width of a column, so that its delimiter cell has at least three dashes
+/// https://github.github.com/gfm/#delimiter-row
+const MIN_COLUMN_WIDTH: usize = 3;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Alignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    fn from_delimiter(delimiter: &str) -> Self {
+        match (delimiter.starts_with(':'), delimiter.ends_with(':')) {
+            (true, true) if delimiter.len() > 1 => Self::Center,
+            (true, _) => Self::Left,
+            (false, true) => Self::Right,
+            (false, false) => Self::None,
+        }
+    }
+
+    fn delimiter(self, width: usize) -> String {
+        match self {
+            Self::None => "-".repeat(width),
+            Self::Left => std::format!(":{}", "-".repeat(width - 1)),
+            Self::Right => std::format!("{}:", "-".repeat(width - 1)),
+            Self::Center => std::format!(":{}:", "-".repeat(width - 2)),
+        }
+    }
+}
+
+/// The formatted content of a cell
+struct Cell {
+    content: Option<FormatElement>,
+    width: usize,
+}
+
+/// Aligns the columns of the table, and pads every cell to the width of its column.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTable;
+impl FormatNodeRule<MdTable> for FormatMdTable {
+    fn fmt_fields(&self, node: &MdTable, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableFields {
+            header,
+            delimiter,
+            rows,
+        } = node.as_fields();
+        let header = header?;
+        let delimiter = delimiter?;
+
+        let alignments: Vec<_> = delimiter
+            .cells()
+            .iter()
+            .map(|cell| {
+                let text: String = cell
+                    .map(|cell| cell.into_syntax())
+                    .into_iter()
+                    .flat_map(|cell| cell.descendants_tokens(Direction::Next))
+                    .map(|token| token.text_trimmed().to_string())
+                    .collect();
+                Alignment::from_delimiter(&text)
+            })
+            .collect();
+        format_removed_node(delimiter.syntax(), f)?;
+
+        let mut table = vec![format_row(&header, f)?];
+        for row in &rows {
+            table.push(format_row(&row, f)?);
+        }
+
+        let mut widths = vec![MIN_COLUMN_WIDTH; alignments.len()];
+        for row in &table {
+            for (cell, width) in row.iter().zip(widths.iter_mut()) {
+                *width = cell.width.max(*width);
+            }
+        }
+
+        let position = node.syntax().text_trimmed_range().start();
+        for (index, row) in table.iter().enumerate() {
+            if index > 0 {
+                write!(f, [line_break()])?;
+            }
+
+            write!(f, [text("|")])?;
+            for (column, cell) in row.iter().enumerate() {
+                let alignment = alignments.get(column).copied().unwrap_or(Alignment::None);
+                let padding = widths.get(column).map_or(0, |width| width - cell.width);
+                let (before, after) = match alignment {
+                    Alignment::None | Alignment::Left => (0, padding),
+                    Alignment::Right => (padding, 0),
+                    Alignment::Center => (padding / 2, padding - padding / 2),
+                };
+
+                write!(f, [text(" ")])?;
+                write_padding(before, position, f)?;
+                if let Some(content) = &cell.content {
+                    f.write_element(content.clone())?;
+                }
+                write_padding(after, position, f)?;
+                write!(f, [text(" |")])?;
+            }
+            // Cells missing at the end of the row
+            for width in widths.iter().skip(row.len()) {
+                write_padding(width + 1, position, f)?;
+                write!(f, [text(" |")])?;
+            }
+
+            if index == 0 {
+                write!(f, [line_break(), text("|")])?;
+                for (alignment, width) in alignments.iter().zip(&widths) {
+                    write!(
+                        f,
+                        [
+                            text(" "),
+                            dynamic_text(&alignment.delimiter(*width), position),
+                            text(" |")
+                        ]
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the cells of `row`, and removes its pipes and its line break
+fn format_row(row: &MdTableRow, f: &mut MarkdownFormatter) -> FormatResult<Vec<Cell>> {
+    f.context()
+        .comments()
+        .mark_suppression_checked(row.syntax());
+
+    if let Some(line_break) = row.line_break_token() {
+        write!(f, [format_removed(&line_break)])?;
+    }
+    if let Some(l_pipe) = row.l_pipe_token() {
+        write!(f, [format_removed(&l_pipe)])?;
+    }
+
+    let mut cells = Vec::new();
+    for element in row.cells().elements() {
+        let cell = element.node()?;
+        f.context()
+            .comments()
+            .mark_suppression_checked(cell.syntax());
+        let content = f.intern(&cell.content().format())?;
+        let width = content.as_ref().map_or(0, element_width);
+        cells.push(Cell { content, width });
+
+        if let Some(separator) = element.trailing_separator()? {
+            write!(f, [format_removed(separator)])?;
+        }
+    }
+
+    if let Some(r_pipe) = row.r_pipe_token() {
+        write!(f, [format_removed(&r_pipe)])?;
+    }
+
+    Ok(cells)
+}
+
+/// Returns the width of the text printed by `element`. The content of a cell is on one line.
+fn element_width(element: &FormatElement) -> usize {
+    match element {
+        FormatElement::Space | FormatElement::HardSpace => 1,
+        FormatElement::StaticText { text } => text.width(),
+        FormatElement::DynamicText { text, .. } => text.width(),
+        FormatElement::LocatedTokenText { slice, .. } => slice.width(),
+        FormatElement::Interned(interned) => interned.iter().map(element_width).sum(),
+        _ => 0,
+    }
+}
+
+fn write_padding(width: usize, position: TextSize, f: &mut MarkdownFormatter) -> FormatResult<()> {
+    if width > 0 {
+        write!(f, [dynamic_text(&" ".repeat(width), position)])?;
+    }
+    Ok(())
+}
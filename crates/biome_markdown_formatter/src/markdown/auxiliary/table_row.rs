@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use crate::utils::line_break;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTableRow, MdTableRowFields};
+
+/// Prints a row without aligning its cells. The rows of a table are aligned by the table.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableRow;
+impl FormatNodeRule<MdTableRow> for FormatMdTableRow {
+    fn fmt_fields(&self, node: &MdTableRow, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableRowFields {
+            line_break_token,
+            l_pipe_token,
+            cells,
+            r_pipe_token,
+        } = node.as_fields();
+
+        if let Some(line_break_token) = line_break_token {
+            write!(f, [format_replaced(&line_break_token, &line_break())])?;
+        }
+
+        match l_pipe_token {
+            Some(l_pipe_token) => write!(f, [l_pipe_token.format()])?,
+            None => write!(f, [text("|")])?,
+        }
+        write!(f, [space(), cells.format(), space()])?;
+        match r_pipe_token {
+            Some(r_pipe_token) => write!(f, [r_pipe_token.format()]),
+            None => write!(f, [text("|")]),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::block::list_sibling_index;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdThematicBreakBlock, MdThematicBreakBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdThematicBreakBlock;
+impl FormatNodeRule<MdThematicBreakBlock> for FormatMdThematicBreakBlock {
+    fn fmt_fields(
+        &self,
+        node: &MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdThematicBreakBlockFields { value_token } = node.as_fields();
+
+        // Inside a list whose items start with `-`, a `---` could be mistaken for an item
+        let is_in_dash_list = node
+            .syntax()
+            .ancestors()
+            .find(|ancestor| {
+                matches!(
+                    ancestor.kind(),
+                    MarkdownSyntaxKind::MD_BULLET_LIST | MarkdownSyntaxKind::MD_ORDERED_LIST
+                )
+            })
+            .is_some_and(|list| list_sibling_index(&list) % 2 == 0);
+        let marker = if is_in_dash_list { "***" } else { "---" };
+
+        write!(f, [format_replaced(&value_token?, &text(marker))])
+    }
+}
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+use crate::utils::block::{is_list_item_content, is_newline, trailing_line_breaks};
+use crate::utils::{blank_line, line_break};
+use biome_formatter::write;
+use biome_markdown_syntax::{AnyMdBlock, AnyMdLeafBlock, MdBlockList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBlockList;
+impl FormatRule<MdBlockList> for FormatMdBlockList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBlockList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let is_tight = is_list_item_content(node) && f.context().is_in_tight_list_item();
+
+        let mut previous: Option<AnyMdBlock> = None;
+        let mut line_breaks = 0;
+
+        for block in node {
+            if is_newline(&block) {
+                write!(f, [block.format()])?;
+                line_breaks += 1;
+                continue;
+            }
+
+            if let Some(previous) = &previous {
+                let has_empty_line = line_breaks + trailing_line_breaks(previous) > 1;
+                if !is_tight && needs_empty_line(previous, &block, has_empty_line) {
+                    write!(f, [blank_line()])?;
+                }
+                write!(f, [line_break()])?;
+            }
+
+            write!(f, [block.format()])?;
+
+            previous = Some(block);
+            line_breaks = 0;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if an empty line must separate `previous` from `next`.
+///
+/// The blocks are always separated by an empty line, unless they follow each other in the
+/// source and they don't need one: consecutive link reference definitions, and the blocks
+/// that follow an HTML block.
+fn needs_empty_line(previous: &AnyMdBlock, next: &AnyMdBlock, has_empty_line: bool) -> bool {
+    if has_empty_line {
+        return true;
+    }
+
+    !matches!(
+        (previous, next),
+        (
+            AnyMdBlock::AnyMdLeafBlock(AnyMdLeafBlock::MdLinkReferenceDefinition(_)),
+            AnyMdBlock::AnyMdLeafBlock(AnyMdLeafBlock::MdLinkReferenceDefinition(_))
+        ) | (
+            AnyMdBlock::AnyMdLeafBlock(AnyMdLeafBlock::MdHtmlBlock(_)),
+            _
+        )
+    )
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::{blank_line, line_break};
+use biome_formatter::write;
+use biome_markdown_syntax::MdOrderedListItemList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderedListItemList;
+impl FormatRule<MdOrderedListItemList> for FormatMdOrderedListItemList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdOrderedListItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let is_tight = f.context().is_in_tight_list_item();
+
+        for (index, item) in node.iter().enumerate() {
+            if index > 0 {
+                if !is_tight {
+                    write!(f, [blank_line()])?;
+                }
+                write!(f, [line_break()])?;
+            }
+            write!(f, [item.format()])?;
+        }
+
+        Ok(())
+    }
+}
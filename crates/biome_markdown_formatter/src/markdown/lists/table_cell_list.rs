@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::MdTableCellList;
+use biome_rowan::AstSeparatedList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableCellList;
+impl FormatRule<MdTableCellList> for FormatMdTableCellList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdTableCellList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        for element in node.elements() {
+            write!(f, [element.node()?.format()])?;
+            if let Some(separator) = element.trailing_separator()? {
+                write!(f, [space(), separator.format(), space()])?;
+            }
+        }
+        Ok(())
+    }
+}
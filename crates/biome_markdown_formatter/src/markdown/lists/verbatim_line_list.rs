@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdVerbatimLineList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdVerbatimLineList;
+impl FormatRule<MdVerbatimLineList> for FormatMdVerbatimLineList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdVerbatimLineList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        f.join().entries(node.iter().formatted()).finish()
+    }
+}
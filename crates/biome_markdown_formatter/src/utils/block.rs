@@ -0,0 +1,111 @@
+use biome_markdown_syntax::{
+    AnyMdBlock, AnyMdContainerBlock, AnyMdLeafBlock, MarkdownSyntaxKind, MarkdownSyntaxNode,
+    MdBlockList,
+};
+use biome_rowan::{AstNode, AstNodeList};
+
+/// Returns `true` if `block` is the line break at the end of a block or an empty line
+pub(crate) fn is_newline(block: &AnyMdBlock) -> bool {
+    matches!(
+        block,
+        AnyMdBlock::AnyMdLeafBlock(AnyMdLeafBlock::MdNewline(_))
+    )
+}
+
+/// Returns the number of line breaks at the end of `block`.
+///
+/// The last item of a list keeps the empty lines that follow it, even when the list ends
+/// after them.
+pub(crate) fn trailing_line_breaks(block: &AnyMdBlock) -> usize {
+    let last_item_content = match block {
+        AnyMdBlock::AnyMdContainerBlock(AnyMdContainerBlock::MdBulletList(list)) => {
+            list.items().last().map(|item| item.content())
+        }
+        AnyMdBlock::AnyMdContainerBlock(AnyMdContainerBlock::MdOrderedList(list)) => {
+            list.items().last().map(|item| item.content())
+        }
+        _ => None,
+    };
+
+    last_item_content.map_or(0, |content| trailing_line_breaks_in(&content))
+}
+
+/// Returns the number of line breaks at the end of the blocks of `list`
+pub(crate) fn trailing_line_breaks_in(list: &MdBlockList) -> usize {
+    let blocks: Vec<_> = list.iter().collect();
+
+    let mut count = 0;
+    for block in blocks.iter().rev() {
+        if !is_newline(block) {
+            return count + trailing_line_breaks(block);
+        }
+        count += 1;
+    }
+
+    count
+}
+
+/// Returns `true` if an empty line separates two of the blocks of `list`
+pub(crate) fn has_empty_line_between_blocks(list: &MdBlockList) -> bool {
+    let mut previous = None;
+    let mut line_breaks = 0;
+
+    for block in list {
+        if is_newline(&block) {
+            line_breaks += 1;
+            continue;
+        }
+
+        if previous
+            .as_ref()
+            .is_some_and(|previous| line_breaks + trailing_line_breaks(previous) > 1)
+        {
+            return true;
+        }
+
+        previous = Some(block);
+        line_breaks = 0;
+    }
+
+    false
+}
+
+/// Returns `true` if the list whose items have the blocks `items` is loose: its items are
+/// separated by empty lines, or an item contains two blocks separated by an empty line.
+/// https://spec.commonmark.org/0.31.2/#loose
+pub(crate) fn is_loose_list(items: &[MdBlockList]) -> bool {
+    items.iter().enumerate().any(|(index, content)| {
+        has_empty_line_between_blocks(content)
+            || (index + 1 < items.len() && trailing_line_breaks_in(content) > 1)
+    })
+}
+
+/// Returns the number of lists of the same kind as `list` that directly precede it.
+///
+/// Two lists of the same kind can only follow each other when they use different markers,
+/// so the formatter alternates between two markers.
+pub(crate) fn list_sibling_index(list: &MarkdownSyntaxNode) -> usize {
+    let mut index = 0;
+    let mut current = list.prev_sibling();
+
+    while let Some(node) = current {
+        match node.kind() {
+            MarkdownSyntaxKind::MD_NEWLINE => {}
+            kind if kind == list.kind() => index += 1,
+            _ => break,
+        }
+        current = node.prev_sibling();
+    }
+
+    index
+}
+
+/// Returns `true` if `node` is the content of a list item
+pub(crate) fn is_list_item_content(node: &MdBlockList) -> bool {
+    node.syntax().parent().is_some_and(|parent| {
+        matches!(
+            parent.kind(),
+            MarkdownSyntaxKind::MD_BULLET_LIST_ITEM | MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM
+        )
+    })
+}
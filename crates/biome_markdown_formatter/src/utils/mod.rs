@@ -0,0 +1,212 @@
+pub(crate) mod block;
+pub(crate) mod verbatim;
+
+use crate::context::LinePrefix;
+use crate::prelude::*;
+use crate::utils::block::is_newline;
+use crate::utils::verbatim::line_start_after;
+use biome_formatter::{CstFormatContext, write};
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MarkdownSyntaxNode, MarkdownSyntaxToken, MdBlockList,
+};
+use biome_rowan::Direction;
+
+/// Prints a line break followed by the markers of the containers of the block that is being
+/// formatted: the `>` of the block quotes and the indentation of the list items.
+///
+/// A blank line only prints the markers of the block quotes, without trailing whitespace.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct FormatLineBreak {
+    is_blank: bool,
+}
+
+/// Starts a new line inside the current container blocks
+pub(crate) const fn line_break() -> FormatLineBreak {
+    FormatLineBreak { is_blank: false }
+}
+
+/// Starts a new line that stays empty inside the current container blocks
+pub(crate) const fn blank_line() -> FormatLineBreak {
+    FormatLineBreak { is_blank: true }
+}
+
+impl Format<MarkdownFormatContext> for FormatLineBreak {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let prefix = f.context().line_prefix().to_vec();
+
+        let end = if self.is_blank {
+            prefix
+                .iter()
+                .rposition(|prefix| matches!(prefix, LinePrefix::Quote))
+                .map_or(0, |index| index + 1)
+        } else {
+            prefix.len()
+        };
+
+        write!(f, [text("\n")])?;
+
+        for (index, prefix) in prefix[..end].iter().enumerate() {
+            match prefix {
+                LinePrefix::Quote if self.is_blank && index + 1 == end => {
+                    write!(f, [text(">")])?;
+                }
+                LinePrefix::Quote => write!(f, [text("> ")])?,
+                LinePrefix::Indent(width) => write!(f, [spaces(*width)])?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints `width` spaces
+pub(crate) fn spaces(width: usize) -> FormatSpaces {
+    FormatSpaces { width }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct FormatSpaces {
+    width: usize,
+}
+
+impl Format<MarkdownFormatContext> for FormatSpaces {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        const SPACES: &str = "                ";
+
+        let mut remaining = self.width;
+        while remaining > 0 {
+            let width = remaining.min(SPACES.len());
+            write!(f, [text(&SPACES[..width])])?;
+            remaining -= width;
+        }
+
+        Ok(())
+    }
+}
+
+/// The width of a tab stop, used to compute the indentation of a line.
+/// https://spec.commonmark.org/0.31.2/#tabs
+const TAB_STOP: usize = 4;
+
+/// Returns the column of the tab stop that follows `column`
+pub(crate) fn next_tab_stop(column: usize) -> usize {
+    column + TAB_STOP - column % TAB_STOP
+}
+
+/// Returns the number of columns of `text` when it starts at `column`, once its tabs are
+/// expanded
+pub(crate) fn whitespace_width(text: &str, column: usize) -> usize {
+    let end = text.chars().fold(column, |column, char| match char {
+        '\t' => next_tab_stop(column),
+        _ => column + 1,
+    });
+    end - column
+}
+
+/// Prints the content of a block quote or of a list item, whose lines start with `prefix`.
+pub(crate) fn format_container_content(
+    content: &MdBlockList,
+    prefix: LinePrefix,
+    f: &mut MarkdownFormatter,
+) -> FormatResult<()> {
+    if content.iter().all(|block| is_newline(&block)) {
+        return write!(f, [content.format()]);
+    }
+
+    write!(f, [text(" ")])?;
+
+    f.context_mut().push_line_prefix(prefix);
+    let result = write!(f, [content.format()]);
+    f.context_mut().pop_line_prefix();
+
+    result
+}
+
+/// Prints the start of a continuation line of a paragraph, so that the line stays part of
+/// the paragraph.
+///
+/// A line indented by four or more columns can't start a block that interrupts the paragraph,
+/// so its indentation is kept when the line would start a block otherwise.
+/// https://spec.commonmark.org/0.31.2/#paragraph-continuation-text
+///
+/// A lazy continuation line is printed with the markers of its containers, so it would become
+/// the underline of a setext heading or the delimiter row of a table: its first character is
+/// escaped instead.
+/// https://spec.commonmark.org/0.31.2/#lazy-continuation-line
+pub(crate) fn format_continuation_line(
+    line_break: &MarkdownSyntaxToken,
+    f: &mut MarkdownFormatter,
+) -> FormatResult<()> {
+    let mut line = String::new();
+    let mut current = line_break.next_token();
+    while let Some(token) = current {
+        if matches!(
+            token.kind(),
+            MarkdownSyntaxKind::MD_SOFT_BREAK_LITERAL
+                | MarkdownSyntaxKind::MD_HARD_LINE_LITERAL
+                | MarkdownSyntaxKind::NEWLINE
+                | MarkdownSyntaxKind::EOF
+        ) {
+            break;
+        }
+        line.push_str(token.text_trimmed());
+        current = token.next_token();
+    }
+
+    if line_start_after(line_break).indentation() >= TAB_STOP {
+        let starts_block = line.starts_with(|char: char| {
+            char.is_ascii_digit()
+                || matches!(
+                    char,
+                    '#' | '>' | '-' | '+' | '*' | '=' | '_' | '~' | '`' | '<' | '|'
+                )
+        });
+
+        if starts_block {
+            write!(f, [spaces(TAB_STOP)])?;
+        }
+        return Ok(());
+    }
+
+    let is_setext_underline = line.bytes().all(|byte| byte == b'=');
+    let is_delimiter_row = line.contains('|')
+        && line.contains('-')
+        && line
+            .bytes()
+            .all(|byte| matches!(byte, b'|' | b'-' | b':' | b' ' | b'\t'));
+
+    // Only the lines of a container can be lazy, and the content of a code span is printed
+    // as it is
+    let mut is_in_container = false;
+    for ancestor in line_break.ancestors() {
+        match ancestor.kind() {
+            MarkdownSyntaxKind::MD_INLINE_CODE => return Ok(()),
+            MarkdownSyntaxKind::MD_QUOTE
+            | MarkdownSyntaxKind::MD_BULLET_LIST_ITEM
+            | MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM => is_in_container = true,
+            _ => {}
+        }
+    }
+
+    if !line.is_empty() && is_in_container && (is_setext_underline || is_delimiter_row) {
+        write!(f, [text("\\")])?;
+    }
+
+    Ok(())
+}
+
+/// Removes `node` from the output, because it's printed from its text or it isn't printed at all
+pub(crate) fn format_removed_node(
+    node: &MarkdownSyntaxNode,
+    f: &mut MarkdownFormatter,
+) -> FormatResult<()> {
+    for descendant in node.descendants() {
+        f.context().comments().mark_suppression_checked(&descendant);
+    }
+
+    for token in node.descendants_tokens(Direction::Next) {
+        write!(f, [format_removed(&token)])?;
+    }
+
+    Ok(())
+}
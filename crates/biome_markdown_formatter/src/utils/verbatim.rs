@@ -0,0 +1,222 @@
+use crate::prelude::*;
+use crate::utils::{next_tab_stop, whitespace_width};
+use biome_markdown_syntax::{
+    AnyMdVerbatimLine, MarkdownSyntaxKind, MarkdownSyntaxToken, MdVerbatimLineList, T,
+};
+use biome_rowan::{SyntaxResult, TextSize};
+
+/// The whitespace at the start of a line, after the markers of its containers.
+///
+/// The columns are the columns of the source line once its tabs are expanded. A tab can be
+/// partially consumed by the markers of the containers, so the content of the containers can
+/// start after the column of the whitespace.
+/// https://spec.commonmark.org/0.31.2/#tabs
+#[derive(Debug, Default)]
+pub(crate) struct LineStart {
+    pub(crate) text: String,
+    /// The column at which `text` starts
+    pub(crate) column: usize,
+    /// The column at which the content of the containers starts
+    pub(crate) content_column: usize,
+}
+
+impl LineStart {
+    /// Returns the number of columns of `text`, relative to the content of the containers
+    pub(crate) fn indentation(&self) -> usize {
+        (self.column + whitespace_width(&self.text, self.column))
+            .saturating_sub(self.content_column)
+    }
+}
+
+/// A line of a code block or of an HTML block, without the markers of its containers
+#[derive(Debug)]
+pub(crate) struct VerbatimLine {
+    start: LineStart,
+    /// The position of the line in the source
+    pub(crate) position: TextSize,
+}
+
+impl VerbatimLine {
+    /// Returns the text of the line without the first `columns` columns of its indentation,
+    /// relative to the content of its containers.
+    ///
+    /// A tab that is only partially removed is replaced by the spaces that remain.
+    pub(crate) fn strip_indentation(&self, columns: usize) -> String {
+        let text = &self.start.text;
+        let end = self.start.content_column + columns;
+        let mut column = self.start.column;
+
+        for (offset, char) in text.char_indices() {
+            if column >= end {
+                return text[offset..].to_string();
+            }
+
+            match char {
+                ' ' => column += 1,
+                '\t' => {
+                    let next = next_tab_stop(column);
+                    if next > end {
+                        let mut stripped = " ".repeat(next - end);
+                        stripped.push_str(&text[offset + 1..]);
+                        return stripped;
+                    }
+                    column = next;
+                }
+                _ => return text[offset..].to_string(),
+            }
+        }
+
+        String::new()
+    }
+}
+
+/// Returns the lines of `list` as they're written in the source, including their indentation
+/// and their trailing whitespace.
+///
+/// `first_line` is the start of the first line when the list starts with the content of a
+/// line, because its indentation belongs to the token that precedes the list.
+pub(crate) fn verbatim_lines(
+    list: &MdVerbatimLineList,
+    first_line: Option<LineStart>,
+) -> SyntaxResult<Vec<VerbatimLine>> {
+    let mut lines = Vec::new();
+    let mut current = first_line.map(|start| VerbatimLine {
+        start,
+        position: list.syntax().text_trimmed_range().start(),
+    });
+
+    for line in list {
+        match line {
+            AnyMdVerbatimLine::MdNewline(newline) => {
+                let token = newline.value_token()?;
+                if let Some(mut line) = current.take() {
+                    line.start.text.push_str(token.leading_trivia().text());
+                    lines.push(line);
+                }
+                current = Some(VerbatimLine {
+                    start: line_start_after(&token),
+                    position: token.text_trimmed_range().end(),
+                });
+            }
+            AnyMdVerbatimLine::MdTextual(textual) => {
+                let token = textual.value_token()?;
+                let line = current.get_or_insert_with(|| VerbatimLine {
+                    start: LineStart::default(),
+                    position: token.text_trimmed_range().start(),
+                });
+                line.start.text.push_str(token.leading_trivia().text());
+                line.start.text.push_str(token.text_trimmed());
+                line.start.text.push_str(token.trailing_trivia().text());
+            }
+        }
+    }
+
+    lines.extend(current);
+
+    Ok(lines)
+}
+
+/// Returns the start of the line of `token`, when `token` is the first token of its line.
+///
+/// The indentation is part of the trailing trivia of the token that precedes `token`: the
+/// line break, the `>` of a block quote, or the marker of a list item. It's only part of the
+/// leading trivia of `token` at the start of the document.
+pub(crate) fn indentation_before(token: &MarkdownSyntaxToken) -> LineStart {
+    token
+        .prev_token()
+        .map_or_else(LineStart::default, |previous| line_start_after(&previous))
+}
+
+/// Returns the start of the line that continues after `previous`
+pub(crate) fn line_start_after(previous: &MarkdownSyntaxToken) -> LineStart {
+    let trivia = previous.trailing_trivia();
+    let trailing = trivia.text();
+
+    match previous.kind() {
+        MarkdownSyntaxKind::NEWLINE
+        | MarkdownSyntaxKind::MD_SOFT_BREAK_LITERAL
+        | MarkdownSyntaxKind::MD_HARD_LINE_LITERAL => {
+            // The line break includes the markers of the containers of the next line
+            let text = previous.text_trimmed();
+            let prefix = &text[text.rfind('\n').map_or(0, |index| index + 1)..];
+            let column = whitespace_width(prefix, 0);
+            // A tab after `>` is partially consumed by the marker
+            let consumed = usize::from(prefix.ends_with('>') && trailing.starts_with('\t'));
+            LineStart {
+                text: trailing.to_string(),
+                column,
+                content_column: column + consumed,
+            }
+        }
+        // The space after `>` is part of the marker
+        T![>] => {
+            let column = column_after(previous);
+            LineStart {
+                text: trailing.to_string(),
+                column,
+                content_column: column + usize::from(!trailing.is_empty()),
+            }
+        }
+        // The content of a list item starts after the spaces that follow the marker, unless
+        // there are more than four of them
+        T![-] | T![*] | T![+] | MarkdownSyntaxKind::MD_ORDERED_LIST_MARKER_LITERAL => {
+            let column = column_after(previous);
+            let width = whitespace_width(trailing, column);
+            if width > 4 {
+                LineStart {
+                    text: trailing.to_string(),
+                    column,
+                    content_column: column + 1,
+                }
+            } else {
+                LineStart {
+                    text: String::new(),
+                    column: column + width,
+                    content_column: column + width,
+                }
+            }
+        }
+        _ => LineStart::default(),
+    }
+}
+
+/// Returns the column of the end of `token` in its source line, before its trailing trivia
+fn column_after(token: &MarkdownSyntaxToken) -> usize {
+    let mut texts = vec![std::format!(
+        "{}{}",
+        token.leading_trivia().text(),
+        token.text_trimmed()
+    )];
+
+    let mut current = token.prev_token();
+    while let Some(previous) = current {
+        let text = previous.text().to_string();
+        let is_line_start = text.contains('\n');
+        texts.push(text);
+        if is_line_start {
+            break;
+        }
+        current = previous.prev_token();
+    }
+
+    let text: String = texts.into_iter().rev().collect();
+    let line = &text[text.rfind('\n').map_or(0, |index| index + 1)..];
+    whitespace_width(line, 0)
+}
+
+/// Returns a code fence made of `char` for a code block with the given `lines`. The fence is
+/// longer than any sequence of `char` in the code, so that the code can't close it.
+pub(crate) fn code_fence<S: AsRef<str>>(lines: &[S], char: char) -> String {
+    let longest_sequence = lines
+        .iter()
+        .filter_map(|line| {
+            line.as_ref()
+                .split(|current| current != char)
+                .map(str::len)
+                .max()
+        })
+        .max()
+        .unwrap_or(0);
+
+    char.to_string().repeat((longest_sequence + 1).max(3))
+}
@@ -0,0 +1,62 @@
+use biome_configuration::markdown::MarkdownFormatterConfiguration;
+use biome_configuration::{Configuration, MarkdownConfiguration};
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_markdown_formatter::{MarkdownFormatLanguage, context::MarkdownFormatOptions};
+use biome_service::workspace::UpdateSettingsParams;
+use camino::Utf8Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_markdown_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.md` and the output file name is `{spec_name}.md.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `markdown/list` -> input: `tests/specs/markdown/list.md`, expected output: `tests/specs/markdown/list.md.snap`
+/// * `list` -> input: `tests/specs/list.md`, expected output: `tests/specs/list.md.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Utf8Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+    let settings = |project_key| {
+        Some(UpdateSettingsParams {
+            project_key,
+            configuration: Configuration {
+                markdown: Some(MarkdownConfiguration {
+                    formatter: Some(MarkdownFormatterConfiguration {
+                        enabled: Some(true.into()),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            },
+            workspace_directory: None,
+        })
+    };
+
+    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, settings) else {
+        return;
+    };
+
+    let options = MarkdownFormatOptions::default();
+    let language = language::MarkdownTestFormatLanguage::default();
+
+    let snapshot = SpecSnapshot::new(
+        test_file,
+        test_directory,
+        language,
+        MarkdownFormatLanguage::new(options),
+    );
+
+    snapshot.test()
+}
@@ -1,10 +1,10 @@
-//! An extremely fast, lookup table based, JSON lexer which yields SyntaxKind tokens used by the rome-json parser.
+//! An extremely fast, lookup table based, Markdown lexer which yields SyntaxKind tokens used by the Markdown parser.
 
 #[rustfmt::skip]
 mod tests;
 
-use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_markdown_syntax::{MarkdownSyntaxKind, T};
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{
     LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, ReLexer, TokenFlags,
@@ -14,8 +14,12 @@ use biome_unicode_table::{Dispatch::*, lookup_byte};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum MarkdownLexContext {
+    /// Lexes the start of a line or of a block: whitespace is trivia.
     #[default]
     Regular,
+    /// Lexes the content of a paragraph, a heading or a table cell. Whitespace is significant
+    /// and part of the textual tokens, except at the end of a line.
+    Inline,
 }
 
 impl LexContext for MarkdownLexContext {
@@ -26,11 +30,15 @@ impl LexContext for MarkdownLexContext {
 }
 
 /// Context in which the [MarkdownLexContext]'s current should be re-lexed.
+///
+/// Most of the Markdown syntax depends on information that isn't available to the lexer: the
+/// container blocks a line belongs to, whether a delimiter run can open or close an emphasis, or
+/// where a link destination ends. The parser resolves the extent of these tokens by looking at
+/// the source text and re-lexes the current token accordingly.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MarkdownReLexContext {
-    #[expect(dead_code)]
-    Regular,
-    // UnicodeRange,
+    /// Re-lexes the current token as a textual token that ends at the given offset.
+    Until(TextSize),
 }
 
 /// An extremely fast, lookup table based, lossless Markdown lexer
@@ -90,12 +98,12 @@ impl<'src> Lexer<'src> for MarkdownLexer<'src> {
         self.diagnostics.push(diagnostic);
     }
 
-    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
+    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
         self.current_start = self.text_position();
         self.current_flags = TokenFlags::empty();
 
         let kind = match self.current_byte() {
-            Some(current) => self.consume_token(current),
+            Some(current) => self.consume_token(current, context),
             None => EOF,
         };
 
@@ -176,12 +184,37 @@ impl<'src> MarkdownLexer<'src> {
         }
     }
 
-    pub(crate) fn consume_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+    pub(crate) fn consume_token(
+        &mut self,
+        current: u8,
+        context: MarkdownLexContext,
+    ) -> MarkdownSyntaxKind {
         let dispatched = lookup_byte(current);
         match dispatched {
-            WHS => self.consume_newline_or_whitespace(),
-            MUL | MIN | IDT => self.consume_thematic_break_literal(),
-            _ => self.consume_textual(),
+            UNI if self.position == 0 && self.source.starts_with('\u{feff}') => {
+                self.unicode_bom_length = '\u{feff}'.len_utf8();
+                self.advance(self.unicode_bom_length);
+                UNICODE_BOM
+            }
+            WHS if matches!(current, b'\n' | b'\r') => self.consume_newline(),
+            WHS if context.is_regular() || self.is_at_trailing_whitespace() => {
+                self.consume_newline_or_whitespace()
+            }
+            MUL | MIN => self.consume_thematic_break_literal(),
+            IDT if current == b'_' => self.consume_thematic_break_literal(),
+            HAS => self.consume_byte(T![#]),
+            MOR => self.consume_byte(T![>]),
+            LSS => self.consume_byte(T![<]),
+            BTO => self.consume_byte(T!['[']),
+            BTC => self.consume_byte(T![']']),
+            PNO => self.consume_byte(T!['(']),
+            PNC => self.consume_byte(T![')']),
+            EXL => self.consume_byte(T![!]),
+            PIP => self.consume_byte(T![|]),
+            TLD => self.consume_byte(T![~]),
+            TPL => self.consume_backticks(),
+            BSL => self.consume_escape(),
+            _ => self.consume_textual(context),
         }
     }
 
@@ -189,12 +222,6 @@ impl<'src> MarkdownLexer<'src> {
         TextSize::try_from(self.position).expect("Input to be smaller than 4 GB")
     }
 
-    /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
-    fn eat_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
-        self.advance(1);
-        tok
-    }
     /// Returns the byte at position `self.position + offset` or `None` if it is out of bounds.
     #[inline]
     fn byte_at(&self, offset: usize) -> Option<u8> {
@@ -219,7 +246,7 @@ impl<'src> MarkdownLexer<'src> {
             Some(b'\n' | b'\r') => self.consume_newline(),
             Some(b' ') => self.consume_whitespace(),
             Some(b'\t') => self.consume_tab(),
-            _ => self.consume_textual(),
+            _ => self.consume_textual(MarkdownLexContext::Regular),
         }
     }
 
@@ -272,11 +299,12 @@ impl<'src> MarkdownLexer<'src> {
     fn consume_thematic_break_literal(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
 
-        let start_char = match self.current_byte() {
-            Some(b'-') => b'-',
-            Some(b'*') => b'*',
-            Some(b'_') => b'_',
-            _ => return self.consume_textual(),
+        let start = self.position;
+        let (start_char, punct) = match self.current_byte() {
+            Some(b'-') => (b'-', T![-]),
+            Some(b'*') => (b'*', T![*]),
+            Some(b'_') => (b'_', T!["_"]),
+            _ => unreachable!(),
         };
 
         let mut count = 0;
@@ -293,7 +321,47 @@ impl<'src> MarkdownLexer<'src> {
         if matches!(self.current_byte(), Some(b'\n' | b'\r') | None) && count >= 3 {
             return MD_THEMATIC_BREAK_LITERAL;
         }
-        ERROR_TOKEN
+
+        self.position = start;
+        self.consume_byte(punct)
+    }
+
+    /// Consumes a run of backticks, the delimiter of a code span or a code fence.
+    fn consume_backticks(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        while let Some(b'`') = self.current_byte() {
+            self.advance(1);
+        }
+
+        MD_BACKTICKS_LITERAL
+    }
+
+    /// Consumes a backslash escape. A backslash at the end of a line is a hard line break.
+    fn consume_escape(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+        self.advance(1);
+
+        match self.current_byte() {
+            Some(b'\n' | b'\r') => {
+                self.consume_newline();
+                MD_HARD_LINE_LITERAL
+            }
+            Some(byte) if byte.is_ascii_punctuation() => {
+                self.advance(1);
+                MD_TEXTUAL_LITERAL
+            }
+            _ => MD_TEXTUAL_LITERAL,
+        }
+    }
+
+    /// Returns `true` if the whitespace at the current position is only followed by other
+    /// whitespace until the end of the line.
+    fn is_at_trailing_whitespace(&self) -> bool {
+        self.source.as_bytes()[self.position..]
+            .iter()
+            .find(|byte| !matches!(byte, b' ' | b'\t'))
+            .is_none_or(|byte| matches!(byte, b'\n' | b'\r'))
     }
 
     /// Get the UTF8 char which starts at the current byte
@@ -350,18 +418,35 @@ impl<'src> MarkdownLexer<'src> {
         self.position >= self.source.len()
     }
 
-    #[inline]
-    fn consume_textual(&mut self) -> MarkdownSyntaxKind {
+    /// Consumes a run of characters without any special meaning. In the [MarkdownLexContext::Inline]
+    /// context, the run includes the whitespace that isn't at the end of a line.
+    fn consume_textual(&mut self, context: MarkdownLexContext) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
 
         let char = self.current_char_unchecked();
         self.advance(char.len_utf8());
 
+        while let Some(byte) = self.current_byte() {
+            let is_part_of_text = match lookup_byte(byte) {
+                WHS if matches!(byte, b'\n' | b'\r') => false,
+                WHS => !context.is_regular() && !self.is_at_trailing_whitespace(),
+                IDT => byte != b'_',
+                MUL | MIN | HAS | MOR | LSS | BTO | BTC | PNO | PNC | EXL | PIP | TLD | TPL
+                | BSL => false,
+                _ => true,
+            };
+
+            if !is_part_of_text {
+                break;
+            }
+
+            self.advance_char_unchecked();
+        }
+
         MD_TEXTUAL_LITERAL
     }
 
     /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
     fn consume_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
         self.advance(1);
         tok
@@ -370,25 +455,14 @@ impl<'src> MarkdownLexer<'src> {
 
 impl<'src> ReLexer<'src> for MarkdownLexer<'src> {
     fn re_lex(&mut self, context: Self::ReLexContext) -> Self::Kind {
-        let old_position = self.position;
-        self.position = u32::from(self.current_start) as usize;
-
-        let re_lexed_kind = match self.current_byte() {
-            Some(current) => match context {
-                MarkdownReLexContext::Regular => self.consume_token(current),
-                // MarkdownReLexContext::UnicodeRange => self.consume_unicode_range_token(current),
-            },
-            None => EOF,
-        };
+        let MarkdownReLexContext::Until(end) = context;
+        debug_assert!(end > self.current_start, "Token must not be empty");
+        debug_assert!(self.source.is_char_boundary(u32::from(end) as usize));
 
-        if self.current() == re_lexed_kind {
-            // Didn't re-lex anything. Return existing token again
-            self.position = old_position;
-        } else {
-            self.current_kind = re_lexed_kind;
-        }
+        self.position = u32::from(end) as usize;
+        self.current_kind = MD_TEXTUAL_LITERAL;
 
-        re_lexed_kind
+        MD_TEXTUAL_LITERAL
     }
 }
 
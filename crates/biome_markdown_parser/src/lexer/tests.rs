@@ -162,3 +162,78 @@ _ _ _ _  _ "#,
         MD_THEMATIC_BREAK_LITERAL:11,
     }
 }
+
+#[test]
+fn punctuation() {
+    assert_lex! {
+        "#>[]()!|~<",
+        HASH:1,
+        R_ANGLE:1,
+        L_BRACK:1,
+        R_BRACK:1,
+        L_PAREN:1,
+        R_PAREN:1,
+        BANG:1,
+        PIPE:1,
+        TILDE:1,
+        L_ANGLE:1,
+    }
+}
+
+#[test]
+fn emphasis_delimiters() {
+    assert_lex! {
+        "*a*_b_",
+        STAR:1,
+        MD_TEXTUAL_LITERAL:1,
+        STAR:1,
+        UNDERSCORE:1,
+        MD_TEXTUAL_LITERAL:1,
+        UNDERSCORE:1,
+    }
+}
+
+#[test]
+fn backticks() {
+    assert_lex! {
+        "```js",
+        MD_BACKTICKS_LITERAL:3,
+        MD_TEXTUAL_LITERAL:2,
+    }
+}
+
+#[test]
+fn escape() {
+    assert_lex! {
+        "\\*\\a\\\n",
+        MD_TEXTUAL_LITERAL:2,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_HARD_LINE_LITERAL:2,
+    }
+}
+
+#[test]
+fn textual_regular() {
+    assert_lex! {
+        "foo bar",
+        MD_TEXTUAL_LITERAL:3,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:3,
+    }
+}
+
+#[test]
+fn textual_inline() {
+    let mut lexer = MarkdownLexer::from_str("foo bar  \n");
+    let mut tokens = vec![];
+
+    while lexer.next_token(MarkdownLexContext::Inline) != EOF {
+        tokens.push((lexer.current(), u32::from(lexer.current_range().len())));
+    }
+
+    assert_eq!(
+        tokens,
+        vec![(MD_TEXTUAL_LITERAL, 7), (WHITESPACE, 2), (NEWLINE, 1)]
+    );
+}
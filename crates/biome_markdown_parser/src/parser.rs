@@ -1,15 +1,29 @@
 use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_parser::ParserContext;
+use biome_parser::diagnostic::merge_diagnostics;
 use biome_parser::event::Event;
 use biome_parser::prelude::*;
 use biome_parser::token_source::Trivia;
-use biome_parser::{ParserContextCheckpoint, diagnostic::merge_diagnostics};
+use biome_rowan::TextSize;
 
-use crate::token_source::{MarkdownTokenSource, MarkdownTokenSourceCheckpoint};
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::syntax::line::{Container, LinePosition};
+use crate::token_source::MarkdownTokenSource;
 
 pub(crate) struct MarkdownParser<'source> {
     context: ParserContext<MarkdownSyntaxKind>,
     source: MarkdownTokenSource<'source>,
+    state: MarkdownParserState,
+}
+
+/// The state of the block structure at the current line.
+#[derive(Debug, Default)]
+pub(crate) struct MarkdownParserState {
+    /// The open container blocks, from the outermost to the innermost.
+    pub(crate) containers: Vec<Container>,
+
+    /// The position right after the prefix of the open containers on the current line.
+    pub(crate) line: LinePosition,
 }
 
 impl<'source> MarkdownParser<'source> {
@@ -17,25 +31,51 @@ impl<'source> MarkdownParser<'source> {
         Self {
             context: ParserContext::default(),
             source: MarkdownTokenSource::from_str(source),
+            state: MarkdownParserState::default(),
         }
     }
 
-    pub fn checkpoint(&self) -> MarkdownParserCheckpoint {
-        MarkdownParserCheckpoint {
-            context: self.context.checkpoint(),
-            source: self.source.checkpoint(),
-        }
+    pub(crate) fn state(&self) -> &MarkdownParserState {
+        &self.state
+    }
+
+    pub(crate) fn state_mut(&mut self) -> &mut MarkdownParserState {
+        &mut self.state
+    }
+
+    /// Returns the source text
+    pub(crate) fn text(&self) -> &'source str {
+        self.source.source_text()
+    }
+
+    /// Returns the byte offset at which the current token starts
+    pub(crate) fn cur_start(&self) -> usize {
+        usize::from(self.cur_range().start())
     }
 
-    pub fn before_whitespace_count(&self) -> usize {
-        self.source.before_whitespace_count()
+    /// Returns the byte offset at which the current token ends
+    pub(crate) fn cur_end(&self) -> usize {
+        usize::from(self.cur_range().end())
     }
 
-    pub fn rewind(&mut self, checkpoint: MarkdownParserCheckpoint) {
-        let MarkdownParserCheckpoint { context, source } = checkpoint;
+    /// Bumps the current token as a token of `kind` that ends at the byte offset `end`, and lexes
+    /// the next token in the given `context`.
+    ///
+    /// The extent of most Markdown tokens depends on the block structure and on the surrounding
+    /// inline content, so the parser computes it and re-lexes the current token accordingly.
+    pub(crate) fn bump_until(
+        &mut self,
+        kind: MarkdownSyntaxKind,
+        end: usize,
+        context: MarkdownLexContext,
+    ) {
+        debug_assert!(end > self.cur_start(), "Expected a non empty token");
 
-        self.context.rewind(context);
-        self.source.rewind(source);
+        let end = TextSize::from(end as u32);
+        if self.cur_range().end() != end {
+            self.source.re_lex(MarkdownReLexContext::Until(end));
+        }
+        self.bump_remap_with_context(kind, context);
     }
 
     pub fn finish(
@@ -74,8 +114,3 @@ impl<'source> Parser for MarkdownParser<'source> {
         &mut self.source
     }
 }
-
-pub struct MarkdownParserCheckpoint {
-    pub(super) context: ParserContextCheckpoint,
-    pub(super) source: MarkdownTokenSourceCheckpoint,
-}
@@ -1,74 +1,189 @@
-pub mod thematic_break_block;
+mod code_block;
+mod header;
+mod html;
+mod html_block;
+mod inline;
+pub(crate) mod line;
+mod list;
+mod paragraph;
+mod quote;
+mod table;
+mod thematic_break_block;
 
+use crate::MarkdownParser;
+use crate::lexer::MarkdownLexContext;
 use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
 use biome_parser::{
     Parser,
     prelude::ParsedSyntax::{self, *},
 };
-use thematic_break_block::{at_thematic_break_block, parse_thematic_break_block};
-
-use crate::MarkdownParser;
+use code_block::{parse_fenced_code_block, parse_indent_code_block};
+use header::parse_header;
+use html_block::parse_html_block;
+use line::{
+    BlockStart, Container, ContainerPrefix, LinePosition, first_non_whitespace, line_end,
+    list_item_start, match_containers, next_line_start, trimmed_line_end,
+};
+use list::{parse_bullet_list, parse_ordered_list};
+use paragraph::parse_paragraph;
+use quote::parse_quote;
+use table::{at_table, parse_table};
+use thematic_break_block::parse_thematic_break_block;
 
 pub(crate) fn parse_document(p: &mut MarkdownParser) {
     let m = p.start();
-    let _ = parse_block_list(p);
+
+    // The whitespace at the start of the document is the leading trivia of the first token
+    let start = if p.eat(UNICODE_BOM) {
+        '\u{feff}'.len_utf8()
+    } else {
+        0
+    };
+    p.state_mut().line = LinePosition::line_start(start);
+
+    let _ = parse_front_matter(p);
+    parse_block_list(p);
+
+    p.expect(T![EOF]);
     m.complete(p, MD_DOCUMENT);
 }
 
-pub(crate) fn parse_block_list(p: &mut MarkdownParser) -> ParsedSyntax {
+/// Parses a YAML front matter: a block delimited by `---` lines at the very start of the
+/// document.
+fn parse_front_matter(p: &mut MarkdownParser) -> ParsedSyntax {
+    let text = p.text();
+    let start = p.cur_start();
+    if start != p.state().line.offset || &text[start..trimmed_line_end(text, start)] != "---" {
+        return Absent;
+    }
+
+    let mut current = line_end(text, start);
+    let end = loop {
+        let Some(line_start) = next_line_start(text, current) else {
+            return Absent;
+        };
+        let end = trimmed_line_end(text, line_start);
+        if &text[line_start..end] == "---" {
+            break end;
+        }
+        current = line_end(text, line_start);
+    };
+
+    let m = p.start();
+    p.bump_until(MD_FRONT_MATTER_LITERAL, end, MarkdownLexContext::Regular);
+    Present(m.complete(p, MD_FRONT_MATTER))
+}
+
+/// Parses the blocks of the document or of a container block, until a line doesn't belong to
+/// the container anymore.
+pub(crate) fn parse_block_list(p: &mut MarkdownParser) {
     let m = p.start();
 
-    while !p.at(T![EOF]) {
-        parse_any_block(p);
+    loop {
+        if p.at(T![EOF]) {
+            break;
+        }
+
+        if p.at(NEWLINE) {
+            if !parse_newline(p) {
+                break;
+            }
+        } else {
+            let _ = parse_any_block(p);
+        }
     }
-    Present(m.complete(p, MD_BLOCK_LIST))
+
+    m.complete(p, MD_BLOCK_LIST);
 }
 
-pub(crate) fn parse_any_block(p: &mut MarkdownParser) {
-    if at_indent_code_block(p) {
-        parse_indent_code_block(p);
-    } else if at_thematic_break_block(p) {
-        let break_block = try_parse(p, |p| {
-            let break_block = parse_thematic_break_block(p);
-            if break_block.is_absent() {
-                return Err(());
-            }
-            Ok(break_block)
-        });
-        if break_block.is_err() {
-            parse_paragraph(p);
+/// Parses the line break at the end of a block or of a blank line, if the next line belongs to
+/// the current container. Returns `false` if the container ends.
+fn parse_newline(p: &mut MarkdownParser) -> bool {
+    let depth = p.state().containers.len();
+
+    let Some(prefix) = next_line_prefix(p) else {
+        // The last line break of the document belongs to the document
+        if depth > 0 {
+            return false;
+        }
+        let m = p.start();
+        let end = p.cur_end();
+        p.bump_until(NEWLINE, end, MarkdownLexContext::Regular);
+        m.complete(p, MD_NEWLINE);
+        return true;
+    };
+
+    if prefix.matched == depth {
+        parse_line_break(p, prefix);
+        return true;
+    }
+
+    // A list item ends when the next line starts a sibling item. The line break then belongs
+    // to the list item that ends.
+    if let Some(Container::ListItem { marker, .. }) = p.state().containers.last() {
+        let marker = *marker;
+        let text = p.text();
+        let starts_sibling = prefix.matched == depth - 1
+            && matches!(
+                line::block_start(text, prefix.end, false),
+                Some(BlockStart::BulletListItem | BlockStart::OrderedListItem)
+            )
+            && list_item_start(text, first_non_whitespace(text, prefix.end).0)
+                .is_some_and(|item| item.marker == marker);
+
+        if starts_sibling {
+            parse_line_break(p, prefix);
         }
     }
+
+    false
+}
+
+/// Returns the container prefix of the line that follows the line break at the current
+/// position, or `None` if there's no such line.
+pub(crate) fn next_line_prefix(p: &MarkdownParser) -> Option<ContainerPrefix> {
+    let text = p.text();
+    let start = next_line_start(text, p.cur_start())?;
+    if start == text.len() {
+        return None;
+    }
+
+    Some(match_containers(text, start, &p.state().containers))
 }
 
-pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
-    p.before_whitespace_count() > 4
+/// Parses the line break at the current position as a [MD_NEWLINE]. The line break includes
+/// the container `prefix` of the next line.
+pub(crate) fn parse_line_break(p: &mut MarkdownParser, prefix: ContainerPrefix) {
+    let m = p.start();
+    p.bump_until(NEWLINE, prefix.end.offset, MarkdownLexContext::Regular);
+    m.complete(p, MD_NEWLINE);
+    p.state_mut().line = prefix.end;
 }
 
-pub(crate) fn parse_indent_code_block(_p: &mut MarkdownParser) {
-    todo!()
+/// Parses the rest of the current line as a [MD_TEXTUAL], as it is written
+pub(crate) fn parse_verbatim_line(p: &mut MarkdownParser) {
+    let m = p.start();
+    let end = line_end(p.text(), p.cur_start());
+    p.bump_until(MD_TEXTUAL_LITERAL, end, MarkdownLexContext::Regular);
+    m.complete(p, MD_TEXTUAL);
 }
 
-pub(crate) fn parse_paragraph(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns `true` if the current token is at the end of a line
+pub(crate) fn at_line_end(p: &MarkdownParser) -> bool {
+    p.at(NEWLINE) || p.at(T![EOF])
 }
 
-/// Attempt to parse some input with the given parsing function. If parsing
-/// succeeds, `Ok` is returned with the result of the parse and the state is
-/// preserved. If parsing fails, this function rewinds the parser back to
-/// where it was before attempting the parse and the `Err` value is returned.
-#[must_use = "The result of try_parse contains information about whether the parse succeeded and should not be ignored"]
-pub(crate) fn try_parse<T, E>(
-    p: &mut MarkdownParser,
-    func: impl FnOnce(&mut MarkdownParser) -> Result<T, E>,
-) -> Result<T, E> {
-    let checkpoint = p.checkpoint();
-
-    let res = func(p);
-
-    if res.is_err() {
-        p.rewind(checkpoint);
+pub(crate) fn parse_any_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    match line::block_start(p.text(), p.state().line, false) {
+        Some(BlockStart::IndentedCode) => parse_indent_code_block(p),
+        Some(BlockStart::ThematicBreak) => parse_thematic_break_block(p),
+        Some(BlockStart::AtxHeading) => parse_header(p),
+        Some(BlockStart::FencedCode) => parse_fenced_code_block(p),
+        Some(BlockStart::Html(kind)) => parse_html_block(p, kind),
+        Some(BlockStart::Quote) => parse_quote(p),
+        Some(BlockStart::BulletListItem) => parse_bullet_list(p),
+        Some(BlockStart::OrderedListItem) => parse_ordered_list(p),
+        None if at_table(p) => parse_table(p),
+        None => parse_paragraph(p),
     }
-
-    res
 }
@@ -0,0 +1,132 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::line::{
+    code_fence, first_non_whitespace, is_blank, is_closing_code_fence, line_end, match_containers,
+    next_line_start, trimmed_line_end,
+};
+use crate::syntax::{at_line_end, next_line_prefix, parse_line_break, parse_verbatim_line};
+use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Parses an indented code block: lines indented by four or more columns. Blank lines belong to
+/// the code block only when they're followed by another indented line.
+/// https://spec.commonmark.org/0.31.2/#indented-code-blocks
+pub(crate) fn parse_indent_code_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    let m = p.start();
+    let content = p.start();
+
+    parse_verbatim_line(p);
+
+    while p.at(NEWLINE) {
+        let line_breaks = indented_code_continuation(p);
+        if line_breaks == 0 {
+            break;
+        }
+
+        for _ in 0..line_breaks {
+            let Some(prefix) = next_line_prefix(p) else {
+                break;
+            };
+            parse_line_break(p, prefix);
+        }
+        parse_verbatim_line(p);
+    }
+
+    content.complete(p, MD_VERBATIM_LINE_LIST);
+    Present(m.complete(p, MD_INDENT_CODE_BLOCK))
+}
+
+/// Returns the number of line breaks until the next line of the indented code block, or `0` if
+/// the code block ends at the current line break.
+fn indented_code_continuation(p: &MarkdownParser) -> usize {
+    let text = p.text();
+    let containers = &p.state().containers;
+
+    let mut current = p.cur_start();
+    let mut line_breaks = 0;
+
+    loop {
+        let Some(start) = next_line_start(text, current) else {
+            return 0;
+        };
+        if start == text.len() {
+            return 0;
+        }
+
+        let prefix = match_containers(text, start, containers);
+        if prefix.matched < containers.len() {
+            return 0;
+        }
+
+        line_breaks += 1;
+        if is_blank(text, prefix.end.offset) {
+            current = line_end(text, prefix.end.offset);
+            continue;
+        }
+
+        return if first_non_whitespace(text, prefix.end).1 >= 4 {
+            line_breaks
+        } else {
+            0
+        };
+    }
+}
+
+/// Parses a fenced code block: the lines between an opening code fence and a closing code fence
+/// made of the same character. The block ends with its container if it isn't closed.
+/// https://spec.commonmark.org/0.31.2/#fenced-code-blocks
+pub(crate) fn parse_fenced_code_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    let text = p.text();
+    let start = p.cur_start();
+    let Some(fence) = code_fence(text, start) else {
+        return Absent;
+    };
+
+    let m = p.start();
+
+    p.bump_until(
+        MD_CODE_FENCE_LITERAL,
+        start + fence.len,
+        MarkdownLexContext::Regular,
+    );
+
+    if !at_line_end(p) {
+        let end = trimmed_line_end(text, p.cur_start());
+        p.bump_until(MD_TEXTUAL_LITERAL, end, MarkdownLexContext::Regular);
+    }
+
+    let content = p.start();
+    let mut is_closed = false;
+
+    while p.at(NEWLINE) {
+        let Some(prefix) = next_line_prefix(p) else {
+            break;
+        };
+        if prefix.matched < p.state().containers.len() {
+            break;
+        }
+
+        parse_line_break(p, prefix);
+
+        if is_closing_code_fence(text, prefix.end, fence) {
+            is_closed = true;
+            break;
+        }
+
+        if !at_line_end(p) {
+            parse_verbatim_line(p);
+        }
+    }
+
+    content.complete(p, MD_VERBATIM_LINE_LIST);
+
+    if is_closed {
+        let end = trimmed_line_end(text, p.cur_start());
+        p.bump_until(MD_CODE_FENCE_LITERAL, end, MarkdownLexContext::Regular);
+    }
+
+    Present(m.complete(p, MD_FENCED_CODE_BLOCK))
+}
@@ -0,0 +1,73 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::{InlineLine, parse_inline_item_list};
+use crate::syntax::line::{atx_heading_level, trimmed_line_end};
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Parses an ATX heading: one to six `#`, the content of the heading and an optional closing
+/// sequence of `#`.
+/// https://spec.commonmark.org/0.31.2/#atx-headings
+pub(crate) fn parse_header(p: &mut MarkdownParser) -> ParsedSyntax {
+    let text = p.text();
+    let start = p.cur_start();
+    let Some(level) = atx_heading_level(text, start) else {
+        return Absent;
+    };
+
+    let end = trimmed_line_end(text, start);
+    let content_start = start + level + text[start + level..end].len()
+        - text[start + level..end]
+            .trim_start_matches([' ', '\t'])
+            .len();
+
+    // The closing sequence must be preceded by whitespace, unless the heading is empty
+    let closing_len = text[content_start..end]
+        .bytes()
+        .rev()
+        .take_while(|byte| *byte == b'#')
+        .count();
+    let closing_start = end - closing_len;
+    let has_closing_sequence = closing_len > 0
+        && (closing_start == content_start || text[..closing_start].ends_with([' ', '\t']));
+    let (content_end, closing_len) = if has_closing_sequence {
+        let content = text[content_start..closing_start].trim_end_matches([' ', '\t']);
+        (content_start + content.len(), closing_len)
+    } else {
+        (end, 0)
+    };
+
+    let m = p.start();
+
+    parse_hash_list(p, start, level);
+
+    if content_end > content_start {
+        let line = InlineLine {
+            start: content_start,
+            end: content_end,
+            break_end: None,
+        };
+        parse_inline_item_list(p, &[line], MarkdownLexContext::Regular);
+    } else {
+        p.start().complete(p, MD_INLINE_ITEM_LIST);
+    }
+
+    parse_hash_list(p, closing_start, closing_len);
+
+    Present(m.complete(p, MD_HEADER))
+}
+
+fn parse_hash_list(p: &mut MarkdownParser, start: usize, len: usize) {
+    let list = p.start();
+
+    for index in 0..len {
+        let m = p.start();
+        p.bump_until(T![#], start + index + 1, MarkdownLexContext::Regular);
+        m.complete(p, MD_HASH);
+    }
+
+    list.complete(p, MD_HASH_LIST);
+}
@@ -0,0 +1,342 @@
+//! Recognition of raw HTML, in HTML blocks and inline.
+//! https://spec.commonmark.org/0.31.2/#html-blocks
+//! https://spec.commonmark.org/0.31.2/#raw-html
+
+use crate::syntax::line::line_end;
+use biome_string_case::StrLikeExtension;
+
+/// The seven kinds of HTML blocks, which differ by the condition that ends them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum HtmlBlockKind {
+    /// `<script>`, `<pre>`, `<style>` or `<textarea>`, ends with the closing tag
+    Raw,
+    /// `<!--`, ends with `-->`
+    Comment,
+    /// `<?`, ends with `?>`
+    ProcessingInstruction,
+    /// `<!DOCTYPE`, ends with `>`
+    Declaration,
+    /// `<![CDATA[`, ends with `]]>`
+    CData,
+    /// A block level tag, ends with a blank line
+    Block,
+    /// Any other complete tag alone on its line, ends with a blank line
+    Tag,
+}
+
+impl HtmlBlockKind {
+    /// Returns `true` if the HTML block ends with a blank line rather than with the line that
+    /// contains a specific closing sequence.
+    pub(crate) fn ends_with_blank_line(self) -> bool {
+        matches!(self, Self::Block | Self::Tag)
+    }
+
+    /// Returns `true` if `line` contains the sequence that closes the HTML block.
+    pub(crate) fn is_closed_by(self, line: &str) -> bool {
+        match self {
+            Self::Raw => {
+                let line = line.to_ascii_lowercase_cow();
+                RAW_TAGS
+                    .iter()
+                    .any(|tag| line.contains(&format!("</{tag}>")))
+            }
+            Self::Comment => line.contains("-->"),
+            Self::ProcessingInstruction => line.contains("?>"),
+            Self::Declaration => line.contains('>'),
+            Self::CData => line.contains("]]>"),
+            Self::Block | Self::Tag => false,
+        }
+    }
+}
+
+const RAW_TAGS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+const BLOCK_TAGS: [&str; 62] = [
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// Returns the kind of the HTML block that starts at `offset`.
+///
+/// When `in_paragraph` is `true`, only the HTML blocks that can interrupt a paragraph are
+/// returned.
+pub(crate) fn html_block_start(
+    text: &str,
+    offset: usize,
+    in_paragraph: bool,
+) -> Option<HtmlBlockKind> {
+    let line = &text[offset..line_end(text, offset)];
+    let bytes = line.as_bytes();
+    if bytes.first() != Some(&b'<') {
+        return None;
+    }
+
+    if line.starts_with("<!--") {
+        return Some(HtmlBlockKind::Comment);
+    }
+    if line.starts_with("<?") {
+        return Some(HtmlBlockKind::ProcessingInstruction);
+    }
+    if line.starts_with("<![CDATA[") {
+        return Some(HtmlBlockKind::CData);
+    }
+    if line.starts_with("<!") && bytes.get(2).is_some_and(u8::is_ascii_alphabetic) {
+        return Some(HtmlBlockKind::Declaration);
+    }
+
+    let is_closing = bytes.get(1) == Some(&b'/');
+    let name_start = if is_closing { 2 } else { 1 };
+    let name_len = bytes[name_start..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_alphanumeric() || **byte == b'-')
+        .count();
+    let name = line[name_start..name_start + name_len].to_ascii_lowercase_cow();
+    let after_name = bytes.get(name_start + name_len);
+
+    if !is_closing
+        && RAW_TAGS.contains(&name.as_ref())
+        && matches!(after_name, None | Some(b' ' | b'\t' | b'>'))
+    {
+        return Some(HtmlBlockKind::Raw);
+    }
+
+    if BLOCK_TAGS.contains(&name.as_ref())
+        && (matches!(after_name, None | Some(b' ' | b'\t' | b'>'))
+            || line[name_start + name_len..].starts_with("/>"))
+    {
+        return Some(HtmlBlockKind::Block);
+    }
+
+    if in_paragraph || RAW_TAGS.contains(&name.as_ref()) {
+        return None;
+    }
+
+    let end = if is_closing {
+        scan_closing_tag(bytes, 0)
+    } else {
+        scan_open_tag(bytes, 0)
+    }?;
+
+    line[end..]
+        .bytes()
+        .all(|byte| matches!(byte, b' ' | b'\t'))
+        .then_some(HtmlBlockKind::Tag)
+}
+
+/// Returns the end of the raw inline HTML that starts at `offset`: a tag, a comment, a
+/// processing instruction, a declaration or a CDATA section.
+pub(crate) fn scan_inline_html(text: &str, offset: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let rest = &text[offset..];
+
+    if let Some(comment) = rest.strip_prefix("<!--") {
+        // `<!-->` and `<!--->` are also comments
+        if comment.starts_with('>') {
+            return Some(offset + 5);
+        }
+        if comment.starts_with("->") {
+            return Some(offset + 6);
+        }
+        return comment.find("-->").map(|end| offset + 4 + end + 3);
+    }
+    if let Some(instruction) = rest.strip_prefix("<?") {
+        return instruction.find("?>").map(|end| offset + 2 + end + 2);
+    }
+    if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+        return cdata.find("]]>").map(|end| offset + 9 + end + 3);
+    }
+    if let Some(declaration) = rest.strip_prefix("<!") {
+        if !declaration.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        return declaration.find('>').map(|end| offset + 2 + end + 1);
+    }
+
+    if bytes.get(offset + 1) == Some(&b'/') {
+        scan_closing_tag(bytes, offset)
+    } else {
+        scan_open_tag(bytes, offset)
+    }
+}
+
+/// Returns the end of the autolink that starts at `offset`: an absolute URI or an email address
+/// between `<` and `>`.
+/// https://spec.commonmark.org/0.31.2/#autolinks
+pub(crate) fn scan_autolink(text: &str, offset: usize) -> Option<usize> {
+    let rest = &text[offset + 1..];
+    let end = rest.find('>')?;
+    let content = &rest[..end];
+
+    if content.is_empty()
+        || content
+            .bytes()
+            .any(|byte| byte <= b' ' || byte == b'<' || byte == b'>')
+    {
+        return None;
+    }
+
+    let is_uri = content.split_once(':').is_some_and(|(scheme, _)| {
+        (2..=32).contains(&scheme.len())
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'.' | b'-'))
+    });
+
+    let is_email = content.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && local
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || b".!#$%&'*+/=?^_`{|}~-".contains(&byte))
+            && !domain.is_empty()
+            && domain.split('.').all(|label| {
+                !label.is_empty()
+                    && label.len() <= 63
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label
+                        .bytes()
+                        .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+            })
+    });
+
+    (is_uri || is_email).then_some(offset + 1 + end + 1)
+}
+
+fn scan_tag_name(bytes: &[u8], offset: usize) -> Option<usize> {
+    if !bytes.get(offset)?.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let len = bytes[offset..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_alphanumeric() || **byte == b'-')
+        .count();
+    Some(offset + len)
+}
+
+fn skip_html_whitespace(bytes: &[u8], mut offset: usize) -> usize {
+    while bytes
+        .get(offset)
+        .is_some_and(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+    {
+        offset += 1;
+    }
+    offset
+}
+
+fn scan_open_tag(bytes: &[u8], offset: usize) -> Option<usize> {
+    let mut current = scan_tag_name(bytes, offset + 1)?;
+
+    loop {
+        let after_whitespace = skip_html_whitespace(bytes, current);
+        match bytes.get(after_whitespace)? {
+            b'>' => return Some(after_whitespace + 1),
+            b'/' => {
+                return (bytes.get(after_whitespace + 1) == Some(&b'>'))
+                    .then_some(after_whitespace + 2);
+            }
+            _ if after_whitespace == current => return None,
+            _ => current = scan_attribute(bytes, after_whitespace)?,
+        }
+    }
+}
+
+fn scan_attribute(bytes: &[u8], offset: usize) -> Option<usize> {
+    let first = *bytes.get(offset)?;
+    if !(first.is_ascii_alphabetic() || first == b'_' || first == b':') {
+        return None;
+    }
+
+    let name_len = bytes[offset..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_alphanumeric() || b"_.:-".contains(byte))
+        .count();
+    let name_end = offset + name_len;
+
+    let before_equal = skip_html_whitespace(bytes, name_end);
+    if bytes.get(before_equal) != Some(&b'=') {
+        return Some(name_end);
+    }
+
+    let value_start = skip_html_whitespace(bytes, before_equal + 1);
+    match bytes.get(value_start)? {
+        quote @ (b'"' | b'\'') => bytes[value_start + 1..]
+            .iter()
+            .position(|byte| byte == quote)
+            .map(|end| value_start + 1 + end + 1),
+        _ => {
+            let len = bytes[value_start..]
+                .iter()
+                .take_while(|byte| !b" \t\n\r\"'=<>`".contains(byte))
+                .count();
+            (len > 0).then_some(value_start + len)
+        }
+    }
+}
+
+fn scan_closing_tag(bytes: &[u8], offset: usize) -> Option<usize> {
+    let name_end = scan_tag_name(bytes, offset + 2)?;
+    let end = skip_html_whitespace(bytes, name_end);
+    (bytes.get(end) == Some(&b'>')).then_some(end + 1)
+}
@@ -0,0 +1,45 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::html::HtmlBlockKind;
+use crate::syntax::line::{is_blank, line_end};
+use crate::syntax::{at_line_end, next_line_prefix, parse_line_break, parse_verbatim_line};
+use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Parses an HTML block. Its lines are kept as they are, until the end condition of its `kind`
+/// is met.
+/// https://spec.commonmark.org/0.31.2/#html-blocks
+pub(crate) fn parse_html_block(p: &mut MarkdownParser, kind: HtmlBlockKind) -> ParsedSyntax {
+    let text = p.text();
+
+    let m = p.start();
+    let content = p.start();
+
+    let start = p.cur_start();
+    parse_verbatim_line(p);
+    let mut is_closed = kind.is_closed_by(&text[start..line_end(text, start)]);
+
+    while !is_closed && p.at(NEWLINE) {
+        let Some(prefix) = next_line_prefix(p) else {
+            break;
+        };
+        if prefix.matched < p.state().containers.len()
+            || (kind.ends_with_blank_line() && is_blank(text, prefix.end.offset))
+        {
+            break;
+        }
+
+        parse_line_break(p, prefix);
+
+        if !at_line_end(p) {
+            let start = p.cur_start();
+            parse_verbatim_line(p);
+            is_closed = kind.is_closed_by(&text[start..line_end(text, start)]);
+        }
+    }
+
+    content.complete(p, MD_VERBATIM_LINE_LIST);
+    Present(m.complete(p, MD_HTML_BLOCK))
+}
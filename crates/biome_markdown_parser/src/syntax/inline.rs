@@ -0,0 +1,812 @@
+//! Parsing of the inline content of paragraphs, headings and table cells.
+//!
+//! Inline parsing follows the algorithm described by the CommonMark specification: the content
+//! is first scanned for code spans, raw HTML, autolinks, links and delimiter runs, then the
+//! delimiter runs are matched to form emphasis. The result is a tree of [Inline] elements that
+//! the parser then emits as tokens.
+//! https://spec.commonmark.org/0.31.2/#phase-2-inline-structure
+
+use crate::MarkdownParser;
+use crate::lexer::MarkdownLexContext;
+use crate::syntax::html::{scan_autolink, scan_inline_html};
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxKind::*, T};
+use biome_parser::Parser;
+use std::ops::Range;
+
+/// A line of inline content
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InlineLine {
+    /// The offset of the first character of the line, after the leading whitespace
+    pub(crate) start: usize,
+    /// The offset of the end of the line, before the trailing whitespace
+    pub(crate) end: usize,
+    /// The offset at which the line break after this line ends: right after the container
+    /// prefix of the next line. `None` for the last line.
+    pub(crate) break_end: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    /// Where the segment starts in the inline text
+    start: usize,
+    /// Where the segment starts in the source
+    source_start: usize,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LineBreak {
+    kind: MarkdownSyntaxKind,
+    end: usize,
+}
+
+/// The inline content of a block. Its lines are joined with `\n` in a single text that
+/// can be scanned without caring about the container prefixes.
+pub(crate) struct InlineContent {
+    text: String,
+    segments: Vec<Segment>,
+    breaks: Vec<LineBreak>,
+}
+
+impl InlineContent {
+    pub(crate) fn new(source: &str, lines: &[InlineLine]) -> Self {
+        let mut text = String::new();
+        let mut segments = Vec::with_capacity(lines.len());
+        let mut breaks = Vec::with_capacity(lines.len().saturating_sub(1));
+
+        for line in lines {
+            let mut end = line.end;
+
+            if let Some(break_end) = line.break_end {
+                let line_end = crate::syntax::line::line_end(source, end);
+                let kind = if source[end..line_end].ends_with("  ") {
+                    MD_HARD_LINE_LITERAL
+                } else if end == line_end && ends_with_backslash(&source[line.start..end]) {
+                    // The backslash is part of the hard line break
+                    end -= 1;
+                    MD_HARD_LINE_LITERAL
+                } else {
+                    MD_SOFT_BREAK_LITERAL
+                };
+                breaks.push(LineBreak {
+                    kind,
+                    end: break_end,
+                });
+            }
+
+            segments.push(Segment {
+                start: text.len(),
+                source_start: line.start,
+                len: end - line.start,
+            });
+            text.push_str(&source[line.start..end]);
+
+            if line.break_end.is_some() {
+                text.push('\n');
+            }
+        }
+
+        Self {
+            text,
+            segments,
+            breaks,
+        }
+    }
+
+    /// Returns the source offset that corresponds to `offset` in the inline text
+    fn source_offset(&self, offset: usize) -> usize {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start + segment.len < offset);
+        let segment = self.segments[index.min(self.segments.len() - 1)];
+        segment.source_start + (offset - segment.start).min(segment.len)
+    }
+
+    /// Returns the index of the line break at `offset` in the inline text
+    fn line_break_at(&self, offset: usize) -> LineBreak {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start + segment.len < offset);
+        self.breaks[index]
+    }
+
+    /// Returns the offset in the source where the content ends
+    fn source_end(&self) -> usize {
+        self.source_offset(self.text.len())
+    }
+}
+
+fn ends_with_backslash(text: &str) -> bool {
+    text.bytes().rev().take_while(|byte| *byte == b'\\').count() % 2 == 1
+}
+
+/// An element of the inline content. All ranges are ranges of the inline text.
+#[derive(Debug)]
+pub(crate) enum Inline {
+    Text(Range<usize>),
+    LineBreak(usize),
+    Code {
+        open: Range<usize>,
+        close: Range<usize>,
+    },
+    Delimited {
+        kind: MarkdownSyntaxKind,
+        open: Range<usize>,
+        children: Vec<Inline>,
+        close: Range<usize>,
+    },
+    Link {
+        is_image: bool,
+        start: usize,
+        text: Vec<Inline>,
+        text_end: usize,
+        source: Range<usize>,
+    },
+    Html(Range<usize>),
+    Autolink(Range<usize>),
+}
+
+/// A run of `*`, `_` or `~` that may open or close an emphasis
+#[derive(Debug)]
+struct Delimiter {
+    marker: u8,
+    /// The part of the run that hasn't been matched yet
+    start: usize,
+    end: usize,
+    original_len: usize,
+    can_open: bool,
+    can_close: bool,
+    removed: bool,
+}
+
+impl Delimiter {
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+/// A `[` or `![` that may start a link or an image
+#[derive(Debug)]
+struct Bracket {
+    start: usize,
+    is_image: bool,
+    /// The number of delimiters before the bracket
+    delimiters_bottom: usize,
+    active: bool,
+}
+
+/// An element that contains other elements, or that is atomic
+#[derive(Debug)]
+enum InlineSpan {
+    Code {
+        open: Range<usize>,
+        close: Range<usize>,
+    },
+    Html(Range<usize>),
+    Autolink(Range<usize>),
+    Delimited {
+        kind: MarkdownSyntaxKind,
+        open: Range<usize>,
+        close: Range<usize>,
+    },
+    Link {
+        is_image: bool,
+        start: usize,
+        text_end: usize,
+        source: Range<usize>,
+    },
+}
+
+impl InlineSpan {
+    fn range(&self) -> Range<usize> {
+        match self {
+            Self::Code { open, close } | Self::Delimited { open, close, .. } => {
+                open.start..close.end
+            }
+            Self::Html(range) | Self::Autolink(range) => range.clone(),
+            Self::Link { start, source, .. } => *start..source.end + 1,
+        }
+    }
+}
+
+struct InlineScanner<'a> {
+    text: &'a str,
+    spans: Vec<InlineSpan>,
+    delimiters: Vec<Delimiter>,
+    brackets: Vec<Bracket>,
+}
+
+impl<'a> InlineScanner<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            spans: Vec::new(),
+            delimiters: Vec::new(),
+            brackets: Vec::new(),
+        }
+    }
+
+    fn scan(mut self) -> Vec<Inline> {
+        let bytes = self.text.as_bytes();
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            offset = match bytes[offset] {
+                b'\\'
+                    if bytes
+                        .get(offset + 1)
+                        .is_some_and(|byte| byte.is_ascii_punctuation()) =>
+                {
+                    offset + 2
+                }
+                b'`' => self.scan_code_span(offset),
+                b'<' => self.scan_angle_bracket(offset),
+                b'*' | b'_' | b'~' => self.scan_delimiter_run(offset),
+                b'!' if bytes.get(offset + 1) == Some(&b'[') => {
+                    self.push_bracket(offset, true);
+                    offset + 2
+                }
+                b'[' => {
+                    self.push_bracket(offset, false);
+                    offset + 1
+                }
+                b']' => self.scan_close_bracket(offset),
+                _ => offset + self.text[offset..].chars().next().map_or(1, char::len_utf8),
+            };
+        }
+
+        self.process_emphasis(0);
+
+        let mut spans = self.spans;
+        spans.sort_by(|left, right| {
+            let (left, right) = (left.range(), right.range());
+            left.start.cmp(&right.start).then(right.end.cmp(&left.end))
+        });
+
+        let mut spans = spans.into_iter().peekable();
+        build_inlines(self.text, 0..self.text.len(), &mut spans)
+    }
+
+    fn scan_code_span(&mut self, offset: usize) -> usize {
+        let bytes = self.text.as_bytes();
+        let len = run_length(bytes, offset, b'`');
+        let content_start = offset + len;
+
+        let mut current = content_start;
+        while current < bytes.len() {
+            if bytes[current] == b'`' {
+                let closing_len = run_length(bytes, current, b'`');
+                if closing_len == len {
+                    self.spans.push(InlineSpan::Code {
+                        open: offset..content_start,
+                        close: current..current + len,
+                    });
+                    return current + len;
+                }
+                current += closing_len;
+            } else {
+                current += 1;
+            }
+        }
+
+        // No matching backtick string: the backticks are literal text
+        content_start
+    }
+
+    fn scan_angle_bracket(&mut self, offset: usize) -> usize {
+        if let Some(end) = scan_autolink(self.text, offset) {
+            self.spans.push(InlineSpan::Autolink(offset..end));
+            end
+        } else if let Some(end) = scan_inline_html(self.text, offset) {
+            self.spans.push(InlineSpan::Html(offset..end));
+            end
+        } else {
+            offset + 1
+        }
+    }
+
+    fn scan_delimiter_run(&mut self, offset: usize) -> usize {
+        let bytes = self.text.as_bytes();
+        let marker = bytes[offset];
+        let len = run_length(bytes, offset, marker);
+        let end = offset + len;
+
+        // GFM strikethrough uses one or two tildes
+        if marker == b'~' && len > 2 {
+            return end;
+        }
+
+        let before = self.text[..offset].chars().next_back();
+        let after = self.text[end..].chars().next();
+
+        let is_whitespace_before = before.is_none_or(char::is_whitespace);
+        let is_whitespace_after = after.is_none_or(char::is_whitespace);
+        let is_punctuation_before = before.is_some_and(is_punctuation);
+        let is_punctuation_after = after.is_some_and(is_punctuation);
+
+        let left_flanking = !is_whitespace_after
+            && (!is_punctuation_after || is_whitespace_before || is_punctuation_before);
+        let right_flanking = !is_whitespace_before
+            && (!is_punctuation_before || is_whitespace_after || is_punctuation_after);
+
+        let (can_open, can_close) = if marker == b'_' {
+            (
+                left_flanking && (!right_flanking || is_punctuation_before),
+                right_flanking && (!left_flanking || is_punctuation_after),
+            )
+        } else {
+            (left_flanking, right_flanking)
+        };
+
+        if can_open || can_close {
+            self.delimiters.push(Delimiter {
+                marker,
+                start: offset,
+                end,
+                original_len: len,
+                can_open,
+                can_close,
+                removed: false,
+            });
+        }
+
+        end
+    }
+
+    fn push_bracket(&mut self, offset: usize, is_image: bool) {
+        self.brackets.push(Bracket {
+            start: offset,
+            is_image,
+            delimiters_bottom: self.delimiters.len(),
+            active: true,
+        });
+    }
+
+    fn scan_close_bracket(&mut self, offset: usize) -> usize {
+        let Some(bracket) = self.brackets.pop() else {
+            return offset + 1;
+        };
+
+        if !bracket.active {
+            return offset + 1;
+        }
+
+        let Some(source_end) = scan_link_source(self.text, offset + 1) else {
+            return offset + 1;
+        };
+
+        self.process_emphasis(bracket.delimiters_bottom);
+        self.spans.push(InlineSpan::Link {
+            is_image: bracket.is_image,
+            start: bracket.start,
+            text_end: offset,
+            source: offset + 2..source_end,
+        });
+
+        // Links can't contain other links
+        if !bracket.is_image {
+            for bracket in &mut self.brackets {
+                if !bracket.is_image {
+                    bracket.active = false;
+                }
+            }
+        }
+
+        source_end + 1
+    }
+
+    /// Matches the delimiter runs after `bottom` to form emphasis, then removes them.
+    /// https://spec.commonmark.org/0.31.2/#process-emphasis
+    fn process_emphasis(&mut self, bottom: usize) {
+        let mut closer_index = bottom;
+
+        while closer_index < self.delimiters.len() {
+            let closer = &self.delimiters[closer_index];
+            if closer.removed || !closer.can_close {
+                closer_index += 1;
+                continue;
+            }
+
+            let opener_index = (bottom..closer_index).rev().find(|index| {
+                let opener = &self.delimiters[*index];
+                !opener.removed
+                    && opener.can_open
+                    && opener.marker == closer.marker
+                    && can_match(opener, closer)
+            });
+
+            let Some(opener_index) = opener_index else {
+                if !closer.can_open {
+                    self.delimiters[closer_index].removed = true;
+                }
+                closer_index += 1;
+                continue;
+            };
+
+            let (opener, closer) = (
+                &self.delimiters[opener_index],
+                &self.delimiters[closer_index],
+            );
+            let (len, kind) = match closer.marker {
+                b'~' => (closer.len(), MD_INLINE_STRIKETHROUGH),
+                _ if opener.len() >= 2 && closer.len() >= 2 => (2, MD_INLINE_EMPHASIS),
+                _ => (1, MD_INLINE_ITALIC),
+            };
+
+            let open = opener.end - len..opener.end;
+            let close = closer.start..closer.start + len;
+            self.spans.push(InlineSpan::Delimited {
+                kind,
+                open: open.clone(),
+                close: close.clone(),
+            });
+
+            self.delimiters[opener_index].end = open.start;
+            self.delimiters[closer_index].start = close.end;
+
+            for delimiter in &mut self.delimiters[opener_index + 1..closer_index] {
+                delimiter.removed = true;
+            }
+            if self.delimiters[opener_index].len() == 0 {
+                self.delimiters[opener_index].removed = true;
+            }
+            if self.delimiters[closer_index].len() == 0 {
+                self.delimiters[closer_index].removed = true;
+                closer_index += 1;
+            }
+        }
+
+        self.delimiters.truncate(bottom);
+    }
+}
+
+fn can_match(opener: &Delimiter, closer: &Delimiter) -> bool {
+    if closer.marker == b'~' {
+        return opener.len() == closer.len();
+    }
+
+    // The "rule of 3": https://spec.commonmark.org/0.31.2/#can-open-emphasis
+    let is_multiple_of_three = (opener.original_len + closer.original_len) % 3 == 0;
+    !((opener.can_close || closer.can_open)
+        && is_multiple_of_three
+        && !(opener.original_len % 3 == 0 && closer.original_len % 3 == 0))
+}
+
+fn run_length(bytes: &[u8], offset: usize, byte: u8) -> usize {
+    bytes[offset..].iter().take_while(|b| **b == byte).count()
+}
+
+fn is_punctuation(char: char) -> bool {
+    if char.is_ascii() {
+        char.is_ascii_punctuation()
+    } else {
+        !char.is_alphanumeric() && !char.is_whitespace() && !char.is_control()
+    }
+}
+
+/// Scans the destination and the title of an inline link, starting right after the `]` of the
+/// link text. Returns the offset of the closing `)`.
+/// https://spec.commonmark.org/0.31.2/#inline-link
+fn scan_link_source(text: &str, offset: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.get(offset) != Some(&b'(') {
+        return None;
+    }
+
+    let mut current = skip_link_whitespace(bytes, offset + 1);
+
+    // Destination
+    let destination_start = current;
+    if bytes.get(current) == Some(&b'<') {
+        current += 1;
+        loop {
+            match bytes.get(current)? {
+                b'>' => {
+                    current += 1;
+                    break;
+                }
+                b'\n' | b'<' => return None,
+                b'\\' => current += 2,
+                _ => current += 1,
+            }
+        }
+    } else {
+        let mut depth = 0usize;
+        while let Some(byte) = bytes.get(current) {
+            match byte {
+                b'\\'
+                    if bytes
+                        .get(current + 1)
+                        .is_some_and(|byte| byte.is_ascii_punctuation()) =>
+                {
+                    current += 2;
+                    continue;
+                }
+                b'(' => depth += 1,
+                b')' if depth == 0 => break,
+                b')' => depth -= 1,
+                byte if *byte <= b' ' => break,
+                _ => {}
+            }
+            current += 1;
+        }
+        if depth > 0 {
+            return None;
+        }
+    }
+    let has_destination = current > destination_start;
+
+    // Title
+    let before_title = current;
+    current = skip_link_whitespace(bytes, current);
+    if let Some(quote @ (b'"' | b'\'' | b'(')) = bytes.get(current).copied() {
+        if has_destination && current == before_title {
+            return None;
+        }
+        let closing = if quote == b'(' { b')' } else { quote };
+        current += 1;
+        loop {
+            match bytes.get(current)? {
+                b'\\' => current += 2,
+                byte if *byte == closing => {
+                    current += 1;
+                    break;
+                }
+                b'(' if quote == b'(' => return None,
+                _ => current += 1,
+            }
+        }
+        current = skip_link_whitespace(bytes, current);
+    }
+
+    (bytes.get(current) == Some(&b')')).then_some(current)
+}
+
+fn skip_link_whitespace(bytes: &[u8], mut offset: usize) -> usize {
+    while bytes
+        .get(offset)
+        .is_some_and(|byte| matches!(byte, b' ' | b'\t' | b'\n'))
+    {
+        offset += 1;
+    }
+    offset
+}
+
+/// Builds the elements of `range` from the sorted `spans`. Everything that isn't part of a span
+/// is text.
+fn build_inlines(
+    text: &str,
+    range: Range<usize>,
+    spans: &mut std::iter::Peekable<std::vec::IntoIter<InlineSpan>>,
+) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    let mut current = range.start;
+
+    while let Some(span) = spans.next_if(|span| span.range().start < range.end) {
+        let span_range = span.range();
+        push_text(text, current..span_range.start, &mut inlines);
+
+        inlines.push(match span {
+            InlineSpan::Code { open, close } => Inline::Code { open, close },
+            InlineSpan::Html(range) => Inline::Html(range),
+            InlineSpan::Autolink(range) => Inline::Autolink(range),
+            InlineSpan::Delimited { kind, open, close } => Inline::Delimited {
+                kind,
+                children: build_inlines(text, open.end..close.start, spans),
+                open,
+                close,
+            },
+            InlineSpan::Link {
+                is_image,
+                start,
+                text_end,
+                source,
+            } => {
+                let text_start = start + if is_image { 2 } else { 1 };
+                Inline::Link {
+                    is_image,
+                    start,
+                    text: build_inlines(text, text_start..text_end, spans),
+                    text_end,
+                    source,
+                }
+            }
+        });
+
+        current = span_range.end;
+    }
+
+    push_text(text, current..range.end, &mut inlines);
+    inlines
+}
+
+/// Pushes the text of `range`, split at the line breaks
+fn push_text(text: &str, range: Range<usize>, inlines: &mut Vec<Inline>) {
+    let mut start = range.start;
+    for (index, _) in text[range.clone()].match_indices('\n') {
+        let line_break = range.start + index;
+        if line_break > start {
+            inlines.push(Inline::Text(start..line_break));
+        }
+        inlines.push(Inline::LineBreak(line_break));
+        start = line_break + 1;
+    }
+    if range.end > start {
+        inlines.push(Inline::Text(start..range.end));
+    }
+}
+
+/// Parses the inline content made of `lines` as a [MD_INLINE_ITEM_LIST].
+///
+/// The token that follows the inline content is lexed in `end_context`.
+pub(crate) fn parse_inline_item_list(
+    p: &mut MarkdownParser,
+    lines: &[InlineLine],
+    end_context: MarkdownLexContext,
+) {
+    let content = InlineContent::new(p.text(), lines);
+    let inlines = InlineScanner::new(&content.text).scan();
+
+    let mut emitter = InlineEmitter {
+        content: &content,
+        end: content.source_end(),
+        end_context,
+    };
+
+    let m = p.start();
+    emitter.emit_inlines(p, &inlines);
+    m.complete(p, MD_INLINE_ITEM_LIST);
+}
+
+/// Parses the text of `lines` as a [MD_INLINE_ITEM_LIST] made of text and line breaks only.
+pub(crate) fn parse_verbatim_item_list(
+    p: &mut MarkdownParser,
+    lines: &[InlineLine],
+    end_context: MarkdownLexContext,
+) {
+    let content = InlineContent::new(p.text(), lines);
+    let mut emitter = InlineEmitter {
+        content: &content,
+        end: content.source_end(),
+        end_context,
+    };
+
+    let m = p.start();
+    emitter.emit_verbatim(p, 0..content.text.len());
+    m.complete(p, MD_INLINE_ITEM_LIST);
+}
+
+struct InlineEmitter<'a> {
+    content: &'a InlineContent,
+    /// Where the inline content ends in the source
+    end: usize,
+    end_context: MarkdownLexContext,
+}
+
+impl InlineEmitter<'_> {
+    fn emit_inlines(&mut self, p: &mut MarkdownParser, inlines: &[Inline]) {
+        for inline in inlines {
+            self.emit_inline(p, inline);
+        }
+    }
+
+    fn emit_inline(&mut self, p: &mut MarkdownParser, inline: &Inline) {
+        match inline {
+            Inline::Text(range) => {
+                let m = p.start();
+                self.bump_until(p, MD_TEXTUAL_LITERAL, range.end);
+                m.complete(p, MD_TEXTUAL);
+            }
+            Inline::LineBreak(offset) => self.emit_line_break(p, *offset),
+            Inline::Code { open, close } => {
+                let m = p.start();
+                self.bump_until(p, MD_BACKTICKS_LITERAL, open.end);
+                let list = p.start();
+                self.emit_verbatim(p, open.end..close.start);
+                list.complete(p, MD_INLINE_ITEM_LIST);
+                self.bump_until(p, MD_BACKTICKS_LITERAL, close.end);
+                m.complete(p, MD_INLINE_CODE);
+            }
+            Inline::Delimited {
+                kind,
+                open,
+                children,
+                close,
+            } => {
+                let delimiter = &self.content.text[open.clone()];
+                let delimiter_kind = match delimiter {
+                    "**" => T![**],
+                    "__" => T!["__"],
+                    "*" => T![*],
+                    "_" => T!["_"],
+                    "~~" => T![~~],
+                    _ => T![~],
+                };
+
+                let m = p.start();
+                self.bump_until(p, delimiter_kind, open.end);
+                let list = p.start();
+                self.emit_inlines(p, children);
+                list.complete(p, MD_INLINE_ITEM_LIST);
+                self.bump_until(p, delimiter_kind, close.end);
+                m.complete(p, *kind);
+            }
+            Inline::Link {
+                is_image,
+                start,
+                text,
+                text_end,
+                source,
+            } => {
+                let m = p.start();
+                if *is_image {
+                    self.bump_until(p, T![!], start + 1);
+                }
+                let text_start = start + if *is_image { 2 } else { 1 };
+                self.bump_until(p, T!['['], text_start);
+                let list = p.start();
+                self.emit_inlines(p, text);
+                list.complete(p, MD_INLINE_ITEM_LIST);
+                self.bump_until(p, T![']'], text_end + 1);
+                self.bump_until(p, T!['('], source.start);
+                let list = p.start();
+                self.emit_verbatim(p, source.clone());
+                list.complete(p, MD_INLINE_ITEM_LIST);
+                self.bump_until(p, T![')'], source.end + 1);
+                m.complete(
+                    p,
+                    if *is_image {
+                        MD_INLINE_IMAGE
+                    } else {
+                        MD_INLINE_LINK
+                    },
+                );
+            }
+            Inline::Html(range) => {
+                let m = p.start();
+                let list = p.start();
+                self.emit_verbatim(p, range.clone());
+                list.complete(p, MD_INLINE_ITEM_LIST);
+                m.complete(p, MD_INLINE_HTML);
+            }
+            Inline::Autolink(range) => {
+                let m = p.start();
+                self.bump_until(p, T![<], range.start + 1);
+                self.bump_until(p, MD_TEXTUAL_LITERAL, range.end - 1);
+                self.bump_until(p, T![>], range.end);
+                m.complete(p, MD_AUTOLINK);
+            }
+        }
+    }
+
+    /// Emits the text of `range` as it is, split at the line breaks
+    fn emit_verbatim(&mut self, p: &mut MarkdownParser, range: Range<usize>) {
+        let mut inlines = Vec::new();
+        push_text(&self.content.text, range, &mut inlines);
+        self.emit_inlines(p, &inlines);
+    }
+
+    fn emit_line_break(&mut self, p: &mut MarkdownParser, offset: usize) {
+        let line_break = self.content.line_break_at(offset);
+        let (kind, node_kind) = if line_break.kind == MD_HARD_LINE_LITERAL {
+            (MD_HARD_LINE_LITERAL, MD_HARD_LINE)
+        } else {
+            (MD_SOFT_BREAK_LITERAL, MD_SOFT_BREAK)
+        };
+
+        let m = p.start();
+        p.bump_until(kind, line_break.end, MarkdownLexContext::Regular);
+        m.complete(p, node_kind);
+    }
+
+    /// Bumps the current token until `end`, an offset of the inline text.
+    fn bump_until(&mut self, p: &mut MarkdownParser, kind: MarkdownSyntaxKind, end: usize) {
+        let end = self.content.source_offset(end);
+        let context = if end == self.end {
+            self.end_context
+        } else {
+            MarkdownLexContext::Inline
+        };
+        p.bump_until(kind, end, context);
+    }
+}
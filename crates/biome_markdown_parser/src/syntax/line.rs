@@ -0,0 +1,426 @@
+//! Utilities to inspect the source text line by line.
+//!
+//! The block structure of a Markdown document is defined by the beginning of its lines:
+//! a line belongs to an open container block only if it starts with the markers of that
+//! container (`>` for a block quote, the indentation of the content for a list item).
+//! https://spec.commonmark.org/0.31.2/#appendix-a-parsing-strategy
+
+use crate::syntax::html::{HtmlBlockKind, html_block_start};
+
+/// The width of a tab stop, used to compute the indentation of a line.
+/// https://spec.commonmark.org/0.31.2/#tabs
+const TAB_STOP: usize = 4;
+
+/// A container block that is open while parsing a line.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Container {
+    /// A block quote. Its lines start with `>`.
+    Quote,
+    /// A list item. Its lines are indented by `indent` columns.
+    ///
+    /// An item that starts with two blank lines is empty: no other line can continue it.
+    ListItem {
+        indent: usize,
+        marker: ListMarker,
+        is_empty: bool,
+    },
+}
+
+/// The kind of marker of a list item. Two list items belong to the same list only if they use
+/// the same kind of marker.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ListMarker {
+    /// `-`, `+` or `*`
+    Bullet(u8),
+    /// `1.` or `1)`
+    Ordered(u8),
+}
+
+/// A position inside a line, together with the column it corresponds to once tabs are expanded.
+///
+/// A tab can be partially consumed by the markers of a container: the position then points to
+/// the tab, and the column is between the column of the tab and the next tab stop.
+/// https://spec.commonmark.org/0.31.2/#tabs
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct LinePosition {
+    pub(crate) offset: usize,
+    pub(crate) column: usize,
+}
+
+impl LinePosition {
+    pub(crate) fn new(offset: usize, column: usize) -> Self {
+        Self { offset, column }
+    }
+
+    /// Returns the position of the start of the line that starts at `offset`
+    pub(crate) fn line_start(offset: usize) -> Self {
+        Self { offset, column: 0 }
+    }
+
+    /// Returns the position right after the character at the current position.
+    fn advance(self, text: &str) -> Self {
+        match text.as_bytes().get(self.offset) {
+            Some(b'\t') => Self {
+                offset: self.offset + 1,
+                column: next_tab_stop(self.column),
+            },
+            Some(_) => {
+                let len = text[self.offset..].chars().next().map_or(1, char::len_utf8);
+                Self {
+                    offset: self.offset + len,
+                    column: self.column + 1,
+                }
+            }
+            None => self,
+        }
+    }
+}
+
+fn next_tab_stop(column: usize) -> usize {
+    column + TAB_STOP - column % TAB_STOP
+}
+
+/// Returns the offset of the end of the line that contains `offset`, excluding the line break.
+pub(crate) fn line_end(text: &str, offset: usize) -> usize {
+    text.as_bytes()[offset..]
+        .iter()
+        .position(|byte| matches!(byte, b'\n' | b'\r'))
+        .map_or(text.len(), |position| offset + position)
+}
+
+/// Returns the offset of the start of the line that follows the line break at `offset`, or
+/// `None` if there's no line break at `offset`.
+pub(crate) fn next_line_start(text: &str, offset: usize) -> Option<usize> {
+    match text.as_bytes().get(offset) {
+        Some(b'\r') if text.as_bytes().get(offset + 1) == Some(&b'\n') => Some(offset + 2),
+        Some(b'\r' | b'\n') => Some(offset + 1),
+        _ => None,
+    }
+}
+
+/// Returns the offset of the end of the line that contains `offset`, excluding the trailing
+/// whitespace and the line break.
+pub(crate) fn trimmed_line_end(text: &str, offset: usize) -> usize {
+    let end = line_end(text, offset);
+    offset + text[offset..end].trim_end_matches([' ', '\t']).len()
+}
+
+/// Returns `true` if the rest of the line starting at `offset` only contains whitespace.
+pub(crate) fn is_blank(text: &str, offset: usize) -> bool {
+    text[offset..line_end(text, offset)]
+        .bytes()
+        .all(|byte| matches!(byte, b' ' | b'\t'))
+}
+
+/// Skips the whitespace at `position` until `max_columns` columns have been skipped.
+///
+/// A tab that goes beyond the limit is partially consumed if `split_tabs` is `true`, and it
+/// isn't skipped otherwise.
+pub(crate) fn skip_whitespace(
+    text: &str,
+    position: LinePosition,
+    max_columns: usize,
+    split_tabs: bool,
+) -> LinePosition {
+    let mut current = position;
+
+    while current.column - position.column < max_columns {
+        match text.as_bytes().get(current.offset) {
+            Some(b' ') => current = current.advance(text),
+            Some(b'\t') => {
+                let next = current.advance(text);
+                if next.column - position.column > max_columns {
+                    if split_tabs {
+                        current.column = position.column + max_columns;
+                    }
+                    break;
+                }
+                current = next;
+            }
+            _ => break,
+        }
+    }
+
+    current
+}
+
+/// Skips all the whitespace at `position` and returns the position of the first other character
+/// together with the number of skipped columns.
+pub(crate) fn first_non_whitespace(text: &str, position: LinePosition) -> (LinePosition, usize) {
+    let end = skip_whitespace(text, position, usize::MAX, true);
+    (end, end.column - position.column)
+}
+
+/// The result of matching the markers of the open containers at the start of a line.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ContainerPrefix {
+    /// How many of the open containers continue on this line
+    pub(crate) matched: usize,
+    /// The position right after the markers of the matched containers
+    pub(crate) end: LinePosition,
+}
+
+/// Matches the markers of the `containers` at the start of the line that starts at `line_start`.
+pub(crate) fn match_containers(
+    text: &str,
+    line_start: usize,
+    containers: &[Container],
+) -> ContainerPrefix {
+    let mut position = LinePosition::line_start(line_start);
+
+    for (index, container) in containers.iter().enumerate() {
+        let next = match container {
+            Container::Quote => quote_marker(text, position).map(|(_, content)| content),
+            Container::ListItem {
+                indent, is_empty, ..
+            } => {
+                if is_blank(text, position.offset) {
+                    Some(skip_whitespace(text, position, *indent, false))
+                } else if *is_empty {
+                    None
+                } else {
+                    let next = skip_whitespace(text, position, *indent, true);
+                    (next.column - position.column >= *indent).then_some(next)
+                }
+            }
+        };
+
+        match next {
+            Some(next) => position = next,
+            None => {
+                return ContainerPrefix {
+                    matched: index,
+                    end: position,
+                };
+            }
+        }
+    }
+
+    ContainerPrefix {
+        matched: containers.len(),
+        end: position,
+    }
+}
+
+/// Returns the offset of the `>` of a block quote marker that starts at `position`, and the
+/// position of the content of the block quote.
+pub(crate) fn quote_marker(text: &str, position: LinePosition) -> Option<(usize, LinePosition)> {
+    let marker = skip_whitespace(text, position, 3, false);
+    if text.as_bytes().get(marker.offset) != Some(&b'>') {
+        return None;
+    }
+
+    let after_marker = marker.advance(text);
+    // The marker includes an optional space after `>`
+    let content = skip_whitespace(text, after_marker, 1, true);
+
+    Some((marker.offset, content))
+}
+
+/// The beginning of a block, other than a paragraph.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum BlockStart {
+    IndentedCode,
+    ThematicBreak,
+    AtxHeading,
+    FencedCode,
+    Html(HtmlBlockKind),
+    Quote,
+    BulletListItem,
+    OrderedListItem,
+}
+
+/// Returns the kind of block that starts at `position`, or `None` if the line is the start or
+/// the continuation of a paragraph.
+///
+/// When `in_paragraph` is `true`, only the blocks that can interrupt a paragraph are returned.
+pub(crate) fn block_start(
+    text: &str,
+    position: LinePosition,
+    in_paragraph: bool,
+) -> Option<BlockStart> {
+    let (start, indent) = first_non_whitespace(text, position);
+    if is_blank(text, start.offset) {
+        return None;
+    }
+
+    if indent >= 4 {
+        return (!in_paragraph).then_some(BlockStart::IndentedCode);
+    }
+
+    let offset = start.offset;
+    match text.as_bytes()[offset] {
+        b'>' => Some(BlockStart::Quote),
+        b'#' if atx_heading_level(text, offset).is_some() => Some(BlockStart::AtxHeading),
+        b'`' | b'~' if code_fence(text, offset).is_some() => Some(BlockStart::FencedCode),
+        b'<' => html_block_start(text, offset, in_paragraph).map(BlockStart::Html),
+        b'*' | b'-' | b'_' if is_thematic_break(text, offset) => Some(BlockStart::ThematicBreak),
+        b'*' | b'-' | b'+' | b'0'..=b'9' => {
+            let item = list_item_start(text, start)?;
+            if in_paragraph && (item.is_empty || item.start_number.is_some_and(|n| n != 1)) {
+                return None;
+            }
+
+            Some(match item.marker {
+                ListMarker::Bullet(_) => BlockStart::BulletListItem,
+                ListMarker::Ordered(_) => BlockStart::OrderedListItem,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the line at `offset` is a thematic break: three or more `*`, `-` or `_`,
+/// optionally separated by whitespace.
+pub(crate) fn is_thematic_break(text: &str, offset: usize) -> bool {
+    let line = &text[offset..line_end(text, offset)];
+    let Some(marker) = line.bytes().next() else {
+        return false;
+    };
+
+    let mut count = 0;
+    for byte in line.bytes() {
+        match byte {
+            b' ' | b'\t' => {}
+            byte if byte == marker => count += 1,
+            _ => return false,
+        }
+    }
+
+    matches!(marker, b'*' | b'-' | b'_') && count >= 3
+}
+
+/// Returns the level of the ATX heading that starts at `offset`.
+pub(crate) fn atx_heading_level(text: &str, offset: usize) -> Option<usize> {
+    let bytes = &text.as_bytes()[offset..];
+    let level = bytes.iter().take_while(|byte| **byte == b'#').count();
+
+    let is_followed_by_space =
+        matches!(bytes.get(level), None | Some(b' ' | b'\t' | b'\n' | b'\r'));
+    ((1..=6).contains(&level) && is_followed_by_space).then_some(level)
+}
+
+/// Returns `true` if the line at `offset` is the underline of a setext heading.
+pub(crate) fn is_setext_underline(text: &str, position: LinePosition) -> bool {
+    let (start, indent) = first_non_whitespace(text, position);
+    if indent >= 4 {
+        return false;
+    }
+
+    let line = text[start.offset..line_end(text, start.offset)].trim_end_matches([' ', '\t']);
+    match line.bytes().next() {
+        Some(marker @ (b'=' | b'-')) => line.bytes().all(|byte| byte == marker),
+        _ => false,
+    }
+}
+
+/// An opening or closing code fence.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct CodeFence {
+    /// The fence character: `` ` `` or `~`
+    pub(crate) marker: u8,
+    /// The number of fence characters
+    pub(crate) len: usize,
+}
+
+/// Returns the opening code fence that starts at `offset`.
+pub(crate) fn code_fence(text: &str, offset: usize) -> Option<CodeFence> {
+    let bytes = &text.as_bytes()[offset..];
+    let marker = *bytes.first()?;
+    if !matches!(marker, b'`' | b'~') {
+        return None;
+    }
+
+    let len = bytes.iter().take_while(|byte| **byte == marker).count();
+    if len < 3 {
+        return None;
+    }
+
+    // The info string of a backtick fence can't contain backticks
+    let info = &text[offset + len..line_end(text, offset)];
+    if marker == b'`' && info.contains('`') {
+        return None;
+    }
+
+    Some(CodeFence { marker, len })
+}
+
+/// Returns `true` if the line at `position` closes a code block opened by `fence`.
+pub(crate) fn is_closing_code_fence(text: &str, position: LinePosition, fence: CodeFence) -> bool {
+    let (start, indent) = first_non_whitespace(text, position);
+    if indent >= 4 {
+        return false;
+    }
+
+    let line = text[start.offset..line_end(text, start.offset)].trim_end_matches([' ', '\t']);
+    line.len() >= fence.len && line.bytes().all(|byte| byte == fence.marker)
+}
+
+/// The beginning of a list item.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ListItemStart {
+    pub(crate) marker: ListMarker,
+    /// The offset right after the marker
+    pub(crate) marker_end: usize,
+    /// The number for the first item of an ordered list
+    pub(crate) start_number: Option<u64>,
+    /// The position of the content of the list item
+    pub(crate) content: LinePosition,
+    /// `true` if the list item starts with an empty line
+    pub(crate) is_empty: bool,
+}
+
+/// Returns the list item that starts at `position`, which must be the first non whitespace
+/// character of the line.
+pub(crate) fn list_item_start(text: &str, position: LinePosition) -> Option<ListItemStart> {
+    let bytes = text.as_bytes();
+    let offset = position.offset;
+
+    let (marker, marker_len, start_number) = match bytes.get(offset)? {
+        byte @ (b'-' | b'+' | b'*') => (ListMarker::Bullet(*byte), 1, None),
+        b'0'..=b'9' => {
+            let digits = bytes[offset..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+            if digits > 9 {
+                return None;
+            }
+            let delimiter = *bytes.get(offset + digits)?;
+            if !matches!(delimiter, b'.' | b')') {
+                return None;
+            }
+            let number = text[offset..offset + digits].parse().ok()?;
+            (ListMarker::Ordered(delimiter), digits + 1, Some(number))
+        }
+        _ => return None,
+    };
+
+    let marker_end = LinePosition::new(offset + marker_len, position.column + marker_len);
+    if !matches!(
+        bytes.get(marker_end.offset),
+        None | Some(b' ' | b'\t' | b'\n' | b'\r')
+    ) {
+        return None;
+    }
+
+    let is_empty = is_blank(text, marker_end.offset);
+    let (first_content, spaces) = first_non_whitespace(text, marker_end);
+    let content = if is_empty {
+        // The content of the following lines starts one column after the marker
+        LinePosition::new(marker_end.offset, marker_end.column + 1)
+    } else if spaces > 4 {
+        // The content starts after exactly one space: the rest belongs to an indented code block
+        skip_whitespace(text, marker_end, 1, true)
+    } else {
+        first_content
+    };
+
+    Some(ListItemStart {
+        marker,
+        marker_end: marker_end.offset,
+        start_number,
+        content,
+        is_empty,
+    })
+}
@@ -0,0 +1,107 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::line::{
+    Container, ListMarker, first_non_whitespace, is_blank, line_end, list_item_start,
+    match_containers, next_line_start,
+};
+use crate::syntax::{at_line_end, parse_block_list};
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Parses a list of items that start with `-`, `+` or `*`.
+/// https://spec.commonmark.org/0.31.2/#lists
+pub(crate) fn parse_bullet_list(p: &mut MarkdownParser) -> ParsedSyntax {
+    parse_list(
+        p,
+        MD_BULLET_LIST_ITEM,
+        MD_BULLET_LIST_ITEM_LIST,
+        MD_BULLET_LIST,
+    )
+}
+
+/// Parses a list of items that start with a number followed by `.` or `)`.
+/// https://spec.commonmark.org/0.31.2/#lists
+pub(crate) fn parse_ordered_list(p: &mut MarkdownParser) -> ParsedSyntax {
+    parse_list(
+        p,
+        MD_ORDERED_LIST_ITEM,
+        MD_ORDERED_LIST_ITEM_LIST,
+        MD_ORDERED_LIST,
+    )
+}
+
+fn parse_list(
+    p: &mut MarkdownParser,
+    item_kind: MarkdownSyntaxKind,
+    list_kind: MarkdownSyntaxKind,
+    kind: MarkdownSyntaxKind,
+) -> ParsedSyntax {
+    let m = p.start();
+    let items = p.start();
+
+    loop {
+        if parse_list_item(p, item_kind).is_absent() {
+            break;
+        }
+
+        // The line break after an item is only consumed when the next line starts a sibling
+        if at_line_end(p) {
+            break;
+        }
+    }
+
+    items.complete(p, list_kind);
+    Present(m.complete(p, kind))
+}
+
+/// Parses a list item. Its content continues on the following lines that are indented at
+/// least as much as the first block of the item.
+/// https://spec.commonmark.org/0.31.2/#list-items
+fn parse_list_item(p: &mut MarkdownParser, kind: MarkdownSyntaxKind) -> ParsedSyntax {
+    let text = p.text();
+    let line = p.state().line;
+    let Some(item) = list_item_start(text, first_non_whitespace(text, line).0) else {
+        return Absent;
+    };
+
+    let m = p.start();
+
+    let marker_kind = match item.marker {
+        ListMarker::Bullet(b'-') => T![-],
+        ListMarker::Bullet(b'*') => T![*],
+        ListMarker::Bullet(_) => T![+],
+        ListMarker::Ordered(_) => MD_ORDERED_LIST_MARKER_LITERAL,
+    };
+    p.bump_until(marker_kind, item.marker_end, MarkdownLexContext::Regular);
+
+    // A list item can begin with at most one blank line
+    let is_empty = item.is_empty && is_followed_by_blank_line(p);
+
+    let state = p.state_mut();
+    state.containers.push(Container::ListItem {
+        indent: item.content.column - line.column,
+        marker: item.marker,
+        is_empty,
+    });
+    state.line = item.content;
+
+    parse_block_list(p);
+
+    p.state_mut().containers.pop();
+
+    Present(m.complete(p, kind))
+}
+
+/// Returns `true` if the line after the current line is blank
+fn is_followed_by_blank_line(p: &MarkdownParser) -> bool {
+    let text = p.text();
+    let Some(start) = next_line_start(text, line_end(text, p.cur_start())) else {
+        return false;
+    };
+
+    let prefix = match_containers(text, start, &p.state().containers);
+    prefix.matched == p.state().containers.len() && is_blank(text, prefix.end.offset)
+}
@@ -0,0 +1,305 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::{InlineLine, parse_inline_item_list, parse_verbatim_item_list};
+use crate::syntax::line::{
+    BlockStart, Container, block_start, first_non_whitespace, is_blank, is_setext_underline,
+    line_end, match_containers, next_line_start, trimmed_line_end,
+};
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Parses a paragraph, or a setext heading when the paragraph is followed by an underline.
+///
+/// The paragraph continues on the following lines until a blank line or the start of a block
+/// that can interrupt a paragraph. A line that doesn't match all the open containers is also
+/// part of the paragraph if it would be a continuation line otherwise.
+/// https://spec.commonmark.org/0.31.2/#paragraphs
+/// https://spec.commonmark.org/0.31.2/#lazy-continuation-line
+pub(crate) fn parse_paragraph(p: &mut MarkdownParser) -> ParsedSyntax {
+    if let Some(definition) =
+        link_reference_definition(p.text(), p.cur_start(), &p.state().containers)
+    {
+        return parse_link_reference_definition(p, definition);
+    }
+
+    let text = p.text();
+    let containers = &p.state().containers;
+    let depth = containers.len();
+
+    let start = p.cur_start();
+    let mut lines = vec![InlineLine {
+        start,
+        end: trimmed_line_end(text, start),
+        break_end: None,
+    }];
+    let mut underline = None;
+
+    loop {
+        let Some(last) = lines.last_mut() else {
+            break;
+        };
+        let Some(next) = next_line_start(text, line_end(text, last.end)) else {
+            break;
+        };
+        if next == text.len() {
+            break;
+        }
+
+        let prefix = match_containers(text, next, containers);
+        if is_blank(text, prefix.end.offset) {
+            break;
+        }
+        if prefix.matched == depth && is_setext_underline(text, prefix.end) {
+            underline = Some(prefix);
+            break;
+        }
+        // A line that doesn't match all the containers closes them if it starts any block,
+        // because it doesn't interrupt the paragraph
+        let interrupts = if prefix.matched == depth {
+            block_start(text, prefix.end, true).is_some()
+        } else {
+            !matches!(
+                block_start(text, prefix.end, false),
+                None | Some(BlockStart::IndentedCode)
+            )
+        };
+        if interrupts {
+            break;
+        }
+
+        let start = first_non_whitespace(text, prefix.end).0.offset;
+        last.break_end = Some(prefix.end.offset);
+        lines.push(InlineLine {
+            start,
+            end: trimmed_line_end(text, start),
+            break_end: None,
+        });
+    }
+
+    let m = p.start();
+
+    parse_inline_item_list(p, &lines, MarkdownLexContext::Regular);
+
+    let Some(prefix) = underline else {
+        return Present(m.complete(p, MD_PARAGRAPH));
+    };
+
+    p.bump_until(NEWLINE, prefix.end.offset, MarkdownLexContext::Regular);
+    p.state_mut().line = prefix.end;
+
+    let end = trimmed_line_end(text, p.cur_start());
+    p.bump_until(
+        MD_SETEXT_UNDERLINE_LITERAL,
+        end,
+        MarkdownLexContext::Regular,
+    );
+
+    Present(m.complete(p, MD_SETEXT_HEADER))
+}
+
+/// The parts of a link reference definition
+#[derive(Debug, Clone)]
+struct LinkReferenceDefinition {
+    /// The offset of the `]` that closes the label
+    label_end: usize,
+    /// The lines of the destination and of the title. The destination and the title can
+    /// each start on a new line.
+    lines: Vec<InlineLine>,
+}
+
+/// Returns the link reference definition that starts at `offset`, whose label is on the first
+/// line: `[label]: destination "title"`.
+/// https://spec.commonmark.org/0.31.2/#link-reference-definitions
+fn link_reference_definition(
+    text: &str,
+    offset: usize,
+    containers: &[Container],
+) -> Option<LinkReferenceDefinition> {
+    let end = trimmed_line_end(text, offset);
+    let bytes = &text.as_bytes()[..end];
+    if bytes.get(offset) != Some(&b'[') {
+        return None;
+    }
+
+    // Label
+    let mut current = offset + 1;
+    loop {
+        match bytes.get(current)? {
+            b'\\' => current += 2,
+            b'[' => return None,
+            b']' => break,
+            _ => current += 1,
+        }
+    }
+    let label_end = current;
+    if text[offset + 1..label_end].trim().is_empty() || bytes.get(label_end + 1) != Some(&b':') {
+        return None;
+    }
+
+    let mut lines = vec![InlineLine {
+        start: label_end + 2,
+        end,
+        break_end: None,
+    }];
+
+    // Destination
+    current = skip_spaces(bytes, label_end + 2);
+    if current == end {
+        let line = next_definition_line(text, end, containers)?;
+        current = line.start;
+        push_definition_line(&mut lines, line);
+    }
+    let destination_end = destination_end(text, current, lines.last()?.end)?;
+
+    // Title
+    let last_line_end = lines.last()?.end;
+    current = skip_spaces(text.as_bytes(), destination_end);
+    if current == last_line_end {
+        // The title is optional, and it can be on the next line
+        let mut with_title = lines.clone();
+        if let Some(line) = next_definition_line(text, last_line_end, containers) {
+            if title_end(text, line.start, line.end) == Some(line.end) {
+                push_definition_line(&mut with_title, line);
+                return Some(LinkReferenceDefinition {
+                    label_end,
+                    lines: with_title,
+                });
+            }
+        }
+
+        if let Some(last) = lines.last_mut() {
+            last.end = destination_end;
+        }
+        return Some(LinkReferenceDefinition { label_end, lines });
+    }
+    if current == destination_end {
+        return None;
+    }
+
+    (title_end(text, current, last_line_end)? == last_line_end)
+        .then_some(LinkReferenceDefinition { label_end, lines })
+}
+
+/// Returns the next line of a link reference definition that ends at `end`, if it belongs to
+/// the same containers and isn't blank
+fn next_definition_line(text: &str, end: usize, containers: &[Container]) -> Option<InlineLine> {
+    let next = next_line_start(text, line_end(text, end))?;
+    let prefix = match_containers(text, next, containers);
+    if prefix.matched < containers.len() || is_blank(text, prefix.end.offset) {
+        return None;
+    }
+
+    let start = first_non_whitespace(text, prefix.end).0.offset;
+    Some(InlineLine {
+        start,
+        end: trimmed_line_end(text, start),
+        break_end: Some(prefix.end.offset),
+    })
+}
+
+/// Appends `line` to the lines of a definition. The line break of the previous line ends
+/// where `line` starts.
+fn push_definition_line(lines: &mut Vec<InlineLine>, mut line: InlineLine) {
+    if let Some(last) = lines.last_mut() {
+        last.break_end = line.break_end;
+    }
+    line.break_end = None;
+    lines.push(line);
+}
+
+/// Returns the end of the link destination that starts at `start`, on a line that ends at `end`
+fn destination_end(text: &str, start: usize, end: usize) -> Option<usize> {
+    let bytes = &text.as_bytes()[..end];
+    let mut current = start;
+    if bytes.get(current) == Some(&b'<') {
+        current += 1;
+        loop {
+            match bytes.get(current)? {
+                b'>' => return Some(current + 1),
+                b'<' => return None,
+                b'\\' => current += 2,
+                _ => current += 1,
+            }
+        }
+    }
+
+    let mut depth = 0usize;
+    while let Some(byte) = bytes.get(current) {
+        match byte {
+            b'\\' => current += 1,
+            b'(' => depth += 1,
+            b')' if depth == 0 => return None,
+            b')' => depth -= 1,
+            b' ' | b'\t' => break,
+            _ => {}
+        }
+        current += 1;
+    }
+
+    (depth == 0 && current > start).then_some(current)
+}
+
+/// Returns the end of the link title that starts at `start`, on a line that ends at `end`
+fn title_end(text: &str, start: usize, end: usize) -> Option<usize> {
+    let bytes = &text.as_bytes()[..end];
+    let closing = match bytes.get(start)? {
+        quote @ (b'"' | b'\'') => *quote,
+        b'(' => b')',
+        _ => return None,
+    };
+
+    let mut current = start + 1;
+    loop {
+        match bytes.get(current)? {
+            b'\\' => current += 2,
+            byte if *byte == closing => return Some(current + 1),
+            _ => current += 1,
+        }
+    }
+}
+
+fn skip_spaces(bytes: &[u8], mut offset: usize) -> usize {
+    while matches!(bytes.get(offset), Some(b' ' | b'\t')) {
+        offset += 1;
+    }
+    offset
+}
+
+fn parse_link_reference_definition(
+    p: &mut MarkdownParser,
+    definition: LinkReferenceDefinition,
+) -> ParsedSyntax {
+    let text = p.text();
+    let m = p.start();
+
+    let start = p.cur_start();
+    p.bump_until(T!['['], start + 1, MarkdownLexContext::Regular);
+
+    let label_start = p.cur_start();
+    let label = InlineLine {
+        start: label_start,
+        end: label_start + text[label_start..definition.label_end].trim_end().len(),
+        break_end: None,
+    };
+    parse_verbatim_item_list(p, &[label], MarkdownLexContext::Regular);
+
+    p.bump_until(
+        T![']'],
+        definition.label_end + 1,
+        MarkdownLexContext::Regular,
+    );
+    p.bump_until(T![:], definition.label_end + 2, MarkdownLexContext::Regular);
+
+    let mut lines = definition.lines;
+    if let Some(first) = lines.first_mut() {
+        // The whitespace after `:` is trivia
+        first.start = p.cur_start();
+        first.end = first.end.max(first.start);
+    }
+    parse_verbatim_item_list(p, &lines, MarkdownLexContext::Regular);
+
+    Present(m.complete(p, MD_LINK_REFERENCE_DEFINITION))
+}
@@ -0,0 +1,31 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::line::{Container, quote_marker};
+use crate::syntax::parse_block_list;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Parses a block quote. Its content continues on the following lines that start with `>`.
+/// https://spec.commonmark.org/0.31.2/#block-quotes
+pub(crate) fn parse_quote(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some((marker, content)) = quote_marker(p.text(), p.state().line) else {
+        return Absent;
+    };
+
+    let m = p.start();
+
+    p.bump_until(T![>], marker + 1, MarkdownLexContext::Regular);
+
+    let state = p.state_mut();
+    state.line = content;
+    state.containers.push(Container::Quote);
+
+    parse_block_list(p);
+
+    p.state_mut().containers.pop();
+
+    Present(m.complete(p, MD_QUOTE))
+}
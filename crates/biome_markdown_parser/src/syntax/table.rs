@@ -0,0 +1,210 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::{InlineLine, parse_inline_item_list};
+use crate::syntax::line::{
+    ContainerPrefix, block_start, first_non_whitespace, is_blank, line_end, match_containers,
+    next_line_start, trimmed_line_end,
+};
+use crate::syntax::next_line_prefix;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use std::ops::Range;
+
+/// A row of a table, split at its unescaped pipes
+#[derive(Debug)]
+struct TableRow {
+    /// The offset of the leading pipe
+    l_pipe: Option<usize>,
+    /// The content of the cells, without the surrounding whitespace
+    cells: Vec<Range<usize>>,
+    /// The offsets of the pipes between the cells
+    separators: Vec<usize>,
+    /// The offset of the trailing pipe
+    r_pipe: Option<usize>,
+}
+
+impl TableRow {
+    fn has_pipe(&self) -> bool {
+        self.l_pipe.is_some() || self.r_pipe.is_some() || !self.separators.is_empty()
+    }
+
+    /// Returns `true` if all the cells are made of `-` with optional `:` at their ends
+    fn is_delimiter_row(&self, text: &str) -> bool {
+        self.cells.iter().all(|cell| {
+            let cell = &text[cell.clone()];
+            let cell = cell.strip_prefix(':').unwrap_or(cell);
+            let cell = cell.strip_suffix(':').unwrap_or(cell);
+            !cell.is_empty() && cell.bytes().all(|byte| byte == b'-')
+        })
+    }
+}
+
+fn split_table_row(text: &str, start: usize) -> TableRow {
+    let end = trimmed_line_end(text, start);
+    let bytes = text.as_bytes();
+
+    let mut pipes = Vec::new();
+    let mut current = start;
+    while current < end {
+        match bytes[current] {
+            b'\\' => current += 1,
+            b'|' => pipes.push(current),
+            _ => {}
+        }
+        current += 1;
+    }
+
+    let l_pipe = pipes.first().copied().filter(|pipe| *pipe == start);
+    let r_pipe = pipes
+        .last()
+        .copied()
+        .filter(|pipe| *pipe + 1 == end && Some(*pipe) != l_pipe);
+
+    let content_start = l_pipe.map_or(start, |pipe| pipe + 1);
+    let content_end = r_pipe.unwrap_or(end);
+    let separators: Vec<_> = pipes
+        .into_iter()
+        .filter(|pipe| Some(*pipe) != l_pipe && Some(*pipe) != r_pipe)
+        .collect();
+
+    let boundaries = std::iter::once(content_start)
+        .chain(separators.iter().map(|pipe| pipe + 1))
+        .zip(
+            separators
+                .iter()
+                .copied()
+                .chain(std::iter::once(content_end)),
+        );
+    let cells = boundaries
+        .map(|(start, end)| {
+            let cell = &text[start..end];
+            let trimmed_start = start + cell.len() - cell.trim_start_matches([' ', '\t']).len();
+            let trimmed_end = start + cell.trim_end_matches([' ', '\t']).len();
+            trimmed_start..trimmed_end.max(trimmed_start)
+        })
+        .collect();
+
+    TableRow {
+        l_pipe,
+        cells,
+        separators,
+        r_pipe,
+    }
+}
+
+/// Returns `true` if a GFM table starts at the current line: a header row followed by a
+/// delimiter row with the same number of cells.
+/// https://github.github.com/gfm/#tables-extension-
+pub(crate) fn at_table(p: &MarkdownParser) -> bool {
+    let text = p.text();
+    let start = p.cur_start();
+
+    let header = split_table_row(text, start);
+    if !header.has_pipe() {
+        return false;
+    }
+
+    let Some(next) = next_line_start(text, line_end(text, start)) else {
+        return false;
+    };
+    let containers = &p.state().containers;
+    let prefix = match_containers(text, next, containers);
+    if prefix.matched < containers.len() {
+        return false;
+    }
+
+    let (delimiter_start, indent) = first_non_whitespace(text, prefix.end);
+    if indent >= 4 || is_blank(text, delimiter_start.offset) {
+        return false;
+    }
+
+    let delimiter = split_table_row(text, delimiter_start.offset);
+    delimiter.has_pipe()
+        && delimiter.is_delimiter_row(text)
+        && delimiter.cells.len() == header.cells.len()
+}
+
+/// Parses a GFM table. The table ends at the first blank line or at the start of another block.
+/// https://github.github.com/gfm/#tables-extension-
+pub(crate) fn parse_table(p: &mut MarkdownParser) -> ParsedSyntax {
+    let text = p.text();
+    let start = p.cur_start();
+    let Some(next) = next_line_start(text, line_end(text, start)) else {
+        return Absent;
+    };
+    let prefix = match_containers(text, next, &p.state().containers);
+
+    let m = p.start();
+
+    let header = split_table_row(text, start);
+    parse_table_row(p, &header, None);
+
+    let delimiter = split_table_row(text, first_non_whitespace(text, prefix.end).0.offset);
+    parse_table_row(p, &delimiter, Some(prefix));
+
+    let rows = p.start();
+    while p.at(NEWLINE) {
+        let Some(prefix) = next_line_prefix(p) else {
+            break;
+        };
+        if prefix.matched < p.state().containers.len()
+            || is_blank(text, prefix.end.offset)
+            || block_start(text, prefix.end, true).is_some()
+        {
+            break;
+        }
+
+        let row = split_table_row(text, first_non_whitespace(text, prefix.end).0.offset);
+        parse_table_row(p, &row, Some(prefix));
+    }
+    rows.complete(p, MD_TABLE_ROW_LIST);
+
+    Present(m.complete(p, MD_TABLE))
+}
+
+fn parse_table_row(p: &mut MarkdownParser, row: &TableRow, line_break: Option<ContainerPrefix>) {
+    let m = p.start();
+
+    if let Some(prefix) = line_break {
+        p.bump_until(NEWLINE, prefix.end.offset, MarkdownLexContext::Regular);
+        p.state_mut().line = prefix.end;
+    }
+
+    if let Some(pipe) = row.l_pipe {
+        p.bump_until(T![|], pipe + 1, MarkdownLexContext::Regular);
+    }
+
+    let cells = p.start();
+    for (index, cell) in row.cells.iter().enumerate() {
+        if index > 0 {
+            p.bump_until(
+                T![|],
+                row.separators[index - 1] + 1,
+                MarkdownLexContext::Regular,
+            );
+        }
+
+        let m = p.start();
+        if cell.is_empty() {
+            p.start().complete(p, MD_INLINE_ITEM_LIST);
+        } else {
+            let line = InlineLine {
+                start: cell.start,
+                end: cell.end,
+                break_end: None,
+            };
+            parse_inline_item_list(p, &[line], MarkdownLexContext::Regular);
+        }
+        m.complete(p, MD_TABLE_CELL);
+    }
+    cells.complete(p, MD_TABLE_CELL_LIST);
+
+    if let Some(pipe) = row.r_pipe {
+        p.bump_until(T![|], pipe + 1, MarkdownLexContext::Regular);
+    }
+
+    m.complete(p, MD_TABLE_ROW);
+}
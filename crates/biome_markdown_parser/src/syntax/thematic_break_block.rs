@@ -1,21 +1,19 @@
+use crate::lexer::MarkdownLexContext;
 use crate::parser::MarkdownParser;
+use crate::syntax::line::trimmed_line_end;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
 use biome_parser::{
     Parser,
     prelude::ParsedSyntax::{self, *},
 };
 
-pub(crate) fn at_thematic_break_block(p: &mut MarkdownParser) -> bool {
-    p.at(MD_THEMATIC_BREAK_LITERAL)
-}
-
+/// Parses a thematic break, a line of three or more `*`, `-` or `_`.
+/// https://spec.commonmark.org/0.31.2/#thematic-breaks
 pub(crate) fn parse_thematic_break_block(p: &mut MarkdownParser) -> ParsedSyntax {
-    if !at_thematic_break_block(p) {
-        return Absent;
-    }
     let m = p.start();
 
-    p.expect(MD_THEMATIC_BREAK_LITERAL);
+    let end = trimmed_line_end(p.text(), p.cur_start());
+    p.bump_until(MD_THEMATIC_BREAK_LITERAL, end, MarkdownLexContext::Regular);
 
     Present(m.complete(p, MD_THEMATIC_BREAK_BLOCK))
 }
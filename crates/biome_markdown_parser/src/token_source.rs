@@ -1,10 +1,10 @@
 use crate::lexer::{MarkdownLexContext, MarkdownLexer, MarkdownReLexContext};
 use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::EOF;
+use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::BufferedLexer;
 use biome_parser::prelude::{BumpWithContext, TokenSource};
 use biome_parser::token_source::{TokenSourceWithBufferedLexer, Trivia};
-use biome_parser::{diagnostic::ParseDiagnostic, token_source::TokenSourceCheckpoint};
 use biome_rowan::{TextRange, TriviaPieceKind};
 
 pub(crate) struct MarkdownTokenSource<'source> {
@@ -14,8 +14,6 @@ pub(crate) struct MarkdownTokenSource<'source> {
     pub(super) trivia_list: Vec<Trivia>,
 }
 
-pub(crate) type MarkdownTokenSourceCheckpoint = TokenSourceCheckpoint<MarkdownSyntaxKind>;
-
 impl<'source> MarkdownTokenSource<'source> {
     /// Creates a new token source.
     pub(crate) fn new(lexer: BufferedLexer<MarkdownSyntaxKind, MarkdownLexer<'source>>) -> Self {
@@ -60,48 +58,15 @@ impl<'source> MarkdownTokenSource<'source> {
         }
     }
 
-    /// Returns the number of whitespace characters before the current token until the first new line.
-    /// tab will be counted as 4 spaces https://spec.commonmark.org/0.31.2/#tabs
-    /// whitespace will be counted as 1 space
-    pub fn before_whitespace_count(&self) -> usize {
-        let last_trivia: Vec<&Trivia> = self
-            .trivia_list
-            .iter()
-            .rev()
-            .take_while(|item| {
-                // get before whitespace and tab collect
-                matches!(
-                    item.kind(),
-                    TriviaPieceKind::Whitespace | TriviaPieceKind::Skipped
-                )
-            })
-            .collect();
-        last_trivia.iter().fold(0, |count, b| match b.kind() {
-            TriviaPieceKind::Skipped => count + 4,
-            TriviaPieceKind::Whitespace => count + u32::from(b.len()) as usize,
-            _ => count,
-        })
+    /// Returns the source text with the lifetime of the source, rather than the one of the token source
+    pub fn source_text(&self) -> &'source str {
+        self.lexer.source()
     }
 
-    #[expect(dead_code)]
+    /// Re-lexes the current token in the given context. See [MarkdownReLexContext].
     pub fn re_lex(&mut self, mode: MarkdownReLexContext) -> MarkdownSyntaxKind {
         self.lexer.re_lex(mode)
     }
-
-    /// Creates a checkpoint to which it can later return using [Self::rewind].
-    pub fn checkpoint(&self) -> MarkdownTokenSourceCheckpoint {
-        MarkdownTokenSourceCheckpoint {
-            trivia_len: self.trivia_list.len() as u32,
-            lexer_checkpoint: self.lexer.checkpoint(),
-        }
-    }
-
-    /// Restores the token source to a previous state
-    pub fn rewind(&mut self, checkpoint: MarkdownTokenSourceCheckpoint) {
-        assert!(self.trivia_list.len() >= checkpoint.trivia_len as usize);
-        self.trivia_list.truncate(checkpoint.trivia_len as usize);
-        self.lexer.rewind(checkpoint.lexer_checkpoint);
-    }
 }
 
 impl TokenSource for MarkdownTokenSource<'_> {
@@ -18,28 +18,28 @@ pub enum MarkdownSyntaxKind {
     R_PAREN,
     L_BRACK,
     R_BRACK,
-    SLASH,
-    EQ,
     BANG,
     MINUS,
+    PLUS,
     STAR,
     DOUBLE_STAR,
-    BACKTICK,
-    TRIPLE_BACKTICK,
     TILDE,
-    WHITESPACE3,
+    DOUBLE_TILDE,
     UNDERSCORE,
     DOUBLE_UNDERSCORE,
     HASH,
-    COMMA,
+    PIPE,
+    COLON,
     NULL_KW,
     MD_HARD_LINE_LITERAL,
     MD_SOFT_BREAK_LITERAL,
     MD_TEXTUAL_LITERAL,
-    MD_STRING_LITERAL,
-    MD_INDENT_CHUNK_LITERAL,
     MD_THEMATIC_BREAK_LITERAL,
-    MD_ERROR_LITERAL,
+    MD_SETEXT_UNDERLINE_LITERAL,
+    MD_CODE_FENCE_LITERAL,
+    MD_BACKTICKS_LITERAL,
+    MD_ORDERED_LIST_MARKER_LITERAL,
+    MD_FRONT_MATTER_LITERAL,
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
@@ -47,40 +47,44 @@ pub enum MarkdownSyntaxKind {
     BOGUS,
     MD_BOGUS,
     MD_DOCUMENT,
+    MD_FRONT_MATTER,
     MD_BLOCK_LIST,
+    MD_NEWLINE,
+    MD_THEMATIC_BREAK_BLOCK,
     MD_HASH_LIST,
     MD_HASH,
     MD_HEADER,
+    MD_SETEXT_HEADER,
     MD_INDENT_CODE_BLOCK,
     MD_FENCED_CODE_BLOCK,
-    MD_CODE_NAME_LIST,
     MD_HTML_BLOCK,
-    MD_LINK_BLOCK,
+    MD_VERBATIM_LINE_LIST,
+    MD_LINK_REFERENCE_DEFINITION,
+    MD_TABLE,
+    MD_TABLE_ROW_LIST,
+    MD_TABLE_ROW,
+    MD_TABLE_CELL_LIST,
+    MD_TABLE_CELL,
     MD_QUOTE,
-    MD_ORDER_LIST_ITEM,
-    MD_BULLET_LIST_ITEM,
     MD_BULLET_LIST,
-    MD_ORDER_LIST,
+    MD_BULLET_LIST_ITEM_LIST,
+    MD_BULLET_LIST_ITEM,
+    MD_ORDERED_LIST,
+    MD_ORDERED_LIST_ITEM_LIST,
+    MD_ORDERED_LIST_ITEM,
     MD_PARAGRAPH,
     MD_INLINE_ITEM_LIST,
     MD_INLINE_EMPHASIS,
     MD_INLINE_ITALIC,
+    MD_INLINE_STRIKETHROUGH,
     MD_INLINE_CODE,
-    MD_BULLET,
     MD_INLINE_LINK,
     MD_INLINE_IMAGE,
-    MD_INLINE_IMAGE_ALT,
-    MD_INDENTED_CODE_LINE,
-    MD_INLINE_IMAGE_LINK,
-    MD_INLINE_IMAGE_SOURCE,
-    MD_INDENTED_CODE_LINE_LIST,
+    MD_INLINE_HTML,
+    MD_AUTOLINK,
     MD_HARD_LINE,
     MD_SOFT_BREAK,
     MD_TEXTUAL,
-    MD_SETEXT_HEADER,
-    MD_STRING,
-    MD_INDENT,
-    MD_THEMATIC_BREAK_BLOCK,
     #[doc(hidden)]
     __LAST,
 }
@@ -95,20 +99,18 @@ impl MarkdownSyntaxKind {
                 | R_PAREN
                 | L_BRACK
                 | R_BRACK
-                | SLASH
-                | EQ
                 | BANG
                 | MINUS
+                | PLUS
                 | STAR
                 | DOUBLE_STAR
-                | BACKTICK
-                | TRIPLE_BACKTICK
                 | TILDE
-                | WHITESPACE3
+                | DOUBLE_TILDE
                 | UNDERSCORE
                 | DOUBLE_UNDERSCORE
                 | HASH
-                | COMMA
+                | PIPE
+                | COLON
         )
     }
     pub const fn is_literal(self) -> bool {
@@ -117,10 +119,12 @@ impl MarkdownSyntaxKind {
             MD_HARD_LINE_LITERAL
                 | MD_SOFT_BREAK_LITERAL
                 | MD_TEXTUAL_LITERAL
-                | MD_STRING_LITERAL
-                | MD_INDENT_CHUNK_LITERAL
                 | MD_THEMATIC_BREAK_LITERAL
-                | MD_ERROR_LITERAL
+                | MD_SETEXT_UNDERLINE_LITERAL
+                | MD_CODE_FENCE_LITERAL
+                | MD_BACKTICKS_LITERAL
+                | MD_ORDERED_LIST_MARKER_LITERAL
+                | MD_FRONT_MATTER_LITERAL
         )
     }
     pub const fn is_list(self) -> bool {
@@ -128,11 +132,14 @@ impl MarkdownSyntaxKind {
             self,
             MD_BLOCK_LIST
                 | MD_HASH_LIST
-                | MD_CODE_NAME_LIST
+                | MD_VERBATIM_LINE_LIST
+                | MD_TABLE_ROW_LIST
+                | MD_TABLE_CELL_LIST
                 | MD_BULLET_LIST
-                | MD_ORDER_LIST
+                | MD_BULLET_LIST_ITEM_LIST
+                | MD_ORDERED_LIST
+                | MD_ORDERED_LIST_ITEM_LIST
                 | MD_INLINE_ITEM_LIST
-                | MD_INDENTED_CODE_LINE_LIST
         )
     }
     pub fn from_keyword(ident: &str) -> Option<Self> {
@@ -150,20 +157,18 @@ impl MarkdownSyntaxKind {
             R_PAREN => ")",
             L_BRACK => "[",
             R_BRACK => "]",
-            SLASH => "/",
-            EQ => "=",
             BANG => "!",
             MINUS => "-",
+            PLUS => "+",
             STAR => "*",
             DOUBLE_STAR => "**",
-            BACKTICK => "`",
-            TRIPLE_BACKTICK => "```",
             TILDE => "~",
-            WHITESPACE3 => "   ",
+            DOUBLE_TILDE => "~~",
             UNDERSCORE => "_",
             DOUBLE_UNDERSCORE => "__",
             HASH => "#",
-            COMMA => ",",
+            PIPE => "|",
+            COLON => ":",
             NULL_KW => "null",
             EOF => "EOF",
             _ => return None,
@@ -173,4 +178,4 @@ impl MarkdownSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [/] => { $ crate :: MarkdownSyntaxKind :: SLASH } ; [=] => { $ crate :: MarkdownSyntaxKind :: EQ } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; ['`'] => { $ crate :: MarkdownSyntaxKind :: BACKTICK } ; ["```"] => { $ crate :: MarkdownSyntaxKind :: TRIPLE_BACKTICK } ; [~] => { $ crate :: MarkdownSyntaxKind :: TILDE } ; ["   "] => { $ crate :: MarkdownSyntaxKind :: WHITESPACE3 } ; ["_"] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; ["__"] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [,] => { $ crate :: MarkdownSyntaxKind :: COMMA } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
+macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [+] => { $ crate :: MarkdownSyntaxKind :: PLUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; [~] => { $ crate :: MarkdownSyntaxKind :: TILDE } ; [~~] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_TILDE } ; ["_"] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; ["__"] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [|] => { $ crate :: MarkdownSyntaxKind :: PIPE } ; [:] => { $ crate :: MarkdownSyntaxKind :: COLON } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
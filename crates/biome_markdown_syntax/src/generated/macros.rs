@@ -16,8 +16,12 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::MarkdownSyntaxNode::kind(&node) {
-                $crate::MarkdownSyntaxKind::MD_BULLET => {
-                    let $pattern = unsafe { $crate::MdBullet::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_AUTOLINK => {
+                    let $pattern = unsafe { $crate::MdAutolink::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_BULLET_LIST => {
+                    let $pattern = unsafe { $crate::MdBulletList::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_BULLET_LIST_ITEM => {
@@ -32,6 +36,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdFencedCodeBlock::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_FRONT_MATTER => {
+                    let $pattern = unsafe { $crate::MdFrontMatter::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_HARD_LINE => {
                     let $pattern = unsafe { $crate::MdHardLine::new_unchecked(node) };
                     $body
@@ -48,18 +56,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdHtmlBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENT => {
-                    let $pattern = unsafe { $crate::MdIndent::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK => {
                     let $pattern = unsafe { $crate::MdIndentCodeBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENTED_CODE_LINE => {
-                    let $pattern = unsafe { $crate::MdIndentedCodeLine::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INLINE_CODE => {
                     let $pattern = unsafe { $crate::MdInlineCode::new_unchecked(node) };
                     $body
@@ -68,36 +68,41 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineEmphasis::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_INLINE_HTML => {
+                    let $pattern = unsafe { $crate::MdInlineHtml::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE => {
                     let $pattern = unsafe { $crate::MdInlineImage::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_ALT => {
-                    let $pattern = unsafe { $crate::MdInlineImageAlt::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_INLINE_ITALIC => {
+                    let $pattern = unsafe { $crate::MdInlineItalic::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_LINK => {
-                    let $pattern = unsafe { $crate::MdInlineImageLink::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_INLINE_LINK => {
+                    let $pattern = unsafe { $crate::MdInlineLink::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_SOURCE => {
-                    let $pattern = unsafe { $crate::MdInlineImageSource::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_INLINE_STRIKETHROUGH => {
+                    let $pattern = unsafe { $crate::MdInlineStrikethrough::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_ITALIC => {
-                    let $pattern = unsafe { $crate::MdInlineItalic::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_LINK_REFERENCE_DEFINITION => {
+                    let $pattern =
+                        unsafe { $crate::MdLinkReferenceDefinition::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_LINK => {
-                    let $pattern = unsafe { $crate::MdInlineLink::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_NEWLINE => {
+                    let $pattern = unsafe { $crate::MdNewline::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_LINK_BLOCK => {
-                    let $pattern = unsafe { $crate::MdLinkBlock::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_ORDERED_LIST => {
+                    let $pattern = unsafe { $crate::MdOrderedList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_ORDER_LIST_ITEM => {
-                    let $pattern = unsafe { $crate::MdOrderListItem::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM => {
+                    let $pattern = unsafe { $crate::MdOrderedListItem::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_PARAGRAPH => {
@@ -116,6 +121,18 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdSoftBreak::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_TABLE => {
+                    let $pattern = unsafe { $crate::MdTable::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL => {
+                    let $pattern = unsafe { $crate::MdTableCell::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW => {
+                    let $pattern = unsafe { $crate::MdTableRow::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_TEXTUAL => {
                     let $pattern = unsafe { $crate::MdTextual::new_unchecked(node) };
                     $body
@@ -132,28 +149,32 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdBlockList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_BULLET_LIST => {
-                    let $pattern = unsafe { $crate::MdBulletList::new_unchecked(node) };
-                    $body
-                }
-                $crate::MarkdownSyntaxKind::MD_CODE_NAME_LIST => {
-                    let $pattern = unsafe { $crate::MdCodeNameList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_BULLET_LIST_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::MdBulletListItemList::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_HASH_LIST => {
                     let $pattern = unsafe { $crate::MdHashList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENTED_CODE_LINE_LIST => {
-                    let $pattern = unsafe { $crate::MdIndentedCodeLineList::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INLINE_ITEM_LIST => {
                     let $pattern = unsafe { $crate::MdInlineItemList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_ORDER_LIST => {
-                    let $pattern = unsafe { $crate::MdOrderList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::MdOrderedListItemList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL_LIST => {
+                    let $pattern = unsafe { $crate::MdTableCellList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW_LIST => {
+                    let $pattern = unsafe { $crate::MdTableRowList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_VERBATIM_LINE_LIST => {
+                    let $pattern = unsafe { $crate::MdVerbatimLineList::new_unchecked(node) };
                     $body
                 }
                 _ => unreachable!(),
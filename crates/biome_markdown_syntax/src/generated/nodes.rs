@@ -21,10 +21,10 @@ use std::fmt::{Debug, Formatter};
 #[doc = r" the slots are not statically known."]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdBullet {
+pub struct MdAutolink {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdBullet {
+impl MdAutolink {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -34,24 +34,24 @@ impl MdBullet {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdBulletFields {
-        MdBulletFields {
-            bullet: self.bullet(),
-            space_token: self.space_token(),
-            content: self.content(),
+    pub fn as_fields(&self) -> MdAutolinkFields {
+        MdAutolinkFields {
+            l_angle_token: self.l_angle_token(),
+            value_token: self.value_token(),
+            r_angle_token: self.r_angle_token(),
         }
     }
-    pub fn bullet(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_angle_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn space_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 2usize)
+    pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdBullet {
+impl Serialize for MdAutolink {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -60,10 +60,45 @@ impl Serialize for MdBullet {
     }
 }
 #[derive(Serialize)]
-pub struct MdBulletFields {
-    pub bullet: SyntaxResult<SyntaxToken>,
-    pub space_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+pub struct MdAutolinkFields {
+    pub l_angle_token: SyntaxResult<SyntaxToken>,
+    pub value_token: SyntaxResult<SyntaxToken>,
+    pub r_angle_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdBulletList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdBulletList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdBulletListFields {
+        MdBulletListFields {
+            items: self.items(),
+        }
+    }
+    pub fn items(&self) -> MdBulletListItemList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdBulletList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdBulletListFields {
+    pub items: MdBulletListItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdBulletListItem {
@@ -81,11 +116,15 @@ impl MdBulletListItem {
     }
     pub fn as_fields(&self) -> MdBulletListItemFields {
         MdBulletListItemFields {
-            md_bullet_list: self.md_bullet_list(),
+            marker: self.marker(),
+            content: self.content(),
         }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
-        support::list(&self.syntax, 0usize)
+    pub fn marker(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
     }
 }
 impl Serialize for MdBulletListItem {
@@ -98,7 +137,8 @@ impl Serialize for MdBulletListItem {
 }
 #[derive(Serialize)]
 pub struct MdBulletListItemFields {
-    pub md_bullet_list: MdBulletList,
+    pub marker: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdDocument {
@@ -117,6 +157,7 @@ impl MdDocument {
     pub fn as_fields(&self) -> MdDocumentFields {
         MdDocumentFields {
             bom_token: self.bom_token(),
+            front_matter: self.front_matter(),
             value: self.value(),
             eof_token: self.eof_token(),
         }
@@ -124,11 +165,14 @@ impl MdDocument {
     pub fn bom_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, 0usize)
     }
+    pub fn front_matter(&self) -> Option<MdFrontMatter> {
+        support::node(&self.syntax, 1usize)
+    }
     pub fn value(&self) -> MdBlockList {
-        support::list(&self.syntax, 1usize)
+        support::list(&self.syntax, 2usize)
     }
     pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+        support::required_token(&self.syntax, 3usize)
     }
 }
 impl Serialize for MdDocument {
@@ -142,6 +186,7 @@ impl Serialize for MdDocument {
 #[derive(Serialize)]
 pub struct MdDocumentFields {
     pub bom_token: Option<SyntaxToken>,
+    pub front_matter: Option<MdFrontMatter>,
     pub value: MdBlockList,
     pub eof_token: SyntaxResult<SyntaxToken>,
 }
@@ -162,30 +207,22 @@ impl MdFencedCodeBlock {
     pub fn as_fields(&self) -> MdFencedCodeBlockFields {
         MdFencedCodeBlockFields {
             l_fence_token: self.l_fence_token(),
-            code_list: self.code_list(),
-            l_hard_line: self.l_hard_line(),
+            info_token: self.info_token(),
             content: self.content(),
-            r_hard_line: self.r_hard_line(),
             r_fence_token: self.r_fence_token(),
         }
     }
     pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn code_list(&self) -> MdCodeNameList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn l_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 2usize)
+    pub fn info_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
     }
-    pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 3usize)
-    }
-    pub fn r_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 4usize)
+    pub fn content(&self) -> MdVerbatimLineList {
+        support::list(&self.syntax, 2usize)
     }
-    pub fn r_fence_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
+    pub fn r_fence_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
 }
 impl Serialize for MdFencedCodeBlock {
@@ -199,11 +236,44 @@ impl Serialize for MdFencedCodeBlock {
 #[derive(Serialize)]
 pub struct MdFencedCodeBlockFields {
     pub l_fence_token: SyntaxResult<SyntaxToken>,
-    pub code_list: MdCodeNameList,
-    pub l_hard_line: SyntaxResult<MdHardLine>,
-    pub content: SyntaxResult<MdTextual>,
-    pub r_hard_line: SyntaxResult<MdHardLine>,
-    pub r_fence_token: SyntaxResult<SyntaxToken>,
+    pub info_token: Option<SyntaxToken>,
+    pub content: MdVerbatimLineList,
+    pub r_fence_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdFrontMatter {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdFrontMatter {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdFrontMatterFields {
+        MdFrontMatterFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdFrontMatter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdFrontMatterFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdHardLine {
@@ -256,10 +326,10 @@ impl MdHash {
     }
     pub fn as_fields(&self) -> MdHashFields {
         MdHashFields {
-            hash_token: self.hash_token(),
+            value_token: self.value_token(),
         }
     }
-    pub fn hash_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
 }
@@ -273,7 +343,7 @@ impl Serialize for MdHash {
 }
 #[derive(Serialize)]
 pub struct MdHashFields {
-    pub hash_token: SyntaxResult<SyntaxToken>,
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdHeader {
@@ -299,8 +369,8 @@ impl MdHeader {
     pub fn before(&self) -> MdHashList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> Option<MdParagraph> {
-        support::node(&self.syntax, 1usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
     }
     pub fn after(&self) -> MdHashList {
         support::list(&self.syntax, 2usize)
@@ -317,7 +387,7 @@ impl Serialize for MdHeader {
 #[derive(Serialize)]
 pub struct MdHeaderFields {
     pub before: MdHashList,
-    pub content: Option<MdParagraph>,
+    pub content: MdInlineItemList,
     pub after: MdHashList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -336,11 +406,11 @@ impl MdHtmlBlock {
     }
     pub fn as_fields(&self) -> MdHtmlBlockFields {
         MdHtmlBlockFields {
-            md_textual: self.md_textual(),
+            content: self.content(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdVerbatimLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdHtmlBlock {
@@ -353,42 +423,7 @@ impl Serialize for MdHtmlBlock {
 }
 #[derive(Serialize)]
 pub struct MdHtmlBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndent {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndent {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentFields {
-        MdIndentFields {
-            value_token: self.value_token(),
-        }
-    }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-}
-impl Serialize for MdIndent {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+    pub content: MdVerbatimLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdIndentCodeBlock {
@@ -406,10 +441,10 @@ impl MdIndentCodeBlock {
     }
     pub fn as_fields(&self) -> MdIndentCodeBlockFields {
         MdIndentCodeBlockFields {
-            lines: self.lines(),
+            content: self.content(),
         }
     }
-    pub fn lines(&self) -> MdIndentedCodeLineList {
+    pub fn content(&self) -> MdVerbatimLineList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -423,47 +458,7 @@ impl Serialize for MdIndentCodeBlock {
 }
 #[derive(Serialize)]
 pub struct MdIndentCodeBlockFields {
-    pub lines: MdIndentedCodeLineList,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndentedCodeLine {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndentedCodeLine {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentedCodeLineFields {
-        MdIndentedCodeLineFields {
-            indentation: self.indentation(),
-            content: self.content(),
-        }
-    }
-    pub fn indentation(&self) -> SyntaxResult<MdIndent> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
-    }
-}
-impl Serialize for MdIndentedCodeLine {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentedCodeLineFields {
-    pub indentation: SyntaxResult<MdIndent>,
-    pub content: SyntaxResult<MdTextual>,
+    pub content: MdVerbatimLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineCode {
@@ -556,6 +551,41 @@ pub struct MdInlineEmphasisFields {
     pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdInlineHtml {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdInlineHtml {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdInlineHtmlFields {
+        MdInlineHtmlFields {
+            value: self.value(),
+        }
+    }
+    pub fn value(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdInlineHtml {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdInlineHtmlFields {
+    pub value: MdInlineItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineImage {
     pub(crate) syntax: SyntaxNode,
 }
@@ -571,31 +601,35 @@ impl MdInlineImage {
     }
     pub fn as_fields(&self) -> MdInlineImageFields {
         MdInlineImageFields {
-            l_brack_token: self.l_brack_token(),
             excl_token: self.excl_token(),
+            l_brack_token: self.l_brack_token(),
             alt: self.alt(),
-            source: self.source(),
             r_brack_token: self.r_brack_token(),
-            link: self.link(),
+            l_paren_token: self.l_paren_token(),
+            source: self.source(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn alt(&self) -> SyntaxResult<MdInlineImageAlt> {
-        support::required_node(&self.syntax, 2usize)
-    }
-    pub fn source(&self) -> SyntaxResult<MdInlineImageSource> {
-        support::required_node(&self.syntax, 3usize)
+    pub fn alt(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
     }
     pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 4usize)
     }
-    pub fn link(&self) -> Option<MdInlineImageLink> {
-        support::node(&self.syntax, 5usize)
+    pub fn source(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 5usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 6usize)
     }
 }
 impl Serialize for MdInlineImage {
@@ -608,18 +642,19 @@ impl Serialize for MdInlineImage {
 }
 #[derive(Serialize)]
 pub struct MdInlineImageFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
     pub excl_token: SyntaxResult<SyntaxToken>,
-    pub alt: SyntaxResult<MdInlineImageAlt>,
-    pub source: SyntaxResult<MdInlineImageSource>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub alt: MdInlineItemList,
     pub r_brack_token: SyntaxResult<SyntaxToken>,
-    pub link: Option<MdInlineImageLink>,
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub source: MdInlineItemList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageAlt {
+pub struct MdInlineItalic {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageAlt {
+impl MdInlineItalic {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -629,24 +664,24 @@ impl MdInlineImageAlt {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageAltFields {
-        MdInlineImageAltFields {
-            l_brack_token: self.l_brack_token(),
+    pub fn as_fields(&self) -> MdInlineItalicFields {
+        MdInlineItalicFields {
+            l_fence: self.l_fence(),
             content: self.content(),
-            r_brack_token: self.r_brack_token(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
     pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdInlineImageAlt {
+impl Serialize for MdInlineItalic {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -655,16 +690,16 @@ impl Serialize for MdInlineImageAlt {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageAltFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
+pub struct MdInlineItalicFields {
+    pub l_fence: SyntaxResult<SyntaxToken>,
     pub content: MdInlineItemList,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageLink {
+pub struct MdInlineLink {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageLink {
+impl MdInlineLink {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -674,24 +709,36 @@ impl MdInlineImageLink {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageLinkFields {
-        MdInlineImageLinkFields {
+    pub fn as_fields(&self) -> MdInlineLinkFields {
+        MdInlineLinkFields {
+            l_brack_token: self.l_brack_token(),
+            text: self.text(),
+            r_brack_token: self.r_brack_token(),
             l_paren_token: self.l_paren_token(),
-            content: self.content(),
+            source: self.source(),
             r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
+    pub fn text(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn source(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 4usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 5usize)
+    }
 }
-impl Serialize for MdInlineImageLink {
+impl Serialize for MdInlineLink {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -700,16 +747,19 @@ impl Serialize for MdInlineImageLink {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageLinkFields {
+pub struct MdInlineLinkFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub text: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
     pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+    pub source: MdInlineItemList,
     pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageSource {
+pub struct MdInlineStrikethrough {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageSource {
+impl MdInlineStrikethrough {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -719,24 +769,24 @@ impl MdInlineImageSource {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageSourceFields {
-        MdInlineImageSourceFields {
-            l_paren_token: self.l_paren_token(),
+    pub fn as_fields(&self) -> MdInlineStrikethroughFields {
+        MdInlineStrikethroughFields {
+            l_fence: self.l_fence(),
             content: self.content(),
-            r_paren_token: self.r_paren_token(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
     pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdInlineImageSource {
+impl Serialize for MdInlineStrikethrough {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -745,16 +795,16 @@ impl Serialize for MdInlineImageSource {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageSourceFields {
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
+pub struct MdInlineStrikethroughFields {
+    pub l_fence: SyntaxResult<SyntaxToken>,
     pub content: MdInlineItemList,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineItalic {
+pub struct MdLinkReferenceDefinition {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineItalic {
+impl MdLinkReferenceDefinition {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -764,24 +814,32 @@ impl MdInlineItalic {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineItalicFields {
-        MdInlineItalicFields {
-            l_fence: self.l_fence(),
-            content: self.content(),
-            r_fence: self.r_fence(),
+    pub fn as_fields(&self) -> MdLinkReferenceDefinitionFields {
+        MdLinkReferenceDefinitionFields {
+            l_brack_token: self.l_brack_token(),
+            label: self.label(),
+            r_brack_token: self.r_brack_token(),
+            colon_token: self.colon_token(),
+            destination: self.destination(),
         }
     }
-    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
+    pub fn label(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn destination(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 4usize)
+    }
 }
-impl Serialize for MdInlineItalic {
+impl Serialize for MdLinkReferenceDefinition {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -790,16 +848,18 @@ impl Serialize for MdInlineItalic {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineItalicFields {
-    pub l_fence: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
-    pub r_fence: SyntaxResult<SyntaxToken>,
+pub struct MdLinkReferenceDefinitionFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub destination: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineLink {
+pub struct MdNewline {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineLink {
+impl MdNewline {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -809,36 +869,16 @@ impl MdInlineLink {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineLinkFields {
-        MdInlineLinkFields {
-            l_brack_token: self.l_brack_token(),
-            text: self.text(),
-            r_brack_token: self.r_brack_token(),
-            l_paren_token: self.l_paren_token(),
-            source: self.source(),
-            r_paren_token: self.r_paren_token(),
+    pub fn as_fields(&self) -> MdNewlineFields {
+        MdNewlineFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn text(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
-    pub fn source(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 4usize)
-    }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
-    }
 }
-impl Serialize for MdInlineLink {
+impl Serialize for MdNewline {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -847,19 +887,14 @@ impl Serialize for MdInlineLink {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineLinkFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub text: MdInlineItemList,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub source: MdInlineItemList,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
+pub struct MdNewlineFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdLinkBlock {
+pub struct MdOrderedList {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdLinkBlock {
+impl MdOrderedList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -869,24 +904,16 @@ impl MdLinkBlock {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdLinkBlockFields {
-        MdLinkBlockFields {
-            label: self.label(),
-            url: self.url(),
-            title: self.title(),
+    pub fn as_fields(&self) -> MdOrderedListFields {
+        MdOrderedListFields {
+            items: self.items(),
         }
     }
-    pub fn label(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn url(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
-    }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn items(&self) -> MdOrderedListItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdLinkBlock {
+impl Serialize for MdOrderedList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -895,16 +922,14 @@ impl Serialize for MdLinkBlock {
     }
 }
 #[derive(Serialize)]
-pub struct MdLinkBlockFields {
-    pub label: SyntaxResult<MdTextual>,
-    pub url: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+pub struct MdOrderedListFields {
+    pub items: MdOrderedListItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdOrderListItem {
+pub struct MdOrderedListItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdOrderListItem {
+impl MdOrderedListItem {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -914,16 +939,20 @@ impl MdOrderListItem {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdOrderListItemFields {
-        MdOrderListItemFields {
-            md_bullet_list: self.md_bullet_list(),
+    pub fn as_fields(&self) -> MdOrderedListItemFields {
+        MdOrderedListItemFields {
+            marker_token: self.marker_token(),
+            content: self.content(),
         }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
-        support::list(&self.syntax, 0usize)
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
     }
 }
-impl Serialize for MdOrderListItem {
+impl Serialize for MdOrderedListItem {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -932,8 +961,9 @@ impl Serialize for MdOrderListItem {
     }
 }
 #[derive(Serialize)]
-pub struct MdOrderListItemFields {
-    pub md_bullet_list: MdBulletList,
+pub struct MdOrderedListItemFields {
+    pub marker_token: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdParagraph {
@@ -951,16 +981,12 @@ impl MdParagraph {
     }
     pub fn as_fields(&self) -> MdParagraphFields {
         MdParagraphFields {
-            list: self.list(),
-            hard_line: self.hard_line(),
+            content: self.content(),
         }
     }
-    pub fn list(&self) -> MdInlineItemList {
+    pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 1usize)
-    }
 }
 impl Serialize for MdParagraph {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -972,8 +998,7 @@ impl Serialize for MdParagraph {
 }
 #[derive(Serialize)]
 pub struct MdParagraphFields {
-    pub list: MdInlineItemList,
-    pub hard_line: SyntaxResult<MdHardLine>,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdQuote {
@@ -991,11 +1016,15 @@ impl MdQuote {
     }
     pub fn as_fields(&self) -> MdQuoteFields {
         MdQuoteFields {
-            any_md_block: self.any_md_block(),
+            marker_token: self.marker_token(),
+            content: self.content(),
         }
     }
-    pub fn any_md_block(&self) -> SyntaxResult<AnyMdBlock> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
     }
 }
 impl Serialize for MdQuote {
@@ -1008,7 +1037,8 @@ impl Serialize for MdQuote {
 }
 #[derive(Serialize)]
 pub struct MdQuoteFields {
-    pub any_md_block: SyntaxResult<AnyMdBlock>,
+    pub marker_token: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdSetextHeader {
@@ -1026,11 +1056,19 @@ impl MdSetextHeader {
     }
     pub fn as_fields(&self) -> MdSetextHeaderFields {
         MdSetextHeaderFields {
-            md_paragraph: self.md_paragraph(),
+            content: self.content(),
+            line_break_token: self.line_break_token(),
+            underline_token: self.underline_token(),
         }
     }
-    pub fn md_paragraph(&self) -> SyntaxResult<MdParagraph> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn line_break_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn underline_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
 impl Serialize for MdSetextHeader {
@@ -1043,7 +1081,9 @@ impl Serialize for MdSetextHeader {
 }
 #[derive(Serialize)]
 pub struct MdSetextHeaderFields {
-    pub md_paragraph: SyntaxResult<MdParagraph>,
+    pub content: MdInlineItemList,
+    pub line_break_token: SyntaxResult<SyntaxToken>,
+    pub underline_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdSoftBreak {
@@ -1081,6 +1121,136 @@ pub struct MdSoftBreakFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableFields {
+        MdTableFields {
+            header: self.header(),
+            delimiter: self.delimiter(),
+            rows: self.rows(),
+        }
+    }
+    pub fn header(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn delimiter(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn rows(&self) -> MdTableRowList {
+        support::list(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableFields {
+    pub header: SyntaxResult<MdTableRow>,
+    pub delimiter: SyntaxResult<MdTableRow>,
+    pub rows: MdTableRowList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableCell {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableCell {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableCellFields {
+        MdTableCellFields {
+            content: self.content(),
+        }
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdTableCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableCellFields {
+    pub content: MdInlineItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableRow {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableRow {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableRowFields {
+        MdTableRowFields {
+            line_break_token: self.line_break_token(),
+            l_pipe_token: self.l_pipe_token(),
+            cells: self.cells(),
+            r_pipe_token: self.r_pipe_token(),
+        }
+    }
+    pub fn line_break_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn l_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn cells(&self) -> MdTableCellList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn r_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
+    }
+}
+impl Serialize for MdTableRow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableRowFields {
+    pub line_break_token: Option<SyntaxToken>,
+    pub l_pipe_token: Option<SyntaxToken>,
+    pub cells: MdTableCellList,
+    pub r_pipe_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdTextual {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1151,11 +1321,30 @@ pub struct MdThematicBreakBlockFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCodeBlock {
+pub enum AnyMdBlock {
+    AnyMdContainerBlock(AnyMdContainerBlock),
+    AnyMdLeafBlock(AnyMdLeafBlock),
+}
+impl AnyMdBlock {
+    pub fn as_any_md_container_block(&self) -> Option<&AnyMdContainerBlock> {
+        match &self {
+            Self::AnyMdContainerBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_any_md_leaf_block(&self) -> Option<&AnyMdLeafBlock> {
+        match &self {
+            Self::AnyMdLeafBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdCodeBlock {
     MdFencedCodeBlock(MdFencedCodeBlock),
     MdIndentCodeBlock(MdIndentCodeBlock),
 }
-impl AnyCodeBlock {
+impl AnyMdCodeBlock {
     pub fn as_md_fenced_code_block(&self) -> Option<&MdFencedCodeBlock> {
         match &self {
             Self::MdFencedCodeBlock(item) => Some(item),
@@ -1170,21 +1359,21 @@ impl AnyCodeBlock {
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyContainerBlock {
-    MdBulletListItem(MdBulletListItem),
-    MdOrderListItem(MdOrderListItem),
+pub enum AnyMdContainerBlock {
+    MdBulletList(MdBulletList),
+    MdOrderedList(MdOrderedList),
     MdQuote(MdQuote),
 }
-impl AnyContainerBlock {
-    pub fn as_md_bullet_list_item(&self) -> Option<&MdBulletListItem> {
+impl AnyMdContainerBlock {
+    pub fn as_md_bullet_list(&self) -> Option<&MdBulletList> {
         match &self {
-            Self::MdBulletListItem(item) => Some(item),
+            Self::MdBulletList(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_order_list_item(&self) -> Option<&MdOrderListItem> {
+    pub fn as_md_ordered_list(&self) -> Option<&MdOrderedList> {
         match &self {
-            Self::MdOrderListItem(item) => Some(item),
+            Self::MdOrderedList(item) => Some(item),
             _ => None,
         }
     }
@@ -1196,94 +1385,109 @@ impl AnyContainerBlock {
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyLeafBlock {
-    AnyCodeBlock(AnyCodeBlock),
-    MdHeader(MdHeader),
-    MdHtmlBlock(MdHtmlBlock),
-    MdLinkBlock(MdLinkBlock),
-    MdParagraph(MdParagraph),
-    MdSetextHeader(MdSetextHeader),
-    MdThematicBreakBlock(MdThematicBreakBlock),
+pub enum AnyMdInline {
+    MdAutolink(MdAutolink),
+    MdHardLine(MdHardLine),
+    MdInlineCode(MdInlineCode),
+    MdInlineEmphasis(MdInlineEmphasis),
+    MdInlineHtml(MdInlineHtml),
+    MdInlineImage(MdInlineImage),
+    MdInlineItalic(MdInlineItalic),
+    MdInlineLink(MdInlineLink),
+    MdInlineStrikethrough(MdInlineStrikethrough),
+    MdSoftBreak(MdSoftBreak),
+    MdTextual(MdTextual),
 }
-impl AnyLeafBlock {
-    pub fn as_any_code_block(&self) -> Option<&AnyCodeBlock> {
+impl AnyMdInline {
+    pub fn as_md_autolink(&self) -> Option<&MdAutolink> {
         match &self {
-            Self::AnyCodeBlock(item) => Some(item),
+            Self::MdAutolink(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_header(&self) -> Option<&MdHeader> {
+    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
         match &self {
-            Self::MdHeader(item) => Some(item),
+            Self::MdHardLine(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
+    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
         match &self {
-            Self::MdHtmlBlock(item) => Some(item),
+            Self::MdInlineCode(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_link_block(&self) -> Option<&MdLinkBlock> {
+    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
         match &self {
-            Self::MdLinkBlock(item) => Some(item),
+            Self::MdInlineEmphasis(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
+    pub fn as_md_inline_html(&self) -> Option<&MdInlineHtml> {
         match &self {
-            Self::MdParagraph(item) => Some(item),
+            Self::MdInlineHtml(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
+    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
         match &self {
-            Self::MdSetextHeader(item) => Some(item),
+            Self::MdInlineImage(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
+    pub fn as_md_inline_italic(&self) -> Option<&MdInlineItalic> {
         match &self {
-            Self::MdThematicBreakBlock(item) => Some(item),
+            Self::MdInlineItalic(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdBlock {
-    AnyContainerBlock(AnyContainerBlock),
-    AnyLeafBlock(AnyLeafBlock),
-}
-impl AnyMdBlock {
-    pub fn as_any_container_block(&self) -> Option<&AnyContainerBlock> {
+    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
+        match &self {
+            Self::MdInlineLink(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_strikethrough(&self) -> Option<&MdInlineStrikethrough> {
+        match &self {
+            Self::MdInlineStrikethrough(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
         match &self {
-            Self::AnyContainerBlock(item) => Some(item),
+            Self::MdSoftBreak(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_any_leaf_block(&self) -> Option<&AnyLeafBlock> {
+    pub fn as_md_textual(&self) -> Option<&MdTextual> {
         match &self {
-            Self::AnyLeafBlock(item) => Some(item),
+            Self::MdTextual(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdInline {
-    MdHardLine(MdHardLine),
+pub enum AnyMdLeafBlock {
+    AnyMdCodeBlock(AnyMdCodeBlock),
+    MdHeader(MdHeader),
     MdHtmlBlock(MdHtmlBlock),
-    MdInlineCode(MdInlineCode),
-    MdInlineEmphasis(MdInlineEmphasis),
-    MdInlineImage(MdInlineImage),
-    MdInlineItalic(MdInlineItalic),
-    MdInlineLink(MdInlineLink),
-    MdSoftBreak(MdSoftBreak),
-    MdTextual(MdTextual),
+    MdLinkReferenceDefinition(MdLinkReferenceDefinition),
+    MdNewline(MdNewline),
+    MdParagraph(MdParagraph),
+    MdSetextHeader(MdSetextHeader),
+    MdTable(MdTable),
+    MdThematicBreakBlock(MdThematicBreakBlock),
 }
-impl AnyMdInline {
-    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
+impl AnyMdLeafBlock {
+    pub fn as_any_md_code_block(&self) -> Option<&AnyMdCodeBlock> {
         match &self {
-            Self::MdHardLine(item) => Some(item),
+            Self::AnyMdCodeBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_header(&self) -> Option<&MdHeader> {
+        match &self {
+            Self::MdHeader(item) => Some(item),
             _ => None,
         }
     }
@@ -1293,39 +1497,52 @@ impl AnyMdInline {
             _ => None,
         }
     }
-    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
+    pub fn as_md_link_reference_definition(&self) -> Option<&MdLinkReferenceDefinition> {
         match &self {
-            Self::MdInlineCode(item) => Some(item),
+            Self::MdLinkReferenceDefinition(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
+    pub fn as_md_newline(&self) -> Option<&MdNewline> {
         match &self {
-            Self::MdInlineEmphasis(item) => Some(item),
+            Self::MdNewline(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
+    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
         match &self {
-            Self::MdInlineImage(item) => Some(item),
+            Self::MdParagraph(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_italic(&self) -> Option<&MdInlineItalic> {
+    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
         match &self {
-            Self::MdInlineItalic(item) => Some(item),
+            Self::MdSetextHeader(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
+    pub fn as_md_table(&self) -> Option<&MdTable> {
         match &self {
-            Self::MdInlineLink(item) => Some(item),
+            Self::MdTable(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
+    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
         match &self {
-            Self::MdSoftBreak(item) => Some(item),
+            Self::MdThematicBreakBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdVerbatimLine {
+    MdNewline(MdNewline),
+    MdTextual(MdTextual),
+}
+impl AnyMdVerbatimLine {
+    pub fn as_md_newline(&self) -> Option<&MdNewline> {
+        match &self {
+            Self::MdNewline(item) => Some(item),
             _ => None,
         }
     }
@@ -1336,12 +1553,12 @@ impl AnyMdInline {
         }
     }
 }
-impl AstNode for MdBullet {
+impl AstNode for MdAutolink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_AUTOLINK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_BULLET
+        kind == MD_AUTOLINK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1357,34 +1574,87 @@ impl AstNode for MdBullet {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdBullet {
+impl std::fmt::Debug for MdAutolink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdBullet")
-                .field("bullet", &support::DebugSyntaxResult(self.bullet()))
+            f.debug_struct("MdAutolink")
                 .field(
-                    "space_token",
-                    &support::DebugSyntaxResult(self.space_token()),
+                    "l_angle_token",
+                    &support::DebugSyntaxResult(self.l_angle_token()),
                 )
-                .field("content", &self.content())
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .field(
+                    "r_angle_token",
+                    &support::DebugSyntaxResult(self.r_angle_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdAutolink").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdAutolink> for SyntaxNode {
+    fn from(n: MdAutolink) -> Self {
+        n.syntax
+    }
+}
+impl From<MdAutolink> for SyntaxElement {
+    fn from(n: MdAutolink) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdBulletList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_BULLET_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdBulletList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdBulletList")
+                .field("items", &self.items())
                 .finish()
         } else {
-            f.debug_struct("MdBullet").finish()
+            f.debug_struct("MdBulletList").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdBullet> for SyntaxNode {
-    fn from(n: MdBullet) -> Self {
+impl From<MdBulletList> for SyntaxNode {
+    fn from(n: MdBulletList) -> Self {
         n.syntax
     }
 }
-impl From<MdBullet> for SyntaxElement {
-    fn from(n: MdBullet) -> Self {
+impl From<MdBulletList> for SyntaxElement {
+    fn from(n: MdBulletList) -> Self {
         n.syntax.into()
     }
 }
@@ -1416,7 +1686,8 @@ impl std::fmt::Debug for MdBulletListItem {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdBulletListItem")
-                .field("md_bullet_list", &self.md_bullet_list())
+                .field("marker", &support::DebugSyntaxResult(self.marker()))
+                .field("content", &self.content())
                 .finish()
         } else {
             f.debug_struct("MdBulletListItem").finish()
@@ -1467,6 +1738,10 @@ impl std::fmt::Debug for MdDocument {
                     "bom_token",
                     &support::DebugOptionalElement(self.bom_token()),
                 )
+                .field(
+                    "front_matter",
+                    &support::DebugOptionalElement(self.front_matter()),
+                )
                 .field("value", &self.value())
                 .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
                 .finish()
@@ -1519,19 +1794,14 @@ impl std::fmt::Debug for MdFencedCodeBlock {
                     "l_fence_token",
                     &support::DebugSyntaxResult(self.l_fence_token()),
                 )
-                .field("code_list", &self.code_list())
                 .field(
-                    "l_hard_line",
-                    &support::DebugSyntaxResult(self.l_hard_line()),
-                )
-                .field("content", &support::DebugSyntaxResult(self.content()))
-                .field(
-                    "r_hard_line",
-                    &support::DebugSyntaxResult(self.r_hard_line()),
+                    "info_token",
+                    &support::DebugOptionalElement(self.info_token()),
                 )
+                .field("content", &self.content())
                 .field(
                     "r_fence_token",
-                    &support::DebugSyntaxResult(self.r_fence_token()),
+                    &support::DebugOptionalElement(self.r_fence_token()),
                 )
                 .finish()
         } else {
@@ -1551,6 +1821,56 @@ impl From<MdFencedCodeBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for MdFrontMatter {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_FRONT_MATTER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_FRONT_MATTER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdFrontMatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdFrontMatter")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdFrontMatter").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdFrontMatter> for SyntaxNode {
+    fn from(n: MdFrontMatter) -> Self {
+        n.syntax
+    }
+}
+impl From<MdFrontMatter> for SyntaxElement {
+    fn from(n: MdFrontMatter) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for MdHardLine {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1629,7 +1949,10 @@ impl std::fmt::Debug for MdHash {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdHash")
-                .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
                 .finish()
         } else {
             f.debug_struct("MdHash").finish()
@@ -1677,7 +2000,7 @@ impl std::fmt::Debug for MdHeader {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdHeader")
                 .field("before", &self.before())
-                .field("content", &support::DebugOptionalElement(self.content()))
+                .field("content", &self.content())
                 .field("after", &self.after())
                 .finish()
         } else {
@@ -1725,7 +2048,7 @@ impl std::fmt::Debug for MdHtmlBlock {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdHtmlBlock")
-                .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+                .field("content", &self.content())
                 .finish()
         } else {
             f.debug_struct("MdHtmlBlock").finish()
@@ -1744,56 +2067,6 @@ impl From<MdHtmlBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndent {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
-    }
-}
-impl std::fmt::Debug for MdIndent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
-        let current_depth = DEPTH.get();
-        let result = if current_depth < 16 {
-            DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndent")
-                .field(
-                    "value_token",
-                    &support::DebugSyntaxResult(self.value_token()),
-                )
-                .finish()
-        } else {
-            f.debug_struct("MdIndent").finish()
-        };
-        DEPTH.set(current_depth);
-        result
-    }
-}
-impl From<MdIndent> for SyntaxNode {
-    fn from(n: MdIndent) -> Self {
-        n.syntax
-    }
-}
-impl From<MdIndent> for SyntaxElement {
-    fn from(n: MdIndent) -> Self {
-        n.syntax.into()
-    }
-}
 impl AstNode for MdIndentCodeBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1822,7 +2095,7 @@ impl std::fmt::Debug for MdIndentCodeBlock {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdIndentCodeBlock")
-                .field("lines", &self.lines())
+                .field("content", &self.content())
                 .finish()
         } else {
             f.debug_struct("MdIndentCodeBlock").finish()
@@ -1841,57 +2114,6 @@ impl From<MdIndentCodeBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndentedCodeLine {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENTED_CODE_LINE
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
-    }
-}
-impl std::fmt::Debug for MdIndentedCodeLine {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
-        let current_depth = DEPTH.get();
-        let result = if current_depth < 16 {
-            DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndentedCodeLine")
-                .field(
-                    "indentation",
-                    &support::DebugSyntaxResult(self.indentation()),
-                )
-                .field("content", &support::DebugSyntaxResult(self.content()))
-                .finish()
-        } else {
-            f.debug_struct("MdIndentedCodeLine").finish()
-        };
-        DEPTH.set(current_depth);
-        result
-    }
-}
-impl From<MdIndentedCodeLine> for SyntaxNode {
-    fn from(n: MdIndentedCodeLine) -> Self {
-        n.syntax
-    }
-}
-impl From<MdIndentedCodeLine> for SyntaxElement {
-    fn from(n: MdIndentedCodeLine) -> Self {
-        n.syntax.into()
-    }
-}
 impl AstNode for MdInlineCode {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1996,12 +2218,12 @@ impl From<MdInlineEmphasis> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImage {
+impl AstNode for MdInlineHtml {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_HTML as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE
+        kind == MD_INLINE_HTML
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2017,49 +2239,38 @@ impl AstNode for MdInlineImage {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImage {
+impl std::fmt::Debug for MdInlineHtml {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImage")
-                .field(
-                    "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
-                )
-                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
-                .field("alt", &support::DebugSyntaxResult(self.alt()))
-                .field("source", &support::DebugSyntaxResult(self.source()))
-                .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
-                )
-                .field("link", &support::DebugOptionalElement(self.link()))
+            f.debug_struct("MdInlineHtml")
+                .field("value", &self.value())
                 .finish()
         } else {
-            f.debug_struct("MdInlineImage").finish()
+            f.debug_struct("MdInlineHtml").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImage> for SyntaxNode {
-    fn from(n: MdInlineImage) -> Self {
+impl From<MdInlineHtml> for SyntaxNode {
+    fn from(n: MdInlineHtml) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImage> for SyntaxElement {
-    fn from(n: MdInlineImage) -> Self {
+impl From<MdInlineHtml> for SyntaxElement {
+    fn from(n: MdInlineHtml) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageAlt {
+impl AstNode for MdInlineImage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_ALT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_ALT
+        kind == MD_INLINE_IMAGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2075,46 +2286,56 @@ impl AstNode for MdInlineImageAlt {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageAlt {
+impl std::fmt::Debug for MdInlineImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageAlt")
+            f.debug_struct("MdInlineImage")
+                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
                 .field(
                     "l_brack_token",
                     &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("content", &self.content())
+                .field("alt", &self.alt())
                 .field(
                     "r_brack_token",
                     &support::DebugSyntaxResult(self.r_brack_token()),
                 )
-                .finish()
-        } else {
-            f.debug_struct("MdInlineImageAlt").finish()
-        };
-        DEPTH.set(current_depth);
-        result
+                .field(
+                    "l_paren_token",
+                    &support::DebugSyntaxResult(self.l_paren_token()),
+                )
+                .field("source", &self.source())
+                .field(
+                    "r_paren_token",
+                    &support::DebugSyntaxResult(self.r_paren_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdInlineImage").finish()
+        };
+        DEPTH.set(current_depth);
+        result
     }
 }
-impl From<MdInlineImageAlt> for SyntaxNode {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdInlineImage> for SyntaxNode {
+    fn from(n: MdInlineImage) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageAlt> for SyntaxElement {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdInlineImage> for SyntaxElement {
+    fn from(n: MdInlineImage) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageLink {
+impl AstNode for MdInlineItalic {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_LINK
+        kind == MD_INLINE_ITALIC
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2130,46 +2351,40 @@ impl AstNode for MdInlineImageLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageLink {
+impl std::fmt::Debug for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageLink")
-                .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
-                )
+            f.debug_struct("MdInlineItalic")
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
                 .field("content", &self.content())
-                .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
-                )
+                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageLink").finish()
+            f.debug_struct("MdInlineItalic").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageLink> for SyntaxNode {
-    fn from(n: MdInlineImageLink) -> Self {
+impl From<MdInlineItalic> for SyntaxNode {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageLink> for SyntaxElement {
-    fn from(n: MdInlineImageLink) -> Self {
+impl From<MdInlineItalic> for SyntaxElement {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageSource {
+impl AstNode for MdInlineLink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_SOURCE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_SOURCE
+        kind == MD_INLINE_LINK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2185,46 +2400,55 @@ impl AstNode for MdInlineImageSource {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageSource {
+impl std::fmt::Debug for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageSource")
+            f.debug_struct("MdInlineLink")
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("text", &self.text())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
                 .field(
                     "l_paren_token",
                     &support::DebugSyntaxResult(self.l_paren_token()),
                 )
-                .field("content", &self.content())
+                .field("source", &self.source())
                 .field(
                     "r_paren_token",
                     &support::DebugSyntaxResult(self.r_paren_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageSource").finish()
+            f.debug_struct("MdInlineLink").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageSource> for SyntaxNode {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdInlineLink> for SyntaxNode {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageSource> for SyntaxElement {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdInlineLink> for SyntaxElement {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineItalic {
+impl AstNode for MdInlineStrikethrough {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_STRIKETHROUGH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITALIC
+        kind == MD_INLINE_STRIKETHROUGH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2240,40 +2464,40 @@ impl AstNode for MdInlineItalic {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineItalic {
+impl std::fmt::Debug for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineItalic")
+            f.debug_struct("MdInlineStrikethrough")
                 .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
                 .field("content", &self.content())
                 .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineItalic").finish()
+            f.debug_struct("MdInlineStrikethrough").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineItalic> for SyntaxNode {
-    fn from(n: MdInlineItalic) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxNode {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineItalic> for SyntaxElement {
-    fn from(n: MdInlineItalic) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxElement {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineLink {
+impl AstNode for MdLinkReferenceDefinition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_REFERENCE_DEFINITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_LINK
+        kind == MD_LINK_REFERENCE_DEFINITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2289,55 +2513,101 @@ impl AstNode for MdInlineLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineLink {
+impl std::fmt::Debug for MdLinkReferenceDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineLink")
+            f.debug_struct("MdLinkReferenceDefinition")
                 .field(
                     "l_brack_token",
                     &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("text", &self.text())
+                .field("label", &self.label())
                 .field(
                     "r_brack_token",
                     &support::DebugSyntaxResult(self.r_brack_token()),
                 )
                 .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
+                    "colon_token",
+                    &support::DebugSyntaxResult(self.colon_token()),
                 )
-                .field("source", &self.source())
+                .field("destination", &self.destination())
+                .finish()
+        } else {
+            f.debug_struct("MdLinkReferenceDefinition").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdLinkReferenceDefinition> for SyntaxNode {
+    fn from(n: MdLinkReferenceDefinition) -> Self {
+        n.syntax
+    }
+}
+impl From<MdLinkReferenceDefinition> for SyntaxElement {
+    fn from(n: MdLinkReferenceDefinition) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdNewline {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_NEWLINE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_NEWLINE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdNewline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdNewline")
                 .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdInlineLink").finish()
+            f.debug_struct("MdNewline").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineLink> for SyntaxNode {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdNewline> for SyntaxNode {
+    fn from(n: MdNewline) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineLink> for SyntaxElement {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdNewline> for SyntaxElement {
+    fn from(n: MdNewline) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdLinkBlock {
+impl AstNode for MdOrderedList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDERED_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_LINK_BLOCK
+        kind == MD_ORDERED_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2353,40 +2623,38 @@ impl AstNode for MdLinkBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdLinkBlock {
+impl std::fmt::Debug for MdOrderedList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdLinkBlock")
-                .field("label", &support::DebugSyntaxResult(self.label()))
-                .field("url", &support::DebugSyntaxResult(self.url()))
-                .field("title", &support::DebugOptionalElement(self.title()))
+            f.debug_struct("MdOrderedList")
+                .field("items", &self.items())
                 .finish()
         } else {
-            f.debug_struct("MdLinkBlock").finish()
+            f.debug_struct("MdOrderedList").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdLinkBlock> for SyntaxNode {
-    fn from(n: MdLinkBlock) -> Self {
+impl From<MdOrderedList> for SyntaxNode {
+    fn from(n: MdOrderedList) -> Self {
         n.syntax
     }
 }
-impl From<MdLinkBlock> for SyntaxElement {
-    fn from(n: MdLinkBlock) -> Self {
+impl From<MdOrderedList> for SyntaxElement {
+    fn from(n: MdOrderedList) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdOrderListItem {
+impl AstNode for MdOrderedListItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDERED_LIST_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST_ITEM
+        kind == MD_ORDERED_LIST_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2402,29 +2670,33 @@ impl AstNode for MdOrderListItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdOrderListItem {
+impl std::fmt::Debug for MdOrderedListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdOrderListItem")
-                .field("md_bullet_list", &self.md_bullet_list())
+            f.debug_struct("MdOrderedListItem")
+                .field(
+                    "marker_token",
+                    &support::DebugSyntaxResult(self.marker_token()),
+                )
+                .field("content", &self.content())
                 .finish()
         } else {
-            f.debug_struct("MdOrderListItem").finish()
+            f.debug_struct("MdOrderedListItem").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdOrderListItem> for SyntaxNode {
-    fn from(n: MdOrderListItem) -> Self {
+impl From<MdOrderedListItem> for SyntaxNode {
+    fn from(n: MdOrderedListItem) -> Self {
         n.syntax
     }
 }
-impl From<MdOrderListItem> for SyntaxElement {
-    fn from(n: MdOrderListItem) -> Self {
+impl From<MdOrderedListItem> for SyntaxElement {
+    fn from(n: MdOrderedListItem) -> Self {
         n.syntax.into()
     }
 }
@@ -2456,8 +2728,7 @@ impl std::fmt::Debug for MdParagraph {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdParagraph")
-                .field("list", &self.list())
-                .field("hard_line", &support::DebugSyntaxResult(self.hard_line()))
+                .field("content", &self.content())
                 .finish()
         } else {
             f.debug_struct("MdParagraph").finish()
@@ -2505,9 +2776,10 @@ impl std::fmt::Debug for MdQuote {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdQuote")
                 .field(
-                    "any_md_block",
-                    &support::DebugSyntaxResult(self.any_md_block()),
+                    "marker_token",
+                    &support::DebugSyntaxResult(self.marker_token()),
                 )
+                .field("content", &self.content())
                 .finish()
         } else {
             f.debug_struct("MdQuote").finish()
@@ -2554,9 +2826,14 @@ impl std::fmt::Debug for MdSetextHeader {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdSetextHeader")
+                .field("content", &self.content())
+                .field(
+                    "line_break_token",
+                    &support::DebugSyntaxResult(self.line_break_token()),
+                )
                 .field(
-                    "md_paragraph",
-                    &support::DebugSyntaxResult(self.md_paragraph()),
+                    "underline_token",
+                    &support::DebugSyntaxResult(self.underline_token()),
                 )
                 .finish()
         } else {
@@ -2626,6 +2903,161 @@ impl From<MdSoftBreak> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for MdTable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdTable")
+                .field("header", &support::DebugSyntaxResult(self.header()))
+                .field("delimiter", &support::DebugSyntaxResult(self.delimiter()))
+                .field("rows", &self.rows())
+                .finish()
+        } else {
+            f.debug_struct("MdTable").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdTable> for SyntaxNode {
+    fn from(n: MdTable) -> Self {
+        n.syntax
+    }
+}
+impl From<MdTable> for SyntaxElement {
+    fn from(n: MdTable) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdTableCell {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_CELL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdTableCell")
+                .field("content", &self.content())
+                .finish()
+        } else {
+            f.debug_struct("MdTableCell").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdTableCell> for SyntaxNode {
+    fn from(n: MdTableCell) -> Self {
+        n.syntax
+    }
+}
+impl From<MdTableCell> for SyntaxElement {
+    fn from(n: MdTableCell) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdTableRow {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_ROW
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTableRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdTableRow")
+                .field(
+                    "line_break_token",
+                    &support::DebugOptionalElement(self.line_break_token()),
+                )
+                .field(
+                    "l_pipe_token",
+                    &support::DebugOptionalElement(self.l_pipe_token()),
+                )
+                .field("cells", &self.cells())
+                .field(
+                    "r_pipe_token",
+                    &support::DebugOptionalElement(self.r_pipe_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdTableRow").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdTableRow> for SyntaxNode {
+    fn from(n: MdTableRow) -> Self {
+        n.syntax
+    }
+}
+impl From<MdTableRow> for SyntaxElement {
+    fn from(n: MdTableRow) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for MdTextual {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -2726,17 +3158,75 @@ impl From<MdThematicBreakBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl From<MdFencedCodeBlock> for AnyCodeBlock {
+impl AstNode for AnyMdBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        AnyMdContainerBlock::KIND_SET.union(AnyMdLeafBlock::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        match kind {
+            k if AnyMdContainerBlock::can_cast(k) => true,
+            k if AnyMdLeafBlock::can_cast(k) => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let syntax = match AnyMdContainerBlock::try_cast(syntax) {
+            Ok(any_md_container_block) => {
+                return Some(Self::AnyMdContainerBlock(any_md_container_block));
+            }
+            Err(syntax) => syntax,
+        };
+        if let Some(any_md_leaf_block) = AnyMdLeafBlock::cast(syntax) {
+            return Some(Self::AnyMdLeafBlock(any_md_leaf_block));
+        }
+        None
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::AnyMdContainerBlock(it) => it.syntax(),
+            Self::AnyMdLeafBlock(it) => it.syntax(),
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            Self::AnyMdContainerBlock(it) => it.into_syntax(),
+            Self::AnyMdLeafBlock(it) => it.into_syntax(),
+        }
+    }
+}
+impl std::fmt::Debug for AnyMdBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AnyMdContainerBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::AnyMdLeafBlock(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyMdBlock> for SyntaxNode {
+    fn from(n: AnyMdBlock) -> Self {
+        match n {
+            AnyMdBlock::AnyMdContainerBlock(it) => it.into(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.into(),
+        }
+    }
+}
+impl From<AnyMdBlock> for SyntaxElement {
+    fn from(n: AnyMdBlock) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<MdFencedCodeBlock> for AnyMdCodeBlock {
     fn from(node: MdFencedCodeBlock) -> Self {
         Self::MdFencedCodeBlock(node)
     }
 }
-impl From<MdIndentCodeBlock> for AnyCodeBlock {
+impl From<MdIndentCodeBlock> for AnyMdCodeBlock {
     fn from(node: MdIndentCodeBlock) -> Self {
         Self::MdIndentCodeBlock(node)
     }
 }
-impl AstNode for AnyCodeBlock {
+impl AstNode for AnyMdCodeBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
         MdFencedCodeBlock::KIND_SET.union(MdIndentCodeBlock::KIND_SET);
@@ -2764,7 +3254,7 @@ impl AstNode for AnyCodeBlock {
         }
     }
 }
-impl std::fmt::Debug for AnyCodeBlock {
+impl std::fmt::Debug for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
@@ -2772,47 +3262,47 @@ impl std::fmt::Debug for AnyCodeBlock {
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxNode {
-    fn from(n: AnyCodeBlock) -> Self {
+impl From<AnyMdCodeBlock> for SyntaxNode {
+    fn from(n: AnyMdCodeBlock) -> Self {
         match n {
-            AnyCodeBlock::MdFencedCodeBlock(it) => it.into(),
-            AnyCodeBlock::MdIndentCodeBlock(it) => it.into(),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => it.into(),
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxElement {
-    fn from(n: AnyCodeBlock) -> Self {
+impl From<AnyMdCodeBlock> for SyntaxElement {
+    fn from(n: AnyMdCodeBlock) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdBulletListItem> for AnyContainerBlock {
-    fn from(node: MdBulletListItem) -> Self {
-        Self::MdBulletListItem(node)
+impl From<MdBulletList> for AnyMdContainerBlock {
+    fn from(node: MdBulletList) -> Self {
+        Self::MdBulletList(node)
     }
 }
-impl From<MdOrderListItem> for AnyContainerBlock {
-    fn from(node: MdOrderListItem) -> Self {
-        Self::MdOrderListItem(node)
+impl From<MdOrderedList> for AnyMdContainerBlock {
+    fn from(node: MdOrderedList) -> Self {
+        Self::MdOrderedList(node)
     }
 }
-impl From<MdQuote> for AnyContainerBlock {
+impl From<MdQuote> for AnyMdContainerBlock {
     fn from(node: MdQuote) -> Self {
         Self::MdQuote(node)
     }
 }
-impl AstNode for AnyContainerBlock {
+impl AstNode for AnyMdContainerBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = MdBulletListItem::KIND_SET
-        .union(MdOrderListItem::KIND_SET)
+    const KIND_SET: SyntaxKindSet<Language> = MdBulletList::KIND_SET
+        .union(MdOrderedList::KIND_SET)
         .union(MdQuote::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MD_BULLET_LIST_ITEM | MD_ORDER_LIST_ITEM | MD_QUOTE)
+        matches!(kind, MD_BULLET_LIST | MD_ORDERED_LIST | MD_QUOTE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            MD_BULLET_LIST_ITEM => Self::MdBulletListItem(MdBulletListItem { syntax }),
-            MD_ORDER_LIST_ITEM => Self::MdOrderListItem(MdOrderListItem { syntax }),
+            MD_BULLET_LIST => Self::MdBulletList(MdBulletList { syntax }),
+            MD_ORDERED_LIST => Self::MdOrderedList(MdOrderedList { syntax }),
             MD_QUOTE => Self::MdQuote(MdQuote { syntax }),
             _ => return None,
         };
@@ -2820,91 +3310,279 @@ impl AstNode for AnyContainerBlock {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            Self::MdBulletListItem(it) => &it.syntax,
-            Self::MdOrderListItem(it) => &it.syntax,
+            Self::MdBulletList(it) => &it.syntax,
+            Self::MdOrderedList(it) => &it.syntax,
             Self::MdQuote(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            Self::MdBulletListItem(it) => it.syntax,
-            Self::MdOrderListItem(it) => it.syntax,
+            Self::MdBulletList(it) => it.syntax,
+            Self::MdOrderedList(it) => it.syntax,
             Self::MdQuote(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyContainerBlock {
+impl std::fmt::Debug for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::MdBulletListItem(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdOrderListItem(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdBulletList(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdOrderedList(it) => std::fmt::Debug::fmt(it, f),
             Self::MdQuote(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxNode {
-    fn from(n: AnyContainerBlock) -> Self {
+impl From<AnyMdContainerBlock> for SyntaxNode {
+    fn from(n: AnyMdContainerBlock) -> Self {
+        match n {
+            AnyMdContainerBlock::MdBulletList(it) => it.into(),
+            AnyMdContainerBlock::MdOrderedList(it) => it.into(),
+            AnyMdContainerBlock::MdQuote(it) => it.into(),
+        }
+    }
+}
+impl From<AnyMdContainerBlock> for SyntaxElement {
+    fn from(n: AnyMdContainerBlock) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<MdAutolink> for AnyMdInline {
+    fn from(node: MdAutolink) -> Self {
+        Self::MdAutolink(node)
+    }
+}
+impl From<MdHardLine> for AnyMdInline {
+    fn from(node: MdHardLine) -> Self {
+        Self::MdHardLine(node)
+    }
+}
+impl From<MdInlineCode> for AnyMdInline {
+    fn from(node: MdInlineCode) -> Self {
+        Self::MdInlineCode(node)
+    }
+}
+impl From<MdInlineEmphasis> for AnyMdInline {
+    fn from(node: MdInlineEmphasis) -> Self {
+        Self::MdInlineEmphasis(node)
+    }
+}
+impl From<MdInlineHtml> for AnyMdInline {
+    fn from(node: MdInlineHtml) -> Self {
+        Self::MdInlineHtml(node)
+    }
+}
+impl From<MdInlineImage> for AnyMdInline {
+    fn from(node: MdInlineImage) -> Self {
+        Self::MdInlineImage(node)
+    }
+}
+impl From<MdInlineItalic> for AnyMdInline {
+    fn from(node: MdInlineItalic) -> Self {
+        Self::MdInlineItalic(node)
+    }
+}
+impl From<MdInlineLink> for AnyMdInline {
+    fn from(node: MdInlineLink) -> Self {
+        Self::MdInlineLink(node)
+    }
+}
+impl From<MdInlineStrikethrough> for AnyMdInline {
+    fn from(node: MdInlineStrikethrough) -> Self {
+        Self::MdInlineStrikethrough(node)
+    }
+}
+impl From<MdSoftBreak> for AnyMdInline {
+    fn from(node: MdSoftBreak) -> Self {
+        Self::MdSoftBreak(node)
+    }
+}
+impl From<MdTextual> for AnyMdInline {
+    fn from(node: MdTextual) -> Self {
+        Self::MdTextual(node)
+    }
+}
+impl AstNode for AnyMdInline {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = MdAutolink::KIND_SET
+        .union(MdHardLine::KIND_SET)
+        .union(MdInlineCode::KIND_SET)
+        .union(MdInlineEmphasis::KIND_SET)
+        .union(MdInlineHtml::KIND_SET)
+        .union(MdInlineImage::KIND_SET)
+        .union(MdInlineItalic::KIND_SET)
+        .union(MdInlineLink::KIND_SET)
+        .union(MdInlineStrikethrough::KIND_SET)
+        .union(MdSoftBreak::KIND_SET)
+        .union(MdTextual::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            MD_AUTOLINK
+                | MD_HARD_LINE
+                | MD_INLINE_CODE
+                | MD_INLINE_EMPHASIS
+                | MD_INLINE_HTML
+                | MD_INLINE_IMAGE
+                | MD_INLINE_ITALIC
+                | MD_INLINE_LINK
+                | MD_INLINE_STRIKETHROUGH
+                | MD_SOFT_BREAK
+                | MD_TEXTUAL
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            MD_AUTOLINK => Self::MdAutolink(MdAutolink { syntax }),
+            MD_HARD_LINE => Self::MdHardLine(MdHardLine { syntax }),
+            MD_INLINE_CODE => Self::MdInlineCode(MdInlineCode { syntax }),
+            MD_INLINE_EMPHASIS => Self::MdInlineEmphasis(MdInlineEmphasis { syntax }),
+            MD_INLINE_HTML => Self::MdInlineHtml(MdInlineHtml { syntax }),
+            MD_INLINE_IMAGE => Self::MdInlineImage(MdInlineImage { syntax }),
+            MD_INLINE_ITALIC => Self::MdInlineItalic(MdInlineItalic { syntax }),
+            MD_INLINE_LINK => Self::MdInlineLink(MdInlineLink { syntax }),
+            MD_INLINE_STRIKETHROUGH => {
+                Self::MdInlineStrikethrough(MdInlineStrikethrough { syntax })
+            }
+            MD_SOFT_BREAK => Self::MdSoftBreak(MdSoftBreak { syntax }),
+            MD_TEXTUAL => Self::MdTextual(MdTextual { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::MdAutolink(it) => &it.syntax,
+            Self::MdHardLine(it) => &it.syntax,
+            Self::MdInlineCode(it) => &it.syntax,
+            Self::MdInlineEmphasis(it) => &it.syntax,
+            Self::MdInlineHtml(it) => &it.syntax,
+            Self::MdInlineImage(it) => &it.syntax,
+            Self::MdInlineItalic(it) => &it.syntax,
+            Self::MdInlineLink(it) => &it.syntax,
+            Self::MdInlineStrikethrough(it) => &it.syntax,
+            Self::MdSoftBreak(it) => &it.syntax,
+            Self::MdTextual(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            Self::MdAutolink(it) => it.syntax,
+            Self::MdHardLine(it) => it.syntax,
+            Self::MdInlineCode(it) => it.syntax,
+            Self::MdInlineEmphasis(it) => it.syntax,
+            Self::MdInlineHtml(it) => it.syntax,
+            Self::MdInlineImage(it) => it.syntax,
+            Self::MdInlineItalic(it) => it.syntax,
+            Self::MdInlineLink(it) => it.syntax,
+            Self::MdInlineStrikethrough(it) => it.syntax,
+            Self::MdSoftBreak(it) => it.syntax,
+            Self::MdTextual(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyMdInline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MdAutolink(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineHtml(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineStrikethrough(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdTextual(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyMdInline> for SyntaxNode {
+    fn from(n: AnyMdInline) -> Self {
         match n {
-            AnyContainerBlock::MdBulletListItem(it) => it.into(),
-            AnyContainerBlock::MdOrderListItem(it) => it.into(),
-            AnyContainerBlock::MdQuote(it) => it.into(),
+            AnyMdInline::MdAutolink(it) => it.into(),
+            AnyMdInline::MdHardLine(it) => it.into(),
+            AnyMdInline::MdInlineCode(it) => it.into(),
+            AnyMdInline::MdInlineEmphasis(it) => it.into(),
+            AnyMdInline::MdInlineHtml(it) => it.into(),
+            AnyMdInline::MdInlineImage(it) => it.into(),
+            AnyMdInline::MdInlineItalic(it) => it.into(),
+            AnyMdInline::MdInlineLink(it) => it.into(),
+            AnyMdInline::MdInlineStrikethrough(it) => it.into(),
+            AnyMdInline::MdSoftBreak(it) => it.into(),
+            AnyMdInline::MdTextual(it) => it.into(),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxElement {
-    fn from(n: AnyContainerBlock) -> Self {
+impl From<AnyMdInline> for SyntaxElement {
+    fn from(n: AnyMdInline) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdHeader> for AnyLeafBlock {
+impl From<MdHeader> for AnyMdLeafBlock {
     fn from(node: MdHeader) -> Self {
         Self::MdHeader(node)
     }
 }
-impl From<MdHtmlBlock> for AnyLeafBlock {
+impl From<MdHtmlBlock> for AnyMdLeafBlock {
     fn from(node: MdHtmlBlock) -> Self {
         Self::MdHtmlBlock(node)
     }
 }
-impl From<MdLinkBlock> for AnyLeafBlock {
-    fn from(node: MdLinkBlock) -> Self {
-        Self::MdLinkBlock(node)
+impl From<MdLinkReferenceDefinition> for AnyMdLeafBlock {
+    fn from(node: MdLinkReferenceDefinition) -> Self {
+        Self::MdLinkReferenceDefinition(node)
     }
 }
-impl From<MdParagraph> for AnyLeafBlock {
+impl From<MdNewline> for AnyMdLeafBlock {
+    fn from(node: MdNewline) -> Self {
+        Self::MdNewline(node)
+    }
+}
+impl From<MdParagraph> for AnyMdLeafBlock {
     fn from(node: MdParagraph) -> Self {
         Self::MdParagraph(node)
     }
 }
-impl From<MdSetextHeader> for AnyLeafBlock {
+impl From<MdSetextHeader> for AnyMdLeafBlock {
     fn from(node: MdSetextHeader) -> Self {
         Self::MdSetextHeader(node)
     }
 }
-impl From<MdThematicBreakBlock> for AnyLeafBlock {
+impl From<MdTable> for AnyMdLeafBlock {
+    fn from(node: MdTable) -> Self {
+        Self::MdTable(node)
+    }
+}
+impl From<MdThematicBreakBlock> for AnyMdLeafBlock {
     fn from(node: MdThematicBreakBlock) -> Self {
         Self::MdThematicBreakBlock(node)
     }
 }
-impl AstNode for AnyLeafBlock {
+impl AstNode for AnyMdLeafBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = AnyCodeBlock::KIND_SET
+    const KIND_SET: SyntaxKindSet<Language> = AnyMdCodeBlock::KIND_SET
         .union(MdHeader::KIND_SET)
         .union(MdHtmlBlock::KIND_SET)
-        .union(MdLinkBlock::KIND_SET)
+        .union(MdLinkReferenceDefinition::KIND_SET)
+        .union(MdNewline::KIND_SET)
         .union(MdParagraph::KIND_SET)
         .union(MdSetextHeader::KIND_SET)
+        .union(MdTable::KIND_SET)
         .union(MdThematicBreakBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
             MD_HEADER
             | MD_HTML_BLOCK
-            | MD_LINK_BLOCK
+            | MD_LINK_REFERENCE_DEFINITION
+            | MD_NEWLINE
             | MD_PARAGRAPH
             | MD_SETEXT_HEADER
+            | MD_TABLE
             | MD_THEMATIC_BREAK_BLOCK => true,
-            k if AnyCodeBlock::can_cast(k) => true,
+            k if AnyMdCodeBlock::can_cast(k) => true,
             _ => false,
         }
     }
@@ -2912,13 +3590,17 @@ impl AstNode for AnyLeafBlock {
         let res = match syntax.kind() {
             MD_HEADER => Self::MdHeader(MdHeader { syntax }),
             MD_HTML_BLOCK => Self::MdHtmlBlock(MdHtmlBlock { syntax }),
-            MD_LINK_BLOCK => Self::MdLinkBlock(MdLinkBlock { syntax }),
+            MD_LINK_REFERENCE_DEFINITION => {
+                Self::MdLinkReferenceDefinition(MdLinkReferenceDefinition { syntax })
+            }
+            MD_NEWLINE => Self::MdNewline(MdNewline { syntax }),
             MD_PARAGRAPH => Self::MdParagraph(MdParagraph { syntax }),
             MD_SETEXT_HEADER => Self::MdSetextHeader(MdSetextHeader { syntax }),
+            MD_TABLE => Self::MdTable(MdTable { syntax }),
             MD_THEMATIC_BREAK_BLOCK => Self::MdThematicBreakBlock(MdThematicBreakBlock { syntax }),
             _ => {
-                if let Some(any_code_block) = AnyCodeBlock::cast(syntax) {
-                    return Some(Self::AnyCodeBlock(any_code_block));
+                if let Some(any_md_code_block) = AnyMdCodeBlock::cast(syntax) {
+                    return Some(Self::AnyMdCodeBlock(any_md_code_block));
                 }
                 return None;
             }
@@ -2929,195 +3611,84 @@ impl AstNode for AnyLeafBlock {
         match self {
             Self::MdHeader(it) => &it.syntax,
             Self::MdHtmlBlock(it) => &it.syntax,
-            Self::MdLinkBlock(it) => &it.syntax,
+            Self::MdLinkReferenceDefinition(it) => &it.syntax,
+            Self::MdNewline(it) => &it.syntax,
             Self::MdParagraph(it) => &it.syntax,
             Self::MdSetextHeader(it) => &it.syntax,
+            Self::MdTable(it) => &it.syntax,
             Self::MdThematicBreakBlock(it) => &it.syntax,
-            Self::AnyCodeBlock(it) => it.syntax(),
+            Self::AnyMdCodeBlock(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             Self::MdHeader(it) => it.syntax,
             Self::MdHtmlBlock(it) => it.syntax,
-            Self::MdLinkBlock(it) => it.syntax,
+            Self::MdLinkReferenceDefinition(it) => it.syntax,
+            Self::MdNewline(it) => it.syntax,
             Self::MdParagraph(it) => it.syntax,
             Self::MdSetextHeader(it) => it.syntax,
+            Self::MdTable(it) => it.syntax,
             Self::MdThematicBreakBlock(it) => it.syntax,
-            Self::AnyCodeBlock(it) => it.into_syntax(),
+            Self::AnyMdCodeBlock(it) => it.into_syntax(),
         }
     }
 }
-impl std::fmt::Debug for AnyLeafBlock {
+impl std::fmt::Debug for AnyMdLeafBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::AnyCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::AnyMdCodeBlock(it) => std::fmt::Debug::fmt(it, f),
             Self::MdHeader(it) => std::fmt::Debug::fmt(it, f),
             Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdLinkReferenceDefinition(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdNewline(it) => std::fmt::Debug::fmt(it, f),
             Self::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
             Self::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdTable(it) => std::fmt::Debug::fmt(it, f),
             Self::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyLeafBlock> for SyntaxNode {
-    fn from(n: AnyLeafBlock) -> Self {
-        match n {
-            AnyLeafBlock::AnyCodeBlock(it) => it.into(),
-            AnyLeafBlock::MdHeader(it) => it.into(),
-            AnyLeafBlock::MdHtmlBlock(it) => it.into(),
-            AnyLeafBlock::MdLinkBlock(it) => it.into(),
-            AnyLeafBlock::MdParagraph(it) => it.into(),
-            AnyLeafBlock::MdSetextHeader(it) => it.into(),
-            AnyLeafBlock::MdThematicBreakBlock(it) => it.into(),
-        }
-    }
-}
-impl From<AnyLeafBlock> for SyntaxElement {
-    fn from(n: AnyLeafBlock) -> Self {
-        let node: SyntaxNode = n.into();
-        node.into()
-    }
-}
-impl AstNode for AnyMdBlock {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        AnyContainerBlock::KIND_SET.union(AnyLeafBlock::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            k if AnyContainerBlock::can_cast(k) => true,
-            k if AnyLeafBlock::can_cast(k) => true,
-            _ => false,
-        }
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let syntax = match AnyContainerBlock::try_cast(syntax) {
-            Ok(any_container_block) => {
-                return Some(Self::AnyContainerBlock(any_container_block));
-            }
-            Err(syntax) => syntax,
-        };
-        if let Some(any_leaf_block) = AnyLeafBlock::cast(syntax) {
-            return Some(Self::AnyLeafBlock(any_leaf_block));
-        }
-        None
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            Self::AnyContainerBlock(it) => it.syntax(),
-            Self::AnyLeafBlock(it) => it.syntax(),
-        }
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        match self {
-            Self::AnyContainerBlock(it) => it.into_syntax(),
-            Self::AnyLeafBlock(it) => it.into_syntax(),
-        }
-    }
-}
-impl std::fmt::Debug for AnyMdBlock {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::AnyContainerBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::AnyLeafBlock(it) => std::fmt::Debug::fmt(it, f),
-        }
-    }
-}
-impl From<AnyMdBlock> for SyntaxNode {
-    fn from(n: AnyMdBlock) -> Self {
-        match n {
-            AnyMdBlock::AnyContainerBlock(it) => it.into(),
-            AnyMdBlock::AnyLeafBlock(it) => it.into(),
-        }
-    }
-}
-impl From<AnyMdBlock> for SyntaxElement {
-    fn from(n: AnyMdBlock) -> Self {
-        let node: SyntaxNode = n.into();
-        node.into()
-    }
-}
-impl From<MdHardLine> for AnyMdInline {
-    fn from(node: MdHardLine) -> Self {
-        Self::MdHardLine(node)
-    }
-}
-impl From<MdHtmlBlock> for AnyMdInline {
-    fn from(node: MdHtmlBlock) -> Self {
-        Self::MdHtmlBlock(node)
-    }
-}
-impl From<MdInlineCode> for AnyMdInline {
-    fn from(node: MdInlineCode) -> Self {
-        Self::MdInlineCode(node)
-    }
-}
-impl From<MdInlineEmphasis> for AnyMdInline {
-    fn from(node: MdInlineEmphasis) -> Self {
-        Self::MdInlineEmphasis(node)
-    }
-}
-impl From<MdInlineImage> for AnyMdInline {
-    fn from(node: MdInlineImage) -> Self {
-        Self::MdInlineImage(node)
-    }
-}
-impl From<MdInlineItalic> for AnyMdInline {
-    fn from(node: MdInlineItalic) -> Self {
-        Self::MdInlineItalic(node)
+impl From<AnyMdLeafBlock> for SyntaxNode {
+    fn from(n: AnyMdLeafBlock) -> Self {
+        match n {
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.into(),
+            AnyMdLeafBlock::MdHeader(it) => it.into(),
+            AnyMdLeafBlock::MdHtmlBlock(it) => it.into(),
+            AnyMdLeafBlock::MdLinkReferenceDefinition(it) => it.into(),
+            AnyMdLeafBlock::MdNewline(it) => it.into(),
+            AnyMdLeafBlock::MdParagraph(it) => it.into(),
+            AnyMdLeafBlock::MdSetextHeader(it) => it.into(),
+            AnyMdLeafBlock::MdTable(it) => it.into(),
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => it.into(),
+        }
     }
 }
-impl From<MdInlineLink> for AnyMdInline {
-    fn from(node: MdInlineLink) -> Self {
-        Self::MdInlineLink(node)
+impl From<AnyMdLeafBlock> for SyntaxElement {
+    fn from(n: AnyMdLeafBlock) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
     }
 }
-impl From<MdSoftBreak> for AnyMdInline {
-    fn from(node: MdSoftBreak) -> Self {
-        Self::MdSoftBreak(node)
+impl From<MdNewline> for AnyMdVerbatimLine {
+    fn from(node: MdNewline) -> Self {
+        Self::MdNewline(node)
     }
 }
-impl From<MdTextual> for AnyMdInline {
+impl From<MdTextual> for AnyMdVerbatimLine {
     fn from(node: MdTextual) -> Self {
         Self::MdTextual(node)
     }
 }
-impl AstNode for AnyMdInline {
+impl AstNode for AnyMdVerbatimLine {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = MdHardLine::KIND_SET
-        .union(MdHtmlBlock::KIND_SET)
-        .union(MdInlineCode::KIND_SET)
-        .union(MdInlineEmphasis::KIND_SET)
-        .union(MdInlineImage::KIND_SET)
-        .union(MdInlineItalic::KIND_SET)
-        .union(MdInlineLink::KIND_SET)
-        .union(MdSoftBreak::KIND_SET)
-        .union(MdTextual::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = MdNewline::KIND_SET.union(MdTextual::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(
-            kind,
-            MD_HARD_LINE
-                | MD_HTML_BLOCK
-                | MD_INLINE_CODE
-                | MD_INLINE_EMPHASIS
-                | MD_INLINE_IMAGE
-                | MD_INLINE_ITALIC
-                | MD_INLINE_LINK
-                | MD_SOFT_BREAK
-                | MD_TEXTUAL
-        )
+        matches!(kind, MD_NEWLINE | MD_TEXTUAL)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            MD_HARD_LINE => Self::MdHardLine(MdHardLine { syntax }),
-            MD_HTML_BLOCK => Self::MdHtmlBlock(MdHtmlBlock { syntax }),
-            MD_INLINE_CODE => Self::MdInlineCode(MdInlineCode { syntax }),
-            MD_INLINE_EMPHASIS => Self::MdInlineEmphasis(MdInlineEmphasis { syntax }),
-            MD_INLINE_IMAGE => Self::MdInlineImage(MdInlineImage { syntax }),
-            MD_INLINE_ITALIC => Self::MdInlineItalic(MdInlineItalic { syntax }),
-            MD_INLINE_LINK => Self::MdInlineLink(MdInlineLink { syntax }),
-            MD_SOFT_BREAK => Self::MdSoftBreak(MdSoftBreak { syntax }),
+            MD_NEWLINE => Self::MdNewline(MdNewline { syntax }),
             MD_TEXTUAL => Self::MdTextual(MdTextual { syntax }),
             _ => return None,
         };
@@ -3125,93 +3696,75 @@ impl AstNode for AnyMdInline {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            Self::MdHardLine(it) => &it.syntax,
-            Self::MdHtmlBlock(it) => &it.syntax,
-            Self::MdInlineCode(it) => &it.syntax,
-            Self::MdInlineEmphasis(it) => &it.syntax,
-            Self::MdInlineImage(it) => &it.syntax,
-            Self::MdInlineItalic(it) => &it.syntax,
-            Self::MdInlineLink(it) => &it.syntax,
-            Self::MdSoftBreak(it) => &it.syntax,
+            Self::MdNewline(it) => &it.syntax,
             Self::MdTextual(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            Self::MdHardLine(it) => it.syntax,
-            Self::MdHtmlBlock(it) => it.syntax,
-            Self::MdInlineCode(it) => it.syntax,
-            Self::MdInlineEmphasis(it) => it.syntax,
-            Self::MdInlineImage(it) => it.syntax,
-            Self::MdInlineItalic(it) => it.syntax,
-            Self::MdInlineLink(it) => it.syntax,
-            Self::MdSoftBreak(it) => it.syntax,
+            Self::MdNewline(it) => it.syntax,
             Self::MdTextual(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyMdInline {
+impl std::fmt::Debug for AnyMdVerbatimLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdNewline(it) => std::fmt::Debug::fmt(it, f),
             Self::MdTextual(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyMdInline> for SyntaxNode {
-    fn from(n: AnyMdInline) -> Self {
+impl From<AnyMdVerbatimLine> for SyntaxNode {
+    fn from(n: AnyMdVerbatimLine) -> Self {
         match n {
-            AnyMdInline::MdHardLine(it) => it.into(),
-            AnyMdInline::MdHtmlBlock(it) => it.into(),
-            AnyMdInline::MdInlineCode(it) => it.into(),
-            AnyMdInline::MdInlineEmphasis(it) => it.into(),
-            AnyMdInline::MdInlineImage(it) => it.into(),
-            AnyMdInline::MdInlineItalic(it) => it.into(),
-            AnyMdInline::MdInlineLink(it) => it.into(),
-            AnyMdInline::MdSoftBreak(it) => it.into(),
-            AnyMdInline::MdTextual(it) => it.into(),
+            AnyMdVerbatimLine::MdNewline(it) => it.into(),
+            AnyMdVerbatimLine::MdTextual(it) => it.into(),
         }
     }
 }
-impl From<AnyMdInline> for SyntaxElement {
-    fn from(n: AnyMdInline) -> Self {
+impl From<AnyMdVerbatimLine> for SyntaxElement {
+    fn from(n: AnyMdVerbatimLine) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl std::fmt::Display for AnyCodeBlock {
+impl std::fmt::Display for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyContainerBlock {
+impl std::fmt::Display for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyLeafBlock {
+impl std::fmt::Display for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdBlock {
+impl std::fmt::Display for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdInline {
+impl std::fmt::Display for AnyMdLeafBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyMdVerbatimLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdAutolink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdBullet {
+impl std::fmt::Display for MdBulletList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3231,27 +3784,27 @@ impl std::fmt::Display for MdFencedCodeBlock {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdHardLine {
+impl std::fmt::Display for MdFrontMatter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdHash {
+impl std::fmt::Display for MdHardLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdHeader {
+impl std::fmt::Display for MdHash {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdHtmlBlock {
+impl std::fmt::Display for MdHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndent {
+impl std::fmt::Display for MdHtmlBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3261,17 +3814,17 @@ impl std::fmt::Display for MdIndentCodeBlock {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndentedCodeLine {
+impl std::fmt::Display for MdInlineCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineCode {
+impl std::fmt::Display for MdInlineEmphasis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineEmphasis {
+impl std::fmt::Display for MdInlineHtml {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3281,37 +3834,37 @@ impl std::fmt::Display for MdInlineImage {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageAlt {
+impl std::fmt::Display for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageLink {
+impl std::fmt::Display for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageSource {
+impl std::fmt::Display for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineItalic {
+impl std::fmt::Display for MdLinkReferenceDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineLink {
+impl std::fmt::Display for MdNewline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdLinkBlock {
+impl std::fmt::Display for MdOrderedList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdOrderListItem {
+impl std::fmt::Display for MdOrderedListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3336,6 +3889,21 @@ impl std::fmt::Display for MdSoftBreak {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for MdTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for MdTextual {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -3486,10 +4054,10 @@ impl IntoIterator for MdBlockList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdBulletList {
+pub struct MdBulletListItemList {
     syntax_list: SyntaxList,
 }
-impl MdBulletList {
+impl MdBulletListItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3502,12 +4070,12 @@ impl MdBulletList {
         }
     }
 }
-impl AstNode for MdBulletList {
+impl AstNode for MdBulletListItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_BULLET_LIST
+        kind == MD_BULLET_LIST_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3525,7 +4093,7 @@ impl AstNode for MdBulletList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdBulletList {
+impl Serialize for MdBulletListItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3537,9 +4105,9 @@ impl Serialize for MdBulletList {
         seq.end()
     }
 }
-impl AstNodeList for MdBulletList {
+impl AstNodeList for MdBulletListItemList {
     type Language = Language;
-    type Node = MdBullet;
+    type Node = MdBulletListItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3547,31 +4115,31 @@ impl AstNodeList for MdBulletList {
         self.syntax_list
     }
 }
-impl Debug for MdBulletList {
+impl Debug for MdBulletListItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdBulletList ")?;
+        f.write_str("MdBulletListItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdBulletList {
-    type Item = MdBullet;
-    type IntoIter = AstNodeListIterator<Language, MdBullet>;
+impl IntoIterator for &MdBulletListItemList {
+    type Item = MdBulletListItem;
+    type IntoIter = AstNodeListIterator<Language, MdBulletListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdBulletList {
-    type Item = MdBullet;
-    type IntoIter = AstNodeListIterator<Language, MdBullet>;
+impl IntoIterator for MdBulletListItemList {
+    type Item = MdBulletListItem;
+    type IntoIter = AstNodeListIterator<Language, MdBulletListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdCodeNameList {
+pub struct MdHashList {
     syntax_list: SyntaxList,
 }
-impl MdCodeNameList {
+impl MdHashList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3584,12 +4152,12 @@ impl MdCodeNameList {
         }
     }
 }
-impl AstNode for MdCodeNameList {
+impl AstNode for MdHashList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_CODE_NAME_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_CODE_NAME_LIST
+        kind == MD_HASH_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3607,7 +4175,7 @@ impl AstNode for MdCodeNameList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdCodeNameList {
+impl Serialize for MdHashList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3619,9 +4187,9 @@ impl Serialize for MdCodeNameList {
         seq.end()
     }
 }
-impl AstSeparatedList for MdCodeNameList {
+impl AstNodeList for MdHashList {
     type Language = Language;
-    type Node = MdTextual;
+    type Node = MdHash;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3629,31 +4197,31 @@ impl AstSeparatedList for MdCodeNameList {
         self.syntax_list
     }
 }
-impl Debug for MdCodeNameList {
+impl Debug for MdHashList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdCodeNameList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("MdHashList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for &MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdHashList {
+pub struct MdInlineItemList {
     syntax_list: SyntaxList,
 }
-impl MdHashList {
+impl MdInlineItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3666,12 +4234,12 @@ impl MdHashList {
         }
     }
 }
-impl AstNode for MdHashList {
+impl AstNode for MdInlineItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HASH_LIST
+        kind == MD_INLINE_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3689,7 +4257,7 @@ impl AstNode for MdHashList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdHashList {
+impl Serialize for MdInlineItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3701,9 +4269,9 @@ impl Serialize for MdHashList {
         seq.end()
     }
 }
-impl AstNodeList for MdHashList {
+impl AstNodeList for MdInlineItemList {
     type Language = Language;
-    type Node = MdHash;
+    type Node = AnyMdInline;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3711,31 +4279,31 @@ impl AstNodeList for MdHashList {
         self.syntax_list
     }
 }
-impl Debug for MdHashList {
+impl Debug for MdInlineItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdHashList ")?;
+        f.write_str("MdInlineItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for &MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdIndentedCodeLineList {
+pub struct MdOrderedListItemList {
     syntax_list: SyntaxList,
 }
-impl MdIndentedCodeLineList {
+impl MdOrderedListItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3748,12 +4316,12 @@ impl MdIndentedCodeLineList {
         }
     }
 }
-impl AstNode for MdIndentedCodeLineList {
+impl AstNode for MdOrderedListItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDERED_LIST_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENTED_CODE_LINE_LIST
+        kind == MD_ORDERED_LIST_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3771,7 +4339,7 @@ impl AstNode for MdIndentedCodeLineList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdIndentedCodeLineList {
+impl Serialize for MdOrderedListItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3783,9 +4351,9 @@ impl Serialize for MdIndentedCodeLineList {
         seq.end()
     }
 }
-impl AstNodeList for MdIndentedCodeLineList {
+impl AstNodeList for MdOrderedListItemList {
     type Language = Language;
-    type Node = MdIndentedCodeLine;
+    type Node = MdOrderedListItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3793,31 +4361,31 @@ impl AstNodeList for MdIndentedCodeLineList {
         self.syntax_list
     }
 }
-impl Debug for MdIndentedCodeLineList {
+impl Debug for MdOrderedListItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdIndentedCodeLineList ")?;
+        f.write_str("MdOrderedListItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for &MdOrderedListItemList {
+    type Item = MdOrderedListItem;
+    type IntoIter = AstNodeListIterator<Language, MdOrderedListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for MdOrderedListItemList {
+    type Item = MdOrderedListItem;
+    type IntoIter = AstNodeListIterator<Language, MdOrderedListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdInlineItemList {
+pub struct MdTableCellList {
     syntax_list: SyntaxList,
 }
-impl MdInlineItemList {
+impl MdTableCellList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3830,12 +4398,12 @@ impl MdInlineItemList {
         }
     }
 }
-impl AstNode for MdInlineItemList {
+impl AstNode for MdTableCellList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITEM_LIST
+        kind == MD_TABLE_CELL_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3853,7 +4421,7 @@ impl AstNode for MdInlineItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdInlineItemList {
+impl Serialize for MdTableCellList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3865,9 +4433,9 @@ impl Serialize for MdInlineItemList {
         seq.end()
     }
 }
-impl AstNodeList for MdInlineItemList {
+impl AstSeparatedList for MdTableCellList {
     type Language = Language;
-    type Node = AnyMdInline;
+    type Node = MdTableCell;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3875,31 +4443,113 @@ impl AstNodeList for MdInlineItemList {
         self.syntax_list
     }
 }
-impl Debug for MdInlineItemList {
+impl Debug for MdTableCellList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdInlineItemList ")?;
+        f.write_str("MdTableCellList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for MdTableCellList {
+    type Item = SyntaxResult<MdTableCell>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, MdTableCell>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &MdTableCellList {
+    type Item = SyntaxResult<MdTableCell>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, MdTableCell>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MdTableRowList {
+    syntax_list: SyntaxList,
+}
+impl MdTableRowList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MdTableRowList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_ROW_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for MdTableRowList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MdTableRowList {
+    type Language = Language;
+    type Node = MdTableRow;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MdTableRowList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MdTableRowList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for &MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdOrderList {
+pub struct MdVerbatimLineList {
     syntax_list: SyntaxList,
 }
-impl MdOrderList {
+impl MdVerbatimLineList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3912,12 +4562,12 @@ impl MdOrderList {
         }
     }
 }
-impl AstNode for MdOrderList {
+impl AstNode for MdVerbatimLineList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_VERBATIM_LINE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST
+        kind == MD_VERBATIM_LINE_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3935,7 +4585,7 @@ impl AstNode for MdOrderList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdOrderList {
+impl Serialize for MdVerbatimLineList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3947,9 +4597,9 @@ impl Serialize for MdOrderList {
         seq.end()
     }
 }
-impl AstNodeList for MdOrderList {
+impl AstNodeList for MdVerbatimLineList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = AnyMdVerbatimLine;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3957,22 +4607,22 @@ impl AstNodeList for MdOrderList {
         self.syntax_list
     }
 }
-impl Debug for MdOrderList {
+impl Debug for MdVerbatimLineList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdOrderList ")?;
+        f.write_str("MdVerbatimLineList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for &MdVerbatimLineList {
+    type Item = AnyMdVerbatimLine;
+    type IntoIter = AstNodeListIterator<Language, AnyMdVerbatimLine>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for MdVerbatimLineList {
+    type Item = AnyMdVerbatimLine;
+    type IntoIter = AstNodeListIterator<Language, AnyMdVerbatimLine>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
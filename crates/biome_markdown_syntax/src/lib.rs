@@ -2,10 +2,12 @@
 
 #[macro_use]
 mod generated;
+mod file_source;
 mod syntax_node;
 
 pub use self::generated::*;
 use biome_rowan::{RawSyntaxKind, SyntaxKind, TriviaPieceKind};
+pub use file_source::MarkdownFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for MarkdownSyntaxKind {
@@ -37,7 +39,7 @@ impl SyntaxKind for MarkdownSyntaxKind {
     }
 
     fn is_root(&self) -> bool {
-        todo!()
+        matches!(self, Self::MD_DOCUMENT)
     }
 
     fn is_list(&self) -> bool {
@@ -45,7 +47,8 @@ impl SyntaxKind for MarkdownSyntaxKind {
     }
 
     fn is_trivia(self) -> bool {
-        matches!(self, Self::NEWLINE | Self::WHITESPACE | Self::TAB)
+        // Line breaks are significant in Markdown, so they are never trivia
+        matches!(self, Self::WHITESPACE | Self::TAB)
     }
 
     fn to_string(&self) -> Option<&'static str> {
@@ -59,9 +62,7 @@ impl TryFrom<MarkdownSyntaxKind> for TriviaPieceKind {
     fn try_from(value: MarkdownSyntaxKind) -> Result<Self, Self::Error> {
         if value.is_trivia() {
             match value {
-                MarkdownSyntaxKind::NEWLINE => Ok(Self::Newline),
-                MarkdownSyntaxKind::WHITESPACE => Ok(Self::Whitespace),
-                MarkdownSyntaxKind::TAB => Ok(Self::Skipped),
+                MarkdownSyntaxKind::WHITESPACE | MarkdownSyntaxKind::TAB => Ok(Self::Whitespace),
                 _ => unreachable!("Not Trivia"),
             }
         } else {
@@ -0,0 +1,167 @@
+mod visitor;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+    sync::Arc,
+};
+
+use biome_resolver::ResolvedPath;
+use biome_rowan::{Text, TextRange};
+use camino::Utf8Path;
+
+pub(crate) use visitor::CssModuleVisitor;
+
+/// Information restricted to a single stylesheet in the
+/// [ModuleGraph](crate::ModuleGraph).
+#[derive(Clone, Debug)]
+pub struct CssModuleInfo(pub(super) Arc<CssModuleInfoInner>);
+
+impl Deref for CssModuleInfo {
+    type Target = CssModuleInfoInner;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl CssModuleInfo {
+    /// Returns an iterator over the paths of all the stylesheets and other
+    /// resources referenced by this stylesheet.
+    pub fn all_import_paths(&self) -> impl Iterator<Item = &ResolvedPath> {
+        self.references
+            .iter()
+            .map(|reference| &reference.resolved_path)
+    }
+
+    /// Returns the reference whose specifier is found at the given `range`.
+    pub fn reference_at_range(&self, range: TextRange) -> Option<&CssReference> {
+        self.references
+            .iter()
+            .find(|reference| reference.range == range)
+    }
+
+    /// Finds the class with the given `name` among the classes declared by
+    /// this stylesheet.
+    ///
+    /// Since bundlers commonly expose classes with dashes in their name in
+    /// camel case too, `fooBar` also finds the class `foo-bar`.
+    pub fn find_class(&self, name: &str) -> Option<(&Text, &TextRange)> {
+        self.classes.get_key_value(name).or_else(|| {
+            self.classes
+                .iter()
+                .find(|(class, _)| class.contains('-') && dashes_to_camel_case(class) == name)
+        })
+    }
+
+    /// Returns whether a CSS Module with this stylesheet exports the given
+    /// `name`, either as a class or as one of its other [identifiers].
+    ///
+    /// [identifiers]: CssModuleInfoInner::identifiers
+    pub fn exports_name(&self, name: &str) -> bool {
+        self.find_class(name).is_some()
+            || self.identifiers.contains(name)
+            || self.identifiers.iter().any(|identifier| {
+                identifier.contains('-') && dashes_to_camel_case(identifier) == name
+            })
+    }
+}
+
+#[derive(Debug)]
+pub struct CssModuleInfoInner {
+    /// All the references to other files found in the stylesheet, in source
+    /// order.
+    ///
+    /// This includes `@import` rules, the `@use` and `@forward` rules of
+    /// SCSS, the `url()` functions, and the classes composed from other
+    /// stylesheets through `composes`.
+    ///
+    /// References with a scheme, such as `https://example.com/font.woff` and
+    /// `data:` URLs, as well as URLs that only consist of a fragment, are not
+    /// included.
+    pub references: Box<[CssReference]>,
+
+    /// Classes declared by the stylesheet through class selectors, mapped to
+    /// the range of their first declaration.
+    ///
+    /// Classes that are only declared inside `:global()` are not included,
+    /// since those are not scoped to CSS Modules.
+    pub classes: BTreeMap<Text, TextRange>,
+
+    /// Identifiers other than classes that CSS Modules scope and export, such
+    /// as ids and the names of keyframes.
+    pub identifiers: BTreeSet<Text>,
+
+    /// Classes that are composed by other classes of the same stylesheet,
+    /// such as `base` in `.button { composes: base; }`.
+    pub locally_composed_classes: BTreeSet<Text>,
+
+    /// Whether the stylesheet may declare classes that cannot be determined
+    /// statically, such as those declared by SCSS selectors with a suffix
+    /// like `&-primary`.
+    pub has_dynamic_classes: bool,
+}
+
+static_assertions::assert_impl_all!(CssModuleInfo: Send, Sync);
+
+/// A reference from a stylesheet to another file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CssReference {
+    /// What the reference is used for.
+    pub kind: CssReferenceKind,
+
+    /// The specifier as it appeared in the source text, without quotes.
+    pub specifier: Text,
+
+    /// Range of the specifier in the source text, including quotes.
+    pub range: TextRange,
+
+    /// Absolute path of the referenced file, if it can be resolved.
+    pub resolved_path: ResolvedPath,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CssReferenceKind {
+    /// A stylesheet imported through `@import`, or through `@use` or
+    /// `@forward` in SCSS.
+    Import,
+
+    /// A resource referenced through `url()`, such as an image or a font.
+    Url,
+
+    /// A stylesheet from which classes are composed, such as
+    /// `./base.module.css` in `composes: button from "./base.module.css"`.
+    Composes {
+        /// Names of the classes that are composed.
+        class_names: Box<[Text]>,
+    },
+}
+
+/// Returns whether the file at `path` is a CSS Module, based on its name.
+///
+/// CSS Modules are stylesheets with the `.module.css` or `.module.scss`
+/// extension, whose classes are scoped to the modules that import them.
+pub fn is_css_module_path(path: &Utf8Path) -> bool {
+    path.file_name().is_some_and(|file_name| {
+        file_name.ends_with(".module.css") || file_name.ends_with(".module.scss")
+    })
+}
+
+/// Converts a class name with dashes, such as `foo-bar`, to camel case, such
+/// as `fooBar`.
+pub fn dashes_to_camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut uppercase_next = false;
+    for char in name.chars() {
+        if char == '-' {
+            uppercase_next = !result.is_empty();
+        } else if uppercase_next {
+            result.extend(char.to_uppercase());
+            uppercase_next = false;
+        } else {
+            result.push(char);
+        }
+    }
+
+    result
+}
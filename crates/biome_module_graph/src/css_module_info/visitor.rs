@@ -0,0 +1,291 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use biome_css_syntax::{
+    AnyCssComposesImportSource, AnyCssImportUrl, AnyCssKeyframesIdentifier, AnyCssKeyframesName,
+    AnyCssUrlValue, CssClassSelector, CssComposesProperty, CssCompoundSelector, CssIdSelector,
+    CssImportAtRule, CssKeyframesAtRule, CssPseudoClassFunctionSelector, CssRoot, CssSyntaxNode,
+    CssSyntaxToken, CssUrlFunction, ScssForwardAtRule, ScssUseAtRule,
+};
+use biome_resolver::{ResolveError, ResolveOptions, ResolvedPath, resolve};
+use biome_rowan::{AstNode, AstNodeList, Text, TextRange, TextSize, TokenText};
+use camino::Utf8Path;
+
+use crate::module_graph::ModuleGraphFsProxy;
+
+use super::{CssModuleInfo, CssModuleInfoInner, CssReference, CssReferenceKind};
+
+/// Condition names to accept for the `exports` of packages that provide
+/// stylesheets.
+const STYLE_CONDITION_NAMES: &[&str] = &["style", "import", "default"];
+
+pub(crate) struct CssModuleVisitor<'a> {
+    root: CssRoot,
+    directory: &'a Utf8Path,
+    is_scss: bool,
+    fs_proxy: &'a ModuleGraphFsProxy<'a>,
+}
+
+impl<'a> CssModuleVisitor<'a> {
+    pub fn new(
+        root: CssRoot,
+        directory: &'a Utf8Path,
+        is_scss: bool,
+        fs_proxy: &'a ModuleGraphFsProxy,
+    ) -> Self {
+        Self {
+            root,
+            directory,
+            is_scss,
+            fs_proxy,
+        }
+    }
+
+    pub fn collect_info(self) -> CssModuleInfo {
+        let mut references = Vec::new();
+        let mut classes = BTreeMap::new();
+        let mut identifiers = BTreeSet::new();
+        let mut locally_composed_classes = BTreeSet::new();
+        let mut has_dynamic_classes = false;
+
+        for node in self.root.syntax().descendants() {
+            if let Some(import) = CssImportAtRule::cast_ref(&node) {
+                let token = match import.url() {
+                    Ok(AnyCssImportUrl::CssString(string)) => string.value_token().ok(),
+                    Ok(AnyCssImportUrl::CssUrlFunction(function)) => url_function_token(&function),
+                    Err(_) => None,
+                };
+                references.extend(
+                    token.and_then(|token| self.reference(&token, CssReferenceKind::Import)),
+                );
+            } else if let Some(rule) = ScssUseAtRule::cast_ref(&node) {
+                references.extend(rule.url().ok().and_then(|url| {
+                    self.reference(&url.value_token().ok()?, CssReferenceKind::Import)
+                }));
+            } else if let Some(rule) = ScssForwardAtRule::cast_ref(&node) {
+                references.extend(rule.url().ok().and_then(|url| {
+                    self.reference(&url.value_token().ok()?, CssReferenceKind::Import)
+                }));
+            } else if let Some(function) = CssUrlFunction::cast_ref(&node) {
+                // The URLs of `@import` rules have been handled above.
+                let is_import = node
+                    .parent()
+                    .is_some_and(|parent| CssImportAtRule::can_cast(parent.kind()));
+                if !is_import {
+                    references.extend(
+                        url_function_token(&function)
+                            .and_then(|token| self.reference(&token, CssReferenceKind::Url)),
+                    );
+                }
+            } else if let Some(property) = CssComposesProperty::cast_ref(&node) {
+                let Ok(value) = property.value() else {
+                    continue;
+                };
+
+                let class_names: Box<[Text]> = value
+                    .classes()
+                    .into_iter()
+                    .filter_map(|class| class.value_token().ok())
+                    .map(|token| token.token_text_trimmed().into())
+                    .collect();
+                match value.specifier() {
+                    Some(specifier) => {
+                        // Classes composed from `global` are not scoped.
+                        if let Ok(AnyCssComposesImportSource::CssString(source)) =
+                            specifier.source()
+                        {
+                            references.extend(source.value_token().ok().and_then(|token| {
+                                self.reference(&token, CssReferenceKind::Composes { class_names })
+                            }));
+                        }
+                    }
+                    None => locally_composed_classes.extend(class_names),
+                }
+            } else if let Some(selector) = CssClassSelector::cast_ref(&node) {
+                if is_global_selector(selector.syntax()) {
+                    continue;
+                }
+
+                if let Some(token) = selector
+                    .name()
+                    .ok()
+                    .and_then(|name| name.as_css_custom_identifier()?.value_token().ok())
+                {
+                    classes
+                        .entry(token.token_text_trimmed().into())
+                        .or_insert_with(|| token.text_trimmed_range());
+                }
+            } else if let Some(selector) = CssIdSelector::cast_ref(&node) {
+                if is_global_selector(selector.syntax()) {
+                    continue;
+                }
+
+                if let Some(token) = selector
+                    .name()
+                    .ok()
+                    .and_then(|name| name.as_css_custom_identifier()?.value_token().ok())
+                {
+                    identifiers.insert(token.token_text_trimmed().into());
+                }
+            } else if let Some(rule) = CssKeyframesAtRule::cast_ref(&node) {
+                if let Ok(AnyCssKeyframesName::AnyCssKeyframesIdentifier(
+                    AnyCssKeyframesIdentifier::CssCustomIdentifier(name),
+                )) = rule.name()
+                {
+                    identifiers.extend(
+                        name.value_token()
+                            .ok()
+                            .map(|token| token.token_text_trimmed().into()),
+                    );
+                }
+            } else if let Some(selector) = CssCompoundSelector::cast_ref(&node) {
+                // A nesting selector directly followed by a type selector, as
+                // in `&-primary`, appends a suffix to the parent selector.
+                if !selector.nesting_selectors().is_empty() && selector.simple_selector().is_some()
+                {
+                    has_dynamic_classes = true;
+                }
+            }
+        }
+
+        CssModuleInfo(Arc::new(CssModuleInfoInner {
+            references: references.into(),
+            classes,
+            identifiers,
+            locally_composed_classes,
+            has_dynamic_classes,
+        }))
+    }
+
+    /// Creates a reference of the given `kind` for the specifier contained in
+    /// `token`, which may be a string or a raw URL.
+    ///
+    /// Returns `None` for specifiers that don't refer to a local file or a
+    /// package.
+    fn reference(&self, token: &CssSyntaxToken, kind: CssReferenceKind) -> Option<CssReference> {
+        let specifier = unquoted_text(token);
+        if !is_resolvable_specifier(&specifier) {
+            return None;
+        }
+
+        let resolved_path = match kind {
+            CssReferenceKind::Url => self.resolve_url(&specifier),
+            CssReferenceKind::Import | CssReferenceKind::Composes { .. } => {
+                self.resolve_stylesheet(&specifier)
+            }
+        };
+
+        Some(CssReference {
+            kind,
+            specifier: specifier.into(),
+            range: token.text_trimmed_range(),
+            resolved_path,
+        })
+    }
+
+    /// Resolves the `specifier` of an imported stylesheet.
+    ///
+    /// As in browsers, specifiers without a `./` prefix are relative to the
+    /// stylesheet, but packages are resolved as a fallback. The `~` prefix
+    /// that is used by webpack to refer to packages is supported too. SCSS
+    /// files may also refer to partials without their `_` prefix.
+    fn resolve_stylesheet(&self, specifier: &str) -> ResolvedPath {
+        let (extensions, default_files): (&[&str], &[&str]) = if self.is_scss {
+            (&["scss", "css"], &["index", "_index"])
+        } else {
+            (&["css"], &["index"])
+        };
+        let options = ResolveOptions {
+            condition_names: STYLE_CONDITION_NAMES,
+            default_files,
+            extensions,
+            ..Default::default()
+        };
+
+        if let Some(package_specifier) = specifier.strip_prefix('~') {
+            let resolved_path = resolve(package_specifier, self.directory, self.fs_proxy, &options);
+            return ResolvedPath::new(resolved_path);
+        }
+
+        let options = options.with_assume_relative();
+        let mut resolved_path = resolve(specifier, self.directory, self.fs_proxy, &options);
+        if self.is_scss && matches!(resolved_path, Err(ResolveError::NotFound)) {
+            let partial_specifier = match specifier.rsplit_once('/') {
+                Some((directory, file_name)) => format!("{directory}/_{file_name}"),
+                None => format!("_{specifier}"),
+            };
+            if let Ok(path) = resolve(&partial_specifier, self.directory, self.fs_proxy, &options) {
+                resolved_path = Ok(path);
+            }
+        }
+
+        ResolvedPath::new(resolved_path)
+    }
+
+    /// Resolves the `specifier` of a `url()` function, which must refer to an
+    /// existing file.
+    fn resolve_url(&self, specifier: &str) -> ResolvedPath {
+        let options = ResolveOptions::new()
+            .with_assume_relative()
+            .with_condition_names(STYLE_CONDITION_NAMES);
+        let specifier = specifier.strip_prefix('~').unwrap_or(specifier);
+        ResolvedPath::new(resolve(specifier, self.directory, self.fs_proxy, &options))
+    }
+}
+
+fn url_function_token(function: &CssUrlFunction) -> Option<CssSyntaxToken> {
+    match function.value()? {
+        AnyCssUrlValue::CssString(string) => string.value_token().ok(),
+        AnyCssUrlValue::CssUrlValueRaw(raw) => raw.value_token().ok(),
+    }
+}
+
+/// Returns the text of `token` without the surrounding quotes, if any.
+fn unquoted_text(token: &CssSyntaxToken) -> TokenText {
+    let text = token.token_text_trimmed();
+    let is_quoted = text.len() >= TextSize::from(2)
+        && (text.starts_with('"') && text.ends_with('"')
+            || text.starts_with('\'') && text.ends_with('\''));
+    if is_quoted {
+        let range = TextRange::new(TextSize::from(1), text.len() - TextSize::from(1));
+        text.slice(range)
+    } else {
+        text
+    }
+}
+
+/// Returns whether `specifier` may refer to a local file or to a package.
+///
+/// Specifiers with a scheme, such as `https:`, `data:`, or the `sass:`
+/// prefix of built-in SCSS modules, protocol-relative and root-relative URLs,
+/// fragments, and specifiers containing SCSS interpolation or variables
+/// cannot be resolved statically.
+fn is_resolvable_specifier(specifier: &str) -> bool {
+    let has_scheme = specifier.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.'))
+    });
+
+    !(specifier.is_empty()
+        || has_scheme
+        || specifier.starts_with('/')
+        || specifier.starts_with('#')
+        || specifier.contains('$')
+        || specifier.contains("#{"))
+}
+
+/// Returns whether the `selector` is inside a `:global()` selector.
+fn is_global_selector(selector: &CssSyntaxNode) -> bool {
+    selector
+        .ancestors()
+        .filter_map(CssPseudoClassFunctionSelector::cast)
+        .any(|function| {
+            function
+                .name()
+                .is_ok_and(|name| name.text_trimmed().eq_ignore_ascii_case("global"))
+        })
+}
@@ -8,9 +8,9 @@ use std::{borrow::Cow, collections::BTreeMap, ops::Deref, sync::Arc};
 
 use biome_js_syntax::{AnyJsExpression, AnyJsImportLike};
 use biome_js_type_info::{
-    BindingId, GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, ImportSymbol, ResolvedTypeData, ResolvedTypeId,
-    ScopeId, TypeData, TypeId, TypeReference, TypeReferenceQualifier, TypeResolver,
-    TypeResolverLevel,
+    BindingId, GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, GlobalsResolver, ImportSymbol, ResolvedTypeData,
+    ResolvedTypeId, ScopeId, TsLibSet, TypeData, TypeId, TypeReference, TypeReferenceQualifier,
+    TypeResolver, TypeResolverLevel,
 };
 use biome_jsdoc_comment::JsdocComment;
 use biome_resolver::ResolvedPath;
@@ -49,6 +49,51 @@ impl JsModuleInfo {
         }
     }
 
+    /// Returns the symbols that this module imports or re-exports from the
+    /// given `specifier`, sorted by name.
+    ///
+    /// Re-exports are described as `name as exported_name`, and imports of
+    /// all the symbols as `*`.
+    pub fn symbols_imported_from(&self, specifier: &str) -> Vec<String> {
+        let module_info = self.0.as_ref();
+        let symbol_name = |symbol: &ImportSymbol| match symbol {
+            ImportSymbol::Default => "default".to_string(),
+            ImportSymbol::Named(name) => name.to_string(),
+            ImportSymbol::All => "*".to_string(),
+        };
+
+        let imports = module_info
+            .static_imports
+            .values()
+            .filter(|import| import.specifier == specifier)
+            .map(|import| symbol_name(&import.symbol));
+        let reexports =
+            module_info
+                .exports
+                .iter()
+                .filter_map(|(exported_name, export)| match export {
+                    JsExport::Reexport(reexport) | JsExport::ReexportType(reexport)
+                        if reexport.import.specifier == specifier =>
+                    {
+                        Some(format!(
+                            "{} as {exported_name}",
+                            symbol_name(&reexport.import.symbol)
+                        ))
+                    }
+                    _ => None,
+                });
+        let blanket_reexports = module_info
+            .blanket_reexports
+            .iter()
+            .filter(|reexport| reexport.import.specifier == specifier)
+            .map(|reexport| symbol_name(&reexport.import.symbol));
+
+        let mut symbols: Vec<_> = imports.chain(reexports).chain(blanket_reexports).collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
     pub fn as_resolver(&self) -> &impl TypeResolver {
         self.0.as_ref()
     }
@@ -145,6 +190,13 @@ pub struct JsModuleInfoInner {
     /// assigning a name to them.
     pub blanket_reexports: Box<[JsReexport]>,
 
+    /// References to the classes of CSS Modules, through the bindings of
+    /// default and namespace imports of the CSS Modules.
+    ///
+    /// See [CssModuleClassReference] for the kinds of references that are
+    /// tracked.
+    pub css_module_class_references: Box<[CssModuleClassReference]>,
+
     /// Collection of all the declarations in the module.
     pub(crate) bindings: Box<[JsBindingData]>,
 
@@ -161,6 +213,9 @@ pub struct JsModuleInfoInner {
 
     /// Collection of all types in the module.
     pub(crate) types: Box<[TypeData]>,
+
+    /// TypeScript libs whose globals are available to the module.
+    pub(crate) libs: TsLibSet,
 }
 
 #[derive(Debug, Default)]
@@ -278,7 +333,7 @@ impl TypeResolver for JsModuleInfoInner {
                     JsOwnExport::Type(type_id) => Some(*type_id),
                 })
         } else {
-            GLOBAL_RESOLVER.resolve_qualifier(qualifier)
+            GlobalsResolver::with_libs(self.libs).resolve_qualifier(qualifier)
         }
     }
 
@@ -293,7 +348,7 @@ impl TypeResolver for JsModuleInfoInner {
                     JsOwnExport::Type(type_id) => Some(*type_id),
                 })
         } else {
-            GLOBAL_RESOLVER.resolve_type_of(identifier, scope_id)
+            GlobalsResolver::with_libs(self.libs).resolve_type_of(identifier, scope_id)
         }
     }
 
@@ -372,6 +427,26 @@ pub struct JsImport {
     pub symbol: ImportSymbol,
 }
 
+/// A reference to a class of a CSS Module, such as `styles.button`, where
+/// `styles` is imported from the CSS Module.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CssModuleClassReference {
+    /// Absolute path of the CSS Module.
+    pub resolved_path: ResolvedPath,
+
+    /// Name of the referenced class, if it can be determined statically.
+    ///
+    /// This is `None` if the import binding is used in any other way than a
+    /// static member access or a computed member access with a string
+    /// literal, for example when it is passed to a function or destructured.
+    /// Any class of the CSS Module may be referenced in that case.
+    pub class_name: Option<Text>,
+
+    /// Range of the member that names the class, or the range of the
+    /// reference to the import binding if `class_name` is `None`.
+    pub range: TextRange,
+}
+
 /// Information tracked for every "own" export.
 ///
 /// Exports can reference bindings, types of expressions or other references for
@@ -7,21 +7,23 @@ use std::{
 use biome_js_semantic::{SemanticEvent, SemanticEventExtractor};
 use biome_js_syntax::{
     AnyJsCombinedSpecifier, AnyJsDeclaration, AnyJsExportDefaultDeclaration, AnyJsExpression,
-    AnyJsImportClause, JsFormalParameter, JsIdentifierBinding, JsSyntaxKind, JsSyntaxNode,
-    JsSyntaxToken, JsVariableDeclaration, TsIdentifierBinding, TsTypeParameter,
-    TsTypeParameterName, inner_string_text,
+    AnyJsImportClause, JsComputedMemberExpression, JsFormalParameter, JsIdentifierBinding,
+    JsIdentifierExpression, JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsVariableDeclaration, TsIdentifierBinding, TsTypeParameter, TsTypeParameterName,
+    inner_string_text,
 };
 use biome_js_type_info::{
-    BindingId, FunctionParameter, GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, GenericTypeParameter, Module,
-    Namespace, Resolvable, ResolvedTypeData, ResolvedTypeId, ScopeId, TypeData, TypeId,
-    TypeImportQualifier, TypeMember, TypeMemberKind, TypeReference, TypeReferenceQualifier,
-    TypeResolver, TypeResolverLevel, TypeStore,
+    BindingId, FunctionParameter, GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, GenericTypeParameter,
+    GlobalsResolver, Module, Namespace, Resolvable, ResolvedTypeData, ResolvedTypeId, ScopeId,
+    TsLibSet, TypeData, TypeId, TypeImportQualifier, TypeMember, TypeMemberKind, TypeReference,
+    TypeReferenceQualifier, TypeResolver, TypeResolverLevel, TypeStore,
 };
 use biome_jsdoc_comment::JsdocComment;
 use biome_rowan::{AstNode, Text, TextRange, TextSize, TokenText};
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
 
+use crate::is_css_module_path;
 use crate::js_module_info::{
     binding::{JsBindingReference, JsBindingReferenceKind, JsDeclarationKind},
     scope::TsBindingReference,
@@ -29,8 +31,9 @@ use crate::js_module_info::{
 };
 
 use super::{
-    Exports, ImportSymbol, Imports, JsExport, JsImport, JsModuleInfo, JsModuleInfoInner,
-    JsOwnExport, JsReexport, ResolvedPath, binding::JsBindingData, scope::JsScopeData,
+    CssModuleClassReference, Exports, ImportSymbol, Imports, JsExport, JsImport, JsModuleInfo,
+    JsModuleInfoInner, JsOwnExport, JsReexport, ResolvedPath, binding::JsBindingData,
+    scope::JsScopeData,
 };
 
 /// Responsible for collecting all the information from which to build the
@@ -110,6 +113,9 @@ pub(super) struct JsModuleInfoCollector {
 
     /// Types collected in the module.
     types: TypeStore,
+
+    /// Resolver for the globals that are available to the module.
+    globals: GlobalsResolver,
 }
 
 /// Intermediary representation for an exported symbol.
@@ -139,6 +145,13 @@ pub(super) enum JsCollectedExport {
 }
 
 impl JsModuleInfoCollector {
+    pub fn with_libs(libs: TsLibSet) -> Self {
+        Self {
+            globals: GlobalsResolver::with_libs(libs),
+            ..Default::default()
+        }
+    }
+
     pub fn push_node(&mut self, node: &JsSyntaxNode) {
         use JsSyntaxKind::*;
         match node.kind() {
@@ -650,7 +663,7 @@ impl TypeResolver for JsModuleInfoCollector {
     fn resolve_qualifier(&self, qualifier: &TypeReferenceQualifier) -> Option<ResolvedTypeId> {
         let identifier = qualifier.path.first()?;
         let Some(binding_ref) = self.find_binding_in_scope(identifier, qualifier.scope_id) else {
-            return GLOBAL_RESOLVER.resolve_qualifier(qualifier);
+            return self.globals.resolve_qualifier(qualifier);
         };
 
         let binding_id = binding_ref.get_binding_id_for_qualifier(qualifier)?;
@@ -692,7 +705,7 @@ impl TypeResolver for JsModuleInfoCollector {
             };
         }
 
-        GLOBAL_RESOLVER.resolve_type_of(identifier, scope_id)
+        self.globals.resolve_type_of(identifier, scope_id)
     }
 
     fn resolve_expression(&mut self, _scope_id: ScopeId, expr: &AnyJsExpression) -> Cow<TypeData> {
@@ -776,7 +789,7 @@ impl JsModuleInfoBag {
                             let local_name = local_name.as_js_identifier_binding()?;
                             let local_name_token = local_name.name_token().ok()?;
                             let symbol_name = specifier
-                                .imported_name()
+                                .imported_symbol_name()
                                 .unwrap_or_else(|| local_name_token.clone())
                                 .token_text_trimmed();
                             self.static_imports.insert(
@@ -834,7 +847,7 @@ impl JsModuleInfoBag {
                     let local_name = local_name.as_js_identifier_binding()?;
                     let local_name_token = local_name.name_token().ok()?;
                     let symbol_name = specifier
-                        .imported_name()
+                        .imported_symbol_name()
                         .unwrap_or_else(|| local_name_token.clone())
                         .token_text_trimmed();
                     self.static_imports.insert(
@@ -984,6 +997,8 @@ impl JsModuleInfo {
         scope_by_range: Lapper<u32, ScopeId>,
     ) -> Self {
         let bag = JsModuleInfoBag::from_collector(&mut collector);
+        let css_module_class_references =
+            collector.css_module_class_references(&bag.static_imports);
 
         Self(Arc::new(JsModuleInfoInner {
             static_imports: Imports(bag.static_imports),
@@ -991,15 +1006,103 @@ impl JsModuleInfo {
             dynamic_import_paths: collector.dynamic_import_paths,
             exports: Exports(bag.exports),
             blanket_reexports: bag.blanket_reexports.into(),
+            css_module_class_references,
             bindings: collector.bindings.into(),
             expressions: collector.parsed_expressions,
             scopes: collector.scopes.into(),
             scope_by_range,
             types: collector.types.into(),
+            libs: collector.globals.libs(),
         }))
     }
 }
 
+impl JsModuleInfoCollector {
+    /// Collects the references to the classes of the CSS Modules that are
+    /// imported through the given `static_imports`.
+    ///
+    /// Only default and namespace imports are considered, since these are
+    /// the ones that give access to the classes of a CSS Module.
+    fn css_module_class_references(
+        &self,
+        static_imports: &BTreeMap<Text, JsImport>,
+    ) -> Box<[CssModuleClassReference]> {
+        let mut references = Vec::new();
+        for binding in &self.bindings {
+            if !binding.declaration_kind.is_import_declaration() {
+                continue;
+            }
+
+            let Some(import) = static_imports.get(&binding.name) else {
+                continue;
+            };
+            if !matches!(import.symbol, ImportSymbol::Default | ImportSymbol::All)
+                || !import
+                    .resolved_path
+                    .as_path()
+                    .is_some_and(is_css_module_path)
+            {
+                continue;
+            }
+
+            for reference in binding
+                .references
+                .iter()
+                .filter(|reference| reference.is_read())
+            {
+                let Some(node) = self.binding_node_by_start.get(&reference.range_start) else {
+                    continue;
+                };
+
+                let (class_name, range) = css_module_class_name(node)
+                    .unwrap_or_else(|| (None, node.text_trimmed_range()));
+                references.push(CssModuleClassReference {
+                    resolved_path: import.resolved_path.clone(),
+                    class_name,
+                    range,
+                });
+            }
+        }
+
+        references.into()
+    }
+}
+
+/// Returns the name and range of the class that is accessed through the
+/// reference `node`, as in `styles.button` or `styles["button"]`.
+///
+/// Returns `None` if the reference isn't the object of such a member access.
+fn css_module_class_name(node: &JsSyntaxNode) -> Option<(Option<Text>, TextRange)> {
+    let expression = JsIdentifierExpression::cast(node.parent()?)?;
+    let parent = expression.syntax().parent()?;
+    if let Some(member_expression) = JsStaticMemberExpression::cast_ref(&parent) {
+        if member_expression.object().ok()?.syntax() != expression.syntax() {
+            return None;
+        }
+
+        let member = member_expression.member().ok()?;
+        let name = member.as_js_name()?.value_token().ok()?;
+        Some((
+            Some(name.token_text_trimmed().into()),
+            name.text_trimmed_range(),
+        ))
+    } else if let Some(member_expression) = JsComputedMemberExpression::cast_ref(&parent) {
+        if member_expression.object().ok()?.syntax() != expression.syntax() {
+            return None;
+        }
+
+        let member = member_expression.member().ok()?;
+        let name = member
+            .as_any_js_literal_expression()?
+            .as_js_string_literal_expression()?
+            .inner_string_text()
+            .ok()?;
+        Some((Some(name.into()), member.range()))
+    } else {
+        None
+    }
+}
+
 fn find_jsdoc(node: &JsSyntaxNode) -> Option<JsdocComment> {
     match node.ancestors().find_map(biome_js_syntax::JsExport::cast) {
         Some(export) => JsdocComment::try_from(export.syntax()).ok(),
@@ -6,9 +6,10 @@ use std::{
 
 use biome_js_syntax::AnyJsExpression;
 use biome_js_type_info::{
-    GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, ImportSymbol, ModuleId, Resolvable, ResolvedTypeData,
-    ResolvedTypeId, ResolverId, ScopeId, Type, TypeData, TypeId, TypeImportQualifier,
-    TypeReference, TypeReferenceQualifier, TypeResolver, TypeResolverLevel, TypeStore,
+    GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, GlobalsResolver, ImportSymbol, ModuleId, Resolvable,
+    ResolvedTypeData, ResolvedTypeId, ResolverId, ScopeId, Type, TypeData, TypeId,
+    TypeImportQualifier, TypeReference, TypeReferenceQualifier, TypeResolver, TypeResolverLevel,
+    TypeStore,
 };
 use biome_resolver::ResolvedPath;
 use biome_rowan::{AstNode, Text, TextRange};
@@ -282,7 +283,7 @@ impl TypeResolver for ScopedResolver {
     fn resolve_type_of(&self, identifier: &Text, scope_id: ScopeId) -> Option<ResolvedTypeId> {
         let module = &self.modules[0];
         let Some(binding_ref) = module.find_binding_in_scope(identifier, scope_id) else {
-            return GLOBAL_RESOLVER.resolve_type_of(identifier, scope_id);
+            return GlobalsResolver::with_libs(module.libs).resolve_type_of(identifier, scope_id);
         };
 
         let binding = module.binding(binding_ref.value_ty_or_ty());
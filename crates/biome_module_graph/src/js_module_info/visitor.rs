@@ -5,7 +5,7 @@ use biome_js_syntax::{
     JsExportFromClause, JsExportNamedFromClause, JsExportNamedSpecifierList, JsIdentifierBinding,
     JsVariableDeclaratorList, TsExportAssignmentClause, unescape_js_string,
 };
-use biome_js_type_info::{ImportSymbol, ScopeId, TypeData, TypeReference, TypeResolver};
+use biome_js_type_info::{ImportSymbol, ScopeId, TsLibSet, TypeData, TypeReference, TypeResolver};
 use biome_jsdoc_comment::JsdocComment;
 use biome_resolver::{ResolveOptions, resolve};
 use biome_rowan::{AstNode, TokenText, WalkEvent};
@@ -21,20 +21,27 @@ use super::{ResolvedPath, collector::JsModuleInfoCollector};
 pub(crate) struct JsModuleVisitor<'a> {
     root: AnyJsRoot,
     directory: &'a Utf8Path,
+    libs: TsLibSet,
     fs_proxy: &'a ModuleGraphFsProxy<'a>,
 }
 
 impl<'a> JsModuleVisitor<'a> {
-    pub fn new(root: AnyJsRoot, directory: &'a Utf8Path, fs_proxy: &'a ModuleGraphFsProxy) -> Self {
+    pub fn new(
+        root: AnyJsRoot,
+        directory: &'a Utf8Path,
+        libs: TsLibSet,
+        fs_proxy: &'a ModuleGraphFsProxy,
+    ) -> Self {
         Self {
             root,
             directory,
+            libs,
             fs_proxy,
         }
     }
 
     pub fn collect_info(self) -> JsModuleInfo {
-        let mut collector = JsModuleInfoCollector::default();
+        let mut collector = JsModuleInfoCollector::with_libs(self.libs);
 
         let iter = self.root.syntax().preorder();
         for event in iter {
@@ -415,7 +422,7 @@ impl<'a> JsModuleVisitor<'a> {
 
     fn resolved_path_from_specifier(&self, specifier: &str) -> ResolvedPath {
         let options = ResolveOptions {
-            condition_names: &["types", "default"],
+            condition_names: &["types", "import", "default"],
             default_files: &["index"],
             extensions: SUPPORTED_TYPE_EXTENSIONS,
             resolve_node_builtins: true,
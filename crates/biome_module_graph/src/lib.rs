@@ -1,5 +1,6 @@
 #![deny(clippy::use_self)]
 
+mod css_module_info;
 mod format_module_graph;
 mod js_module_info;
 mod module_graph;
@@ -7,7 +8,11 @@ mod module_graph;
 pub use biome_js_type_info::ImportSymbol;
 pub use biome_resolver::ResolvedPath;
 
+pub use css_module_info::{
+    CssModuleInfo, CssReference, CssReferenceKind, dashes_to_camel_case, is_css_module_path,
+};
 pub use js_module_info::{
-    JsExport, JsImport, JsModuleInfo, JsOwnExport, JsReexport, ScopedResolver,
+    CssModuleClassReference, JsExport, JsImport, JsModuleInfo, JsOwnExport, JsReexport,
+    ScopedResolver,
 };
 pub use module_graph::{ModuleGraph, SUPPORTED_EXTENSIONS, SUPPORTED_TYPE_EXTENSIONS};
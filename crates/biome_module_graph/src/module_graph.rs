@@ -8,19 +8,27 @@
 
 mod fs_proxy;
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, RwLock};
 
+use biome_css_syntax::CssRoot;
 use biome_fs::BiomePath;
-use biome_js_syntax::AnyJsRoot;
-use biome_js_type_info::ImportSymbol;
+use biome_js_parser::{JsParserOptions, parse};
+use biome_js_syntax::{AnyJsRoot, JsFileSource};
+use biome_js_type_info::{ImportSymbol, ScopeId};
 use biome_jsdoc_comment::JsdocComment;
 use biome_project_layout::ProjectLayout;
-use biome_resolver::{FsWithResolverProxy, PathInfo};
+use biome_resolver::{FsWithResolverProxy, PathInfo, ResolveError, ResolvedPath};
+use biome_rowan::{Text, TextRange};
 use camino::{Utf8Path, Utf8PathBuf};
 use papaya::{HashMap, HashMapRef, LocalGuard};
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHasher};
 
-use crate::{JsExport, JsModuleInfo, JsOwnExport, js_module_info::JsModuleVisitor};
+use crate::{
+    CssModuleInfo, CssReferenceKind, JsExport, JsImport, JsModuleInfo, JsOwnExport,
+    css_module_info::CssModuleVisitor, js_module_info::JsModuleVisitor,
+};
 
 pub(crate) use fs_proxy::ModuleGraphFsProxy;
 
@@ -48,9 +56,27 @@ pub struct ModuleGraph {
     //       `Js(JsModuleInfo)` and those for other languages.
     data: HashMap<Utf8PathBuf, JsModuleInfo, FxBuildHasher>,
 
+    /// Cached stylesheet info per file.
+    css_data: HashMap<Utf8PathBuf, CssModuleInfo, FxBuildHasher>,
+
     /// Cache that tracks the presence of files, directories, and symlinks
     /// across the project.
     path_info: HashMap<Utf8PathBuf, Option<PathInfo>>,
+
+    /// Indexes derived from the imports of all the modules. They are computed
+    /// lazily, and cleared whenever the graph is updated.
+    indexes: RwLock<ImportIndexes>,
+}
+
+#[derive(Debug, Default)]
+struct ImportIndexes {
+    /// Maps the path of every imported module to the paths of the modules
+    /// that import or re-export from it.
+    importers: Option<Arc<FxHashMap<Utf8PathBuf, Vec<Utf8PathBuf>>>>,
+
+    /// The results of [ModuleGraph::reachable_paths], keyed by the hash of
+    /// the key of their entry points.
+    reachable_paths: FxHashMap<u64, Arc<BTreeSet<Utf8PathBuf>>>,
 }
 
 impl ModuleGraph {
@@ -65,12 +91,28 @@ impl ModuleGraph {
         self.data.pin()
     }
 
+    /// Returns the stylesheet info, such as its imports and declared classes,
+    /// for the given `path`.
+    pub fn css_module_info_for_path(&self, path: &Utf8Path) -> Option<CssModuleInfo> {
+        self.css_data.pin().get(path).cloned()
+    }
+
+    /// Returns the stylesheet data of the module graph.
+    pub fn css_data(&self) -> HashMapRef<Utf8PathBuf, CssModuleInfo, FxBuildHasher, LocalGuard> {
+        self.css_data.pin()
+    }
+
     /// Updates the module graph to add, update, or remove files.
     ///
     /// Only JavaScript/TypeScript files need to be provided as part of
     /// `added_or_updated_paths` and `removed_paths`. Manifests are expected to
     /// be resolved through the `project_layout`. As such, the `project_layout`
     /// must have been updated before calling this method.
+    ///
+    /// Type declarations inside dependencies, such as those in `node_modules`
+    /// and `@types` packages, don't need to be provided either. They are
+    /// loaded from the file system when they are imported by any of the
+    /// `added_or_updated_paths`.
     pub fn update_graph_for_js_paths(
         &self,
         fs: &dyn FsWithResolverProxy,
@@ -78,39 +120,108 @@ impl ModuleGraph {
         added_or_updated_paths: &[(&BiomePath, AnyJsRoot)],
         removed_paths: &[&BiomePath],
     ) {
-        // Make sure all directories are registered for the added/updated paths.
-        let path_info = self.path_info.pin();
-        for (path, _) in added_or_updated_paths {
-            let mut parent = path.parent();
-            while let Some(path) = parent {
-                let mut inserted = false;
-                path_info.get_or_insert_with(path.to_path_buf(), || {
-                    inserted = true;
-                    fs.path_info(path).ok()
-                });
-                if !inserted {
-                    break;
-                }
-                parent = path.parent();
-            }
-        }
+        self.register_parent_directories(fs, added_or_updated_paths.iter().map(|(path, _)| *path));
 
         let fs_proxy = ModuleGraphFsProxy::new(fs, self, project_layout);
 
         // Traverse all the added and updated paths and insert their resolved
         // imports.
         let imports = self.data.pin();
+        let mut declaration_paths = Vec::new();
         for (path, root) in added_or_updated_paths {
             let directory = path.parent().unwrap_or(path);
-            let visitor = JsModuleVisitor::new(root.clone(), directory, &fs_proxy);
-            imports.insert(path.to_path_buf(), visitor.collect_info());
+            let libs = fs_proxy.ts_libs_for_path(path);
+            let visitor = JsModuleVisitor::new(root.clone(), directory, libs, &fs_proxy);
+            let module_info = visitor.collect_info();
+            declaration_paths.extend(dependency_declaration_paths(&module_info));
+            imports.insert(path.to_path_buf(), module_info);
+        }
+
+        // Type declarations from dependencies are not scanned, so we load
+        // those that are imported by the added and updated paths, as well as
+        // those imported by the declarations themselves.
+        while let Some(path) = declaration_paths.pop() {
+            if imports.contains_key(&path) {
+                continue;
+            }
+
+            let Ok(content) = fs.read_file_from_path(&path) else {
+                continue;
+            };
+
+            let parsed = parse(&content, JsFileSource::d_ts(), JsParserOptions::default());
+            let directory = path.parent().unwrap_or(&path);
+            let libs = fs_proxy.ts_libs_for_path(&path);
+            let visitor = JsModuleVisitor::new(parsed.tree(), directory, libs, &fs_proxy);
+            let module_info = visitor.collect_info();
+            declaration_paths.extend(dependency_declaration_paths(&module_info));
+            imports.insert(path, module_info);
         }
 
         // Clean up removed paths.
+        let path_info = self.path_info.pin();
         for removed_path in removed_paths {
             imports.remove(removed_path.as_path());
             path_info.remove(removed_path.as_path());
         }
+
+        *self.indexes.write().unwrap() = ImportIndexes::default();
+    }
+
+    /// Updates the module graph to add, update, or remove stylesheets.
+    ///
+    /// Only CSS and SCSS files need to be provided as part of
+    /// `added_or_updated_paths` and `removed_paths`. The references of the
+    /// stylesheets are resolved against the file system, and packages are
+    /// resolved through the `project_layout`.
+    pub fn update_graph_for_css_paths(
+        &self,
+        fs: &dyn FsWithResolverProxy,
+        project_layout: &ProjectLayout,
+        added_or_updated_paths: &[(&BiomePath, CssRoot)],
+        removed_paths: &[&BiomePath],
+    ) {
+        self.register_parent_directories(fs, added_or_updated_paths.iter().map(|(path, _)| *path));
+
+        let fs_proxy = ModuleGraphFsProxy::new(fs, self, project_layout);
+
+        let css_data = self.css_data.pin();
+        for (path, root) in added_or_updated_paths {
+            let directory = path.parent().unwrap_or(path);
+            let is_scss = path.extension() == Some("scss");
+            let visitor = CssModuleVisitor::new(root.clone(), directory, is_scss, &fs_proxy);
+            css_data.insert(path.to_path_buf(), visitor.collect_info());
+        }
+
+        let path_info = self.path_info.pin();
+        for removed_path in removed_paths {
+            css_data.remove(removed_path.as_path());
+            path_info.remove(removed_path.as_path());
+        }
+    }
+
+    /// Makes sure all the parent directories of the given `paths` are
+    /// registered.
+    fn register_parent_directories<'a>(
+        &self,
+        fs: &dyn FsWithResolverProxy,
+        paths: impl Iterator<Item = &'a BiomePath>,
+    ) {
+        let path_info = self.path_info.pin();
+        for path in paths {
+            let mut parent = path.parent();
+            while let Some(path) = parent {
+                let mut inserted = false;
+                path_info.get_or_insert_with(path.to_path_buf(), || {
+                    inserted = true;
+                    fs.path_info(path).ok()
+                });
+                if !inserted {
+                    break;
+                }
+                parent = path.parent();
+            }
+        }
     }
 
     pub fn get_or_insert_path_info(
@@ -159,6 +270,589 @@ impl ModuleGraph {
             },
         )
     }
+
+    /// Finds the declaration of the binding at `range` in the module at
+    /// `path`.
+    ///
+    /// If the binding is an import, the declaration of the imported symbol is
+    /// looked up instead, following re-exports if necessary. Otherwise, the
+    /// binding is its own declaration.
+    ///
+    /// Returns the path of the module that declares the symbol, together with
+    /// the range of its binding, or `None` if there is no binding at `range`.
+    pub fn find_binding_declaration(
+        &self,
+        path: &Utf8Path,
+        range: TextRange,
+    ) -> Option<(Utf8PathBuf, TextRange)> {
+        let data = self.data.pin();
+        let module = data.get(path)?;
+        let binding = module
+            .bindings
+            .iter()
+            .find(|binding| binding.range == range)?;
+
+        if binding.declaration_kind.is_import_declaration() {
+            if let Some(import) = module.static_imports.get(&binding.name) {
+                if let (Some(import_path), Some(name)) = (
+                    import.resolved_path.as_path(),
+                    import_symbol_name(&import.symbol),
+                ) {
+                    let mut seen_paths = BTreeSet::new();
+                    if let Some(declaration) = find_exported_symbol_declaration_with_seen_paths(
+                        &data,
+                        import_path,
+                        name,
+                        &mut seen_paths,
+                    ) {
+                        return Some(declaration);
+                    }
+                }
+            }
+        }
+
+        Some((path.to_path_buf(), range))
+    }
+
+    /// Finds all the references to the binding at `range` in the module at
+    /// `path`, across all the modules in the graph.
+    ///
+    /// The references are collected starting from the declaration of the
+    /// binding, as returned by [Self::find_binding_declaration()]. This
+    /// includes the declaration itself, its references inside the declaring
+    /// module, and for every module that imports it, the import binding
+    /// together with its references. Modules that import the symbol through
+    /// a re-export are included as well.
+    pub fn find_binding_references(
+        &self,
+        path: &Utf8Path,
+        range: TextRange,
+    ) -> Vec<(Utf8PathBuf, TextRange)> {
+        let Some((declaration_path, declaration_range)) =
+            self.find_binding_declaration(path, range)
+        else {
+            return Vec::new();
+        };
+
+        let data = self.data.pin();
+        let Some(module) = data.get(declaration_path.as_path()) else {
+            return Vec::new();
+        };
+        let Some(binding) = module
+            .bindings
+            .iter()
+            .find(|binding| binding.range == declaration_range)
+        else {
+            return Vec::new();
+        };
+
+        let mut references = vec![(declaration_path.clone(), binding.range)];
+        references.extend(binding.references.iter().map(|reference| {
+            (
+                declaration_path.clone(),
+                TextRange::at(reference.range_start, binding.range.len()),
+            )
+        }));
+
+        let exported_names =
+            module
+                .exports
+                .iter()
+                .filter_map(|(name, export)| match export.as_own_export()? {
+                    JsOwnExport::Binding(binding_id)
+                        if module.bindings[binding_id.index()].range == declaration_range =>
+                    {
+                        Some(name)
+                    }
+                    _ => None,
+                });
+        for exported_name in exported_names {
+            collect_references_to_exported_symbol(
+                &data,
+                &declaration_path,
+                exported_name.text(),
+                &mut references,
+            );
+        }
+
+        references.sort_by(|(a_path, a_range), (b_path, b_range)| {
+            a_path
+                .cmp(b_path)
+                .then_with(|| a_range.start().cmp(&b_range.start()))
+        });
+        references.dedup();
+        references
+    }
+
+    /// Finds the modules that refer to the binding at `range` in the module at
+    /// `path` by its name.
+    ///
+    /// This is the case when the binding is exported under its own name, such
+    /// as with `export function foo() {}` or `export { foo }`, because renaming
+    /// the binding also renames the export. Every module that statically
+    /// imports or re-exports from `path` is returned, together with the
+    /// specifiers through which it does so.
+    ///
+    /// Blanket re-exports such as `export * from "./foo"` are followed, so the
+    /// modules importing from such a barrel module are returned as well, unless
+    /// the barrel shadows the name with an export of its own.
+    ///
+    /// Returns an empty list if the binding is not exported under its own
+    /// name.
+    pub fn find_modules_importing_binding_by_name(
+        &self,
+        path: &Utf8Path,
+        range: TextRange,
+    ) -> Vec<(Utf8PathBuf, Vec<Text>)> {
+        let data = self.data.pin();
+        let Some(module) = data.get(path) else {
+            return Vec::new();
+        };
+        let Some(binding) = module
+            .bindings
+            .iter()
+            .find(|binding| binding.range == range)
+        else {
+            return Vec::new();
+        };
+
+        let is_exported_by_name = module.exports.get(&binding.name).is_some_and(|export| {
+            matches!(
+                export.as_own_export(),
+                Some(JsOwnExport::Binding(binding_id))
+                    if module.bindings[binding_id.index()].range == range
+            )
+        });
+        if !is_exported_by_name {
+            return Vec::new();
+        }
+
+        let name = binding.name.clone();
+        let mut modules: BTreeMap<Utf8PathBuf, Vec<Text>> = BTreeMap::new();
+        let mut seen_paths = BTreeSet::from([path.to_path_buf()]);
+        let mut queue = vec![path.to_path_buf()];
+        while let Some(exporting_path) = queue.pop() {
+            for (importing_path, importing_module) in data.iter() {
+                let specifiers = importing_module
+                    .static_import_paths
+                    .iter()
+                    .filter(|(_, resolved_path)| {
+                        resolved_path.as_path() == Some(exporting_path.as_path())
+                    })
+                    .map(|(specifier, _)| specifier.clone());
+                let mut specifiers = specifiers.peekable();
+                if specifiers.peek().is_none() {
+                    continue;
+                }
+                modules
+                    .entry(importing_path.clone())
+                    .or_default()
+                    .extend(specifiers);
+
+                let is_barrel = importing_module.exports.get(&name).is_none()
+                    && importing_module.blanket_reexports.iter().any(|reexport| {
+                        reexport.import.resolved_path.as_path() == Some(exporting_path.as_path())
+                    });
+                if is_barrel && seen_paths.insert(importing_path.clone()) {
+                    queue.push(importing_path.clone());
+                }
+            }
+        }
+
+        modules
+            .into_iter()
+            .map(|(path, mut specifiers)| {
+                specifiers.sort();
+                specifiers.dedup();
+                (path, specifiers)
+            })
+            .collect()
+    }
+
+    /// Returns the paths of all the modules that the module at `path` imports,
+    /// either directly or transitively.
+    ///
+    /// Only imports that could be resolved are followed. The module at `path`
+    /// is only included in the result if it's part of an import cycle.
+    pub fn transitive_dependencies(&self, path: &Utf8Path) -> BTreeSet<Utf8PathBuf> {
+        let data = self.data.pin();
+        let mut dependencies = BTreeSet::new();
+        let mut queue = vec![path.to_path_buf()];
+        while let Some(path) = queue.pop() {
+            let Some(module) = data.get(&path) else {
+                continue;
+            };
+
+            for resolved_path in module.all_import_paths() {
+                if let Some(dependency) = resolved_path.as_path() {
+                    if dependencies.insert(dependency.to_path_buf()) {
+                        queue.push(dependency.to_path_buf());
+                    }
+                }
+            }
+        }
+
+        dependencies
+    }
+
+    /// Returns the paths of the modules for which `is_entry_point` returns
+    /// `true`, together with the paths of all the modules they import, either
+    /// directly or transitively.
+    ///
+    /// The result is cached until the graph is updated. `key` must identify
+    /// the entry points that `is_entry_point` matches, so that the result can
+    /// be shared between the callers that use the same entry points.
+    pub fn reachable_paths(
+        &self,
+        key: impl Hash,
+        is_entry_point: impl Fn(&Utf8Path) -> bool,
+    ) -> Arc<BTreeSet<Utf8PathBuf>> {
+        let mut hasher = FxHasher::default();
+        key.hash(&mut hasher);
+        let key = hasher.finish();
+        if let Some(reachable) = self.indexes.read().unwrap().reachable_paths.get(&key) {
+            return reachable.clone();
+        }
+
+        let data = self.data.pin();
+        let mut reachable = BTreeSet::new();
+        let mut queue: Vec<_> = data
+            .keys()
+            .filter(|path| is_entry_point(path))
+            .cloned()
+            .collect();
+        while let Some(path) = queue.pop() {
+            let Some(module) = data.get(&path) else {
+                continue;
+            };
+
+            for resolved_path in module.all_import_paths() {
+                if let Some(dependency) = resolved_path.as_path() {
+                    if !reachable.contains(dependency) {
+                        queue.push(dependency.to_path_buf());
+                    }
+                }
+            }
+
+            reachable.insert(path);
+        }
+
+        let reachable = Arc::new(reachable);
+        self.indexes
+            .write()
+            .unwrap()
+            .reachable_paths
+            .insert(key, reachable.clone());
+        reachable
+    }
+
+    /// Returns the index of the modules that import or re-export from every
+    /// module, building it if the graph was updated since it was last built.
+    fn importers(&self) -> Arc<FxHashMap<Utf8PathBuf, Vec<Utf8PathBuf>>> {
+        if let Some(importers) = &self.indexes.read().unwrap().importers {
+            return importers.clone();
+        }
+
+        let mut importers: FxHashMap<Utf8PathBuf, Vec<Utf8PathBuf>> = FxHashMap::default();
+        for (path, module) in self.data.pin().iter() {
+            for resolved_path in module.all_import_paths() {
+                if let Some(imported_path) = resolved_path.as_path() {
+                    let paths = importers.entry(imported_path.to_path_buf()).or_default();
+                    if paths.last() != Some(path) {
+                        paths.push(path.clone());
+                    }
+                }
+            }
+        }
+
+        let importers = Arc::new(importers);
+        self.indexes.write().unwrap().importers = Some(importers.clone());
+        importers
+    }
+
+    /// Returns the classes of the CSS Module at `path` that are referenced by
+    /// other modules.
+    ///
+    /// Classes are referenced by JavaScript modules through member accesses
+    /// on the default or namespace imports of the CSS Module, such as
+    /// `styles.button`, and by other CSS Modules through `composes`. The
+    /// names are returned as they are referenced, so they may be in camel
+    /// case.
+    ///
+    /// Returns `None` if the CSS Module isn't statically imported by any
+    /// JavaScript module, or if any of its classes may be referenced, for
+    /// instance because it is imported dynamically or because an import
+    /// binding is passed to a function.
+    pub fn referenced_css_module_classes(&self, path: &Utf8Path) -> Option<BTreeSet<Text>> {
+        let mut is_imported = false;
+        let mut classes = BTreeSet::new();
+        for module in self.data.pin().values() {
+            let resolves_to_path =
+                |resolved_path: &ResolvedPath| resolved_path.as_path() == Some(path);
+            if module.dynamic_import_paths.values().any(resolves_to_path) {
+                return None;
+            }
+
+            if module.static_import_paths.values().any(resolves_to_path) {
+                is_imported = true;
+            }
+
+            for reference in module
+                .css_module_class_references
+                .iter()
+                .filter(|reference| resolves_to_path(&reference.resolved_path))
+            {
+                classes.insert(reference.class_name.clone()?);
+            }
+        }
+
+        if !is_imported {
+            return None;
+        }
+
+        for module in self.css_data.pin().values() {
+            for reference in module.references.iter() {
+                if let CssReferenceKind::Composes { class_names } = &reference.kind {
+                    if reference.resolved_path.as_path() == Some(path) {
+                        classes.extend(class_names.iter().cloned());
+                    }
+                }
+            }
+        }
+
+        Some(classes)
+    }
+
+    /// Returns whether the symbol exported as `symbol_name` by the module at
+    /// `path` is imported by any other module.
+    ///
+    /// A symbol that is re-exported by another module is used if the
+    /// re-export is used in turn. Namespace imports, dynamic imports, and
+    /// `require()` calls are considered to use all the exports of a module.
+    ///
+    /// Exports of the modules for which `is_entry_point` returns `true` are
+    /// always considered to be used, since they are imported from outside the
+    /// project.
+    pub fn is_export_used(
+        &self,
+        path: &Utf8Path,
+        symbol_name: &str,
+        is_entry_point: impl Fn(&Utf8Path) -> bool,
+    ) -> bool {
+        let data = self.data.pin();
+        let importers = self.importers();
+        let mut seen_exports = BTreeSet::new();
+        let mut queue = vec![(path.to_path_buf(), symbol_name.to_string())];
+
+        while let Some((exporting_path, exported_name)) = queue.pop() {
+            if is_entry_point(&exporting_path) {
+                return true;
+            }
+
+            if !seen_exports.insert((exporting_path.clone(), exported_name.clone())) {
+                continue;
+            }
+
+            let is_exporting_path = |import: &JsImport| {
+                import.resolved_path.as_path() == Some(exporting_path.as_path())
+            };
+
+            let importing_modules = importers
+                .get(&exporting_path)
+                .into_iter()
+                .flatten()
+                .filter_map(|module_path| Some((module_path, data.get(module_path)?)));
+            for (module_path, module) in importing_modules {
+                if module_path == &exporting_path {
+                    continue;
+                }
+
+                let is_imported = module.static_imports.values().any(|import| {
+                    is_exporting_path(import)
+                        && import_symbol_name(&import.symbol)
+                            .is_none_or(|name| name == exported_name)
+                }) || module
+                    .dynamic_import_paths
+                    .values()
+                    .any(|resolved_path| resolved_path.as_path() == Some(exporting_path.as_path()));
+                if is_imported {
+                    return true;
+                }
+
+                for (name, export) in module.exports.iter() {
+                    if let JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) = export
+                    {
+                        if is_exporting_path(&reexport.import)
+                            && import_symbol_name(&reexport.import.symbol)
+                                .is_none_or(|name| name == exported_name)
+                        {
+                            queue.push((module_path.clone(), name.to_string()));
+                        }
+                    }
+                }
+
+                if exported_name != "default"
+                    && module
+                        .blanket_reexports
+                        .iter()
+                        .any(|reexport| is_exporting_path(&reexport.import))
+                {
+                    queue.push((module_path.clone(), exported_name.clone()));
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns whether the module at `path` contains imports that couldn't be
+    /// resolved.
+    ///
+    /// Imports of Node.js built-in modules are not considered unresolved.
+    pub fn has_unresolved_imports(&self, path: &Utf8Path) -> bool {
+        self.data.pin().get(path).is_some_and(|module| {
+            module.all_import_paths().any(|resolved_path| {
+                resolved_path
+                    .error()
+                    .is_some_and(|error| *error != ResolveError::NodeBuiltIn)
+            })
+        })
+    }
+}
+
+/// Returns the paths of the type declarations inside dependencies that are
+/// imported by the given `module`.
+fn dependency_declaration_paths(module: &JsModuleInfo) -> impl Iterator<Item = Utf8PathBuf> {
+    module.all_import_paths().filter_map(|resolved_path| {
+        let path = BiomePath::new(resolved_path.as_path()?);
+        (path.is_dependency() && path.is_type_declaration()).then(|| path.to_path_buf())
+    })
+}
+
+/// Collects the references to the symbol exported as `symbol_name` by the
+/// module at `path`, across all the modules that import it.
+///
+/// References inside the module at `path` itself are not included.
+fn collect_references_to_exported_symbol(
+    data: &HashMapRef<Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard>,
+    path: &Utf8Path,
+    symbol_name: &str,
+    references: &mut Vec<(Utf8PathBuf, TextRange)>,
+) {
+    let mut seen_exports = BTreeSet::new();
+    let mut queue = vec![(path.to_path_buf(), symbol_name.to_string())];
+
+    while let Some((exporting_path, exported_name)) = queue.pop() {
+        if !seen_exports.insert((exporting_path.clone(), exported_name.clone())) {
+            continue;
+        }
+
+        let imports_symbol = |import: &JsImport| {
+            import.resolved_path.as_path() == Some(exporting_path.as_path())
+                && import_symbol_name(&import.symbol) == Some(exported_name.as_str())
+        };
+
+        for (module_path, module) in data.iter() {
+            for (local_name, import) in module.static_imports.iter() {
+                if !imports_symbol(import) {
+                    continue;
+                }
+
+                let Some(binding) = module.bindings.iter().find(|binding| {
+                    binding.scope_id == ScopeId::GLOBAL
+                        && binding.declaration_kind.is_import_declaration()
+                        && &binding.name == local_name
+                }) else {
+                    continue;
+                };
+
+                references.push((module_path.clone(), binding.range));
+                references.extend(binding.references.iter().map(|reference| {
+                    (
+                        module_path.clone(),
+                        TextRange::at(reference.range_start, binding.range.len()),
+                    )
+                }));
+            }
+
+            for (name, export) in module.exports.iter() {
+                if let JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) = export {
+                    if imports_symbol(&reexport.import) {
+                        queue.push((module_path.clone(), name.to_string()));
+                    }
+                }
+            }
+
+            if exported_name != "default"
+                && module.blanket_reexports.iter().any(|reexport| {
+                    reexport.import.resolved_path.as_path() == Some(exporting_path.as_path())
+                })
+            {
+                queue.push((module_path.clone(), exported_name.clone()));
+            }
+        }
+    }
+}
+
+fn find_exported_symbol_declaration_with_seen_paths<'a>(
+    data: &'a HashMapRef<Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard>,
+    path: &'a Utf8Path,
+    symbol_name: &str,
+    seen_paths: &mut BTreeSet<&'a Utf8Path>,
+) -> Option<(Utf8PathBuf, TextRange)> {
+    if !seen_paths.insert(path) {
+        return None;
+    }
+
+    let module = data.get(path)?;
+    match module.exports.get(symbol_name) {
+        Some(JsExport::Own(own_export) | JsExport::OwnType(own_export)) => match own_export {
+            JsOwnExport::Binding(binding_id) => {
+                let binding = &module.bindings[binding_id.index()];
+                if binding.declaration_kind.is_import_declaration() {
+                    // The module exports a binding it imported itself, so we
+                    // continue looking in the module it was imported from.
+                    if let Some(import) = module.static_imports.get(&binding.name) {
+                        if let (Some(path), Some(name)) = (
+                            import.resolved_path.as_path(),
+                            import_symbol_name(&import.symbol),
+                        ) {
+                            if let Some(declaration) =
+                                find_exported_symbol_declaration_with_seen_paths(
+                                    data, path, name, seen_paths,
+                                )
+                            {
+                                return Some(declaration);
+                            }
+                        }
+                    }
+                }
+
+                Some((path.to_path_buf(), binding.range))
+            }
+            JsOwnExport::Type(_) => None,
+        },
+        Some(JsExport::Reexport(reexport) | JsExport::ReexportType(reexport)) => {
+            let path = reexport.import.resolved_path.as_path()?;
+            let name = import_symbol_name(&reexport.import.symbol)?;
+            find_exported_symbol_declaration_with_seen_paths(data, path, name, seen_paths)
+        }
+        None if symbol_name == "default" => None,
+        None => module.blanket_reexports.iter().find_map(|reexport| {
+            let path = reexport.import.resolved_path.as_path()?;
+            find_exported_symbol_declaration_with_seen_paths(data, path, symbol_name, seen_paths)
+        }),
+    }
+}
+
+/// Returns the name under which the given `symbol` is exported by the module
+/// it's imported from, or `None` for namespace imports.
+fn import_symbol_name(symbol: &ImportSymbol) -> Option<&str> {
+    match symbol {
+        ImportSymbol::Default => Some("default"),
+        ImportSymbol::Named(name) => Some(name.text()),
+        ImportSymbol::All => None,
+    }
 }
 
 fn find_exported_symbol_with_seen_paths<'a>(
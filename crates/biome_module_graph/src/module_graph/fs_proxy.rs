@@ -1,3 +1,4 @@
+use biome_js_type_info::TsLibSet;
 use biome_package::{PackageJson, TsConfigJson};
 use biome_project_layout::ProjectLayout;
 use biome_resolver::{FsWithResolverProxy, PathInfo, ResolveError, ResolverFsProxy};
@@ -23,6 +24,19 @@ impl<'a> ModuleGraphFsProxy<'a> {
             project_layout,
         }
     }
+
+    /// Returns the TypeScript libs whose globals are available to the module
+    /// at the given `path`, based on the closest `tsconfig.json`.
+    ///
+    /// Modules without a `tsconfig.json` have all libs available.
+    pub fn ts_libs_for_path(&self, path: &Utf8Path) -> TsLibSet {
+        self.project_layout
+            .find_tsconfig_json_for_path(path)
+            .map_or_else(TsLibSet::default, |tsconfig| {
+                let options = &tsconfig.compiler_options;
+                TsLibSet::from_compiler_options(options.lib.as_deref(), options.target.as_deref())
+            })
+    }
 }
 
 impl ResolverFsProxy for ModuleGraphFsProxy<'_> {
@@ -14,12 +14,12 @@ use biome_json_parser::{JsonParserOptions, parse_json};
 use biome_json_value::{JsonObject, JsonString};
 use biome_module_graph::JsExport;
 use biome_module_graph::{
-    ImportSymbol, JsImport, JsReexport, ModuleGraph, ResolvedPath, ScopedResolver,
+    CssReferenceKind, ImportSymbol, JsImport, JsReexport, ModuleGraph, ResolvedPath, ScopedResolver,
 };
 use biome_package::{Dependencies, PackageJson};
 use biome_project_layout::ProjectLayout;
-use biome_rowan::Text;
-use biome_test_utils::get_added_paths;
+use biome_rowan::{Text, TextRange, TextSize};
+use biome_test_utils::{get_added_css_paths, get_added_paths};
 use camino::{Utf8Path, Utf8PathBuf};
 
 fn create_test_project_layout() -> (MemoryFileSystem, ProjectLayout) {
@@ -143,6 +143,36 @@ fn test_resolve_relative_import() {
     );
 }
 
+#[test]
+fn test_resolve_renamed_import() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/a.ts".into(), "export const a = 1;\n");
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { a as b } from \"./a.ts\";\n",
+    );
+
+    let added_paths = [BiomePath::new("/src/a.ts"), BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let imports = module_graph.data();
+    let file_imports = imports.get(Utf8Path::new("/src/index.ts")).unwrap();
+
+    // The import is registered under its local name, but refers to the
+    // symbol that's exported by the imported module.
+    assert_eq!(
+        file_imports.static_imports.get("b"),
+        Some(&JsImport {
+            specifier: "./a.ts".into(),
+            resolved_path: ResolvedPath::from_path("/src/a.ts"),
+            symbol: "a".into()
+        })
+    );
+}
+
 #[test]
 fn test_resolve_package_import() {
     let (fs, project_layout) = create_test_project_layout();
@@ -615,6 +645,50 @@ export const promise = makePromiseCb();
     snapshot.assert_snapshot("test_resolve_generic_return_value");
 }
 
+#[test]
+fn test_resolve_function_that_returns_promise() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"async function fetchValue(): Promise<string> {
+    return "value";
+}
+
+const makePromise = (): Promise<number> => Promise.resolve(1);
+
+function getValue(): string {
+    return "value";
+}
+"#,
+    );
+
+    let added_paths = [BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+
+    let resolver = Arc::new(resolver);
+    let type_of = |name: &'static str| {
+        let resolved_id = resolver
+            .resolve_type_of(&Text::Static(name), ScopeId::GLOBAL)
+            .expect("binding not found");
+        Type::from_id(resolver.clone(), resolved_id)
+    };
+
+    // The return types are instances of `Promise`, not the `Promise` class
+    // itself.
+    assert!(type_of("fetchValue").is_function_that_returns_promise());
+    assert!(type_of("makePromise").is_function_that_returns_promise());
+    assert!(!type_of("getValue").is_function_that_returns_promise());
+}
+
 #[test]
 fn test_resolve_generic_return_value_with_multiple_modules() {
     let mut fs = MemoryFileSystem::default();
@@ -685,6 +759,109 @@ fn test_resolve_generic_return_value_with_multiple_modules() {
     snapshot.assert_snapshot("test_resolve_generic_return_value_with_multiple_modules");
 }
 
+#[test]
+fn test_resolve_instantiated_generics() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"function withRetry<T>(fn: () => Promise<T>): Promise<T>;
+function unwrap<T>(value: Box<T>): T;
+function withDefault<T = Promise<void>>(): T;
+function withConstraint<T extends Promise<unknown>>(): T;
+
+function unwrapObject<T>(wrapper: Wrapper<T>): T;
+
+class Box<T> {
+    value: T;
+}
+
+class Holder<T> {
+    get(): T;
+}
+
+type Wrapper<T> = { value: T };
+
+declare const ids: Array<string>;
+declare const box: Box<Promise<string>>;
+declare const wrapper: Wrapper<Promise<number>>;
+declare const promise: Promise<number>;
+
+const fetchUser = (id: string): Promise<number> => Promise.resolve(1);
+
+export const retried = withRetry(() => fetchUser("1"));
+export const requests = ids.map(fetchUser);
+export const boxed = box.value;
+export const unwrapped = unwrap(box);
+export const fromDefault = withDefault();
+export const fromConstraint = withConstraint();
+export const wrapped = wrapper.value;
+export const unwrappedObject = unwrapObject({ value: promise });
+export const held = new Holder<Promise<number>>().get();
+"#,
+    );
+
+    let added_paths = [BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+
+    let ids: Vec<_> = [
+        "retried",
+        "requests",
+        "boxed",
+        "unwrapped",
+        "fromDefault",
+        "fromConstraint",
+        "wrapped",
+        "unwrappedObject",
+        "held",
+    ]
+    .into_iter()
+    .map(|name| {
+        let resolved_id = resolver
+            .resolve_type_of(&Text::Static(name), ScopeId::GLOBAL)
+            .expect("variable not found");
+        let ty = resolver
+            .get_by_resolved_id(resolved_id)
+            .expect("cannot find type data")
+            .to_data()
+            .inferred(&mut resolver);
+        (name, resolver.register_type(Cow::Owned(ty)))
+    })
+    .collect();
+    resolver.run_inference();
+
+    let level = resolver.level();
+    let resolver = Arc::new(resolver);
+    for (name, id) in ids {
+        let ty = Type::from_id(resolver.clone(), ResolvedTypeId::new(level, id));
+        if name == "requests" {
+            // `Array<string>.map()` returns an array of the callback's return type.
+            let TypeData::InstanceOf(instance) = &*ty else {
+                panic!("`requests` should be an array instance");
+            };
+            let element_ty = instance
+                .type_parameters
+                .first()
+                .and_then(|element_ty| ty.resolve(element_ty))
+                .expect("`requests` should have an element type");
+            assert!(
+                element_ty.is_promise_instance(),
+                "`requests` should be an array of promises"
+            );
+        } else {
+            assert!(ty.is_promise_instance(), "`{name}` should be a promise");
+        }
+    }
+}
+
 #[test]
 fn test_resolve_nested_function_call_with_namespace_in_return_type() {
     let mut fs = MemoryFileSystem::default();
@@ -1135,6 +1312,244 @@ fn test_resolve_react_types() {
     snapshot.assert_snapshot("test_resolve_react_types");
 }
 
+#[test]
+fn test_resolve_types_from_dependency_declarations() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/node_modules/orm/package.json".into(),
+        r#"{
+            "name": "orm",
+            "exports": {
+                ".": {
+                    "types": "./dist/index.d.ts",
+                    "default": "./dist/index.js"
+                }
+            }
+        }"#,
+    );
+    fs.insert(
+        "/node_modules/orm/dist/index.d.ts".into(),
+        r#"export { Client, createClient } from "./client";"#,
+    );
+    fs.insert(
+        "/node_modules/orm/dist/client.d.ts".into(),
+        r#"export interface Client {
+            query(sql: string): Promise<unknown>;
+        }
+
+        export declare function createClient(): Client;
+        "#,
+    );
+    fs.insert(
+        "/node_modules/@types/api/index.d.ts".into(),
+        r#"export declare function get(url: string): Promise<unknown>;"#,
+    );
+    fs.insert(
+        "/node_modules/legacy/package.json".into(),
+        r#"{ "name": "legacy", "main": "./index.js" }"#,
+    );
+    fs.insert(
+        "/node_modules/legacy/index.js".into(),
+        r#"exports.save = async function save() {};"#,
+    );
+    fs.insert(
+        "/node_modules/@types/legacy/index.d.ts".into(),
+        r#"export declare function save(): Promise<void>;"#,
+    );
+    fs.insert(
+        "/node_modules/bundled/package.json".into(),
+        r#"{ "name": "bundled", "main": "./lib/main.js" }"#,
+    );
+    fs.insert(
+        "/node_modules/bundled/lib/main.js".into(),
+        r#"exports.load = async function load() {};"#,
+    );
+    fs.insert(
+        "/node_modules/bundled/lib/main.d.ts".into(),
+        r#"export declare function load(): Promise<string>;"#,
+    );
+    fs.insert(
+        "/node_modules/unused/index.d.ts".into(),
+        r#"export declare function unused(): void;"#,
+    );
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"import { createClient } from "orm";
+        import { get } from "api";
+        import { save } from "legacy";
+        import { load } from "bundled";
+
+        export const result = createClient().query("SELECT 1");
+        export const response = get("/users");
+        export const saved = save();
+        export const loaded = load();
+        "#,
+    );
+
+    let added_paths = [BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let project_layout = ProjectLayout::default();
+    project_layout.insert_node_manifest(
+        "/".into(),
+        PackageJson::new("frontend")
+            .with_version("0.0.0")
+            .with_dependencies(Dependencies(Box::new([
+                ("orm".into(), "1.0.0".into()),
+                ("api".into(), "1.0.0".into()),
+                ("legacy".into(), "1.0.0".into()),
+                ("bundled".into(), "1.0.0".into()),
+            ]))),
+    );
+    for package_path in [
+        "/node_modules/orm",
+        "/node_modules/legacy",
+        "/node_modules/bundled",
+    ] {
+        let manifest_path = Utf8PathBuf::from(format!("{package_path}/package.json"));
+        project_layout.insert_serialized_node_manifest(
+            package_path.into(),
+            parse_json(
+                &fs.read_file_from_path(&manifest_path).unwrap(),
+                JsonParserOptions::default(),
+            )
+            .into(),
+        );
+    }
+
+    let tsconfig_json = parse_json(r#"{}"#, JsonParserOptions::default());
+    project_layout.insert_serialized_tsconfig("/".into(), tsconfig_json.into());
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &project_layout, &added_paths, &[]);
+
+    // Imports resolve to the type declarations of the dependencies, rather
+    // than to their JavaScript entry points.
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    let import_paths: Vec<_> = index_module
+        .static_import_paths
+        .iter()
+        .map(|(specifier, resolved_path)| (specifier.text(), resolved_path.as_path()))
+        .collect();
+    assert_eq!(
+        import_paths,
+        [
+            (
+                "api",
+                Some(Utf8Path::new("/node_modules/@types/api/index.d.ts"))
+            ),
+            (
+                "bundled",
+                Some(Utf8Path::new("/node_modules/bundled/lib/main.d.ts"))
+            ),
+            (
+                "legacy",
+                Some(Utf8Path::new("/node_modules/@types/legacy/index.d.ts"))
+            ),
+            (
+                "orm",
+                Some(Utf8Path::new("/node_modules/orm/dist/index.d.ts"))
+            ),
+        ]
+    );
+
+    // Only the declarations that are imported are loaded.
+    let data = module_graph.data();
+    assert!(data.contains_key(Utf8Path::new("/node_modules/orm/dist/index.d.ts")));
+    assert!(data.contains_key(Utf8Path::new("/node_modules/orm/dist/client.d.ts")));
+    assert!(data.contains_key(Utf8Path::new("/node_modules/@types/api/index.d.ts")));
+    assert!(data.contains_key(Utf8Path::new("/node_modules/@types/legacy/index.d.ts")));
+    assert!(data.contains_key(Utf8Path::new("/node_modules/bundled/lib/main.d.ts")));
+    assert!(!data.contains_key(Utf8Path::new("/node_modules/legacy/index.js")));
+    assert!(!data.contains_key(Utf8Path::new("/node_modules/unused/index.d.ts")));
+
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+
+    let ids: Vec<_> = ["result", "response", "saved", "loaded"]
+        .into_iter()
+        .map(|name| {
+            let resolved_id = resolver
+                .resolve_type_of(&Text::Static(name), ScopeId::GLOBAL)
+                .expect("variable not found");
+            let ty = resolver
+                .get_by_resolved_id(resolved_id)
+                .expect("cannot find type data")
+                .to_data()
+                .inferred(&mut resolver);
+            (name, resolver.register_type(Cow::Owned(ty)))
+        })
+        .collect();
+    resolver.run_inference();
+
+    let level = resolver.level();
+    let resolver = Arc::new(resolver);
+    for (name, id) in ids {
+        let ty = Type::from_id(resolver.clone(), ResolvedTypeId::new(level, id));
+        assert!(ty.is_promise_instance(), "`{name}` should be a promise");
+    }
+}
+
+#[test]
+fn test_resolve_globals_from_configured_libs() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"export const response = fetch("/api");
+        export const map = new Map();
+        "#,
+    );
+
+    let added_paths = [BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let project_layout = ProjectLayout::default();
+    let tsconfig_json = parse_json(
+        r#"{ "compilerOptions": { "lib": ["es5", "dom"] } }"#,
+        JsonParserOptions::default(),
+    );
+    project_layout.insert_serialized_tsconfig("/".into(), tsconfig_json.into());
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &project_layout, &added_paths, &[]);
+
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+
+    let response_id = resolver
+        .resolve_type_of(&Text::Static("response"), ScopeId::GLOBAL)
+        .expect("response variable not found");
+    let ty = resolver
+        .get_by_resolved_id(response_id)
+        .expect("cannot find type data")
+        .to_data()
+        .inferred(&mut resolver);
+
+    let id = resolver.register_type(Cow::Owned(ty));
+    resolver.run_inference();
+
+    let resolved_id = ResolvedTypeId::new(resolver.level(), id);
+    let resolver = Arc::new(resolver);
+    let ty = Type::from_id(resolver.clone(), resolved_id);
+    assert!(ty.is_promise_instance());
+
+    // `Map` is declared in `es2015.collection`, which is not enabled.
+    assert!(
+        resolver
+            .resolve_type_of(&Text::Static("Map"), ScopeId::GLOBAL)
+            .is_none()
+    );
+
+    let snapshot =
+        ModuleGraphSnapshot::new(module_graph.as_ref(), &fs).with_resolver(resolver.as_ref());
+    snapshot.assert_snapshot("test_resolve_globals_from_configured_libs");
+}
+
 #[test]
 fn test_resolve_export_type_referencing_imported_type() {
     let mut fs = MemoryFileSystem::default();
@@ -1308,3 +1723,449 @@ fn test_resolve_promise_from_imported_function_returning_reexported_promise_type
         "test_resolve_promise_from_imported_function_returning_reexported_promise_type",
     );
 }
+
+#[test]
+fn test_find_binding_declaration_and_references_through_reexport() {
+    const FOO_CONTENT: &str = "export function foo() {}\nfoo();\n";
+    const REEXPORT_CONTENT: &str = "export { foo } from \"./foo.ts\";\n";
+    const INDEX_CONTENT: &str = "import { foo as bar } from \"./reexport.ts\";\nbar();\nbar();\n";
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), FOO_CONTENT);
+    fs.insert("/src/reexport.ts".into(), REEXPORT_CONTENT);
+    fs.insert("/src/index.ts".into(), INDEX_CONTENT);
+
+    let added_paths = [
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/reexport.ts"),
+        BiomePath::new("/src/index.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let foo_declaration = TextRange::at(TextSize::from(16), TextSize::from(3));
+    let bar_declaration = TextRange::at(TextSize::from(16), TextSize::from(3));
+
+    assert_eq!(
+        module_graph.find_binding_declaration(Utf8Path::new("/src/index.ts"), bar_declaration),
+        Some((Utf8PathBuf::from("/src/foo.ts"), foo_declaration))
+    );
+
+    let references =
+        module_graph.find_binding_references(Utf8Path::new("/src/index.ts"), bar_declaration);
+    let offsets: Vec<_> = references
+        .iter()
+        .map(|(path, range)| (path.as_str(), u32::from(range.start())))
+        .collect();
+    assert_eq!(
+        offsets,
+        [
+            ("/src/foo.ts", 16),
+            ("/src/foo.ts", 25),
+            ("/src/index.ts", 16),
+            ("/src/index.ts", 44),
+            ("/src/index.ts", 51),
+        ]
+    );
+}
+
+#[test]
+fn test_find_modules_importing_binding_by_name() {
+    const FOO_CONTENT: &str = "export function foo() {}\nconst bar = 1;\nexport { bar as baz };\n";
+    const REEXPORT_CONTENT: &str = "export { foo } from \"./foo.ts\";\n";
+    const INDEX_CONTENT: &str =
+        "import { foo } from \"./foo\";\nimport { baz } from \"./foo.ts\";\n";
+    const OTHER_CONTENT: &str = "export const other = 1;\n";
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), FOO_CONTENT);
+    fs.insert("/src/reexport.ts".into(), REEXPORT_CONTENT);
+    fs.insert("/src/index.ts".into(), INDEX_CONTENT);
+    fs.insert("/src/other.ts".into(), OTHER_CONTENT);
+
+    let added_paths = [
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/reexport.ts"),
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/other.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let foo_declaration = TextRange::at(TextSize::from(16), TextSize::from(3));
+    assert_eq!(
+        module_graph
+            .find_modules_importing_binding_by_name(Utf8Path::new("/src/foo.ts"), foo_declaration),
+        [
+            (
+                Utf8PathBuf::from("/src/index.ts"),
+                vec![Text::Static("./foo"), Text::Static("./foo.ts")]
+            ),
+            (
+                Utf8PathBuf::from("/src/reexport.ts"),
+                vec![Text::Static("./foo.ts")]
+            ),
+        ]
+    );
+
+    // `bar` is exported under another name, so renaming it doesn't affect
+    // other modules.
+    let bar_declaration = TextRange::at(TextSize::from(31), TextSize::from(3));
+    assert!(
+        module_graph
+            .find_modules_importing_binding_by_name(Utf8Path::new("/src/foo.ts"), bar_declaration)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_find_modules_importing_binding_by_name_through_barrels() {
+    const FOO_CONTENT: &str = "export function foo() {}\n";
+    const BARREL_CONTENT: &str = "export * from \"./foo.ts\";\n";
+    const NESTED_BARREL_CONTENT: &str = "export * from \"./barrel.ts\";\n";
+    const SHADOWING_BARREL_CONTENT: &str = "export * from \"./foo.ts\";\nexport const foo = 1;\n";
+    const INDEX_CONTENT: &str = "import { foo } from \"./nested-barrel.ts\";\n";
+    const OTHER_CONTENT: &str = "import { foo } from \"./shadowing-barrel.ts\";\n";
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), FOO_CONTENT);
+    fs.insert("/src/barrel.ts".into(), BARREL_CONTENT);
+    fs.insert("/src/nested-barrel.ts".into(), NESTED_BARREL_CONTENT);
+    fs.insert("/src/shadowing-barrel.ts".into(), SHADOWING_BARREL_CONTENT);
+    fs.insert("/src/index.ts".into(), INDEX_CONTENT);
+    fs.insert("/src/other.ts".into(), OTHER_CONTENT);
+
+    let added_paths = [
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/barrel.ts"),
+        BiomePath::new("/src/nested-barrel.ts"),
+        BiomePath::new("/src/shadowing-barrel.ts"),
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/other.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    // `/src/other.ts` imports the `foo` declared by the shadowing barrel.
+    let foo_declaration = TextRange::at(TextSize::from(16), TextSize::from(3));
+    assert_eq!(
+        module_graph
+            .find_modules_importing_binding_by_name(Utf8Path::new("/src/foo.ts"), foo_declaration),
+        [
+            (
+                Utf8PathBuf::from("/src/barrel.ts"),
+                vec![Text::Static("./foo.ts")]
+            ),
+            (
+                Utf8PathBuf::from("/src/index.ts"),
+                vec![Text::Static("./nested-barrel.ts")]
+            ),
+            (
+                Utf8PathBuf::from("/src/nested-barrel.ts"),
+                vec![Text::Static("./barrel.ts")]
+            ),
+            (
+                Utf8PathBuf::from("/src/shadowing-barrel.ts"),
+                vec![Text::Static("./foo.ts")]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_transitive_dependencies() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { foo } from \"./foo.ts\";\nimport fs from \"node:fs\";\n",
+    );
+    fs.insert(
+        "/src/foo.ts".into(),
+        "export { bar as foo } from \"./bar.ts\";\n",
+    );
+    fs.insert(
+        "/src/bar.ts".into(),
+        "import { foo } from \"./foo.ts\";\nexport const bar = 1;\n",
+    );
+    fs.insert(
+        "/src/missing.ts".into(),
+        "import { missing } from \"./does-not-exist.ts\";\n",
+    );
+
+    let added_paths = [
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/bar.ts"),
+        BiomePath::new("/src/missing.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    assert_eq!(
+        module_graph.transitive_dependencies(Utf8Path::new("/src/index.ts")),
+        [
+            Utf8PathBuf::from("/src/bar.ts"),
+            Utf8PathBuf::from("/src/foo.ts"),
+        ]
+        .into()
+    );
+    assert!(!module_graph.has_unresolved_imports(Utf8Path::new("/src/index.ts")));
+    assert!(module_graph.has_unresolved_imports(Utf8Path::new("/src/missing.ts")));
+}
+
+#[test]
+fn test_reachable_paths_and_used_exports() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { foo } from \"./foo.ts\";\nexport { bar } from \"./reexport.ts\";\n",
+    );
+    fs.insert(
+        "/src/foo.ts".into(),
+        "export const foo = 1;\nexport const unusedFoo = 2;\n",
+    );
+    fs.insert("/src/reexport.ts".into(), "export * from \"./bar.ts\";\n");
+    fs.insert(
+        "/src/bar.ts".into(),
+        "export const bar = 1;\nexport const baz = 2;\nexport default 3;\n",
+    );
+    fs.insert(
+        "/src/lazy.ts".into(),
+        "const module = import(\"./dynamic.ts\");\nimport * as namespace from \"./namespace.ts\";\n",
+    );
+    fs.insert("/src/dynamic.ts".into(), "export const dynamic = 1;\n");
+    fs.insert("/src/namespace.ts".into(), "export const namespaced = 1;\n");
+    fs.insert("/src/orphan.ts".into(), "export const orphan = 1;\n");
+
+    let added_paths = [
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/reexport.ts"),
+        BiomePath::new("/src/bar.ts"),
+        BiomePath::new("/src/lazy.ts"),
+        BiomePath::new("/src/dynamic.ts"),
+        BiomePath::new("/src/namespace.ts"),
+        BiomePath::new("/src/orphan.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    assert_eq!(
+        *module_graph.reachable_paths("index", |path| path == "/src/index.ts"),
+        [
+            Utf8PathBuf::from("/src/bar.ts"),
+            Utf8PathBuf::from("/src/foo.ts"),
+            Utf8PathBuf::from("/src/index.ts"),
+            Utf8PathBuf::from("/src/reexport.ts"),
+        ]
+        .into()
+    );
+
+    let is_entry_point = |path: &Utf8Path| path == "/src/index.ts";
+    let is_export_used =
+        |path: &str, name: &str| module_graph.is_export_used(path.into(), name, is_entry_point);
+    assert!(is_export_used("/src/foo.ts", "foo"));
+    assert!(!is_export_used("/src/foo.ts", "unusedFoo"));
+
+    // `bar` is re-exported through `reexport.ts` by the entry point.
+    assert!(is_export_used("/src/bar.ts", "bar"));
+    assert!(!is_export_used("/src/bar.ts", "baz"));
+    assert!(!is_export_used("/src/bar.ts", "default"));
+
+    // Dynamic imports and namespace imports use every export.
+    assert!(is_export_used("/src/dynamic.ts", "dynamic"));
+    assert!(is_export_used("/src/namespace.ts", "namespaced"));
+    assert!(!is_export_used("/src/orphan.ts", "orphan"));
+
+    // Updating the graph invalidates the cached reachability and importers.
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { orphan } from \"./orphan.ts\";\n",
+    );
+    let updated_paths = [BiomePath::new("/src/index.ts")];
+    let updated_paths = get_added_paths(&fs, &updated_paths);
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &updated_paths, &[]);
+
+    assert_eq!(
+        *module_graph.reachable_paths("index", is_entry_point),
+        [
+            Utf8PathBuf::from("/src/index.ts"),
+            Utf8PathBuf::from("/src/orphan.ts"),
+        ]
+        .into()
+    );
+    assert!(!is_export_used("/src/foo.ts", "foo"));
+    assert!(is_export_used("/src/orphan.ts", "orphan"));
+}
+
+#[test]
+fn test_css_modules() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/App.jsx".into(),
+        r#"import styles from "./App.module.css";
+import * as theme from "./theme.module.css";
+import "./global.css";
+
+function helper(styles) {
+    return styles.shadowed;
+}
+
+export const App = () => (
+    <div className={styles.container}>
+        <h1 className={styles["main-title"]}>{theme.primary}</h1>
+    </div>
+);
+"#,
+    );
+    fs.insert(
+        "/src/App.module.css".into(),
+        r#".container { composes: base from "./base.module.css"; composes: local; }
+.main-title { background: url(./logo.png); }
+.local {}
+:global(.global) {}
+.unused {}
+#header {}
+@keyframes fade-in {}
+"#,
+    );
+    fs.insert("/src/base.module.css".into(), ".base { color: red; }\n");
+    fs.insert("/src/theme.module.css".into(), ".primary {}\n");
+    fs.insert(
+        "/src/global.css".into(),
+        r#"@import "./reset.css";
+@import url("missing.css");
+@import "https://example.com/font.css";
+body { background: url(data:image/png;base64,iVBORw0KGgo=) }
+"#,
+    );
+    fs.insert("/src/reset.css".into(), "* { margin: 0 }\n");
+    fs.insert("/src/logo.png".into(), "");
+
+    let added_paths = [BiomePath::new("/src/App.jsx")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+    let added_css_paths = [
+        BiomePath::new("/src/App.module.css"),
+        BiomePath::new("/src/base.module.css"),
+        BiomePath::new("/src/theme.module.css"),
+        BiomePath::new("/src/global.css"),
+        BiomePath::new("/src/reset.css"),
+    ];
+    let added_css_paths = get_added_css_paths(&fs, &added_css_paths);
+
+    let project_layout = ProjectLayout::default();
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &project_layout, &added_paths, &[]);
+    module_graph.update_graph_for_css_paths(&fs, &project_layout, &added_css_paths, &[]);
+
+    let app_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/App.jsx"))
+        .expect("module must exist");
+    let class_names: Vec<_> = app_module
+        .css_module_class_references
+        .iter()
+        .map(|reference| {
+            (
+                reference.resolved_path.as_path().map(Utf8Path::as_str),
+                reference.class_name.as_ref().map(Text::text),
+            )
+        })
+        .collect();
+    assert_eq!(
+        class_names,
+        [
+            (Some("/src/App.module.css"), Some("container")),
+            (Some("/src/App.module.css"), Some("main-title")),
+            (Some("/src/theme.module.css"), Some("primary")),
+        ]
+    );
+
+    let app_styles = module_graph
+        .css_module_info_for_path(Utf8Path::new("/src/App.module.css"))
+        .expect("stylesheet must exist");
+    assert_eq!(
+        app_styles
+            .classes
+            .keys()
+            .map(Text::text)
+            .collect::<Vec<_>>(),
+        ["container", "local", "main-title", "unused"]
+    );
+    assert!(app_styles.find_class("mainTitle").is_some());
+    assert!(app_styles.find_class("global").is_none());
+    assert_eq!(
+        app_styles
+            .identifiers
+            .iter()
+            .map(Text::text)
+            .collect::<Vec<_>>(),
+        ["fade-in", "header"]
+    );
+    assert!(app_styles.exports_name("fadeIn"));
+    assert!(!app_styles.exports_name("missing"));
+    assert_eq!(
+        app_styles
+            .locally_composed_classes
+            .iter()
+            .map(Text::text)
+            .collect::<Vec<_>>(),
+        ["local"]
+    );
+    assert_eq!(
+        app_styles
+            .all_import_paths()
+            .map(|path| path.as_path().map(Utf8Path::as_str))
+            .collect::<Vec<_>>(),
+        [Some("/src/base.module.css"), Some("/src/logo.png")]
+    );
+
+    let global_styles = module_graph
+        .css_module_info_for_path(Utf8Path::new("/src/global.css"))
+        .expect("stylesheet must exist");
+    let references: Vec<_> = global_styles
+        .references
+        .iter()
+        .map(|reference| {
+            (
+                reference.kind.clone(),
+                reference.specifier.text(),
+                reference.resolved_path.as_path().map(Utf8Path::as_str),
+            )
+        })
+        .collect();
+    assert_eq!(
+        references,
+        [
+            (
+                CssReferenceKind::Import,
+                "./reset.css",
+                Some("/src/reset.css")
+            ),
+            (CssReferenceKind::Import, "missing.css", None),
+        ]
+    );
+
+    assert_eq!(
+        module_graph
+            .referenced_css_module_classes(Utf8Path::new("/src/App.module.css"))
+            .map(|classes| classes.iter().map(Text::to_string).collect::<Vec<_>>()),
+        Some(vec!["container".to_string(), "main-title".to_string()])
+    );
+    assert_eq!(
+        module_graph
+            .referenced_css_module_classes(Utf8Path::new("/src/base.module.css"))
+            .map(|classes| classes.iter().map(Text::to_string).collect::<Vec<_>>()),
+        None,
+        "base.module.css is only composed, never imported from JavaScript"
+    );
+}
@@ -7,7 +7,7 @@ use biome_deserialize::{
 use biome_diagnostics::Error;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
-use biome_json_value::{JsonObject, JsonValue};
+use biome_json_value::{JsonObject, JsonString, JsonValue};
 use biome_text_size::TextRange;
 use camino::Utf8Path;
 use node_semver::{Range, SemverError};
@@ -113,6 +113,68 @@ impl PackageJson {
         }
         Some(value)
     }
+
+    /// Returns the paths of the files through which the package may be
+    /// entered, as declared by the `main`, `module`, `types`, `typings`,
+    /// `bin`, and `exports` fields.
+    ///
+    /// The paths are returned as written, and are relative to the directory
+    /// of the `package.json`. Subpath patterns in `exports`, such as
+    /// `"./features/*.js"`, are not included.
+    pub fn entry_points(&self) -> Vec<&str> {
+        let mut entry_points = Vec::new();
+        for field in ["main", "module", "types", "typings"] {
+            if let Some(path) = self.raw_json.get(field).and_then(JsonValue::as_string) {
+                entry_points.push(path.as_str());
+            }
+        }
+
+        match self.raw_json.get("bin") {
+            Some(JsonValue::String(path)) => entry_points.push(path.as_str()),
+            Some(JsonValue::Object(bin)) => {
+                entry_points.extend(
+                    bin.values()
+                        .filter_map(JsonValue::as_string)
+                        .map(JsonString::as_str),
+                );
+            }
+            _ => {}
+        }
+
+        if let Some(exports) = self.raw_json.get("exports") {
+            collect_export_targets(exports, &mut entry_points);
+        }
+
+        let mut unique_entry_points = Vec::with_capacity(entry_points.len());
+        for entry_point in entry_points {
+            if !unique_entry_points.contains(&entry_point) {
+                unique_entry_points.push(entry_point);
+            }
+        }
+
+        unique_entry_points
+    }
+}
+
+/// Collects the target paths of the given `exports` value, descending into
+/// subpaths and conditions.
+fn collect_export_targets<'a>(exports: &'a JsonValue, targets: &mut Vec<&'a str>) {
+    match exports {
+        JsonValue::String(target) if !target.as_str().contains('*') => {
+            targets.push(target.as_str());
+        }
+        JsonValue::Array(array) => {
+            for value in array.iter() {
+                collect_export_targets(value, targets);
+            }
+        }
+        JsonValue::Object(object) => {
+            for value in object.values() {
+                collect_export_targets(value, targets);
+            }
+        }
+        _ => {}
+    }
 }
 
 impl Manifest for PackageJson {
@@ -376,6 +438,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entry_points_from_package_json() {
+        let deserialized = deserialize_from_json_str::<PackageJson>(
+            r#"{
+    "name": "@shared/utils",
+    "main": "./dist/index.cjs",
+    "types": "./dist/index.d.ts",
+    "bin": {
+        "utils": "./bin/cli.js"
+    },
+    "exports": {
+        ".": {
+            "import": "./dist/index.js",
+            "require": "./dist/index.cjs"
+        },
+        "./features/*.js": "./dist/features/*.js",
+        "./package.json": "./package.json"
+    }
+}"#,
+            JsonParserOptions::default(),
+            "",
+        );
+        let (package_json, errors) = deserialized.consume();
+        assert!(errors.is_empty());
+
+        let package_json = package_json.expect("parsing must have succeeded");
+        assert_eq!(
+            package_json.entry_points(),
+            [
+                "./dist/index.cjs",
+                "./dist/index.d.ts",
+                "./bin/cli.js",
+                "./dist/index.js",
+                "./package.json"
+            ]
+        );
+    }
+
     #[test]
     fn should_not_panic_on_invalid_semver_range() {
         let result = parse_range("~0.x.0");
@@ -98,6 +98,12 @@ pub struct CompilerOptions {
     /// See: https://www.typescriptlang.org/tsconfig/#typeRoots
     #[deserializable(rename = "typeRoots")]
     pub type_roots: Option<Vec<String>>,
+
+    /// See: https://www.typescriptlang.org/tsconfig/#lib
+    pub lib: Option<Vec<String>>,
+
+    /// See: https://www.typescriptlang.org/tsconfig/#target
+    pub target: Option<String>,
 }
 
 pub type CompilerOptionsPathsMap = IndexMap<String, Vec<String>, BuildHasherDefault<FxHasher>>;
@@ -4,7 +4,7 @@ use biome_diagnostics::console::fmt::Display;
 use biome_diagnostics::console::{MarkupBuf, markup};
 use biome_diagnostics::location::AsSpan;
 use biome_diagnostics::{Advices, Diagnostic, Location, LogCategory, MessageAndDescription, Visit};
-use biome_rowan::{SyntaxKind, TextLen, TextRange};
+use biome_rowan::{SyntaxKind, TextLen, TextRange, TextSize};
 use std::cmp::Ordering;
 
 /// A specialized diagnostic for the parser
@@ -222,6 +222,18 @@ impl ParseDiagnostic {
         self
     }
 
+    /// Moves the diagnostic and its details by `offset`, e.g. when the parsed
+    /// source is embedded at `offset` in another document.
+    pub fn with_offset(mut self, offset: TextSize) -> Self {
+        self.span = self.span.map(|span| span + offset);
+        for advice in &mut self.advice.advice_list {
+            if let ParserAdviceKind::Detail(detail) = advice {
+                detail.span = detail.span.map(|span| span + offset);
+            }
+        }
+        self
+    }
+
     /// Small message that should suggest the user how they could fix the error
     ///
     /// Hints are rendered a **last part** of the diagnostics
@@ -1,17 +1,18 @@
-use biome_analyze::RuleDiagnostic;
+use biome_analyze::{FixKind, PluginAction, PluginSignal, RuleDiagnostic};
 use biome_console::markup;
-use biome_diagnostics::{Severity, category};
+use biome_diagnostics::{Applicability, Severity, category};
 use biome_fs::FileSystem;
 use biome_grit_patterns::{
     BuiltInFunction, CompilePatternOptions, GritBinding, GritExecContext, GritPattern, GritQuery,
-    GritQueryContext, GritQueryState, GritResolvedPattern, GritTargetFile,
-    compile_pattern_with_options,
+    GritQueryContext, GritQueryEffect, GritQueryState, GritResolvedPattern, GritTargetFile,
+    Rewrite, compile_pattern_with_options,
 };
 use biome_parser::AnyParse;
-use biome_rowan::TextRange;
+use biome_rowan::{TextRange, TextSize};
 use camino::{Utf8Path, Utf8PathBuf};
 use grit_pattern_matcher::{binding::Binding, pattern::ResolvedPattern};
 use grit_util::{AnalysisLogs, error::GritPatternError};
+use similar::{ChangeTag, TextDiff};
 use std::{borrow::Cow, fmt::Debug, str::FromStr, sync::Arc};
 
 use crate::{AnalyzerPlugin, PluginDiagnostic};
@@ -20,10 +21,17 @@ use crate::{AnalyzerPlugin, PluginDiagnostic};
 #[derive(Debug)]
 pub struct AnalyzerGritPlugin {
     grit_query: GritQuery,
+
+    /// The kind of the code actions emitted for the rewrites of the plugin.
+    fix_kind: FixKind,
 }
 
 impl AnalyzerGritPlugin {
-    pub fn load(fs: &dyn FileSystem, path: &Utf8Path) -> Result<Self, PluginDiagnostic> {
+    pub fn load(
+        fs: &dyn FileSystem,
+        path: &Utf8Path,
+        fix_kind: FixKind,
+    ) -> Result<Self, PluginDiagnostic> {
         let source = fs.read_file_from_path(path)?;
         let options = CompilePatternOptions::default()
             .with_extra_built_ins(vec![
@@ -37,54 +45,155 @@ impl AnalyzerGritPlugin {
             .with_path(path);
         let grit_query = compile_pattern_with_options(&source, options)?;
 
-        Ok(Self { grit_query })
+        Ok(Self {
+            grit_query,
+            fix_kind,
+        })
+    }
+
+    /// Returns the code action that applies the given `fragments` of the
+    /// rewrite of the plugin, if its fixes aren't disabled.
+    fn rewrite_action(
+        &self,
+        name: &str,
+        rewrite: &Rewrite,
+        fragments: &[&RewrittenFragment],
+        path: &Utf8Path,
+    ) -> Option<PluginAction> {
+        let applicability = Applicability::try_from(self.fix_kind).ok()?;
+        if fragments.is_empty() {
+            return None;
+        }
+
+        let mut content = rewrite.original_content.clone();
+        for fragment in fragments.iter().rev() {
+            content.replace_range(
+                usize::from(fragment.range.start())..usize::from(fragment.range.end()),
+                &fragment.replacement,
+            );
+        }
+
+        // Don't let a rewrite break the code
+        let root = self.grit_query.parse(&content, path);
+        if root.has_errors() {
+            return None;
+        }
+
+        Some(PluginAction {
+            applicability,
+            message: markup!("Apply the rewrite of "<Emphasis>{name}</Emphasis>".").to_owned(),
+            root,
+        })
     }
 }
 
 impl AnalyzerPlugin for AnalyzerGritPlugin {
-    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic> {
+    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<PluginSignal> {
         let name: &str = self.grit_query.name.as_deref().unwrap_or("anonymous");
 
-        let file = GritTargetFile { parse: root, path };
+        let file = GritTargetFile {
+            parse: root,
+            path: path.clone(),
+        };
         match self.grit_query.execute(file) {
             Ok(result) => {
-                let mut diagnostics: Vec<_> = result
+                let rewrite = result.effects.iter().rev().find_map(|effect| match effect {
+                    GritQueryEffect::Rewrite(rewrite) => Some(rewrite),
+                    _ => None,
+                });
+                let fragments = rewrite
+                    .map(|rewrite| {
+                        rewritten_fragments(&rewrite.original_content, &rewrite.rewritten.content)
+                    })
+                    .unwrap_or_default();
+
+                // Each action only applies the fragments of the rewrite that
+                // overlap the range of its own diagnostic
+                let action_for_range = |range: Option<TextRange>| {
+                    let fragments: Vec<_> = fragments
+                        .iter()
+                        .filter(|fragment| range.is_none_or(|range| fragment.overlaps(range)))
+                        .collect();
+                    self.rewrite_action(name, rewrite?, &fragments, &path)
+                };
+
+                // Plugins that only rewrite code emit a signal for every
+                // match, so that the rewrites can be applied as fixes
+                let rewrite_signals = match rewrite {
+                    Some(rewrite) if result.diagnostics.is_empty() => {
+                        rewrite_ranges(rewrite, &fragments)
+                            .into_iter()
+                            .filter_map(|range| {
+                                let action = action_for_range(Some(range))?;
+                                let signal = PluginSignal::from(
+                                    RuleDiagnostic::new(
+                                        category!("plugin"),
+                                        range,
+                                        markup!(<Emphasis>{name}</Emphasis>" can rewrite this code."),
+                                    )
+                                    .with_severity(Severity::Information),
+                                );
+                                Some(signal.with_action(action))
+                            })
+                            .collect()
+                    }
+                    _ => Vec::new(),
+                };
+
+                let mut signals: Vec<_> = result
                     .logs
                     .iter()
                     .map(|log| {
-                        RuleDiagnostic::new(
-                        category!("plugin"),
-                        log.range.map(from_grit_range),
-                        markup!(<Emphasis>{name}</Emphasis>" logged: "<Info>{log.message}</Info>),
-                    )
-                    .verbose()
+                        PluginSignal::from(RuleDiagnostic::new(
+                            category!("plugin"),
+                            log.range.map(from_grit_range),
+                            markup!(<Emphasis>{name}</Emphasis>" logged: "<Info>{log.message}</Info>),
+                        )
+                        .verbose())
+                    })
+                    .chain(result.diagnostics.into_iter().map(|diagnostic| {
+                        let action = action_for_range(diagnostic.span());
+                        let signal = PluginSignal::from(diagnostic);
+                        match action {
+                            Some(action) => signal.with_action(action),
+                            None => signal,
+                        }
+                    }))
+                    .chain(rewrite_signals)
+                    .map(|signal| PluginSignal {
+                        diagnostic: signal.diagnostic.subcategory(name.to_string()),
+                        ..signal
                     })
-                    .chain(result.diagnostics)
-                    .map(|diagnostics| diagnostics.subcategory(name.to_string()))
                     .collect();
 
-                if diagnostics
+                if signals
                     .iter()
-                    .any(|diagnostic| diagnostic.span().is_none())
+                    .any(|signal| signal.diagnostic.span().is_none())
                 {
-                    diagnostics.push(RuleDiagnostic::new(
-                        category!("plugin"),
-                        None::<TextRange>,
-                        markup!(
-                            "Plugin "<Emphasis>{name}</Emphasis>" reported one or more diagnostics, "
-                            "but it didn't specify a valid "<Emphasis>"span"</Emphasis>". "
-                            "Diagnostics have been shown without context."
-                        ),
-                    ));
+                    signals.push(
+                        RuleDiagnostic::new(
+                            category!("plugin"),
+                            None::<TextRange>,
+                            markup!(
+                                "Plugin "<Emphasis>{name}</Emphasis>" reported one or more diagnostics, "
+                                "but it didn't specify a valid "<Emphasis>"span"</Emphasis>". "
+                                "Diagnostics have been shown without context."
+                            ),
+                        )
+                        .into(),
+                    );
                 }
 
-                diagnostics
+                signals
             }
-            Err(error) => vec![RuleDiagnostic::new(
-                category!("plugin"),
-                None::<TextRange>,
-                markup!(<Emphasis>{name}</Emphasis>" errored: "<Error>{error.to_string()}</Error>),
-            )],
+            Err(error) => vec![
+                RuleDiagnostic::new(
+                    category!("plugin"),
+                    None::<TextRange>,
+                    markup!(<Emphasis>{name}</Emphasis>" errored: "<Error>{error.to_string()}</Error>),
+                )
+                .into(),
+            ],
         }
     }
 
@@ -92,15 +201,114 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
         self.grit_query.supports_css()
     }
 
+    fn supports_graphql(&self) -> bool {
+        self.grit_query.supports_graphql()
+    }
+
+    fn supports_html(&self) -> bool {
+        self.grit_query.supports_html()
+    }
+
     fn supports_js(&self) -> bool {
         self.grit_query.supports_js()
     }
+
+    fn supports_json(&self) -> bool {
+        self.grit_query.supports_json()
+    }
 }
 
 fn from_grit_range(range: grit_util::Range) -> TextRange {
     TextRange::new(range.start_byte.into(), range.end_byte.into())
 }
 
+/// A fragment of the analyzed file that is replaced by the rewrite of a
+/// plugin.
+#[derive(Debug)]
+struct RewrittenFragment {
+    /// The range of the replaced text in the analyzed file
+    range: TextRange,
+    /// The text that replaces it
+    replacement: String,
+}
+
+impl RewrittenFragment {
+    /// Returns whether the fragment replaces text inside `range`, or inserts
+    /// text inside it.
+    fn overlaps(&self, range: TextRange) -> bool {
+        if self.range.is_empty() {
+            range.contains_inclusive(self.range.start())
+        } else {
+            range
+                .intersect(self.range)
+                .is_some_and(|intersection| !intersection.is_empty())
+        }
+    }
+}
+
+/// Computes the fragments of `original` that are replaced in `rewritten`.
+fn rewritten_fragments(original: &str, rewritten: &str) -> Vec<RewrittenFragment> {
+    let diff = TextDiff::configure().diff_unicode_words(original, rewritten);
+
+    let mut fragments: Vec<RewrittenFragment> = Vec::new();
+    let mut offset = TextSize::default();
+    let mut is_in_fragment = false;
+    for change in diff.iter_all_changes() {
+        let value = change.value();
+        if change.tag() == ChangeTag::Equal {
+            offset += TextSize::of(value);
+            is_in_fragment = false;
+            continue;
+        }
+
+        if !is_in_fragment {
+            fragments.push(RewrittenFragment {
+                range: TextRange::empty(offset),
+                replacement: String::new(),
+            });
+            is_in_fragment = true;
+        }
+        let Some(fragment) = fragments.last_mut() else {
+            continue;
+        };
+        if change.tag() == ChangeTag::Delete {
+            offset += TextSize::of(value);
+            fragment.range = TextRange::new(fragment.range.start(), offset);
+        } else {
+            fragment.replacement.push_str(value);
+        }
+    }
+
+    fragments
+}
+
+/// Returns the ranges of the matches of the `rewrite` that contain at least
+/// one of the `fragments`, together with the ranges of the fragments that
+/// are outside of any match.
+fn rewrite_ranges(rewrite: &Rewrite, fragments: &[RewrittenFragment]) -> Vec<TextRange> {
+    let matches: Vec<_> = rewrite
+        .original
+        .ranges
+        .iter()
+        .copied()
+        .map(from_grit_range)
+        .collect();
+
+    let mut ranges = Vec::new();
+    for fragment in fragments {
+        let range = matches
+            .iter()
+            .find(|range| fragment.overlaps(**range))
+            .copied()
+            .unwrap_or(fragment.range);
+        if !ranges.contains(&range) {
+            ranges.push(range);
+        }
+    }
+
+    ranges
+}
+
 fn register_diagnostic<'a>(
     args: &'a [Option<GritPattern<GritQueryContext>>],
     context: &'a GritExecContext,
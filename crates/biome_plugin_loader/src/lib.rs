@@ -11,7 +11,7 @@ pub use plugin_cache::*;
 
 use std::sync::Arc;
 
-use biome_analyze::{AnalyzerPlugin, AnalyzerPluginVec};
+use biome_analyze::{AnalyzerPlugin, AnalyzerPluginVec, FixKind};
 use biome_console::markup;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_fs::{FileSystem, normalize_path};
@@ -27,11 +27,13 @@ pub struct BiomePlugin {
 impl BiomePlugin {
     /// Loads a plugin from the given `plugin_path`.
     ///
-    /// The base path is used to resolve relative paths.
+    /// The base path is used to resolve relative paths. The rewrites of the
+    /// plugin are emitted as code actions of the given `fix_kind`.
     pub fn load(
         fs: &dyn FileSystem,
         plugin_path: &str,
         base_path: &Utf8Path,
+        fix_kind: FixKind,
     ) -> Result<(Self, Utf8PathBuf), PluginDiagnostic> {
         let plugin_path = normalize_path(&base_path.join(plugin_path));
 
@@ -41,7 +43,7 @@ impl BiomePlugin {
             .extension()
             .is_some_and(|extension| extension == "grit")
         {
-            let plugin = AnalyzerGritPlugin::load(fs, &plugin_path)?;
+            let plugin = AnalyzerGritPlugin::load(fs, &plugin_path, fix_kind)?;
             return Ok((
                 Self {
                     analyzer_plugins: vec![Arc::new(Box::new(plugin) as Box<dyn AnalyzerPlugin>)],
@@ -77,7 +79,8 @@ impl BiomePlugin {
                 .map(|rule| Utf8PathBuf::from_path_buf(rule).unwrap())
                 .map(|rule| {
                     if rule.as_os_str().as_encoded_bytes().ends_with(b".grit") {
-                        let plugin = AnalyzerGritPlugin::load(fs, &plugin_path.join(rule))?;
+                        let plugin =
+                            AnalyzerGritPlugin::load(fs, &plugin_path.join(rule), fix_kind)?;
                         Ok(Arc::new(Box::new(plugin) as Box<dyn AnalyzerPlugin>))
                     } else {
                         Err(PluginDiagnostic::unsupported_rule_format(markup!(
@@ -126,8 +129,9 @@ mod test {
 
         fs.insert("/my-plugin/rules/1.grit".into(), r#"`hello`"#);
 
-        let (plugin, _) = BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"))
-            .expect("Couldn't load plugin");
+        let (plugin, _) =
+            BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"), FixKind::Unsafe)
+                .expect("Couldn't load plugin");
         assert_eq!(plugin.analyzer_plugins.len(), 1);
     }
 
@@ -136,7 +140,7 @@ mod test {
         let mut fs = MemoryFileSystem::default();
         fs.insert("/my-plugin/rules/1.grit".into(), r#"`hello`"#);
 
-        let error = BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"))
+        let error = BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"), FixKind::Unsafe)
             .expect_err("Plugin loading should've failed");
         snap_diagnostic("load_plugin_without_manifest", error.into());
     }
@@ -152,7 +156,7 @@ mod test {
 }"#,
         );
 
-        let error = BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"))
+        let error = BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"), FixKind::Unsafe)
             .expect_err("Plugin loading should've failed");
         snap_diagnostic("load_plugin_with_wrong_version", error.into());
     }
@@ -168,7 +172,7 @@ mod test {
 }"#,
         );
 
-        let error = BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"))
+        let error = BiomePlugin::load(&fs, "./my-plugin", Utf8Path::new("/"), FixKind::Unsafe)
             .expect_err("Plugin loading should've failed");
         snap_diagnostic("load_plugin_with_wrong_rule_extension", error.into());
     }
@@ -178,8 +182,9 @@ mod test {
         let mut fs = MemoryFileSystem::default();
         fs.insert("/my-plugin.grit".into(), r#"`hello`"#);
 
-        let (plugin, _) = BiomePlugin::load(&fs, "./my-plugin.grit", Utf8Path::new("/"))
-            .expect("Couldn't load plugin");
+        let (plugin, _) =
+            BiomePlugin::load(&fs, "./my-plugin.grit", Utf8Path::new("/"), FixKind::Unsafe)
+                .expect("Couldn't load plugin");
         assert_eq!(plugin.analyzer_plugins.len(), 1);
     }
 }
@@ -1,5 +1,5 @@
 use biome_analyze::AnalyzerPluginVec;
-use biome_configuration::plugins::{PluginConfiguration, Plugins};
+use biome_configuration::plugins::Plugins;
 use camino::Utf8PathBuf;
 use papaya::HashMap;
 use rustc_hash::{FxBuildHasher, FxHashSet};
@@ -30,21 +30,18 @@ impl PluginCache {
 
         let map = self.0.pin();
         for plugin_config in plugin_configs.iter() {
-            match plugin_config {
-                PluginConfiguration::Path(plugin_path) => {
-                    if seen.insert(plugin_path) {
-                        let path_buf = Utf8PathBuf::from(plugin_path);
-                        match map
-                            .iter()
-                            .find(|(path, _)| path.ends_with(path_buf.as_path()))
-                        {
-                            Some((_, plugin)) => {
-                                result.extend_from_slice(&plugin.analyzer_plugins);
-                            }
-                            None => {
-                                diagnostics.push(PluginDiagnostic::not_loaded(path_buf));
-                            }
-                        }
+            let plugin_path = plugin_config.path();
+            if seen.insert(plugin_path) {
+                let path_buf = Utf8PathBuf::from(plugin_path);
+                match map
+                    .iter()
+                    .find(|(path, _)| path.ends_with(path_buf.as_path()))
+                {
+                    Some((_, plugin)) => {
+                        result.extend_from_slice(&plugin.analyzer_plugins);
+                    }
+                    None => {
+                        diagnostics.push(PluginDiagnostic::not_loaded(path_buf));
                     }
                 }
             }
@@ -70,6 +70,21 @@ impl ProjectLayout {
             .cloned()
     }
 
+    /// Returns the `tsconfig.json` that should be used for the given `path`.
+    ///
+    /// This function will look for the closest `tsconfig.json` file in the
+    /// ancestors of the given `path`, and returns the first one it finds.
+    pub fn find_tsconfig_json_for_path(&self, path: &Utf8Path) -> Option<TsConfigJson> {
+        let packages = self.0.pin();
+        path.ancestors().skip(1).find_map(|package_path| {
+            packages
+                .get(package_path)
+                .and_then(|data| data.node_package.as_ref())
+                .and_then(|node_package| node_package.tsconfig.as_ref())
+                .cloned()
+        })
+    }
+
     /// Returns the `tsconfig.json` inside the given `package_path`.
     ///
     /// This function does not look for the closest `tsconfig.json` file in the
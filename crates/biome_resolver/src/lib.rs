@@ -540,25 +540,64 @@ fn resolve_package_path(
         }
 
         if subpath.is_empty() {
-            let fallback_field = if options.resolve_types {
-                "types"
+            // `typings` is an alias for `types`, which is still used by some
+            // packages.
+            let fallback_fields: &[&str] = if options.resolve_types {
+                &["types", "typings"]
             } else {
-                "main"
+                &["main"]
             };
 
-            if let Some(main_target) = package_json
-                .get_value_by_path(&[fallback_field])
-                .and_then(JsonValue::as_string)
-            {
+            if let Some(main_target) = fallback_fields.iter().find_map(|field| {
+                package_json
+                    .get_value_by_path(&[field])
+                    .and_then(JsonValue::as_string)
+            }) {
                 let options = options.without_extensions_or_manifests();
                 return resolve_relative_path(main_target.as_str(), &package_path, fs, &options);
             }
+
+            if options.resolve_types {
+                if let Some(path) =
+                    resolve_declaration_for_main(&package_path, &package_json, fs, options)
+                {
+                    return Ok(path);
+                }
+            }
         }
     }
 
     resolve_relative_path(subpath, &package_path, fs, options)
 }
 
+/// Resolves the type declaration next to the `main` entry point of a package
+/// without a `types` field, such as `lib/main.d.ts` for `lib/main.js`.
+///
+/// This mirrors TypeScript, which looks for declarations by substituting the
+/// extension of the `main` field with the declaration extensions from
+/// [`ResolveOptions::extensions`].
+fn resolve_declaration_for_main(
+    package_path: &Utf8Path,
+    package_json: &PackageJson,
+    fs: &dyn ResolverFsProxy,
+    options: &ResolveOptions,
+) -> Option<Utf8PathBuf> {
+    let main_target = package_json
+        .get_value_by_path(&["main"])
+        .and_then(JsonValue::as_string)?;
+    let main_path = Utf8Path::new(main_target.as_str()).with_extension("");
+
+    let path_options = options.without_extensions_or_manifests();
+    options
+        .extensions
+        .iter()
+        .filter(|extension| extension.starts_with("d."))
+        .find_map(|extension| {
+            let path = format!("{main_path}.{extension}");
+            resolve_relative_path(&path, package_path, fs, &path_options).ok()
+        })
+}
+
 enum ResolvedPathInfo {
     Directory,
     File,
@@ -721,7 +760,8 @@ pub struct ResolveOptions<'a> {
     /// TypeScript-like type resolution:
     /// - If no `"types"` export is found in a package, the `package.json`'s
     ///   `types` field  is used as a fallback.
-    /// - The `package.json`'s `main` field will be ignored.
+    /// - The `package.json`'s `main` field is only used to look for a type
+    ///   declaration next to it, such as `lib/main.d.ts` for `lib/main.js`.
     /// - Directories configured in [`Self::type_roots`] will be checked before
     ///   looking for dependencies in `node_modules/`.
     ///
@@ -772,8 +812,15 @@ impl<'a> ResolveOptions<'a> {
 
     /// Returns whether the resolver should resolve types inside `node_modules`
     /// based on these options.
+    ///
+    /// [`TypeRoots::Auto`] is only initialised when a `package.json` is found,
+    /// so it falls back to the default type roots here.
     const fn resolve_types_in_node_modules(&self) -> bool {
-        self.resolve_types && matches!(self.type_roots, TypeRoots::TypesInNodeModules)
+        self.resolve_types
+            && matches!(
+                self.type_roots,
+                TypeRoots::Auto | TypeRoots::TypesInNodeModules
+            )
     }
 
     /// Sets [`Self::assume_relative`] to `true` and returns this instance.
@@ -431,6 +431,45 @@ fn test_resolve_type_definitions() {
             "{base_dir}/node_modules/@types/react/index.d.ts"
         )))
     );
+
+    assert_eq!(
+        resolve("typings-only", &base_dir, &fs, &options),
+        Ok(Utf8PathBuf::from(format!(
+            "{base_dir}/node_modules/typings-only/lib/main.d.ts"
+        )))
+    );
+
+    // Without a `types` field, the declaration next to `main` is used.
+    assert_eq!(
+        resolve("main-only", &base_dir, &fs, &options),
+        Ok(Utf8PathBuf::from(format!(
+            "{base_dir}/node_modules/main-only/lib/main.d.ts"
+        )))
+    );
+}
+
+#[test]
+fn test_resolve_type_definitions_without_package_json() {
+    let base_dir = get_fixtures_path("resolver_cases_5");
+    let fs = OsFileSystem::new(base_dir.clone());
+
+    let options = ResolveOptions {
+        condition_names: &["types", "default"],
+        default_files: &["index"],
+        extensions: &["d.ts", "ts", "js"],
+        package_json: DiscoverableManifest::Off,
+        resolve_types: true,
+        ..Default::default()
+    };
+
+    // The `@types` package is preferred over the `main` of the package itself,
+    // even if no `package.json` is found to initialise the type roots from.
+    assert_eq!(
+        resolve("react", &base_dir.join("src"), &fs, &options),
+        Ok(Utf8PathBuf::from(format!(
+            "{base_dir}/node_modules/@types/react/index.d.ts"
+        )))
+    );
 }
 
 #[test]
@@ -296,8 +296,13 @@ impl NodeCache {
     ) -> (u64, GreenToken) {
         let hash = token_hash_of(kind, text);
 
+        // The same text can be split differently between the token and its trivia
         let entry = self.tokens.raw_entry_mut().from_hash(hash, |token| {
-            token.0.value().kind() == kind && token.0.value().text() == text
+            let token = token.0.value();
+            token.kind() == kind
+                && token.text() == text
+                && token.leading_trivia().pieces() == leading
+                && token.trailing_trivia().pieces() == trailing
         });
 
         let token = match entry {
@@ -514,9 +519,9 @@ impl TriviaCache {
 mod tests {
     use std::mem::size_of;
 
-    use crate::green::node_cache::{CachedNode, CachedToken, CachedTrivia, token_hash};
+    use crate::green::node_cache::{CachedNode, CachedToken, CachedTrivia, NodeCache, token_hash};
     use crate::green::trivia::GreenTrivia;
-    use crate::{GreenToken, RawSyntaxKind};
+    use crate::{GreenToken, RawSyntaxKind, TriviaPiece};
     use biome_text_size::TextSize;
 
     #[test]
@@ -550,6 +555,19 @@ mod tests {
         assert_ne!(token_hash(&t1), token_hash(&t4));
     }
 
+    #[test]
+    fn cached_token_with_different_trivia() {
+        let mut cache = NodeCache::default();
+        let kind = RawSyntaxKind(0);
+
+        let (_, without_trivia) = cache.token_with_trivia(kind, "\n  ", &[], &[]);
+        let (_, with_trivia) =
+            cache.token_with_trivia(kind, "\n  ", &[], &[TriviaPiece::whitespace(2)]);
+
+        assert_eq!(without_trivia.text_trimmed(), "\n  ");
+        assert_eq!(with_trivia.text_trimmed(), "\n");
+    }
+
     #[test]
     fn cache_entry_size() {
         assert_eq!(size_of::<CachedNode>(), 16);
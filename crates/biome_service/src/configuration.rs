@@ -19,9 +19,11 @@ use biome_css_syntax::CssLanguage;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, Merge};
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
-use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions};
+use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions, normalize_path};
 use biome_graphql_analyze::METADATA as graphql_lint_metadata;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_analyze::METADATA as html_lint_metadata;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_analyze::METADATA as js_lint_metadata;
 use biome_js_syntax::JsLanguage;
 use biome_json_analyze::METADATA as json_lint_metadata;
@@ -481,12 +483,14 @@ pub fn to_analyzer_rules(settings: &Settings, path: &Utf8Path) -> AnalyzerRules
         push_to_analyzer_rules(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, json_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, html_lint_metadata.deref(), &mut analyzer_rules);
     }
     if let Some(rules) = settings.assist.actions.as_ref() {
         push_to_analyzer_assist(rules, js_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_assist(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_assist(rules, json_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_assist(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_assist(rules, html_lint_metadata.deref(), &mut analyzer_rules);
     }
     let overrides = &settings.override_settings;
     overrides.override_analyzer_rules(path, analyzer_rules)
@@ -506,7 +510,7 @@ pub trait ConfigurationExt {
         fs: &dyn FsWithResolverProxy,
         relative_resolution_base_path: &Utf8Path,
         external_resolution_base_path: &Utf8Path,
-    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError>;
+    ) -> Result<Vec<(Utf8PathBuf, Deserialized<Configuration>)>, WorkspaceError>;
 
     fn migrate_deprecated_fields(&mut self);
 }
@@ -515,7 +519,8 @@ impl ConfigurationExt for Configuration {
     /// Mutates the configuration so that any fields that have not been configured explicitly are
     /// filled in with their values from configs listed in the `extends` field.
     ///
-    /// The `extends` configs are applied from left to right.
+    /// The `extends` configs are applied from left to right. The `extends` of
+    /// an extended config are applied first, and are resolved relative to it.
     ///
     /// If a configuration can't be resolved from the file system, the operation will fail.
     fn apply_extends(
@@ -525,47 +530,25 @@ impl ConfigurationExt for Configuration {
         external_resolution_base_path: &Utf8Path,
         diagnostics: &mut Vec<Error>,
     ) -> Result<(), WorkspaceError> {
-        let deserialized = self.deserialize_extends(
+        apply_extends_recursively(
+            self,
             fs,
-            file_path.parent().expect("file path should have a parent"),
+            file_path,
             external_resolution_base_path,
-        )?;
-        let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
-            .into_iter()
-            .map(|d| d.consume())
-            .map(|(config, diagnostics)| (config.unwrap_or_default(), diagnostics))
-            .unzip();
-
-        let extended_configuration = configurations.into_iter().reduce(
-            |mut previous_configuration, current_configuration| {
-                previous_configuration.merge_with(current_configuration);
-                previous_configuration
-            },
-        );
-        if let Some(mut extended_configuration) = extended_configuration {
-            // We swap them to avoid having to clone `self.configuration` to merge it.
-            std::mem::swap(self, &mut extended_configuration);
-            self.merge_with(extended_configuration)
-        }
-
-        diagnostics.extend(
-            errors
-                .into_iter()
-                .flatten()
-                .map(|diagnostic| diagnostic.with_file_path(file_path.to_string()))
-                .collect::<Vec<_>>(),
-        );
-
-        Ok(())
+            diagnostics,
+            &mut vec![normalize_path(file_path)],
+        )
     }
 
-    /// It attempts to deserialize all the configuration files that were specified in the `extends` property
+    /// It attempts to deserialize all the configuration files that were specified in the `extends` property.
+    ///
+    /// The path of each configuration file is returned along its deserialized content.
     fn deserialize_extends(
         &mut self,
         fs: &dyn FsWithResolverProxy,
         relative_resolution_base_path: &Utf8Path,
         external_resolution_base_path: &Utf8Path,
-    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError> {
+    ) -> Result<Vec<(Utf8PathBuf, Deserialized<Configuration>)>, WorkspaceError> {
         let Some(extends) = &self.extends else {
             return Ok(Vec::new());
         };
@@ -576,7 +559,7 @@ impl ConfigurationExt for Configuration {
                 let extend_entry_as_path = Path::new(extend_entry.as_ref());
 
                 let extend_configuration_file_path = if extend_entry_as_path.starts_with(".") {
-                    relative_resolution_base_path.join(extend_entry.as_ref())
+                    normalize_path(&relative_resolution_base_path.join(extend_entry.as_ref()))
                 } else {
                     const RESOLVE_OPTIONS: ResolveOptions = ResolveOptions::new()
                         .with_assume_relative()
@@ -637,7 +620,7 @@ impl ConfigurationExt for Configuration {
                     },
                     "",
                 );
-                deserialized_configurations.push(deserialized)
+                deserialized_configurations.push((extend_configuration_file_path, deserialized))
             }
         }
         Ok(deserialized_configurations)
@@ -648,6 +631,84 @@ impl ConfigurationExt for Configuration {
     fn migrate_deprecated_fields(&mut self) {}
 }
 
+/// Applies the `extends` of `configuration`, which was loaded from `file_path`.
+///
+/// `stack` contains the configuration files that are currently being
+/// extended, and it's used to detect cycles.
+fn apply_extends_recursively(
+    configuration: &mut Configuration,
+    fs: &dyn FsWithResolverProxy,
+    file_path: &Utf8Path,
+    external_resolution_base_path: &Utf8Path,
+    diagnostics: &mut Vec<Error>,
+    stack: &mut Vec<Utf8PathBuf>,
+) -> Result<(), WorkspaceError> {
+    let directory_path = file_path.parent().expect("file path should have a parent");
+    let deserialized =
+        configuration.deserialize_extends(fs, directory_path, external_resolution_base_path)?;
+
+    let mut extended_configuration: Option<Configuration> = None;
+    for (extend_file_path, deserialized) in deserialized {
+        if stack.contains(&extend_file_path) {
+            return Err(CantLoadExtendFile::new(
+                extend_file_path.to_string(),
+                "The configuration file is extended by itself, directly or through other configuration files.",
+            )
+            .into());
+        }
+
+        let (current_configuration, errors) = deserialized.consume();
+        diagnostics.extend(
+            errors
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_file_path(file_path.to_string())),
+        );
+        let mut current_configuration = current_configuration.unwrap_or_default();
+
+        // Plugins, and the configurations extended by the current
+        // configuration, are relative to the current configuration.
+        // This allows npm packages to ship their own plugins and presets.
+        let extend_directory_path = extend_file_path
+            .parent()
+            .expect("file path should have a parent");
+        if let Some(plugins) = current_configuration.plugins.as_mut() {
+            plugins.rebase(extend_directory_path);
+        }
+        if let Some(overrides) = current_configuration.overrides.as_mut() {
+            for pattern in overrides.0.iter_mut() {
+                if let Some(plugins) = pattern.plugins.as_mut() {
+                    plugins.rebase(extend_directory_path);
+                }
+            }
+        }
+        stack.push(extend_file_path.clone());
+        apply_extends_recursively(
+            &mut current_configuration,
+            fs,
+            &extend_file_path,
+            extend_directory_path,
+            diagnostics,
+            stack,
+        )?;
+        stack.pop();
+
+        match extended_configuration.as_mut() {
+            Some(previous_configuration) => {
+                previous_configuration.merge_with(current_configuration)
+            }
+            None => extended_configuration = Some(current_configuration),
+        }
+    }
+
+    if let Some(mut extended_configuration) = extended_configuration {
+        // We swap them to avoid having to clone `configuration` to merge it.
+        std::mem::swap(configuration, &mut extended_configuration);
+        configuration.merge_with(extended_configuration)
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use crate::{WorkspaceError, configuration::load_configuration};
@@ -763,6 +824,7 @@ impl<'a> ProjectScanComputer<'a> {
         }
 
         biome_graphql_analyze::visit_registry(&mut self);
+        biome_html_analyze::visit_registry(&mut self);
         biome_css_analyze::visit_registry(&mut self);
         biome_json_analyze::visit_registry(&mut self);
         biome_js_analyze::visit_registry(&mut self);
@@ -837,6 +899,16 @@ impl RegistryVisitor<GraphqlLanguage> for ProjectScanComputer<'_> {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for ProjectScanComputer<'_> {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.check_rule::<R, HtmlLanguage>();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
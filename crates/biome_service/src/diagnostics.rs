@@ -331,6 +331,20 @@ pub struct NoProject;
 #[diagnostic(category = "project")]
 pub struct NoWorkspaceDirectory;
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "configuration",
+    message(
+        message("The introspection file "{self.path}" can't be combined with other files in "<Emphasis>"graphql.schema"</Emphasis>"."),
+        description = "The introspection file {path} can't be combined with other files in graphql.schema."
+    ),
+    advice = "Use either SDL files, or a single introspection file."
+)]
+pub struct MixedGraphqlSchemaSources {
+    #[location(resource)]
+    pub(crate) path: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SourceFileNotSupported {
     file_source: DocumentFileSource,
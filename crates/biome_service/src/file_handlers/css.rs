@@ -26,7 +26,7 @@ use biome_configuration::css::{
     CssFormatterConfiguration, CssFormatterEnabled, CssLinterConfiguration, CssLinterEnabled,
     CssModulesEnabled, CssParserConfiguration,
 };
-use biome_css_analyze::analyze;
+use biome_css_analyze::{CssAnalyzerServices, analyze};
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::format_node;
 use biome_css_parser::CssParserOptions;
@@ -342,6 +342,9 @@ impl ExtensionHandler for CssFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -380,7 +383,7 @@ fn search_enabled(_path: &Utf8Path, _handle: &WorkspaceSettingsHandle) -> bool {
 
 fn parse(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
+    file_source: DocumentFileSource,
     text: &str,
     handle: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
@@ -396,6 +399,9 @@ fn parse(
             .unwrap_or_default()
             .into(),
         grit_metavariables: false,
+        scss: file_source
+            .to_css_file_source()
+            .is_some_and(|file_source| file_source.is_scss()),
     };
     if let Some(settings) = settings {
         options = settings
@@ -502,7 +508,7 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     let _ =
         debug_span!("Linting CSS file", path =? params.path, language =? params.language).entered();
     let workspace_settings = &params.workspace;
@@ -531,10 +537,13 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
+    let file_source = params.language.to_css_file_source().unwrap_or_default();
+    let services = CssAnalyzerServices::from((params.module_graph.clone(), file_source));
     let (_, analyze_diagnostics) = analyze(
         &tree,
         filter,
         &analyzer_options,
+        services,
         &params.plugins,
         |signal| process_lint.process_signal(signal),
     );
@@ -549,7 +558,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         range,
         workspace,
         path,
-        module_graph: _,
+        module_graph,
         project_layout,
         language,
         only,
@@ -590,19 +599,27 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
     info!("CSS runs the analyzer");
 
-    analyze(&tree, filter, &analyzer_options, &plugins, |signal| {
-        actions.extend(signal.actions().into_code_action_iter().map(|item| {
-            CodeAction {
-                category: item.category.clone(),
-                rule_name: item
-                    .rule_name
-                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                suggestion: item.suggestion,
-            }
-        }));
+    let file_source = language.to_css_file_source().unwrap_or_default();
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        CssAnalyzerServices::from((module_graph, file_source)),
+        &plugins,
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -646,11 +663,17 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
 
+    let file_source = params
+        .document_file_source
+        .to_css_file_source()
+        .unwrap_or_default();
+
     loop {
         let (action, _) = analyze(
             &tree,
             filter,
             &analyzer_options,
+            CssAnalyzerServices::from((params.module_graph.clone(), file_source)),
             &params.plugins,
             |signal| {
                 let current_diagnostic = signal.diagnostic();
@@ -0,0 +1,456 @@
+//! Support for the CSS and GraphQL code embedded in tagged template literals, such as
+//! ``styled.div`...` `` or ``gql`...` ``.
+//!
+//! The expressions of a template are replaced by placeholders, so that its content can be parsed,
+//! formatted and linted as a regular CSS or GraphQL file.
+
+use super::{LintParams, LintResults, css, graphql};
+use crate::settings::WorkspaceSettingsHandle;
+use crate::workspace::DocumentFileSource;
+use biome_css_parser::{CssParserOptions, parse_css};
+use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_diagnostics::Diagnostic;
+use biome_formatter::{FormatOptions, FormatResult, Formatted, IndentStyle};
+use biome_fs::BiomePath;
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
+use biome_js_formatter::context::{JsFormatContext, JsFormatOptions};
+use biome_js_formatter::{
+    EmbeddedTemplateFormatter, FormattedEmbeddedTemplate, format_node,
+    format_node_with_embedded_templates,
+};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, AnyJsTemplateElement, JsSyntaxNode, JsTemplateExpression,
+    TextRange, TextSize,
+};
+use biome_rowan::{AstNode, AstNodeList, TokenText};
+use std::rc::Rc;
+
+/// The tags of the template literals that embed CSS, unless they are configured
+const CSS_TAGS: &[&str] = &[
+    "css",
+    "keyframes",
+    "injectGlobal",
+    "createGlobalStyle",
+    "styled",
+];
+
+/// The tags of the template literals that embed GraphQL, unless they are configured
+const GRAPHQL_TAGS: &[&str] = &["gql", "graphql"];
+
+/// The language embedded in a template literal
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TemplateLanguage {
+    /// The declarations of a rule, e.g. ``styled.div`color: red;` ``
+    CssDeclarations,
+    /// The keyframes of an animation, e.g. ``keyframes`from { opacity: 0; }` ``
+    CssKeyframes,
+    /// A whole stylesheet, e.g. ``createGlobalStyle`body { margin: 0; }` ``
+    CssStylesheet,
+    Graphql,
+}
+
+/// The tags that identify the template literals embedding CSS or GraphQL code.
+#[derive(Debug)]
+pub(crate) struct EmbeddedTemplateTags {
+    css: Vec<String>,
+    graphql: Vec<String>,
+}
+
+impl EmbeddedTemplateTags {
+    /// Returns the tags configured for the file at `path`, or `None` if the embedded templates
+    /// aren't enabled.
+    pub(crate) fn from_settings(
+        settings: &WorkspaceSettingsHandle,
+        path: &BiomePath,
+    ) -> Option<Self> {
+        let settings = settings.settings()?;
+        let configuration = settings.override_settings.override_embedded_templates(
+            path,
+            settings
+                .languages
+                .javascript
+                .environment
+                .embedded_templates
+                .as_ref(),
+        )?;
+        if !configuration.enabled.unwrap_or_default().value() {
+            return None;
+        }
+
+        let tags = |defaults: &[&str], configured: Option<Vec<String>>| {
+            defaults
+                .iter()
+                .map(|tag| (*tag).to_string())
+                .chain(configured.unwrap_or_default())
+                .collect()
+        };
+        Some(Self {
+            css: tags(CSS_TAGS, configuration.css_tags),
+            graphql: tags(GRAPHQL_TAGS, configuration.graphql_tags),
+        })
+    }
+
+    /// Returns the language embedded in `template`, based on the name at the root of its tag:
+    /// ``styled.div`...` ``, ``styled(Button)`...` `` and ``styled.div.attrs({})`...` `` are
+    /// all tagged by `styled`.
+    fn language_of(&self, template: &JsTemplateExpression) -> Option<TemplateLanguage> {
+        let name = tag_name(template.tag()?)?;
+        let name = name.text();
+        if self.graphql.iter().any(|tag| tag == name) {
+            Some(TemplateLanguage::Graphql)
+        } else if self.css.iter().any(|tag| tag == name) {
+            Some(match name {
+                "keyframes" => TemplateLanguage::CssKeyframes,
+                "injectGlobal" | "createGlobalStyle" => TemplateLanguage::CssStylesheet,
+                _ => TemplateLanguage::CssDeclarations,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn tag_name(tag: AnyJsExpression) -> Option<TokenText> {
+    match tag.omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => Some(
+            identifier
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .token_text_trimmed(),
+        ),
+        AnyJsExpression::JsStaticMemberExpression(member) => tag_name(member.object().ok()?),
+        AnyJsExpression::JsCallExpression(call) => tag_name(call.callee().ok()?),
+        _ => None,
+    }
+}
+
+/// The content of a template literal, in which the expressions are replaced by placeholders.
+#[derive(Debug)]
+struct EmbeddedTemplate {
+    language: TemplateLanguage,
+    code: String,
+    placeholders: Vec<String>,
+    /// The ranges of the chunks of the template in `code`, with their offset in the host file.
+    chunks: Vec<(TextRange, TextSize)>,
+}
+
+impl EmbeddedTemplate {
+    /// Returns `None` if an expression is at a position where no placeholder can stand in for
+    /// it, or if the template contains escape sequences.
+    fn new(template: &JsTemplateExpression, language: TemplateLanguage) -> Option<Self> {
+        let (prefix, suffix) = match language {
+            TemplateLanguage::CssDeclarations => ("biome-embedded {\n", "\n}"),
+            TemplateLanguage::CssKeyframes => ("@keyframes biome-embedded {\n", "\n}"),
+            TemplateLanguage::CssStylesheet | TemplateLanguage::Graphql => ("", ""),
+        };
+
+        let mut code = String::from(prefix);
+        let mut placeholders = Vec::new();
+        let mut chunks = Vec::new();
+        let elements: Vec<_> = template.elements().iter().collect();
+        for (index, element) in elements.iter().enumerate() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    let token = chunk.template_chunk_token().ok()?;
+                    let text = token.text_trimmed();
+                    // The raw text of the template differs from its value
+                    if text.contains('\\') {
+                        return None;
+                    }
+                    chunks.push((
+                        TextRange::at(TextSize::of(code.as_str()), TextSize::of(text)),
+                        token.text_trimmed_range().start(),
+                    ));
+                    code.push_str(text);
+                }
+                AnyJsTemplateElement::JsTemplateElement(_) => {
+                    let next = match elements.get(index + 1) {
+                        Some(AnyJsTemplateElement::JsTemplateChunkElement(chunk)) => {
+                            chunk.template_chunk_token().ok()
+                        }
+                        _ => None,
+                    };
+                    let next = next.as_ref().map_or("", |token| token.text_trimmed());
+                    let placeholder = placeholder(language, placeholders.len(), &code, next)?;
+                    code.push_str(&placeholder);
+                    placeholders.push(placeholder);
+                }
+            }
+        }
+        code.push_str(suffix);
+
+        Some(Self {
+            language,
+            code,
+            placeholders,
+            chunks,
+        })
+    }
+
+    /// Maps a range of [Self::code] to the host file. Returns `None` if the range starts or
+    /// ends within a placeholder.
+    fn to_host_range(&self, range: TextRange) -> Option<TextRange> {
+        let to_host_offset = |offset: TextSize, is_end: bool| {
+            self.chunks.iter().find_map(|(chunk, host_offset)| {
+                let contains = if is_end {
+                    chunk.start() < offset && offset <= chunk.end()
+                } else {
+                    chunk.start() <= offset && offset < chunk.end()
+                };
+                contains.then(|| offset - chunk.start() + *host_offset)
+            })
+        };
+
+        if range.is_empty() {
+            let offset = to_host_offset(range.start(), false)?;
+            return Some(TextRange::empty(offset));
+        }
+        Some(TextRange::new(
+            to_host_offset(range.start(), false)?,
+            to_host_offset(range.end(), true)?,
+        ))
+    }
+}
+
+/// Returns the placeholder of the expression at `index`, given the `previous` code of the
+/// template and the text of the `next` chunk.
+fn placeholder(
+    language: TemplateLanguage,
+    index: usize,
+    previous: &str,
+    next: &str,
+) -> Option<String> {
+    if language == TemplateLanguage::Graphql {
+        // Expressions are usually fragments, interpolated on their own line. They are replaced
+        // by comments, which can't be used anywhere else.
+        let line_start = previous.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = next.find('\n').unwrap_or(next.len());
+        let is_alone =
+            previous[line_start..].trim().is_empty() && next[..line_end].trim().is_empty();
+        return is_alone.then(|| format!("#biome-placeholder-{index}"));
+    }
+
+    // An expression that stands for whole declarations, such as a mixin, is replaced by a
+    // custom property. Any other expression is replaced by an identifier, which is valid in
+    // selectors, property names and values.
+    let previous = previous.trim_end().chars().last();
+    let next = next.trim_start_matches([' ', '\t']).chars().next();
+    let is_declaration = matches!(previous, None | Some('{' | ';' | '}'))
+        && matches!(next, None | Some(';' | '}' | '\n' | '\r'));
+    Some(if !is_declaration {
+        format!("biome-placeholder-{index}")
+    } else if next == Some(';') {
+        format!("--biome-placeholder-{index}: 0")
+    } else {
+        format!("--biome-placeholder-{index}: 0;")
+    })
+}
+
+/// Formats the CSS and GraphQL code embedded in the template literals of a JavaScript file.
+#[derive(Debug)]
+struct JsEmbeddedTemplateFormatter {
+    biome_path: BiomePath,
+    settings: WorkspaceSettingsHandle,
+    tags: EmbeddedTemplateTags,
+}
+
+impl JsEmbeddedTemplateFormatter {
+    fn format_css(&self, template: &EmbeddedTemplate) -> Option<String> {
+        if !self
+            .settings
+            .formatter_enabled_for_file_path::<CssLanguage>(&self.biome_path)
+        {
+            return None;
+        }
+
+        let parse = parse_css(&template.code, CssParserOptions::default());
+        if parse.has_errors() {
+            return None;
+        }
+        let options = self.settings.format_options::<CssLanguage>(
+            &self.biome_path,
+            &DocumentFileSource::Css(CssFileSource::css()),
+        );
+        let indent = match options.indent_style() {
+            IndentStyle::Tab => "\t".to_string(),
+            IndentStyle::Space => " ".repeat(options.indent_width().value().into()),
+        };
+        let printed = biome_css_formatter::format_node(options, &parse.syntax())
+            .ok()?
+            .print()
+            .ok()?;
+
+        if template.language == TemplateLanguage::CssStylesheet {
+            return Some(printed.into_code());
+        }
+
+        // Removes the rule that wraps the content of the template
+        let code = printed.as_code().trim_end();
+        let (_, content) = code.split_once('\n')?;
+        let content = content.strip_suffix('}')?;
+        let content = content
+            .lines()
+            .map(|line| line.strip_prefix(indent.as_str()).unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(content)
+    }
+
+    fn format_graphql(&self, template: &EmbeddedTemplate) -> Option<String> {
+        if !self
+            .settings
+            .formatter_enabled_for_file_path::<GraphqlLanguage>(&self.biome_path)
+        {
+            return None;
+        }
+
+        let parse = parse_graphql(&template.code);
+        if parse.has_errors() {
+            return None;
+        }
+        let options = self.settings.format_options::<GraphqlLanguage>(
+            &self.biome_path,
+            &DocumentFileSource::Graphql(GraphqlFileSource::default()),
+        );
+        let printed = biome_graphql_formatter::format_node(options, &parse.syntax())
+            .ok()?
+            .print()
+            .ok()?;
+        Some(printed.into_code())
+    }
+}
+
+impl EmbeddedTemplateFormatter for JsEmbeddedTemplateFormatter {
+    fn format(&self, template: &JsTemplateExpression) -> Option<FormattedEmbeddedTemplate> {
+        let language = self.tags.language_of(template)?;
+        let template = EmbeddedTemplate::new(template, language)?;
+        let code = match language {
+            TemplateLanguage::Graphql => self.format_graphql(&template)?,
+            _ => self.format_css(&template)?,
+        };
+
+        Some(FormattedEmbeddedTemplate {
+            code,
+            placeholders: template.placeholders,
+        })
+    }
+}
+
+/// Formats a JavaScript file, along with the code embedded in its template literals when it's
+/// enabled in the settings.
+pub(crate) fn format_js_node(
+    options: JsFormatOptions,
+    root: &JsSyntaxNode,
+    biome_path: &BiomePath,
+    settings: WorkspaceSettingsHandle,
+) -> FormatResult<Formatted<JsFormatContext>> {
+    match EmbeddedTemplateTags::from_settings(&settings, biome_path) {
+        Some(tags) => format_node_with_embedded_templates(
+            options,
+            root,
+            Rc::new(JsEmbeddedTemplateFormatter {
+                biome_path: biome_path.clone(),
+                settings,
+                tags,
+            }),
+        ),
+        None => format_node(options, root),
+    }
+}
+
+/// Lints the code embedded in the template literals of a JavaScript file with the analyzer of
+/// its language. The diagnostics are mapped to the host file; the ones that start or end
+/// within an expression of a template are dropped.
+///
+/// Templates with syntax errors aren't linted.
+pub(crate) fn lint_embedded_templates(params: &LintParams, root: &AnyJsRoot) -> LintResults {
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+    let Some(tags) = EmbeddedTemplateTags::from_settings(params.workspace, params.path) else {
+        return results;
+    };
+
+    for template in root
+        .syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+    {
+        let Some(template) = tags
+            .language_of(&template)
+            .and_then(|language| EmbeddedTemplate::new(&template, language))
+        else {
+            continue;
+        };
+
+        let embedded_params = |parse, language| LintParams {
+            parse,
+            workspace: params.workspace,
+            language,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            module_graph: params.module_graph.clone(),
+            project_layout: params.project_layout.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+            enabled_rules: params.enabled_rules.clone(),
+            plugins: params.plugins.clone(),
+            // The actions would apply to the code with placeholders
+            pull_code_actions: false,
+        };
+        let template_results = if template.language == TemplateLanguage::Graphql {
+            if !params
+                .workspace
+                .linter_enabled_for_file_path::<GraphqlLanguage>(params.path)
+            {
+                continue;
+            }
+            let parse = parse_graphql(&template.code);
+            if parse.has_errors() {
+                continue;
+            }
+            graphql::lint(embedded_params(
+                parse.into(),
+                DocumentFileSource::Graphql(GraphqlFileSource::default()),
+            ))
+        } else {
+            if !params
+                .workspace
+                .linter_enabled_for_file_path::<CssLanguage>(params.path)
+            {
+                continue;
+            }
+            let parse = parse_css(&template.code, CssParserOptions::default());
+            if parse.has_errors() {
+                continue;
+            }
+            css::lint(embedded_params(
+                parse.into(),
+                DocumentFileSource::Css(CssFileSource::css()),
+            ))
+        };
+
+        results.errors += template_results.errors;
+        results.skipped_diagnostics += template_results.skipped_diagnostics;
+        results
+            .diagnostics
+            .extend(
+                template_results
+                    .diagnostics
+                    .into_iter()
+                    .filter_map(|diagnostic| {
+                        // Diagnostics that can't be located in the host file are dropped
+                        template.to_host_range(diagnostic.location().span?)?;
+                        Some(diagnostic.with_mapped_spans(|span| template.to_host_range(span)))
+                    }),
+            );
+    }
+
+    results
+}
@@ -1,9 +1,10 @@
 use super::{
     AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EnabledForPath,
     ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, ProcessLint,
-    SearchCapabilities, is_diagnostic_error,
+    SearchCapabilities, is_diagnostic_error, search,
 };
 use crate::WorkspaceError;
+use crate::diagnostics::MixedGraphqlSchemaSources;
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
@@ -20,21 +21,23 @@ use biome_configuration::graphql::{
     GraphqlAssistConfiguration, GraphqlAssistEnabled, GraphqlFormatterConfiguration,
     GraphqlFormatterEnabled, GraphqlLinterConfiguration, GraphqlLinterEnabled,
 };
-use biome_diagnostics::Applicability;
+use biome_diagnostics::{Applicability, DiagnosticExt, Error};
 use biome_formatter::{
     BracketSpacing, FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
     QuoteStyle,
 };
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, normalize_path};
 use biome_graphql_analyze::analyze;
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_formatter::format_node;
-use biome_graphql_parser::parse_graphql_with_cache;
+use biome_graphql_parser::{GraphqlParserOptions, parse_graphql, parse_graphql_with_cache};
+use biome_graphql_semantic::{GraphqlSchema, GraphqlSchemaBuilder};
 use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache, TokenAtOffset};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::borrow::Cow;
+use std::sync::Arc;
 use tracing::{debug_span, error, info, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -77,6 +80,52 @@ impl From<GraphqlLinterConfiguration> for GraphqlLinterSettings {
     }
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GraphqlEnvironmentSettings {
+    /// Paths to the files that describe the schema of the project.
+    pub schema: Option<Vec<String>>,
+
+    /// The directory that the paths of [Self::schema] are relative to.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) schema_base_path: Utf8PathBuf,
+
+    /// The schema built from the files of [Self::schema]. It's loaded by the
+    /// workspace, and reloaded when one of these files changes.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) loaded_schema: Option<Arc<GraphqlSchema>>,
+}
+
+impl GraphqlEnvironmentSettings {
+    /// Whether the file at `path` is one of the files of [Self::schema].
+    pub(crate) fn is_schema_file(&self, path: &Utf8Path) -> bool {
+        self.schema
+            .iter()
+            .flatten()
+            .any(|schema_path| normalize_path(&self.schema_base_path.join(schema_path)) == path)
+    }
+
+    /// Builds the schema from the files of [Self::schema], whose paths are
+    /// relative to `base_path`, and returns the diagnostics of the files that
+    /// couldn't be loaded.
+    pub(crate) fn load_schema(
+        &mut self,
+        base_path: Utf8PathBuf,
+        read_file: impl Fn(&Utf8Path) -> Result<String, Error>,
+    ) -> Vec<Error> {
+        let (schema, diagnostics) = load_schema(
+            read_file,
+            &base_path,
+            self.schema.as_deref().unwrap_or_default(),
+        );
+        self.schema_base_path = base_path;
+        self.loaded_schema = schema.map(Arc::new);
+        diagnostics
+    }
+}
+
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GraphqlAssistSettings {
@@ -96,7 +145,7 @@ impl ServiceLanguage for GraphqlLanguage {
     type LinterSettings = GraphqlLinterSettings;
     type FormatOptions = GraphqlFormatOptions;
     type ParserSettings = ();
-    type EnvironmentSettings = ();
+    type EnvironmentSettings = GraphqlEnvironmentSettings;
     type AssistSettings = GraphqlAssistSettings;
 
     fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
@@ -251,8 +300,8 @@ impl ServiceLanguage for GraphqlLanguage {
             .into()
     }
 
-    fn resolve_environment(_settings: Option<&Settings>) -> Option<&Self::EnvironmentSettings> {
-        None
+    fn resolve_environment(global: Option<&Settings>) -> Option<&Self::EnvironmentSettings> {
+        global.map(|g| &g.languages.graphql.environment)
     }
 }
 
@@ -281,6 +330,9 @@ impl ExtensionHandler for GraphqlFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -288,7 +340,9 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
         }
     }
 }
@@ -316,7 +370,7 @@ fn parse(
     _settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let parse = parse_graphql_with_cache(text, cache);
+    let parse = parse_graphql_with_cache(text, cache, GraphqlParserOptions::default());
 
     ParseResult {
         any_parse: parse.into(),
@@ -417,7 +471,86 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+/// Returns the schema that GraphQL operations are validated against, if one
+/// is configured and could be loaded.
+fn loaded_schema(workspace: &WorkspaceSettingsHandle) -> Option<Arc<GraphqlSchema>> {
+    workspace
+        .settings()?
+        .languages
+        .graphql
+        .environment
+        .loaded_schema
+        .clone()
+}
+
+/// Builds the schema described by the files of `graphql.schema`, whose paths
+/// are relative to `base_path`.
+///
+/// The schema is either built from SDL files, or from a single introspection
+/// file. No schema is returned when a file can't be loaded, because validating
+/// operations against a partial schema would report false positives.
+fn load_schema(
+    read_file: impl Fn(&Utf8Path) -> Result<String, Error>,
+    base_path: &Utf8Path,
+    paths: &[String],
+) -> (Option<GraphqlSchema>, Vec<Error>) {
+    if paths.is_empty() {
+        return (None, Vec::new());
+    }
+
+    let mut diagnostics: Vec<Error> = Vec::new();
+    let mut builder = GraphqlSchemaBuilder::default();
+    let mut introspection_schema = None;
+
+    for path in paths {
+        let path = normalize_path(&base_path.join(path));
+        let source = match read_file(&path) {
+            Ok(source) => source,
+            Err(diagnostic) => {
+                diagnostics.push(diagnostic);
+                continue;
+            }
+        };
+
+        if path.extension() == Some("json") {
+            if paths.len() > 1 {
+                diagnostics.push(
+                    MixedGraphqlSchemaSources {
+                        path: path.to_string(),
+                    }
+                    .into(),
+                );
+                continue;
+            }
+            let (schema, errors) = GraphqlSchema::from_introspection(&source);
+            diagnostics.extend(errors.into_iter().map(|error| {
+                error
+                    .with_file_path(path.as_str())
+                    .with_file_source_code(&source)
+            }));
+            introspection_schema = schema;
+        } else {
+            let parse = parse_graphql(&source);
+            if parse.has_errors() {
+                diagnostics.extend(parse.into_diagnostics().into_iter().map(|diagnostic| {
+                    Error::from(diagnostic)
+                        .with_file_path(path.as_str())
+                        .with_file_source_code(&source)
+                }));
+                continue;
+            }
+            builder.add_document(&parse.tree());
+        }
+    }
+
+    if !diagnostics.is_empty() {
+        return (None, diagnostics);
+    }
+    let schema = introspection_schema.unwrap_or_else(|| builder.build());
+    (Some(schema), diagnostics)
+}
+
+pub(crate) fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting GraphQL file", path =? params.path, language =? params.language)
         .entered();
     let workspace_settings = &params.workspace;
@@ -446,9 +579,14 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
-    let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-        process_lint.process_signal(signal)
-    });
+    let (_, analyze_diagnostics) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        loaded_schema(params.workspace),
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
 
     process_lint.into_result(params.parse.into_diagnostics(), analyze_diagnostics)
 }
@@ -467,7 +605,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         skip,
         suppression_reason,
         enabled_rules: rules,
-        plugins: _,
+        plugins,
         categories,
     } = params;
     let _ = debug_span!("Code actions GraphQL", range =? range, path =? path).entered();
@@ -504,19 +642,26 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
     info!("GraphQL runs the analyzer");
 
-    analyze(&tree, filter, &analyzer_options, |signal| {
-        actions.extend(signal.actions().into_code_action_iter().map(|item| {
-            CodeAction {
-                category: item.category.clone(),
-                rule_name: item
-                    .rule_name
-                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                suggestion: item.suggestion,
-            }
-        }));
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        loaded_schema(workspace),
+        &plugins,
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -556,53 +701,61 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         range: None,
     };
 
+    let schema = loaded_schema(&params.workspace);
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
 
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            schema.clone(),
+            &params.plugins,
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::ApplySuppressions => {
+                            // TODO: implement once a GraphQL suppression action is available
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: implement once a GraphQL suppression action is available
-                    }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
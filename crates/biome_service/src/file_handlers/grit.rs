@@ -255,6 +255,9 @@ impl ExtensionHandler for GritFileHandler {
                 lint: Some(lint),
                 code_actions: None,
                 rename: None,
+                rename_imports: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
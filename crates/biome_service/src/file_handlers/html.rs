@@ -1,34 +1,48 @@
+use super::embedded_templates::format_js_node;
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    AnalyzerCapabilities, AnalyzerVisitorBuilder, Capabilities, CodeActionsParams,
+    DebugCapabilities, DocumentFileSource, EnabledForPath, ExtensionHandler, FixAllParams,
+    FormatterCapabilities, LintParams, LintResults, ParseResult, ParserCapabilities, ProcessLint,
+    SearchCapabilities, css, is_diagnostic_error, javascript, search,
 };
 use crate::settings::{check_feature_activity, check_override_feature_activity};
-use crate::workspace::FixFileResult;
+use crate::workspace::{CodeAction, FixAction, FixFileMode, FixFileResult, PullActionsResult};
 use crate::{
     WorkspaceError,
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
     workspace::GetSyntaxTreeResult,
 };
-use biome_analyze::AnalyzerOptions;
-use biome_configuration::html::{HtmlFormatterConfiguration, HtmlFormatterEnabled};
-use biome_diagnostics::{Diagnostic, Severity};
+use biome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleError};
+use biome_configuration::html::{
+    HtmlFormatterConfiguration, HtmlFormatterEnabled, HtmlLinterConfiguration, HtmlLinterEnabled,
+};
+use biome_css_parser::{CssParserOptions, parse_css};
+use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_diagnostics::Applicability;
 use biome_formatter::{
-    AttributePosition, BracketSameLine, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
+    AttributePosition, BracketSameLine, FormatResult, Formatted, IndentStyle, IndentWidth,
+    LineEnding, LineWidth, Printed,
 };
 use biome_fs::BiomePath;
+use biome_html_analyze::analyze;
 use biome_html_formatter::context::SelfCloseVoidElements;
 use biome_html_formatter::{
-    HtmlFormatOptions,
-    context::{IndentScriptAndStyle, WhitespaceSensitivity},
-    format_node,
+    EmbeddedLanguage, EmbeddedLanguageFormatter, FormattedEmbeddedContent, HtmlFormatOptions,
+    context::{HtmlFormatContext, IndentScriptAndStyle, WhitespaceSensitivity},
+    format_node_with_embedded_languages,
 };
 use biome_html_parser::parse_html_with_cache;
-use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_html_syntax::{HtmlElement, HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_json_syntax::{JsonFileSource, JsonLanguage};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, Direction, Language, NodeCache, SyntaxNode, TextRange};
 use camino::Utf8Path;
-use tracing::debug_span;
+use std::borrow::Cow;
+use std::rc::Rc;
+use tracing::{debug_span, error, info, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -62,9 +76,23 @@ impl From<HtmlFormatterConfiguration> for HtmlFormatterSettings {
     }
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HtmlLinterSettings {
+    pub enabled: Option<HtmlLinterEnabled>,
+}
+
+impl From<HtmlLinterConfiguration> for HtmlLinterSettings {
+    fn from(configuration: HtmlLinterConfiguration) -> Self {
+        Self {
+            enabled: configuration.enabled,
+        }
+    }
+}
+
 impl ServiceLanguage for HtmlLanguage {
     type FormatterSettings = HtmlFormatterSettings;
-    type LinterSettings = ();
+    type LinterSettings = HtmlLinterSettings;
     type FormatOptions = HtmlFormatOptions;
     type ParserSettings = ();
     type EnvironmentSettings = ();
@@ -180,8 +208,33 @@ impl ServiceLanguage for HtmlLanguage {
         false
     }
 
-    fn linter_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
-        false
+    fn linter_enabled_for_file_path(settings: Option<&Settings>, path: &Utf8Path) -> bool {
+        settings
+            .and_then(|settings| {
+                let overrides_activity =
+                    settings
+                        .override_settings
+                        .patterns
+                        .iter()
+                        .rev()
+                        .find_map(|pattern| {
+                            check_override_feature_activity(
+                                pattern.languages.html.linter.enabled,
+                                pattern.linter.enabled,
+                            )
+                            .filter(|_| {
+                                // Then check whether the path satisfies
+                                pattern.is_file_included(path)
+                            })
+                        });
+
+                overrides_activity.or(check_feature_activity(
+                    settings.languages.html.linter.enabled,
+                    settings.linter.enabled,
+                ))
+            })
+            .unwrap_or_default()
+            .into()
     }
 
     fn resolve_environment(_settings: Option<&Settings>) -> Option<&Self::EnvironmentSettings> {
@@ -212,8 +265,11 @@ impl ExtensionHandler for HtmlFileHandler {
             },
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
-                code_actions: None,
+                code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -221,7 +277,9 @@ impl ExtensionHandler for HtmlFileHandler {
                 format_range: None,
                 format_on_type: None,
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
         }
     }
 }
@@ -257,6 +315,147 @@ fn parse(
     }
 }
 
+/// Formats a Html file, along with the content of its `<script>` and `<style>` elements.
+fn format_html_node(
+    options: HtmlFormatOptions,
+    root: &HtmlSyntaxNode,
+    biome_path: &BiomePath,
+    settings: WorkspaceSettingsHandle,
+) -> FormatResult<Formatted<HtmlFormatContext>> {
+    format_node_with_embedded_languages(
+        options,
+        root,
+        Rc::new(HtmlEmbeddedLanguageFormatter {
+            biome_path: biome_path.clone(),
+            settings,
+        }),
+    )
+}
+
+/// Formats the content of the `<script>` and `<style>` elements of a Html file with the
+/// JavaScript, JSON and CSS formatters.
+#[derive(Debug)]
+struct HtmlEmbeddedLanguageFormatter {
+    biome_path: BiomePath,
+    settings: WorkspaceSettingsHandle,
+}
+
+impl EmbeddedLanguageFormatter for HtmlEmbeddedLanguageFormatter {
+    fn format(&self, language: EmbeddedLanguage, code: &str) -> Option<FormattedEmbeddedContent> {
+        // The code is printed as it is when it has syntax errors
+        let (code, verbatim_ranges) = match language {
+            EmbeddedLanguage::Script | EmbeddedLanguage::Module => {
+                if !self
+                    .settings
+                    .formatter_enabled_for_file_path::<JsLanguage>(&self.biome_path)
+                {
+                    return None;
+                }
+                let file_source = script_file_source(language);
+                let parse = biome_js_parser::parse(code, file_source, JsParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                let options = self
+                    .settings
+                    .format_options::<JsLanguage>(&self.biome_path, &file_source.into());
+                let code = format_js_node(
+                    options,
+                    &parse.syntax(),
+                    &self.biome_path,
+                    WorkspaceSettingsHandle::from(self.settings.settings().cloned()),
+                )
+                .ok()?
+                .print()
+                .ok()?
+                .into_code();
+                let parse = biome_js_parser::parse(&code, file_source, JsParserOptions::default());
+                let verbatim_ranges = verbatim_ranges(&parse.syntax());
+                (code, verbatim_ranges)
+            }
+            EmbeddedLanguage::Json => {
+                if !self
+                    .settings
+                    .formatter_enabled_for_file_path::<JsonLanguage>(&self.biome_path)
+                {
+                    return None;
+                }
+                let parse = parse_json(code, JsonParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                let options = self.settings.format_options::<JsonLanguage>(
+                    &self.biome_path,
+                    &JsonFileSource::json().into(),
+                );
+                let code = biome_json_formatter::format_node(options, &parse.syntax())
+                    .ok()?
+                    .print()
+                    .ok()?
+                    .into_code();
+                (code, Vec::new())
+            }
+            EmbeddedLanguage::Style => {
+                if !self
+                    .settings
+                    .formatter_enabled_for_file_path::<CssLanguage>(&self.biome_path)
+                {
+                    return None;
+                }
+                let parse = parse_css(code, CssParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                let options = self
+                    .settings
+                    .format_options::<CssLanguage>(&self.biome_path, &CssFileSource::css().into());
+                let code = biome_css_formatter::format_node(options, &parse.syntax())
+                    .ok()?
+                    .print()
+                    .ok()?
+                    .into_code();
+                let parse = parse_css(&code, CssParserOptions::default());
+                let verbatim_ranges = verbatim_ranges(&parse.syntax());
+                (code, verbatim_ranges)
+            }
+        };
+
+        Some(FormattedEmbeddedContent {
+            code,
+            verbatim_ranges,
+        })
+    }
+}
+
+fn script_file_source(language: EmbeddedLanguage) -> JsFileSource {
+    if language == EmbeddedLanguage::Module {
+        JsFileSource::js_module()
+    } else {
+        JsFileSource::js_script()
+    }
+}
+
+/// Returns the ranges of the tokens and comments that span multiple lines, whose indentation
+/// is part of their content.
+fn verbatim_ranges<L: Language>(root: &SyntaxNode<L>) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    for token in root.descendants_tokens(Direction::Next) {
+        for piece in token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces())
+        {
+            if piece.is_comments() && piece.text().contains('\n') {
+                ranges.push(piece.text_range());
+            }
+        }
+        if token.text_trimmed().contains('\n') {
+            ranges.push(token.text_trimmed_range());
+        }
+    }
+    ranges
+}
+
 fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
     let syntax: HtmlSyntaxNode = parse.syntax();
     let tree: HtmlRoot = parse.tree();
@@ -275,7 +474,7 @@ fn debug_formatter_ir(
     let options = settings.format_options::<HtmlLanguage>(path, document_file_source);
 
     let tree = parse.syntax();
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_html_node(options, &tree, path, settings)?;
 
     let root_element = formatted.into_document();
     Ok(root_element.to_string())
@@ -291,7 +490,7 @@ fn format(
     let options = settings.format_options::<HtmlLanguage>(biome_path, document_file_source);
 
     let tree = parse.syntax();
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_html_node(options, &tree, biome_path, settings)?;
 
     match formatted.print() {
         Ok(printed) => Ok(printed),
@@ -303,42 +502,337 @@ fn format(
 fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting HTML file", path =? params.path, language =? params.language)
         .entered();
-    let diagnostics = params.parse.into_diagnostics();
-
-    let diagnostic_count = diagnostics.len() as u32;
-    let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
-    let errors = diagnostics
-        .iter()
-        .filter(|diag| diag.severity() <= Severity::Error)
-        .count();
-
-    LintResults {
-        diagnostics,
-        errors,
-        skipped_diagnostics,
+    let workspace_settings = &params.workspace;
+    let analyzer_options = workspace_settings.analyzer_options::<HtmlLanguage>(
+        params.path,
+        &params.language,
+        params.suppression_reason.as_deref(),
+    );
+    let tree: HtmlRoot = params.parse.tree();
+
+    let (enabled_rules, disabled_rules, analyzer_options) =
+        AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
+            .with_only(&params.only)
+            .with_skip(&params.skip)
+            .with_path(params.path.as_path())
+            .with_enabled_rules(&params.enabled_rules)
+            .with_project_layout(params.project_layout.clone())
+            .finish();
+
+    let filter = AnalysisFilter {
+        categories: params.categories,
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: None,
+    };
+
+    let mut process_lint = ProcessLint::new(&params);
+
+    let (_, analyze_diagnostics) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
+    let embedded_results = lint_embedded_content(&params, &tree);
+
+    let mut results =
+        process_lint.into_result(params.parse.into_diagnostics(), analyze_diagnostics);
+    results.errors += embedded_results.errors;
+    results.skipped_diagnostics += embedded_results.skipped_diagnostics;
+    results.diagnostics.extend(embedded_results.diagnostics);
+    results
+}
+
+/// Lints the content of the `<script>` and `<style>` elements with the JavaScript and CSS
+/// analyzers. Content with syntax errors is skipped, the same way the formatter prints it as it is.
+fn lint_embedded_content(params: &LintParams, root: &HtmlRoot) -> LintResults {
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+
+    for element in root.syntax().descendants().filter_map(HtmlElement::cast) {
+        let Some(language) = element
+            .opening_element()
+            .ok()
+            .and_then(|opening_element| EmbeddedLanguage::from_opening_element(&opening_element))
+        else {
+            continue;
+        };
+        let children = element.children();
+        let range = children.syntax().text_range_with_trivia();
+        let code = children.syntax().to_string();
+
+        let embedded_params = |parse, language| LintParams {
+            parse,
+            workspace: params.workspace,
+            language,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            module_graph: params.module_graph.clone(),
+            project_layout: params.project_layout.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+            enabled_rules: params.enabled_rules.clone(),
+            plugins: params.plugins.clone(),
+            // The actions would apply to the content of the element
+            pull_code_actions: false,
+        };
+        let embedded_results = match language {
+            EmbeddedLanguage::Script | EmbeddedLanguage::Module => {
+                if !params
+                    .workspace
+                    .linter_enabled_for_file_path::<JsLanguage>(params.path)
+                {
+                    continue;
+                }
+                let file_source = script_file_source(language);
+                let parse = biome_js_parser::parse(&code, file_source, JsParserOptions::default());
+                if parse.has_errors() {
+                    continue;
+                }
+                javascript::lint(embedded_params(parse.into(), file_source.into()))
+            }
+            EmbeddedLanguage::Style => {
+                if !params
+                    .workspace
+                    .linter_enabled_for_file_path::<CssLanguage>(params.path)
+                {
+                    continue;
+                }
+                let parse = parse_css(&code, CssParserOptions::default());
+                if parse.has_errors() {
+                    continue;
+                }
+                css::lint(embedded_params(parse.into(), CssFileSource::css().into()))
+            }
+            EmbeddedLanguage::Json => continue,
+        };
+
+        results.errors += embedded_results.errors;
+        results.skipped_diagnostics += embedded_results.skipped_diagnostics;
+        results.diagnostics.extend(
+            embedded_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(range.start())),
+        );
     }
+
+    results
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let CodeActionsParams {
+        parse,
+        range,
+        workspace,
+        path,
+        module_graph: _,
+        project_layout,
+        language,
+        only,
+        skip,
+        suppression_reason,
+        enabled_rules: rules,
+        plugins,
+        categories,
+    } = params;
+    let _ = debug_span!("Code actions HTML", range =? range, path =? path).entered();
+    let tree: HtmlRoot = parse.tree();
+    let _ = trace_span!("Parsed file", tree =? tree).entered();
+    let Some(_) = language.to_html_file_source() else {
+        error!("Could not determine the file source of the file");
+        return PullActionsResult {
+            actions: Vec::new(),
+        };
+    };
+
+    let analyzer_options =
+        workspace.analyzer_options::<HtmlLanguage>(path, &language, suppression_reason.as_deref());
+    let mut actions = Vec::new();
+    let (enabled_rules, disabled_rules, analyzer_options) =
+        AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
+            .with_only(&only)
+            .with_skip(&skip)
+            .with_path(path.as_path())
+            .with_enabled_rules(&rules)
+            .with_project_layout(project_layout)
+            .finish();
+
+    let filter = AnalysisFilter {
+        categories,
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range,
+    };
+
+    info!("HTML runs the analyzer");
+
+    analyze(&tree, filter, &analyzer_options, &plugins, |signal| {
+        actions.extend(signal.actions().into_code_action_iter().map(|item| {
+            CodeAction {
+                category: item.category.clone(),
+                rule_name: item
+                    .rule_name
+                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                suggestion: item.suggestion,
+            }
+        }));
+
+        ControlFlow::<Never>::Continue(())
+    });
+
+    PullActionsResult { actions }
 }
 
+/// If applies all the safe fixes to the given syntax tree.
 #[tracing::instrument(level = "debug", skip(params))]
 pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    // We don't have analyzer rules yet
-    let tree: HtmlRoot = params.parse.tree();
-    let code = if params.should_format {
-        format_node(
-            params
-                .workspace
-                .format_options::<HtmlLanguage>(params.biome_path, &params.document_file_source),
-            tree.syntax(),
-        )?
-        .print()?
-        .into_code()
-    } else {
-        tree.syntax().to_string()
+    let mut tree: HtmlRoot = params.parse.tree();
+    let Some(settings) = params.workspace.settings() else {
+        return Ok(FixFileResult {
+            actions: Vec::new(),
+            errors: 0,
+            skipped_suggested_fixes: 0,
+            code: tree.syntax().to_string(),
+        });
     };
-    Ok(FixFileResult {
-        code,
-        skipped_suggested_fixes: 0,
-        actions: vec![],
-        errors: 0,
-    })
+
+    // Compute final rules (taking `overrides` into account)
+    let rules = settings.as_linter_rules(params.biome_path.as_path());
+    let analyzer_options = params.workspace.analyzer_options::<HtmlLanguage>(
+        params.biome_path,
+        &params.document_file_source,
+        params.suppression_reason.as_deref(),
+    );
+    let (enabled_rules, disabled_rules, analyzer_options) =
+        AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
+            .with_only(&params.only)
+            .with_skip(&params.skip)
+            .with_path(params.biome_path.as_path())
+            .with_enabled_rules(&params.enabled_rules)
+            .with_project_layout(params.project_layout)
+            .finish();
+
+    let filter = AnalysisFilter {
+        categories: params.rule_categories,
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: None,
+    };
+
+    let mut actions = Vec::new();
+    let mut skipped_suggested_fixes = 0;
+    let mut errors: u16 = 0;
+
+    loop {
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            &params.plugins,
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
+                }
+
+                for action in signal.actions() {
+                    match params.fix_file_mode {
+                        FixFileMode::ApplySuppressions => {
+                            if action.is_suppression() {
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                        FixFileMode::SafeFixes => {
+                            // suppression actions should not be part of the fixes (safe or suggested)
+                            if action.is_suppression() {
+                                continue;
+                            }
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if action.is_suppression() {
+                                continue;
+                            }
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                    }
+                }
+
+                ControlFlow::Continue(())
+            },
+        );
+
+        match action {
+            Some(action) => {
+                if let (root, Some((range, _))) =
+                    action.mutation.commit_with_text_range_and_edit(true)
+                {
+                    tree = match HtmlRoot::cast(root) {
+                        Some(tree) => tree,
+                        None => {
+                            return Err(WorkspaceError::RuleError(
+                                RuleError::ReplacedRootWithNonRootError {
+                                    rule_name: action.rule_name.map(|(group, rule)| {
+                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
+                                    }),
+                                },
+                            ));
+                        }
+                    };
+                    actions.push(FixAction {
+                        rule_name: action
+                            .rule_name
+                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+                        range,
+                    });
+                }
+            }
+            None => {
+                let code = if params.should_format {
+                    format_html_node(
+                        params.workspace.format_options::<HtmlLanguage>(
+                            params.biome_path,
+                            &params.document_file_source,
+                        ),
+                        tree.syntax(),
+                        params.biome_path,
+                        params.workspace,
+                    )?
+                    .print()?
+                    .into_code()
+                } else {
+                    tree.syntax().to_string()
+                };
+                return Ok(FixFileResult {
+                    code,
+                    skipped_suggested_fixes,
+                    actions,
+                    errors: errors.into(),
+                });
+            }
+        }
+    }
 }
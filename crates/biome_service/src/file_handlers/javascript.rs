@@ -1,7 +1,9 @@
+use super::embedded_templates::{format_js_node, lint_embedded_templates};
 use super::{
     AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    EnabledForPath, ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, ProcessLint, SearchCapabilities, search,
+    EnabledForPath, ExtensionHandler, FormatterCapabilities, LintParams, LintResults,
+    NavigationParams, ParseResult, ParserCapabilities, ProcessLint, RenameImportsParams,
+    SearchCapabilities, search,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
@@ -18,7 +20,7 @@ use crate::{
     },
     workspace::{
         CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        RenameResult, SymbolLocation,
     },
 };
 use biome_analyze::options::PreferredQuote;
@@ -27,9 +29,10 @@ use biome_analyze::{
     RuleCategoriesBuilder, RuleError, RuleFilter,
 };
 use biome_configuration::javascript::{
-    JsAssistConfiguration, JsAssistEnabled, JsFormatterConfiguration, JsFormatterEnabled,
-    JsGritMetavariable, JsLinterConfiguration, JsLinterEnabled, JsParserConfiguration,
-    JsxEverywhere, JsxRuntime, UnsafeParameterDecoratorsEnabled,
+    JsAssistConfiguration, JsAssistEnabled, JsEmbeddedTemplatesConfiguration,
+    JsFormatterConfiguration, JsFormatterEnabled, JsGritMetavariable, JsLinterConfiguration,
+    JsLinterEnabled, JsParserConfiguration, JsxEverywhere, JsxRuntime,
+    UnsafeParameterDecoratorsEnabled,
 };
 use biome_diagnostics::Applicability;
 use biome_formatter::{
@@ -37,25 +40,33 @@ use biome_formatter::{
     IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+use biome_js_analyze::utils::rename::{
+    AnyJsRenamableDeclaration, RenameError, RenameSymbolExtensions,
+};
 use biome_js_analyze::{
-    ControlFlowGraph, JsAnalyzerServices, analyze, analyze_with_inspect_matcher,
+    ControlFlowGraph, EmbeddedBindings, JsAnalyzerServices, analyze, analyze_with_inspect_matcher,
 };
+use biome_js_factory::make;
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{ArrowParentheses, JsFormatOptions, QuoteProperties, Semicolons};
-use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{SemanticModelOptions, semantic_model};
+use biome_js_semantic::{Binding, SemanticModel, SemanticModelOptions, semantic_model};
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
 use biome_js_syntax::{
-    AnyJsRoot, JsClassDeclaration, JsClassExpression, JsFileSource, JsFunctionDeclaration,
-    JsLanguage, JsSyntaxNode, JsVariableDeclarator, LanguageVariant, TextRange, TextSize,
-    TokenAtOffset,
+    AnyJsCombinedSpecifier, AnyJsIdentifierUsage, AnyJsImportClause, AnyJsModuleSource,
+    AnyJsNamedImportSpecifier, AnyJsRoot, JsClassDeclaration, JsClassExpression,
+    JsExportNamedFromClause, JsFileSource, JsFunctionDeclaration, JsIdentifierBinding, JsLanguage,
+    JsStaticMemberExpression, JsSyntaxNode, JsSyntaxToken, JsVariableDeclarator, LanguageVariant,
+    T, TextRange, TextSize, TokenAtOffset, TsQualifiedName,
 };
 use biome_js_type_info::{GlobalsResolver, ScopeId, TypeData, TypeResolver};
 use biome_module_graph::ModuleGraph;
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache, WalkEvent};
-use camino::Utf8Path;
+use biome_rowan::{
+    AstNode, AstSeparatedList, BatchMutationExt, Direction, NodeCache, SyntaxResult, WalkEvent,
+};
+use biome_text_edit::TextEdit;
+use camino::{Utf8Path, Utf8PathBuf};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -158,12 +169,14 @@ impl From<JsAssistConfiguration> for JsAssistSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsEnvironmentSettings {
     pub jsx_runtime: Option<JsxRuntime>,
+    pub embedded_templates: Option<JsEmbeddedTemplatesConfiguration>,
 }
 
 impl From<JsxRuntime> for JsEnvironmentSettings {
     fn from(jsx_runtime: JsxRuntime) -> Self {
         Self {
             jsx_runtime: Some(jsx_runtime),
+            embedded_templates: None,
         }
     }
 }
@@ -494,6 +507,9 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                rename_imports: Some(rename_imports),
+                goto_definition: Some(goto_definition),
+                find_references: Some(find_references),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -631,7 +647,7 @@ fn debug_formatter_ir(
     let options = settings.format_options::<JsLanguage>(path, document_file_source);
 
     let tree = parse.syntax();
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_js_node(options, &tree, path, settings)?;
 
     let root_element = formatted.into_document();
     Ok(root_element.to_string())
@@ -740,6 +756,16 @@ fn debug_semantic_model(_path: &BiomePath, parse: AnyParse) -> Result<String, Wo
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
+    lint_with_embedded_bindings(params, EmbeddedBindings::default())
+}
+
+/// Lints a script embedded in another document, e.g. a `<script>` block of a
+/// Vue component, knowing the bindings that the rest of the document declares
+/// and references.
+pub(crate) fn lint_with_embedded_bindings(
+    params: LintParams,
+    embedded_bindings: EmbeddedBindings,
+) -> LintResults {
     let _ =
         debug_span!("Linting JavaScript file", path =? params.path, language =? params.language)
             .entered();
@@ -776,9 +802,11 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
         range: None,
     };
 
+    let embedded_results = lint_embedded_templates(&params, &tree);
     let mut process_lint = ProcessLint::new(&params);
     let services =
-        JsAnalyzerServices::from((params.module_graph, params.project_layout, file_source));
+        JsAnalyzerServices::from((params.module_graph, params.project_layout, file_source))
+            .with_embedded_bindings(embedded_bindings);
     let (_, analyze_diagnostics) = analyze(
         &tree,
         filter,
@@ -788,11 +816,25 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
         |signal| process_lint.process_signal(signal),
     );
 
-    process_lint.into_result(params.parse.into_diagnostics(), analyze_diagnostics)
+    let mut results =
+        process_lint.into_result(params.parse.into_diagnostics(), analyze_diagnostics);
+    results.errors += embedded_results.errors;
+    results.skipped_diagnostics += embedded_results.skipped_diagnostics;
+    results.diagnostics.extend(embedded_results.diagnostics);
+    results
 }
 
-#[tracing::instrument(level = "debug", skip(params))]
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    code_actions_with_embedded_bindings(params, EmbeddedBindings::default())
+}
+
+/// Pulls the code actions of a script embedded in another document, see
+/// [lint_with_embedded_bindings].
+#[tracing::instrument(level = "debug", skip(params, embedded_bindings))]
+pub(crate) fn code_actions_with_embedded_bindings(
+    params: CodeActionsParams,
+    embedded_bindings: EmbeddedBindings,
+) -> PullActionsResult {
     let CodeActionsParams {
         parse,
         range,
@@ -836,7 +878,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         };
     };
 
-    let services = JsAnalyzerServices::from((module_graph, project_layout, source_type));
+    let services = JsAnalyzerServices::from((module_graph, project_layout, source_type))
+        .with_embedded_bindings(embedded_bindings);
 
     debug!("Javascript runs the analyzer");
     analyze(
@@ -866,6 +909,15 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
 /// If applies all the safe fixes to the given syntax tree.
 pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    fix_all_with_embedded_bindings(params, EmbeddedBindings::default())
+}
+
+/// Applies the fixes to a script embedded in another document, see
+/// [lint_with_embedded_bindings].
+pub(crate) fn fix_all_with_embedded_bindings(
+    params: FixAllParams,
+    embedded_bindings: EmbeddedBindings,
+) -> Result<FixFileResult, WorkspaceError> {
     let mut tree: AnyJsRoot = params.parse.tree();
     let Some(settings) = params.workspace.settings() else {
         return Ok(FixFileResult {
@@ -916,7 +968,8 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             params.module_graph.clone(),
             params.project_layout.clone(),
             file_source,
-        ));
+        ))
+        .with_embedded_bindings(embedded_bindings.clone());
 
         let (action, _) = analyze(
             &tree,
@@ -998,12 +1051,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             }
             None => {
                 let code = if params.should_format {
-                    format_node(
+                    format_js_node(
                         params.workspace.format_options::<JsLanguage>(
                             params.biome_path,
                             &params.document_file_source,
                         ),
                         tree.syntax(),
+                        params.biome_path,
+                        params.workspace,
                     )?
                     .print()?
                     .into_code()
@@ -1030,7 +1085,7 @@ pub(crate) fn format(
     let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
     debug!("{:?}", &options);
     let tree = parse.syntax();
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_js_node(options, &tree, biome_path, settings)?;
     match formatted.print() {
         Ok(printed) => Ok(printed),
         Err(error) => {
@@ -1122,7 +1177,11 @@ fn rename(
                     }))
                 } else {
                     let (range, indels) = batch.to_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        other_files: Vec::new(),
+                    })
                 }
             }
             Err(err) => Err(WorkspaceError::RenameError(err)),
@@ -1134,6 +1193,277 @@ fn rename(
     }
 }
 
+/// Updates the imports and re-exports of a binding that was renamed in another
+/// module, from `old_name` to `new_name`.
+///
+/// Only the import and export clauses whose source is one of the given
+/// `specifiers` are updated. Imported bindings are renamed together with their
+/// references, unless that would conflict with another binding, in which case
+/// the new name is aliased to the old one. Re-exports keep exporting the symbol
+/// under its old name, so modules further down the chain are unaffected.
+fn rename_imports(
+    params: RenameImportsParams,
+) -> Result<Option<(TextRange, TextEdit)>, WorkspaceError> {
+    let RenameImportsParams {
+        parse,
+        specifiers,
+        old_name,
+        new_name,
+    } = params;
+
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let mut batch = root.clone().begin();
+    let is_renamed_source = |source: SyntaxResult<AnyJsModuleSource>| {
+        source
+            .ok()
+            .and_then(|source| source.as_js_module_source()?.inner_string_text().ok())
+            .is_some_and(|source| {
+                specifiers
+                    .iter()
+                    .any(|specifier| specifier.text() == source.text())
+            })
+    };
+
+    for node in root.syntax().descendants() {
+        if let Some(clause) = AnyJsImportClause::cast_ref(&node) {
+            if !is_renamed_source(clause.source().map(AnyJsModuleSource::from)) {
+                continue;
+            }
+
+            if let Some(binding) = namespace_import_binding(&clause) {
+                let binding = model.as_binding(&binding);
+                for reference in binding.all_references() {
+                    let Some(member_token) = namespace_member_token(reference.syntax()) else {
+                        continue;
+                    };
+                    if member_token.text_trimmed() == old_name {
+                        batch.replace_token(member_token, make::ident(new_name));
+                    }
+                }
+            }
+
+            let Some(named_specifiers) = clause.named_specifiers() else {
+                continue;
+            };
+            for specifier in named_specifiers.specifiers().iter().flatten() {
+                match specifier {
+                    AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                        let Ok(name_token) = specifier.name().and_then(|name| name.value()) else {
+                            continue;
+                        };
+                        if name_token.text_trimmed() == old_name {
+                            batch.replace_token(name_token, make::ident(new_name));
+                        }
+                    }
+                    AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                        let Some(binding) = specifier
+                            .local_name()
+                            .ok()
+                            .and_then(|binding| binding.as_js_identifier_binding().cloned())
+                        else {
+                            continue;
+                        };
+                        if binding
+                            .name_token()
+                            .map_or(true, |token| token.text_trimmed() != old_name)
+                        {
+                            continue;
+                        }
+
+                        let declaration =
+                            AnyJsRenamableDeclaration::JsIdentifierBinding(binding.clone());
+                        if !batch.rename_node_declaration(&model, &declaration, new_name) {
+                            let mut aliased_specifier = make::js_named_import_specifier(
+                                make::js_literal_export_name(make::ident(new_name)),
+                                make::token_decorated_with_space(T![as]),
+                                binding.into(),
+                            );
+                            if let Some(type_token) = specifier.type_token() {
+                                aliased_specifier = aliased_specifier.with_type_token(type_token);
+                            }
+                            batch.replace_node(
+                                AnyJsNamedImportSpecifier::from(specifier),
+                                aliased_specifier.build().into(),
+                            );
+                        }
+                    }
+                    AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+                }
+            }
+        } else if let Some(clause) = JsExportNamedFromClause::cast_ref(&node) {
+            if !is_renamed_source(clause.source()) {
+                continue;
+            }
+
+            for specifier in clause.specifiers().iter().flatten() {
+                let Ok(source_name) = specifier.source_name() else {
+                    continue;
+                };
+                let Ok(name_token) = source_name.value() else {
+                    continue;
+                };
+                if name_token.text_trimmed() != old_name {
+                    continue;
+                }
+
+                if specifier.export_as().is_some() {
+                    batch.replace_token(name_token, make::ident(new_name));
+                } else {
+                    let mut aliased_specifier = make::js_export_named_from_specifier(
+                        make::js_literal_export_name(make::ident(new_name)),
+                    )
+                    .with_export_as(make::js_export_as_clause(
+                        make::token_decorated_with_space(T![as]),
+                        make::js_literal_export_name(make::ident(old_name)),
+                    ));
+                    if let Some(type_token) = specifier.type_token() {
+                        aliased_specifier = aliased_specifier.with_type_token(type_token);
+                    }
+                    batch.replace_node(specifier, aliased_specifier.build());
+                }
+            }
+        }
+    }
+
+    Ok(batch.to_text_range_and_edit())
+}
+
+/// Returns the binding of the namespace import in `clause`, such as `ns` in
+/// `import * as ns from "./foo"`.
+fn namespace_import_binding(clause: &AnyJsImportClause) -> Option<JsIdentifierBinding> {
+    let specifier = match clause {
+        AnyJsImportClause::JsImportNamespaceClause(clause) => clause.namespace_specifier().ok()?,
+        AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier().ok()? {
+            AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier) => specifier,
+            AnyJsCombinedSpecifier::JsNamedImportSpecifiers(_) => return None,
+        },
+        _ => return None,
+    };
+
+    specifier
+        .local_name()
+        .ok()?
+        .as_js_identifier_binding()
+        .cloned()
+}
+
+/// Returns the token of the member that is accessed through the reference to
+/// a namespace import, such as `foo` in `ns.foo` or in the type `ns.Foo`.
+fn namespace_member_token(reference: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    let parent = reference.parent()?;
+    if let Some(qualified_name) = TsQualifiedName::cast_ref(&parent) {
+        return qualified_name.right().ok()?.value_token().ok();
+    }
+
+    let member_expression = JsStaticMemberExpression::cast(parent.parent()?)?;
+    if member_expression.object().ok()?.syntax() != &parent {
+        return None;
+    }
+    member_expression
+        .member()
+        .ok()?
+        .as_js_name()?
+        .value_token()
+        .ok()
+}
+
+fn goto_definition(params: NavigationParams) -> Result<Option<SymbolLocation>, WorkspaceError> {
+    let NavigationParams {
+        parse,
+        path,
+        module_graph,
+        symbol_at,
+    } = params;
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some(binding) = binding_at_offset(&model, &root, symbol_at) else {
+        return Ok(None);
+    };
+
+    let range = binding.syntax().text_trimmed_range();
+    let location = match module_graph.find_binding_declaration(path, range) {
+        Some((declaration_path, declaration_range)) => SymbolLocation {
+            path: BiomePath::new(declaration_path),
+            range: declaration_range,
+        },
+        None => SymbolLocation {
+            path: path.clone(),
+            range,
+        },
+    };
+
+    Ok(Some(location))
+}
+
+fn find_references(
+    params: NavigationParams,
+    include_declaration: bool,
+) -> Result<Vec<SymbolLocation>, WorkspaceError> {
+    let NavigationParams {
+        parse,
+        path,
+        module_graph,
+        symbol_at,
+    } = params;
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some(binding) = binding_at_offset(&model, &root, symbol_at) else {
+        return Ok(Vec::new());
+    };
+
+    let range = binding.syntax().text_trimmed_range();
+    let declaration = module_graph
+        .find_binding_declaration(path, range)
+        .unwrap_or_else(|| (path.to_path_buf(), range));
+
+    // References inside the current file are taken from the semantic model,
+    // which is always in sync with the document. The module graph is only
+    // consulted for references inside other files.
+    let mut references: Vec<(Utf8PathBuf, TextRange)> = std::iter::once(range)
+        .chain(
+            binding
+                .all_references()
+                .map(|reference| reference.syntax().text_trimmed_range()),
+        )
+        .map(|range| (path.to_path_buf(), range))
+        .collect();
+    references.extend(
+        module_graph
+            .find_binding_references(path, range)
+            .into_iter()
+            .filter(|(reference_path, _)| reference_path != path.as_path()),
+    );
+
+    if !include_declaration {
+        references.retain(|reference| reference != &declaration);
+    }
+
+    Ok(references
+        .into_iter()
+        .map(|(path, range)| SymbolLocation {
+            path: BiomePath::new(path),
+            range,
+        })
+        .collect())
+}
+
+/// Returns the binding declared or referenced by the identifier at `offset`.
+fn binding_at_offset(model: &SemanticModel, root: &AnyJsRoot, offset: TextSize) -> Option<Binding> {
+    root.syntax().token_at_offset(offset).find_map(|token| {
+        let node = token.parent()?;
+        if let Some(binding) = AnyJsIdentifierBinding::cast_ref(&node) {
+            return Some(model.as_binding(&binding));
+        }
+
+        match AnyJsIdentifierUsage::cast(node)? {
+            AnyJsIdentifierUsage::JsReferenceIdentifier(reference) => model.binding(&reference),
+            AnyJsIdentifierUsage::JsIdentifierAssignment(assignment) => model.binding(&assignment),
+            AnyJsIdentifierUsage::JsxReferenceIdentifier(reference) => model.binding(&reference),
+        }
+    })
+}
+
 #[cfg(test)]
 #[path = "javascript.tests.rs"]
 mod tests;
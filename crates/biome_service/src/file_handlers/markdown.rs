@@ -0,0 +1,445 @@
+use super::{
+    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
+    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
+    ParserCapabilities, SearchCapabilities,
+};
+use crate::settings::{check_feature_activity, check_override_feature_activity};
+use crate::workspace::{FixFileResult, GetSyntaxTreeResult};
+use crate::{
+    WorkspaceError,
+    settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+};
+use biome_analyze::AnalyzerOptions;
+use biome_configuration::markdown::{MarkdownFormatterConfiguration, MarkdownFormatterEnabled};
+use biome_css_parser::{CssParserOptions, parse_css};
+use biome_css_syntax::CssLanguage;
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_formatter::{FormatError, LineEnding, Printed};
+use biome_fs::BiomePath;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{EmbeddingKind, JsLanguage};
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::{
+    CodeBlockFormatter, context::MarkdownFormatOptions, format_node_with_code_blocks,
+    format_sub_tree,
+};
+use biome_markdown_parser::parse_markdown_with_cache;
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache, TextRange, TextSize, TokenAtOffset};
+use biome_string_case::StrLikeExtension;
+use camino::Utf8Path;
+use std::rc::Rc;
+use tracing::debug_span;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MarkdownFormatterSettings {
+    pub enabled: Option<MarkdownFormatterEnabled>,
+    pub line_ending: Option<LineEnding>,
+}
+
+impl From<MarkdownFormatterConfiguration> for MarkdownFormatterSettings {
+    fn from(config: MarkdownFormatterConfiguration) -> Self {
+        Self {
+            enabled: config.enabled,
+            line_ending: config.line_ending,
+        }
+    }
+}
+
+impl ServiceLanguage for MarkdownLanguage {
+    type FormatterSettings = MarkdownFormatterSettings;
+    type LinterSettings = ();
+    type FormatOptions = MarkdownFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+    type AssistSettings = ();
+
+    fn lookup_settings(
+        languages: &crate::settings::LanguageListSettings,
+    ) -> &crate::settings::LanguageSettings<Self> {
+        &languages.markdown
+    }
+
+    fn resolve_format_options(
+        global: Option<&crate::settings::FormatSettings>,
+        overrides: Option<&crate::settings::OverrideSettings>,
+        language: Option<&Self::FormatterSettings>,
+        path: &BiomePath,
+        _file_source: &DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let line_ending = language
+            .and_then(|l| l.line_ending)
+            .or(global.and_then(|g| g.line_ending))
+            .unwrap_or_default();
+
+        // The paragraphs aren't wrapped, and the indentation of the list items follows their
+        // markers, so only the line ending is configurable
+        let options = MarkdownFormatOptions::default().with_line_ending(line_ending);
+        if let Some(overrides) = overrides {
+            overrides.to_override_markdown_format_options(path, options)
+        } else {
+            options
+        }
+    }
+
+    fn resolve_analyzer_options(
+        _global: Option<&Settings>,
+        _language: Option<&Self::LinterSettings>,
+        _environment: Option<&Self::EnvironmentSettings>,
+        path: &BiomePath,
+        _file_source: &DocumentFileSource,
+        suppression_reason: Option<&str>,
+    ) -> AnalyzerOptions {
+        AnalyzerOptions::default()
+            .with_file_path(path.as_path())
+            .with_suppression_reason(suppression_reason)
+    }
+
+    fn formatter_enabled_for_file_path(settings: Option<&Settings>, path: &Utf8Path) -> bool {
+        settings
+            .and_then(|settings| {
+                let overrides_activity =
+                    settings
+                        .override_settings
+                        .patterns
+                        .iter()
+                        .rev()
+                        .find_map(|pattern| {
+                            check_override_feature_activity(
+                                pattern.languages.markdown.formatter.enabled,
+                                pattern.formatter.enabled,
+                            )
+                            .filter(|_| {
+                                // Then check whether the path satisfies
+                                pattern.is_file_included(path)
+                            })
+                        });
+
+                overrides_activity.or(check_feature_activity(
+                    settings.languages.markdown.formatter.enabled,
+                    settings.formatter.enabled,
+                ))
+            })
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn assist_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
+        false
+    }
+
+    fn linter_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
+        false
+    }
+
+    fn resolve_environment(_settings: Option<&Settings>) -> Option<&Self::EnvironmentSettings> {
+        None
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            enabled_for_path: EnabledForPath {
+                formatter: Some(formatter_enabled),
+                linter: Some(linter_enabled),
+                assist: Some(assist_enabled),
+                search: Some(search_enabled),
+            },
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+                debug_type_info: None,
+                debug_registered_types: None,
+                debug_semantic_model: None,
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: None,
+                rename: None,
+                rename_imports: None,
+                goto_definition: None,
+                find_references: None,
+                fix_all: Some(fix_all),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: Some(format_range),
+                format_on_type: Some(format_on_type),
+            },
+            search: SearchCapabilities { search: None },
+        }
+    }
+}
+
+/// Formats the fenced code blocks of a Markdown file whose language is supported by Biome,
+/// with the formatter options that apply to the Markdown file.
+#[derive(Debug)]
+struct MarkdownCodeBlockFormatter {
+    biome_path: BiomePath,
+    settings: WorkspaceSettingsHandle,
+}
+
+impl MarkdownCodeBlockFormatter {
+    /// Returns the file source of a code block whose info string starts with `language`.
+    /// Only JavaScript, TypeScript, CSS and JSON are supported.
+    fn file_source(language: &str) -> Option<DocumentFileSource> {
+        let language = language.to_ascii_lowercase_cow();
+        let file_source = match DocumentFileSource::from_language_id(&language) {
+            DocumentFileSource::Unknown => DocumentFileSource::from_extension(&language),
+            file_source => file_source,
+        };
+
+        match file_source {
+            DocumentFileSource::Js(js) if matches!(js.as_embedding_kind(), EmbeddingKind::None) => {
+                Some(file_source)
+            }
+            DocumentFileSource::Css(_) | DocumentFileSource::Json(_) => Some(file_source),
+            _ => None,
+        }
+    }
+}
+
+impl CodeBlockFormatter for MarkdownCodeBlockFormatter {
+    fn format(&self, language: &str, code: &str) -> Option<String> {
+        let file_source = Self::file_source(language)?;
+
+        // The code is printed as it is when it has syntax errors
+        let printed = match file_source {
+            DocumentFileSource::Js(js) => {
+                let parse = biome_js_parser::parse(code, js, JsParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                let options = self
+                    .settings
+                    .format_options::<JsLanguage>(&self.biome_path, &file_source);
+                biome_js_formatter::format_node(options, &parse.syntax())
+                    .ok()?
+                    .print()
+                    .ok()?
+            }
+            DocumentFileSource::Css(_) => {
+                let parse = parse_css(code, CssParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                let options = self
+                    .settings
+                    .format_options::<CssLanguage>(&self.biome_path, &file_source);
+                biome_css_formatter::format_node(options, &parse.syntax())
+                    .ok()?
+                    .print()
+                    .ok()?
+            }
+            DocumentFileSource::Json(json) => {
+                let parse = parse_json(code, JsonParserOptions::from(&json));
+                if parse.has_errors() {
+                    return None;
+                }
+                let options = self
+                    .settings
+                    .format_options::<JsonLanguage>(&self.biome_path, &file_source);
+                biome_json_formatter::format_node(options, &parse.syntax())
+                    .ok()?
+                    .print()
+                    .ok()?
+            }
+            _ => return None,
+        };
+
+        Some(printed.into_code())
+    }
+}
+
+fn formatter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.formatter_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.linter_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn assist_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.assist_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn search_enabled(_path: &Utf8Path, _handle: &WorkspaceSettingsHandle) -> bool {
+    true
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _handle: WorkspaceSettingsHandle,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = parse_markdown_with_cache(text, cache);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: MarkdownSyntaxNode = parse.syntax();
+    let tree: MdDocument = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let code_blocks = MarkdownCodeBlockFormatter {
+        biome_path: biome_path.clone(),
+        settings,
+    };
+    let formatted = format_node_with_code_blocks(options, &tree, Rc::new(code_blocks))?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let code_blocks = MarkdownCodeBlockFormatter {
+        biome_path: biome_path.clone(),
+        settings,
+    };
+    let formatted = format_node_with_code_blocks(options, &tree, Rc::new(code_blocks))?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn format_range(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    range: TextRange,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let printed = biome_markdown_formatter::format_range(options, &tree, range)?;
+    Ok(printed)
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn format_on_type(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    offset: TextSize,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+
+    let range = tree.text_range_with_trivia();
+    if offset < range.start() || offset > range.end() {
+        return Err(WorkspaceError::FormatError(FormatError::RangeError {
+            input: TextRange::at(offset, TextSize::from(0)),
+            tree: range,
+        }));
+    }
+
+    let token = match tree.token_at_offset(offset) {
+        // File is empty, do nothing
+        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::Single(token) => token,
+        // The cursor should be right after the closing character that was just typed,
+        // select the previous token as the correct one
+        TokenAtOffset::Between(token, _) => token,
+    };
+
+    let root_node = match token.parent() {
+        Some(node) => node,
+        None => panic!("found a token with no parent"),
+    };
+
+    let printed = format_sub_tree(options, &root_node)?;
+    Ok(printed)
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+fn lint(params: LintParams) -> LintResults {
+    let _ = debug_span!(
+        "Linting Markdown file",
+        path =? params.path,
+        language =? params.language
+    )
+    .entered();
+    let diagnostics = params.parse.into_diagnostics();
+
+    let diagnostic_count = diagnostics.len() as u32;
+    let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+    let errors = diagnostics
+        .iter()
+        .filter(|diag| diag.severity() <= Severity::Error)
+        .count();
+
+    LintResults {
+        diagnostics,
+        errors,
+        skipped_diagnostics,
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    // We don't have analyzer rules yet
+    let tree: MdDocument = params.parse.tree();
+    let code = if params.should_format {
+        let options = params
+            .workspace
+            .format_options::<MarkdownLanguage>(params.biome_path, &params.document_file_source);
+        let code_blocks = MarkdownCodeBlockFormatter {
+            biome_path: params.biome_path.clone(),
+            settings: params.workspace,
+        };
+        format_node_with_code_blocks(options, tree.syntax(), Rc::new(code_blocks))?
+            .print()?
+            .into_code()
+    } else {
+        tree.syntax().to_string()
+    };
+    Ok(FixFileResult {
+        code,
+        skipped_suggested_fixes: 0,
+        actions: vec![],
+        errors: 0,
+    })
+}
@@ -5,77 +5,348 @@ use crate::file_handlers::{
     ParserCapabilities, javascript,
 };
 use crate::settings::WorkspaceSettingsHandle;
-use crate::workspace::{DocumentFileSource, FixFileResult, PullActionsResult};
-use biome_formatter::Printed;
+use crate::workspace::{
+    CodeAction, DocumentFileSource, FixAction, FixFileResult, PullActionsResult,
+};
+use biome_formatter::{FormatError, Printed};
 use biome_fs::BiomePath;
-use biome_js_parser::{JsParserOptions, parse_js_with_cache};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
+use biome_html_parser::{parse_html, parse_html_with_cache};
+use biome_html_syntax::HtmlRoot;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_js_analyze::EmbeddedBindings;
+use biome_js_parser::{JsParserOptions, parse, parse_js_with_cache};
+use biome_js_semantic::{SemanticModelOptions, semantic_model};
+use biome_js_syntax::{
+    AnyJsRoot, EmbeddingKind, JsFileSource, Language, LanguageVariant, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
-use regex::{Match, Regex};
+use biome_rowan::{AstNode, AstNodeList, NodeCache};
+use biome_string_case::Case;
+use regex::Regex;
+use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 use tracing::debug;
 
-use super::{SearchCapabilities, parse_lang_from_script_opening_tag};
+use super::{SearchCapabilities, language_from_script_lang};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct VueFileHandler;
 
-// https://regex101.com/r/E4n4hh/6
-pub static VUE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#).unwrap()
-});
+/// Matches the text interpolations of a Vue template, e.g. `{{ count + 1 }}`
+static VUE_INTERPOLATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)\{\{(.*?)\}\}").unwrap());
+
+/// A `<script>` block of a Vue single-file component
+struct VueScript {
+    /// The range of the code of the block inside the component
+    range: TextRange,
+    file_source: JsFileSource,
+    parse: AnyParse,
+}
+
+/// The script blocks of a Vue single-file component, parsed independently
+struct VueComponent {
+    root: HtmlRoot,
+    /// The source text of the whole component
+    text: String,
+    scripts: Vec<VueScript>,
+}
+
+impl VueComponent {
+    fn new(root: HtmlRoot) -> Self {
+        let text = root.syntax().to_string();
+        let scripts = script_blocks(&root, &text)
+            .map(|(range, file_source)| VueScript {
+                range,
+                file_source,
+                parse: parse_js_with_cache(
+                    &text[range],
+                    file_source,
+                    JsParserOptions::default(),
+                    &mut NodeCache::default(),
+                )
+                .into(),
+            })
+            .collect();
+
+        Self {
+            root,
+            text,
+            scripts,
+        }
+    }
+
+    /// Returns, for each script block, the bindings that the rest of the
+    /// component declares and references: the other script blocks and the
+    /// template.
+    fn embedded_bindings(&self) -> Vec<EmbeddedBindings> {
+        let template_references = template_references(&self.root);
+        let models: Vec<_> = self
+            .scripts
+            .iter()
+            .map(|script| semantic_model(&script.parse.tree(), SemanticModelOptions::default()))
+            .collect();
+
+        (0..self.scripts.len())
+            .map(|index| {
+                let mut bindings = EmbeddedBindings::default();
+                for reference in &template_references {
+                    bindings.insert_reference(reference.as_ref());
+                }
+                for (_, model) in models.iter().enumerate().filter(|(i, _)| *i != index) {
+                    for binding in model.global_scope().bindings() {
+                        if let Ok(token) = binding.tree().name_token() {
+                            bindings.insert_declaration(token.text_trimmed());
+                        }
+                    }
+                    for reference in model.all_unresolved_references() {
+                        if let Ok(token) = reference.tree().value_token() {
+                            bindings.insert_reference(token.text_trimmed());
+                        }
+                    }
+                }
+                bindings
+            })
+            .collect()
+    }
+
+    /// Returns the script block that contains `range`
+    fn script_containing(&self, range: TextRange) -> Option<&VueScript> {
+        self.scripts
+            .iter()
+            .find(|script| script.range.contains_range(range))
+    }
+
+    /// Replaces the code of each script block with the code returned by `f`
+    fn replace_scripts(
+        &self,
+        mut f: impl FnMut(&VueScript) -> Result<String, WorkspaceError>,
+    ) -> Result<String, WorkspaceError> {
+        let mut output = String::with_capacity(self.text.len());
+        let mut last_end = 0;
+        for script in &self.scripts {
+            output.push_str(&self.text[last_end..usize::from(script.range.start())]);
+            output.push_str(&f(script)?);
+            last_end = usize::from(script.range.end());
+        }
+        output.push_str(&self.text[last_end..]);
+        Ok(output)
+    }
+}
 
 impl VueFileHandler {
-    /// It extracts the JavaScript/TypeScript code contained in the script block of a Vue file
-    ///
-    /// If there's no script block, an empty string is returned.
-    pub fn input(text: &str) -> &str {
-        match Self::matches_script(text) {
-            Some(script) => &text[script.start()..script.end()],
-            _ => "",
+    /// Returns the code and the file source of each `<script>` block of a Vue
+    /// component.
+    pub fn scripts(text: &str) -> Vec<(&str, JsFileSource)> {
+        let root: HtmlRoot = parse_html(text).tree();
+        script_blocks(&root, text)
+            .map(|(range, file_source)| (&text[range], file_source))
+            .collect()
+    }
+
+    /// Returns a single JavaScript root made of all the script blocks of the
+    /// component, used to register the component in the module graph.
+    pub(crate) fn module_root(root: &HtmlRoot) -> AnyJsRoot {
+        let text = root.syntax().to_string();
+        let mut file_source = None;
+        let mut code = String::new();
+        for (range, script_file_source) in script_blocks(root, &text) {
+            file_source.get_or_insert(script_file_source);
+            code.push_str(&text[range]);
+            code.push('\n');
         }
+
+        parse(
+            &code,
+            file_source.unwrap_or_else(JsFileSource::vue),
+            JsParserOptions::default(),
+        )
+        .tree()
     }
+}
+
+/// Returns the range of the code and the file source of each `<script>` block
+/// of a Vue component.
+///
+/// The line break that follows the opening tag isn't part of the code.
+fn script_blocks<'a>(
+    root: &HtmlRoot,
+    text: &'a str,
+) -> impl Iterator<Item = (TextRange, JsFileSource)> + 'a {
+    root.html()
+        .iter()
+        .filter_map(|element| {
+            let element = element.as_html_element()?;
+            let opening_element = AnyHtmlTagElement::from(element.opening_element().ok()?);
+            if !opening_element.has_name("script") {
+                return None;
+            }
+            let start = opening_element.syntax().text_trimmed_range().end();
+            let end = element
+                .closing_element()
+                .ok()?
+                .syntax()
+                .text_trimmed_range()
+                .start();
 
-    /// It takes the original content of a Vue file, and new output of an Vue file. The output is only the content contained inside the
-    /// Vue `<script>` tag. The function replaces `output` inside that `<script>`.
-    pub fn output(input: &str, output: &str) -> String {
-        if let Some(script) = Self::matches_script(input) {
-            format!(
-                "{}{}{}",
-                &input[..script.start()],
-                output,
-                &input[script.end()..]
+            Some((
+                TextRange::new(start, end),
+                script_file_source(&opening_element),
+            ))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|(range, file_source)| {
+            let code = &text[range];
+            let line_break = if code.starts_with("\r\n") {
+                2
+            } else if code.starts_with('\n') {
+                1
+            } else {
+                0
+            };
+            (
+                TextRange::new(range.start() + TextSize::from(line_break), range.end()),
+                file_source,
             )
-        } else {
-            input.to_string()
+        })
+}
+
+/// Returns the file source of a script block, based on its `lang` attribute
+fn script_file_source(opening_element: &AnyHtmlTagElement) -> JsFileSource {
+    let (language, variant) = opening_element
+        .find_attribute_by_name("lang")
+        .and_then(|attribute| language_from_script_lang(attribute.value()?.text()))
+        .unwrap_or((Language::JavaScript, LanguageVariant::Standard));
+
+    JsFileSource::from(language)
+        .with_variant(variant)
+        .with_embedding_kind(EmbeddingKind::Vue)
+}
+
+/// Returns the names referenced by the templates of a Vue component: the
+/// names used in interpolations and directives, and the components.
+fn template_references(root: &HtmlRoot) -> FxHashSet<Box<str>> {
+    let mut references = FxHashSet::default();
+    let templates = root.html().iter().filter_map(|element| {
+        let element = element.as_html_element()?.clone();
+        AnyHtmlTagElement::from(element.opening_element().ok()?)
+            .has_name("template")
+            .then_some(element)
+    });
+
+    for template in templates {
+        for interpolation in VUE_INTERPOLATION.captures_iter(&template.syntax().to_string()) {
+            collect_expression_references(&interpolation[1], &mut references);
+        }
+
+        for element in template
+            .syntax()
+            .descendants()
+            .filter_map(AnyHtmlTagElement::cast)
+        {
+            collect_component_reference(&element, &mut references);
+            for attribute in element.attributes() {
+                let Some(attribute) = attribute.as_html_attribute() else {
+                    continue;
+                };
+                let Ok(name) = attribute.name().and_then(|name| name.value_token()) else {
+                    continue;
+                };
+                let value = attribute.value();
+                collect_directive_references(
+                    name.text_trimmed(),
+                    value.as_ref().map(|value| value.text()),
+                    &mut references,
+                );
+            }
         }
     }
 
-    /// Returns the start byte offset of the Vue `<script>` tag
-    pub fn start(input: &str) -> Option<u32> {
-        Self::matches_script(input).map(|m| m.start() as u32)
+    references
+}
+
+/// Components are referenced in templates by their name in PascalCase or in
+/// kebab-case: `<MyComponent>` and `<my-component>` both reference the
+/// binding `MyComponent`.
+fn collect_component_reference(element: &AnyHtmlTagElement, references: &mut FxHashSet<Box<str>>) {
+    let Ok(name) = element.name_value_token() else {
+        return;
+    };
+    let name = name.text_trimmed();
+    if name.contains('-') {
+        references.insert(Case::Pascal.convert(name).into());
+    } else if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        // Namespaced components, e.g. `<Form.Input>`
+        let name = name.split('.').next().unwrap_or(name);
+        references.insert(name.into());
     }
+}
+
+/// Collects the names referenced by the value of a directive, e.g.
+/// `:title="title"`, `@click="increment"` or `v-if="visible"`.
+///
+/// Custom directives also reference their own binding: `v-focus` references
+/// `vFocus`.
+fn collect_directive_references(
+    name: &str,
+    value: Option<&str>,
+    references: &mut FxHashSet<Box<str>>,
+) {
+    let directive = if name.starts_with([':', '.']) {
+        "bind"
+    } else if name.starts_with('@') {
+        "on"
+    } else if name.starts_with('#') {
+        "slot"
+    } else if let Some(directive) = name.strip_prefix("v-") {
+        directive.split([':', '.']).next().unwrap_or(directive)
+    } else {
+        return;
+    };
+
+    let expression = match directive {
+        // Slot props declare bindings, they don't reference any
+        "slot" => return,
+        "for" => value.and_then(|value| {
+            value
+                .split_once(" in ")
+                .or_else(|| value.split_once(" of "))
+                .map(|(_, expression)| expression)
+        }),
+        "bind" | "cloak" | "else" | "else-if" | "html" | "if" | "memo" | "model" | "on"
+        | "once" | "pre" | "show" | "text" => value,
+        custom => {
+            references.insert(format!("v{}", Case::Pascal.convert(custom)).into());
+            value
+        }
+    };
 
-    fn matches_script(input: &str) -> Option<Match> {
-        VUE_FENCE
-            .captures(input)
-            .and_then(|captures| captures.name("script"))
+    if let Some(expression) = expression {
+        collect_expression_references(expression, references);
     }
+}
 
-    pub fn file_source(text: &str) -> JsFileSource {
-        VUE_FENCE
-            .captures(text)
-            .and_then(|captures| {
-                let (language, variant) =
-                    parse_lang_from_script_opening_tag(captures.name("opening")?.as_str());
-                Some(
-                    JsFileSource::from(language)
-                        .with_variant(variant)
-                        .with_embedding_kind(EmbeddingKind::Vue),
-                )
-            })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+/// Collects the names that `expression` references, but doesn't declare.
+///
+/// Event handlers can be statements, e.g. `@click="count++; emit('change')"`,
+/// so the code is parsed as statements when it isn't an expression.
+fn collect_expression_references(expression: &str, references: &mut FxHashSet<Box<str>>) {
+    let file_source = JsFileSource::ts();
+    let mut parsed = parse(
+        &format!("({expression})"),
+        file_source,
+        JsParserOptions::default(),
+    );
+    if parsed.has_errors() {
+        parsed = parse(expression, file_source, JsParserOptions::default());
+    }
+
+    let model = semantic_model(&parsed.tree(), SemanticModelOptions::default());
+    for reference in model.all_unresolved_references() {
+        if let Ok(token) = reference.tree().value_token() {
+            references.insert(token.text_trimmed().into());
+        }
     }
 }
 
@@ -88,7 +359,9 @@ impl ExtensionHandler for VueFileHandler {
                 assist: Some(javascript::assist_enabled),
                 linter: Some(javascript::linter_enabled),
             },
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse_vue),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: None,
                 debug_control_flow: None,
@@ -101,6 +374,9 @@ impl ExtensionHandler for VueFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -114,22 +390,43 @@ impl ExtensionHandler for VueFileHandler {
     }
 }
 
-fn parse(
+/// Parses the component with the HTML parser.
+///
+/// The diagnostics of the returned parse are the ones of the script blocks:
+/// the template isn't analyzed, so its syntax errors don't prevent the
+/// formatting of the scripts.
+fn parse_vue(
     _rome_path: &BiomePath,
     _file_source: DocumentFileSource,
     text: &str,
     _settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = VueFileHandler::input(text);
-    let file_source = VueFileHandler::file_source(text);
+    let root: HtmlRoot = parse_html_with_cache(text, cache).tree();
 
-    debug!("Parsing file with language {:?}", file_source);
+    let mut file_source = None;
+    let mut diagnostics = Vec::new();
+    for (range, script_file_source) in script_blocks(&root, text) {
+        file_source.get_or_insert(script_file_source);
+        let parse = parse_js_with_cache(
+            &text[range],
+            script_file_source,
+            JsParserOptions::default(),
+            cache,
+        );
+        diagnostics.extend(
+            parse
+                .into_diagnostics()
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(range.start())),
+        );
+    }
+    let file_source = file_source.unwrap_or_else(JsFileSource::vue);
 
-    let parse = parse_js_with_cache(script, file_source, JsParserOptions::default(), cache);
+    debug!("Parsing file with language {:?}", file_source);
 
     ParseResult {
-        any_parse: parse.into(),
+        any_parse: AnyParse::new(root.syntax().as_send().unwrap(), diagnostics),
         language: Some(file_source.into()),
     }
 }
@@ -137,41 +434,227 @@ fn parse(
 #[tracing::instrument(level = "debug", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let component = VueComponent::new(parse.tree());
+    let code = component.replace_scripts(|script| {
+        let printed = javascript::format(
+            biome_path,
+            &script.file_source.into(),
+            script.parse.clone(),
+            WorkspaceSettingsHandle::from(settings.settings().cloned()),
+        )?;
+        Ok(printed.into_code())
+    })?;
+
+    Ok(Printed::new(code, None, Vec::new(), Vec::new()))
 }
 
 pub(crate) fn format_range(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let component = VueComponent::new(parse.tree());
+    let Some(script) = component.script_containing(range) else {
+        return Err(WorkspaceError::FormatError(FormatError::RangeError {
+            input: range,
+            tree: component.root.syntax().text_range_with_trivia(),
+        }));
+    };
+
+    let offset = script.range.start();
+    let printed = javascript::format_range(
+        biome_path,
+        &script.file_source.into(),
+        script.parse.clone(),
+        settings,
+        range - offset,
+    )?;
+    let printed_range = printed.range().map(|range| range + offset);
+
+    Ok(Printed::new(
+        printed.into_code(),
+        printed_range,
+        Vec::new(),
+        Vec::new(),
+    ))
 }
 
 pub(crate) fn format_on_type(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let component = VueComponent::new(parse.tree());
+    let Some(script) = component.script_containing(TextRange::empty(offset)) else {
+        return Err(WorkspaceError::FormatError(FormatError::RangeError {
+            input: TextRange::empty(offset),
+            tree: component.root.syntax().text_range_with_trivia(),
+        }));
+    };
+
+    let script_offset = script.range.start();
+    let printed = javascript::format_on_type(
+        biome_path,
+        &script.file_source.into(),
+        script.parse.clone(),
+        settings,
+        offset - script_offset,
+    )?;
+    let printed_range = printed.range().map(|range| range + script_offset);
+
+    Ok(Printed::new(
+        printed.into_code(),
+        printed_range,
+        Vec::new(),
+        Vec::new(),
+    ))
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let component = VueComponent::new(params.parse.tree());
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+
+    for (script, embedded_bindings) in component.scripts.iter().zip(component.embedded_bindings()) {
+        let script_results = javascript::lint_with_embedded_bindings(
+            LintParams {
+                parse: script.parse.clone(),
+                workspace: params.workspace,
+                language: script.file_source.into(),
+                path: params.path,
+                only: params.only.clone(),
+                skip: params.skip.clone(),
+                categories: params.categories,
+                module_graph: params.module_graph.clone(),
+                project_layout: params.project_layout.clone(),
+                suppression_reason: params.suppression_reason.clone(),
+                enabled_rules: params.enabled_rules.clone(),
+                plugins: params.plugins.clone(),
+                pull_code_actions: params.pull_code_actions,
+            },
+            embedded_bindings,
+        );
+
+        results.errors += script_results.errors;
+        results.skipped_diagnostics += script_results.skipped_diagnostics;
+        results.diagnostics.extend(
+            script_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(script.range.start())),
+        );
+    }
+
+    results
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let component = VueComponent::new(params.parse.tree());
+    let mut actions = Vec::new();
+
+    for (script, embedded_bindings) in component.scripts.iter().zip(component.embedded_bindings()) {
+        let offset = script.range.start();
+        let range = match params.range {
+            Some(range) => match range.intersect(script.range) {
+                Some(range) => Some(range - offset),
+                None => continue,
+            },
+            None => None,
+        };
+
+        let script_actions = javascript::code_actions_with_embedded_bindings(
+            CodeActionsParams {
+                parse: script.parse.clone(),
+                range,
+                workspace: params.workspace,
+                path: params.path,
+                module_graph: params.module_graph.clone(),
+                project_layout: params.project_layout.clone(),
+                language: script.file_source.into(),
+                only: params.only.clone(),
+                skip: params.skip.clone(),
+                suppression_reason: params.suppression_reason.clone(),
+                enabled_rules: params.enabled_rules.clone(),
+                plugins: params.plugins.clone(),
+                categories: params.categories,
+            },
+            embedded_bindings,
+        );
+
+        let prefix = &component.text[..usize::from(offset)];
+        actions.extend(
+            script_actions
+                .actions
+                .into_iter()
+                .map(|mut action: CodeAction| {
+                    let suggestion = &mut action.suggestion;
+                    suggestion.span += offset;
+                    for label in &mut suggestion.labels {
+                        *label += offset;
+                    }
+                    suggestion.suggestion = suggestion.suggestion.with_prefix(prefix);
+                    action
+                }),
+        );
+    }
+
+    PullActionsResult { actions }
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let component = VueComponent::new(params.parse.tree());
+    let embedded_bindings = component.embedded_bindings();
+    let mut scripts_bindings = embedded_bindings.into_iter();
+    let mut actions = Vec::new();
+    let mut errors = 0;
+    let mut skipped_suggested_fixes = 0;
+
+    let code = component.replace_scripts(|script| {
+        let result = javascript::fix_all_with_embedded_bindings(
+            FixAllParams {
+                parse: script.parse.clone(),
+                fix_file_mode: params.fix_file_mode,
+                workspace: WorkspaceSettingsHandle::from(params.workspace.settings().cloned()),
+                should_format: params.should_format,
+                biome_path: params.biome_path,
+                module_graph: params.module_graph.clone(),
+                project_layout: params.project_layout.clone(),
+                document_file_source: script.file_source.into(),
+                only: params.only.clone(),
+                skip: params.skip.clone(),
+                rule_categories: params.rule_categories,
+                suppression_reason: params.suppression_reason.clone(),
+                enabled_rules: params.enabled_rules.clone(),
+                plugins: params.plugins.clone(),
+            },
+            scripts_bindings.next().unwrap_or_default(),
+        )?;
+
+        errors += result.errors;
+        skipped_suggested_fixes += result.skipped_suggested_fixes;
+        actions.extend(result.actions.into_iter().map(|action| FixAction {
+            range: action.range + script.range.start(),
+            ..action
+        }));
+
+        Ok(result.code)
+    })?;
+
+    Ok(FixFileResult {
+        code,
+        actions,
+        errors,
+        skipped_suggested_fixes,
+    })
 }
@@ -109,6 +109,21 @@ impl Projects {
             })
     }
 
+    /// Retrieves all the nested settings of the given project, along with
+    /// the paths they apply to.
+    pub fn get_all_nested_settings(&self, project_key: ProjectKey) -> Vec<(Utf8PathBuf, Settings)> {
+        self.0
+            .pin()
+            .get(&project_key)
+            .map(|data| {
+                data.nested_settings
+                    .iter()
+                    .map(|(path, settings)| (path.clone(), settings.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Whether the project has been registered
     pub fn is_project_registered(&self, project_key: ProjectKey) -> bool {
         self.0.pin().get(&project_key).is_some()
@@ -7,10 +7,12 @@ use biome_configuration::bool::Bool;
 use biome_configuration::diagnostics::InvalidIgnorePattern;
 use biome_configuration::formatter::{FormatWithErrorsEnabled, FormatterEnabled};
 use biome_configuration::html::HtmlConfiguration;
-use biome_configuration::javascript::JsxRuntime;
+use biome_configuration::javascript::{JsEmbeddedTemplatesConfiguration, JsxRuntime};
+use biome_configuration::markdown::MarkdownConfiguration;
 use biome_configuration::max_size::MaxSize;
 use biome_configuration::plugins::Plugins;
 use biome_configuration::vcs::{VcsClientKind, VcsConfiguration, VcsEnabled, VcsUseIgnoreFile};
+use biome_configuration::yaml::YamlConfiguration;
 use biome_configuration::{
     BiomeDiagnostic, Configuration, CssConfiguration, FilesConfiguration,
     FilesIgnoreUnknownEnabled, FormatterConfiguration, GraphqlConfiguration, GritConfiguration,
@@ -39,6 +41,10 @@ use biome_js_syntax::JsLanguage;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use std::borrow::Cow;
@@ -159,6 +165,14 @@ impl Settings {
         if let Some(html) = configuration.html {
             self.languages.html = html.into()
         }
+        // yaml settings
+        if let Some(yaml) = configuration.yaml {
+            self.languages.yaml = yaml.into()
+        }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.languages.markdown = markdown.into()
+        }
 
         // plugin settings
         if let Some(plugins) = configuration.plugins {
@@ -458,6 +472,8 @@ pub struct LanguageListSettings {
     pub graphql: LanguageSettings<GraphqlLanguage>,
     pub html: LanguageSettings<HtmlLanguage>,
     pub grit: LanguageSettings<GritLanguage>,
+    pub yaml: LanguageSettings<YamlLanguage>,
+    pub markdown: LanguageSettings<MarkdownLanguage>,
 }
 
 impl From<JsConfiguration> for LanguageSettings<JsLanguage> {
@@ -484,6 +500,8 @@ impl From<JsConfiguration> for LanguageSettings<JsLanguage> {
             language_setting.environment = jsx_runtime.into();
         }
 
+        language_setting.environment.embedded_templates = javascript.embedded_templates;
+
         if let Some(globals) = javascript.globals {
             language_setting.globals = Some(globals);
         }
@@ -555,6 +573,8 @@ impl From<GraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
             language_setting.assist = assist.into();
         }
 
+        language_setting.environment.schema = graphql.schema;
+
         language_setting
     }
 }
@@ -578,6 +598,28 @@ impl From<GritConfiguration> for LanguageSettings<GritLanguage> {
     }
 }
 
+impl From<YamlConfiguration> for LanguageSettings<YamlLanguage> {
+    fn from(yaml: YamlConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+        if let Some(formatter) = yaml.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        language_setting
+    }
+}
+
+impl From<MarkdownConfiguration> for LanguageSettings<MarkdownLanguage> {
+    fn from(markdown: MarkdownConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+        if let Some(formatter) = markdown.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        language_setting
+    }
+}
+
 impl From<HtmlConfiguration> for LanguageSettings<HtmlLanguage> {
     fn from(html: HtmlConfiguration) -> Self {
         let mut language_setting: Self = Self::default();
@@ -585,11 +627,11 @@ impl From<HtmlConfiguration> for LanguageSettings<HtmlLanguage> {
             language_setting.formatter = formatter.into();
         }
 
+        if let Some(linter) = html.linter {
+            language_setting.linter = linter.into();
+        }
+
         // NOTE: uncomment once ready
-        // if let Some(linter) = html.linter {
-        //     language_setting.linter = linter.into();
-        // }
-        //
         // if let Some(assist) = html.assist {
         //     language_setting.assist = assist.into();
         // }
@@ -1127,6 +1169,30 @@ impl OverrideSettings {
             .unwrap_or(base_setting)
     }
 
+    pub fn override_embedded_templates(
+        &self,
+        path: &BiomePath,
+        base_setting: Option<&JsEmbeddedTemplatesConfiguration>,
+    ) -> Option<JsEmbeddedTemplatesConfiguration> {
+        self.patterns
+            .iter()
+            // Reverse the traversal as only the last override takes effect
+            .rev()
+            .find_map(|pattern| {
+                if pattern.is_file_included(path) {
+                    pattern
+                        .languages
+                        .javascript
+                        .environment
+                        .embedded_templates
+                        .clone()
+                } else {
+                    None
+                }
+            })
+            .or_else(|| base_setting.cloned())
+    }
+
     pub fn to_override_grit_format_options(
         &self,
         path: &Utf8Path,
@@ -1153,6 +1219,32 @@ impl OverrideSettings {
         options
     }
 
+    pub fn to_override_yaml_format_options(
+        &self,
+        path: &Utf8Path,
+        mut options: YamlFormatOptions,
+    ) -> YamlFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_yaml_format_options(&mut options);
+            }
+        }
+        options
+    }
+
+    pub fn to_override_markdown_format_options(
+        &self,
+        path: &Utf8Path,
+        mut options: MarkdownFormatOptions,
+    ) -> MarkdownFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_markdown_format_options(&mut options);
+            }
+        }
+        options
+    }
+
     pub fn to_override_js_parser_options(
         &self,
         path: &Utf8Path,
@@ -1285,6 +1377,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
 
                 if let Some(actions) = pattern.assist.actions.as_ref() {
@@ -1308,6 +1405,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_assist(
+                        actions,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
             }
         }
@@ -1478,6 +1580,30 @@ impl OverrideSettingPattern {
         }
     }
 
+    fn apply_overrides_to_yaml_format_options(&self, options: &mut YamlFormatOptions) {
+        let yaml_formatter = &self.languages.yaml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = yaml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = yaml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = yaml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+    }
+
+    fn apply_overrides_to_markdown_format_options(&self, options: &mut MarkdownFormatOptions) {
+        let markdown_formatter = &self.languages.markdown.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(line_ending) = markdown_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+    }
+
     fn apply_overrides_to_html_format_options(&self, options: &mut HtmlFormatOptions) {
         let html_formatter = &self.languages.html.formatter;
         let formatter = &self.formatter;
@@ -1622,6 +1748,8 @@ pub fn to_override_settings(
         let graphql = pattern.graphql.take().unwrap_or_default();
         let grit = pattern.grit.take().unwrap_or_default();
         let html = pattern.html.take().unwrap_or_default();
+        let yaml = pattern.yaml.take().unwrap_or_default();
+        let markdown = pattern.markdown.take().unwrap_or_default();
 
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
@@ -1632,6 +1760,9 @@ pub fn to_override_settings(
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
         languages.grit = to_grit_language_settings(grit, &current_settings.languages.grit);
         languages.html = to_html_language_settings(html, &current_settings.languages.html);
+        languages.yaml = to_yaml_language_settings(yaml, &current_settings.languages.yaml);
+        languages.markdown =
+            to_markdown_language_settings(markdown, &current_settings.languages.markdown);
 
         let pattern_setting = OverrideSettingPattern {
             includes: OverrideIncludes::new(working_directory.clone(), pattern.includes),
@@ -1670,6 +1801,9 @@ fn to_javascript_language_settings(
     language_setting.globals = conf.globals;
     language_setting.environment.jsx_runtime =
         conf.jsx_runtime.or(parent_settings.environment.jsx_runtime);
+    language_setting.environment.embedded_templates = conf
+        .embedded_templates
+        .or_else(|| parent_settings.environment.embedded_templates.clone());
 
     language_setting
 }
@@ -1770,6 +1904,33 @@ fn to_html_language_settings(
 
     language_setting.formatter = formatter.into();
 
+    let linter = conf.linter.take().unwrap_or_default();
+    language_setting.linter = linter.into();
+
+    language_setting
+}
+
+fn to_yaml_language_settings(
+    mut conf: YamlConfiguration,
+    _parent_settings: &LanguageSettings<YamlLanguage>,
+) -> LanguageSettings<YamlLanguage> {
+    let mut language_setting: LanguageSettings<YamlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
+    language_setting
+}
+
+fn to_markdown_language_settings(
+    mut conf: MarkdownConfiguration,
+    _parent_settings: &LanguageSettings<MarkdownLanguage>,
+) -> LanguageSettings<MarkdownLanguage> {
+    let mut language_setting: LanguageSettings<MarkdownLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
     language_setting
 }
 
@@ -912,6 +912,143 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// Edits to apply to other files affected by this rename operation, such
+    /// as the modules importing a renamed export
+    pub other_files: Vec<RenameFileEdit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RenameFileEdit {
+    /// Path of the file to modify
+    pub path: BiomePath,
+    /// Range of source code modified in the file
+    pub range: TextRange,
+    /// List of text edit operations to apply on the source code of the file
+    pub indels: TextEdit,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GotoDefinitionParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GotoDefinitionResult {
+    /// Location of the declaration of the symbol, if it could be found
+    pub definition: Option<SymbolLocation>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+    /// Whether the declaration of the symbol should be included in the result
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesResult {
+    /// Locations of all the references to the symbol, across all files
+    pub references: Vec<SymbolLocation>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleDependenciesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleDependenciesResult {
+    /// Paths of the modules imported by the file, either directly or
+    /// transitively, sorted by path
+    pub dependencies: Vec<BiomePath>,
+    /// Whether the file contains imports that couldn't be resolved
+    pub has_unresolved_imports: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleGraphParams {
+    pub project_key: ProjectKey,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleGraphResult {
+    /// The modules of the project that are part of the module graph, sorted
+    /// by path
+    pub modules: Vec<ModuleGraphNode>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleGraphNode {
+    /// Path of the module
+    pub path: BiomePath,
+    /// Imports of the module, sorted by specifier
+    pub imports: Vec<ModuleGraphImport>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleGraphImport {
+    /// The specifier of the import, as written in the source
+    pub specifier: String,
+    /// Path of the imported file, or `None` if the specifier couldn't be
+    /// resolved
+    pub resolved_path: Option<BiomePath>,
+    /// How the file is imported
+    pub kind: ModuleGraphImportKind,
+    /// Symbols imported or re-exported from the file, sorted by name.
+    /// Re-exports are described as `name as exported_name`, and imports of
+    /// all the symbols as `*`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub symbols: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleGraphImportKind {
+    /// A static `import` or `export ... from` statement, or an `@import`
+    /// rule or `composes` property in stylesheets
+    Static,
+    /// A dynamic `import()` expression or a `require()` call
+    Dynamic,
+    /// A resource referenced through `url()` in stylesheets
+    Url,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolLocation {
+    /// Path of the file in which the symbol is located
+    pub path: BiomePath,
+    /// Range of the symbol inside the file
+    pub range: TextRange,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1012,6 +1149,9 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub path: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The content of the file after applying the rewrites of the pattern,
+    /// or `None` if the pattern doesn't rewrite the file.
+    pub rewritten: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1312,6 +1452,44 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Returns the content of the file after renaming a symbol.
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Returns the location of the declaration of the symbol at a given
+    /// position within a file.
+    ///
+    /// If the symbol is imported from another module, the declaration is
+    /// looked up through the module graph.
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError>;
+
+    /// Returns the locations of all the references to the symbol at a given
+    /// position within a file.
+    ///
+    /// References in other modules are looked up through the module graph.
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError>;
+
+    /// Returns the modules that a file depends on, according to the module
+    /// graph.
+    ///
+    /// The result is empty for files that are not part of the module graph,
+    /// for example when the project wasn't scanned.
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError>;
+
+    /// Returns the modules of a project along with their resolved imports,
+    /// according to the module graph.
+    ///
+    /// The result is empty if the project wasn't scanned.
+    fn get_module_graph(
+        &self,
+        params: GetModuleGraphParams,
+    ) -> Result<GetModuleGraphResult, WorkspaceError>;
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1,15 +1,16 @@
 use std::num::NonZeroU64;
 use std::str::FromStr;
 
-use biome_analyze::RuleCategories;
+use biome_analyze::{FixKind, RuleCategories};
 use biome_configuration::analyzer::{RuleGroup, RuleSelector};
-use biome_configuration::plugins::{PluginConfiguration, Plugins};
+use biome_configuration::plugins::{PluginConfiguration, PluginPathWithOptions, Plugins};
 use biome_configuration::{
-    Configuration, FilesConfiguration, OverrideGlobs, OverridePattern, Overrides,
+    Configuration, FilesConfiguration, GraphqlConfiguration, OverrideGlobs, OverridePattern,
+    Overrides,
 };
 use biome_diagnostics::Diagnostic;
 use biome_fs::{BiomePath, MemoryFileSystem};
-use biome_js_syntax::{JsFileSource, TextSize};
+use biome_js_syntax::{JsFileSource, TextRange, TextSize};
 use camino::Utf8PathBuf;
 use insta::{assert_debug_snapshot, assert_snapshot};
 
@@ -18,10 +19,11 @@ use crate::projects::ProjectKey;
 use crate::{Workspace, WorkspaceError};
 
 use super::{
-    CloseFileParams, CloseProjectParams, FileContent, FileFeaturesResult, FileGuard,
-    GetFileContentParams, GetSyntaxTreeParams, OpenFileParams, OpenProjectParams,
-    OpenProjectResult, PullDiagnosticsParams, ScanKind, ScanProjectFolderParams,
-    UpdateSettingsParams, server,
+    ChangeFileParams, CloseFileParams, CloseProjectParams, FileContent, FileFeaturesResult,
+    FileGuard, FindReferencesParams, FixFileMode, FixFileParams, GetFileContentParams,
+    GetSyntaxTreeParams, GotoDefinitionParams, OpenFileParams, OpenProjectParams,
+    OpenProjectResult, PullDiagnosticsParams, RenameParams, ScanKind, ScanProjectFolderParams,
+    SymbolLocation, UpdateSettingsParams, server,
 };
 
 fn create_server() -> (Box<dyn Workspace>, ProjectKey) {
@@ -285,6 +287,84 @@ fn correctly_pulls_lint_diagnostics() {
     assert_eq!(diagnostics.len(), 1)
 }
 
+#[test]
+fn graphql_schema_is_reloaded_when_its_files_change() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/schema.graphql"),
+        "type Query {\n  id: ID\n}\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/query.graphql"),
+        "query {\n  name\n}\n",
+    );
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            configuration: Configuration {
+                graphql: Some(GraphqlConfiguration {
+                    schema: Some(vec!["schema.graphql".to_string()]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            workspace_directory: Some(BiomePath::new("/project")),
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let pull_diagnostics = || {
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                project_key,
+                path: BiomePath::new("/project/query.graphql"),
+                categories: RuleCategories::all(),
+                only: vec![RuleSelector::Rule(
+                    RuleGroup::Nursery.as_str(),
+                    "noUnknownFields",
+                )],
+                skip: Vec::new(),
+                enabled_rules: Vec::new(),
+                pull_code_actions: false,
+            })
+            .unwrap()
+            .diagnostics
+    };
+
+    assert_eq!(pull_diagnostics().len(), 1);
+
+    workspace
+        .change_file(ChangeFileParams {
+            project_key,
+            path: BiomePath::new("/project/schema.graphql"),
+            content: "type Query {\n  id: ID\n  name: String\n}\n".to_string(),
+            version: 1,
+        })
+        .unwrap();
+
+    assert!(pull_diagnostics().is_empty());
+}
+
 #[test]
 fn pull_grit_debug_info() {
     let (workspace, project_key) = create_server();
@@ -391,6 +471,261 @@ fn files_loaded_by_the_scanner_are_only_unloaded_when_the_project_is_unregistere
     );
 }
 
+#[test]
+fn goto_definition_and_find_references_follow_imports() {
+    const FILE_A_CONTENT: &[u8] = b"export function foo() {}\nfoo();\n";
+    const FILE_B_CONTENT: &[u8] = b"import { foo } from './a.ts';\nfoo();\n";
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from("/project/a.ts"), FILE_A_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/b.ts"), FILE_B_CONTENT);
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let location = |path: &str, start: u32| SymbolLocation {
+        path: BiomePath::new(path),
+        range: TextRange::at(TextSize::from(start), TextSize::from(3)),
+    };
+
+    // The reference to `foo` on the second line of `b.ts`.
+    let result = workspace
+        .goto_definition(GotoDefinitionParams {
+            project_key,
+            path: BiomePath::new("/project/b.ts"),
+            symbol_at: TextSize::from(31),
+        })
+        .unwrap();
+    assert_eq!(result.definition, Some(location("/project/a.ts", 16)));
+
+    let result = workspace
+        .find_references(FindReferencesParams {
+            project_key,
+            path: BiomePath::new("/project/b.ts"),
+            symbol_at: TextSize::from(31),
+            include_declaration: true,
+        })
+        .unwrap();
+    let mut references = result.references;
+    references.sort_by(|a, b| {
+        a.path
+            .cmp(&b.path)
+            .then_with(|| a.range.start().cmp(&b.range.start()))
+    });
+    assert_eq!(
+        references,
+        [
+            location("/project/a.ts", 16),
+            location("/project/a.ts", 25),
+            location("/project/b.ts", 9),
+            location("/project/b.ts", 30),
+        ]
+    );
+
+    let result = workspace
+        .find_references(FindReferencesParams {
+            project_key,
+            path: BiomePath::new("/project/a.ts"),
+            symbol_at: TextSize::from(16),
+            include_declaration: false,
+        })
+        .unwrap();
+    assert_eq!(result.references.len(), 3);
+    assert!(!result.references.contains(&location("/project/a.ts", 16)));
+}
+
+#[test]
+fn rename_updates_modules_importing_the_binding() {
+    const FILE_A_CONTENT: &str = "export function foo() {}\nfoo();\n";
+    const FILE_B_CONTENT: &str = "import { foo } from './a.ts';\nfoo();\n";
+    const FILE_C_CONTENT: &str = "import { foo as bar } from './a.ts';\nbar();\n";
+    const FILE_D_CONTENT: &str = "export { foo } from './a.ts';\n";
+    const FILE_E_CONTENT: &str = "import { foo } from './a.ts';\nconst baz = foo;\n";
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from("/project/a.ts"), FILE_A_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/b.ts"), FILE_B_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/c.ts"), FILE_C_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/d.ts"), FILE_D_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/e.ts"), FILE_E_CONTENT);
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let result = workspace
+        .rename(RenameParams {
+            project_key,
+            path: BiomePath::new("/project/a.ts"),
+            symbol_at: TextSize::from(16),
+            new_name: "baz".to_string(),
+        })
+        .unwrap();
+
+    assert_eq!(
+        result.indels.new_string(FILE_A_CONTENT),
+        "export function baz() {}\nbaz();\n"
+    );
+
+    let other_files: Vec<_> = result
+        .other_files
+        .iter()
+        .map(|edit| {
+            let content = match edit.path.as_str() {
+                "/project/b.ts" => FILE_B_CONTENT,
+                "/project/c.ts" => FILE_C_CONTENT,
+                "/project/d.ts" => FILE_D_CONTENT,
+                "/project/e.ts" => FILE_E_CONTENT,
+                path => panic!("unexpected edit in {path}"),
+            };
+            (edit.path.as_str(), edit.indels.new_string(content))
+        })
+        .collect();
+    assert_eq!(
+        other_files,
+        [
+            (
+                "/project/b.ts",
+                "import { baz } from './a.ts';\nbaz();\n".to_string()
+            ),
+            (
+                "/project/c.ts",
+                "import { baz as bar } from './a.ts';\nbar();\n".to_string()
+            ),
+            (
+                "/project/d.ts",
+                "export { baz as foo } from './a.ts';\n".to_string()
+            ),
+            (
+                "/project/e.ts",
+                "import { baz as foo } from './a.ts';\nconst baz = foo;\n".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn rename_updates_namespace_imports_and_imports_through_barrels() {
+    const FILE_A_CONTENT: &str = "export function foo() {}\n";
+    const FILE_B_CONTENT: &str =
+        "import * as ns from './a.ts';\nns.foo();\nns.bar();\ntype Foo = typeof ns.foo;\n";
+    const FILE_C_CONTENT: &str = "export * from './a.ts';\n";
+    const FILE_D_CONTENT: &str = "import { foo } from './c.ts';\nfoo();\n";
+    const FILE_E_CONTENT: &str = "import * as barrel from './c.ts';\nbarrel.foo();\n";
+    const FILE_F_CONTENT: &str = "export * from './a.ts';\nexport const foo = 1;\n";
+    const FILE_G_CONTENT: &str = "import { foo } from './f.ts';\nfoo;\n";
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from("/project/a.ts"), FILE_A_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/b.ts"), FILE_B_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/c.ts"), FILE_C_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/d.ts"), FILE_D_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/e.ts"), FILE_E_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/f.ts"), FILE_F_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/g.ts"), FILE_G_CONTENT);
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let result = workspace
+        .rename(RenameParams {
+            project_key,
+            path: BiomePath::new("/project/a.ts"),
+            symbol_at: TextSize::from(16),
+            new_name: "baz".to_string(),
+        })
+        .unwrap();
+
+    assert_eq!(
+        result.indels.new_string(FILE_A_CONTENT),
+        "export function baz() {}\n"
+    );
+
+    // `g.ts` imports the `foo` declared by `f.ts`, which shadows the one that
+    // is re-exported from `a.ts`.
+    let other_files: Vec<_> = result
+        .other_files
+        .iter()
+        .map(|edit| {
+            let content = match edit.path.as_str() {
+                "/project/b.ts" => FILE_B_CONTENT,
+                "/project/d.ts" => FILE_D_CONTENT,
+                "/project/e.ts" => FILE_E_CONTENT,
+                path => panic!("unexpected edit in {path}"),
+            };
+            (edit.path.as_str(), edit.indels.new_string(content))
+        })
+        .collect();
+    assert_eq!(
+        other_files,
+        [
+            (
+                "/project/b.ts",
+                "import * as ns from './a.ts';\nns.baz();\nns.bar();\ntype Foo = typeof ns.baz;\n"
+                    .to_string()
+            ),
+            (
+                "/project/d.ts",
+                "import { baz } from './c.ts';\nbaz();\n".to_string()
+            ),
+            (
+                "/project/e.ts",
+                "import * as barrel from './c.ts';\nbarrel.baz();\n".to_string()
+            ),
+        ]
+    );
+}
+
 #[test]
 fn too_large_files_are_tracked_but_not_parsed() {
     const FILE_CONTENT: &[u8] = b"console.log(`I'm YUUUGE!`);";
@@ -641,6 +976,102 @@ fn plugins_may_use_invalid_span() {
     assert_eq!(result.errors, 0);
 }
 
+#[test]
+fn plugins_rewrites_are_applied_by_fix_file() {
+    const PLUGIN_CONTENT: &[u8] = br#"
+`Object.assign({}, $args)` as $call where {
+    register_diagnostic(
+        span = $call,
+        message = "Prefer object spread instead of `Object.assign()`"
+    ),
+    $call => `({ ...$args })`
+}
+"#;
+
+    const FILE_CONTENT: &[u8] = b"export const a = Object.assign({}, b);";
+
+    for (fix, fix_file_mode, expected) in [
+        (
+            Some(FixKind::Safe),
+            FixFileMode::SafeFixes,
+            "export const a = ({ ...b });",
+        ),
+        (
+            None,
+            FixFileMode::SafeFixes,
+            "export const a = Object.assign({}, b);",
+        ),
+        (
+            None,
+            FixFileMode::SafeAndUnsafeFixes,
+            "export const a = ({ ...b });",
+        ),
+        (
+            Some(FixKind::None),
+            FixFileMode::SafeAndUnsafeFixes,
+            "export const a = Object.assign({}, b);",
+        ),
+    ] {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(Utf8PathBuf::from("/project/plugin.grit"), PLUGIN_CONTENT);
+        fs.insert(Utf8PathBuf::from("/project/a.js"), FILE_CONTENT);
+
+        let workspace = server(Box::new(fs), None);
+        let OpenProjectResult { project_key, .. } = workspace
+            .open_project(OpenProjectParams {
+                path: Utf8PathBuf::from("/project").into(),
+                open_uninitialized: true,
+                only_rules: None,
+                skip_rules: None,
+            })
+            .unwrap();
+
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration: Configuration {
+                    plugins: Some(Plugins(vec![PluginConfiguration::PathWithOptions(
+                        PluginPathWithOptions {
+                            path: "./plugin.grit".to_string(),
+                            fix,
+                        },
+                    )])),
+                    ..Default::default()
+                },
+                workspace_directory: Some(BiomePath::new("/project")),
+            })
+            .unwrap();
+
+        workspace
+            .scan_project_folder(ScanProjectFolderParams {
+                project_key,
+                path: None,
+                watch: false,
+                force: false,
+                scan_kind: ScanKind::Project,
+            })
+            .unwrap();
+
+        let result = workspace
+            .fix_file(FixFileParams {
+                project_key,
+                path: BiomePath::new("/project/a.js"),
+                fix_file_mode,
+                should_format: false,
+                only: Vec::new(),
+                skip: Vec::new(),
+                enabled_rules: Vec::new(),
+                rule_categories: RuleCategories::default(),
+                suppression_reason: None,
+            })
+            .unwrap();
+        assert_eq!(
+            result.code, expected,
+            "fix: {fix:?}, mode: {fix_file_mode:?}"
+        );
+    }
+}
+
 #[test]
 fn correctly_apply_plugins_in_override() {
     let files: &[(&str, &[u8])] = &[
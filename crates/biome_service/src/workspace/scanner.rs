@@ -279,7 +279,8 @@ impl TraversalContext for ScanContext<'_> {
             Ok(PathKind::Directory { .. }) => {
                 if self.scan_kind.is_project() && path.is_dependency() {
                     // In project mode, the scanner always scans dependencies
-                    // because they're a valuable source of type information.
+                    // for their manifests. Their type declarations are loaded
+                    // by the module graph when they're imported.
                     true
                 } else if !path.is_dependency() {
                     !self
@@ -300,7 +301,7 @@ impl TraversalContext for ScanContext<'_> {
                 ScanKind::KnownFiles => path.is_required_during_scan() && !path.is_dependency(),
                 ScanKind::Project => {
                     if path.is_dependency() {
-                        path.is_package_json() || path.is_type_declaration()
+                        path.is_package_json()
                     } else {
                         path.is_required_during_scan()
                             || DocumentFileSource::try_from_path(path).is_ok()
@@ -335,7 +336,7 @@ impl TraversalContext for ScanContext<'_> {
 fn open_file(ctx: &ScanContext, path: &BiomePath) {
     match catch_unwind(move || {
         let is_ignored = if ctx.scan_kind.is_project() && path.is_dependency() {
-            !(path.is_package_json() || path.is_type_declaration())
+            !path.is_package_json()
         } else if path.is_required_during_scan() {
             // Required files are only ignored if they are in an ignored
             // directory.
@@ -1,27 +1,29 @@
 use super::document::Document;
 use super::{
     ChangeFileParams, CheckFileSizeParams, CheckFileSizeResult, CloseFileParams,
-    CloseProjectParams, FeatureName, FileContent, FileExitsParams, FixFileParams, FixFileResult,
-    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
-    GetFormatterIRParams, GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult,
-    OpenFileParams, OpenProjectParams, ParsePatternParams, ParsePatternResult, PatternId,
-    ProjectKey, PullActionsParams, PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult,
-    RenameResult, ScanProjectFolderParams, ScanProjectFolderResult, SearchPatternParams,
-    SearchResults, ServiceDataNotification, SupportsFeatureParams, UpdateSettingsParams,
-    UpdateSettingsResult,
+    CloseProjectParams, FeatureName, FileContent, FileExitsParams, FindReferencesParams,
+    FindReferencesResult, FixFileParams, FixFileResult, FormatFileParams, FormatOnTypeParams,
+    FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
+    GetModuleDependenciesParams, GetModuleDependenciesResult, GetModuleGraphParams,
+    GetModuleGraphResult, GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult,
+    GotoDefinitionParams, GotoDefinitionResult, OpenFileParams, OpenProjectParams,
+    ParsePatternParams, ParsePatternResult, PatternId, ProjectKey, PullActionsParams,
+    PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RenameFileEdit, RenameResult,
+    ScanProjectFolderParams, ScanProjectFolderResult, SearchPatternParams, SearchResults,
+    ServiceDataNotification, SupportsFeatureParams, UpdateSettingsParams, UpdateSettingsResult,
 };
 use crate::configuration::{LoadedConfiguration, ProjectScanComputer, read_config};
 use crate::diagnostics::FileTooLarge;
 use crate::file_handlers::{
     Capabilities, CodeActionsParams, DocumentFileSource, Features, FixAllParams, LintParams,
-    ParseResult,
+    NavigationParams, ParseResult, RenameImportsParams, VueFileHandler,
 };
 use crate::projects::Projects;
-use crate::settings::WorkspaceSettingsHandle;
+use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
     FileFeaturesResult, GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams,
-    IsPathIgnoredParams, OpenProjectResult, RageEntry, RageParams, RageResult, ScanKind,
-    ServerInfo,
+    IsPathIgnoredParams, ModuleGraphImport, ModuleGraphImportKind, ModuleGraphNode,
+    OpenProjectResult, RageEntry, RageParams, RageResult, ScanKind, ServerInfo, SymbolLocation,
 };
 use crate::workspace_watcher::{OpenFileReason, WatcherSignalKind};
 use crate::{WatcherInstruction, Workspace, WorkspaceError};
@@ -29,26 +31,28 @@ use append_only_vec::AppendOnlyVec;
 use biome_analyze::{AnalyzerPluginVec, RuleCategory};
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::bool::Bool;
-use biome_configuration::plugins::{PluginConfiguration, Plugins};
+use biome_configuration::plugins::Plugins;
 use biome_configuration::{BiomeDiagnostic, Configuration, ConfigurationPathHint};
+use biome_css_syntax::CssRoot;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, Merge};
 use biome_diagnostics::print_diagnostic_to_string;
 use biome_diagnostics::{
-    Diagnostic, DiagnosticExt, Severity, serde::Diagnostic as SerdeDiagnostic,
+    Diagnostic, DiagnosticExt, Error, Severity, serde::Diagnostic as SerdeDiagnostic,
 };
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName};
 use biome_grit_patterns::{CompilePatternOptions, GritQuery, compile_pattern_with_options};
+use biome_html_syntax::HtmlRoot;
 use biome_js_syntax::{AnyJsRoot, ModuleKind};
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonFileSource;
-use biome_module_graph::ModuleGraph;
+use biome_module_graph::{CssReferenceKind, ModuleGraph};
 use biome_package::PackageType;
 use biome_parser::AnyParse;
 use biome_plugin_loader::{BiomePlugin, PluginCache, PluginDiagnostic};
 use biome_project_layout::ProjectLayout;
-use biome_resolver::FsWithResolverProxy;
+use biome_resolver::{FsWithResolverProxy, ResolvedPath};
 use biome_rowan::{AstNode, NodeCache, SendNode};
 use camino::{Utf8Path, Utf8PathBuf};
 use crossbeam::channel::Sender;
@@ -571,15 +575,17 @@ impl WorkspaceServer {
         let plugin_cache = PluginCache::default();
 
         for plugin_config in plugins.iter() {
-            match plugin_config {
-                PluginConfiguration::Path(plugin_path) => {
-                    match BiomePlugin::load(self.fs.as_ref(), plugin_path, base_path) {
-                        Ok((plugin, _)) => {
-                            plugin_cache.insert_plugin(plugin_path.clone().into(), plugin);
-                        }
-                        Err(diagnostic) => diagnostics.push(diagnostic),
-                    }
+            let plugin_path = plugin_config.path();
+            match BiomePlugin::load(
+                self.fs.as_ref(),
+                plugin_path,
+                base_path,
+                plugin_config.fix_kind(),
+            ) {
+                Ok((plugin, _)) => {
+                    plugin_cache.insert_plugin(plugin_path.into(), plugin);
                 }
+                Err(diagnostic) => diagnostics.push(diagnostic),
             }
         }
 
@@ -601,6 +607,52 @@ impl WorkspaceServer {
         }
     }
 
+    /// Loads the GraphQL schema configured by `settings`, whose files are
+    /// relative to `base_path`.
+    fn load_graphql_schema(&self, base_path: Utf8PathBuf, settings: &mut Settings) -> Vec<Error> {
+        settings
+            .languages
+            .graphql
+            .environment
+            .load_schema(base_path, |path| self.read_graphql_schema_file(path))
+    }
+
+    /// Reads a file of a GraphQL schema. The content of open documents is
+    /// preferred over the file system, so that changes are taken into account
+    /// before they're saved.
+    fn read_graphql_schema_file(&self, path: &Utf8Path) -> Result<String, Error> {
+        match self.documents.pin().get(path) {
+            Some(document) => Ok(document.content.clone()),
+            None => self.fs.read_file_from_path(path).map_err(Error::from),
+        }
+    }
+
+    /// Reloads the GraphQL schemas that include the file at `path`.
+    fn update_graphql_schemas(&self, path: &Utf8Path) {
+        let Some(project_key) = self.projects.find_project_for_path(path) else {
+            return;
+        };
+
+        if let Some(mut settings) = self.projects.get_root_settings(project_key) {
+            let environment = &settings.languages.graphql.environment;
+            if environment.is_schema_file(path) {
+                let base_path = environment.schema_base_path.clone();
+                self.load_graphql_schema(base_path, &mut settings);
+                self.projects.set_root_settings(project_key, settings);
+            }
+        }
+
+        for (settings_path, mut settings) in self.projects.get_all_nested_settings(project_key) {
+            let environment = &settings.languages.graphql.environment;
+            if environment.is_schema_file(path) {
+                let base_path = environment.schema_base_path.clone();
+                self.load_graphql_schema(base_path, &mut settings);
+                self.projects
+                    .set_nested_settings(project_key, settings_path, settings);
+            }
+        }
+    }
+
     /// It updates the nested settings of the project assigned to the `project_key`.
     ///
     /// If a configuration file contains errors, it's not processed and the project isn't updated.
@@ -789,6 +841,9 @@ impl WorkspaceServer {
     }
 
     /// Updates the [ModuleGraph] for the given `path` with an optional `root`.
+    ///
+    /// Stylesheets are tracked in the [ModuleGraph] too, so that their
+    /// references and classes can be checked.
     #[tracing::instrument(level = "debug", skip(self, root))]
     fn update_module_graph(
         &self,
@@ -796,24 +851,119 @@ impl WorkspaceServer {
         path: &BiomePath,
         root: Option<SendNode>,
     ) {
-        let (added_or_changed_paths, removed_paths) = match signal_kind {
+        let is_stylesheet = matches!(path.extension(), Some("css" | "scss"));
+        match signal_kind {
+            WatcherSignalKind::AddedOrChanged(_) if is_stylesheet => {
+                let Some(root) = root.and_then(SendNode::into_node).and_then(CssRoot::cast) else {
+                    return;
+                };
+
+                self.module_graph.update_graph_for_css_paths(
+                    self.fs.as_ref(),
+                    &self.project_layout,
+                    &[(path, root)],
+                    &[],
+                );
+            }
             WatcherSignalKind::AddedOrChanged(_) => {
-                let Some(root) = root.and_then(SendNode::into_node).and_then(AnyJsRoot::cast)
-                else {
+                let root = if path.extension() == Some("vue") {
+                    // Vue components are parsed with the HTML parser, their
+                    // script blocks make the module
+                    root.and_then(SendNode::into_node)
+                        .and_then(HtmlRoot::cast)
+                        .map(|root| VueFileHandler::module_root(&root))
+                } else {
+                    root.and_then(SendNode::into_node).and_then(AnyJsRoot::cast)
+                };
+                let Some(root) = root else {
                     return;
                 };
 
-                (&[(path, root)] as &[_], &[] as &[_])
+                self.module_graph.update_graph_for_js_paths(
+                    self.fs.as_ref(),
+                    &self.project_layout,
+                    &[(path, root)],
+                    &[],
+                );
+            }
+            WatcherSignalKind::Removed if is_stylesheet => {
+                self.module_graph.update_graph_for_css_paths(
+                    self.fs.as_ref(),
+                    &self.project_layout,
+                    &[],
+                    &[path],
+                );
+            }
+            WatcherSignalKind::Removed => {
+                self.module_graph.update_graph_for_js_paths(
+                    self.fs.as_ref(),
+                    &self.project_layout,
+                    &[],
+                    &[path],
+                );
+            }
+        }
+    }
+
+    /// Updates the modules importing the binding declared at `declaration` by
+    /// name, after the binding has been renamed to `new_name`.
+    ///
+    /// The modules are looked up in the [ModuleGraph], and are updated by the
+    /// `rename_imports` capability of their respective file handlers.
+    fn rename_imports(
+        &self,
+        declaration: &SymbolLocation,
+        new_name: &str,
+    ) -> Result<Vec<RenameFileEdit>, WorkspaceError> {
+        let old_name = {
+            let documents = self.documents.pin();
+            let old_name = documents
+                .get(declaration.path.as_path())
+                .and_then(|document| {
+                    document.content.get(
+                        usize::from(declaration.range.start())
+                            ..usize::from(declaration.range.end()),
+                    )
+                });
+            match old_name {
+                Some(old_name) => old_name.to_string(),
+                None => return Ok(Vec::new()),
             }
-            WatcherSignalKind::Removed => (&[] as &[_], &[path] as &[_]),
         };
 
-        self.module_graph.update_graph_for_js_paths(
-            self.fs.as_ref(),
-            &self.project_layout,
-            added_or_changed_paths,
-            removed_paths,
-        );
+        let modules = self
+            .module_graph
+            .find_modules_importing_binding_by_name(&declaration.path, declaration.range);
+
+        let mut edits = Vec::new();
+        for (path, specifiers) in modules {
+            let path = BiomePath::from(path);
+            if path == declaration.path {
+                continue;
+            }
+
+            let capabilities = self.get_file_capabilities(&path);
+            let Some(rename_imports) = capabilities.analyzer.rename_imports else {
+                continue;
+            };
+
+            let parse = self.get_parse(&path)?;
+            let edit = rename_imports(RenameImportsParams {
+                parse,
+                specifiers: &specifiers,
+                old_name: &old_name,
+                new_name,
+            })?;
+            if let Some((range, indels)) = edit {
+                edits.push(RenameFileEdit {
+                    path,
+                    range,
+                    indels,
+                });
+            }
+        }
+
+        Ok(edits)
     }
 
     /// Updates the state of any services relevant to the given `path`.
@@ -829,6 +979,7 @@ impl WorkspaceServer {
         }
 
         self.update_module_graph(signal_kind, &path, root);
+        self.update_graphql_schemas(&path);
 
         match signal_kind {
             WatcherSignalKind::AddedOrChanged(OpenFileReason::InitialScan) => {
@@ -982,6 +1133,9 @@ impl Workspace for WorkspaceServer {
             return Err(WorkspaceError::plugin_errors(diagnostics));
         }
 
+        let schema_diagnostics =
+            self.load_graphql_schema(loading_directory.unwrap_or_default(), &mut settings);
+
         if !is_root {
             self.projects.set_nested_settings(
                 params.project_key,
@@ -994,7 +1148,11 @@ impl Workspace for WorkspaceServer {
         }
 
         Ok(UpdateSettingsResult {
-            diagnostics: diagnostics.into_iter().map(Into::into).collect(),
+            diagnostics: diagnostics
+                .into_iter()
+                .map(Into::into)
+                .chain(schema_diagnostics.into_iter().map(SerdeDiagnostic::new))
+                .collect(),
         })
     }
 
@@ -1595,11 +1753,176 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(&params.path)?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let mut result = rename(
+            &params.path,
+            parse.clone(),
+            params.symbol_at,
+            params.new_name.clone(),
+        )?;
+
+        // If the binding is declared in this file, other modules may be
+        // importing it by name.
+        if let Some(goto_definition) = capabilities.analyzer.goto_definition {
+            let definition = goto_definition(NavigationParams {
+                parse,
+                path: &params.path,
+                module_graph: self.module_graph.clone(),
+                symbol_at: params.symbol_at,
+            })?;
+            if let Some(definition) = definition.filter(|definition| definition.path == params.path)
+            {
+                result.other_files = self.rename_imports(&definition, &params.new_name)?;
+            }
+        }
 
         Ok(result)
     }
 
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let goto_definition = capabilities
+            .analyzer
+            .goto_definition
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let definition = goto_definition(NavigationParams {
+            parse,
+            path: &params.path,
+            module_graph: self.module_graph.clone(),
+            symbol_at: params.symbol_at,
+        })?;
+
+        Ok(GotoDefinitionResult { definition })
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let find_references = capabilities
+            .analyzer
+            .find_references
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let references = find_references(
+            NavigationParams {
+                parse,
+                path: &params.path,
+                module_graph: self.module_graph.clone(),
+                symbol_at: params.symbol_at,
+            },
+            params.include_declaration,
+        )?;
+
+        Ok(FindReferencesResult { references })
+    }
+
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError> {
+        let dependencies = self
+            .module_graph
+            .transitive_dependencies(params.path.as_path())
+            .into_iter()
+            .map(BiomePath::from)
+            .collect();
+
+        Ok(GetModuleDependenciesResult {
+            dependencies,
+            has_unresolved_imports: self
+                .module_graph
+                .has_unresolved_imports(params.path.as_path()),
+        })
+    }
+
+    fn get_module_graph(
+        &self,
+        params: GetModuleGraphParams,
+    ) -> Result<GetModuleGraphResult, WorkspaceError> {
+        let Some(project_path) = self.projects.get_project_path(params.project_key) else {
+            return Ok(GetModuleGraphResult::default());
+        };
+
+        // Dependencies may be part of the module graph because their type
+        // declarations are loaded, but they aren't modules of the project.
+        let is_project_module = |path: &Utf8Path| {
+            path.starts_with(&project_path)
+                && !path
+                    .components()
+                    .any(|component| component.as_str() == "node_modules")
+        };
+        let to_import = |specifier: &str, resolved_path: &ResolvedPath, kind| ModuleGraphImport {
+            specifier: specifier.to_string(),
+            resolved_path: resolved_path.as_path().map(BiomePath::new),
+            kind,
+            symbols: Vec::new(),
+        };
+
+        let mut modules: Vec<_> =
+            self.module_graph
+                .data()
+                .iter()
+                .filter(|(path, _)| is_project_module(path))
+                .map(|(path, module_info)| {
+                    let static_imports =
+                        module_info
+                            .static_import_paths
+                            .iter()
+                            .map(|(specifier, resolved_path)| ModuleGraphImport {
+                                symbols: module_info.symbols_imported_from(specifier),
+                                ..to_import(specifier, resolved_path, ModuleGraphImportKind::Static)
+                            });
+                    let dynamic_imports = module_info.dynamic_import_paths.iter().map(
+                        |(specifier, resolved_path)| {
+                            to_import(specifier, resolved_path, ModuleGraphImportKind::Dynamic)
+                        },
+                    );
+                    ModuleGraphNode {
+                        path: BiomePath::new(path),
+                        imports: static_imports.chain(dynamic_imports).collect(),
+                    }
+                })
+                .collect();
+
+        modules.extend(
+            self.module_graph
+                .css_data()
+                .iter()
+                .filter(|(path, _)| is_project_module(path))
+                .map(|(path, css_module_info)| ModuleGraphNode {
+                    path: BiomePath::new(path),
+                    imports: css_module_info
+                        .references
+                        .iter()
+                        .map(|reference| {
+                            let kind = match reference.kind {
+                                CssReferenceKind::Url => ModuleGraphImportKind::Url,
+                                CssReferenceKind::Import | CssReferenceKind::Composes { .. } => {
+                                    ModuleGraphImportKind::Static
+                                }
+                            };
+                            to_import(&reference.specifier, &reference.resolved_path, kind)
+                        })
+                        .collect(),
+                }),
+        );
+
+        for module in &mut modules {
+            module.imports.sort_by(|a, b| a.specifier.cmp(&b.specifier));
+            module.imports.dedup();
+        }
+        modules.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(GetModuleGraphResult { modules })
+    }
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1688,9 +2011,7 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(&path)?;
 
         let document_file_source = self.get_file_source(&path);
-        let matches = search(&path, &document_file_source, parse, query, settings.into())?;
-
-        Ok(SearchResults { path, matches })
+        search(path, &document_file_source, parse, query, settings.into())
     }
 
     fn drop_pattern(&self, params: super::DropPatternParams) -> Result<(), WorkspaceError> {
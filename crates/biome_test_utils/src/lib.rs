@@ -5,6 +5,8 @@ use biome_analyze::{AnalyzerAction, AnalyzerConfiguration, AnalyzerOptions};
 use biome_configuration::Configuration;
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
+use biome_css_parser::CssParserOptions;
+use biome_css_syntax::{CssFileSource, CssRoot};
 use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
 use biome_fs::{BiomePath, FileSystem, OsFileSystem};
@@ -185,10 +187,13 @@ pub fn module_graph_for_test_file(
 
     let dir = input_file.parent().unwrap().to_path_buf();
     let paths = get_js_like_paths_in_dir(&dir);
+    let css_paths = get_css_paths_in_dir(&dir);
     let fs = OsFileSystem::new(dir);
     let paths = get_added_paths(&fs, &paths);
+    let css_paths = get_added_css_paths(&fs, &css_paths);
 
     module_graph.update_graph_for_js_paths(&fs, project_layout, &paths, &[]);
+    module_graph.update_graph_for_css_paths(&fs, project_layout, &css_paths, &[]);
 
     Arc::new(module_graph)
 }
@@ -217,6 +222,48 @@ pub fn get_added_paths<'a>(
         .collect()
 }
 
+/// Loads and parses stylesheets from the file system to pass them to service
+/// methods.
+///
+/// Files ending with `.module.css` are parsed with CSS Modules enabled.
+pub fn get_added_css_paths<'a>(
+    fs: &dyn FileSystem,
+    paths: &'a [BiomePath],
+) -> Vec<(&'a BiomePath, CssRoot)> {
+    paths
+        .iter()
+        .filter_map(|path| {
+            let content = fs.read_file_from_path(path).ok()?;
+            let mut options = CssParserOptions::default();
+            if path.extension() == Some("scss") {
+                options = options.allow_scss();
+            }
+            if path.as_str().ends_with(".module.css") {
+                options.css_modules = true;
+            }
+            let parsed = biome_css_parser::parse_css(&content, options);
+            Some((path, parsed.tree()))
+        })
+        .collect()
+}
+
+fn get_css_paths_in_dir(dir: &Utf8Path) -> Vec<BiomePath> {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .flat_map(|path| {
+            let path = Utf8PathBuf::try_from(path.unwrap().path()).unwrap();
+            if path.is_dir() {
+                get_css_paths_in_dir(&path)
+            } else {
+                CssFileSource::try_from(path.as_path())
+                    .map(|_| BiomePath::new(path))
+                    .into_iter()
+                    .collect()
+            }
+        })
+        .collect()
+}
+
 fn get_js_like_paths_in_dir(dir: &Utf8Path) -> Vec<BiomePath> {
     std::fs::read_dir(dir)
         .unwrap()
@@ -110,6 +110,35 @@ impl TextEdit {
         &self.dictionary[range]
     }
 
+    /// Returns a copy of this [TextEdit] that keeps `prefix` unchanged before
+    /// applying its operations, e.g. to move the edit of an embedded source
+    /// to the position of this source in its document
+    pub fn with_prefix(&self, prefix: &str) -> Self {
+        let mut builder = Self::builder();
+        if !prefix.is_empty() {
+            builder.equal(prefix);
+        }
+        for op in &self.ops {
+            match op {
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                    let range = builder.intern(&self.dictionary[*range]);
+                    builder
+                        .edit
+                        .ops
+                        .push(CompressedOp::DiffOp(DiffOp::Equal { range }));
+                }
+                CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                    builder.insert(&self.dictionary[*range]);
+                }
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                    builder.delete(&self.dictionary[*range]);
+                }
+                CompressedOp::EqualLines { .. } => builder.edit.ops.push(*op),
+            }
+        }
+        builder.finish()
+    }
+
     /// Return the content of the "new" revision of the text represented in
     /// this [TextEdit]. This methods needs to be provided with the "old"
     /// revision of the string since [TextEdit] doesn't store the content of
@@ -383,4 +412,30 @@ line 7 new";
 
         assert_eq!(new_string, NEW);
     }
+
+    #[test]
+    fn new_string_with_prefix() {
+        const PREFIX: &str = "<script>
+";
+        const OLD: &str = "line 1 old
+line 2
+line 3
+line 4
+line 5
+line 6
+line 7 old";
+
+        const NEW: &str = "line 1 new
+line 2
+line 3
+line 4
+line 5
+line 6
+line 7 new";
+
+        let diff = TextEdit::from_unicode_words(OLD, NEW).with_prefix(PREFIX);
+        let new_string = diff.new_string(&format!("{PREFIX}{OLD}"));
+
+        assert_eq!(new_string, format!("{PREFIX}{NEW}"));
+    }
 }
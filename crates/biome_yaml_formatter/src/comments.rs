@@ -0,0 +1,115 @@
+use crate::prelude::*;
+use biome_diagnostics::category;
+use biome_formatter::comments::{
+    CommentKind, CommentPlacement, CommentStyle, Comments, DecoratedComment, SourceComment,
+};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{FormatResult, FormatRule, write};
+use biome_rowan::AstNode;
+use biome_rowan::SyntaxTriviaPieceComments;
+use biome_suppression::parse_suppression_comment;
+use biome_yaml_syntax::{YamlDocument, YamlLanguage, YamlSyntaxKind};
+
+pub type YamlComments = Comments<YamlLanguage>;
+
+#[derive(Default)]
+pub struct FormatYamlLeadingComment;
+
+impl FormatRule<SourceComment<YamlLanguage>> for FormatYamlLeadingComment {
+    type Context = YamlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<YamlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct YamlCommentStyle;
+
+impl CommentStyle for YamlCommentStyle {
+    type Language = YamlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        parse_suppression_comment(text)
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, ..)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Line
+    }
+
+    fn place_comment(
+        &self,
+        comment: DecoratedComment<Self::Language>,
+    ) -> CommentPlacement<Self::Language> {
+        handle_comment_after_indicator(comment).or_else(handle_comment_after_document_start)
+    }
+}
+
+/// Keeps a comment that directly follows a block indicator on the indicator line.
+///
+/// ```yaml
+/// key: # comment
+///   nested: value
+/// - # comment
+///   nested: value
+/// ```
+///
+/// Without this rule, the comment becomes a leading comment of the value and moves to the next line.
+fn handle_comment_after_indicator(
+    comment: DecoratedComment<YamlLanguage>,
+) -> CommentPlacement<YamlLanguage> {
+    if !comment.text_position().is_end_of_line() || comment.preceding_node().is_some() {
+        return CommentPlacement::Default(comment);
+    }
+
+    match comment.enclosing_node().kind() {
+        YamlSyntaxKind::YAML_BLOCK_MAP_IMPLICIT_VALUE
+        | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_KEY
+        | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_VALUE
+        | YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY => {
+            let enclosing = comment.enclosing_node().clone();
+            CommentPlacement::dangling(enclosing, comment)
+        }
+        _ => CommentPlacement::Default(comment),
+    }
+}
+
+/// Keeps a comment on the line of the `---` marker of a document.
+///
+/// ```yaml
+/// --- # comment
+/// key: value
+/// ```
+fn handle_comment_after_document_start(
+    comment: DecoratedComment<YamlLanguage>,
+) -> CommentPlacement<YamlLanguage> {
+    if !comment.text_position().is_end_of_line() {
+        return CommentPlacement::Default(comment);
+    }
+
+    let Some(document) = YamlDocument::cast_ref(comment.enclosing_node()) else {
+        return CommentPlacement::Default(comment);
+    };
+    let Some(dashdashdash_token) = document.dashdashdash_token() else {
+        return CommentPlacement::Default(comment);
+    };
+
+    let follows_marker =
+        comment.piece().text_range().start() > dashdashdash_token.text_trimmed_range().end();
+    let precedes_node = document.node().is_none_or(|node| {
+        comment.piece().text_range().end() <= node.syntax().text_trimmed_range().start()
+    });
+
+    if follows_marker && precedes_node {
+        CommentPlacement::dangling(document.into_syntax(), comment)
+    } else {
+        CommentPlacement::Default(comment)
+    }
+}
@@ -0,0 +1,145 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockIndented, AnyYamlBlockNode, AnyYamlFlowNode,
+    AnyYamlJsonContent, YamlSyntaxNode,
+};
+
+/// The position of a block node relative to the indicator that introduces it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum BlockValuePosition {
+    /// The value of an implicit mapping entry, e.g. `key: value`.
+    ImplicitValue,
+
+    /// The node following a `-`, `?` or `:` indicator of a block sequence entry or of an
+    /// explicit mapping entry. These nodes can be compact collections, e.g. `- a: b`.
+    Indented,
+}
+
+/// Formats the node following a block indicator, including the separation between the two.
+///
+/// Comments directly following the indicator are dangling comments of `parent` and are kept on
+/// the indicator line.
+pub(crate) struct FormatBlockValue<'a> {
+    parent: &'a YamlSyntaxNode,
+    value: Option<AnyYamlBlockIndented>,
+    position: BlockValuePosition,
+}
+
+impl<'a> FormatBlockValue<'a> {
+    pub(crate) fn new(
+        parent: &'a YamlSyntaxNode,
+        value: Option<AnyYamlBlockIndented>,
+        position: BlockValuePosition,
+    ) -> Self {
+        Self {
+            parent,
+            value,
+            position,
+        }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatBlockValue<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let has_dangling_comments = f.comments().has_dangling_comments(self.parent);
+        if has_dangling_comments {
+            write!(f, [space(), format_dangling_comments(self.parent)])?;
+        }
+
+        let Some(value) = &self.value else {
+            return Ok(());
+        };
+
+        let kind = BlockValueKind::from_node(value);
+        let value = value.format();
+
+        match self.position {
+            BlockValuePosition::ImplicitValue => match kind {
+                _ if has_dangling_comments => {
+                    write!(f, [indent(&format_args![hard_line_break(), value])])
+                }
+                // The collection must be on its own line, more indented than the key
+                BlockValueKind::Collection {
+                    has_properties: false,
+                } => write!(f, [indent(&format_args![hard_line_break(), value])]),
+                // The properties stay on the key line, the content of the collection is
+                // indented on the next lines
+                BlockValueKind::Collection {
+                    has_properties: true,
+                } => write!(f, [space(), indent(&value)]),
+                // The closing bracket of a broken flow collection must be more indented than
+                // the key, moving the whole collection to the next line keeps it readable
+                BlockValueKind::FlowCollection => write!(
+                    f,
+                    [group(&indent(&format_args![
+                        soft_line_break_or_space(),
+                        value
+                    ]))]
+                ),
+                BlockValueKind::BlockScalar | BlockValueKind::Other => {
+                    write!(f, [space(), value])
+                }
+            },
+            BlockValuePosition::Indented => match kind {
+                // Collections are aligned with the first character after the indicator, which
+                // is where the entries of a compact collection start. This also keeps the
+                // closing bracket of a broken flow collection more indented than the indicator.
+                BlockValueKind::Collection { .. } | BlockValueKind::FlowCollection => {
+                    if has_dangling_comments {
+                        write!(f, [align(2, &format_args![hard_line_break(), value])])
+                    } else {
+                        write!(f, [space(), align(2, &value)])
+                    }
+                }
+                BlockValueKind::BlockScalar | BlockValueKind::Other => {
+                    if has_dangling_comments {
+                        write!(f, [indent(&format_args![hard_line_break(), value])])
+                    } else {
+                        write!(f, [space(), value])
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum BlockValueKind {
+    Collection { has_properties: bool },
+    FlowCollection,
+    BlockScalar,
+    Other,
+}
+
+impl BlockValueKind {
+    fn from_node(node: &AnyYamlBlockIndented) -> Self {
+        match node {
+            AnyYamlBlockIndented::YamlCompactMapping(_)
+            | AnyYamlBlockIndented::YamlCompactSequence(_) => Self::Collection {
+                has_properties: false,
+            },
+            AnyYamlBlockIndented::AnyYamlBlockNode(node) => match node {
+                AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                    AnyYamlBlockInBlockNode::YamlBlockCollection(collection),
+                ) => Self::Collection {
+                    has_properties: collection.properties().is_some(),
+                },
+                AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                    AnyYamlBlockInBlockNode::YamlBlockScalar(_),
+                ) => Self::BlockScalar,
+                AnyYamlBlockNode::YamlFlowInBlockNode(node) => match node.flow() {
+                    Ok(AnyYamlFlowNode::YamlFlowJsonNode(node)) => match node.content() {
+                        Some(
+                            AnyYamlJsonContent::YamlFlowMapping(_)
+                            | AnyYamlJsonContent::YamlFlowSequence(_),
+                        ) => Self::FlowCollection,
+                        _ => Self::Other,
+                    },
+                    _ => Self::Other,
+                },
+                AnyYamlBlockNode::YamlBogusBlockNode(_) => Self::Other,
+            },
+        }
+    }
+}
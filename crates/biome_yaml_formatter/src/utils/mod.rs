@@ -0,0 +1,19 @@
+pub(crate) mod block_value;
+pub(crate) mod scalar;
+
+use biome_yaml_syntax::{YamlSyntaxKind, YamlSyntaxNode};
+
+/// Returns `true` if a space must separate the key `node` from the following `:`.
+///
+/// Alias and anchor names, as well as tags, can contain a `:`, so it would become part of the
+/// name of the last property of the key.
+pub(crate) fn needs_space_before_colon(node: &YamlSyntaxNode) -> bool {
+    node.last_token().is_some_and(|token| {
+        matches!(
+            token.kind(),
+            YamlSyntaxKind::ALIAS_LITERAL
+                | YamlSyntaxKind::ANCHOR_PROPERTY_LITERAL
+                | YamlSyntaxKind::TAG_PROPERTY_LITERAL
+        )
+    })
+}
@@ -0,0 +1,218 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{TextSize, YamlSyntaxToken};
+
+/// The style of a scalar, which determines how its lines can be re-indented.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ScalarStyle {
+    Plain,
+    SingleQuoted,
+    DoubleQuoted,
+}
+
+/// Formats a plain or quoted scalar.
+///
+/// The line breaks of a multiline flow scalar are folded when the document is loaded, and the
+/// whitespace surrounding them isn't part of the content. This allows to re-indent the
+/// continuation lines to the current indentation level. Empty lines are meaningful, so they're
+/// all preserved.
+pub(crate) struct FormatFlowScalar<'a> {
+    token: &'a YamlSyntaxToken,
+    style: ScalarStyle,
+}
+
+impl<'a> FormatFlowScalar<'a> {
+    pub(crate) fn new(token: &'a YamlSyntaxToken, style: ScalarStyle) -> Self {
+        Self { token, style }
+    }
+
+    /// Removes the trailing whitespace of a line, unless it's part of an escape sequence.
+    fn trim_line_end<'t>(&self, line: &'t str) -> &'t str {
+        let trimmed = line.trim_end_matches([' ', '\t']);
+        if self.style == ScalarStyle::DoubleQuoted && trimmed.ends_with('\\') {
+            line
+        } else {
+            trimmed
+        }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatFlowScalar<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let text = self.token.text_trimmed();
+        if !text.contains(['\n', '\r']) {
+            return write!(f, [self.token.format()]);
+        }
+
+        let start = self.token.text_trimmed_range().start();
+        let mut lines = ScalarLines::new(text, start);
+        let content = format_once(|f| {
+            if let Some((first_line, position)) = lines.next() {
+                write!(f, [dynamic_text(self.trim_line_end(first_line), position)])?;
+            }
+
+            let continuation_lines = format_once(|f| {
+                let mut empty_lines = 0;
+                for (line, position) in lines {
+                    let leading_whitespace =
+                        line.len() - line.trim_start_matches([' ', '\t']).len();
+                    let line = self.trim_line_end(&line[leading_whitespace..]);
+                    if line.is_empty() {
+                        empty_lines += 1;
+                        continue;
+                    }
+                    write!(
+                        f,
+                        [
+                            format_line_breaks(empty_lines),
+                            dynamic_text(
+                                line,
+                                position + TextSize::from(leading_whitespace as u32)
+                            )
+                        ]
+                    )?;
+                    empty_lines = 0;
+                }
+                Ok(())
+            });
+
+            write!(f, [indent(&continuation_lines)])
+        });
+
+        write!(f, [format_replaced(self.token, &content)])
+    }
+}
+
+/// Formats the content of a literal or folded block scalar.
+///
+/// The content is re-indented one level deeper than the current indentation. Every line keeps
+/// its content after the detected indentation, including the trailing whitespace and the empty
+/// lines, because they are all part of the value.
+///
+/// Block scalars with an explicit indentation indicator must be formatted verbatim, because the
+/// indentation of their content is relative to the indentation of their parent node.
+pub(crate) struct FormatBlockScalarToken<'a> {
+    token: &'a YamlSyntaxToken,
+}
+
+impl<'a> FormatBlockScalarToken<'a> {
+    pub(crate) fn new(token: &'a YamlSyntaxToken) -> Self {
+        Self { token }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatBlockScalarToken<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let text = self.token.text_trimmed();
+        let start = self.token.text_trimmed_range().start();
+        let mut lines = ScalarLines::new(text, start);
+
+        let content = format_once(|f| {
+            if let Some((header, position)) = lines.next() {
+                write!(f, [dynamic_text(header.trim_end(), position)])?;
+            }
+
+            let lines: Vec<_> = lines.collect();
+            // The indentation of the first non-empty line is the indentation of the content
+            let content_indent = lines
+                .iter()
+                .find_map(|(line, _)| {
+                    let trimmed = line.trim_start_matches(' ');
+                    (!trimmed.is_empty()).then_some(line.len() - trimmed.len())
+                })
+                .unwrap_or_default();
+
+            let content_lines = format_once(|f| {
+                let mut empty_lines = 0;
+                for (line, position) in &lines {
+                    let content = line.get(content_indent..).unwrap_or_default();
+                    if content.is_empty() {
+                        empty_lines += 1;
+                        continue;
+                    }
+                    write!(
+                        f,
+                        [
+                            format_line_breaks(empty_lines),
+                            dynamic_text(
+                                content,
+                                *position + TextSize::from(content_indent as u32)
+                            )
+                        ]
+                    )?;
+                    empty_lines = 0;
+                }
+
+                // The trailing empty lines are only part of the token when they're kept
+                // by the chomping indicator.
+                if empty_lines > 0 {
+                    write!(f, [format_empty_lines(empty_lines)])?;
+                }
+                Ok(())
+            });
+
+            write!(f, [indent(&content_lines)])
+        });
+
+        write!(f, [format_replaced(self.token, &content)])
+    }
+}
+
+/// Returns `true` if the header of a block scalar has an explicit indentation indicator, e.g. `|2`.
+pub(crate) fn has_indentation_indicator(token: &YamlSyntaxToken) -> bool {
+    token
+        .text_trimmed()
+        .bytes()
+        .skip(1)
+        .take(2)
+        .any(|byte| byte.is_ascii_digit())
+}
+
+/// Writes a line break followed by `count` empty lines.
+///
+/// The printer collapses consecutive empty lines, but all the empty lines of a scalar are part
+/// of its value.
+fn format_line_breaks(count: usize) -> impl Format<YamlFormatContext> {
+    format_with(move |f| {
+        format_empty_lines(count).fmt(f)?;
+        write!(f, [hard_line_break()])
+    })
+}
+
+fn format_empty_lines(count: usize) -> impl Format<YamlFormatContext> {
+    format_with(move |f| {
+        if count > 0 {
+            // Terminates the current line and writes the empty lines
+            for _ in 0..=count {
+                write!(f, [text("\n")])?;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Iterates over the lines of a scalar token, together with their position in the source.
+struct ScalarLines<'a> {
+    lines: std::str::Split<'a, char>,
+    position: TextSize,
+}
+
+impl<'a> ScalarLines<'a> {
+    fn new(text: &'a str, start: TextSize) -> Self {
+        Self {
+            lines: text.split('\n'),
+            position: start,
+        }
+    }
+}
+
+impl<'a> Iterator for ScalarLines<'a> {
+    type Item = (&'a str, TextSize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        let position = self.position;
+        self.position += TextSize::from(line.len() as u32 + 1);
+        Some((line.strip_suffix('\r').unwrap_or(line), position))
+    }
+}
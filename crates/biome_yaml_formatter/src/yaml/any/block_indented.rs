@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlBlockIndented;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlBlockIndented;
+impl FormatRule<AnyYamlBlockIndented> for FormatAnyYamlBlockIndented {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlBlockIndented, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlBlockIndented::AnyYamlBlockNode(node) => node.format().fmt(f),
+            AnyYamlBlockIndented::YamlCompactMapping(node) => node.format().fmt(f),
+            AnyYamlBlockIndented::YamlCompactSequence(node) => node.format().fmt(f),
+        }
+    }
+}
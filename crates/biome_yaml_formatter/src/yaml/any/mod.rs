@@ -0,0 +1,17 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block_collection_content;
+pub(crate) mod block_in_block_node;
+pub(crate) mod block_indented;
+pub(crate) mod block_map_entry;
+pub(crate) mod block_map_implicit_key;
+pub(crate) mod block_node;
+pub(crate) mod block_scalar_content;
+pub(crate) mod block_sequence_entry;
+pub(crate) mod document;
+pub(crate) mod flow_map_entry;
+pub(crate) mod flow_map_implicit_key;
+pub(crate) mod flow_node;
+pub(crate) mod flow_sequence_entry;
+pub(crate) mod json_content;
+pub(crate) mod properties_combination;
@@ -0,0 +1,87 @@
+use crate::prelude::*;
+use crate::utils::scalar::has_indentation_indicator;
+use biome_formatter::write;
+use biome_rowan::Direction;
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockNode, YamlDocument, YamlDocumentFields, YamlSyntaxKind,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocument;
+impl FormatNodeRule<YamlDocument> for FormatYamlDocument {
+    fn fmt_fields(&self, node: &YamlDocument, f: &mut YamlFormatter) -> FormatResult<()> {
+        // The content of a block scalar with an indentation indicator is indented relative
+        // to its parent node. The formatter can't keep this relation when it re-indents
+        // the parent node, so the document is left untouched.
+        if node
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .any(|token| {
+                matches!(
+                    token.kind(),
+                    YamlSyntaxKind::LITERAL_BLOCK_LITERAL | YamlSyntaxKind::FOLDED_BLOCK_LITERAL
+                ) && has_indentation_indicator(&token)
+            })
+        {
+            return format_verbatim_node(node.syntax()).fmt(f);
+        }
+
+        let YamlDocumentFields {
+            bom_token,
+            directives,
+            dashdashdash_token,
+            node: block_node,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format(), directives.format()])?;
+
+        if let Some(dashdashdash_token) = &dashdashdash_token {
+            if !directives.is_empty() {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(f, [dashdashdash_token.format()])?;
+
+            let has_dangling_comments = f.comments().has_dangling_comments(node.syntax());
+            if has_dangling_comments {
+                write!(f, [space(), format_dangling_comments(node.syntax())])?;
+            }
+
+            if let Some(block_node) = &block_node {
+                if has_dangling_comments || starts_on_own_line(block_node) {
+                    write!(f, [hard_line_break()])?;
+                } else {
+                    write!(f, [space()])?;
+                }
+            }
+        }
+
+        write!(f, [block_node.format()])?;
+
+        if let Some(dotdotdot_token) = &dotdotdot_token {
+            if dashdashdash_token.is_some() || block_node.is_some() || !directives.is_empty() {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(f, [dotdotdot_token.format()])?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_dangling_comments(&self, _: &YamlDocument, _: &mut YamlFormatter) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
+
+/// Returns `true` if the node must start on the line following the `---` marker.
+///
+/// Block collections can't start on the marker line, other nodes stay where they are.
+fn starts_on_own_line(node: &AnyYamlBlockNode) -> bool {
+    match node {
+        AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+            AnyYamlBlockInBlockNode::YamlBlockCollection(collection),
+        ) if collection.properties().is_none() => true,
+        _ => node.syntax().has_leading_newline(),
+    }
+}
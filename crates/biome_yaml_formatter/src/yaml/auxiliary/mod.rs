@@ -0,0 +1,8 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod alias_node;
+pub(crate) mod directive;
+pub(crate) mod document;
+pub(crate) mod properties_anchor_first;
+pub(crate) mod properties_tag_first;
+pub(crate) mod root;
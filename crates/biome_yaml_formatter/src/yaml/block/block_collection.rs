@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockCollection, YamlBlockCollectionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockCollection;
+impl FormatNodeRule<YamlBlockCollection> for FormatYamlBlockCollection {
+    fn fmt_fields(&self, node: &YamlBlockCollection, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockCollectionFields {
+            properties,
+            content,
+        } = node.as_fields();
+
+        // A block collection always starts on a new line, the properties stay on the line
+        // of the parent node
+        if let Some(properties) = properties {
+            write!(f, [properties.format(), hard_line_break()])?;
+        }
+
+        write!(f, [content.format()])
+    }
+}
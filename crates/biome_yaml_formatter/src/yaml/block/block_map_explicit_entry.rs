@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockMapExplicitEntry, YamlBlockMapExplicitEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapExplicitEntry;
+impl FormatNodeRule<YamlBlockMapExplicitEntry> for FormatYamlBlockMapExplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapExplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapExplicitEntryFields { key, value } = node.as_fields();
+
+        write!(f, [key.format()])?;
+
+        if let Some(value) = value {
+            write!(f, [hard_line_break(), value.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use crate::utils::block_value::{BlockValuePosition, FormatBlockValue};
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockMapExplicitKey, YamlBlockMapExplicitKeyFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapExplicitKey;
+impl FormatNodeRule<YamlBlockMapExplicitKey> for FormatYamlBlockMapExplicitKey {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapExplicitKey,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapExplicitKeyFields {
+            question_mark_token,
+            key,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                question_mark_token.format(),
+                FormatBlockValue::new(node.syntax(), key, BlockValuePosition::Indented)
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlBlockMapExplicitKey,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
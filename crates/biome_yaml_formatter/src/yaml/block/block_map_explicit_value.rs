@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use crate::utils::block_value::{BlockValuePosition, FormatBlockValue};
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockMapExplicitValue, YamlBlockMapExplicitValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapExplicitValue;
+impl FormatNodeRule<YamlBlockMapExplicitValue> for FormatYamlBlockMapExplicitValue {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapExplicitValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapExplicitValueFields { colon_token, value } = node.as_fields();
+
+        write!(
+            f,
+            [
+                colon_token.format(),
+                FormatBlockValue::new(node.syntax(), value, BlockValuePosition::Indented)
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlBlockMapExplicitValue,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
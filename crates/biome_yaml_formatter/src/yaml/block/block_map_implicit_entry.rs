@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::needs_space_before_colon;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockMapImplicitEntry, YamlBlockMapImplicitEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapImplicitEntry;
+impl FormatNodeRule<YamlBlockMapImplicitEntry> for FormatYamlBlockMapImplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapImplicitEntryFields { key, value } = node.as_fields();
+
+        if let Some(key) = key {
+            write!(f, [key.format()])?;
+            if needs_space_before_colon(key.syntax()) {
+                write!(f, [space()])?;
+            }
+        }
+
+        write!(f, [value.format()])
+    }
+}
@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use crate::utils::block_value::{BlockValuePosition, FormatBlockValue};
+use biome_formatter::write;
+use biome_yaml_syntax::{
+    AnyYamlBlockIndented, YamlBlockMapImplicitValue, YamlBlockMapImplicitValueFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapImplicitValue;
+impl FormatNodeRule<YamlBlockMapImplicitValue> for FormatYamlBlockMapImplicitValue {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapImplicitValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapImplicitValueFields { colon_token, value } = node.as_fields();
+
+        write!(
+            f,
+            [
+                colon_token.format(),
+                FormatBlockValue::new(
+                    node.syntax(),
+                    value.map(AnyYamlBlockIndented::AnyYamlBlockNode),
+                    BlockValuePosition::ImplicitValue
+                )
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlBlockMapImplicitValue,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
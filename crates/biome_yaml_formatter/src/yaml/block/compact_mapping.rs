@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlCompactMapping, YamlCompactMappingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlCompactMapping;
+impl FormatNodeRule<YamlCompactMapping> for FormatYamlCompactMapping {
+    fn fmt_fields(&self, node: &YamlCompactMapping, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlCompactMappingFields { entries } = node.as_fields();
+
+        write!(f, [entries.format()])
+    }
+}
@@ -0,0 +1,13 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block_collection;
+pub(crate) mod block_map_explicit_entry;
+pub(crate) mod block_map_explicit_key;
+pub(crate) mod block_map_explicit_value;
+pub(crate) mod block_map_implicit_entry;
+pub(crate) mod block_map_implicit_value;
+pub(crate) mod block_mapping;
+pub(crate) mod block_sequence;
+pub(crate) mod block_sequence_entry;
+pub(crate) mod compact_mapping;
+pub(crate) mod compact_sequence;
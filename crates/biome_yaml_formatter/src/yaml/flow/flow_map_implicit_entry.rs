@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use crate::utils::needs_space_before_colon;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowMapImplicitEntry, YamlFlowMapImplicitEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapImplicitEntry;
+impl FormatNodeRule<YamlFlowMapImplicitEntry> for FormatYamlFlowMapImplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlFlowMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlFlowMapImplicitEntryFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format()])?;
+
+        if let Some(colon_token) = colon_token {
+            if key
+                .as_ref()
+                .is_some_and(|key| needs_space_before_colon(key.syntax()))
+            {
+                write!(f, [space()])?;
+            }
+            write!(f, [colon_token.format()])?;
+        }
+
+        if let Some(value) = value {
+            write!(f, [space(), value.format()])?;
+        }
+
+        Ok(())
+    }
+}
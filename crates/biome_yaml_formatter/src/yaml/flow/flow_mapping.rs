@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{YamlFlowMapping, YamlFlowMappingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapping;
+impl FormatNodeRule<YamlFlowMapping> for FormatYamlFlowMapping {
+    fn fmt_fields(&self, node: &YamlFlowMapping, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowMappingFields {
+            l_curly_token,
+            entries,
+            r_curly_token,
+        } = node.as_fields();
+
+        if entries.is_empty() {
+            return write!(
+                f,
+                [
+                    l_curly_token.format(),
+                    format_dangling_comments(node.syntax()).with_block_indent(),
+                    r_curly_token.format()
+                ]
+            );
+        }
+
+        write!(
+            f,
+            [group(&format_args![
+                l_curly_token.format(),
+                soft_block_indent_with_maybe_space(&entries.format(), true),
+                r_curly_token.format()
+            ])]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlFlowMapping,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
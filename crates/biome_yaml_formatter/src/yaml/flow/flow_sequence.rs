@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{YamlFlowSequence, YamlFlowSequenceFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowSequence;
+impl FormatNodeRule<YamlFlowSequence> for FormatYamlFlowSequence {
+    fn fmt_fields(&self, node: &YamlFlowSequence, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowSequenceFields {
+            l_brack_token,
+            entries,
+            r_brack_token,
+        } = node.as_fields();
+
+        if entries.is_empty() {
+            return write!(
+                f,
+                [
+                    l_brack_token.format(),
+                    format_dangling_comments(node.syntax()).with_block_indent(),
+                    r_brack_token.format()
+                ]
+            );
+        }
+
+        write!(
+            f,
+            [group(&format_args![
+                l_brack_token.format(),
+                soft_block_indent(&entries.format()),
+                r_brack_token.format()
+            ])]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlFlowSequence,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod flow_in_block_node;
+pub(crate) mod flow_json_node;
+pub(crate) mod flow_map_explicit_entry;
+pub(crate) mod flow_map_implicit_entry;
+pub(crate) mod flow_mapping;
+pub(crate) mod flow_sequence;
+pub(crate) mod flow_yaml_node;
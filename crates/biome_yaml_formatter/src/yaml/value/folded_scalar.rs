@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use crate::utils::scalar::FormatBlockScalarToken;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFoldedScalar, YamlFoldedScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFoldedScalar;
+impl FormatNodeRule<YamlFoldedScalar> for FormatYamlFoldedScalar {
+    fn fmt_fields(&self, node: &YamlFoldedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFoldedScalarFields { value_token } = node.as_fields();
+
+        write!(f, [FormatBlockScalarToken::new(&value_token?)])
+    }
+}
@@ -0,0 +1,10 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod anchor_property;
+pub(crate) mod block_scalar;
+pub(crate) mod double_quoted_scalar;
+pub(crate) mod folded_scalar;
+pub(crate) mod literal_scalar;
+pub(crate) mod plain_scalar;
+pub(crate) mod single_quoted_scalar;
+pub(crate) mod tag_property;
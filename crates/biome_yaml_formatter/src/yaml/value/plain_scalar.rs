@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use crate::utils::scalar::{FormatFlowScalar, ScalarStyle};
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlPlainScalar, YamlPlainScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlPlainScalar;
+impl FormatNodeRule<YamlPlainScalar> for FormatYamlPlainScalar {
+    fn fmt_fields(&self, node: &YamlPlainScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlPlainScalarFields { value_token } = node.as_fields();
+
+        write!(
+            f,
+            [FormatFlowScalar::new(&value_token?, ScalarStyle::Plain)]
+        )
+    }
+}
@@ -54,16 +54,31 @@ impl<'src> YamlLexer<'src> {
         let start = self.text_position();
 
         let kind = match current {
-            b':' => self.consume_byte(T![:]),
+            UNICODE_BOM_FIRST_BYTE if self.position == 0 => self.consume_unicode_bom(),
+            b' ' | b'\t' | b'\n' | b'\r' => self.consume_newline_or_whitespaces(),
+            b'#' => self.consume_comment(),
+            b'%' if self.is_at_line_start_column() => self.consume_directive(),
+            b'-' if self.is_at_document_marker(b'-') => self.consume_document_marker(T![---]),
+            b'.' if self.is_at_document_marker(b'.') => self.consume_document_marker(T![...]),
+            b':' if self.is_after_json_node(context) => self.consume_byte(T![:]),
+            b':' | b'?' | b'-' if !self.is_first_plain_char(current, context) => {
+                self.consume_byte(match current {
+                    b':' => T![:],
+                    b'?' => T![?],
+                    _ => T![-],
+                })
+            }
             b',' => self.consume_byte(T![,]),
             b'[' => self.consume_byte(T!['[']),
             b']' => self.consume_byte(T![']']),
-            b'?' => self.consume_byte(T![?]),
-            b'-' => self.consume_byte(T![-]),
-            b'#' => self.consume_comment(),
+            b'{' => self.consume_byte(T!['{']),
+            b'}' => self.consume_byte(T!['}']),
+            b'&' => self.consume_node_property(ANCHOR_PROPERTY_LITERAL),
+            b'*' => self.consume_node_property(ALIAS_LITERAL),
+            b'!' => self.consume_tag_property(),
+            b'|' | b'>' if !context.is_flow() => self.consume_block_scalar(current, context),
             b'\'' => self.consume_single_quoted_literal(),
             b'"' => self.consume_double_quoted_literal(),
-            b' ' | b'\n' => self.consume_newline_or_whitespaces(),
             _ if self.is_first_plain_char(current, context) => self.consume_plain_literal(context),
             _ => self.consume_unexpected_token(),
         };
@@ -79,19 +94,119 @@ impl<'src> YamlLexer<'src> {
         tok
     }
 
+    fn consume_unicode_bom(&mut self) -> YamlSyntaxKind {
+        if self.source.starts_with('\u{feff}') {
+            self.unicode_bom_length = '\u{feff}'.len_utf8();
+            self.advance(self.unicode_bom_length);
+            UNICODE_BOM
+        } else {
+            self.consume_unexpected_token()
+        }
+    }
+
     fn consume_comment(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'#');
+        self.consume_until_line_break();
+        COMMENT
+    }
+
+    /// Consumes all the characters until the end of the current line, excluding the line break.
+    fn consume_until_line_break(&mut self) {
         while let Some(c) = self.current_byte() {
-            if c == b'\n' {
+            if is_break(c) {
                 break;
             }
-            self.advance(1);
+            self.advance_char_unchecked();
         }
-        COMMENT
+    }
+
+    // https://yaml.org/spec/1.2.2/#68-directives
+    fn consume_directive(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'%');
+        while let Some(c) = self.current_byte() {
+            if is_break(c) || (is_space(c) && self.is_at_comment_after_spaces()) {
+                break;
+            }
+            self.advance_char_unchecked();
+        }
+        DIRECTIVE_LITERAL
+    }
+
+    /// Returns `true` if the whitespaces at the current position are followed by a comment
+    fn is_at_comment_after_spaces(&self) -> bool {
+        let spaces = self.count_spaces_from(self.position);
+        self.source.as_bytes().get(self.position + spaces) == Some(&b'#')
+    }
+
+    // https://yaml.org/spec/1.2.2/#rule-c-directives-end
+    // https://yaml.org/spec/1.2.2/#rule-c-document-end
+    fn is_at_document_marker(&self, marker: u8) -> bool {
+        self.is_at_line_start_column()
+            && self.peek_byte() == Some(marker)
+            && self.byte_at(2) == Some(marker)
+            && self.byte_at(3).is_none_or(|c| is_space(c) || is_break(c))
+    }
+
+    fn consume_document_marker(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        self.advance(3);
+        kind
+    }
+
+    /// Anchors and aliases share the same syntax, only their first character differs.
+    ///
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-anchor-property
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-alias-node
+    fn consume_node_property(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        self.advance(1);
+        let start = self.position;
+        while let Some(c) = self.current_byte() {
+            if is_space(c) || is_break(c) || is_flow_indicator(c) {
+                break;
+            }
+            self.advance_char_unchecked();
+        }
+        if self.position == start {
+            self.diagnostics.push(ParseDiagnostic::new(
+                "Expected the name of an anchor or an alias.",
+                self.current_start..self.text_position(),
+            ));
+            return ERROR_TOKEN;
+        }
+        kind
+    }
+
+    // https://yaml.org/spec/1.2.2/#rule-c-ns-tag-property
+    fn consume_tag_property(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'!');
+        self.advance(1);
+        // Verbatim tag, e.g. !<tag:yaml.org,2002:str>
+        if self.current_byte() == Some(b'<') {
+            while let Some(c) = self.current_byte() {
+                if is_space(c) || is_break(c) {
+                    break;
+                }
+                self.advance_char_unchecked();
+                if c == b'>' {
+                    return TAG_PROPERTY_LITERAL;
+                }
+            }
+            self.diagnostics.push(ParseDiagnostic::new(
+                "Missing closing `>` of the verbatim tag.",
+                self.current_start..self.text_position(),
+            ));
+            return ERROR_TOKEN;
+        }
+
+        while let Some(c) = self.current_byte() {
+            if is_space(c) || is_break(c) || is_flow_indicator(c) {
+                break;
+            }
+            self.advance_char_unchecked();
+        }
+        TAG_PROPERTY_LITERAL
     }
 
     // https://yaml.org/spec/1.2.2/#rule-ns-plain
-    // TODO: parse multiline plain scalar at current indentation level
     fn consume_plain_literal(&mut self, context: YamlLexContext) -> YamlSyntaxKind {
         self.assert_current_char_boundary();
         debug_assert!(
@@ -99,28 +214,101 @@ impl<'src> YamlLexer<'src> {
                 .is_some_and(|c| self.is_first_plain_char(c, context))
         );
         self.advance_char_unchecked();
+        loop {
+            self.consume_plain_line(context);
+            if !self.consume_plain_line_continuation(context) {
+                break;
+            }
+        }
+        PLAIN_LITERAL
+    }
+
+    /// Consumes the characters of a plain scalar until the end of the current line.
+    fn consume_plain_line(&mut self, context: YamlLexContext) {
         while let Some(c) = self.current_byte() {
             // https://yaml.org/spec/1.2.2/#rule-ns-plain-char
-            if is_plain_safe(c, context) && c != b':' && c != b'#' {
-                self.advance_char_unchecked();
-            } else if is_non_space_char(c) && self.peek_byte().is_some_and(|c| c == b'#') {
-                self.advance_char_unchecked();
-                self.advance(1); // '#'
-            } else if c == b':' && self.peek_byte().is_some_and(|c| is_plain_safe(c, context)) {
-                self.advance(1); // ':'
+            if is_space(c) {
+                // Yes plain token can contain spaces
+                // For example:
+                // a bc: x yz
+                // Is a valid mapping
+                // Trailing spaces aren't part of the scalar though.
+                let spaces = self.count_spaces_from(self.position);
+                match self.byte_at(spaces) {
+                    Some(b'#') => break,
+                    Some(b':')
+                        if !self
+                            .byte_at(spaces + 1)
+                            .is_some_and(|c| is_plain_safe(c, context)) =>
+                    {
+                        break;
+                    }
+                    Some(c) if is_plain_safe(c, context) => self.advance(spaces),
+                    _ => break,
+                }
+            } else if c == b':' {
+                if self.peek_byte().is_some_and(|c| is_plain_safe(c, context)) {
+                    self.advance(1);
+                } else {
+                    break;
+                }
+            } else if c == b'#' || is_plain_safe(c, context) {
+                // `#` is part of the scalar when it isn't preceded by a whitespace
                 self.advance_char_unchecked();
-            }
-            // Yes plain token can contain spaces
-            // For example:
-            // a bc: x yz
-            // Is a valid mapping
-            else if is_space(c) {
-                self.advance(1);
             } else {
                 break;
             }
         }
-        PLAIN_LITERAL
+    }
+
+    /// Plain scalars can span multiple lines when the following lines are indented enough, for
+    /// example:
+    /// ```yaml
+    /// key: this is
+    ///   a single scalar
+    /// ```
+    ///
+    /// Returns `true` if the scalar continues on the next line, in which case the lexer is moved
+    /// to the first character of that line.
+    fn consume_plain_line_continuation(&mut self, context: YamlLexContext) -> bool {
+        let min_indent = match context {
+            YamlLexContext::FlowOut { indent } => indent,
+            YamlLexContext::FlowIn | YamlLexContext::FlowKey => 0,
+            YamlLexContext::Regular | YamlLexContext::BlockKey => return false,
+        };
+
+        let bytes = self.source.as_bytes();
+        let mut position = self.position + self.count_spaces_from(self.position);
+        loop {
+            match bytes.get(position) {
+                Some(b'\r') if bytes.get(position + 1) == Some(&b'\n') => position += 2,
+                Some(b'\n' | b'\r') => position += 1,
+                _ => return false,
+            }
+            let indent = count_indentation(&bytes[position..]);
+            let spaces = self.count_spaces_from(position);
+            match bytes.get(position + spaces) {
+                // Empty lines are folded inside the scalar
+                Some(c) if is_break(*c) => position += spaces,
+                None => return false,
+                Some(c) => {
+                    let c = *c;
+                    if indent < min_indent
+                        || c == b'#'
+                        || !is_plain_safe(c, context)
+                        || (c == b':'
+                            && !bytes
+                                .get(position + spaces + 1)
+                                .is_some_and(|c| is_plain_safe(*c, context)))
+                        || (indent == 0 && is_document_marker(&bytes[position..]))
+                    {
+                        return false;
+                    }
+                    self.position = position + spaces;
+                    return true;
+                }
+            }
+        }
     }
 
     // https://yaml.org/spec/1.2.2/#rule-ns-plain-first
@@ -130,6 +318,112 @@ impl<'src> YamlLexer<'src> {
                 && self.peek_byte().is_some_and(|c| is_plain_safe(c, context)))
     }
 
+    /// Inside flow collections, a `:` directly following a JSON-like node is always a value
+    /// indicator, for example `{"a":b}`.
+    ///
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-flow-map-adjacent-value
+    fn is_after_json_node(&self, context: YamlLexContext) -> bool {
+        context.is_flow()
+            && self.position > 0
+            && matches!(
+                self.source.as_bytes()[self.position - 1],
+                b'"' | b'\'' | b']' | b'}'
+            )
+    }
+
+    // https://yaml.org/spec/1.2.2/#81-block-scalar-styles
+    fn consume_block_scalar(&mut self, current: u8, context: YamlLexContext) -> YamlSyntaxKind {
+        let kind = if current == b'|' {
+            LITERAL_BLOCK_LITERAL
+        } else {
+            FOLDED_BLOCK_LITERAL
+        };
+        let line_indent = self.current_line_indent();
+        self.advance(1);
+
+        // https://yaml.org/spec/1.2.2/#rule-c-b-block-header
+        let mut indentation_indicator = None;
+        let mut keep_trailing_lines = false;
+        for _ in 0..2 {
+            match self.current_byte() {
+                Some(c @ b'1'..=b'9') if indentation_indicator.is_none() => {
+                    indentation_indicator = Some(usize::from(c - b'0'));
+                }
+                Some(b'+') => keep_trailing_lines = true,
+                Some(b'-') => {}
+                _ => break,
+            }
+            self.advance(1);
+        }
+
+        let spaces = self.count_spaces_from(self.position);
+        match self.byte_at(spaces) {
+            Some(b'#') if spaces > 0 => {
+                self.advance(spaces);
+                self.consume_until_line_break();
+            }
+            Some(c) if is_break(c) => {}
+            None => return kind,
+            // Let the parser report the unexpected content of the header
+            Some(_) => return kind,
+        }
+
+        // The content must be more indented than the parent node
+        let min_indent = match context {
+            YamlLexContext::FlowOut { indent } => indent,
+            _ => line_indent + 1,
+        };
+        let mut content_indent =
+            indentation_indicator.map(|indicator| (min_indent + indicator).saturating_sub(1));
+
+        let bytes = self.source.as_bytes();
+        let mut position = self.position + spaces;
+        let mut end = self.position;
+        loop {
+            match bytes.get(position) {
+                Some(b'\r') if bytes.get(position + 1) == Some(&b'\n') => position += 2,
+                Some(b'\n' | b'\r') => position += 1,
+                _ => break,
+            }
+            let indent = count_indentation(&bytes[position..]);
+            match bytes.get(position + indent) {
+                // Empty lines don't end the scalar
+                Some(c) if is_break(*c) => {
+                    position += indent;
+                    if keep_trailing_lines {
+                        end = position;
+                    }
+                }
+                None => {
+                    if keep_trailing_lines && indent > 0 {
+                        end = position + indent;
+                    }
+                    break;
+                }
+                Some(_) => {
+                    let content_indent = *content_indent.get_or_insert(indent);
+                    if content_indent < min_indent
+                        || indent < content_indent
+                        || (indent == 0 && is_document_marker(&bytes[position..]))
+                    {
+                        break;
+                    }
+                    position += indent;
+                    while let Some(c) = bytes.get(position) {
+                        if is_break(*c) {
+                            break;
+                        }
+                        position += 1;
+                    }
+                    end = position;
+                }
+            }
+        }
+
+        self.position = end;
+        kind
+    }
+
     // https://yaml.org/spec/1.2.2/#731-double-quoted-style
     fn consume_double_quoted_literal(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'"');
@@ -138,18 +432,17 @@ impl<'src> YamlLexer<'src> {
         loop {
             match self.current_byte() {
                 Some(b'\\') => {
-                    if matches!(self.peek_byte(), Some(b'"')) {
-                        self.advance(2)
-                    } else {
-                        self.advance(1)
+                    self.advance(1);
+                    if self.current_byte().is_some() {
+                        self.advance_char_unchecked();
                     }
                 }
                 Some(b'"') => {
                     self.advance(1);
                     break DOUBLE_QUOTED_LITERAL;
                 }
-                Some(_) => self.advance(1),
-                None => break ERROR_TOKEN,
+                Some(_) => self.advance_char_unchecked(),
+                None => break self.unterminated_string(),
             }
         }
     }
@@ -169,12 +462,20 @@ impl<'src> YamlLexer<'src> {
                         break SINGLE_QUOTED_LITERAL;
                     }
                 }
-                Some(_) => self.advance(1),
-                None => break ERROR_TOKEN,
+                Some(_) => self.advance_char_unchecked(),
+                None => break self.unterminated_string(),
             }
         }
     }
 
+    fn unterminated_string(&mut self) -> YamlSyntaxKind {
+        self.diagnostics.push(ParseDiagnostic::new(
+            "Missing closing quote",
+            self.current_start..self.text_position(),
+        ));
+        ERROR_TOKEN
+    }
+
     fn consume_unexpected_token(&mut self) -> YamlSyntaxKind {
         self.assert_current_char_boundary();
 
@@ -188,8 +489,35 @@ impl<'src> YamlLexer<'src> {
 
         ERROR_TOKEN
     }
+
+    /// Returns the number of spaces and tabs starting at `position`
+    fn count_spaces_from(&self, position: usize) -> usize {
+        self.source.as_bytes()[position..]
+            .iter()
+            .take_while(|c| is_space(**c))
+            .count()
+    }
+
+    /// Returns `true` if the current position is at the start of a line
+    fn is_at_line_start_column(&self) -> bool {
+        self.position == self.unicode_bom_length
+            || self.position == 0
+            || is_break(self.source.as_bytes()[self.position - 1])
+    }
+
+    /// Returns the indentation of the line that contains the current position
+    fn current_line_indent(&self) -> usize {
+        let bytes = self.source.as_bytes();
+        let line_start = bytes[..self.position]
+            .iter()
+            .rposition(|c| is_break(*c))
+            .map_or(0, |index| index + 1);
+        count_indentation(&bytes[line_start..self.position])
+    }
 }
 
+const UNICODE_BOM_FIRST_BYTE: u8 = 0xef;
+
 impl<'src> Lexer<'src> for YamlLexer<'src> {
     const NEWLINE: Self::Kind = YamlSyntaxKind::NEWLINE;
     const WHITESPACE: Self::Kind = YamlSyntaxKind::WHITESPACE;
@@ -341,7 +669,8 @@ pub enum YamlLexContext {
     /// Inside block value, but outside flow context, for example:
     /// abc: [1, 2, 3]
     ///      ^^^^^^^^^
-    FlowOut,
+    /// `indent` is the minimal indentation of the lines of a multiline scalar.
+    FlowOut { indent: usize },
     /// Inside flow context, for example:
     /// abc: [1, 2, [4, 5]]
     ///       ^  ^  ^^^^^^
@@ -352,6 +681,12 @@ pub enum YamlLexContext {
     FlowKey,
 }
 
+impl YamlLexContext {
+    pub(crate) const fn is_flow(&self) -> bool {
+        matches!(self, Self::FlowIn | Self::FlowKey)
+    }
+}
+
 impl LexContext for YamlLexContext {
     fn is_regular(&self) -> bool {
         matches!(self, Self::Regular)
@@ -362,7 +697,7 @@ impl LexContext for YamlLexContext {
 fn is_plain_safe(c: u8, context: YamlLexContext) -> bool {
     use YamlLexContext::*;
     match context {
-        FlowOut | BlockKey => is_non_space_char(c),
+        FlowOut { .. } | BlockKey => is_non_space_char(c),
         FlowIn | FlowKey => is_non_space_char(c) && !is_flow_indicator(c),
         // Can happen when a YAML document starts with a plain token. This token will then be
         // used by the parser to determine whether the parser is already inside the document
@@ -372,6 +707,17 @@ fn is_plain_safe(c: u8, context: YamlLexContext) -> bool {
     }
 }
 
+/// Returns the number of spaces at the start of `line`. Tabs aren't allowed in the indentation.
+// https://yaml.org/spec/1.2.2/#rule-s-indent
+fn count_indentation(line: &[u8]) -> usize {
+    line.iter().take_while(|c| **c == b' ').count()
+}
+
+fn is_document_marker(line: &[u8]) -> bool {
+    (line.starts_with(b"---") || line.starts_with(b"..."))
+        && line.get(3).is_none_or(|c| is_space(*c) || is_break(*c))
+}
+
 // https://yaml.org/spec/1.2.2/#rule-ns-char
 fn is_non_space_char(c: u8) -> bool {
     !is_space(c) && !is_break(c)
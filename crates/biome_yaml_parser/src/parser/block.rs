@@ -1,9 +1,11 @@
 use biome_parser::{
-    CompletedMarker, Marker, Parser,
+    CompletedMarker, Parser,
+    diagnostic::ParseDiagnostic,
     parse_lists::ParseNodeList,
-    parse_recovery::ParseRecovery,
+    parse_recovery::{RecoveryError, RecoveryResult},
     prelude::ParsedSyntax::{self, *},
 };
+use biome_rowan::TextRange;
 use biome_yaml_syntax::{
     T,
     YamlSyntaxKind::{self, *},
@@ -13,229 +15,392 @@ use crate::lexer::YamlLexContext;
 
 use super::{
     YamlParser,
-    flow::{is_at_flow_yaml_node, parse_flow_yaml_node},
-    parse_error::expected_block_mapping,
+    flow::{
+        is_at_flow_node, is_at_properties, parse_flow_node, parse_flow_node_content,
+        parse_properties,
+    },
+    parse_error::{expected_block_mapping_entry, expected_block_sequence_entry},
 };
 
-pub(crate) fn parse_any_block_node(p: &mut YamlParser) -> ParsedSyntax {
-    if maybe_at_implicit_mapping_key(p) {
-        Present(parse_implicit_entry_or_flow_node(p))
-    } else if is_at_explicit_mapping_key(p) {
-        Present(parse_block_collection(p))
-    } else if is_at_block_scalar(p) {
-        Present(parse_block_scalar(p))
-    } else {
-        Absent
-    }
+/// The indentation constraints that a block node inherits from the collection containing it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BlockContext {
+    /// The indentation of the parent collection, `-1` for the top level node of a document
+    indent: isize,
+    /// Whether the node can be a collection starting on the same line as its parent entry:
+    /// ```yaml
+    /// - a: b
+    ///   c: d
+    /// ```
+    allow_compact: bool,
+    /// Whether the node can be a sequence that's indented at the same level as its parent mapping:
+    /// ```yaml
+    /// key:
+    /// - a
+    /// - b
+    /// ```
+    allow_same_indent_sequence: bool,
 }
 
-fn parse_block_scalar(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    match p.cur() {
-        LITERAL_BLOCK_LITERAL => {
-            parse_literal_scalar(p);
+impl BlockContext {
+    pub(crate) const DOCUMENT: Self = Self {
+        indent: -1,
+        allow_compact: false,
+        allow_same_indent_sequence: false,
+    };
+
+    /// The context of a sequence entry, or of an explicit mapping key or value
+    fn entry(indent: usize) -> Self {
+        Self {
+            indent: indent as isize,
+            allow_compact: true,
+            allow_same_indent_sequence: false,
+        }
+    }
+
+    /// The context of the value of an implicit mapping entry
+    fn implicit_value(indent: usize) -> Self {
+        Self {
+            indent: indent as isize,
+            allow_compact: false,
+            allow_same_indent_sequence: true,
         }
-        FOLDED_BLOCK_LITERAL => {
-            parse_folded_scalar(p);
+    }
+
+    /// Multiline scalars must be indented more than their parent collection
+    fn lex_context(self) -> YamlLexContext {
+        YamlLexContext::FlowOut {
+            indent: (self.indent + 1) as usize,
         }
-        _ => {}
     }
-    m.complete(p, YAML_BLOCK_SCALAR)
 }
 
-fn parse_literal_scalar(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    p.bump(LITERAL_BLOCK_LITERAL);
-    m.complete(p, YAML_LITERAL_SCALAR)
+// https://yaml.org/spec/1.2.2/#rule-s-l+block-node
+pub(crate) fn parse_block_node(p: &mut YamlParser, context: BlockContext) -> ParsedSyntax {
+    if p.at(EOF) || is_at_document_marker(p) {
+        return Absent;
+    }
+
+    if p.at_line_start() {
+        if is_at_block_collection(p, context) {
+            return Present(parse_block_collection(p));
+        }
+        // The node belongs to one of the ancestors
+        if p.cur_column() as isize <= context.indent {
+            return Absent;
+        }
+    } else if context.allow_compact {
+        if p.at(T![-]) {
+            return Present(parse_block_sequence(p, YAML_COMPACT_SEQUENCE));
+        }
+        if is_at_block_map_entry(p) {
+            return Present(parse_block_mapping(p, YAML_COMPACT_MAPPING));
+        }
+    }
+
+    if is_at_properties(p) {
+        Present(parse_block_node_with_properties(p, context))
+    } else if is_at_block_scalar(p) {
+        let m = p.start();
+        parse_block_scalar_content(p, context);
+        Present(m.complete(p, YAML_BLOCK_SCALAR))
+    } else if is_at_flow_node(p) {
+        let m = p.start();
+        parse_flow_node(p, context.lex_context()).ok();
+        Present(m.complete(p, YAML_FLOW_IN_BLOCK_NODE))
+    } else {
+        Absent
+    }
 }
 
-fn parse_folded_scalar(p: &mut YamlParser) -> CompletedMarker {
+/// Properties only belong to a block collection when the collection starts on the next line:
+/// ```yaml
+/// key: &anchor
+///   a: b
+/// ```
+/// Otherwise, they belong to the scalar or the flow node that follows them.
+fn parse_block_node_with_properties(p: &mut YamlParser, context: BlockContext) -> CompletedMarker {
     let m = p.start();
-    p.bump(FOLDED_BLOCK_LITERAL);
-    m.complete(p, YAML_FOLDED_SCALAR)
+    let properties = parse_properties(p);
+
+    if !p.at_line_start() && is_at_block_scalar(p) {
+        parse_block_scalar_content(p, context);
+        return m.complete(p, YAML_BLOCK_SCALAR);
+    }
+    if p.at_line_start() && is_at_block_collection(p, context) {
+        parse_block_collection_content(p);
+        return m.complete(p, YAML_BLOCK_COLLECTION);
+    }
+
+    let node = properties.precede(p);
+    let content = if !p.at_line_start() || p.cur_column() as isize > context.indent {
+        parse_flow_node_content(p, context.lex_context())
+    } else {
+        Absent
+    };
+    let kind = match content.kind(p) {
+        Some(YAML_PLAIN_SCALAR) | None => YAML_FLOW_YAML_NODE,
+        Some(_) => YAML_FLOW_JSON_NODE,
+    };
+    node.complete(p, kind);
+    m.complete(p, YAML_FLOW_IN_BLOCK_NODE)
 }
 
 fn parse_block_collection(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    parse_block_mapping(p);
+    parse_block_collection_content(p);
     m.complete(p, YAML_BLOCK_COLLECTION)
 }
 
-/// Since the block key context's only difference with flow context is that it disallows multiline
-/// flow node, we can just parse a flow node and emit a diagnostic when we encounter a key that
-/// violate the restriction.lexer
-fn parse_implicit_entry_or_flow_node(p: &mut YamlParser) -> CompletedMarker {
-    let implicit_key_marker = parse_block_map_implicit_key(p);
-    // It's indeed an implicit key
-    if p.at(T![:]) {
-        let implicit_entry_marker = implicit_key_marker.precede(p);
-        parse_block_map_implicit_value(p);
-        let implicit_entry_marker =
-            implicit_entry_marker.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY);
-        parse_block_collection_starting_with_implicit_entry(p, implicit_entry_marker)
-    }
-    // It's just a flow node
-    else {
-        let block_node_marker = implicit_key_marker.precede(p);
-        p.eat(NEWLINE);
-        block_node_marker.complete(p, YAML_FLOW_IN_BLOCK_NODE)
+fn parse_block_collection_content(p: &mut YamlParser) -> CompletedMarker {
+    if p.at(T![-]) {
+        parse_block_sequence(p, YAML_BLOCK_SEQUENCE)
+    } else {
+        parse_block_mapping(p, YAML_BLOCK_MAPPING)
     }
 }
 
-fn parse_block_collection_starting_with_implicit_entry(
-    p: &mut YamlParser,
-    implicit_entry_marker: CompletedMarker,
-) -> CompletedMarker {
-    let block_mapping_marker =
-        parse_block_mapping_starting_with_implicit_entry(p, implicit_entry_marker);
-    let block_collection_marker = block_mapping_marker.precede(p);
-    block_collection_marker.complete(p, YAML_BLOCK_COLLECTION)
-}
-
-fn parse_block_mapping(p: &mut YamlParser) -> CompletedMarker {
+fn parse_block_scalar_content(p: &mut YamlParser, context: BlockContext) -> CompletedMarker {
+    // The end of a block scalar depends on the indentation of its parent, which isn't known
+    // when the token is lexed for the first time
+    let kind = p.re_lex(context.lex_context());
     let m = p.start();
-    BlockMapEntryList::default().parse_list(p);
-    m.complete(p, YAML_BLOCK_MAPPING)
+    p.bump(kind);
+    match kind {
+        LITERAL_BLOCK_LITERAL => m.complete(p, YAML_LITERAL_SCALAR),
+        _ => m.complete(p, YAML_FOLDED_SCALAR),
+    }
 }
 
-fn parse_block_mapping_starting_with_implicit_entry(
-    p: &mut YamlParser,
-    implicit_entry_marker: CompletedMarker,
-) -> CompletedMarker {
-    let block_map_entry_list_marker = implicit_entry_marker.precede(p);
-    let block_map_entry_list_marker =
-        BlockMapEntryList::with(block_map_entry_list_marker).parse_list(p);
-    let block_map_marker = block_map_entry_list_marker.precede(p);
-    block_map_marker.complete(p, YAML_BLOCK_MAPPING)
+/// Parses a block sequence, or a compact sequence if `kind` is [YAML_COMPACT_SEQUENCE].
+///
+/// The entries of the sequence are aligned on the column of the first entry.
+fn parse_block_sequence(p: &mut YamlParser, kind: YamlSyntaxKind) -> CompletedMarker {
+    let m = p.start();
+    BlockSequenceEntryList::new(p.cur_column()).parse_list(p);
+    m.complete(p, kind)
 }
 
-#[derive(Default)]
-pub(crate) struct BlockMapEntryList {
-    start_marker: Option<Marker>,
+struct BlockSequenceEntryList {
+    indent: usize,
+    is_first: bool,
 }
 
-impl BlockMapEntryList {
-    fn with(start_marker: Marker) -> Self {
+impl BlockSequenceEntryList {
+    fn new(indent: usize) -> Self {
         Self {
-            start_marker: Some(start_marker),
+            indent,
+            is_first: true,
         }
     }
 }
 
-impl ParseNodeList for BlockMapEntryList {
+impl ParseNodeList for BlockSequenceEntryList {
     type Kind = YamlSyntaxKind;
     type Parser<'source> = YamlParser<'source>;
 
-    const LIST_KIND: Self::Kind = YAML_BLOCK_MAP_ENTRY_LIST;
-
-    fn start_list(&mut self, p: &mut Self::Parser<'_>) -> Marker {
-        self.start_marker.take().unwrap_or_else(|| p.start())
-    }
+    const LIST_KIND: Self::Kind = YAML_BLOCK_SEQUENCE_ENTRY_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        parse_block_map_entry(p)
+        let is_first = std::mem::take(&mut self.is_first);
+        if !p.at(T![-]) || (!is_first && !is_at_block_entry_start(p, self.indent)) {
+            return Absent;
+        }
+        let m = p.start();
+        p.bump(T![-]);
+        parse_block_node(p, BlockContext::entry(self.indent)).ok();
+        Present(m.complete(p, YAML_BLOCK_SEQUENCE_ENTRY))
     }
 
     fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(DEDENT)
-        // If this is the top level block mapping
-        || p.at(DOC_END)
+        is_at_block_list_end(p, self.indent)
+            || (p.at_line_start() && p.cur_column() == self.indent && !p.at(T![-]))
     }
 
     fn recover(
         &mut self,
         p: &mut Self::Parser<'_>,
         parsed_element: ParsedSyntax,
-    ) -> biome_parser::parse_recovery::RecoveryResult {
-        parsed_element.or_recover(p, &BlockMapEntryListParseRecovery, expected_block_mapping)
+    ) -> RecoveryResult {
+        recover_block_entry(
+            p,
+            parsed_element,
+            YAML_BOGUS,
+            self.indent,
+            expected_block_sequence_entry,
+        )
     }
 }
 
-struct BlockMapEntryListParseRecovery;
+/// Parses a block mapping, or a compact mapping if `kind` is [YAML_COMPACT_MAPPING].
+///
+/// The entries of the mapping are aligned on the column of the first entry.
+fn parse_block_mapping(p: &mut YamlParser, kind: YamlSyntaxKind) -> CompletedMarker {
+    let m = p.start();
+    BlockMapEntryList::new(p.cur_column()).parse_list(p);
+    m.complete(p, kind)
+}
+
+struct BlockMapEntryList {
+    indent: usize,
+    is_first: bool,
+}
+
+impl BlockMapEntryList {
+    fn new(indent: usize) -> Self {
+        Self {
+            indent,
+            is_first: true,
+        }
+    }
+}
 
-impl ParseRecovery for BlockMapEntryListParseRecovery {
+impl ParseNodeList for BlockMapEntryList {
     type Kind = YamlSyntaxKind;
     type Parser<'source> = YamlParser<'source>;
-    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_BLOCK_MAP_ENTRY;
 
-    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(DEDENT)
+    const LIST_KIND: Self::Kind = YAML_BLOCK_MAP_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        let is_first = std::mem::take(&mut self.is_first);
+        if !is_first && !is_at_block_entry_start(p, self.indent) {
+            return Absent;
+        }
+        parse_block_map_entry(p, self.indent)
     }
-}
 
-fn parse_block_map_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if is_at_explicit_mapping_key(p) {
-        parse_block_map_explicit_entry(p)
-    } else {
-        parse_block_map_implicit_entry(p)
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        is_at_block_list_end(p, self.indent)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        recover_block_entry(
+            p,
+            parsed_element,
+            YAML_BOGUS_BLOCK_MAP_ENTRY,
+            self.indent,
+            expected_block_mapping_entry,
+        )
     }
 }
 
-fn parse_block_map_explicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if !is_at_explicit_mapping_key(p) {
-        return Absent;
+// https://yaml.org/spec/1.2.2/#rule-ns-l-block-map-entry
+fn parse_block_map_entry(p: &mut YamlParser, indent: usize) -> ParsedSyntax {
+    if p.at(T![?]) {
+        Present(parse_block_map_explicit_entry(p, indent))
+    } else if p.at(T![:]) || is_at_implicit_block_key(p) {
+        Present(parse_block_map_implicit_entry(p, indent))
+    } else {
+        Absent
     }
-    let m = p.start();
-    parse_block_map_explicit_key(p);
-    // Value can be omitted in an explicit entry
-    parse_block_map_explicit_value(p).ok();
-    Present(m.complete(p, YAML_BLOCK_MAP_EXPLICIT_ENTRY))
 }
 
-fn parse_block_map_explicit_key(p: &mut YamlParser) -> CompletedMarker {
+fn parse_block_map_explicit_entry(p: &mut YamlParser, indent: usize) -> CompletedMarker {
     let m = p.start();
+
+    let key = p.start();
     p.bump(T![?]);
-    parse_block_indented(p).ok();
-    m.complete(p, YAML_BLOCK_MAP_EXPLICIT_KEY)
+    parse_block_node(p, BlockContext::entry(indent)).ok();
+    key.complete(p, YAML_BLOCK_MAP_EXPLICIT_KEY);
+
+    // The value indicator must be aligned with the key indicator
+    if p.at(T![:]) && is_at_block_entry_start(p, indent) {
+        let value = p.start();
+        p.bump(T![:]);
+        parse_block_node(p, BlockContext::entry(indent)).ok();
+        value.complete(p, YAML_BLOCK_MAP_EXPLICIT_VALUE);
+    }
+
+    m.complete(p, YAML_BLOCK_MAP_EXPLICIT_ENTRY)
 }
 
-fn parse_block_map_explicit_value(p: &mut YamlParser) -> ParsedSyntax {
+fn parse_block_map_implicit_entry(p: &mut YamlParser, indent: usize) -> CompletedMarker {
+    let m = p.start();
+    // The key can be omitted, e.g. `: value`
     if !p.at(T![:]) {
-        return Absent;
+        parse_flow_node(p, YamlLexContext::BlockKey).ok();
     }
-    let m = p.start();
-    p.bump(T![:]);
-    parse_block_indented(p).ok();
-    Present(m.complete(p, YAML_BLOCK_MAP_EXPLICIT_VALUE))
-}
 
-fn parse_block_indented(p: &mut YamlParser) -> ParsedSyntax {
-    parse_any_block_node(p)
+    let value = p.start();
+    p.expect(T![:]);
+    parse_block_node(p, BlockContext::implicit_value(indent)).ok();
+    value.complete(p, YAML_BLOCK_MAP_IMPLICIT_VALUE);
+
+    m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY)
 }
 
-fn parse_block_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if !is_at_flow_yaml_node(p) {
-        return Absent;
+fn recover_block_entry(
+    p: &mut YamlParser,
+    parsed_element: ParsedSyntax,
+    kind: YamlSyntaxKind,
+    indent: usize,
+    error: impl FnOnce(&YamlParser, TextRange) -> ParseDiagnostic,
+) -> RecoveryResult {
+    if let Present(m) = parsed_element {
+        return Ok(m);
+    }
+    if p.at(EOF) {
+        return Err(RecoveryError::Eof);
     }
+
+    p.error(error(p, p.cur_range()));
+    // The current token is always skipped because it may already be at the start of a line with
+    // a valid indentation, e.g. a sequence entry in a mapping.
     let m = p.start();
-    parse_block_map_implicit_key(p);
-    parse_block_map_implicit_value(p);
-    Present(m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY))
+    p.bump_any();
+    while !(p.at(EOF) || is_at_block_list_end(p, indent + 1)) {
+        p.bump_any();
+    }
+    Ok(m.complete(p, kind))
 }
 
-fn parse_block_map_implicit_key(p: &mut YamlParser) -> CompletedMarker {
-    parse_flow_yaml_node(p, YamlLexContext::BlockKey)
+/// Returns `true` if the current token starts a new line aligned with the other entries of the
+/// collection
+fn is_at_block_entry_start(p: &YamlParser, indent: usize) -> bool {
+    p.at_line_start() && p.cur_column() == indent
 }
 
-fn parse_block_map_implicit_value(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    p.bump(COLON);
-    // Value can be completely empty according to the spec
-    let value = parse_any_block_node(p);
-    if value.is_absent() {
-        p.eat(NEWLINE);
+fn is_at_block_list_end(p: &YamlParser, indent: usize) -> bool {
+    is_at_document_marker(p) || (p.at_line_start() && p.cur_column() < indent)
+}
+
+/// Returns `true` if a block collection starts on the current line and is indented enough to be
+/// a child of the current context.
+fn is_at_block_collection(p: &mut YamlParser, context: BlockContext) -> bool {
+    let column = p.cur_column() as isize;
+    if p.at(T![-]) {
+        column > context.indent || (context.allow_same_indent_sequence && column == context.indent)
+    } else {
+        column > context.indent && is_at_block_map_entry(p)
     }
-    m.complete(p, YAML_BLOCK_MAP_IMPLICIT_VALUE)
 }
 
-fn is_at_explicit_mapping_key(p: &mut YamlParser) -> bool {
-    p.at(T![?])
+fn is_at_block_map_entry(p: &mut YamlParser) -> bool {
+    p.at(T![?]) || p.at(T![:]) || is_at_implicit_block_key(p)
 }
 
-fn maybe_at_implicit_mapping_key(p: &YamlParser) -> bool {
-    is_at_flow_yaml_node(p)
+/// Returns `true` if the current node is followed by a `:` on the same line, making it the key
+/// of an implicit entry
+// https://yaml.org/spec/1.2.2/#rule-ns-s-block-map-implicit-key
+fn is_at_implicit_block_key(p: &mut YamlParser) -> bool {
+    if !is_at_flow_node(p) {
+        return false;
+    }
+    let checkpoint = p.checkpoint();
+    parse_flow_node(p, YamlLexContext::BlockKey).ok();
+    let is_key = p.at(T![:]) && !p.at_line_start();
+    p.rewind(checkpoint);
+    is_key
 }
 
 fn is_at_block_scalar(p: &YamlParser) -> bool {
     p.at(LITERAL_BLOCK_LITERAL) || p.at(FOLDED_BLOCK_LITERAL)
 }
+
+pub(crate) fn is_at_document_marker(p: &YamlParser) -> bool {
+    p.at(T![---]) || p.at(T![...])
+}
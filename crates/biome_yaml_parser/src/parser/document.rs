@@ -5,12 +5,17 @@ use biome_parser::{
     prelude::ParsedSyntax::{self, *},
     token_set,
 };
+use biome_rowan::TextRange;
 use biome_yaml_syntax::{
     T,
     YamlSyntaxKind::{self, *},
 };
 
-use super::{YamlParser, block::parse_any_block_node, parse_error::expected_directive};
+use super::{
+    YamlParser,
+    block::{BlockContext, is_at_document_marker, parse_block_node},
+    parse_error::{expected_directive, unexpected_document_content},
+};
 
 #[derive(Default)]
 pub(crate) struct DocumentList;
@@ -38,15 +43,36 @@ impl ParseNodeList for DocumentList {
     }
 }
 
+// https://yaml.org/spec/1.2.2/#91-documents
 fn parse_document(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
     p.eat(UNICODE_BOM);
-    p.eat(NEWLINE);
+    let has_directives = p.at(DIRECTIVE_LITERAL);
     DirectiveList.parse_list(p);
-    p.eat(T![---]);
-    parse_any_block_node(p).ok();
+    // Directives must be followed by the directives end marker
+    // https://yaml.org/spec/1.2.2/#rule-l-explicit-document
+    if has_directives {
+        p.expect(T![---]);
+    } else {
+        p.eat(T![---]);
+    }
+    parse_block_node(p, BlockContext::DOCUMENT).ok();
+
+    let kind = if p.at(EOF) || is_at_document_marker(p) {
+        YAML_DOCUMENT
+    } else {
+        let start = p.cur_range().start();
+        let mut end = p.cur_range().end();
+        while !(p.at(EOF) || is_at_document_marker(p)) {
+            end = p.cur_range().end();
+            p.bump_any();
+        }
+        p.error(unexpected_document_content(p, TextRange::new(start, end)));
+        YAML_BOGUS
+    };
+
     p.eat(T![...]);
-    m.complete(p, YAML_DOCUMENT)
+    m.complete(p, kind)
 }
 
 #[derive(Default)]
@@ -1,30 +1,275 @@
-use biome_parser::{CompletedMarker, Parser};
-use biome_yaml_syntax::YamlSyntaxKind::*;
+use biome_parser::{
+    CompletedMarker, Parser,
+    parse_lists::ParseSeparatedList,
+    parse_recovery::{ParseRecoveryTokenSet, RecoveryResult},
+    prelude::ParsedSyntax::{self, *},
+    token_set,
+};
+use biome_yaml_syntax::{
+    T,
+    YamlSyntaxKind::{self, *},
+};
 
 use crate::lexer::YamlLexContext;
 
-use super::YamlParser;
+use super::{
+    YamlParser,
+    parse_error::{expected_flow_mapping_entry, expected_flow_sequence_entry},
+};
+
+// https://yaml.org/spec/1.2.2/#rule-ns-flow-node
+pub(crate) fn parse_flow_node(p: &mut YamlParser, context: YamlLexContext) -> ParsedSyntax {
+    if p.at(ALIAS_LITERAL) {
+        return Present(parse_alias_node(p));
+    }
+    if !is_at_flow_node(p) {
+        return Absent;
+    }
 
-// TODO: parse node properties
-pub(crate) fn parse_flow_yaml_node(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
     let m = p.start();
-    parse_plain_scalar(p, context);
-    m.complete(p, YAML_FLOW_YAML_NODE)
+    let properties = parse_properties_if_present(p);
+    // Outside of flow collections, a node on the next line isn't the content of the properties
+    // but a child of the enclosing block collection
+    let content = if properties.is_present() && p.at_line_start() && !context.is_flow() {
+        Absent
+    } else {
+        parse_flow_node_content(p, context)
+    };
+    let kind = match content.kind(p) {
+        Some(YAML_PLAIN_SCALAR) | None => YAML_FLOW_YAML_NODE,
+        Some(_) => YAML_FLOW_JSON_NODE,
+    };
+    Present(m.complete(p, kind))
+}
+
+/// Parses the content of a flow node, either a plain scalar or a JSON-like content
+pub(crate) fn parse_flow_node_content(p: &mut YamlParser, context: YamlLexContext) -> ParsedSyntax {
+    match p.cur() {
+        T!['['] => Present(parse_flow_sequence(p)),
+        T!['{'] => Present(parse_flow_mapping(p)),
+        DOUBLE_QUOTED_LITERAL => {
+            let m = p.start();
+            p.bump(DOUBLE_QUOTED_LITERAL);
+            Present(m.complete(p, YAML_DOUBLE_QUOTED_SCALAR))
+        }
+        SINGLE_QUOTED_LITERAL => {
+            let m = p.start();
+            p.bump(SINGLE_QUOTED_LITERAL);
+            Present(m.complete(p, YAML_SINGLE_QUOTED_SCALAR))
+        }
+        PLAIN_LITERAL => parse_plain_scalar(p, context),
+        _ => Absent,
+    }
 }
 
-fn parse_plain_scalar(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
+fn parse_plain_scalar(p: &mut YamlParser, context: YamlLexContext) -> ParsedSyntax {
     // The current plain token was lexed during the last `bump` or `bump_with_context` call, which
     // might have used a different context than the current one
-    p.re_lex(context);
+    if p.re_lex(context) != PLAIN_LITERAL {
+        return Absent;
+    }
     let m = p.start();
     p.bump(PLAIN_LITERAL);
-    m.complete(p, YAML_PLAIN_SCALAR)
+    Present(m.complete(p, YAML_PLAIN_SCALAR))
+}
+
+fn parse_alias_node(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(ALIAS_LITERAL);
+    m.complete(p, YAML_ALIAS_NODE)
+}
+
+// https://yaml.org/spec/1.2.2/#rule-c-ns-properties
+pub(crate) fn parse_properties(p: &mut YamlParser) -> CompletedMarker {
+    debug_assert!(is_at_properties(p));
+    let m = p.start();
+    if p.at(ANCHOR_PROPERTY_LITERAL) {
+        parse_anchor_property(p);
+        parse_tag_property(p).ok();
+        m.complete(p, YAML_PROPERTIES_ANCHOR_FIRST)
+    } else {
+        parse_tag_property(p).ok();
+        if p.at(ANCHOR_PROPERTY_LITERAL) {
+            parse_anchor_property(p);
+        }
+        m.complete(p, YAML_PROPERTIES_TAG_FIRST)
+    }
+}
+
+fn parse_properties_if_present(p: &mut YamlParser) -> ParsedSyntax {
+    if is_at_properties(p) {
+        Present(parse_properties(p))
+    } else {
+        Absent
+    }
+}
+
+fn parse_anchor_property(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(ANCHOR_PROPERTY_LITERAL);
+    m.complete(p, YAML_ANCHOR_PROPERTY)
+}
+
+fn parse_tag_property(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(TAG_PROPERTY_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump(TAG_PROPERTY_LITERAL);
+    Present(m.complete(p, YAML_TAG_PROPERTY))
+}
+
+// https://yaml.org/spec/1.2.2/#rule-c-flow-sequence
+fn parse_flow_sequence(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['[']);
+    FlowSequenceEntryList.parse_list(p);
+    p.expect(T![']']);
+    m.complete(p, YAML_FLOW_SEQUENCE)
+}
+
+struct FlowSequenceEntryList;
+
+impl ParseSeparatedList for FlowSequenceEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_SEQUENCE_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if p.at(T![?]) {
+            return Present(parse_flow_map_explicit_entry(p));
+        }
+        let node = parse_flow_node(p, YamlLexContext::FlowIn);
+        // A single pair mapping, e.g. `[a: b]`
+        // https://yaml.org/spec/1.2.2/#rule-ns-flow-pair
+        if p.at(T![:]) {
+            let m = node.precede(p);
+            parse_flow_map_implicit_value(p);
+            Present(m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY))
+        } else {
+            node
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![']'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                YAML_BOGUS_FLOW_NODE,
+                token_set![T![,], T![']'], T!['}'], T![---], T![...]],
+            ),
+            expected_flow_sequence_entry,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+// https://yaml.org/spec/1.2.2/#rule-c-flow-mapping
+fn parse_flow_mapping(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['{']);
+    FlowMapEntryList.parse_list(p);
+    p.expect(T!['}']);
+    m.complete(p, YAML_FLOW_MAPPING)
+}
+
+struct FlowMapEntryList;
+
+impl ParseSeparatedList for FlowMapEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_MAP_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if p.at(T![?]) {
+            Present(parse_flow_map_explicit_entry(p))
+        } else {
+            parse_flow_map_implicit_entry(p)
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T!['}'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                YAML_BOGUS,
+                token_set![T![,], T![']'], T!['}'], T![---], T![...]],
+            ),
+            expected_flow_mapping_entry,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+// https://yaml.org/spec/1.2.2/#rule-ns-flow-map-explicit-entry
+fn parse_flow_map_explicit_entry(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![?]);
+    parse_flow_map_implicit_entry(p).ok();
+    m.complete(p, YAML_FLOW_MAP_EXPLICIT_ENTRY)
+}
+
+// https://yaml.org/spec/1.2.2/#rule-ns-flow-map-implicit-entry
+fn parse_flow_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T![:]) && !is_at_flow_node(p) {
+        return Absent;
+    }
+    let m = p.start();
+    parse_flow_node(p, YamlLexContext::FlowKey).ok();
+    if p.at(T![:]) {
+        parse_flow_map_implicit_value(p);
+    }
+    Present(m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY))
+}
+
+fn parse_flow_map_implicit_value(p: &mut YamlParser) {
+    p.bump(T![:]);
+    parse_flow_node(p, YamlLexContext::FlowIn).ok();
+}
+
+pub(crate) fn is_at_flow_node(p: &YamlParser) -> bool {
+    p.at(ALIAS_LITERAL) || is_at_properties(p) || is_at_flow_content(p)
 }
 
-pub(crate) fn is_at_flow_yaml_node(p: &YamlParser) -> bool {
-    is_at_plain_scalar(p)
+fn is_at_flow_content(p: &YamlParser) -> bool {
+    matches!(
+        p.cur(),
+        T!['['] | T!['{'] | DOUBLE_QUOTED_LITERAL | SINGLE_QUOTED_LITERAL | PLAIN_LITERAL
+    )
 }
 
-fn is_at_plain_scalar(p: &YamlParser) -> bool {
-    p.at(PLAIN_LITERAL)
+pub(crate) fn is_at_properties(p: &YamlParser) -> bool {
+    p.at(ANCHOR_PROPERTY_LITERAL) || p.at(TAG_PROPERTY_LITERAL)
 }
@@ -37,6 +37,26 @@ impl<'source> YamlParser<'source> {
         self.source_mut().re_lex(context)
     }
 
+    /// Returns `true` if the current token is the first token of its line, ignoring the leading
+    /// indentation
+    pub fn at_line_start(&self) -> bool {
+        let start = usize::from(self.cur_range().start());
+        let before = self.source.text()[..start].trim_end_matches([' ', '\t']);
+        before.is_empty() || before == "\u{feff}" || before.ends_with(['\n', '\r'])
+    }
+
+    /// Returns the column of the current token, in bytes from the start of its line
+    pub fn cur_column(&self) -> usize {
+        let text = self.source.text();
+        let start = usize::from(self.cur_range().start());
+        let line_start = match text[..start].rfind(['\n', '\r']) {
+            Some(index) => index + 1,
+            None if text.starts_with('\u{feff}') => '\u{feff}'.len_utf8(),
+            None => 0,
+        };
+        start.saturating_sub(line_start)
+    }
+
     pub fn finish(
         self,
     ) -> (
@@ -52,7 +72,6 @@ impl<'source> YamlParser<'source> {
         (events, diagnostics, trivia)
     }
 
-    #[expect(dead_code)]
     pub fn checkpoint(&self) -> YamlParserCheckpoint {
         YamlParserCheckpoint {
             context: self.context.checkpoint(),
@@ -63,7 +82,6 @@ impl<'source> YamlParser<'source> {
         }
     }
 
-    #[expect(dead_code)]
     pub fn rewind(&mut self, checkpoint: YamlParserCheckpoint) {
         let YamlParserCheckpoint { context, source } = checkpoint;
 
@@ -1,11 +1,32 @@
 use crate::parser::YamlParser;
 use biome_parser::diagnostic::{ParseDiagnostic, expected_node};
+use biome_parser::prelude::*;
 use biome_rowan::TextRange;
 
-pub(crate) fn expected_block_mapping(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
-    expected_node("mapping", range, p)
+pub(crate) fn expected_block_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("mapping entry", range, p)
+}
+
+pub(crate) fn expected_block_sequence_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("sequence entry", range, p)
+}
+
+pub(crate) fn expected_flow_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("flow mapping entry", range, p)
+}
+
+pub(crate) fn expected_flow_sequence_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("flow sequence entry", range, p)
 }
 
 pub(crate) fn expected_directive(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
     expected_node("directive", range, p)
 }
+
+pub(crate) fn unexpected_document_content(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "Unexpected content after the end of the document node.",
+        range,
+    )
+    .with_hint("The content of the document may be indented inconsistently.")
+}
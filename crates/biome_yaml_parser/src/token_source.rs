@@ -2,6 +2,7 @@ use biome_parser::lexer::BufferedLexer;
 use biome_parser::prelude::{BumpWithContext, ParseDiagnostic, TokenSource};
 use biome_parser::token_source::{TokenSourceCheckpoint, TokenSourceWithBufferedLexer, Trivia};
 use biome_rowan::TriviaPieceKind;
+use biome_yaml_syntax::T;
 use biome_yaml_syntax::YamlSyntaxKind::{self, EOF};
 
 use crate::lexer::{YamlLexContext, YamlLexer};
@@ -9,15 +10,26 @@ use crate::lexer::{YamlLexContext, YamlLexer};
 pub(crate) struct YamlTokenSource<'source> {
     lexer: BufferedLexer<YamlSyntaxKind, YamlLexer<'source>>,
     trivia_list: Vec<Trivia>,
+    /// Whether the current token is preceded by a line break
+    preceding_line_break: bool,
+    /// The number of flow collections that contain the current token. Tokens are lexed in the
+    /// flow context when it's greater than zero.
+    flow_depth: usize,
 }
 
-pub(crate) type YamlTokenSourceCheckpoint = TokenSourceCheckpoint<YamlSyntaxKind>;
+pub(crate) struct YamlTokenSourceCheckpoint {
+    inner: TokenSourceCheckpoint<YamlSyntaxKind>,
+    preceding_line_break: bool,
+    flow_depth: usize,
+}
 
 impl<'source> YamlTokenSource<'source> {
     pub(crate) fn new(lexer: BufferedLexer<YamlSyntaxKind, YamlLexer<'source>>) -> Self {
         Self {
             lexer,
             trivia_list: Vec::new(),
+            preceding_line_break: false,
+            flow_depth: 0,
         }
     }
 
@@ -26,12 +38,31 @@ impl<'source> YamlTokenSource<'source> {
         let lexer = BufferedLexer::new(lexer);
 
         let mut source = YamlTokenSource::new(lexer);
-        source.next_non_trivia_token(YamlLexContext::Regular, true);
+        source.next_non_trivia_token(YamlLexContext::BlockKey, true);
         source
     }
 
+    /// The context used to lex the next token, unless the parser requests another one
+    fn default_context(&self) -> YamlLexContext {
+        if self.flow_depth > 0 {
+            YamlLexContext::FlowIn
+        } else {
+            YamlLexContext::BlockKey
+        }
+    }
+
+    /// Tracks whether the token that's about to be bumped opens or closes a flow collection
+    fn update_flow_depth(&mut self) {
+        match self.current() {
+            T!['['] | T!['{'] => self.flow_depth += 1,
+            T![']'] | T!['}'] => self.flow_depth = self.flow_depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
     fn next_non_trivia_token(&mut self, context: YamlLexContext, first_token: bool) {
         let mut trailing = !first_token;
+        self.preceding_line_break = first_token;
 
         loop {
             let kind = self.lexer.next_token(context);
@@ -46,6 +77,7 @@ impl<'source> YamlTokenSource<'source> {
                 Ok(trivia_kind) => {
                     if trivia_kind.is_newline() {
                         trailing = false;
+                        self.preceding_line_break = true;
                     }
 
                     self.trivia_list
@@ -62,16 +94,27 @@ impl<'source> YamlTokenSource<'source> {
     /// Creates a checkpoint to which it can later return using [Self::rewind].
     pub fn checkpoint(&self) -> YamlTokenSourceCheckpoint {
         YamlTokenSourceCheckpoint {
-            trivia_len: self.trivia_list.len() as u32,
-            lexer_checkpoint: self.lexer.checkpoint(),
+            inner: TokenSourceCheckpoint {
+                trivia_len: self.trivia_list.len() as u32,
+                lexer_checkpoint: self.lexer.checkpoint(),
+            },
+            preceding_line_break: self.preceding_line_break,
+            flow_depth: self.flow_depth,
         }
     }
 
     /// Restores the token source to a previous state
     pub fn rewind(&mut self, checkpoint: YamlTokenSourceCheckpoint) {
-        assert!(self.trivia_list.len() >= checkpoint.trivia_len as usize);
-        self.trivia_list.truncate(checkpoint.trivia_len as usize);
-        self.lexer.rewind(checkpoint.lexer_checkpoint);
+        let YamlTokenSourceCheckpoint {
+            inner,
+            preceding_line_break,
+            flow_depth,
+        } = checkpoint;
+        assert!(self.trivia_list.len() >= inner.trivia_len as usize);
+        self.trivia_list.truncate(inner.trivia_len as usize);
+        self.lexer.rewind(inner.lexer_checkpoint);
+        self.preceding_line_break = preceding_line_break;
+        self.flow_depth = flow_depth;
     }
 }
 
@@ -91,11 +134,14 @@ impl TokenSource for YamlTokenSource<'_> {
     }
 
     fn has_preceding_line_break(&self) -> bool {
-        self.lexer.has_preceding_line_break()
+        self.preceding_line_break
     }
 
     fn bump(&mut self) {
-        self.bump_with_context(YamlLexContext::default());
+        if self.current() != EOF {
+            self.update_flow_depth();
+            self.next_non_trivia_token(self.default_context(), false);
+        }
     }
 
     fn skip_as_trivia(&mut self) {
@@ -106,7 +152,8 @@ impl TokenSource for YamlTokenSource<'_> {
                 false,
             ));
 
-            self.next_non_trivia_token(YamlLexContext::Regular, false)
+            self.update_flow_depth();
+            self.next_non_trivia_token(self.default_context(), false)
         }
     }
 
@@ -126,6 +173,7 @@ impl BumpWithContext for YamlTokenSource<'_> {
 
     fn bump_with_context(&mut self, context: Self::Context) {
         if self.current() != EOF {
+            self.update_flow_depth();
             self.next_non_trivia_token(context, false);
         }
     }
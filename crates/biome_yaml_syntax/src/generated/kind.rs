@@ -36,8 +36,6 @@ pub enum YamlSyntaxKind {
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
-    INDENT,
-    DEDENT,
     COMMENT,
     YAML_ROOT,
     YAML_DOCUMENT_LIST,
@@ -66,7 +64,6 @@ pub enum YamlSyntaxKind {
     YAML_BLOCK_MAP_EXPLICIT_VALUE,
     YAML_BLOCK_MAP_IMPLICIT_ENTRY,
     YAML_BLOCK_MAP_IMPLICIT_VALUE,
-    YAML_INDENTED_BLOCK,
     YAML_COMPACT_MAPPING,
     YAML_COMPACT_SEQUENCE,
     YAML_SINGLE_QUOTED_SCALAR,
@@ -81,6 +78,7 @@ pub enum YamlSyntaxKind {
     YAML_BOGUS,
     YAML_BOGUS_BLOCK_NODE,
     YAML_BOGUS_BLOCK_MAP_ENTRY,
+    YAML_BOGUS_FLOW_NODE,
     #[doc(hidden)]
     __LAST,
 }
@@ -307,7 +307,6 @@ impl YamlBlockMapImplicitValue {
         YamlBlockMapImplicitValueFields {
             colon_token: self.colon_token(),
             value: self.value(),
-            newline_token: self.newline_token(),
         }
     }
     pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
@@ -316,9 +315,6 @@ impl YamlBlockMapImplicitValue {
     pub fn value(&self) -> Option<AnyYamlBlockNode> {
         support::node(&self.syntax, 1usize)
     }
-    pub fn newline_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 2usize)
-    }
 }
 impl Serialize for YamlBlockMapImplicitValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -332,7 +328,6 @@ impl Serialize for YamlBlockMapImplicitValue {
 pub struct YamlBlockMapImplicitValueFields {
     pub colon_token: SyntaxResult<SyntaxToken>,
     pub value: Option<AnyYamlBlockNode>,
-    pub newline_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockMapping {
@@ -350,19 +345,11 @@ impl YamlBlockMapping {
     }
     pub fn as_fields(&self) -> YamlBlockMappingFields {
         YamlBlockMappingFields {
-            indent_token: self.indent_token(),
             entries: self.entries(),
-            dedent_token: self.dedent_token(),
         }
     }
-    pub fn indent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 0usize)
-    }
     pub fn entries(&self) -> YamlBlockMapEntryList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn dedent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 2usize)
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for YamlBlockMapping {
@@ -375,9 +362,7 @@ impl Serialize for YamlBlockMapping {
 }
 #[derive(Serialize)]
 pub struct YamlBlockMappingFields {
-    pub indent_token: Option<SyntaxToken>,
     pub entries: YamlBlockMapEntryList,
-    pub dedent_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockScalar {
@@ -435,19 +420,11 @@ impl YamlBlockSequence {
     }
     pub fn as_fields(&self) -> YamlBlockSequenceFields {
         YamlBlockSequenceFields {
-            indent_token: self.indent_token(),
             entries: self.entries(),
-            dedent_token: self.dedent_token(),
         }
     }
-    pub fn indent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 0usize)
-    }
     pub fn entries(&self) -> YamlBlockSequenceEntryList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn dedent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 2usize)
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for YamlBlockSequence {
@@ -460,9 +437,7 @@ impl Serialize for YamlBlockSequence {
 }
 #[derive(Serialize)]
 pub struct YamlBlockSequenceFields {
-    pub indent_token: Option<SyntaxToken>,
     pub entries: YamlBlockSequenceEntryList,
-    pub dedent_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockSequenceEntry {
@@ -523,7 +498,7 @@ impl YamlCompactMapping {
             entries: self.entries(),
         }
     }
-    pub fn entries(&self) -> YamlBlockSequenceEntryList {
+    pub fn entries(&self) -> YamlBlockMapEntryList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -537,7 +512,7 @@ impl Serialize for YamlCompactMapping {
 }
 #[derive(Serialize)]
 pub struct YamlCompactMappingFields {
-    pub entries: YamlBlockSequenceEntryList,
+    pub entries: YamlBlockMapEntryList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlCompactSequence {
@@ -714,17 +689,11 @@ impl YamlFlowInBlockNode {
         Self { syntax }
     }
     pub fn as_fields(&self) -> YamlFlowInBlockNodeFields {
-        YamlFlowInBlockNodeFields {
-            flow: self.flow(),
-            newline_token: self.newline_token(),
-        }
+        YamlFlowInBlockNodeFields { flow: self.flow() }
     }
     pub fn flow(&self) -> SyntaxResult<AnyYamlFlowNode> {
         support::required_node(&self.syntax, 0usize)
     }
-    pub fn newline_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 1usize)
-    }
 }
 impl Serialize for YamlFlowInBlockNode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -737,7 +706,6 @@ impl Serialize for YamlFlowInBlockNode {
 #[derive(Serialize)]
 pub struct YamlFlowInBlockNodeFields {
     pub flow: SyntaxResult<AnyYamlFlowNode>,
-    pub newline_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlFlowJsonNode {
@@ -843,11 +811,11 @@ impl YamlFlowMapImplicitEntry {
     pub fn key(&self) -> Option<AnyYamlFlowMapImplicitKey> {
         support::node(&self.syntax, 0usize)
     }
-    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+    pub fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
     }
-    pub fn value(&self) -> SyntaxResult<AnyYamlFlowNode> {
-        support::required_node(&self.syntax, 2usize)
+    pub fn value(&self) -> Option<AnyYamlFlowNode> {
+        support::node(&self.syntax, 2usize)
     }
 }
 impl Serialize for YamlFlowMapImplicitEntry {
@@ -861,8 +829,8 @@ impl Serialize for YamlFlowMapImplicitEntry {
 #[derive(Serialize)]
 pub struct YamlFlowMapImplicitEntryFields {
     pub key: Option<AnyYamlFlowMapImplicitKey>,
-    pub colon_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<AnyYamlFlowNode>,
+    pub colon_token: Option<SyntaxToken>,
+    pub value: Option<AnyYamlFlowNode>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlFlowMapping {
@@ -1381,10 +1349,17 @@ impl AnyYamlBlockMapEntry {
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlBlockMapImplicitKey {
+    YamlAliasNode(YamlAliasNode),
     YamlFlowJsonNode(YamlFlowJsonNode),
     YamlFlowYamlNode(YamlFlowYamlNode),
 }
 impl AnyYamlBlockMapImplicitKey {
+    pub fn as_yaml_alias_node(&self) -> Option<&YamlAliasNode> {
+        match &self {
+            Self::YamlAliasNode(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_yaml_flow_json_node(&self) -> Option<&YamlFlowJsonNode> {
         match &self {
             Self::YamlFlowJsonNode(item) => Some(item),
@@ -1444,6 +1419,44 @@ impl AnyYamlBlockScalarContent {
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyYamlBlockSequenceEntry {
+    YamlBlockSequenceEntry(YamlBlockSequenceEntry),
+    YamlBogus(YamlBogus),
+}
+impl AnyYamlBlockSequenceEntry {
+    pub fn as_yaml_block_sequence_entry(&self) -> Option<&YamlBlockSequenceEntry> {
+        match &self {
+            Self::YamlBlockSequenceEntry(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_bogus(&self) -> Option<&YamlBogus> {
+        match &self {
+            Self::YamlBogus(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyYamlDocument {
+    YamlBogus(YamlBogus),
+    YamlDocument(YamlDocument),
+}
+impl AnyYamlDocument {
+    pub fn as_yaml_bogus(&self) -> Option<&YamlBogus> {
+        match &self {
+            Self::YamlBogus(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_document(&self) -> Option<&YamlDocument> {
+        match &self {
+            Self::YamlDocument(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlFlowMapEntry {
     YamlFlowMapExplicitEntry(YamlFlowMapExplicitEntry),
     YamlFlowMapImplicitEntry(YamlFlowMapImplicitEntry),
@@ -1464,10 +1477,17 @@ impl AnyYamlFlowMapEntry {
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlFlowMapImplicitKey {
+    YamlAliasNode(YamlAliasNode),
     YamlFlowJsonNode(YamlFlowJsonNode),
     YamlFlowYamlNode(YamlFlowYamlNode),
 }
 impl AnyYamlFlowMapImplicitKey {
+    pub fn as_yaml_alias_node(&self) -> Option<&YamlAliasNode> {
+        match &self {
+            Self::YamlAliasNode(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_yaml_flow_json_node(&self) -> Option<&YamlFlowJsonNode> {
         match &self {
             Self::YamlFlowJsonNode(item) => Some(item),
@@ -1484,6 +1504,7 @@ impl AnyYamlFlowMapImplicitKey {
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlFlowNode {
     YamlAliasNode(YamlAliasNode),
+    YamlBogusFlowNode(YamlBogusFlowNode),
     YamlFlowJsonNode(YamlFlowJsonNode),
     YamlFlowYamlNode(YamlFlowYamlNode),
 }
@@ -1494,6 +1515,12 @@ impl AnyYamlFlowNode {
             _ => None,
         }
     }
+    pub fn as_yaml_bogus_flow_node(&self) -> Option<&YamlBogusFlowNode> {
+        match &self {
+            Self::YamlBogusFlowNode(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_yaml_flow_json_node(&self) -> Option<&YamlFlowJsonNode> {
         match &self {
             Self::YamlFlowJsonNode(item) => Some(item),
@@ -1960,10 +1987,6 @@ impl std::fmt::Debug for YamlBlockMapImplicitValue {
                     &support::DebugSyntaxResult(self.colon_token()),
                 )
                 .field("value", &support::DebugOptionalElement(self.value()))
-                .field(
-                    "newline_token",
-                    &support::DebugOptionalElement(self.newline_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlBlockMapImplicitValue").finish()
@@ -2010,15 +2033,7 @@ impl std::fmt::Debug for YamlBlockMapping {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("YamlBlockMapping")
-                .field(
-                    "indent_token",
-                    &support::DebugOptionalElement(self.indent_token()),
-                )
                 .field("entries", &self.entries())
-                .field(
-                    "dedent_token",
-                    &support::DebugOptionalElement(self.dedent_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlBlockMapping").finish()
@@ -2116,15 +2131,7 @@ impl std::fmt::Debug for YamlBlockSequence {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("YamlBlockSequence")
-                .field(
-                    "indent_token",
-                    &support::DebugOptionalElement(self.indent_token()),
-                )
                 .field("entries", &self.entries())
-                .field(
-                    "dedent_token",
-                    &support::DebugOptionalElement(self.dedent_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlBlockSequence").finish()
@@ -2477,10 +2484,6 @@ impl std::fmt::Debug for YamlFlowInBlockNode {
             DEPTH.set(current_depth + 1);
             f.debug_struct("YamlFlowInBlockNode")
                 .field("flow", &support::DebugSyntaxResult(self.flow()))
-                .field(
-                    "newline_token",
-                    &support::DebugOptionalElement(self.newline_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlFlowInBlockNode").finish()
@@ -2632,9 +2635,9 @@ impl std::fmt::Debug for YamlFlowMapImplicitEntry {
                 .field("key", &support::DebugOptionalElement(self.key()))
                 .field(
                     "colon_token",
-                    &support::DebugSyntaxResult(self.colon_token()),
+                    &support::DebugOptionalElement(self.colon_token()),
                 )
-                .field("value", &support::DebugSyntaxResult(self.value()))
+                .field("value", &support::DebugOptionalElement(self.value()))
                 .finish()
         } else {
             f.debug_struct("YamlFlowMapImplicitEntry").finish()
@@ -3484,6 +3487,11 @@ impl From<AnyYamlBlockMapEntry> for SyntaxElement {
         node.into()
     }
 }
+impl From<YamlAliasNode> for AnyYamlBlockMapImplicitKey {
+    fn from(node: YamlAliasNode) -> Self {
+        Self::YamlAliasNode(node)
+    }
+}
 impl From<YamlFlowJsonNode> for AnyYamlBlockMapImplicitKey {
     fn from(node: YamlFlowJsonNode) -> Self {
         Self::YamlFlowJsonNode(node)
@@ -3496,13 +3504,18 @@ impl From<YamlFlowYamlNode> for AnyYamlBlockMapImplicitKey {
 }
 impl AstNode for AnyYamlBlockMapImplicitKey {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        YamlFlowJsonNode::KIND_SET.union(YamlFlowYamlNode::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = YamlAliasNode::KIND_SET
+        .union(YamlFlowJsonNode::KIND_SET)
+        .union(YamlFlowYamlNode::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE)
+        matches!(
+            kind,
+            YAML_ALIAS_NODE | YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
+            YAML_ALIAS_NODE => Self::YamlAliasNode(YamlAliasNode { syntax }),
             YAML_FLOW_JSON_NODE => Self::YamlFlowJsonNode(YamlFlowJsonNode { syntax }),
             YAML_FLOW_YAML_NODE => Self::YamlFlowYamlNode(YamlFlowYamlNode { syntax }),
             _ => return None,
@@ -3511,12 +3524,14 @@ impl AstNode for AnyYamlBlockMapImplicitKey {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => &it.syntax,
             Self::YamlFlowJsonNode(it) => &it.syntax,
             Self::YamlFlowYamlNode(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => it.syntax,
             Self::YamlFlowJsonNode(it) => it.syntax,
             Self::YamlFlowYamlNode(it) => it.syntax,
         }
@@ -3525,6 +3540,7 @@ impl AstNode for AnyYamlBlockMapImplicitKey {
 impl std::fmt::Debug for AnyYamlBlockMapImplicitKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::YamlAliasNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowJsonNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowYamlNode(it) => std::fmt::Debug::fmt(it, f),
         }
@@ -3533,6 +3549,7 @@ impl std::fmt::Debug for AnyYamlBlockMapImplicitKey {
 impl From<AnyYamlBlockMapImplicitKey> for SyntaxNode {
     fn from(n: AnyYamlBlockMapImplicitKey) -> Self {
         match n {
+            AnyYamlBlockMapImplicitKey::YamlAliasNode(it) => it.into(),
             AnyYamlBlockMapImplicitKey::YamlFlowJsonNode(it) => it.into(),
             AnyYamlBlockMapImplicitKey::YamlFlowYamlNode(it) => it.into(),
         }
@@ -3678,6 +3695,127 @@ impl From<AnyYamlBlockScalarContent> for SyntaxElement {
         node.into()
     }
 }
+impl From<YamlBlockSequenceEntry> for AnyYamlBlockSequenceEntry {
+    fn from(node: YamlBlockSequenceEntry) -> Self {
+        Self::YamlBlockSequenceEntry(node)
+    }
+}
+impl From<YamlBogus> for AnyYamlBlockSequenceEntry {
+    fn from(node: YamlBogus) -> Self {
+        Self::YamlBogus(node)
+    }
+}
+impl AstNode for AnyYamlBlockSequenceEntry {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        YamlBlockSequenceEntry::KIND_SET.union(YamlBogus::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, YAML_BLOCK_SEQUENCE_ENTRY | YAML_BOGUS)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            YAML_BLOCK_SEQUENCE_ENTRY => {
+                Self::YamlBlockSequenceEntry(YamlBlockSequenceEntry { syntax })
+            }
+            YAML_BOGUS => Self::YamlBogus(YamlBogus { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::YamlBlockSequenceEntry(it) => &it.syntax,
+            Self::YamlBogus(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            Self::YamlBlockSequenceEntry(it) => it.syntax,
+            Self::YamlBogus(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyYamlBlockSequenceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::YamlBlockSequenceEntry(it) => std::fmt::Debug::fmt(it, f),
+            Self::YamlBogus(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyYamlBlockSequenceEntry> for SyntaxNode {
+    fn from(n: AnyYamlBlockSequenceEntry) -> Self {
+        match n {
+            AnyYamlBlockSequenceEntry::YamlBlockSequenceEntry(it) => it.into(),
+            AnyYamlBlockSequenceEntry::YamlBogus(it) => it.into(),
+        }
+    }
+}
+impl From<AnyYamlBlockSequenceEntry> for SyntaxElement {
+    fn from(n: AnyYamlBlockSequenceEntry) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<YamlBogus> for AnyYamlDocument {
+    fn from(node: YamlBogus) -> Self {
+        Self::YamlBogus(node)
+    }
+}
+impl From<YamlDocument> for AnyYamlDocument {
+    fn from(node: YamlDocument) -> Self {
+        Self::YamlDocument(node)
+    }
+}
+impl AstNode for AnyYamlDocument {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = YamlBogus::KIND_SET.union(YamlDocument::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, YAML_BOGUS | YAML_DOCUMENT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            YAML_BOGUS => Self::YamlBogus(YamlBogus { syntax }),
+            YAML_DOCUMENT => Self::YamlDocument(YamlDocument { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::YamlBogus(it) => &it.syntax,
+            Self::YamlDocument(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            Self::YamlBogus(it) => it.syntax,
+            Self::YamlDocument(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyYamlDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::YamlBogus(it) => std::fmt::Debug::fmt(it, f),
+            Self::YamlDocument(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyYamlDocument> for SyntaxNode {
+    fn from(n: AnyYamlDocument) -> Self {
+        match n {
+            AnyYamlDocument::YamlBogus(it) => it.into(),
+            AnyYamlDocument::YamlDocument(it) => it.into(),
+        }
+    }
+}
+impl From<AnyYamlDocument> for SyntaxElement {
+    fn from(n: AnyYamlDocument) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
 impl From<YamlFlowMapExplicitEntry> for AnyYamlFlowMapEntry {
     fn from(node: YamlFlowMapExplicitEntry) -> Self {
         Self::YamlFlowMapExplicitEntry(node)
@@ -3745,6 +3883,11 @@ impl From<AnyYamlFlowMapEntry> for SyntaxElement {
         node.into()
     }
 }
+impl From<YamlAliasNode> for AnyYamlFlowMapImplicitKey {
+    fn from(node: YamlAliasNode) -> Self {
+        Self::YamlAliasNode(node)
+    }
+}
 impl From<YamlFlowJsonNode> for AnyYamlFlowMapImplicitKey {
     fn from(node: YamlFlowJsonNode) -> Self {
         Self::YamlFlowJsonNode(node)
@@ -3757,13 +3900,18 @@ impl From<YamlFlowYamlNode> for AnyYamlFlowMapImplicitKey {
 }
 impl AstNode for AnyYamlFlowMapImplicitKey {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        YamlFlowJsonNode::KIND_SET.union(YamlFlowYamlNode::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = YamlAliasNode::KIND_SET
+        .union(YamlFlowJsonNode::KIND_SET)
+        .union(YamlFlowYamlNode::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE)
+        matches!(
+            kind,
+            YAML_ALIAS_NODE | YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
+            YAML_ALIAS_NODE => Self::YamlAliasNode(YamlAliasNode { syntax }),
             YAML_FLOW_JSON_NODE => Self::YamlFlowJsonNode(YamlFlowJsonNode { syntax }),
             YAML_FLOW_YAML_NODE => Self::YamlFlowYamlNode(YamlFlowYamlNode { syntax }),
             _ => return None,
@@ -3772,12 +3920,14 @@ impl AstNode for AnyYamlFlowMapImplicitKey {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => &it.syntax,
             Self::YamlFlowJsonNode(it) => &it.syntax,
             Self::YamlFlowYamlNode(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => it.syntax,
             Self::YamlFlowJsonNode(it) => it.syntax,
             Self::YamlFlowYamlNode(it) => it.syntax,
         }
@@ -3786,6 +3936,7 @@ impl AstNode for AnyYamlFlowMapImplicitKey {
 impl std::fmt::Debug for AnyYamlFlowMapImplicitKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::YamlAliasNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowJsonNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowYamlNode(it) => std::fmt::Debug::fmt(it, f),
         }
@@ -3794,6 +3945,7 @@ impl std::fmt::Debug for AnyYamlFlowMapImplicitKey {
 impl From<AnyYamlFlowMapImplicitKey> for SyntaxNode {
     fn from(n: AnyYamlFlowMapImplicitKey) -> Self {
         match n {
+            AnyYamlFlowMapImplicitKey::YamlAliasNode(it) => it.into(),
             AnyYamlFlowMapImplicitKey::YamlFlowJsonNode(it) => it.into(),
             AnyYamlFlowMapImplicitKey::YamlFlowYamlNode(it) => it.into(),
         }
@@ -3810,6 +3962,11 @@ impl From<YamlAliasNode> for AnyYamlFlowNode {
         Self::YamlAliasNode(node)
     }
 }
+impl From<YamlBogusFlowNode> for AnyYamlFlowNode {
+    fn from(node: YamlBogusFlowNode) -> Self {
+        Self::YamlBogusFlowNode(node)
+    }
+}
 impl From<YamlFlowJsonNode> for AnyYamlFlowNode {
     fn from(node: YamlFlowJsonNode) -> Self {
         Self::YamlFlowJsonNode(node)
@@ -3823,17 +3980,19 @@ impl From<YamlFlowYamlNode> for AnyYamlFlowNode {
 impl AstNode for AnyYamlFlowNode {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = YamlAliasNode::KIND_SET
+        .union(YamlBogusFlowNode::KIND_SET)
         .union(YamlFlowJsonNode::KIND_SET)
         .union(YamlFlowYamlNode::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            YAML_ALIAS_NODE | YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE
+            YAML_ALIAS_NODE | YAML_BOGUS_FLOW_NODE | YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             YAML_ALIAS_NODE => Self::YamlAliasNode(YamlAliasNode { syntax }),
+            YAML_BOGUS_FLOW_NODE => Self::YamlBogusFlowNode(YamlBogusFlowNode { syntax }),
             YAML_FLOW_JSON_NODE => Self::YamlFlowJsonNode(YamlFlowJsonNode { syntax }),
             YAML_FLOW_YAML_NODE => Self::YamlFlowYamlNode(YamlFlowYamlNode { syntax }),
             _ => return None,
@@ -3843,6 +4002,7 @@ impl AstNode for AnyYamlFlowNode {
     fn syntax(&self) -> &SyntaxNode {
         match self {
             Self::YamlAliasNode(it) => &it.syntax,
+            Self::YamlBogusFlowNode(it) => &it.syntax,
             Self::YamlFlowJsonNode(it) => &it.syntax,
             Self::YamlFlowYamlNode(it) => &it.syntax,
         }
@@ -3850,6 +4010,7 @@ impl AstNode for AnyYamlFlowNode {
     fn into_syntax(self) -> SyntaxNode {
         match self {
             Self::YamlAliasNode(it) => it.syntax,
+            Self::YamlBogusFlowNode(it) => it.syntax,
             Self::YamlFlowJsonNode(it) => it.syntax,
             Self::YamlFlowYamlNode(it) => it.syntax,
         }
@@ -3859,6 +4020,7 @@ impl std::fmt::Debug for AnyYamlFlowNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::YamlAliasNode(it) => std::fmt::Debug::fmt(it, f),
+            Self::YamlBogusFlowNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowJsonNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowYamlNode(it) => std::fmt::Debug::fmt(it, f),
         }
@@ -3868,6 +4030,7 @@ impl From<AnyYamlFlowNode> for SyntaxNode {
     fn from(n: AnyYamlFlowNode) -> Self {
         match n {
             AnyYamlFlowNode::YamlAliasNode(it) => it.into(),
+            AnyYamlFlowNode::YamlBogusFlowNode(it) => it.into(),
             AnyYamlFlowNode::YamlFlowJsonNode(it) => it.into(),
             AnyYamlFlowNode::YamlFlowYamlNode(it) => it.into(),
         }
@@ -4131,6 +4294,16 @@ impl std::fmt::Display for AnyYamlBlockScalarContent {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyYamlBlockSequenceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyYamlDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyYamlFlowMapEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4489,7 +4662,63 @@ impl From<YamlBogusBlockNode> for SyntaxElement {
         n.syntax.into()
     }
 }
-biome_rowan::declare_node_union! { pub AnyYamlBogusNode = YamlBogus | YamlBogusBlockMapEntry | YamlBogusBlockNode }
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct YamlBogusFlowNode {
+    syntax: SyntaxNode,
+}
+impl YamlBogusFlowNode {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for YamlBogusFlowNode {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BOGUS_FLOW_NODE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BOGUS_FLOW_NODE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBogusFlowNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBogusFlowNode")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<YamlBogusFlowNode> for SyntaxNode {
+    fn from(n: YamlBogusFlowNode) -> Self {
+        n.syntax
+    }
+}
+impl From<YamlBogusFlowNode> for SyntaxElement {
+    fn from(n: YamlBogusFlowNode) -> Self {
+        n.syntax.into()
+    }
+}
+biome_rowan::declare_node_union! { pub AnyYamlBogusNode = YamlBogus | YamlBogusBlockMapEntry | YamlBogusBlockNode | YamlBogusFlowNode }
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct YamlBlockMapEntryList {
     syntax_list: SyntaxList,
@@ -4626,7 +4855,7 @@ impl Serialize for YamlBlockSequenceEntryList {
 }
 impl AstNodeList for YamlBlockSequenceEntryList {
     type Language = Language;
-    type Node = YamlBlockSequenceEntry;
+    type Node = AnyYamlBlockSequenceEntry;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -4641,15 +4870,15 @@ impl Debug for YamlBlockSequenceEntryList {
     }
 }
 impl IntoIterator for &YamlBlockSequenceEntryList {
-    type Item = YamlBlockSequenceEntry;
-    type IntoIter = AstNodeListIterator<Language, YamlBlockSequenceEntry>;
+    type Item = AnyYamlBlockSequenceEntry;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlBlockSequenceEntry>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for YamlBlockSequenceEntryList {
-    type Item = YamlBlockSequenceEntry;
-    type IntoIter = AstNodeListIterator<Language, YamlBlockSequenceEntry>;
+    type Item = AnyYamlBlockSequenceEntry;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlBlockSequenceEntry>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -4790,7 +5019,7 @@ impl Serialize for YamlDocumentList {
 }
 impl AstNodeList for YamlDocumentList {
     type Language = Language;
-    type Node = YamlDocument;
+    type Node = AnyYamlDocument;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -4805,15 +5034,15 @@ impl Debug for YamlDocumentList {
     }
 }
 impl IntoIterator for &YamlDocumentList {
-    type Item = YamlDocument;
-    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+    type Item = AnyYamlDocument;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlDocument>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for YamlDocumentList {
-    type Item = YamlDocument;
-    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+    type Item = AnyYamlDocument;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlDocument>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
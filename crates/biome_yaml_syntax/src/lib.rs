@@ -8,6 +8,7 @@ mod syntax_node;
 pub use self::generated::*;
 use biome_rowan::{AstNode, RawSyntaxKind};
 pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::YamlFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for YamlSyntaxKind {
@@ -30,7 +31,10 @@ impl biome_rowan::SyntaxKind for YamlSyntaxKind {
     fn is_bogus(&self) -> bool {
         matches!(
             self,
-            Self::YAML_BOGUS | Self::YAML_BOGUS_BLOCK_NODE | Self::YAML_BOGUS_BLOCK_MAP_ENTRY
+            Self::YAML_BOGUS
+                | Self::YAML_BOGUS_BLOCK_NODE
+                | Self::YAML_BOGUS_BLOCK_MAP_ENTRY
+                | Self::YAML_BOGUS_FLOW_NODE
         )
     }
 
@@ -38,6 +42,7 @@ impl biome_rowan::SyntaxKind for YamlSyntaxKind {
         match self {
             kind if AnyYamlBlockMapEntry::can_cast(*kind) => Self::YAML_BOGUS_BLOCK_MAP_ENTRY,
             kind if AnyYamlBlockNode::can_cast(*kind) => Self::YAML_BOGUS_BLOCK_NODE,
+            kind if AnyYamlFlowNode::can_cast(*kind) => Self::YAML_BOGUS_FLOW_NODE,
             _ => Self::YAML_BOGUS,
         }
     }
@@ -61,7 +66,7 @@ impl biome_rowan::SyntaxKind for YamlSyntaxKind {
     }
 
     fn is_trivia(self) -> bool {
-        matches!(self, Self::WHITESPACE | Self::COMMENT)
+        matches!(self, Self::NEWLINE | Self::WHITESPACE | Self::COMMENT)
     }
 
     fn to_string(&self) -> Option<&'static str> {
@@ -71,7 +76,7 @@ impl biome_rowan::SyntaxKind for YamlSyntaxKind {
 
 impl YamlSyntaxKind {
     pub fn is_trivia(self) -> bool {
-        matches!(self, Self::WHITESPACE)
+        matches!(self, Self::NEWLINE | Self::WHITESPACE)
     }
 
     pub fn is_comments(self) -> bool {
@@ -84,6 +89,7 @@ impl TryFrom<YamlSyntaxKind> for TriviaPieceKind {
 
     fn try_from(value: YamlSyntaxKind) -> Result<Self, Self::Error> {
         match value {
+            YamlSyntaxKind::NEWLINE => Ok(Self::Newline),
             YamlSyntaxKind::WHITESPACE => Ok(Self::Whitespace),
             YamlSyntaxKind::COMMENT => Ok(Self::SingleLineComment),
             _ => Err(()),
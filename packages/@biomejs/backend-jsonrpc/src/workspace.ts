@@ -75,6 +75,10 @@ export interface Configuration {
 	 * The configuration for the linter
 	 */
 	linter?: LinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
@@ -91,6 +95,10 @@ export interface Configuration {
 	 * The configuration of the VCS integration
 	 */
 	vcs?: VcsConfiguration;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
 export type Schema = string;
 export interface AssistConfiguration {
@@ -235,6 +243,12 @@ export interface GraphqlConfiguration {
 	 */
 	formatter?: GraphqlFormatterConfiguration;
 	linter?: GraphqlLinterConfiguration;
+	/**
+	* Paths to the files that describe the GraphQL schema of the project, relative to the configuration file.
+
+`.json` files are read as the result of an introspection query, and other files as SDL. Some lint rules use the schema to validate the operations of the project. 
+	 */
+	schema?: string[];
 }
 /**
  * Options applied to GritQL files
@@ -261,6 +275,10 @@ export interface HtmlConfiguration {
 	 * HTML formatter options
 	 */
 	formatter?: HtmlFormatterConfiguration;
+	/**
+	 * HTML linter options
+	 */
+	linter?: HtmlLinterConfiguration;
 	/**
 	 * HTML parsing options
 	 */
@@ -274,6 +292,10 @@ export interface JsConfiguration {
 	 * Assist options
 	 */
 	assist?: JsAssistConfiguration;
+	/**
+	 * Options for the CSS and GraphQL code embedded in tagged template literals, such as ``styled.div`...` `` or ``gql`...` ``.
+	 */
+	embeddedTemplates?: JsEmbeddedTemplatesConfiguration;
 	/**
 	 * Formatting options
 	 */
@@ -336,6 +358,15 @@ export interface LinterConfiguration {
 	 */
 	rules?: Rules;
 }
+/**
+ * Options applied to Markdown files
+ */
+export interface MarkdownConfiguration {
+	/**
+	 * Markdown formatter options
+	 */
+	formatter?: MarkdownFormatterConfiguration;
+}
 export type Overrides = OverridePattern[];
 export type Plugins = PluginConfiguration[];
 export type Bool = boolean;
@@ -366,6 +397,15 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: Bool;
 }
+/**
+ * Options applied to YAML files
+ */
+export interface YamlConfiguration {
+	/**
+	 * YAML formatter options
+	 */
+	formatter?: YamlFormatterConfiguration;
+}
 export interface Actions {
 	/**
 	 * It enables the assist actions recommended by Biome. `true` by default.
@@ -556,7 +596,7 @@ export interface HtmlFormatterConfiguration {
 	 */
 	enabled?: Bool;
 	/**
-	 * Whether to indent the `<script>` and `<style>` tags for HTML (and its super languages). Defaults to false.
+	 * Whether to indent the content of `<script>` and `<style>` tags in templating languages like Astro. Defaults to false. The content of these tags is always indented in HTML files.
 	 */
 	indentScriptAndStyle?: IndentScriptAndStyle;
 	/**
@@ -584,6 +624,15 @@ export interface HtmlFormatterConfiguration {
 	 */
 	whitespaceSensitivity?: WhitespaceSensitivity;
 }
+/**
+ * Options that changes how the HTML linter behaves
+ */
+export interface HtmlLinterConfiguration {
+	/**
+	 * Control the linter for HTML (and its super languages) files.
+	 */
+	enabled?: Bool;
+}
 /**
  * Options that changes how the HTML parser behaves
  */
@@ -597,6 +646,23 @@ export interface JsAssistConfiguration {
 	 */
 	enabled?: Bool;
 }
+/**
+ * Options that control how the code embedded in tagged template literals is handled
+ */
+export interface JsEmbeddedTemplatesConfiguration {
+	/**
+	 * Tags of template literals that contain CSS, in addition to `css`, `keyframes`, `createGlobalStyle`, `injectGlobal` and `styled`.
+	 */
+	cssTags?: string[];
+	/**
+	 * Whether the CSS and GraphQL code embedded in tagged template literals is formatted and linted. Defaults to `false`.
+	 */
+	enabled?: Bool;
+	/**
+	 * Tags of template literals that contain GraphQL, in addition to `gql` and `graphql`.
+	 */
+	graphqlTags?: string[];
+}
 /**
  * Formatting options specific to the JavaScript files
  */
@@ -776,6 +842,19 @@ export interface Rules {
 	style?: SeverityOrGroup_for_Style;
 	suspicious?: SeverityOrGroup_for_Suspicious;
 }
+/**
+ * Options that changes how the Markdown formatter behaves
+ */
+export interface MarkdownFormatterConfiguration {
+	/**
+	 * Control the formatter for Markdown files.
+	 */
+	enabled?: Bool;
+	/**
+	 * The type of line ending applied to Markdown files.
+	 */
+	lineEnding?: LineEnding;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Json language
@@ -821,13 +900,42 @@ export interface OverridePattern {
 	 * Specific configuration for the Json language
 	 */
 	linter?: OverrideLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * Specific configuration for additional plugins
 	 */
 	plugins?: Plugins;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
-export type PluginConfiguration = string;
+export type PluginConfiguration = string | PluginPathWithOptions;
 export type VcsClientKind = "git";
+/**
+ * Options that changes how the YAML formatter behaves
+ */
+export interface YamlFormatterConfiguration {
+	/**
+	 * Control the formatter for YAML files.
+	 */
+	enabled?: Bool;
+	/**
+	 * The size of the indentation applied to YAML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to YAML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to YAML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -988,6 +1096,16 @@ export interface OverrideLinterConfiguration {
 	 */
 	rules?: Rules;
 }
+export interface PluginPathWithOptions {
+	/**
+	 * The kind of the code actions emitted for the rewrites of the plugin. Defaults to `unsafe`.
+	 */
+	fix?: FixKind;
+	/**
+	 * The path of the plugin, relative to the configuration file.
+	 */
+	path: string;
+}
 export type RuleAssistConfiguration_for_Options =
 	| RuleAssistPlainConfiguration
 	| RuleAssistWithOptions_for_Options;
@@ -1550,6 +1668,10 @@ export interface Nursery {
 	 * Disallow bitwise operators.
 	 */
 	noBitwiseOperators?: RuleConfiguration_for_NoBitwiseOperatorsOptions;
+	/**
+	 * Require expressions of type void to appear in statement position.
+	 */
+	noConfusingVoidExpression?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow expressions where the operation doesn't affect the value
 	 */
@@ -1582,10 +1704,22 @@ export interface Nursery {
 	 * Disallow the use of the !important style.
 	 */
 	noImportantStyles?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow fragments whose type condition can never match.
+	 */
+	noInvalidFragmentTypes?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow variables whose type doesn't match where they are used.
+	 */
+	noInvalidVariableTypes?: RuleConfiguration_for_Null;
 	/**
 	 * Reports usage of "magic numbers" — numbers used directly instead of being assigned to named constants.
 	 */
 	noMagicNumbers?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow Promises to be used in places where they are almost certainly a mistake.
+	 */
+	noMisusedPromises?: RuleConfiguration_for_Null;
 	/**
 	 * Disallows defining React components inside other components.
 	 */
@@ -1622,14 +1756,50 @@ export interface Nursery {
 	 * Disallow let or var variables that are read but never assigned.
 	 */
 	noUnassignedVariables?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow references to classes that aren't declared by the imported CSS Module.
+	 */
+	noUndeclaredCssModuleClasses?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow passing arguments that aren't defined by the field or directive.
+	 */
+	noUnknownArguments?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown at-rules.
 	 */
 	noUnknownAtRule?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selecting fields that don't exist on the type of their selection set.
+	 */
+	noUnknownFields?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow references to types that don't exist in the schema.
+	 */
+	noUnknownTypes?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow conditionals that are always truthy or always falsy, based on their type.
+	 */
+	noUnnecessaryCondition?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow references to stylesheets and resources that cannot be resolved.
+	 */
+	noUnresolvedCssImports?: RuleConfiguration_for_Null;
 	/**
 	 * Warn when importing non-existing exports.
 	 */
 	noUnresolvedImports?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow classes of CSS Modules that are never used.
+	 */
+	noUnusedCssModuleClasses?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow exports that are never imported by another module.
+	 */
+	noUnusedExports?: RuleConfiguration_for_NoUnusedExportsOptions;
+	/**
+	 * Disallow files that are not reachable from any entry point of the project.
+	 */
+	noUnusedFiles?: RuleConfiguration_for_NoUnusedFilesOptions;
 	/**
 	 * Prevent duplicate polyfills from Polyfill.io.
 	 */
@@ -1654,6 +1824,10 @@ export interface Nursery {
 	 * Enforce that getters and setters for the same property are adjacent in class and object definitions.
 	 */
 	useAdjacentGetterSetter?: RuleConfiguration_for_Null;
+	/**
+	 * Require await to only be used on Promise-like values.
+	 */
+	useAwaitThenable?: RuleFixConfiguration_for_Null;
 	/**
 	 * Require the consistent declaration of object literals. Defaults to explicit definitions.
 	 */
@@ -1718,6 +1892,10 @@ export interface Nursery {
 	 * Enforce marking members as readonly if they are never modified outside the constructor.
 	 */
 	useReadonlyClassProperties?: RuleFixConfiguration_for_ReadonlyClassPropertiesOptions;
+	/**
+	 * Require the arguments that are non-null and without a default value in the schema.
+	 */
+	useRequiredArguments?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce JSDoc comment lines to start with a single asterisk, except for the first one.
 	 */
@@ -2395,6 +2573,10 @@ export interface Suspicious {
 	 */
 	useStrictMode?: RuleFixConfiguration_for_Null;
 }
+/**
+ * Used to identify the kind of code action emitted by a rule
+ */
+export type FixKind = "none" | "safe" | "unsafe";
 export type RuleAssistPlainConfiguration = "off" | "on";
 export interface RuleAssistWithOptions_for_Options {
 	/**
@@ -2473,6 +2655,12 @@ export type RuleConfiguration_for_NoRestrictedElementsOptions =
 export type RuleConfiguration_for_NoSecretsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NoSecretsOptions;
+export type RuleConfiguration_for_NoUnusedExportsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnusedExportsOptions;
+export type RuleConfiguration_for_NoUnusedFilesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnusedFilesOptions;
 export type RuleFixConfiguration_for_UseConsistentObjectDefinitionOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UseConsistentObjectDefinitionOptions;
@@ -2741,6 +2929,26 @@ export interface RuleWithOptions_for_NoSecretsOptions {
 	 */
 	options: NoSecretsOptions;
 }
+export interface RuleWithOptions_for_NoUnusedExportsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnusedExportsOptions;
+}
+export interface RuleWithOptions_for_NoUnusedFilesOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnusedFilesOptions;
+}
 export interface RuleWithFixOptions_for_UseConsistentObjectDefinitionOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
@@ -2966,10 +3174,6 @@ export interface RuleWithFixOptions_for_NoDoubleEqualsOptions {
 	options: NoDoubleEqualsOptions;
 }
 export type ImportGroups = ImportGroup[];
-/**
- * Used to identify the kind of code action emitted by a rule
- */
-export type FixKind = "none" | "safe" | "unsafe";
 export interface NoLabelWithoutControlOptions {
 	/**
 	 * Array of component names that should be considered the same as an `input` element.
@@ -2984,6 +3188,9 @@ export interface NoLabelWithoutControlOptions {
 	 */
 	labelComponents?: string[];
 }
+/**
+ * Options of the `useValidAriaRole` rule, shared by all the languages that implement it.
+ */
 export interface ValidAriaRoleOptions {
 	allowInvalidRoles?: string[];
 	ignoreNonDom?: boolean;
@@ -3117,6 +3324,24 @@ export interface NoSecretsOptions {
 	 */
 	entropyThreshold?: number;
 }
+/**
+ * Options for the rule `noUnusedExports`.
+ */
+export interface NoUnusedExportsOptions {
+	/**
+	 * Globs matching the files that are entry points of the project, in addition to those declared in the `package.json`.
+	 */
+	entryPoints: Glob[];
+}
+/**
+ * Options for the rule `noUnusedFiles`.
+ */
+export interface NoUnusedFilesOptions {
+	/**
+	 * Globs matching the files that are entry points of the project, in addition to those declared in the `package.json`.
+	 */
+	entryPoints: Glob[];
+}
 export interface UseConsistentObjectDefinitionOptions {
 	/**
 	 * The preferred syntax to enforce.
@@ -3617,6 +3842,7 @@ export type Category =
 	| "lint/nursery/noAwaitInLoop"
 	| "lint/nursery/noBitwiseOperators"
 	| "lint/nursery/noColorInvalidHex"
+	| "lint/nursery/noConfusingVoidExpression"
 	| "lint/nursery/noConsole"
 	| "lint/nursery/noConstantBinaryExpression"
 	| "lint/nursery/noDestructuredProps"
@@ -3630,10 +3856,13 @@ export type Category =
 	| "lint/nursery/noImportantInKeyframe"
 	| "lint/nursery/noImportantStyles"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
+	| "lint/nursery/noInvalidFragmentTypes"
 	| "lint/nursery/noInvalidGridAreas"
 	| "lint/nursery/noInvalidPositionAtImportRule"
+	| "lint/nursery/noInvalidVariableTypes"
 	| "lint/nursery/noMagicNumbers"
 	| "lint/nursery/noMissingGenericFamilyKeyword"
+	| "lint/nursery/noMisusedPromises"
 	| "lint/nursery/noNestedComponentDefinitions"
 	| "lint/nursery/noNoninteractiveElementInteractions"
 	| "lint/nursery/noProcessGlobal"
@@ -3645,21 +3874,31 @@ export type Category =
 	| "lint/nursery/noShorthandPropertyOverrides"
 	| "lint/nursery/noTsIgnore"
 	| "lint/nursery/noUnassignedVariables"
+	| "lint/nursery/noUndeclaredCssModuleClasses"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUnknownArguments"
 	| "lint/nursery/noUnknownAtRule"
+	| "lint/nursery/noUnknownFields"
 	| "lint/nursery/noUnknownFunction"
 	| "lint/nursery/noUnknownMediaFeatureName"
 	| "lint/nursery/noUnknownProperty"
 	| "lint/nursery/noUnknownSelectorPseudoElement"
+	| "lint/nursery/noUnknownTypes"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnnecessaryCondition"
+	| "lint/nursery/noUnresolvedCssImports"
 	| "lint/nursery/noUnresolvedImports"
+	| "lint/nursery/noUnusedCssModuleClasses"
+	| "lint/nursery/noUnusedExports"
+	| "lint/nursery/noUnusedFiles"
 	| "lint/nursery/noUnusedFunctionParameters"
 	| "lint/nursery/noUnwantedPolyfillio"
 	| "lint/nursery/noUselessBackrefInRegex"
 	| "lint/nursery/noUselessEscapeInString"
 	| "lint/nursery/noUselessUndefined"
 	| "lint/nursery/useAdjacentGetterSetter"
+	| "lint/nursery/useAwaitThenable"
 	| "lint/nursery/useBiomeSuppressionComment"
 	| "lint/nursery/useConsistentObjectDefinition"
 	| "lint/nursery/useConsistentResponse"
@@ -3680,6 +3919,7 @@ export type Category =
 	| "lint/nursery/useObjectSpread"
 	| "lint/nursery/useParseIntRadix"
 	| "lint/nursery/useReadonlyClassProperties"
+	| "lint/nursery/useRequiredArguments"
 	| "lint/nursery/useSingleJsDocAsterisk"
 	| "lint/nursery/useSortedClasses"
 	| "lint/nursery/useSortedProperties"
@@ -3863,6 +4103,7 @@ export type Category =
 	| "plugin"
 	| "project"
 	| "search"
+	| "graph"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
@@ -4033,7 +4274,9 @@ export type DocumentFileSource =
 	| { Css: CssFileSource }
 	| { Graphql: GraphqlFileSource }
 	| { Html: HtmlFileSource }
-	| { Grit: GritFileSource };
+	| { Grit: GritFileSource }
+	| { Yaml: YamlFileSource }
+	| { Markdown: MarkdownFileSource };
 export interface JsFileSource {
 	/**
 	 * Used to mark if the source is being used for an Astro, Svelte or Vue file
@@ -4061,6 +4304,8 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
+export interface YamlFileSource {}
+export interface MarkdownFileSource {}
 export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
 export type Language =
 	| "javaScript"
@@ -4083,9 +4328,9 @@ export type JsonFileVariant = "standard" | "jsonc";
 /**
 	* The style of CSS contained in the file.
 
-Currently, Biome only supports plain CSS, and aims to be compatible with the latest Recommendation level standards. 
+Biome supports plain CSS, and aims to be compatible with the latest Recommendation level standards. It also supports the SCSS syntax of Sass. 
 	 */
-export type CssVariant = "standard";
+export type CssVariant = "standard" | "scss";
 /**
  * The style of GraphQL contained in the file.
  */
@@ -4326,16 +4571,127 @@ export interface RenameResult {
 	 * List of text edit operations to apply on the source code
 	 */
 	indels: TextEdit;
+	/**
+	 * Edits to apply to other files affected by this rename operation, such as the modules importing a renamed export
+	 */
+	otherFiles: RenameFileEdit[];
 	/**
 	 * Range of source code modified by this rename operation
 	 */
 	range: TextRange;
 }
+export interface RenameFileEdit {
+	/**
+	 * List of text edit operations to apply on the source code of the file
+	 */
+	indels: TextEdit;
+	/**
+	 * Path of the file to modify
+	 */
+	path: BiomePath;
+	/**
+	 * Range of source code modified in the file
+	 */
+	range: TextRange;
+}
+export interface GotoDefinitionParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	symbolAt: TextSize;
+}
+export interface GotoDefinitionResult {
+	/**
+	 * Location of the declaration of the symbol, if it could be found
+	 */
+	definition?: SymbolLocation;
+}
+export interface SymbolLocation {
+	/**
+	 * Path of the file in which the symbol is located
+	 */
+	path: BiomePath;
+	/**
+	 * Range of the symbol inside the file
+	 */
+	range: TextRange;
+}
+export interface FindReferencesParams {
+	/**
+	 * Whether the declaration of the symbol should be included in the result
+	 */
+	includeDeclaration: boolean;
+	path: BiomePath;
+	projectKey: ProjectKey;
+	symbolAt: TextSize;
+}
+export interface FindReferencesResult {
+	/**
+	 * Locations of all the references to the symbol, across all files
+	 */
+	references: SymbolLocation[];
+}
+export interface GetModuleDependenciesParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetModuleDependenciesResult {
+	/**
+	 * Paths of the modules imported by the file, either directly or transitively, sorted by path
+	 */
+	dependencies: BiomePath[];
+	/**
+	 * Whether the file contains imports that couldn't be resolved
+	 */
+	hasUnresolvedImports: boolean;
+}
+export interface GetModuleGraphParams {
+	projectKey: ProjectKey;
+}
+export interface GetModuleGraphResult {
+	/**
+	 * The modules of the project that are part of the module graph, sorted by path
+	 */
+	modules: ModuleGraphNode[];
+}
+export interface ModuleGraphNode {
+	/**
+	 * Imports of the module, sorted by specifier
+	 */
+	imports: ModuleGraphImport[];
+	/**
+	 * Path of the module
+	 */
+	path: BiomePath;
+}
+export interface ModuleGraphImport {
+	/**
+	 * How the file is imported
+	 */
+	kind: ModuleGraphImportKind;
+	/**
+	 * Path of the imported file, or `None` if the specifier couldn't be resolved
+	 */
+	resolvedPath?: BiomePath;
+	/**
+	 * The specifier of the import, as written in the source
+	 */
+	specifier: string;
+	/**
+	 * Symbols imported or re-exported from the file, sorted by name. Re-exports are described as `name as exported_name`, and imports of all the symbols as `*`.
+	 */
+	symbols: string[];
+}
+export type ModuleGraphImportKind = "static" | "dynamic" | "url";
 export interface ParsePatternParams {
 	defaultLanguage: GritTargetLanguage;
 	pattern: string;
 }
-export type GritTargetLanguage = "CSS" | "JavaScript";
+export type GritTargetLanguage =
+	| "CSS"
+	| "GraphQL"
+	| "HTML"
+	| "JavaScript"
+	| "JSON";
 export interface ParsePatternResult {
 	patternId: PatternId;
 }
@@ -4348,6 +4704,10 @@ export interface SearchPatternParams {
 export interface SearchResults {
 	matches: TextRange[];
 	path: BiomePath;
+	/**
+	 * The content of the file after applying the rewrites of the pattern, or `None` if the pattern doesn't rewrite the file.
+	 */
+	rewritten?: string;
 }
 export interface DropPatternParams {
 	pattern: PatternId;
@@ -4377,6 +4737,12 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	gotoDefinition(params: GotoDefinitionParams): Promise<GotoDefinitionResult>;
+	findReferences(params: FindReferencesParams): Promise<FindReferencesResult>;
+	getModuleDependencies(
+		params: GetModuleDependenciesParams,
+	): Promise<GetModuleDependenciesResult>;
+	getModuleGraph(params: GetModuleGraphParams): Promise<GetModuleGraphResult>;
 	parsePattern(params: ParsePatternParams): Promise<ParsePatternResult>;
 	searchPattern(params: SearchPatternParams): Promise<SearchResults>;
 	dropPattern(params: DropPatternParams): Promise<void>;
@@ -4450,6 +4816,18 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		gotoDefinition(params) {
+			return transport.request("biome/goto_definition", params);
+		},
+		findReferences(params) {
+			return transport.request("biome/find_references", params);
+		},
+		getModuleDependencies(params) {
+			return transport.request("biome/get_module_dependencies", params);
+		},
+		getModuleGraph(params) {
+			return transport.request("biome/get_module_graph", params);
+		},
 		parsePattern(params) {
 			return transport.request("biome/parse_pattern", params);
 		},
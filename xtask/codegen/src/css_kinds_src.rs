@@ -40,6 +40,8 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%=", "PERCENTEQ"),
         ("@", "AT"),
         ("$=", "DOLLAR_EQ"),
+        ("$", "DOLLAR"),
+        ("...", "DOT3"),
         ("~=", "TILDE_EQ"),
         ("-->", "CDC"),
         ("<!--", "CDO"),
@@ -243,6 +245,16 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "composes",
         "position-try",
         "view-transition",
+        // SCSS
+        "use",
+        "forward",
+        "mixin",
+        "include",
+        "extend",
+        "with",
+        "show",
+        "hide",
+        "optional",
         //
         "font-face",
         // Don't add to the end of this list, add new keywords above the "HERE"
@@ -485,6 +497,42 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_UNKNOWN_BLOCK_AT_RULE",
         "CSS_UNKNOWN_VALUE_AT_RULE",
         "CSS_UNKNOWN_AT_RULE_COMPONENT_LIST",
+        // SCSS
+        "SCSS_DECLARATION",
+        "SCSS_VARIABLE_MODIFIER_LIST",
+        "SCSS_VARIABLE_MODIFIER",
+        "SCSS_NESTED_PROPERTY",
+        "SCSS_IDENTIFIER",
+        "SCSS_QUALIFIED_NAME",
+        "SCSS_BINARY_EXPRESSION",
+        "SCSS_UNARY_EXPRESSION",
+        "SCSS_MAP_EXPRESSION",
+        "SCSS_MAP_ITEM_LIST",
+        "SCSS_MAP_ITEM",
+        "SCSS_INTERPOLATION",
+        "SCSS_INTERPOLATED_IDENTIFIER",
+        "SCSS_INTERPOLATED_IDENTIFIER_PART_LIST",
+        "SCSS_PLACEHOLDER_SELECTOR",
+        "SCSS_USE_AT_RULE",
+        "SCSS_USE_NAMESPACE",
+        "SCSS_USE_ALL_NAMESPACE",
+        "SCSS_MODULE_CONFIGURATION",
+        "SCSS_FORWARD_AT_RULE",
+        "SCSS_FORWARD_PREFIX",
+        "SCSS_FORWARD_VISIBILITY",
+        "SCSS_FORWARD_MEMBER_LIST",
+        "SCSS_MIXIN_AT_RULE",
+        "SCSS_PARAMETERS",
+        "SCSS_PARAMETER_LIST",
+        "SCSS_PARAMETER",
+        "SCSS_PARAMETER_DEFAULT_VALUE",
+        "SCSS_INCLUDE_AT_RULE",
+        "SCSS_ARGUMENTS",
+        "SCSS_ARGUMENT_LIST",
+        "SCSS_KEYWORD_ARGUMENT",
+        "SCSS_REST_ARGUMENT",
+        "SCSS_EXTEND_AT_RULE",
+        "SCSS_EXTEND_OPTIONAL_MODIFIER",
         // Bogus nodes
         "CSS_BOGUS",
         "CSS_BOGUS_BLOCK",
@@ -561,9 +561,12 @@ enum NodeDialect {
     Jsx,
     Json,
     Css,
+    Scss,
     Grit,
     Graphql,
     Html,
+    Yaml,
+    Markdown,
 }
 
 impl NodeDialect {
@@ -574,9 +577,12 @@ impl NodeDialect {
             Self::Jsx,
             Self::Json,
             Self::Css,
+            Self::Scss,
             Self::Grit,
             Self::Graphql,
             Self::Html,
+            Self::Yaml,
+            Self::Markdown,
         ]
     }
 
@@ -591,9 +597,12 @@ impl NodeDialect {
             Self::Jsx => "jsx",
             Self::Json => "json",
             Self::Css => "css",
+            Self::Scss => "scss",
             Self::Grit => "grit",
             Self::Graphql => "graphql",
             Self::Html => "html",
+            Self::Yaml => "yaml",
+            Self::Markdown => "markdown",
         }
     }
 
@@ -604,9 +613,12 @@ impl NodeDialect {
             "Ts" => Self::Ts,
             "Json" => Self::Json,
             "Css" => Self::Css,
+            "Scss" => Self::Scss,
             "Grit" => Self::Grit,
             "Graphql" => Self::Graphql,
             "Html" => Self::Html,
+            "Yaml" => Self::Yaml,
+            "Md" => Self::Markdown,
             _ => {
                 eprintln!("missing prefix {name}");
                 Self::Js
@@ -650,6 +662,10 @@ enum NodeConcept {
     // GraphQL
     Definition,
     Extension,
+
+    // YAML
+    Block,
+    Flow,
 }
 
 impl NodeConcept {
@@ -678,6 +694,8 @@ impl NodeConcept {
             Self::Predicate => "predicates",
             Self::Definition => "definitions",
             Self::Extension => "extensions",
+            Self::Block => "block",
+            Self::Flow => "flow",
         }
     }
 }
@@ -801,6 +819,9 @@ fn get_node_concept(
                         | "Color"
                         | "Length"
                         | "UrlValueRaw"
+                        | "QualifiedName"
+                        | "MapExpression"
+                        | "Interpolation"
                 ) || name.ends_with("Dimension")
                     || name.ends_with("Identifier") =>
                 {
@@ -829,8 +850,12 @@ fn get_node_concept(
                 _ => NodeConcept::Auxiliary,
             },
 
-            // TODO: implement formatter
-            LanguageKind::Yaml => NodeConcept::Auxiliary,
+            LanguageKind::Yaml => match name {
+                _ if name.ends_with("Scalar") || name.ends_with("Property") => NodeConcept::Value,
+                _ if name.starts_with("Flow") => NodeConcept::Flow,
+                _ if name.starts_with("Block") || name.starts_with("Compact") => NodeConcept::Block,
+                _ => NodeConcept::Auxiliary,
+            },
         }
     }
 }
@@ -896,7 +921,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatter",
             Self::Html => "HtmlFormatter",
             Self::Yaml => "YamlFormatter",
-            Self::Markdown => "DemoFormatter",
+            Self::Markdown => "MarkdownFormatter",
         };
 
         Ident::new(name, Span::call_site())
@@ -911,7 +936,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatContext",
             Self::Html => "HtmlFormatContext",
             Self::Yaml => "YamlFormatContext",
-            Self::Markdown => "DemoFormatterContext",
+            Self::Markdown => "MarkdownFormatContext",
         };
 
         Ident::new(name, Span::call_site())
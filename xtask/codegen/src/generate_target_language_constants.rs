@@ -18,35 +18,49 @@ pub fn generate_target_language_constants(
                 .iter()
                 .enumerate()
                 .filter_map(|(index, field)| match field {
-                    Field::Token { name, optional, .. } => Some((index, name, optional)),
-                    Field::Node { .. } => None,
-                })
-                // TODO: We might want to move this to `js_kinds_src.rs` when we
-                //       start supporting other languages with Grit.
-                .filter_map(|(index, name, optional)| match (name.as_str(), optional) {
-                    ("async", true) => Some(format!("({node_kind}, {index}, OnlyIf(&[\"\"])),")),
-                    (";", true) => Some(format!("({node_kind}, {index}, Always),")),
-                    _ => None,
+                    Field::Token { name, optional, .. } => match (name.as_str(), optional) {
+                        ("async", true) => {
+                            Some(format!("({node_kind}, {index}, OnlyIf(&[\"\"])),"))
+                        }
+                        (";", true) => Some(format!("({node_kind}, {index}, Always),")),
+                        _ => None,
+                    },
+                    Field::Node { name, .. } => {
+                        is_disregarded_when_absent(language_kind, &node.name, name)
+                            .then(|| format!("({node_kind}, {index}, OnlyIf(&[\"\"])),"))
+                    }
                 })
                 .collect::<Vec<_>>()
         })
         .collect();
+    // Glob imports would be unused in an empty table.
+    let imports = if disregarded_slots.is_empty() {
+        "self"
+    } else {
+        "self, *"
+    };
     let disregarded_slots = disregarded_slots.join("\n    ");
 
     let syntax_kind = match language_kind {
         LanguageKind::Css => "CssSyntaxKind",
+        LanguageKind::Graphql => "GraphqlSyntaxKind",
+        LanguageKind::Html => "HtmlSyntaxKind",
         LanguageKind::Js => "JsSyntaxKind",
+        LanguageKind::Json => "JsonSyntaxKind",
         _ => unimplemented!(),
     };
     let syntax_kind_module = match language_kind {
         LanguageKind::Css => "biome_css_syntax",
+        LanguageKind::Graphql => "biome_graphql_syntax",
+        LanguageKind::Html => "biome_html_syntax",
         LanguageKind::Js => "biome_js_syntax",
+        LanguageKind::Json => "biome_json_syntax",
         _ => unimplemented!(),
     };
 
     let result = format!(
-        "use crate::grit_target_language::DisregardedSlotCondition::{{self, *}};
-use {syntax_kind_module}::{syntax_kind}::{{self, *}};
+        "use crate::grit_target_language::DisregardedSlotCondition::{{{imports}}};
+use {syntax_kind_module}::{syntax_kind}::{{{imports}}};
 
 pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[({syntax_kind}, u32, DisregardedSlotCondition)] = &[
     {disregarded_slots}
@@ -58,3 +72,19 @@ pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[({syntax_kind}, u32, DisregardedSl
 
     Ok(pretty)
 }
+
+/// Returns whether the given node field is disregarded by snippets that don't
+/// specify it.
+///
+/// This allows a snippet such as `oldField` in GraphQL to match the field
+/// regardless of its alias, arguments, directives and selection set.
+fn is_disregarded_when_absent(language_kind: LanguageKind, node: &str, field: &str) -> bool {
+    matches!(
+        (language_kind, node, field),
+        (
+            LanguageKind::Graphql,
+            "GraphqlField",
+            "alias" | "arguments" | "directives" | "selection_set"
+        ) | (LanguageKind::Html, "HtmlAttribute", "initializer")
+    )
+}
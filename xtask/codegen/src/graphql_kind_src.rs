@@ -69,6 +69,7 @@ pub const GRAPHQL_KINDS_SRC: KindsSrc = KindsSrc {
         "IDENT",
         "COMMENT",
         "COMMA",
+        "GRIT_METAVARIABLE",
     ],
     nodes: &[
         "GRAPHQL_ROOT",
@@ -148,6 +149,8 @@ pub const GRAPHQL_KINDS_SRC: KindsSrc = KindsSrc {
         "GRAPHQL_BOOLEAN_VALUE",
         "GRAPHQL_NULL_VALUE",
         // Bogus nodes
+        // Grit metavariable
+        "GRAPHQL_METAVARIABLE",
         "GRAPHQL_BOGUS",
         "GRAPHQL_BOGUS_DEFINITION",
         "GRAPHQL_BOGUS_SELECTION",
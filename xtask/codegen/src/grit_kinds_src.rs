@@ -48,6 +48,7 @@ pub const GRIT_KINDS_SRC: KindsSrc = KindsSrc {
         "js",
         "css",
         "json",
+        "graphql",
         "grit",
         "html",
         // language flavors:
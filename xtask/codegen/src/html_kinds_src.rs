@@ -21,6 +21,7 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "WHITESPACE",
         "IDENT",
         "HTML_IDENT",
+        "GRIT_METAVARIABLE",
     ],
     nodes: &[
         "HTML_ROOT",
@@ -40,6 +41,8 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "HTML_CONTENT",
         "HTML_COMMENT",
         "HTML_CDATA_SECTION",
+        // Grit metavariable
+        "HTML_METAVARIABLE",
         // Bogus nodes
         "HTML_BOGUS",
         "HTML_BOGUS_ELEMENT",
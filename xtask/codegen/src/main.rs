@@ -6,6 +6,8 @@ mod generate_configuration;
 mod generate_license;
 #[cfg(feature = "configuration")]
 mod generate_migrate_eslint;
+#[cfg(feature = "configuration")]
+mod generate_migrate_stylelint;
 #[cfg(feature = "schema")]
 mod generate_schema;
 mod move_rule;
@@ -19,6 +21,8 @@ use crate::generate_configuration::generate_rules_configuration;
 use crate::generate_license::generate_license;
 #[cfg(feature = "configuration")]
 use crate::generate_migrate_eslint::generate_migrate_eslint;
+#[cfg(feature = "configuration")]
+use crate::generate_migrate_stylelint::generate_migrate_stylelint;
 #[cfg(feature = "schema")]
 use crate::generate_schema::generate_configuration_schema;
 use crate::move_rule::move_rule;
@@ -48,6 +52,10 @@ fn main() -> Result<()> {
             #[cfg(feature = "configuration")]
             generate_migrate_eslint(Overwrite)?;
         }
+        TaskCommand::MigrateStylelint => {
+            #[cfg(feature = "configuration")]
+            generate_migrate_stylelint(Overwrite)?;
+        }
         TaskCommand::Schema => {
             #[cfg(feature = "schema")]
             generate_configuration_schema(Overwrite)?;
@@ -13,7 +13,9 @@ use biome_css_syntax::CssLanguage;
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{DiagnosticExt, PrintDiagnostic, Severity};
 use biome_fs::BiomePath;
+use biome_graphql_semantic::{GraphqlSchema, GraphqlSchemaBuilder};
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_analyze::JsAnalyzerServices;
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, TextSize};
@@ -30,6 +32,7 @@ use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter, Write};
 use std::slice;
 use std::str::FromStr;
+use std::sync::Arc;
 
 #[derive(Debug)]
 struct Errors(String);
@@ -130,11 +133,22 @@ pub fn check_rules() -> anyhow::Result<()> {
         }
     }
 
+    impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+                + 'static,
+        {
+            self.push_rule::<R, <R::Query as Queryable>::Language>()
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
     biome_js_analyze::visit_registry(&mut visitor);
     biome_json_analyze::visit_registry(&mut visitor);
     biome_css_analyze::visit_registry(&mut visitor);
     biome_graphql_analyze::visit_registry(&mut visitor);
+    biome_html_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups, errors } = visitor;
     if !errors.is_empty() {
@@ -171,6 +185,10 @@ struct CodeBlockTest {
     /// Whether to use the last code block that was marked with
     /// `options` as the configuration settings for this code block.
     use_options: bool,
+
+    /// Whether this is a block of GraphQL SDL, used as the schema of
+    /// the following code blocks, instead of a valid/invalid code example.
+    schema: bool,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -209,6 +227,7 @@ impl FromStr for CodeBlockTest {
             ignore: false,
             options: OptionsParsingMode::NoOptions,
             use_options: false,
+            schema: false,
         };
 
         for token in tokens {
@@ -219,6 +238,7 @@ impl FromStr for CodeBlockTest {
                 "options" => test.options = OptionsParsingMode::RuleOptionsOnly,
                 "full_options" => test.options = OptionsParsingMode::FullConfiguration,
                 "use_options" => test.use_options = true,
+                "schema" => test.schema = true,
                 // Regard as language tags, last one wins
                 _ => test.tag = token.to_string(),
             }
@@ -351,6 +371,7 @@ fn assert_lint(
     test: &CodeBlockTest,
     code: &str,
     config: &Option<Configuration>,
+    schema: &Option<Arc<GraphqlSchema>>,
 ) -> anyhow::Result<()> {
     let file_path = format!("code-block.{}", test.tag);
 
@@ -383,23 +404,74 @@ fn assert_lint(
     match test.document_file_source() {
         DocumentFileSource::Js(file_source) => {
             // Temporary support for astro, svelte and vue code blocks
-            let (code, file_source) = match file_source.as_embedding_kind() {
-                EmbeddingKind::Astro => (
+            let scripts = match file_source.as_embedding_kind() {
+                EmbeddingKind::Astro => vec![(
                     biome_service::file_handlers::AstroFileHandler::input(code),
                     JsFileSource::ts(),
-                ),
-                EmbeddingKind::Svelte => (
+                )],
+                EmbeddingKind::Svelte => vec![(
                     biome_service::file_handlers::SvelteFileHandler::input(code),
                     biome_service::file_handlers::SvelteFileHandler::file_source(code),
-                ),
-                EmbeddingKind::Vue => (
-                    biome_service::file_handlers::VueFileHandler::input(code),
-                    biome_service::file_handlers::VueFileHandler::file_source(code),
-                ),
-                _ => (code, file_source),
+                )],
+                EmbeddingKind::Vue => biome_service::file_handlers::VueFileHandler::scripts(code),
+                _ => vec![(code, file_source)],
             };
 
-            let parse = biome_js_parser::parse(code, file_source, JsParserOptions::default());
+            for (code, file_source) in scripts {
+                let parse = biome_js_parser::parse(code, file_source, JsParserOptions::default());
+
+                if parse.has_errors() {
+                    for diag in parse.into_diagnostics() {
+                        let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                        diagnostics.write_diagnostic(error)?;
+                    }
+                } else {
+                    let root = parse.tree();
+
+                    let rule_filter = RuleFilter::Rule(group, rule);
+                    let filter = AnalysisFilter {
+                        enabled_rules: Some(slice::from_ref(&rule_filter)),
+                        ..AnalysisFilter::default()
+                    };
+
+                    let options = create_analyzer_options::<JsLanguage>(
+                        &workspace_settings,
+                        project_key,
+                        &file_path,
+                        test,
+                    );
+
+                    let services = JsAnalyzerServices::from((
+                        Default::default(),
+                        Default::default(),
+                        file_source,
+                    ));
+
+                    biome_js_analyze::analyze(&root, filter, &options, &[], services, |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                            let res = diagnostics.write_diagnostic(error);
+
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
+                        }
+
+                        ControlFlow::Continue(())
+                    });
+                }
+            }
+        }
+        DocumentFileSource::Json(file_source) => {
+            let parse = biome_json_parser::parse_json(code, JsonParserOptions::from(&file_source));
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -415,17 +487,14 @@ fn assert_lint(
                     ..AnalysisFilter::default()
                 };
 
-                let options = create_analyzer_options::<JsLanguage>(
+                let options = create_analyzer_options::<JsonLanguage>(
                     &workspace_settings,
                     project_key,
                     &file_path,
                     test,
                 );
 
-                let services =
-                    JsAnalyzerServices::from((Default::default(), Default::default(), file_source));
-
-                biome_js_analyze::analyze(&root, filter, &options, &[], services, |signal| {
+                biome_json_analyze::analyze(&root, filter, &options, file_source, &[], |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         for action in signal.actions() {
                             if !action.is_suppression() {
@@ -447,8 +516,14 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Json(file_source) => {
-            let parse = biome_json_parser::parse_json(code, JsonParserOptions::from(&file_source));
+        DocumentFileSource::Css(file_source) => {
+            let parse = biome_css_parser::parse_css(
+                code,
+                CssParserOptions {
+                    scss: file_source.is_scss(),
+                    ..CssParserOptions::default()
+                },
+            );
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -471,30 +546,37 @@ fn assert_lint(
                     test,
                 );
 
-                biome_json_analyze::analyze(&root, filter, &options, file_source, |signal| {
-                    if let Some(mut diag) = signal.diagnostic() {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diag = diag.add_code_suggestion(action.into());
+                biome_css_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    file_source.into(),
+                    &[],
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
                             }
-                        }
 
-                        let error = diag.with_file_path(&file_path).with_file_source_code(code);
-                        let res = diagnostics.write_diagnostic(error);
+                            let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                            let res = diagnostics.write_diagnostic(error);
 
-                        // Abort the analysis on error
-                        if let Err(err) = res {
-                            eprintln!("Error: {err}");
-                            return ControlFlow::Break(err);
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
                         }
-                    }
 
-                    ControlFlow::Continue(())
-                });
+                        ControlFlow::Continue(())
+                    },
+                );
             }
         }
-        DocumentFileSource::Css(..) => {
-            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+        DocumentFileSource::Graphql(..) => {
+            let parse = biome_graphql_parser::parse_graphql(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -517,30 +599,37 @@ fn assert_lint(
                     test,
                 );
 
-                biome_css_analyze::analyze(&root, filter, &options, &[], |signal| {
-                    if let Some(mut diag) = signal.diagnostic() {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diag = diag.add_code_suggestion(action.into());
+                biome_graphql_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    schema.clone(),
+                    &[],
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
                             }
-                        }
 
-                        let error = diag.with_file_path(&file_path).with_file_source_code(code);
-                        let res = diagnostics.write_diagnostic(error);
+                            let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                            let res = diagnostics.write_diagnostic(error);
 
-                        // Abort the analysis on error
-                        if let Err(err) = res {
-                            eprintln!("Error: {err}");
-                            return ControlFlow::Break(err);
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
                         }
-                    }
 
-                    ControlFlow::Continue(())
-                });
+                        ControlFlow::Continue(())
+                    },
+                );
             }
         }
-        DocumentFileSource::Graphql(..) => {
-            let parse = biome_graphql_parser::parse_graphql(code);
+        DocumentFileSource::Html(..) => {
+            let parse = biome_html_parser::parse_html(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -556,14 +645,14 @@ fn assert_lint(
                     ..AnalysisFilter::default()
                 };
 
-                let options = create_analyzer_options::<JsonLanguage>(
+                let options = create_analyzer_options::<HtmlLanguage>(
                     &workspace_settings,
                     project_key,
                     &file_path,
                     test,
                 );
 
-                biome_graphql_analyze::analyze(&root, filter, &options, |signal| {
+                biome_html_analyze::analyze(&root, filter, &options, &[], |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         for action in signal.actions() {
                             if !action.is_suppression() {
@@ -585,8 +674,9 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Html(..) => todo!("HTML analysis is not yet supported"),
         DocumentFileSource::Grit(..) => todo!("Grit analysis is not yet supported"),
+        DocumentFileSource::Yaml(..) => todo!("YAML analysis is not yet supported"),
+        DocumentFileSource::Markdown(..) => todo!("Markdown analysis is not yet supported"),
 
         // Unknown code blocks should be ignored by tests
         DocumentFileSource::Unknown | DocumentFileSource::Ignore => {}
@@ -806,6 +896,37 @@ fn parse_rule_options(
     }
 }
 
+/// Parse a block of GraphQL SDL into the schema used by the following code blocks.
+fn parse_schema(
+    group: &'static str,
+    rule_metadata: &RuleMetadata,
+    test: &CodeBlockTest,
+    code: &str,
+) -> anyhow::Result<Arc<GraphqlSchema>> {
+    let parse = biome_graphql_parser::parse_graphql(code);
+    if parse.has_errors() {
+        let file_path = format!("code-block.{}", test.tag);
+        let mut console = biome_console::EnvConsole::default();
+        for diag in parse.into_diagnostics() {
+            let error = diag.with_file_path(&file_path).with_file_source_code(code);
+            console.println(
+                biome_console::LogLevel::Error,
+                markup! {
+                    {PrintDiagnostic::verbose(&error)}
+                },
+            );
+        }
+        bail!(
+            "The schema of '{group}/{}' has syntax errors.\n\n{code}",
+            rule_metadata.name
+        );
+    }
+
+    let mut builder = GraphqlSchemaBuilder::default();
+    builder.add_document(&parse.tree());
+    Ok(Arc::new(builder.build()))
+}
+
 /// Parse the documentation fragment for a lint rule (in markdown) and lint the code blocks.
 fn parse_documentation(
     group: &'static str,
@@ -817,6 +938,9 @@ fn parse_documentation(
     // Track the last configuration options block that was encountered
     let mut last_options: Option<Configuration> = None;
 
+    // Track the last schema block that was encountered
+    let mut last_schema: Option<Arc<GraphqlSchema>> = None;
+
     // Tracks the content of the current code block if it's using a
     // language supported for analysis
     let mut language = None;
@@ -833,8 +957,17 @@ fn parse_documentation(
                     if test.options != OptionsParsingMode::NoOptions {
                         last_options =
                             parse_rule_options(group, &rule_metadata, category, &test, &block)?;
+                    } else if test.schema {
+                        last_schema = Some(parse_schema(group, &rule_metadata, &test, &block)?);
                     } else {
-                        assert_lint(group, rule_metadata.name, &test, &block, &last_options)?;
+                        assert_lint(
+                            group,
+                            rule_metadata.name,
+                            &test,
+                            &block,
+                            &last_options,
+                            &last_schema,
+                        )?;
                     }
                 }
             }